@@ -1,5 +1,8 @@
 use crate::RuleDiagnostic;
+use biome_console::MarkupBuf;
+use biome_diagnostics::Applicability;
 use biome_parser::AnyParse;
+use biome_rowan::{BatchMutation, Language, SyntaxNode, SyntaxToken, TextRange, TextSize};
 use camino::Utf8PathBuf;
 use std::{fmt::Debug, sync::Arc};
 
@@ -11,9 +14,148 @@ pub type AnalyzerPluginVec = Vec<Arc<Box<dyn AnalyzerPlugin>>>;
 
 /// Definition of an analyzer plugin.
 pub trait AnalyzerPlugin: Debug + Send + Sync {
-    fn evaluate(&self, root: AnyParse, path: Arc<Utf8PathBuf>) -> Vec<RuleDiagnostic>;
+    fn evaluate(&self, root: AnyParse, path: Arc<Utf8PathBuf>) -> Vec<PluginSignal>;
 
-    fn supports_css(&self) -> bool;
+    /// Returns the language of the files the plugin applies to.
+    fn language(&self) -> PluginTargetLanguage;
+}
+
+/// Languages that analyzer plugins can target.
+///
+/// The analyzer of each language only runs the plugins that target it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PluginTargetLanguage {
+    Css,
+    Graphql,
+    Html,
+    JavaScript,
+    Json,
+}
+
+/// Diagnostic reported by a plugin, optionally along with a code action to
+/// fix it.
+#[derive(Clone, Debug)]
+pub struct PluginSignal {
+    pub diagnostic: RuleDiagnostic,
+    pub action: Option<PluginAction>,
+}
+
+impl From<RuleDiagnostic> for PluginSignal {
+    fn from(diagnostic: RuleDiagnostic) -> Self {
+        Self {
+            diagnostic,
+            action: None,
+        }
+    }
+}
+
+/// Code action offered by a plugin.
+///
+/// Plugins don't have access to the mutation API of the analyzer, so the
+/// action holds the text edits to apply instead. The edits are sorted and
+/// don't overlap.
+#[derive(Clone, Debug)]
+pub struct PluginAction {
+    pub applicability: Applicability,
+    pub message: MarkupBuf,
+    pub edits: Vec<(TextRange, String)>,
+}
+
+impl PluginAction {
+    /// Converts the text edits into a mutation of the given root.
+    ///
+    /// Each group of consecutive tokens touched by the edits is replaced with
+    /// a single token holding the edited text. The trivia of the group is
+    /// kept unless an edit touches it.
+    pub fn to_mutation<L: Language>(&self, root: &SyntaxNode<L>) -> Option<BatchMutation<L>> {
+        let source = root.text_with_trivia().to_string();
+        let mut mutation = BatchMutation::new(root.clone());
+        let mut edits = self.edits.iter().peekable();
+        while let Some(edit) = edits.next() {
+            let mut group = vec![edit];
+            let mut tokens = tokens_in_range(root, edit.0)?;
+            while let Some((range, _)) = edits.peek() {
+                let last = tokens.last()?;
+                if range.start() >= last.text_range().end() {
+                    break;
+                }
+
+                let next_tokens = tokens_in_range(root, *range)?;
+                let last_end = last.text_range().end();
+                tokens.extend(
+                    next_tokens
+                        .into_iter()
+                        .filter(|token| token.text_range().start() >= last_end),
+                );
+                group.extend(edits.next());
+            }
+
+            let first = tokens.first()?;
+            let last = tokens.last()?;
+            let keep_leading_trivia = group
+                .iter()
+                .all(|(range, _)| range.start() >= first.text_trimmed_range().start());
+            let keep_trailing_trivia = group
+                .iter()
+                .all(|(range, _)| range.end() <= last.text_trimmed_range().end());
+            let start = if keep_leading_trivia {
+                first.text_trimmed_range().start()
+            } else {
+                first.text_range().start()
+            };
+            let end = if keep_trailing_trivia {
+                last.text_trimmed_range().end()
+            } else {
+                last.text_range().end()
+            };
+
+            let mut text = String::new();
+            let mut offset = start;
+            for (range, replacement) in group {
+                text.push_str(&source[TextRange::new(offset, range.start())]);
+                text.push_str(replacement);
+                offset = range.end();
+            }
+            text.push_str(&source[TextRange::new(offset, end)]);
+
+            let mut token = SyntaxToken::new_detached(first.kind(), &text, [], []);
+            if keep_leading_trivia {
+                token = token.with_leading_trivia_pieces(first.leading_trivia().pieces());
+            }
+            if keep_trailing_trivia {
+                token = token.with_trailing_trivia_pieces(last.trailing_trivia().pieces());
+            }
+
+            let mut tokens = tokens.into_iter();
+            mutation.replace_token_discard_trivia(tokens.next()?, token);
+            for token in tokens {
+                mutation.remove_token(token);
+            }
+        }
+
+        Some(mutation)
+    }
+}
+
+/// Returns the consecutive tokens that contain the given range, including
+/// their trivia.
+fn tokens_in_range<L: Language>(
+    root: &SyntaxNode<L>,
+    range: TextRange,
+) -> Option<Vec<SyntaxToken<L>>> {
+    let first = root.token_at_offset(range.start()).right_biased()?;
+    let last = if range.is_empty() {
+        first.clone()
+    } else {
+        root.token_at_offset(range.end() - TextSize::from(1))
+            .right_biased()?
+    };
+
+    let mut tokens = vec![first];
+    while tokens.last()? != &last {
+        let next = tokens.last()?.next_token()?;
+        tokens.push(next);
+    }
 
-    fn supports_js(&self) -> bool;
+    Some(tokens)
 }
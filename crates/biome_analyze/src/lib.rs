@@ -30,7 +30,10 @@ mod visitor;
 // Re-exported for use in the `declare_group` macro
 pub use biome_diagnostics::category_concat;
 
-pub use crate::analyzer_plugin::{AnalyzerPlugin, AnalyzerPluginSlice, AnalyzerPluginVec};
+pub use crate::analyzer_plugin::{
+    AnalyzerPlugin, AnalyzerPluginSlice, AnalyzerPluginVec, PluginAction, PluginSignal,
+    PluginTargetLanguage,
+};
 pub use crate::categories::{
     ActionCategory, OtherActionCategory, RefactorKind, RuleCategories, RuleCategoriesBuilder,
     RuleCategory, SUPPRESSION_INLINE_ACTION_CATEGORY, SUPPRESSION_TOP_LEVEL_ACTION_CATEGORY,
@@ -142,6 +145,20 @@ where
         self.plugins.push(plugin);
     }
 
+    /// Registers the plugins from `plugins` that target the given `language`.
+    pub fn add_plugins_for_language(
+        &mut self,
+        plugins: AnalyzerPluginSlice,
+        language: PluginTargetLanguage,
+    ) {
+        self.plugins.extend(
+            plugins
+                .iter()
+                .filter(|plugin| plugin.language() == language)
+                .cloned(),
+        );
+    }
+
     pub fn run(self, mut ctx: AnalyzerContext<L>) -> Option<Break> {
         let Self {
             phases,
@@ -201,8 +218,8 @@ where
 
         for plugin in plugins {
             let root: AnyParse = ctx.root.syntax().as_send().expect("not a root node").into();
-            let diagnostics = plugin.evaluate(root, ctx.options.file_path.clone());
-            for diagnostic in diagnostics {
+            let signals = plugin.evaluate(root, ctx.options.file_path.clone());
+            for PluginSignal { diagnostic, action } in signals {
                 let name = diagnostic
                     .subcategory
                     .clone()
@@ -261,7 +278,19 @@ where
                         suppression.did_suppress_signal = true;
                     }
                 } else {
-                    let signal = DiagnosticSignal::new(|| diagnostic.clone());
+                    let signal = DiagnosticSignal::new(|| diagnostic.clone()).with_action(|| {
+                        action.as_ref().and_then(|action| {
+                            let mutation = action.to_mutation(ctx.root.syntax())?;
+
+                            Some(AnalyzerAction {
+                                rule_name: None,
+                                category: ActionCategory::QuickFix(format!("plugin.{name}").into()),
+                                applicability: action.applicability,
+                                message: action.message.clone(),
+                                mutation,
+                            })
+                        })
+                    });
                     if let ControlFlow::Break(br) = (emit_signal)(&signal) {
                         return Some(br);
                     }
@@ -50,6 +50,12 @@ impl ServiceBag {
         let svc = self.services.get(&id)?;
         svc.downcast_ref()
     }
+
+    pub fn get_service_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        let id = TypeId::of::<T>();
+        let svc = self.services.get_mut(&id)?;
+        svc.downcast_mut()
+    }
 }
 
 impl FromServices for () {
@@ -1,30 +1,22 @@
 use crate::CliDiagnostic;
+use crate::diagnostics::UnknownRevision;
 use biome_configuration::Configuration;
-use biome_fs::FileSystem;
+use biome_fs::{ChangedFile, FileSystem};
+use biome_git::GitError;
+use biome_rowan::{TextRange, TextSize};
+use camino::{Utf8Path, Utf8PathBuf};
+use std::collections::BTreeMap;
 use std::ffi::OsString;
+use std::io;
 
 pub(crate) fn get_changed_files(
     fs: &dyn FileSystem,
     configuration: &Configuration,
     since: Option<&str>,
 ) -> Result<Vec<OsString>, CliDiagnostic> {
-    let default_branch = configuration
-        .vcs
-        .as_ref()
-        .and_then(|v| v.default_branch.as_ref());
-
-    let base = match (since, default_branch) {
-        (Some(since), Some(_)) => since,
-        (Some(since), None) => since,
-        (None, Some(branch)) => branch,
-        (None, None) => {
-            return Err(CliDiagnostic::incompatible_end_configuration(
-                "The `--changed` flag was set, but Biome couldn't determine the base to compare against. Either set configuration.vcs.defaultBranch or use the --since argument.",
-            ));
-        }
-    };
+    let base = get_base(configuration, since)?;
 
-    let changed_files = fs.get_changed_files(base)?;
+    let changed_files = fs.get_changed_files(base).map_err(git_error)?;
 
     let filtered_changed_files = changed_files.iter().map(OsString::from).collect::<Vec<_>>();
 
@@ -32,9 +24,158 @@ pub(crate) fn get_changed_files(
 }
 
 pub(crate) fn get_staged_files(fs: &dyn FileSystem) -> Result<Vec<OsString>, CliDiagnostic> {
-    let staged_files = fs.get_staged_files()?;
+    let staged_files = fs.get_staged_files().map_err(git_error)?;
 
     let filtered_staged_files = staged_files.iter().map(OsString::from).collect::<Vec<_>>();
 
     Ok(filtered_staged_files)
 }
+
+pub(crate) fn get_changed_lines(
+    fs: &dyn FileSystem,
+    configuration: &Configuration,
+    since: Option<&str>,
+) -> Result<ChangedLines, CliDiagnostic> {
+    let base = get_base(configuration, since)?;
+
+    let changed_files = fs.get_changed_lines(base).map_err(git_error)?;
+
+    Ok(ChangedLines::new(
+        changed_files,
+        fs.working_directory().as_deref(),
+    ))
+}
+
+pub(crate) fn get_staged_lines(fs: &dyn FileSystem) -> Result<ChangedLines, CliDiagnostic> {
+    let staged_files = fs.get_staged_lines().map_err(git_error)?;
+
+    Ok(ChangedLines::new(
+        staged_files,
+        fs.working_directory().as_deref(),
+    ))
+}
+
+/// Returns the base to compare against when `--changed` is used
+fn get_base<'a>(
+    configuration: &'a Configuration,
+    since: Option<&'a str>,
+) -> Result<&'a str, CliDiagnostic> {
+    let default_branch = configuration
+        .vcs
+        .as_ref()
+        .and_then(|v| v.default_branch.as_deref());
+
+    match (since, default_branch) {
+        (Some(since), _) => Ok(since),
+        (None, Some(branch)) => Ok(branch),
+        (None, None) => Err(CliDiagnostic::incompatible_end_configuration(
+            "The `--changed` flag was set, but Biome couldn't determine the base to compare against. Either set configuration.vcs.defaultBranch or use the --since argument.",
+        )),
+    }
+}
+
+/// Converts the errors of the git repository, so that a base that doesn't exist is reported as such
+fn git_error(error: io::Error) -> CliDiagnostic {
+    match error
+        .get_ref()
+        .and_then(|error| error.downcast_ref::<GitError>())
+    {
+        Some(GitError::UnknownRevision(revision)) => {
+            CliDiagnostic::UnknownRevision(UnknownRevision {
+                revision: revision.clone(),
+            })
+        }
+        _ => CliDiagnostic::io_error(error),
+    }
+}
+
+/// The lines that were added or modified in each file, used by `--changed-lines` to
+/// report only the diagnostics and the formatting of the changed code.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ChangedLines {
+    /// The changed files, by absolute path
+    files: BTreeMap<Utf8PathBuf, ChangedFile>,
+}
+
+impl ChangedLines {
+    fn new(changed_files: Vec<ChangedFile>, working_directory: Option<&Utf8Path>) -> Self {
+        let files = changed_files
+            .into_iter()
+            .map(|file| {
+                let path = match working_directory {
+                    Some(working_directory) => working_directory.join(&file.path),
+                    None => Utf8PathBuf::from(&file.path),
+                };
+                (path, file)
+            })
+            .collect();
+
+        Self { files }
+    }
+
+    /// Returns the paths of the changed files, relative to the working directory
+    pub(crate) fn paths(&self) -> Vec<OsString> {
+        self.files
+            .values()
+            .map(|file| OsString::from(&file.path))
+            .collect()
+    }
+
+    /// Returns `true` if the code at `span`, in the file at `path` whose content is
+    /// `content`, is on a line that changed.
+    pub(crate) fn contains_span(&self, path: &Utf8Path, content: &str, span: TextRange) -> bool {
+        let Some(file) = self.files.get(path) else {
+            return false;
+        };
+        let (Some(start), Some(end)) = (
+            line_number(content, span.start()),
+            line_number(content, span.end()),
+        ) else {
+            // The span doesn't belong to this content, so it can't be filtered
+            return true;
+        };
+
+        file.intersects(start..=end)
+    }
+
+    /// Returns the ranges of `content` covered by the changed lines of the file at `path`,
+    /// sorted by their position.
+    ///
+    /// Returns `None` if the file didn't change.
+    pub(crate) fn text_ranges(&self, path: &Utf8Path, content: &str) -> Option<Vec<TextRange>> {
+        let file = self.files.get(path)?;
+
+        let mut line_starts = vec![0];
+        line_starts.extend(content.match_indices('\n').map(|(index, _)| index + 1));
+        let line_end = |line: usize| {
+            line_starts
+                .get(line)
+                .map_or(content.len(), |next_line_start| next_line_start - 1)
+        };
+
+        let ranges = file
+            .lines
+            .iter()
+            .filter_map(|lines| {
+                let start = *line_starts.get((*lines.start() as usize).checked_sub(1)?)?;
+                let end = line_end(*lines.end() as usize).max(start);
+                Some(TextRange::new(
+                    TextSize::try_from(start).ok()?,
+                    TextSize::try_from(end).ok()?,
+                ))
+            })
+            .collect();
+
+        Some(ranges)
+    }
+}
+
+/// Returns the 1-based line of `offset` in `content`
+fn line_number(content: &str, offset: TextSize) -> Option<u32> {
+    let lines = content
+        .get(..usize::from(offset))?
+        .bytes()
+        .filter(|byte| *byte == b'\n')
+        .count();
+    u32::try_from(lines + 1).ok()
+}
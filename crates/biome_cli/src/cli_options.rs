@@ -56,7 +56,7 @@ pub struct CliOptions {
     /// Allows to change how diagnostics and summary are reported.
     #[bpaf(
         long("reporter"),
-        argument("json|json-pretty|github|junit|summary|gitlab"),
+        argument("json|json-pretty|github|junit|summary|gitlab|sarif|checkstyle"),
         fallback(CliReporter::default())
     )]
     pub reporter: CliReporter,
@@ -149,6 +149,10 @@ pub enum CliReporter {
     Summary,
     /// Reports linter diagnostics using the [GitLab Code Quality report](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool).
     GitLab,
+    /// Reports diagnostics using the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) format.
+    Sarif,
+    /// Diagnostics are printed in the Checkstyle XML format
+    Checkstyle,
 }
 
 impl CliReporter {
@@ -168,6 +172,8 @@ impl FromStr for CliReporter {
             "github" => Ok(Self::GitHub),
             "junit" => Ok(Self::Junit),
             "gitlab" => Ok(Self::GitLab),
+            "sarif" => Ok(Self::Sarif),
+            "checkstyle" => Ok(Self::Checkstyle),
             _ => Err(format!(
                 "value {s:?} is not valid for the --reporter argument"
             )),
@@ -185,6 +191,8 @@ impl Display for CliReporter {
             Self::GitHub => f.write_str("github"),
             Self::Junit => f.write_str("junit"),
             Self::GitLab => f.write_str("gitlab"),
+            Self::Sarif => f.write_str("sarif"),
+            Self::Checkstyle => f.write_str("checkstyle"),
         }
     }
 }
@@ -1,6 +1,11 @@
 use super::{FixFileModeOptions, LoadEditorConfig, determine_fix_file_mode};
+use crate::changed::ChangedLines;
 use crate::cli_options::CliOptions;
-use crate::commands::{CommandRunner, get_files_to_process_with_cli_options};
+use crate::commands::{
+    CommandRunner, get_changed_lines_with_cli_options, get_files_to_process_with_cli_options,
+};
+use crate::execute::baseline::BaselineMode;
+use crate::execute::cache::resolve_cache_location;
 use crate::{CliDiagnostic, Execution, TraversalMode};
 use biome_configuration::analyzer::LinterEnabled;
 use biome_configuration::analyzer::assist::{AssistConfiguration, AssistEnabled};
@@ -10,6 +15,7 @@ use biome_console::Console;
 use biome_deserialize::Merge;
 use biome_fs::FileSystem;
 use biome_service::{Workspace, WorkspaceError, configuration::LoadedConfiguration};
+use camino::Utf8PathBuf;
 use std::ffi::OsString;
 
 pub(crate) struct CheckCommandPayload {
@@ -26,6 +32,11 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
+    pub(crate) cache: bool,
+    pub(crate) cache_location: Option<Utf8PathBuf>,
+    pub(crate) baseline: Option<Utf8PathBuf>,
+    pub(crate) write_baseline: Option<Utf8PathBuf>,
 }
 
 impl LoadEditorConfig for CheckCommandPayload {
@@ -109,6 +120,21 @@ impl CommandRunner for CheckCommandPayload {
         Ok(paths)
     }
 
+    fn get_changed_lines(
+        &self,
+        fs: &dyn FileSystem,
+        configuration: &Configuration,
+    ) -> Result<Option<ChangedLines>, CliDiagnostic> {
+        get_changed_lines_with_cli_options(
+            self.since.as_deref(),
+            self.changed,
+            self.staged,
+            self.changed_lines,
+            fs,
+            configuration,
+        )
+    }
+
     fn get_stdin_file_path(&self) -> Option<&str> {
         self.stdin_file_path.as_deref()
     }
@@ -121,7 +147,7 @@ impl CommandRunner for CheckCommandPayload {
         &self,
         cli_options: &CliOptions,
         console: &mut dyn Console,
-        _workspace: &dyn Workspace,
+        workspace: &dyn Workspace,
     ) -> Result<Execution, CliDiagnostic> {
         let fix_file_mode = determine_fix_file_mode(FixFileModeOptions {
             write: self.write,
@@ -137,7 +163,48 @@ impl CommandRunner for CheckCommandPayload {
             vcs_targeted: (self.staged, self.changed).into(),
             enforce_assist: self.enforce_assist,
             skip_parse_errors: cli_options.skip_parse_errors,
+            // Fixes change the files, so their results can't be reused
+            cache: if fix_file_mode.is_none() {
+                resolve_cache_location(self.cache, self.cache_location.as_deref(), workspace.fs())
+            } else {
+                None
+            },
+            baseline: BaselineMode::from_arguments(
+                self.baseline.as_deref(),
+                self.write_baseline.as_deref(),
+                workspace.fs(),
+            ),
         })
         .set_report(cli_options))
     }
+
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.baseline.is_some() && self.write_baseline.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "baseline",
+                "write-baseline",
+            ));
+        }
+        // The baseline would only record the diagnostics of the changed files, and
+        // drop the entries of the other ones
+        if self.write_baseline.is_some() && self.changed_lines {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "write-baseline",
+                "changed-lines",
+            ));
+        }
+        if self.write_baseline.is_some() && self.changed {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "write-baseline",
+                "changed",
+            ));
+        }
+        if self.write_baseline.is_some() && self.staged {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "write-baseline",
+                "staged",
+            ));
+        }
+        Ok(())
+    }
 }
@@ -1,6 +1,8 @@
-use crate::changed::get_changed_files;
+use crate::changed::{ChangedLines, get_changed_files};
 use crate::cli_options::CliOptions;
-use crate::commands::{CommandRunner, LoadEditorConfig};
+use crate::commands::{CommandRunner, LoadEditorConfig, get_changed_lines_with_cli_options};
+use crate::execute::baseline::BaselineMode;
+use crate::execute::cache::resolve_cache_location;
 use crate::{CliDiagnostic, Execution};
 use biome_configuration::analyzer::LinterEnabled;
 use biome_configuration::analyzer::assist::{AssistConfiguration, AssistEnabled};
@@ -11,6 +13,7 @@ use biome_deserialize::Merge;
 use biome_fs::FileSystem;
 use biome_service::configuration::LoadedConfiguration;
 use biome_service::{Workspace, WorkspaceError};
+use camino::Utf8PathBuf;
 use std::ffi::OsString;
 
 pub(crate) struct CiCommandPayload {
@@ -22,6 +25,11 @@ pub(crate) struct CiCommandPayload {
     pub(crate) configuration: Option<Configuration>,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
+    pub(crate) cache: bool,
+    pub(crate) cache_location: Option<Utf8PathBuf>,
+    pub(crate) baseline: Option<Utf8PathBuf>,
+    pub(crate) write_baseline: Option<Utf8PathBuf>,
 }
 
 impl LoadEditorConfig for CiCommandPayload {
@@ -100,6 +108,21 @@ impl CommandRunner for CiCommandPayload {
         }
     }
 
+    fn get_changed_lines(
+        &self,
+        fs: &dyn FileSystem,
+        configuration: &Configuration,
+    ) -> Result<Option<ChangedLines>, CliDiagnostic> {
+        get_changed_lines_with_cli_options(
+            self.since.as_deref(),
+            self.changed,
+            false,
+            self.changed_lines,
+            fs,
+            configuration,
+        )
+    }
+
     fn get_stdin_file_path(&self) -> Option<&str> {
         None
     }
@@ -112,12 +135,18 @@ impl CommandRunner for CiCommandPayload {
         &self,
         cli_options: &CliOptions,
         _console: &mut dyn Console,
-        _workspace: &dyn Workspace,
+        workspace: &dyn Workspace,
     ) -> Result<Execution, CliDiagnostic> {
         Ok(Execution::new_ci(
             (false, self.changed).into(),
             self.enforce_assist,
             cli_options.skip_parse_errors,
+            resolve_cache_location(self.cache, self.cache_location.as_deref(), workspace.fs()),
+            BaselineMode::from_arguments(
+                self.baseline.as_deref(),
+                self.write_baseline.as_deref(),
+                workspace.fs(),
+            ),
         )
         .set_report(cli_options))
     }
@@ -134,6 +163,26 @@ impl CommandRunner for CiCommandPayload {
         if self.since.is_some() && !self.changed {
             return Err(CliDiagnostic::incompatible_arguments("since", "changed"));
         }
+        if self.baseline.is_some() && self.write_baseline.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "baseline",
+                "write-baseline",
+            ));
+        }
+        // The baseline would only record the diagnostics of the changed files, and
+        // drop the entries of the other ones
+        if self.write_baseline.is_some() && self.changed_lines {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "write-baseline",
+                "changed-lines",
+            ));
+        }
+        if self.write_baseline.is_some() && self.changed {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "write-baseline",
+                "changed",
+            ));
+        }
         Ok(())
     }
 }
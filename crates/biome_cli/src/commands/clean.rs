@@ -1,15 +1,41 @@
 use crate::commands::daemon::default_biome_log_path;
+use crate::execute::cache::{CACHE_FILE_PREFIX, is_cache_file, resolve_cache_location};
 use crate::{CliDiagnostic, CliSession};
 use biome_flags::biome_env;
+use biome_fs::ensure_cache_dir;
 use camino::Utf8PathBuf;
-use std::fs::{create_dir, remove_dir_all};
+use std::fs::{create_dir, read_dir, remove_dir_all, remove_file};
 
 /// Runs the clean command
-pub fn clean(_cli_session: CliSession) -> Result<(), CliDiagnostic> {
+pub fn clean(
+    cli_session: CliSession,
+    cache_location: Option<Utf8PathBuf>,
+) -> Result<(), CliDiagnostic> {
     let logs_path = biome_env()
         .biome_log_path
         .value()
         .map_or(default_biome_log_path(), Utf8PathBuf::from);
     remove_dir_all(logs_path.clone()).and_then(|_| create_dir(logs_path))?;
+
+    // Purge the result caches created by `biome check --cache` and `biome ci --cache`
+    for entry in read_dir(ensure_cache_dir())? {
+        let path = entry?.path();
+        let is_result_cache = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with(CACHE_FILE_PREFIX) && name.ends_with(".json"));
+        if is_result_cache && path.is_file() {
+            remove_file(path)?;
+        }
+    }
+
+    // The cache written with `--cache-location` is only removed if it's still a cache,
+    // in case the path now points to another file
+    let fs = cli_session.app.workspace.fs();
+    if let Some(location) = resolve_cache_location(false, cache_location.as_deref(), fs) {
+        if is_cache_file(fs, &location) {
+            remove_file(location)?;
+        }
+    }
     Ok(())
 }
@@ -1,12 +1,18 @@
+use crate::changed::ChangedLines;
 use crate::cli_options::CliOptions;
-use crate::commands::{CommandRunner, LoadEditorConfig, get_files_to_process_with_cli_options};
+use crate::commands::{
+    CommandRunner, LoadEditorConfig, get_changed_lines_with_cli_options,
+    get_files_to_process_with_cli_options,
+};
 use crate::{CliDiagnostic, Execution, TraversalMode};
 use biome_configuration::css::CssFormatterConfiguration;
 use biome_configuration::graphql::GraphqlFormatterConfiguration;
 use biome_configuration::html::HtmlFormatterConfiguration;
 use biome_configuration::javascript::JsFormatterConfiguration;
 use biome_configuration::json::JsonFormatterConfiguration;
+use biome_configuration::markdown::MarkdownFormatterConfiguration;
 use biome_configuration::vcs::VcsConfiguration;
+use biome_configuration::yaml::YamlFormatterConfiguration;
 use biome_configuration::{Configuration, FilesConfiguration, FormatterConfiguration};
 use biome_console::Console;
 use biome_deserialize::Merge;
@@ -21,6 +27,8 @@ pub(crate) struct FormatCommandPayload {
     pub(crate) css_formatter: Option<CssFormatterConfiguration>,
     pub(crate) graphql_formatter: Option<GraphqlFormatterConfiguration>,
     pub(crate) html_formatter: Option<HtmlFormatterConfiguration>,
+    pub(crate) markdown_formatter: Option<MarkdownFormatterConfiguration>,
+    pub(crate) yaml_formatter: Option<YamlFormatterConfiguration>,
     pub(crate) formatter_configuration: Option<FormatterConfiguration>,
     pub(crate) vcs_configuration: Option<VcsConfiguration>,
     pub(crate) files_configuration: Option<FilesConfiguration>,
@@ -31,6 +39,7 @@ pub(crate) struct FormatCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
 }
 
 impl LoadEditorConfig for FormatCommandPayload {
@@ -85,6 +94,16 @@ impl CommandRunner for FormatCommandPayload {
             let html = configuration.html.get_or_insert_with(Default::default);
             html.formatter.merge_with(self.html_formatter.clone());
         }
+        if self.markdown_formatter.is_some() {
+            let markdown = configuration.markdown.get_or_insert_with(Default::default);
+            markdown
+                .formatter
+                .merge_with(self.markdown_formatter.clone());
+        }
+        if self.yaml_formatter.is_some() {
+            let yaml = configuration.yaml.get_or_insert_with(Default::default);
+            yaml.formatter.merge_with(self.yaml_formatter.clone());
+        }
 
         if self.javascript_formatter.is_some() {
             let javascript = configuration
@@ -124,6 +143,21 @@ impl CommandRunner for FormatCommandPayload {
         Ok(paths)
     }
 
+    fn get_changed_lines(
+        &self,
+        fs: &dyn FileSystem,
+        configuration: &Configuration,
+    ) -> Result<Option<ChangedLines>, CliDiagnostic> {
+        get_changed_lines_with_cli_options(
+            self.since.as_deref(),
+            self.changed,
+            self.staged,
+            self.changed_lines,
+            fs,
+            configuration,
+        )
+    }
+
     fn get_stdin_file_path(&self) -> Option<&str> {
         self.stdin_file_path.as_deref()
     }
@@ -1,10 +1,15 @@
 use super::{FixFileModeOptions, determine_fix_file_mode};
+use crate::changed::ChangedLines;
 use crate::cli_options::CliOptions;
-use crate::commands::{CommandRunner, get_files_to_process_with_cli_options};
+use crate::commands::{
+    CommandRunner, get_changed_lines_with_cli_options, get_files_to_process_with_cli_options,
+};
+use crate::execute::baseline::BaselineMode;
 use crate::{CliDiagnostic, Execution, TraversalMode};
 use biome_configuration::analyzer::RuleSelector;
 use biome_configuration::css::CssLinterConfiguration;
 use biome_configuration::graphql::GraphqlLinterConfiguration;
+use biome_configuration::html::HtmlLinterConfiguration;
 use biome_configuration::javascript::JsLinterConfiguration;
 use biome_configuration::json::JsonLinterConfiguration;
 use biome_configuration::vcs::VcsConfiguration;
@@ -14,6 +19,7 @@ use biome_deserialize::Merge;
 use biome_fs::FileSystem;
 use biome_service::configuration::LoadedConfiguration;
 use biome_service::{Workspace, WorkspaceError};
+use camino::Utf8PathBuf;
 use std::ffi::OsString;
 
 pub(crate) struct LintCommandPayload {
@@ -32,10 +38,14 @@ pub(crate) struct LintCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) changed_lines: bool,
     pub(crate) javascript_linter: Option<JsLinterConfiguration>,
     pub(crate) json_linter: Option<JsonLinterConfiguration>,
     pub(crate) css_linter: Option<CssLinterConfiguration>,
     pub(crate) graphql_linter: Option<GraphqlLinterConfiguration>,
+    pub(crate) html_linter: Option<HtmlLinterConfiguration>,
+    pub(crate) baseline: Option<Utf8PathBuf>,
+    pub(crate) write_baseline: Option<Utf8PathBuf>,
 }
 
 impl CommandRunner for LintCommandPayload {
@@ -82,6 +92,10 @@ impl CommandRunner for LintCommandPayload {
                 .get_or_insert_with(Default::default);
             graphql.linter.merge_with(self.graphql_linter.clone());
         }
+        if self.html_linter.is_some() {
+            let html = fs_configuration.html.get_or_insert_with(Default::default);
+            html.linter.merge_with(self.html_linter.clone());
+        }
         if self.javascript_linter.is_some() {
             let javascript = fs_configuration
                 .javascript
@@ -113,6 +127,21 @@ impl CommandRunner for LintCommandPayload {
         Ok(paths)
     }
 
+    fn get_changed_lines(
+        &self,
+        fs: &dyn FileSystem,
+        configuration: &Configuration,
+    ) -> Result<Option<ChangedLines>, CliDiagnostic> {
+        get_changed_lines_with_cli_options(
+            self.since.as_deref(),
+            self.changed,
+            self.staged,
+            self.changed_lines,
+            fs,
+            configuration,
+        )
+    }
+
     fn get_stdin_file_path(&self) -> Option<&str> {
         self.stdin_file_path.as_deref()
     }
@@ -125,7 +154,7 @@ impl CommandRunner for LintCommandPayload {
         &self,
         cli_options: &CliOptions,
         console: &mut dyn Console,
-        _workspace: &dyn Workspace,
+        workspace: &dyn Workspace,
     ) -> Result<Execution, CliDiagnostic> {
         let fix_file_mode = determine_fix_file_mode(FixFileModeOptions {
             write: self.write,
@@ -143,7 +172,42 @@ impl CommandRunner for LintCommandPayload {
             suppress: self.suppress,
             suppression_reason: self.suppression_reason.clone(),
             skip_parse_errors: cli_options.skip_parse_errors,
+            baseline: BaselineMode::from_arguments(
+                self.baseline.as_deref(),
+                self.write_baseline.as_deref(),
+                workspace.fs(),
+            ),
         })
         .set_report(cli_options))
     }
+
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.baseline.is_some() && self.write_baseline.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "baseline",
+                "write-baseline",
+            ));
+        }
+        // The baseline would only record the diagnostics of the changed files, and
+        // drop the entries of the other ones
+        if self.write_baseline.is_some() && self.changed_lines {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "write-baseline",
+                "changed-lines",
+            ));
+        }
+        if self.write_baseline.is_some() && self.changed {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "write-baseline",
+                "changed",
+            ));
+        }
+        if self.write_baseline.is_some() && self.staged {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "write-baseline",
+                "staged",
+            ));
+        }
+        Ok(())
+    }
 }
@@ -1,7 +1,10 @@
-use crate::changed::{get_changed_files, get_staged_files};
+use crate::changed::{
+    ChangedLines, get_changed_files, get_changed_lines, get_staged_files, get_staged_lines,
+};
 use crate::cli_options::{CliOptions, CliReporter, ColorsArg, cli_options};
 use crate::commands::scan_kind::get_forced_scan_kind;
 use crate::execute::Stdin;
+use crate::execute::cache::CacheFingerprint;
 use crate::logging::LoggingKind;
 use crate::{
     CliDiagnostic, CliSession, Execution, LoggingLevel, TraversalMode, VERSION, execute_mode,
@@ -12,10 +15,18 @@ use biome_configuration::analyzer::{LinterEnabled, RuleSelector};
 use biome_configuration::css::{CssFormatterConfiguration, CssLinterConfiguration};
 use biome_configuration::formatter::FormatterEnabled;
 use biome_configuration::graphql::{GraphqlFormatterConfiguration, GraphqlLinterConfiguration};
-use biome_configuration::html::{HtmlFormatterConfiguration, html_formatter_configuration};
+use biome_configuration::html::{
+    HtmlFormatterConfiguration, HtmlLinterConfiguration, html_formatter_configuration,
+    html_linter_configuration,
+};
 use biome_configuration::javascript::{JsFormatterConfiguration, JsLinterConfiguration};
 use biome_configuration::json::{JsonFormatterConfiguration, JsonLinterConfiguration};
+use biome_configuration::markdown::{
+    MarkdownFormatterConfiguration, markdown_formatter_configuration,
+};
+use biome_configuration::plugins::PluginConfiguration;
 use biome_configuration::vcs::VcsConfiguration;
+use biome_configuration::yaml::{YamlFormatterConfiguration, yaml_formatter_configuration};
 use biome_configuration::{BiomeDiagnostic, Configuration};
 use biome_configuration::{
     FilesConfiguration, FormatterConfiguration, LinterConfiguration, configuration,
@@ -39,7 +50,7 @@ use biome_service::workspace::{
 };
 use biome_service::{Workspace, WorkspaceError};
 use bpaf::Bpaf;
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 use std::ffi::OsString;
 use std::time::Duration;
 use tracing::info;
@@ -174,6 +185,37 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Use this together with `--changed` or `--staged` to report only the diagnostics and the
+        /// formatting differences found on the lines that were added or modified, instead of the whole files.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+
+        /// Caches the results of the command, and reuses them for files that didn't change
+        /// since the previous execution. The cache is ignored when fixes are applied.
+        ///
+        /// The cache is stored inside the cache directory of Biome, and it can be removed with `biome clean`.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
+        /// The path of the file where the results are cached. Implies `--cache`.
+        ///
+        /// The file can be removed with `biome clean --cache-location`.
+        #[bpaf(long("cache-location"), argument("PATH"), optional, hide_usage)]
+        cache_location: Option<Utf8PathBuf>,
+
+        /// Reports only the diagnostics that aren't recorded in the given baseline file, which is
+        /// written with `--write-baseline`.
+        ///
+        /// The entries of the diagnostics that were fixed are removed from the baseline.
+        #[bpaf(long("baseline"), argument("PATH"), optional)]
+        baseline: Option<Utf8PathBuf>,
+
+        /// Records all the diagnostics in the given baseline file, instead of reporting them.
+        ///
+        /// Later executions with `--baseline` only report the diagnostics that aren't in the file.
+        #[bpaf(long("write-baseline"), argument("PATH"), optional)]
+        write_baseline: Option<Utf8PathBuf>,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -222,6 +264,9 @@ pub enum BiomeCommand {
         #[bpaf(external(graphql_linter_configuration), optional, hide_usage, hide)]
         graphql_linter: Option<GraphqlLinterConfiguration>,
 
+        #[bpaf(external(html_linter_configuration), optional, hide_usage, hide)]
+        html_linter: Option<HtmlLinterConfiguration>,
+
         #[bpaf(external, hide_usage)]
         cli_options: CliOptions,
 
@@ -262,6 +307,21 @@ pub enum BiomeCommand {
         /// flag and the `defaultBranch` is not set in your biome.json
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
+        /// Use this together with `--changed` or `--staged` to report only the diagnostics found on
+        /// the lines that were added or modified, instead of the whole files.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+        /// Reports only the diagnostics that aren't recorded in the given baseline file, which is
+        /// written with `--write-baseline`.
+        ///
+        /// The entries of the diagnostics that were fixed are removed from the baseline.
+        #[bpaf(long("baseline"), argument("PATH"), optional)]
+        baseline: Option<Utf8PathBuf>,
+        /// Records all the diagnostics in the given baseline file, instead of reporting them.
+        ///
+        /// Later executions with `--baseline` only report the diagnostics that aren't in the file.
+        #[bpaf(long("write-baseline"), argument("PATH"), optional)]
+        write_baseline: Option<Utf8PathBuf>,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -287,6 +347,12 @@ pub enum BiomeCommand {
         #[bpaf(external(html_formatter_configuration), optional, hide_usage, hide)]
         html_formatter: Option<HtmlFormatterConfiguration>,
 
+        #[bpaf(external(markdown_formatter_configuration), optional, hide_usage, hide)]
+        markdown_formatter: Option<MarkdownFormatterConfiguration>,
+
+        #[bpaf(external(yaml_formatter_configuration), optional, hide_usage, hide)]
+        yaml_formatter: Option<YamlFormatterConfiguration>,
+
         #[bpaf(external(vcs_configuration), optional, hide_usage)]
         vcs_configuration: Option<VcsConfiguration>,
 
@@ -329,6 +395,11 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Use this together with `--changed` or `--staged` to format only the lines that were
+        /// added or modified, instead of the whole files.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+
         /// Single file, single path or list of paths.
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -368,6 +439,11 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Use this together with `--changed` to report only the diagnostics and the formatting
+        /// differences found on the lines that were added or modified, instead of the whole files.
+        #[bpaf(long("changed-lines"), switch)]
+        changed_lines: bool,
+
         /// The number of threads to use. This is useful when running the CLI in environments
         /// with limited resource, for example CI.
         #[bpaf(
@@ -379,6 +455,32 @@ pub enum BiomeCommand {
         )]
         threads: Option<usize>,
 
+        /// Caches the results of the command, and reuses them for files that didn't change
+        /// since the previous execution. The cache is ignored when fixes are applied.
+        ///
+        /// The cache is stored inside the cache directory of Biome, and it can be removed with `biome clean`.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
+        /// The path of the file where the results are cached. Implies `--cache`.
+        ///
+        /// The file can be removed with `biome clean --cache-location`.
+        #[bpaf(long("cache-location"), argument("PATH"), optional, hide_usage)]
+        cache_location: Option<Utf8PathBuf>,
+
+        /// Reports only the diagnostics that aren't recorded in the given baseline file, which is
+        /// written with `--write-baseline`.
+        ///
+        /// The entries of the diagnostics that were fixed are removed from the baseline.
+        #[bpaf(long("baseline"), argument("PATH"), optional)]
+        baseline: Option<Utf8PathBuf>,
+
+        /// Records all the diagnostics in the given baseline file, instead of reporting them.
+        ///
+        /// Later executions with `--baseline` only report the diagnostics that aren't in the file.
+        #[bpaf(long("write-baseline"), argument("PATH"), optional)]
+        write_baseline: Option<Utf8PathBuf>,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -446,6 +548,10 @@ pub enum BiomeCommand {
     /// ```shell
     /// biome search '`console.log($message)`' # find all `console.log` invocations
     /// ```
+    ///
+    /// ```shell
+    /// biome search --rewrite '`foo($a)` => `bar($a)`' # show how `foo` calls would be rewritten
+    /// ```
     #[bpaf(command)]
     Search {
         #[bpaf(external, hide_usage)]
@@ -481,9 +587,18 @@ pub enum BiomeCommand {
         #[bpaf(long("language"), short('l'))]
         language: Option<GritTargetLanguage>,
 
-        /// The GritQL pattern to search for.
+        /// Applies the rewrites of the pattern, and prints the difference
+        /// between the original and the rewritten files.
         ///
-        /// Note that the search command (currently) does not support rewrites.
+        /// The rewritten files are formatted using the configured formatter.
+        #[bpaf(long("rewrite"), switch)]
+        rewrite: bool,
+
+        /// Writes the rewritten files to disk. It requires `--rewrite`.
+        #[bpaf(long("write"), switch)]
+        write: bool,
+
+        /// The GritQL pattern to search for.
         #[bpaf(positional("PATTERN"))]
         pattern: String,
 
@@ -511,8 +626,12 @@ pub enum BiomeCommand {
     },
 
     #[bpaf(command)]
-    /// Cleans the logs emitted by the daemon.
-    Clean,
+    /// Cleans the logs emitted by the daemon, and the result caches of `biome check` and `biome ci`.
+    Clean {
+        /// The path of the file where the results were cached with `--cache-location`.
+        #[bpaf(long("cache-location"), argument("PATH"), optional, hide_usage)]
+        cache_location: Option<Utf8PathBuf>,
+    },
 
     #[bpaf(command("__run_server"), hide)]
     RunServer {
@@ -732,6 +851,33 @@ fn get_files_to_process_with_cli_options(
     }
 }
 
+/// Returns the lines changed in each file when `--changed-lines` is used, validating it
+/// together with the other VCS arguments.
+fn get_changed_lines_with_cli_options(
+    since: Option<&str>,
+    changed: bool,
+    staged: bool,
+    changed_lines: bool,
+    fs: &dyn FileSystem,
+    configuration: &Configuration,
+) -> Result<Option<ChangedLines>, CliDiagnostic> {
+    if !changed_lines {
+        return Ok(None);
+    }
+    if since.is_some() && staged {
+        return Err(CliDiagnostic::incompatible_arguments("since", "staged"));
+    }
+
+    match (changed, staged) {
+        (true, true) => Err(CliDiagnostic::incompatible_arguments("changed", "staged")),
+        (true, false) => Ok(Some(get_changed_lines(fs, configuration, since)?)),
+        (false, true) => Ok(Some(get_staged_lines(fs)?)),
+        (false, false) => Err(CliDiagnostic::incompatible_end_configuration(
+            "The `--changed-lines` flag was set, but Biome doesn't know which changes to consider. Use it together with the --changed or the --staged argument.",
+        )),
+    }
+}
+
 /// Holds the options to determine the fix file mode.
 pub(crate) struct FixFileModeOptions {
     write: bool,
@@ -810,6 +956,7 @@ fn check_fix_incompatible_arguments(options: FixFileModeOptions) -> Result<(), C
 ///
 /// Optional methods:
 /// - [CommandRunner::check_incompatible_arguments]
+/// - [CommandRunner::get_changed_lines]
 pub(crate) trait CommandRunner: Sized {
     const COMMAND_NAME: &'static str;
 
@@ -872,13 +1019,20 @@ pub(crate) trait CommandRunner: Sized {
         );
         let configuration_dir_path = loaded_configuration.directory_path.clone();
         let configuration = self.merge_configuration(loaded_configuration, fs, console)?;
-        let paths = self.get_files_to_process(fs, &configuration)?;
+        let changed_lines = self.get_changed_lines(fs, &configuration)?;
+        let paths = match &changed_lines {
+            Some(changed_lines) => changed_lines.paths(),
+            None => self.get_files_to_process(fs, &configuration)?,
+        };
         let project_path = fs
             .working_directory()
             .map(BiomePath::from)
             .unwrap_or_default();
 
-        let execution = self.get_execution(cli_options, console, workspace)?;
+        let mut execution = self.get_execution(cli_options, console, workspace)?;
+        if let Some(changed_lines) = changed_lines {
+            execution.set_changed_lines(changed_lines);
+        }
 
         let params = if let TraversalMode::Lint { only, skip, .. } = execution.traversal_mode() {
             OpenProjectParams {
@@ -913,15 +1067,26 @@ pub(crate) trait CommandRunner: Sized {
             }
         });
 
+        // When the user provides the path to the configuration, we can't use its directory because
+        // it might be outside the project, so we need to use the current project directory.
+        let workspace_directory = if is_configuration_from_user {
+            Some(project_path.clone())
+        } else {
+            configuration_dir_path.map(BiomePath::from)
+        };
+
+        let mut cache_fingerprint = execution.cache_location().map(|_| {
+            compute_cache_fingerprint(
+                fs,
+                &execution,
+                &configuration,
+                workspace_directory.as_deref().unwrap_or(&project_path),
+            )
+        });
+
         let result = workspace.update_settings(UpdateSettingsParams {
             project_key: open_project_result.project_key,
-            // When the user provides the path to the configuration, we can't use its directory because
-            // it might be outside the project, so we need to use the current project directory.
-            workspace_directory: if is_configuration_from_user {
-                Some(project_path.clone())
-            } else {
-                configuration_dir_path.map(BiomePath::from)
-            },
+            workspace_directory,
             configuration,
         })?;
         if self.should_validate_configuration_diagnostics() {
@@ -948,6 +1113,15 @@ pub(crate) trait CommandRunner: Sized {
             )?;
         }
 
+        if let Some(fingerprint) = cache_fingerprint.as_mut() {
+            // Nested configuration files affect the settings of the files they apply to
+            for path in &result.configuration_files {
+                fingerprint.write(path.as_str());
+                fingerprint.write(fs.read_file_from_path(path).ok());
+            }
+            execution.set_cache_fingerprint(fingerprint.finish());
+        }
+
         Ok(ConfiguredWorkspace {
             execution,
             paths,
@@ -1020,12 +1194,52 @@ pub(crate) trait CommandRunner: Sized {
         Ok(())
     }
 
+    /// Optional method that can be implemented to only handle the lines that changed.
+    ///
+    /// When it returns the changed lines, the files that contain them are handled instead of
+    /// the ones returned by [CommandRunner::get_files_to_process].
+    fn get_changed_lines(
+        &self,
+        _fs: &dyn FileSystem,
+        _configuration: &Configuration,
+    ) -> Result<Option<ChangedLines>, CliDiagnostic> {
+        Ok(None)
+    }
+
     /// Checks whether the configuration has errors.
     fn should_validate_configuration_diagnostics(&self) -> bool {
         true
     }
 }
 
+/// Computes the fingerprint of the result cache, from the options of the execution, the
+/// configuration and the plugins it enables.
+fn compute_cache_fingerprint(
+    fs: &dyn FileSystem,
+    execution: &Execution,
+    configuration: &Configuration,
+    workspace_directory: &Utf8Path,
+) -> CacheFingerprint {
+    let mut fingerprint = CacheFingerprint::new(&execution.traversal_mode().to_string());
+    fingerprint.write(execution.should_enforce_assist());
+    fingerprint.write(execution.should_skip_parse_errors());
+    fingerprint.write(serde_json::to_string(configuration).ok());
+    for plugin in configuration
+        .plugins
+        .iter()
+        .flat_map(|plugins| plugins.iter())
+    {
+        match plugin {
+            PluginConfiguration::Path(plugin_path) => {
+                let plugin_path = workspace_directory.join(plugin_path);
+                fingerprint.write(plugin_path.as_str());
+                fingerprint.write(fs.read_file_from_path(&plugin_path).ok());
+            }
+        }
+    }
+    fingerprint
+}
+
 pub(crate) struct ConfiguredWorkspace {
     /// Execution context
     pub execution: Execution,
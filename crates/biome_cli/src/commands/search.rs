@@ -17,6 +17,8 @@ pub(crate) struct SearchCommandPayload {
     pub(crate) paths: Vec<OsString>,
     pub(crate) pattern: String,
     pub(crate) language: Option<GritTargetLanguage>,
+    pub(crate) rewrite: bool,
+    pub(crate) write: bool,
     pub(crate) stdin_file_path: Option<String>,
     pub(crate) vcs_configuration: Option<VcsConfiguration>,
 }
@@ -54,7 +56,7 @@ impl CommandRunner for SearchCommandPayload {
     }
 
     fn should_write(&self) -> bool {
-        false
+        self.write
     }
 
     fn get_execution(
@@ -72,8 +74,18 @@ impl CommandRunner for SearchCommandPayload {
         Ok(Execution::new(TraversalMode::Search {
             pattern,
             language: self.language.clone(),
+            rewrite: self.rewrite,
+            write: self.should_write(),
             stdin: self.get_stdin(console)?,
         })
         .set_report(cli_options))
     }
+
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.write && !self.rewrite {
+            return Err(CliDiagnostic::missing_argument("--rewrite", "search"));
+        }
+
+        Ok(())
+    }
 }
@@ -60,6 +60,10 @@ pub enum CliDiagnostic {
     Report(ReportDiagnostic),
     /// Emitted when there's an error emitted when using stdin mode
     Stdin(StdinDiagnostic),
+    /// Returned when the baseline passed with `--baseline` can't be used
+    InvalidBaseline(InvalidBaseline),
+    /// Returned when the base passed with `--since` or `vcs.defaultBranch` doesn't exist
+    UnknownRevision(UnknownRevision),
 }
 
 #[derive(Debug, Diagnostic)]
@@ -267,6 +271,34 @@ pub struct DeprecatedArgument {
     pub message: MessageAndDescription,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "flags/invalid",
+    severity = Error,
+    message(
+        description = "The baseline {path} can't be used: {reason}",
+        message("The baseline "<Emphasis>{self.path}</Emphasis>" can't be used: "{{&self.reason}})
+    )
+)]
+pub struct InvalidBaseline {
+    pub path: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "flags/invalid",
+    severity = Error,
+    message(
+        description = "The revision {revision} doesn't exist in the repository.",
+        message("The revision "<Emphasis>{self.revision}</Emphasis>" doesn't exist in the repository.")
+    ),
+    advice = "Use the --since argument or the configuration vcs.defaultBranch to compare against an existing branch, tag or commit.",
+)]
+pub struct UnknownRevision {
+    pub revision: String,
+}
+
 #[derive(Debug, Diagnostic)]
 pub enum ReportDiagnostic {
     /// Emitted when trying to serialise the report
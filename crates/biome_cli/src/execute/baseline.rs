@@ -0,0 +1,311 @@
+//! Baseline of the diagnostics emitted by `biome check`, `biome lint` and `biome ci`.
+//!
+//! The baseline is a file that records the diagnostics that existed when it was written,
+//! so that later executions only report the new ones. Each diagnostic is identified by a
+//! fingerprint computed from:
+//! - the category of the diagnostic, for example `lint/suspicious/noDebugger`;
+//! - the path of the file, relative to the working directory;
+//! - a hash of the lines of code spanned by the diagnostic, without their leading and
+//!   trailing whitespace, so the fingerprint doesn't change when the code moves around.
+//!
+//! Identical fingerprints are counted, so adding a second violation of the same rule on
+//! an identical line is still reported.
+//!
+//! When a file that was processed doesn't emit some of its recorded diagnostics anymore,
+//! the corresponding entries are pruned from the baseline.
+
+use super::cache::StableHasher;
+use crate::CliDiagnostic;
+use crate::diagnostics::InvalidBaseline;
+use biome_diagnostics::Error;
+use biome_fs::{BiomePath, FileSystem, OpenOptions};
+use biome_rowan::TextRange;
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Mutex;
+use tracing::warn;
+
+/// The version of the format of the baseline file
+const BASELINE_VERSION: u32 = 1;
+
+/// How the baseline is used by the traversal
+#[derive(Debug, Clone)]
+pub enum BaselineMode {
+    /// All the diagnostics are recorded in the baseline at the given path, which is
+    /// overwritten, and none of them is reported.
+    Write(Utf8PathBuf),
+    /// Only the diagnostics that aren't in the baseline at the given path are reported.
+    Compare(Utf8PathBuf),
+}
+
+impl BaselineMode {
+    /// Returns the mode selected by the `--baseline` and `--write-baseline` arguments,
+    /// resolving the path against the working directory.
+    pub(crate) fn from_arguments(
+        baseline: Option<&Utf8Path>,
+        write_baseline: Option<&Utf8Path>,
+        fs: &dyn FileSystem,
+    ) -> Option<Self> {
+        let resolve = |path: &Utf8Path| match fs.working_directory() {
+            Some(working_directory) => working_directory.join(path),
+            None => path.to_path_buf(),
+        };
+        match (baseline, write_baseline) {
+            (_, Some(path)) => Some(Self::Write(resolve(path))),
+            (Some(path), None) => Some(Self::Compare(resolve(path))),
+            (None, None) => None,
+        }
+    }
+
+    pub(crate) fn location(&self) -> &Utf8Path {
+        match self {
+            Self::Write(location) | Self::Compare(location) => location,
+        }
+    }
+
+    pub(crate) const fn is_write(&self) -> bool {
+        matches!(self, Self::Write(_))
+    }
+}
+
+/// The content of the baseline file
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BaselineFile {
+    /// The version of the format of the file
+    version: u32,
+    /// The recorded diagnostics, by file path
+    files: BTreeMap<String, Vec<BaselineEntry>>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BaselineEntry {
+    /// The category of the diagnostic
+    category: String,
+    /// The hash of the code spanned by the diagnostic
+    hash: String,
+    /// The number of diagnostics with the same category and hash in the file
+    count: u32,
+}
+
+/// Occurrences of the fingerprints of a file, by category and hash
+type FileFingerprints = BTreeMap<(String, String), u32>;
+
+#[derive(Debug)]
+pub(crate) struct Baseline {
+    mode: BaselineMode,
+    /// The fingerprints loaded from the disk
+    previous: BTreeMap<String, FileFingerprints>,
+    /// The fingerprints that weren't matched yet during the current execution
+    remaining: Mutex<BTreeMap<String, FileFingerprints>>,
+    /// The fingerprints recorded or matched during the current execution
+    current: Mutex<BTreeMap<String, FileFingerprints>>,
+}
+
+impl Baseline {
+    /// Loads the baseline for the given `mode`.
+    ///
+    /// ## Errors
+    ///
+    /// When comparing against a baseline that doesn't exist or can't be deserialized.
+    pub(crate) fn load(fs: &dyn FileSystem, mode: BaselineMode) -> Result<Self, CliDiagnostic> {
+        let previous = match &mode {
+            BaselineMode::Write(_) => BTreeMap::default(),
+            BaselineMode::Compare(location) => {
+                let invalid = |reason: String| {
+                    CliDiagnostic::InvalidBaseline(InvalidBaseline {
+                        path: location.to_string(),
+                        reason,
+                    })
+                };
+                let content = fs
+                    .read_file_from_path(location)
+                    .map_err(|_| invalid("the file can't be read".to_string()))?;
+                let file = serde_json::from_str::<BaselineFile>(&content)
+                    .map_err(|error| invalid(error.to_string()))?;
+                if file.version != BASELINE_VERSION {
+                    return Err(invalid(format!(
+                        "the version {} isn't supported",
+                        file.version
+                    )));
+                }
+                file.files
+                    .into_iter()
+                    .map(|(path, entries)| {
+                        let fingerprints = entries
+                            .into_iter()
+                            .map(|entry| ((entry.category, entry.hash), entry.count))
+                            .collect();
+                        (path, fingerprints)
+                    })
+                    .collect()
+            }
+        };
+
+        Ok(Self {
+            mode,
+            remaining: Mutex::new(previous.clone()),
+            previous,
+            current: Mutex::default(),
+        })
+    }
+
+    /// Returns `true` if the `diagnostic`, emitted for the file at `file_path` whose
+    /// content is `content`, is part of the baseline, so it shouldn't be reported.
+    ///
+    /// When writing the baseline, the diagnostic is recorded and `true` is returned.
+    pub(crate) fn contains(&self, file_path: &str, content: &str, diagnostic: &Error) -> bool {
+        let Some(category) = diagnostic.category() else {
+            return false;
+        };
+        let file_path = normalize_separators(file_path);
+        let fingerprint = (
+            category.name().to_string(),
+            format!("{:032x}", code_hash(content, diagnostic.location().span)),
+        );
+
+        if !self.mode.is_write() {
+            let mut remaining = self.remaining.lock().unwrap();
+            let Some(count) = remaining
+                .get_mut(&file_path)
+                .and_then(|fingerprints| fingerprints.get_mut(&fingerprint))
+                .filter(|count| **count > 0)
+            else {
+                return false;
+            };
+            *count -= 1;
+        }
+
+        *self
+            .current
+            .lock()
+            .unwrap()
+            .entry(file_path)
+            .or_default()
+            .entry(fingerprint)
+            .or_default() += 1;
+        true
+    }
+
+    /// Writes the baseline to disk.
+    ///
+    /// When comparing, the entries of the files in `evaluated_paths` that weren't matched
+    /// are pruned, as well as the entries of the files that don't exist anymore. The file
+    /// is only written when entries were pruned.
+    ///
+    /// `prune` is `false` when some rules didn't run, because their diagnostics can't be
+    /// told apart from the fixed ones.
+    pub(crate) fn save(
+        self,
+        fs: &dyn FileSystem,
+        evaluated_paths: &BTreeSet<BiomePath>,
+        prune: bool,
+    ) {
+        let current = self.current.into_inner().unwrap();
+        let files = match &self.mode {
+            BaselineMode::Write(_) => current,
+            BaselineMode::Compare(_) if !prune => return,
+            BaselineMode::Compare(_) => {
+                let working_directory = fs.working_directory();
+                let evaluated_paths: BTreeSet<String> = evaluated_paths
+                    .iter()
+                    .map(|path| {
+                        let path = working_directory
+                            .as_deref()
+                            .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+                            .unwrap_or(path.as_path());
+                        normalize_separators(path.as_str())
+                    })
+                    .collect();
+
+                let mut files = BTreeMap::new();
+                for (path, fingerprints) in &self.previous {
+                    if evaluated_paths.contains(path) {
+                        if let Some(fingerprints) = current.get(path) {
+                            files.insert(path.clone(), fingerprints.clone());
+                        }
+                    } else if working_directory.as_deref().map_or_else(
+                        || fs.path_exists(Utf8Path::new(path)),
+                        |working_directory| fs.path_exists(&working_directory.join(path)),
+                    ) {
+                        files.insert(path.clone(), fingerprints.clone());
+                    }
+                }
+                if files == self.previous {
+                    return;
+                }
+                files
+            }
+        };
+
+        let file = BaselineFile {
+            version: BASELINE_VERSION,
+            files: files
+                .into_iter()
+                .map(|(path, fingerprints)| {
+                    let entries = fingerprints
+                        .into_iter()
+                        .map(|((category, hash), count)| BaselineEntry {
+                            category,
+                            hash,
+                            count,
+                        })
+                        .collect();
+                    (path, entries)
+                })
+                .collect(),
+        };
+        let mut content = match serde_json::to_string_pretty(&file) {
+            Ok(content) => content,
+            Err(error) => {
+                warn!("The baseline couldn't be serialized: {error}");
+                return;
+            }
+        };
+        content.push('\n');
+
+        let location = self.mode.location();
+        let result = fs
+            .open_with_options(
+                location,
+                OpenOptions::default()
+                    .write(true)
+                    .create(true)
+                    .truncate(true),
+            )
+            .and_then(|mut file| file.set_content(content.as_bytes()));
+        if let Err(error) = result {
+            warn!("The baseline couldn't be written to {location}: {error}");
+        }
+    }
+}
+
+/// Hashes the lines of `content` spanned by `span`, ignoring their indentation and
+/// trailing whitespace.
+fn code_hash(content: &str, span: Option<TextRange>) -> u128 {
+    let mut hasher = StableHasher::default();
+    let lines = span.and_then(|span| {
+        let start = usize::from(span.start());
+        let end = usize::from(span.end());
+        let line_start = content
+            .get(..start)?
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let line_end = content
+            .get(end..)?
+            .find('\n')
+            .map_or(content.len(), |index| end + index);
+        content.get(line_start..line_end)
+    });
+    for line in lines.into_iter().flat_map(str::lines) {
+        hasher.write(line.trim());
+    }
+    hasher.finish()
+}
+
+/// Uses `/` as separator, so the baseline can be shared across platforms.
+fn normalize_separators(path: &str) -> String {
+    path.replace('\\', "/")
+}
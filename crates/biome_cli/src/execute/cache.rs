@@ -0,0 +1,507 @@
+//! Persistent cache of the results produced by `biome check` and `biome ci`.
+//!
+//! Each processed file is stored with a key computed from:
+//! - a fingerprint of the execution: the Biome version, the resolved configuration,
+//!   the nested configuration files and the plugins;
+//! - the path and the content of the file;
+//! - the paths and the contents of the modules the file depends on, according to
+//!   the module graph, so rules that inspect other modules are invalidated when
+//!   one of those modules changes. For JSON files, these are the documents of the JSON
+//!   Schema the file is validated against, and for GraphQL files, the files that
+//!   define the schema of the project;
+//! - the paths and the contents of the modules that depend on the file, so rules such
+//!   as `noUnusedExports` are invalidated when an import of the file is added or removed;
+//! - the contents of the `package.json` and `tsconfig.json` files of the directories
+//!   that contain the file, which are read by rules such as `noUndeclaredDependencies`.
+//!
+//! The keys are computed with XXH3-128, whose output doesn't change across releases of
+//! Biome or Rust, and only from the bytes of the inputs.
+//!
+//! When the key of a file matches the stored one, the messages emitted during the
+//! previous run are replayed, and the file isn't parsed, analyzed nor formatted.
+
+use super::process_file::{DiffKind, FileResult, FileStatus, Message};
+use super::traverse::TraversalOptions;
+use crate::VERSION;
+use biome_diagnostics::{Error, Resource};
+use biome_fs::{BiomePath, FileSystem, OpenOptions, ensure_cache_dir};
+use biome_service::workspace::{GetModuleDependenciesParams, GetModuleDependenciesResult};
+use camino::{Utf8Path, Utf8PathBuf};
+use dashmap::DashMap;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use std::panic::RefUnwindSafe;
+use tracing::{debug, warn};
+use xxhash_rust::xxh3::Xxh3;
+
+/// Prefix of the name of the cache files created inside the cache directory of Biome
+pub(crate) const CACHE_FILE_PREFIX: &str = "biome-cache-";
+
+/// Files of the directories containing a file that can change its diagnostics
+const MANIFEST_FILE_NAMES: [&str; 2] = ["package.json", "tsconfig.json"];
+
+/// Returns the location of the cache, or [None] if the cache is disabled.
+///
+/// By default, every project gets its own cache file, which is stored inside the
+/// cache directory of Biome.
+pub(crate) fn resolve_cache_location(
+    cache: bool,
+    cache_location: Option<&Utf8Path>,
+    fs: &dyn FileSystem,
+) -> Option<Utf8PathBuf> {
+    let working_directory = fs.working_directory();
+    match cache_location {
+        Some(location) => Some(match working_directory {
+            Some(working_directory) => working_directory.join(location),
+            None => location.to_path_buf(),
+        }),
+        None if cache => {
+            let mut hasher = StableHasher::default();
+            hasher.write(working_directory.as_ref().map(|path| path.as_str()));
+            Some(
+                ensure_cache_dir()
+                    .join(format!("{CACHE_FILE_PREFIX}{:032x}.json", hasher.finish())),
+            )
+        }
+        None => None,
+    }
+}
+
+/// Returns `true` if the file at `path` was written by the result cache.
+pub(crate) fn is_cache_file(fs: &dyn FileSystem, path: &Utf8Path) -> bool {
+    fs.path_is_file(path)
+        && fs
+            .read_file_from_path(path)
+            .is_ok_and(|content| serde_json::from_str::<CacheFile>(&content).is_ok())
+}
+
+/// Values that can be written to a [StableHasher]
+pub(crate) trait StableHash {
+    fn stable_hash(&self, hasher: &mut StableHasher);
+}
+
+impl StableHash for bool {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.0.update(&[u8::from(*self)]);
+    }
+}
+
+impl StableHash for u64 {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.0.update(&self.to_le_bytes());
+    }
+}
+
+impl StableHash for u128 {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        hasher.0.update(&self.to_le_bytes());
+    }
+}
+
+impl StableHash for str {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        // The length prefix prevents `("ab", "c")` and `("a", "bc")` from colliding
+        (self.len() as u64).stable_hash(hasher);
+        hasher.0.update(self.as_bytes());
+    }
+}
+
+impl StableHash for String {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        self.as_str().stable_hash(hasher);
+    }
+}
+
+impl<T: StableHash> StableHash for Option<T> {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        match self {
+            Some(value) => {
+                true.stable_hash(hasher);
+                value.stable_hash(hasher);
+            }
+            None => false.stable_hash(hasher),
+        }
+    }
+}
+
+impl<T: StableHash + ?Sized> StableHash for &T {
+    fn stable_hash(&self, hasher: &mut StableHasher) {
+        (**self).stable_hash(hasher);
+    }
+}
+
+/// A 128-bit hasher whose output only depends on the hashed values, so it can be
+/// persisted.
+///
+/// Unlike [std::hash::Hash], the encoding of the values is defined by [StableHash],
+/// and it doesn't change across releases of Rust.
+#[derive(Default)]
+pub(crate) struct StableHasher(Xxh3);
+
+impl StableHasher {
+    pub(crate) fn write(&mut self, value: impl StableHash) {
+        value.stable_hash(self);
+    }
+
+    pub(crate) fn finish(&self) -> u128 {
+        self.0.digest128()
+    }
+}
+
+/// Incrementally computes the fingerprint of an execution.
+///
+/// Any change to the inputs of the fingerprint invalidates the whole cache.
+pub(crate) struct CacheFingerprint(StableHasher);
+
+impl CacheFingerprint {
+    pub(crate) fn new(mode: &str) -> Self {
+        let mut hasher = StableHasher::default();
+        hasher.write(VERSION);
+        hasher.write(mode);
+        Self(hasher)
+    }
+
+    pub(crate) fn write(&mut self, value: impl StableHash) {
+        self.0.write(value);
+    }
+
+    pub(crate) fn finish(&self) -> u128 {
+        self.0.finish()
+    }
+}
+
+/// The content of the cache file
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheFile {
+    /// The Biome version that wrote the cache
+    version: String,
+    /// The fingerprint of the execution that wrote the cache
+    fingerprint: u128,
+    /// Entries of the cache, by file path
+    entries: FxHashMap<String, CacheEntry>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheEntry {
+    /// The key of the file when the entry was stored
+    key: u128,
+    /// Whether the file made the command fail
+    failure: bool,
+    /// The messages emitted while processing the file, in order
+    messages: Vec<CachedMessage>,
+}
+
+/// Serializable version of [Message]. The content of the file isn't stored, because it's
+/// part of the key of the entry.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+enum CachedMessage {
+    Error(biome_diagnostics::serde::Diagnostic),
+    Diagnostics {
+        diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
+        skipped_diagnostics: u32,
+    },
+    FormatDiff {
+        new: String,
+    },
+}
+
+impl CachedMessage {
+    fn from_message(message: &Message) -> Option<Self> {
+        match message {
+            Message::Error(error) => Some(Self::Error(
+                biome_diagnostics::serde::Diagnostic::from_diagnostic(error),
+            )),
+            Message::Diagnostics {
+                diagnostics,
+                skipped_diagnostics,
+                ..
+            } => Some(Self::Diagnostics {
+                diagnostics: diagnostics
+                    .iter()
+                    .map(biome_diagnostics::serde::Diagnostic::from_diagnostic)
+                    .collect(),
+                skipped_diagnostics: *skipped_diagnostics,
+            }),
+            Message::Diff {
+                new,
+                diff_kind: DiffKind::Format,
+                ..
+            } => Some(Self::FormatDiff { new: new.clone() }),
+            Message::Diff {
+                diff_kind: DiffKind::Rewrite,
+                ..
+            }
+            | Message::SkippedFixes { .. }
+            | Message::Failure => None,
+        }
+    }
+
+    fn into_message(self, path: &BiomePath, content: &str) -> Message {
+        match self {
+            Self::Error(diagnostic) => Message::Error(Error::from(diagnostic)),
+            Self::Diagnostics {
+                diagnostics,
+                skipped_diagnostics,
+            } => Message::Diagnostics {
+                file_path: path.to_string(),
+                content: content.to_string(),
+                diagnostics: diagnostics.into_iter().map(Error::from).collect(),
+                skipped_diagnostics,
+            },
+            Self::FormatDiff { new } => Message::Diff {
+                file_name: path.to_string(),
+                old: content.to_string(),
+                new,
+                diff_kind: DiffKind::Format,
+            },
+        }
+    }
+}
+
+/// A file that is being processed, and whose messages are recorded
+#[derive(Debug)]
+struct PendingEntry {
+    key: u128,
+    messages: Vec<CachedMessage>,
+}
+
+/// The outcome of [ResultCache::lookup]
+pub(crate) enum CacheLookup {
+    /// The file was found in the cache, and its messages were replayed
+    Hit(FileStatus),
+    /// The file must be processed. Its messages are recorded
+    Miss,
+    /// The file can't be cached
+    Uncacheable,
+}
+
+#[derive(Debug)]
+pub(crate) struct ResultCache {
+    /// Where the cache is stored
+    location: Utf8PathBuf,
+    /// The fingerprint of the current execution
+    fingerprint: u128,
+    /// Entries loaded from the disk
+    previous: FxHashMap<String, CacheEntry>,
+    /// Entries that will be written to disk. Files that aren't processed during the
+    /// current execution are dropped from the cache.
+    current: DashMap<String, CacheEntry>,
+    /// Files that are being processed
+    pending: DashMap<String, PendingEntry>,
+    /// Memoized hashes of the content of the files, `None` if the file can't be read
+    content_hashes: DashMap<Utf8PathBuf, Option<u128>>,
+}
+
+/// The cache is only mutated through the atomic operations of [DashMap], so a
+/// panic while processing a file can't break its invariants.
+impl RefUnwindSafe for ResultCache {}
+
+impl ResultCache {
+    /// Loads the cache stored at `location`.
+    ///
+    /// The previous entries are discarded if the cache can't be read, or if it was written by
+    /// an execution with a different fingerprint.
+    pub(crate) fn load(fs: &dyn FileSystem, location: Utf8PathBuf, fingerprint: u128) -> Self {
+        let previous = if fs.path_is_file(&location) {
+            match fs.read_file_from_path(&location).map(|content| {
+                serde_json::from_str::<CacheFile>(&content).map_err(|error| error.to_string())
+            }) {
+                Ok(Ok(file)) if file.version == VERSION && file.fingerprint == fingerprint => {
+                    file.entries
+                }
+                Ok(Ok(_)) => {
+                    debug!("The cache at {location} is outdated, it will be discarded.");
+                    FxHashMap::default()
+                }
+                Ok(Err(error)) => {
+                    warn!("The cache at {location} couldn't be deserialized: {error}");
+                    FxHashMap::default()
+                }
+                Err(_) => FxHashMap::default(),
+            }
+        } else {
+            FxHashMap::default()
+        };
+
+        Self {
+            location,
+            fingerprint,
+            previous,
+            current: DashMap::default(),
+            pending: DashMap::default(),
+            content_hashes: DashMap::default(),
+        }
+    }
+
+    /// Looks up the file at `path`.
+    ///
+    /// On a hit, the messages stored in the cache are sent to the display thread.
+    /// On a miss, the messages emitted while processing the file are recorded until
+    /// [ResultCache::finish] is called.
+    pub(crate) fn lookup(&self, ctx: &TraversalOptions, path: &BiomePath) -> CacheLookup {
+        let Ok(content) = ctx.fs.read_file_from_path(path) else {
+            return CacheLookup::Uncacheable;
+        };
+        let Some(key) = self.key_for(ctx, path, &content) else {
+            return CacheLookup::Uncacheable;
+        };
+
+        if let Some(entry) = self
+            .previous
+            .get(path.as_str())
+            .filter(|entry| entry.key == key)
+        {
+            for message in entry.messages.iter().cloned() {
+                ctx.push_message(message.into_message(path, &content));
+            }
+            self.current.insert(path.to_string(), entry.clone());
+            return CacheLookup::Hit(if entry.failure {
+                FileStatus::Message(Message::Failure)
+            } else {
+                FileStatus::Unchanged
+            });
+        }
+
+        self.pending.insert(
+            path.to_string(),
+            PendingEntry {
+                key,
+                messages: Vec::new(),
+            },
+        );
+        CacheLookup::Miss
+    }
+
+    /// Records a message sent while processing a file that missed the cache.
+    pub(crate) fn record(&self, message: &Message) {
+        let path = match message {
+            Message::Diagnostics { file_path, .. } => file_path.as_str(),
+            Message::Diff { file_name, .. } => file_name.as_str(),
+            Message::Error(error) => match error.location().resource {
+                Some(Resource::File(path)) => path,
+                _ => return,
+            },
+            Message::SkippedFixes { .. } | Message::Failure => return,
+        };
+        if let Some(mut pending) = self.pending.get_mut(path) {
+            pending
+                .messages
+                .extend(CachedMessage::from_message(message));
+        }
+    }
+
+    /// Stops recording the messages of the file at `path`, and stores them if the result
+    /// of the processing can be reused.
+    ///
+    /// `result` is `None` when the processing panicked.
+    pub(crate) fn finish(&self, path: &BiomePath, result: Option<&FileResult>) {
+        let Some((path, pending)) = self.pending.remove(path.as_str()) else {
+            return;
+        };
+        let failure = match result {
+            Some(Ok(FileStatus::Unchanged)) => false,
+            Some(Ok(FileStatus::Message(Message::Failure))) => true,
+            // Files that were changed, ignored or that couldn't be processed
+            // must be processed again.
+            _ => return,
+        };
+        self.current.insert(
+            path,
+            CacheEntry {
+                key: pending.key,
+                failure,
+                messages: pending.messages,
+            },
+        );
+    }
+
+    /// Writes the cache to disk.
+    pub(crate) fn save(self, fs: &dyn FileSystem) {
+        let file = CacheFile {
+            version: VERSION.to_string(),
+            fingerprint: self.fingerprint,
+            entries: self.current.into_iter().collect(),
+        };
+        let content = match serde_json::to_string(&file) {
+            Ok(content) => content,
+            Err(error) => {
+                warn!("The cache couldn't be serialized: {error}");
+                return;
+            }
+        };
+        let result = fs
+            .open_with_options(
+                &self.location,
+                OpenOptions::default()
+                    .write(true)
+                    .create(true)
+                    .truncate(true),
+            )
+            .and_then(|mut file| file.set_content(content.as_bytes()));
+        if let Err(error) = result {
+            warn!(
+                "The cache couldn't be written to {}: {error}",
+                self.location
+            );
+        }
+    }
+
+    /// Computes the key of the file at `path`, using its dependencies and dependents in the
+    /// module graph and the manifests of its directories.
+    ///
+    /// Returns `None` if the dependencies of the file can't be read.
+    fn key_for(&self, ctx: &TraversalOptions, path: &BiomePath, content: &str) -> Option<u128> {
+        let mut hasher = StableHasher::default();
+        hasher.write(self.fingerprint);
+        hasher.write(path.as_str());
+        hasher.write(content);
+
+        // Missing manifests are part of the key too, so creating one invalidates the entry
+        for directory in path.as_path().ancestors().skip(1) {
+            for file_name in MANIFEST_FILE_NAMES {
+                hasher.write(self.content_hash(ctx.fs, &directory.join(file_name)));
+            }
+        }
+
+        let GetModuleDependenciesResult {
+            dependencies,
+            dependents,
+        } = ctx
+            .workspace
+            .get_module_dependencies(GetModuleDependenciesParams {
+                project_key: ctx.project_key,
+                path: path.clone(),
+            })
+            .ok()?;
+        // The lengths prevent a module from moving from one list to the other without
+        // changing the key
+        hasher.write(dependencies.len() as u64);
+        for dependency in dependencies {
+            hasher.write(dependency.as_str());
+            hasher.write(self.content_hash(ctx.fs, dependency.as_path())?);
+        }
+        hasher.write(dependents.len() as u64);
+        for dependent in dependents {
+            hasher.write(dependent.as_str());
+            hasher.write(self.content_hash(ctx.fs, dependent.as_path())?);
+        }
+
+        Some(hasher.finish())
+    }
+
+    fn content_hash(&self, fs: &dyn FileSystem, path: &Utf8Path) -> Option<u128> {
+        if let Some(hash) = self.content_hashes.get(path) {
+            return *hash;
+        }
+        let hash = fs.read_file_from_path(path).ok().map(|content| {
+            let mut hasher = StableHasher::default();
+            hasher.write(content);
+            hasher.finish()
+        });
+        self.content_hashes.insert(path.to_path_buf(), hash);
+        hash
+    }
+}
@@ -246,6 +246,7 @@ fn migrate_file(payload: MigrateFile) -> Result<MigrationFileResult, CliDiagnost
                     path: biome_path.clone(),
                     content: new_content,
                     version: 1,
+                    edits: Vec::new(),
                 })?;
                 let printed = workspace.format_file(FormatFileParams {
                     project_key,
@@ -319,6 +320,7 @@ fn migrate_file(payload: MigrateFile) -> Result<MigrationFileResult, CliDiagnost
                     path: biome_path.clone(),
                     content: new_content,
                     version: 1,
+                    edits: Vec::new(),
                 })?;
                 let printed = workspace.format_file(FormatFileParams {
                     project_key,
@@ -405,6 +407,7 @@ fn migrate_file(payload: MigrateFile) -> Result<MigrationFileResult, CliDiagnost
                         path: biome_path.clone(),
                         content: new_configuration_content,
                         version: 1,
+                        edits: Vec::new(),
                     })?;
                     let printed = workspace.format_file(FormatFileParams {
                         project_key,
@@ -263,6 +263,7 @@ impl TryFrom<PrettierConfiguration> for biome_configuration::Configuration {
             indent_style: None,
             line_ending: None,
             expand: None,
+            embedded_language_formatting: None,
             enabled: None,
             // js ones
             bracket_same_line: Some(value.bracket_line.into()),
@@ -1,18 +1,25 @@
+pub(crate) mod baseline;
+pub(crate) mod cache;
 mod diagnostics;
 mod migrate;
 mod process_file;
 mod std_in;
 pub(crate) mod traverse;
 
+use crate::changed::ChangedLines;
 use crate::cli_options::{CliOptions, CliReporter};
 use crate::commands::MigrateSubCommand;
 use crate::diagnostics::ReportDiagnostic;
+use crate::execute::baseline::{Baseline, BaselineMode};
+use crate::execute::cache::ResultCache;
 use crate::execute::migrate::MigratePayload;
 use crate::execute::traverse::{TraverseResult, traverse};
+use crate::reporter::checkstyle::{CheckstyleReporter, CheckstyleReporterVisitor};
 use crate::reporter::github::{GithubReporter, GithubReporterVisitor};
 use crate::reporter::gitlab::{GitLabReporter, GitLabReporterVisitor};
 use crate::reporter::json::{JsonReporter, JsonReporterVisitor};
 use crate::reporter::junit::{JunitReporter, JunitReporterVisitor};
+use crate::reporter::sarif::{SarifReporter, SarifReporterVisitor};
 use crate::reporter::summary::{SummaryReporter, SummaryReporterVisitor};
 use crate::reporter::terminal::{ConsoleReporter, ConsoleReporterVisitor};
 use crate::{
@@ -47,6 +54,12 @@ pub struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u32,
+
+    /// The fingerprint of the configuration, used to invalidate the result cache
+    cache_fingerprint: u128,
+
+    /// The lines that changed in each file, when only they should be handled
+    changed_lines: Option<ChangedLines>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -112,6 +125,12 @@ pub enum TraversalMode {
 
         /// It skips parse errors
         skip_parse_errors: bool,
+
+        /// The location of the result cache. It's [None] if the cache is disabled.
+        cache: Option<Utf8PathBuf>,
+
+        /// How the baseline of the diagnostics is used. It's [None] if there's no baseline.
+        baseline: Option<BaselineMode>,
     },
     /// This mode is enabled when running the command `biome lint`
     Lint {
@@ -140,6 +159,9 @@ pub enum TraversalMode {
 
         /// It skips parse errors
         skip_parse_errors: bool,
+
+        /// How the baseline of the diagnostics is used. It's [None] if there's no baseline.
+        baseline: Option<BaselineMode>,
     },
     /// This mode is enabled when running the command `biome ci`
     CI {
@@ -151,6 +173,10 @@ pub enum TraversalMode {
         enforce_assist: bool,
         /// It skips parse errors
         skip_parse_errors: bool,
+        /// The location of the result cache. It's [None] if the cache is disabled.
+        cache: Option<Utf8PathBuf>,
+        /// How the baseline of the diagnostics is used. It's [None] if there's no baseline.
+        baseline: Option<BaselineMode>,
     },
     /// This mode is enabled when running the command `biome format`
     Format {
@@ -176,8 +202,6 @@ pub enum TraversalMode {
     /// This mode is enabled when running the command `biome search`
     Search {
         /// The GritQL pattern to search for.
-        ///
-        /// Note that the search command does not support rewrites.
         pattern: PatternId,
 
         /// The language to query for.
@@ -189,6 +213,12 @@ pub enum TraversalMode {
         /// If none given, the default language is JavaScript.
         language: Option<GritTargetLanguage>,
 
+        /// Whether the rewrites of the pattern should be applied.
+        rewrite: bool,
+
+        /// Write the rewritten files to disk
+        write: bool,
+
         /// An optional tuple.
         /// 1. The virtual path to the file
         /// 2. The content of the file
@@ -210,6 +240,16 @@ impl Display for TraversalMode {
 }
 
 impl TraversalMode {
+    /// Returns how the baseline of the diagnostics is used, if there's one
+    pub(crate) fn baseline(&self) -> Option<&BaselineMode> {
+        match self {
+            Self::Check { baseline, .. }
+            | Self::Lint { baseline, .. }
+            | Self::CI { baseline, .. } => baseline.as_ref(),
+            Self::Format { .. } | Self::Migrate { .. } | Self::Search { .. } => None,
+        }
+    }
+
     /// It returns the best [ScanKind] variant based on the [TraversalMode]
     pub fn to_scan_kind(&self) -> ScanKind {
         match self {
@@ -247,6 +287,10 @@ pub enum ReportMode {
     Junit,
     /// Reports information in the [GitLab Code Quality](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool) format.
     GitLab,
+    /// Reports information in the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) format.
+    Sarif,
+    /// Reports information in the [Checkstyle](https://checkstyle.org/) XML format.
+    Checkstyle,
 }
 
 impl Default for ReportMode {
@@ -269,6 +313,8 @@ impl From<CliReporter> for ReportMode {
             CliReporter::GitHub => Self::GitHub,
             CliReporter::Junit => Self::Junit,
             CliReporter::GitLab => Self::GitLab {},
+            CliReporter::Sarif => Self::Sarif,
+            CliReporter::Checkstyle => Self::Checkstyle,
         }
     }
 }
@@ -279,6 +325,8 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: 20,
+            cache_fingerprint: 0,
+            changed_lines: None,
         }
     }
 
@@ -286,6 +334,8 @@ impl Execution {
         vcs_targeted: VcsTargeted,
         enforce_assist: bool,
         skip_parse_errors: bool,
+        cache: Option<Utf8PathBuf>,
+        baseline: Option<BaselineMode>,
     ) -> Self {
         // Ref: https://docs.github.com/actions/learn-github-actions/variables#default-environment-variables
         let is_github = std::env::var("GITHUB_ACTIONS")
@@ -303,8 +353,12 @@ impl Execution {
                 vcs_targeted,
                 enforce_assist,
                 skip_parse_errors,
+                cache,
+                baseline,
             },
             max_diagnostics: 20,
+            cache_fingerprint: 0,
+            changed_lines: None,
         }
     }
 
@@ -314,6 +368,19 @@ impl Execution {
         self
     }
 
+    pub(crate) fn set_cache_fingerprint(&mut self, fingerprint: u128) {
+        self.cache_fingerprint = fingerprint;
+    }
+
+    pub(crate) fn set_changed_lines(&mut self, changed_lines: ChangedLines) {
+        self.changed_lines = Some(changed_lines);
+    }
+
+    /// Returns the lines that changed in each file, if only they should be handled
+    pub(crate) fn changed_lines(&self) -> Option<&ChangedLines> {
+        self.changed_lines.as_ref()
+    }
+
     pub(crate) fn traversal_mode(&self) -> &TraversalMode {
         &self.traversal_mode
     }
@@ -353,6 +420,14 @@ impl Execution {
         matches!(self.traversal_mode, TraversalMode::Search { .. })
     }
 
+    /// Whether the rewrites of the searched pattern are applied
+    pub(crate) const fn is_search_rewrite(&self) -> bool {
+        matches!(
+            self.traversal_mode,
+            TraversalMode::Search { rewrite: true, .. }
+        )
+    }
+
     pub(crate) const fn is_check(&self) -> bool {
         matches!(self.traversal_mode, TraversalMode::Check { .. })
     }
@@ -414,8 +489,10 @@ impl Execution {
         match self.traversal_mode {
             TraversalMode::Check { fix_file_mode, .. }
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.is_some(),
-            TraversalMode::CI { .. } | TraversalMode::Search { .. } => false,
-            TraversalMode::Format { write, .. } | TraversalMode::Migrate { write, .. } => write,
+            TraversalMode::CI { .. } => false,
+            TraversalMode::Format { write, .. }
+            | TraversalMode::Migrate { write, .. }
+            | TraversalMode::Search { write, .. } => write,
         }
     }
 
@@ -447,7 +524,7 @@ impl Execution {
             TraversalMode::CI { .. } => false,
             TraversalMode::Format { write, .. } => write,
             TraversalMode::Migrate { write, .. } => write,
-            TraversalMode::Search { .. } => false,
+            TraversalMode::Search { write, .. } => write,
         }
     }
 
@@ -461,6 +538,8 @@ impl Execution {
             },
             report_mode: ReportMode::default(),
             max_diagnostics: 0,
+            cache_fingerprint: 0,
+            changed_lines: None,
         }
     }
 
@@ -477,14 +556,23 @@ impl Execution {
                 .with_assist()
                 .build(),
             TraversalMode::Migrate { .. } => FeatureName::empty(),
-            TraversalMode::Search { .. } => FeaturesBuilder::new().with_search().build(),
+            TraversalMode::Search { rewrite, .. } => {
+                if rewrite {
+                    FeaturesBuilder::new()
+                        .with_search()
+                        .with_formatter()
+                        .build()
+                } else {
+                    FeaturesBuilder::new().with_search().build()
+                }
+            }
         }
     }
 
     #[instrument(level = "debug", skip(self), fields(result))]
     pub(crate) fn should_write(&self) -> bool {
         let result = match self.traversal_mode {
-            TraversalMode::Format { write, .. } => write,
+            TraversalMode::Format { write, .. } | TraversalMode::Search { write, .. } => write,
 
             _ => self.is_safe_fixes_enabled() || self.is_safe_and_unsafe_fixes_enabled(),
         };
@@ -515,6 +603,25 @@ impl Execution {
         result
     }
 
+    /// Returns the location of the result cache, if the cache is enabled
+    pub(crate) fn cache_location(&self) -> Option<&Utf8Path> {
+        // The cached results are computed for whole files
+        if self.changed_lines.is_some() {
+            return None;
+        }
+        match &self.traversal_mode {
+            TraversalMode::Check { cache, .. } | TraversalMode::CI { cache, .. } => {
+                cache.as_deref()
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns how the baseline of the diagnostics is used, if there's one
+    pub(crate) fn baseline(&self) -> Option<&BaselineMode> {
+        self.traversal_mode.baseline()
+    }
+
     pub(crate) fn should_enforce_assist(&self) -> bool {
         match self.traversal_mode {
             TraversalMode::CI { enforce_assist, .. } => enforce_assist,
@@ -577,6 +684,19 @@ pub fn execute_mode(
         );
     }
 
+    let cache = execution.cache_location().map(|location| {
+        ResultCache::load(
+            session.app.workspace.fs(),
+            location.to_path_buf(),
+            execution.cache_fingerprint,
+        )
+    });
+
+    let baseline = execution
+        .baseline()
+        .map(|mode| Baseline::load(session.app.workspace.fs(), mode.clone()))
+        .transpose()?;
+
     let TraverseResult {
         mut summary,
         evaluated_paths,
@@ -587,7 +707,23 @@ pub fn execute_mode(
         project_key,
         cli_options,
         paths.clone(),
+        cache.as_ref(),
+        baseline.as_ref(),
     )?;
+
+    if let Some(cache) = cache {
+        cache.save(session.app.workspace.fs());
+    }
+    if let Some(baseline) = baseline {
+        // Diagnostics of the rules that didn't run, or of the files that weren't changed,
+        // can't be told apart from fixed ones
+        let prune = execution.changed_lines().is_none()
+            && !matches!(
+                &execution.traversal_mode,
+                TraversalMode::Lint { only, skip, .. } if !only.is_empty() || !skip.is_empty()
+            );
+        baseline.save(session.app.workspace.fs(), &evaluated_paths, prune);
+    }
     diagnostics.sort_unstable_by(|a, b| match a.severity().cmp(&b.severity()) {
         Ordering::Equal => {
             let a = a.location();
@@ -702,6 +838,17 @@ pub fn execute_mode(
                 session.app.workspace.fs().working_directory(),
             ))?;
         }
+        ReportMode::Sarif => {
+            let reporter = SarifReporter {
+                diagnostics_payload,
+                execution: execution.clone(),
+                verbose: cli_options.verbose,
+            };
+            reporter.write(&mut SarifReporterVisitor::new(
+                console,
+                session.app.workspace.fs().working_directory(),
+            ))?;
+        }
         ReportMode::Junit => {
             let reporter = JunitReporter {
                 summary,
@@ -711,6 +858,17 @@ pub fn execute_mode(
             };
             reporter.write(&mut JunitReporterVisitor::new(console))?;
         }
+        ReportMode::Checkstyle => {
+            let reporter = CheckstyleReporter {
+                diagnostics_payload,
+                execution: execution.clone(),
+                verbose: cli_options.verbose,
+            };
+            reporter.write(&mut CheckstyleReporterVisitor::new(
+                console,
+                session.app.workspace.fs().working_directory(),
+            ))?;
+        }
     }
 
     // Processing emitted error diagnostics, exit with a non-zero code
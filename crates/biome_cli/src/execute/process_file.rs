@@ -74,6 +74,7 @@ impl Message {
 #[derive(Debug)]
 pub(crate) enum DiffKind {
     Format,
+    Rewrite,
 }
 
 impl<D> From<D> for Message
@@ -236,7 +237,7 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
         }
         TraversalMode::Search { ref pattern, .. } => {
             // the unsupported case should be handled already at this point
-            search(shared_context, biome_path.clone(), pattern)
+            search(shared_context, biome_path.clone(), pattern, &file_features)
         }
     }
 }
@@ -6,8 +6,11 @@ use crate::execute::process_file::{
 use biome_analyze::RuleCategoriesBuilder;
 use biome_diagnostics::{Diagnostic, DiagnosticExt, Error, Severity, category};
 use biome_fs::{BiomePath, TraversalContext};
+use biome_rowan::TextRange;
+use biome_service::WorkspaceError;
 use biome_service::diagnostics::FileTooLarge;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler};
+use std::ops::Range;
 use tracing::{debug, instrument};
 
 #[instrument(name = "cli_format", level = "debug", skip(ctx, path))]
@@ -80,35 +83,46 @@ pub(crate) fn format_with_guard<'ctx>(
         skipped_diagnostics: diagnostics_result.skipped_diagnostics as u32,
     });
 
-    let printed = workspace_file
-        .guard()
-        .format_file()
-        .with_file_path_and_code(workspace_file.path.to_string(), category!("format"))?;
+    let changed_ranges = match workspace_file.as_extension() {
+        // Range formatting doesn't support the languages embedded in these files
+        Some("astro" | "svelte") => None,
+        _ => ctx.execution.changed_lines().and_then(|changed_lines| {
+            changed_lines.text_ranges(workspace_file.path.as_path(), &input)
+        }),
+    };
+    let range_output = match changed_ranges {
+        Some(ranges) => format_ranges(workspace_file, &input, &ranges)?,
+        None => None,
+    };
+
+    let output = if let Some(output) = range_output {
+        output
+    } else {
+        let printed = workspace_file
+            .guard()
+            .format_file()
+            .with_file_path_and_code(workspace_file.path.to_string(), category!("format"))?;
 
-    let mut output = printed.into_code();
+        let mut output = printed.into_code();
 
-    match workspace_file.as_extension() {
-        Some("astro") => {
-            if output.is_empty() {
-                return Ok(FileStatus::Unchanged);
+        match workspace_file.as_extension() {
+            Some("astro") => {
+                if output.is_empty() {
+                    return Ok(FileStatus::Unchanged);
+                }
+                output = AstroFileHandler::output(input.as_str(), output.as_str());
             }
-            output = AstroFileHandler::output(input.as_str(), output.as_str());
-        }
-        Some("vue") => {
-            if output.is_empty() {
-                return Ok(FileStatus::Unchanged);
+            Some("svelte") => {
+                if output.is_empty() {
+                    return Ok(FileStatus::Unchanged);
+                }
+                output = SvelteFileHandler::output(input.as_str(), output.as_str());
             }
-            output = VueFileHandler::output(input.as_str(), output.as_str());
+            _ => {}
         }
 
-        Some("svelte") => {
-            if output.is_empty() {
-                return Ok(FileStatus::Unchanged);
-            }
-            output = SvelteFileHandler::output(input.as_str(), output.as_str());
-        }
-        _ => {}
-    }
+        output
+    };
 
     debug!("Format output is different from input: {}", output != input);
     if output != input {
@@ -127,3 +141,72 @@ pub(crate) fn format_with_guard<'ctx>(
         Ok(FileStatus::Unchanged)
     }
 }
+
+/// Formats the given `ranges` of `input`, sorted by their position, and returns the
+/// resulting code.
+///
+/// Returns `None` when the file doesn't support range formatting.
+fn format_ranges(
+    workspace_file: &WorkspaceFile,
+    input: &str,
+    ranges: &[TextRange],
+) -> Result<Option<String>, Error> {
+    let mut ranges: Vec<TextRange> = ranges
+        .iter()
+        .copied()
+        .filter(|range| !range.is_empty())
+        .collect();
+    let printed_ranges = loop {
+        let mut printed_ranges = Vec::with_capacity(ranges.len());
+        for range in &ranges {
+            let printed = match workspace_file.guard().format_range(*range) {
+                Ok(printed) => printed,
+                Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+                Err(error) => {
+                    return Err(error).with_file_path_and_code(
+                        workspace_file.path.to_string(),
+                        category!("format"),
+                    );
+                }
+            };
+            if let Some(printed_range) = printed.range() {
+                printed_ranges.push((printed_range, printed.into_code()));
+            }
+        }
+        printed_ranges.sort_by_key(|(printed_range, _)| printed_range.start());
+
+        // The formatted ranges can extend to the enclosing nodes. When some of them
+        // overlap, their union is formatted instead, until none of them overlap. Each
+        // iteration merges at least two ranges, so this terminates.
+        let merged_ranges = merge_overlapping_ranges(
+            printed_ranges
+                .iter()
+                .map(|(printed_range, _)| *printed_range),
+        );
+        if merged_ranges.len() == printed_ranges.len() {
+            break printed_ranges;
+        }
+        ranges = merged_ranges;
+    };
+
+    let mut output = input.to_string();
+    // The ranges are replaced starting from the end of the file, so the offsets of the
+    // ones that precede them remain valid
+    for (printed_range, code) in printed_ranges.iter().rev() {
+        output.replace_range(Range::<usize>::from(*printed_range), code);
+    }
+
+    Ok(Some(output))
+}
+
+/// Merges the `ranges`, sorted by their start, that overlap each other.
+fn merge_overlapping_ranges(ranges: impl IntoIterator<Item = TextRange>) -> Vec<TextRange> {
+    let mut merged: Vec<TextRange> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start() < last.end() => *last = last.cover(range),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
@@ -7,7 +7,7 @@ use biome_diagnostics::{Diagnostic, DiagnosticExt, Error, Severity, category};
 use biome_fs::{BiomePath, TraversalContext};
 use biome_rowan::TextSize;
 use biome_service::diagnostics::FileTooLarge;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler};
 use tracing::{info, instrument};
 
 /// Lints a single file and returns a [FileResult]
@@ -95,9 +95,6 @@ pub(crate) fn analyze_with_guard<'ctx>(
             Some("astro") => {
                 output = AstroFileHandler::output(input.as_str(), output.as_str());
             }
-            Some("vue") => {
-                output = VueFileHandler::output(input.as_str(), output.as_str());
-            }
             Some("svelte") => {
                 output = SvelteFileHandler::output(input.as_str(), output.as_str());
             }
@@ -131,7 +128,6 @@ pub(crate) fn analyze_with_guard<'ctx>(
 
     if !no_diagnostics {
         let offset = match workspace_file.as_extension() {
-            Some("vue") => VueFileHandler::start(input.as_str()),
             Some("astro") => AstroFileHandler::start(input.as_str()),
             Some("svelte") => SvelteFileHandler::start(input.as_str()),
             _ => None,
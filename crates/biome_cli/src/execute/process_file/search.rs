@@ -1,18 +1,21 @@
 use crate::execute::TraversalMode;
 use crate::execute::diagnostics::{ResultExt, SearchDiagnostic};
 use crate::execute::process_file::workspace_file::WorkspaceFile;
-use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
+use crate::execute::process_file::{
+    DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
+};
 use biome_diagnostics::{DiagnosticExt, category};
 use biome_fs::{BiomePath, TraversalContext};
 use biome_grit_patterns::{GritTargetLanguage, JsTargetLanguage};
 use biome_service::diagnostics::FileTooLarge;
 use biome_service::file_handlers::DocumentFileSource;
-use biome_service::workspace::PatternId;
+use biome_service::workspace::{FileFeaturesResult, PatternId};
 
 pub(crate) fn search<'ctx>(
     ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
     path: BiomePath,
     pattern: &PatternId,
+    file_features: &FileFeaturesResult,
 ) -> FileResult {
     let mut workspace_file = WorkspaceFile::new(ctx, path)?;
     let result = workspace_file.guard().check_file_size()?;
@@ -24,26 +27,35 @@ pub(crate) fn search<'ctx>(
         );
         Ok(FileStatus::Ignored)
     } else {
-        search_with_guard(ctx, &mut workspace_file, pattern)
+        search_with_guard(ctx, &mut workspace_file, pattern, file_features)
     }
 }
 
 pub(crate) fn search_with_guard<'ctx>(
-    _ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
     workspace_file: &mut WorkspaceFile,
     pattern: &PatternId,
+    file_features: &FileFeaturesResult,
 ) -> FileResult {
     let _ = tracing::info_span!("Search ", path =? workspace_file.path).entered();
 
     let file_source = DocumentFileSource::from_path(workspace_file.path.as_path());
-    let pattern_language = match &_ctx.execution.traversal_mode {
+    let (pattern_language, rewrite) = match &ctx.execution.traversal_mode {
         TraversalMode::Search {
             language: Some(pattern_language),
+            rewrite,
+            ..
+        } => (pattern_language, *rewrite),
+        TraversalMode::Search {
+            language: None,
+            rewrite,
             ..
-        } => pattern_language,
-        TraversalMode::Search { language: None, .. } => {
+        } => {
             // Default to JavaScript when no language is specified
-            &GritTargetLanguage::JsTargetLanguage(JsTargetLanguage)
+            (
+                &GritTargetLanguage::JsTargetLanguage(JsTargetLanguage),
+                *rewrite,
+            )
         }
         _ => return Ok(FileStatus::Ignored), // unreachable
     };
@@ -62,6 +74,16 @@ pub(crate) fn search_with_guard<'ctx>(
     let file_name = workspace_file.path.to_string();
     let matches_len = result.matches.len();
 
+    if rewrite {
+        ctx.increment_matches(matches_len);
+        return match result.rewritten {
+            Some(rewritten) if rewritten != input => {
+                rewrite_with_guard(ctx, workspace_file, input, rewritten, file_features)
+            }
+            _ => Ok(FileStatus::Unchanged),
+        };
+    }
+
     let search_results = Message::Diagnostics {
         file_path: file_name,
         content: input,
@@ -76,6 +98,47 @@ pub(crate) fn search_with_guard<'ctx>(
     Ok(FileStatus::SearchResult(matches_len, search_results))
 }
 
+/// Formats the `rewritten` content of the file, and either writes it to disk
+/// or returns the difference with the original `input`.
+fn rewrite_with_guard<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    workspace_file: &mut WorkspaceFile,
+    input: String,
+    rewritten: String,
+    file_features: &FileFeaturesResult,
+) -> FileResult {
+    let should_write = ctx.execution.should_write();
+    if should_write {
+        workspace_file.update_file(rewritten.as_str())?;
+    } else {
+        workspace_file.change_content(rewritten.as_str())?;
+    }
+
+    let mut output = rewritten;
+    if file_features.supports_format() {
+        let printed = workspace_file
+            .guard()
+            .format_file()
+            .with_file_path_and_code(workspace_file.path.to_string(), category!("search"))?;
+        let formatted = printed.into_code();
+        if should_write && formatted != output {
+            workspace_file.update_file(formatted.as_str())?;
+        }
+        output = formatted;
+    }
+
+    if should_write {
+        Ok(FileStatus::Changed)
+    } else {
+        Ok(FileStatus::Message(Message::Diff {
+            file_name: workspace_file.path.to_string(),
+            old: input,
+            new: output,
+            diff_kind: DiffKind::Rewrite,
+        }))
+    }
+}
+
 fn is_file_compatible_with_pattern(
     file_source: &DocumentFileSource,
     pattern_language: &GritTargetLanguage,
@@ -85,5 +148,14 @@ fn is_file_compatible_with_pattern(
         GritTargetLanguage::CssTargetLanguage(_) => {
             matches!(file_source, DocumentFileSource::Css(_))
         }
+        GritTargetLanguage::GraphqlTargetLanguage(_) => {
+            matches!(file_source, DocumentFileSource::Graphql(_))
+        }
+        GritTargetLanguage::HtmlTargetLanguage(_) => {
+            matches!(file_source, DocumentFileSource::Html(_))
+        }
+        GritTargetLanguage::JsonTargetLanguage(_) => {
+            matches!(file_source, DocumentFileSource::Json(_))
+        }
     }
 }
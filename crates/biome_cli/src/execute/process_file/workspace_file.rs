@@ -58,6 +58,14 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
         self.path.extension()
     }
 
+    /// It updates the content of the workspace file with `new_content`, without
+    /// writing it to disk
+    pub(crate) fn change_content(&mut self, new_content: impl Into<String>) -> Result<(), Error> {
+        self.guard
+            .change_file(self.file.file_version() + 1, new_content.into())?;
+        Ok(())
+    }
+
     /// It updates the workspace file with `new_content`
     pub(crate) fn update_file(&mut self, new_content: impl Into<String>) -> Result<(), Error> {
         let new_content = new_content.into();
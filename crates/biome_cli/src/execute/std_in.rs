@@ -9,11 +9,11 @@ use biome_diagnostics::Diagnostic;
 use biome_diagnostics::PrintDiagnostic;
 use biome_fs::BiomePath;
 use biome_service::WorkspaceError;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler};
 use biome_service::projects::ProjectKey;
 use biome_service::workspace::{
     ChangeFileParams, CloseFileParams, DropPatternParams, FeaturesBuilder, FileContent,
-    FixFileParams, FormatFileParams, OpenFileParams, SupportsFeatureParams,
+    FixFileParams, FormatFileParams, OpenFileParams, SearchPatternParams, SupportsFeatureParams,
 };
 use std::borrow::Cow;
 
@@ -67,7 +67,6 @@ pub(crate) fn run<'a>(
             let code = printed.into_code();
             let output = match biome_path.extension() {
                 Some("astro") => AstroFileHandler::output(content, code.as_str()),
-                Some("vue") => VueFileHandler::output(content, code.as_str()),
                 Some("svelte") => SvelteFileHandler::output(content, code.as_str()),
                 _ => code,
             };
@@ -154,7 +153,6 @@ pub(crate) fn run<'a>(
                 let code = fix_file_result.code;
                 let output = match biome_path.extension() {
                     Some("astro") => AstroFileHandler::output(&new_content, code.as_str()),
-                    Some("vue") => VueFileHandler::output(&new_content, code.as_str()),
                     Some("svelte") => SvelteFileHandler::output(&new_content, code.as_str()),
                     _ => code,
                 };
@@ -165,6 +163,7 @@ pub(crate) fn run<'a>(
                         content: output.clone(),
                         path: biome_path.clone(),
                         version,
+                        edits: Vec::new(),
                     })?;
                     new_content = Cow::Owned(output);
                 }
@@ -179,7 +178,6 @@ pub(crate) fn run<'a>(
             let code = printed.into_code();
             let output = match biome_path.extension() {
                 Some("astro") => AstroFileHandler::output(&new_content, code.as_str()),
-                Some("vue") => VueFileHandler::output(&new_content, code.as_str()),
                 Some("svelte") => SvelteFileHandler::output(&new_content, code.as_str()),
                 _ => code,
             };
@@ -210,13 +208,66 @@ pub(crate) fn run<'a>(
             project_key,
             path: biome_path.clone(),
         })?;
-    } else if let TraversalMode::Search { pattern, .. } = mode.traversal_mode() {
+    } else if let TraversalMode::Search {
+        pattern, rewrite, ..
+    } = mode.traversal_mode()
+    {
+        let mut new_content = Cow::Borrowed(content);
+
+        if *rewrite {
+            workspace.open_file(OpenFileParams {
+                project_key,
+                path: biome_path.clone(),
+                content: FileContent::from_client(content),
+                document_file_source: None,
+                persist_node_cache: false,
+            })?;
+            let file_features = workspace.file_features(SupportsFeatureParams {
+                project_key,
+                path: biome_path.clone(),
+                features: FeaturesBuilder::new()
+                    .with_search()
+                    .with_formatter()
+                    .build(),
+            })?;
+
+            let search_results = workspace.search_pattern(SearchPatternParams {
+                project_key,
+                path: biome_path.clone(),
+                pattern: pattern.clone(),
+            })?;
+            if let Some(rewritten) = search_results.rewritten {
+                if file_features.supports_format() {
+                    version += 1;
+                    workspace.change_file(ChangeFileParams {
+                        project_key,
+                        content: rewritten,
+                        path: biome_path.clone(),
+                        version,
+                        edits: Vec::new(),
+                    })?;
+                    let printed = workspace.format_file(FormatFileParams {
+                        project_key,
+                        path: biome_path.clone(),
+                    })?;
+                    new_content = Cow::Owned(printed.into_code());
+                } else {
+                    new_content = Cow::Owned(rewritten);
+                }
+            }
+
+            workspace.close_file(CloseFileParams {
+                project_key,
+                path: biome_path.clone(),
+            })?;
+        }
+
         // Make sure patterns are always cleaned up at the end of execution.
         let _ = session.app.workspace.drop_pattern(DropPatternParams {
             pattern: pattern.clone(),
         });
 
-        console.append(markup! {{content}});
+        console.append(markup! {{new_content}});
     } else {
         console.append(markup! {{content}});
     }
@@ -1,8 +1,11 @@
+use super::baseline::Baseline;
+use super::cache::{CacheLookup, ResultCache};
 use super::process_file::{DiffKind, FileStatus, Message, process_file};
 use super::{Execution, TraversalMode};
 use crate::cli_options::CliOptions;
 use crate::execute::diagnostics::{
     CIFormatDiffDiagnostic, ContentDiffAdvice, FormatDiffDiagnostic, PanicDiagnostic,
+    RewriteDiffDiagnostic,
 };
 use crate::reporter::TraversalSummary;
 use crate::{CliDiagnostic, CliSession};
@@ -41,6 +44,8 @@ pub(crate) fn traverse(
     project_key: ProjectKey,
     cli_options: &CliOptions,
     mut inputs: Vec<OsString>,
+    cache: Option<&ResultCache>,
+    baseline: Option<&Baseline>,
 ) -> Result<TraverseResult, CliDiagnostic> {
     if inputs.is_empty() {
         match &execution.traversal_mode {
@@ -85,7 +90,8 @@ pub(crate) fn traverse(
     let printer = DiagnosticsPrinter::new(execution, working_directory.as_deref())
         .with_verbose(cli_options.verbose)
         .with_diagnostic_level(cli_options.diagnostic_level)
-        .with_max_diagnostics(max_diagnostics);
+        .with_max_diagnostics(max_diagnostics)
+        .with_baseline(baseline);
 
     let (duration, evaluated_paths, diagnostics) = thread::scope(|s| {
         let handler = thread::Builder::new()
@@ -110,6 +116,7 @@ pub(crate) fn traverse(
                 skipped: &skipped,
                 messages: sender,
                 evaluated_paths: RwLock::default(),
+                cache,
             },
         );
         // wait for the main thread to finish
@@ -133,6 +140,7 @@ pub(crate) fn traverse(
     let skipped = skipped.load(Ordering::Relaxed);
     let suggested_fixes_skipped = printer.skipped_fixes();
     let diagnostics_not_printed = printer.not_printed_diagnostics();
+    let baselined = printer.baselined();
     Ok(TraverseResult {
         summary: TraversalSummary {
             changed,
@@ -145,6 +153,7 @@ pub(crate) fn traverse(
             skipped,
             suggested_fixes_skipped,
             diagnostics_not_printed,
+            baselined,
         },
         evaluated_paths,
         diagnostics,
@@ -202,9 +211,13 @@ struct DiagnosticsPrinter<'ctx> {
     not_printed_diagnostics: AtomicU32,
     printed_diagnostics: AtomicU32,
     total_skipped_suggested_fixes: AtomicU32,
+    /// The number of diagnostics that were recorded in, or found in, the baseline
+    baselined: AtomicU32,
 
     /// The current working directory, borrowed from [FileSystem]
     working_directory: Option<&'ctx Utf8Path>,
+    /// The baseline of the diagnostics, if there's one
+    baseline: Option<&'ctx Baseline>,
 }
 
 impl<'ctx> DiagnosticsPrinter<'ctx> {
@@ -220,7 +233,9 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
             not_printed_diagnostics: AtomicU32::new(0),
             printed_diagnostics: AtomicU32::new(0),
             total_skipped_suggested_fixes: AtomicU32::new(0),
+            baselined: AtomicU32::new(0),
             working_directory,
+            baseline: None,
         }
     }
 
@@ -239,6 +254,11 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         self
     }
 
+    fn with_baseline(mut self, value: Option<&'ctx Baseline>) -> Self {
+        self.baseline = value;
+        self
+    }
+
     fn errors(&self) -> u32 {
         self.errors.load(Ordering::Relaxed)
     }
@@ -251,6 +271,10 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         self.not_printed_diagnostics.load(Ordering::Relaxed)
     }
 
+    fn baselined(&self) -> u32 {
+        self.baselined.load(Ordering::Relaxed)
+    }
+
     fn skipped_fixes(&self) -> u32 {
         self.total_skipped_suggested_fixes.load(Ordering::Relaxed)
     }
@@ -353,16 +377,40 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     diagnostics,
                     skipped_diagnostics,
                 } => {
+                    let changed_lines = self.execution.changed_lines();
+                    let absolute_file_path = file_path;
                     // we transform the file string into a path object so we can correctly strip
                     // the working directory without having leading slash in the file name
-                    let file_path = self.to_relative_file_path(&file_path);
+                    let file_path = self.to_relative_file_path(&absolute_file_path);
                     self.not_printed_diagnostics
                         .fetch_add(skipped_diagnostics, Ordering::Relaxed);
                     for diag in diagnostics {
                         let severity = diag.severity();
+                        // Diagnostics that aren't reported aren't part of the baseline either
                         if self.should_skip_diagnostic(severity, diag.tags()) {
                             continue;
                         }
+                        // The diagnostics of all the lines are matched against the
+                        // baseline, so the entries of unchanged lines are kept
+                        if self
+                            .baseline
+                            .is_some_and(|baseline| baseline.contains(&file_path, &content, &diag))
+                        {
+                            self.baselined.fetch_add(1, Ordering::Relaxed);
+                            continue;
+                        }
+                        // Only the diagnostics of the lines that changed are reported
+                        if let (Some(changed_lines), Some(span)) =
+                            (changed_lines, diag.location().span)
+                        {
+                            if !changed_lines.contains_span(
+                                Utf8Path::new(&absolute_file_path),
+                                &content,
+                                span,
+                            ) {
+                                continue;
+                            }
+                        }
                         if severity == Severity::Error {
                             self.errors.fetch_add(1, Ordering::Relaxed);
                         }
@@ -388,16 +436,21 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                 } => {
                     let file_path = self.to_relative_file_path(&file_name);
                     // A diff is an error in CI mode and in format check mode
-                    let is_error = self.execution.is_ci() || !self.execution.is_format_write();
+                    let is_error = match diff_kind {
+                        DiffKind::Format => {
+                            self.execution.is_ci() || !self.execution.is_format_write()
+                        }
+                        DiffKind::Rewrite => false,
+                    };
                     if is_error {
                         self.errors.fetch_add(1, Ordering::Relaxed);
                     }
 
-                    let severity: Severity = if is_error {
-                        Severity::Error
-                    } else {
+                    let severity: Severity = match diff_kind {
+                        _ if is_error => Severity::Error,
                         // we set lowest
-                        Severity::Hint
+                        DiffKind::Format => Severity::Hint,
+                        DiffKind::Rewrite => Severity::Information,
                     };
 
                     if self.should_skip_diagnostic(severity, DiagnosticTags::empty()) {
@@ -434,6 +487,18 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                     diagnostics_to_print.push(diag);
                                 }
                             }
+                            DiffKind::Rewrite => {
+                                let diag = RewriteDiffDiagnostic {
+                                    diff: ContentDiffAdvice {
+                                        old: old.clone(),
+                                        new: new.clone(),
+                                    },
+                                }
+                                .with_severity(severity)
+                                .with_file_source_code(old.clone())
+                                .with_file_path(file_path.to_string());
+                                diagnostics_to_print.push(diag);
+                            }
                         }
                     }
                 }
@@ -476,6 +541,8 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
     pub(crate) messages: Sender<Message>,
     /// List of paths that should be processed
     pub(crate) evaluated_paths: RwLock<BTreeSet<BiomePath>>,
+    /// The result cache, if it's enabled
+    cache: Option<&'ctx ResultCache>,
 }
 
 impl TraversalOptions<'_, '_> {
@@ -496,7 +563,11 @@ impl TraversalOptions<'_, '_> {
 
     /// Send a message to the display thread
     pub(crate) fn push_message(&self, msg: impl Into<Message>) {
-        self.messages.send(msg.into()).ok();
+        let msg = msg.into();
+        if let Some(cache) = self.cache {
+            cache.record(&msg);
+        }
+        self.messages.send(msg).ok();
     }
 
     pub(crate) fn miss_handler_err(&self, err: WorkspaceError, biome_path: &BiomePath) {
@@ -584,6 +655,11 @@ impl TraversalContext for TraversalOptions<'_, '_> {
             return false;
         }
 
+        // the result cache may be stored inside the project
+        if self.execution.cache_location() == Some(path) {
+            return false;
+        }
+
         let file_features = self.workspace.file_features(SupportsFeatureParams {
             project_key: self.project_key,
             path: biome_path.clone(),
@@ -641,7 +717,18 @@ impl TraversalContext for TraversalOptions<'_, '_> {
 /// in a [catch_unwind] block and emit diagnostics in case of error (either the
 /// traversal function returns Err or panics)
 fn handle_file(ctx: &TraversalOptions, path: &BiomePath) {
-    match catch_unwind(move || process_file(ctx, path)) {
+    let result = match ctx.cache.map(|cache| cache.lookup(ctx, path)) {
+        Some(CacheLookup::Hit(status)) => Ok(Ok(status)),
+        Some(CacheLookup::Miss) => {
+            let result = catch_unwind(move || process_file(ctx, path));
+            if let Some(cache) = ctx.cache {
+                cache.finish(path, result.as_ref().ok());
+            }
+            result
+        }
+        Some(CacheLookup::Uncacheable) | None => catch_unwind(move || process_file(ctx, path)),
+    };
+    match result {
         Ok(Ok(FileStatus::Changed)) => {
             ctx.increment_changed(path);
         }
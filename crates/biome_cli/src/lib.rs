@@ -70,7 +70,7 @@ impl<'app> CliSession<'app> {
             BiomeCommand::Rage(_, daemon_logs, formatter, linter) => {
                 commands::rage::rage(self, daemon_logs, formatter, linter)
             }
-            BiomeCommand::Clean => commands::clean::clean(self),
+            BiomeCommand::Clean { cache_location } => commands::clean::clean(self, cache_location),
             BiomeCommand::Start {
                 log_path,
                 log_prefix_name,
@@ -91,6 +91,11 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                changed_lines,
+                cache,
+                cache_location,
+                baseline,
+                write_baseline,
             } => run_command(
                 self,
                 &cli_options,
@@ -108,6 +113,11 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    changed_lines,
+                    cache,
+                    cache_location,
+                    baseline,
+                    write_baseline,
                 },
             ),
             BiomeCommand::Lint {
@@ -127,10 +137,14 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                changed_lines,
                 css_linter,
                 javascript_linter,
                 json_linter,
                 graphql_linter,
+                html_linter,
+                baseline,
+                write_baseline,
             } => run_command(
                 self,
                 &cli_options,
@@ -150,10 +164,14 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    changed_lines,
                     css_linter,
                     javascript_linter,
                     json_linter,
                     graphql_linter,
+                    html_linter,
+                    baseline,
+                    write_baseline,
                 },
             ),
             BiomeCommand::Ci {
@@ -166,6 +184,11 @@ impl<'app> CliSession<'app> {
                 cli_options,
                 changed,
                 since,
+                changed_lines,
+                cache,
+                cache_location,
+                baseline,
+                write_baseline,
                 ..
             } => run_command(
                 self,
@@ -179,6 +202,11 @@ impl<'app> CliSession<'app> {
                     paths,
                     changed,
                     since,
+                    changed_lines,
+                    cache,
+                    cache_location,
+                    baseline,
+                    write_baseline,
                 },
             ),
             BiomeCommand::Format {
@@ -195,9 +223,12 @@ impl<'app> CliSession<'app> {
                 css_formatter,
                 graphql_formatter,
                 html_formatter,
+                markdown_formatter,
+                yaml_formatter,
                 staged,
                 changed,
                 since,
+                changed_lines,
             } => run_command(
                 self,
                 &cli_options,
@@ -214,9 +245,12 @@ impl<'app> CliSession<'app> {
                     css_formatter,
                     graphql_formatter,
                     html_formatter,
+                    markdown_formatter,
+                    yaml_formatter,
                     staged,
                     changed,
                     since,
+                    changed_lines,
                 },
             ),
             BiomeCommand::Explain { doc } => commands::explain::explain(self, doc),
@@ -248,6 +282,8 @@ impl<'app> CliSession<'app> {
                 paths,
                 pattern,
                 language,
+                rewrite,
+                write,
                 stdin_file_path,
                 vcs_configuration,
             } => run_command(
@@ -258,6 +294,8 @@ impl<'app> CliSession<'app> {
                     paths,
                     pattern,
                     language,
+                    rewrite,
+                    write,
                     stdin_file_path,
                     vcs_configuration,
                 },
@@ -0,0 +1,230 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::{Console, ConsoleExt, markup};
+use biome_diagnostics::display::SourceFile;
+use biome_diagnostics::{Error, PrintDescription, Resource, Severity};
+use camino::{Utf8Path, Utf8PathBuf};
+use path_absolutize::Absolutize;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::Path;
+
+pub(crate) struct CheckstyleReporter {
+    pub(crate) diagnostics_payload: DiagnosticsPayload,
+    pub(crate) execution: Execution,
+    pub(crate) verbose: bool,
+}
+
+impl Reporter for CheckstyleReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics_payload, self.verbose)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct CheckstyleReporterVisitor<'a> {
+    console: &'a mut dyn Console,
+    working_directory: Option<Utf8PathBuf>,
+}
+
+impl<'a> CheckstyleReporterVisitor<'a> {
+    pub(crate) fn new(
+        console: &'a mut dyn Console,
+        working_directory: Option<Utf8PathBuf>,
+    ) -> Self {
+        Self {
+            console,
+            working_directory,
+        }
+    }
+
+    /// Returns the path of the file relative to the working directory when possible
+    fn relativize(&self, path: &str) -> String {
+        let relativized = self.working_directory.as_deref().and_then(|root| {
+            let resolved = Path::new(path).absolutize().ok()?;
+            let relativized = resolved.strip_prefix(root).ok()?;
+            Utf8Path::from_path(relativized).map(Utf8Path::to_string)
+        });
+        relativized.unwrap_or_else(|| path.to_string())
+    }
+}
+
+impl ReporterVisitor for CheckstyleReporterVisitor<'_> {
+    fn report_summary(
+        &mut self,
+        _execution: &Execution,
+        _summary: TraversalSummary,
+        _verbose: bool,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+        verbose: bool,
+    ) -> io::Result<()> {
+        let diagnostics = payload
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity() >= payload.diagnostic_level)
+            .filter(|diagnostic| {
+                if diagnostic.tags().is_verbose() {
+                    verbose
+                } else {
+                    true
+                }
+            });
+
+        // Checkstyle groups the errors by file
+        let mut files: BTreeMap<String, Vec<CheckstyleError>> = BTreeMap::new();
+        for diagnostic in diagnostics {
+            let location = diagnostic.location();
+            let Some(Resource::File(path)) = location.resource else {
+                continue;
+            };
+            files
+                .entry(self.relativize(path))
+                .or_default()
+                .push(CheckstyleError::from_diagnostic(diagnostic));
+        }
+        for errors in files.values_mut() {
+            errors.sort_by_key(|error| (error.position, error.source));
+        }
+
+        let report = CheckstyleReport { files }.to_string();
+        self.console.log(markup! {
+            {report}
+        });
+
+        Ok(())
+    }
+}
+
+struct CheckstyleReport {
+    /// The errors of each file, by path
+    files: BTreeMap<String, Vec<CheckstyleError>>,
+}
+
+impl Display for CheckstyleReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(f, r#"<checkstyle version="4.3">"#)?;
+        for (path, errors) in &self.files {
+            writeln!(f, r#"  <file name="{}">"#, escape(path))?;
+            for error in errors {
+                write!(f, "    <error ")?;
+                if let Some((line, column)) = error.position {
+                    write!(f, r#"line="{line}" column="{column}" "#)?;
+                }
+                writeln!(
+                    f,
+                    r#"severity="{}" message="{}" source="{}" />"#,
+                    error.severity,
+                    escape(&error.message),
+                    escape(error.source)
+                )?;
+            }
+            writeln!(f, "  </file>")?;
+        }
+        write!(f, "</checkstyle>")
+    }
+}
+
+/// An `<error>` element of the report
+struct CheckstyleError {
+    /// The line and the column of the start of the diagnostic, starting at 1
+    position: Option<(usize, usize)>,
+    severity: &'static str,
+    message: String,
+    /// The category of the diagnostic, such as `lint/suspicious/noDebugger`
+    source: &'static str,
+}
+
+impl CheckstyleError {
+    fn from_diagnostic(diagnostic: &Error) -> Self {
+        let location = diagnostic.location();
+        // The position is left out when the span can't be resolved in the source code
+        let position = match (location.span, location.source_code) {
+            (Some(span), Some(source_code)) => SourceFile::new(source_code)
+                .location(span.start())
+                .ok()
+                .map(|start| (start.line_number.get(), start.column_number.get())),
+            _ => None,
+        };
+
+        Self {
+            position,
+            severity: match diagnostic.severity() {
+                Severity::Hint | Severity::Information => "info",
+                Severity::Warning => "warning",
+                Severity::Error | Severity::Fatal => "error",
+            },
+            message: PrintDescription(diagnostic).to_string(),
+            source: diagnostic
+                .category()
+                .map(|category| category.name())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Escapes the characters that can't appear inside an attribute value, and
+/// replaces the characters that XML 1.0 doesn't allow with `U+FFFD`
+fn escape(value: &str) -> Cow<'_, str> {
+    if !value.contains(|character| {
+        matches!(character, '&' | '<' | '>' | '"' | '\'') || !is_xml_char(character, false)
+    }) {
+        return Cow::Borrowed(value);
+    }
+
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Line breaks and tabs would be normalized to spaces by XML parsers
+            '\n' => escaped.push_str("&#10;"),
+            '\r' => escaped.push_str("&#13;"),
+            '\t' => escaped.push_str("&#9;"),
+            character if !is_xml_char(character, true) => {
+                escaped.push(char::REPLACEMENT_CHARACTER);
+            }
+            character => escaped.push(character),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/// Returns `true` if `character` can appear as is in an attribute value.
+///
+/// Line breaks and tabs are allowed by XML 1.0, but they must be escaped, unless
+/// `allow_whitespace` is `true`.
+fn is_xml_char(character: char, allow_whitespace: bool) -> bool {
+    match character {
+        '\n' | '\r' | '\t' => allow_whitespace,
+        '\u{0}'..='\u{1F}' | '\u{FFFE}' | '\u{FFFF}' => false,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::escape;
+
+    #[test]
+    fn escape_replaces_invalid_characters() {
+        assert_eq!(
+            escape("a\u{0}b\u{1B}c\u{FFFF}"),
+            "a\u{FFFD}b\u{FFFD}c\u{FFFD}"
+        );
+        assert_eq!(escape("a\tb\nc"), "a&#9;b&#10;c");
+        assert_eq!(escape("a <b> & c"), "a &lt;b&gt; &amp; c");
+        assert_eq!(escape("émoji 🎉"), "émoji 🎉");
+    }
+}
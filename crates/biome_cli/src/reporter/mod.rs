@@ -1,7 +1,9 @@
+pub(crate) mod checkstyle;
 pub(crate) mod github;
 pub(crate) mod gitlab;
 pub(crate) mod json;
 pub(crate) mod junit;
+pub(crate) mod sarif;
 pub(crate) mod summary;
 pub(crate) mod terminal;
 
@@ -40,6 +42,8 @@ pub struct TraversalSummary {
     pub skipped: usize,
     pub suggested_fixes_skipped: u32,
     pub diagnostics_not_printed: u32,
+    /// Diagnostics that were recorded in, or found in, the baseline
+    pub baselined: u32,
 }
 
 /// When using this trait, the type that implements this trait is the one that holds the read-only information to pass around
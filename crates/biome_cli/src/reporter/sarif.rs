@@ -0,0 +1,680 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary, VERSION};
+use biome_analyze::{
+    GroupCategory, Queryable, RegistryVisitor, Rule, RuleGroup, RuleMetadata, RuleSourceKind,
+};
+use biome_console::fmt::{Display, Formatter};
+use biome_console::{Console, ConsoleExt, markup};
+use biome_diagnostics::advice::{LogCategory, Visit};
+use biome_diagnostics::display::SourceFile;
+use biome_diagnostics::{Error, PrintDescription, Resource, Severity};
+use biome_rowan::{Language, TextRange, TextSize};
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use camino::{Utf8Path, Utf8PathBuf};
+use path_absolutize::Absolutize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+use std::sync::LazyLock;
+use url::Url;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+pub(crate) struct SarifReporter {
+    pub(crate) execution: Execution,
+    pub(crate) diagnostics_payload: DiagnosticsPayload,
+    pub(crate) verbose: bool,
+}
+
+impl Reporter for SarifReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics_payload, self.verbose)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct SarifReporterVisitor<'a> {
+    console: &'a mut dyn Console,
+    repository_root: Option<Utf8PathBuf>,
+}
+
+impl<'a> SarifReporterVisitor<'a> {
+    pub(crate) fn new(console: &'a mut dyn Console, repository_root: Option<Utf8PathBuf>) -> Self {
+        Self {
+            console,
+            repository_root,
+        }
+    }
+}
+
+impl ReporterVisitor for SarifReporterVisitor<'_> {
+    fn report_summary(
+        &mut self,
+        _execution: &Execution,
+        _summary: TraversalSummary,
+        _verbose: bool,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+        verbose: bool,
+    ) -> io::Result<()> {
+        let log = SarifLog::from_payload(&payload, self.repository_root.as_deref(), verbose);
+        self.console.log(markup!({ log }));
+        Ok(())
+    }
+}
+
+/// The root object of a SARIF file.
+/// See https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html#_Toc34317478
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+impl SarifLog {
+    fn from_payload(
+        payload: &DiagnosticsPayload,
+        repository_root: Option<&Utf8Path>,
+        verbose: bool,
+    ) -> Self {
+        let mut rules = Vec::new();
+        let mut rule_indices = BTreeMap::new();
+        let mut results = Vec::new();
+
+        let diagnostics = payload
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity() >= payload.diagnostic_level)
+            .filter(|diagnostic| {
+                if diagnostic.tags().is_verbose() {
+                    verbose
+                } else {
+                    true
+                }
+            });
+
+        for diagnostic in diagnostics {
+            let rule_index = diagnostic.category().map(|category| {
+                *rule_indices.entry(category.name()).or_insert_with(|| {
+                    rules.push(SarifReportingDescriptor::new(
+                        category.name(),
+                        category.link(),
+                        RULES_METADATA.get(category.name()),
+                    ));
+                    rules.len() - 1
+                })
+            });
+
+            results.push(SarifResult::from_diagnostic(
+                diagnostic,
+                rule_index,
+                repository_root,
+            ));
+        }
+
+        Self {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifToolComponent {
+                        name: "Biome",
+                        information_uri: "https://biomejs.dev",
+                        version: VERSION,
+                        rules,
+                    },
+                },
+                original_uri_base_ids: repository_root.map(|root| {
+                    BTreeMap::from([(
+                        SRC_ROOT,
+                        SarifArtifactLocation {
+                            uri: to_directory_uri(root),
+                            uri_base_id: None,
+                        },
+                    )])
+                }),
+                column_kind: "unicodeCodePoints",
+                results,
+            }],
+        }
+    }
+}
+
+impl Display for SarifLog {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let serialized = serde_json::to_string_pretty(self)?;
+        fmt.write_str(serialized.as_str())
+    }
+}
+
+/// The base identifier that the URIs of the results are relative to.
+const SRC_ROOT: &str = "%SRCROOT%";
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    tool: SarifTool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    original_uri_base_ids: Option<BTreeMap<&'static str, SarifArtifactLocation>>,
+    /// Biome computes columns by counting characters, see [SourceFile::location]
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct SarifTool {
+    driver: SarifToolComponent,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifToolComponent {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: Vec<SarifReportingDescriptor>,
+}
+
+/// The metadata of a rule, or of any other diagnostic category emitted by Biome.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReportingDescriptor {
+    /// The category of the diagnostic, e.g. `lint/suspicious/noDebugger`
+    id: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    short_description: Option<SarifMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_configuration: Option<SarifReportingConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<SarifRuleProperties>,
+}
+
+impl SarifReportingDescriptor {
+    fn new(
+        id: &'static str,
+        help_uri: Option<&'static str>,
+        metadata: Option<&RuleMetadata>,
+    ) -> Self {
+        let Some(metadata) = metadata else {
+            return Self {
+                id,
+                name: None,
+                short_description: None,
+                help_uri,
+                default_configuration: None,
+                properties: None,
+            };
+        };
+
+        let summary = metadata
+            .docs
+            .trim()
+            .split("\n\n")
+            .next()
+            .unwrap_or_default()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        Self {
+            id,
+            name: Some(metadata.name),
+            short_description: Some(SarifMessage { text: summary }),
+            help_uri,
+            default_configuration: Some(SarifReportingConfiguration {
+                level: SarifLevel::from(metadata.severity),
+            }),
+            properties: Some(SarifRuleProperties {
+                recommended: metadata.recommended,
+                sources: metadata
+                    .sources
+                    .iter()
+                    .map(|source| {
+                        let (url, rule) = source.source.as_url_and_rule_name();
+                        SarifRuleSource {
+                            name: source.source.to_string(),
+                            rule,
+                            url,
+                            inspired: matches!(source.kind, RuleSourceKind::Inspired),
+                        }
+                    })
+                    .collect(),
+            }),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SarifReportingConfiguration {
+    level: SarifLevel,
+}
+
+#[derive(Serialize)]
+struct SarifRuleProperties {
+    recommended: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    sources: Vec<SarifRuleSource>,
+}
+
+/// A rule of another tool that the Biome rule is a port of, or is inspired by.
+#[derive(Serialize)]
+struct SarifRuleSource {
+    name: String,
+    rule: &'static str,
+    url: String,
+    inspired: bool,
+}
+
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum SarifLevel {
+    Note,
+    Warning,
+    Error,
+}
+
+impl From<Severity> for SarifLevel {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Hint | Severity::Information => Self::Note,
+            Severity::Warning => Self::Warning,
+            Severity::Error | Severity::Fatal => Self::Error,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: SarifLevel,
+    message: SarifMessage,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+impl SarifResult {
+    fn from_diagnostic(
+        diagnostic: &Error,
+        rule_index: Option<usize>,
+        repository_root: Option<&Utf8Path>,
+    ) -> Self {
+        let location = diagnostic.location();
+        let artifact_location = match location.resource {
+            Some(Resource::File(path)) => Some(SarifArtifactLocation::new(path, repository_root)),
+            _ => None,
+        };
+
+        let source = location.source_code.map(SourceFile::new);
+        let region = match (location.span, &source) {
+            (Some(span), Some(source)) => SarifRegion::from_range(source, span),
+            _ => None,
+        };
+
+        let locations = artifact_location
+            .clone()
+            .map(|artifact_location| SarifLocation {
+                physical_location: SarifPhysicalLocation {
+                    artifact_location,
+                    region,
+                },
+            })
+            .into_iter()
+            .collect();
+
+        let fixes = match (artifact_location, location.source_code, &source) {
+            (Some(artifact_location), Some(source_code), Some(source)) => {
+                let mut collector = FixesCollector::default();
+                // The advices are only used to gather the fixes, failing to
+                // collect them shouldn't prevent the result from being reported
+                let _ = diagnostic.advices(&mut collector);
+                collector
+                    .fixes
+                    .into_iter()
+                    .filter_map(|(description, edit)| {
+                        let replacements =
+                            SarifReplacement::from_text_edit(&edit, source_code.text, source);
+                        if replacements.is_empty() {
+                            return None;
+                        }
+
+                        Some(SarifFix {
+                            description: description.map(|text| SarifMessage { text }),
+                            artifact_changes: vec![SarifArtifactChange {
+                                artifact_location: artifact_location.clone(),
+                                replacements,
+                            }],
+                        })
+                    })
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+
+        Self {
+            rule_id: diagnostic.category().map(|category| category.name()),
+            rule_index,
+            level: SarifLevel::from(diagnostic.severity()),
+            message: SarifMessage {
+                text: PrintDescription(diagnostic).to_string(),
+            },
+            locations,
+            fixes,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactLocation {
+    uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri_base_id: Option<&'static str>,
+}
+
+impl SarifArtifactLocation {
+    /// Creates a location relative to the repository root when possible,
+    /// otherwise it falls back to the path as it was reported.
+    fn new(path: &str, repository_root: Option<&Utf8Path>) -> Self {
+        let relativized = repository_root.and_then(|root| {
+            let resolved = Path::new(path).absolutize().ok()?;
+            let relativized = resolved.strip_prefix(root).ok()?;
+            Utf8PathBuf::from_path_buf(relativized.to_path_buf()).ok()
+        });
+
+        match relativized {
+            Some(relativized) => Self {
+                uri: to_uri(relativized.as_str()),
+                uri_base_id: Some(SRC_ROOT),
+            },
+            None => Self {
+                uri: to_uri(path),
+                uri_base_id: None,
+            },
+        }
+    }
+}
+
+/// SARIF requires URIs, which always use forward slashes
+fn to_uri(path: &str) -> String {
+    path.replace('\\', "/")
+}
+
+/// The URI of a base identifier must end with a slash, otherwise it can't be
+/// used to resolve relative URIs.
+///
+/// Absolute paths, including Windows paths such as `C:\repo`, become `file://`
+/// URIs regardless of the platform that produced the report.
+fn to_directory_uri(path: &Utf8Path) -> String {
+    let mut uri = to_uri(path.as_str());
+    if !uri.ends_with('/') {
+        uri.push('/');
+    }
+    let file_uri = if uri.starts_with('/') {
+        format!("file://{uri}")
+    } else if has_drive_letter(&uri) {
+        format!("file:///{uri}")
+    } else {
+        return uri;
+    };
+    // Percent-encodes the characters that aren't allowed in a URI
+    Url::parse(&file_uri).map_or(file_uri, String::from)
+}
+
+/// Returns `true` if the path starts with a Windows drive, such as `C:/`
+fn has_drive_letter(path: &str) -> bool {
+    matches!(path.as_bytes(), [drive, b':', b'/', ..] if drive.is_ascii_alphabetic())
+}
+
+/// A region of an artifact. Lines and columns are one-indexed, and the end
+/// column is exclusive.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+impl SarifRegion {
+    fn from_range(source: &SourceFile, range: TextRange) -> Option<Self> {
+        let start = source.location(range.start()).ok()?;
+        let end = source.location(range.end()).ok()?;
+
+        Some(Self {
+            start_line: start.line_number.get(),
+            start_column: start.column_number.get(),
+            end_line: end.line_number.get(),
+            end_column: end.column_number.get(),
+        })
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<SarifMessage>,
+    artifact_changes: Vec<SarifArtifactChange>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inserted_content: Option<SarifArtifactContent>,
+}
+
+impl SarifReplacement {
+    /// Maps a [TextEdit] computed against `old_text` to a list of
+    /// non-overlapping replacements. Consecutive deletions and insertions are
+    /// merged into a single replacement.
+    fn from_text_edit(edit: &TextEdit, old_text: &str, source: &SourceFile) -> Vec<Self> {
+        let mut replacements = Vec::new();
+        let mut offset = TextSize::from(0);
+        let mut pending: Option<(TextRange, String)> = None;
+
+        let mut flush = |pending: &mut Option<(TextRange, String)>| {
+            if let Some((range, inserted)) = pending.take() {
+                if let Some(deleted_region) = SarifRegion::from_range(source, range) {
+                    replacements.push(Self {
+                        deleted_region,
+                        inserted_content: (!inserted.is_empty())
+                            .then_some(SarifArtifactContent { text: inserted }),
+                    });
+                }
+            }
+        };
+
+        for op in edit.iter() {
+            match op {
+                CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                    flush(&mut pending);
+                    offset += range.len();
+                }
+                CompressedOp::EqualLines { line_count } => {
+                    flush(&mut pending);
+                    let input = &old_text[usize::from(offset)..];
+                    for line in input
+                        .split_inclusive('\n')
+                        .take(line_count.get() as usize + 1)
+                    {
+                        offset += TextSize::of(line);
+                    }
+                }
+                CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                    let (pending_range, _) =
+                        pending.get_or_insert_with(|| (TextRange::empty(offset), String::new()));
+                    *pending_range = pending_range.cover_offset(offset + range.len());
+                    offset += range.len();
+                }
+                CompressedOp::DiffOp(DiffOp::Insert { range }) => {
+                    let (_, inserted) =
+                        pending.get_or_insert_with(|| (TextRange::empty(offset), String::new()));
+                    inserted.push_str(edit.get_text(*range));
+                }
+            }
+        }
+        flush(&mut pending);
+
+        replacements
+    }
+}
+
+#[derive(Serialize)]
+struct SarifArtifactContent {
+    text: String,
+}
+
+/// Collects the code suggestions attached to a diagnostic, as pairs of
+/// description and text edit.
+#[derive(Default)]
+struct FixesCollector {
+    fixes: Vec<(Option<String>, TextEdit)>,
+    last_message: Option<String>,
+}
+
+impl Visit for FixesCollector {
+    fn record_log(
+        &mut self,
+        _category: LogCategory,
+        text: &dyn biome_console::fmt::Display,
+    ) -> io::Result<()> {
+        let markup = markup!({ text }).to_owned();
+        self.last_message = Some(markup.0.into_iter().map(|node| node.content).collect());
+        Ok(())
+    }
+
+    fn record_diff(&mut self, diff: &TextEdit) -> io::Result<()> {
+        self.fixes.push((self.last_message.take(), diff.clone()));
+        Ok(())
+    }
+
+    fn record_group(
+        &mut self,
+        _title: &dyn biome_console::fmt::Display,
+        advice: &dyn biome_diagnostics::Advices,
+    ) -> io::Result<()> {
+        advice.record(self)
+    }
+}
+
+/// The metadata of all the rules known to Biome, indexed by their diagnostic
+/// category.
+///
+/// The registries are only visited once, the first time a report contains the
+/// diagnostic of a rule.
+static RULES_METADATA: LazyLock<BTreeMap<String, RuleMetadata>> = LazyLock::new(|| {
+    let mut visitor = RulesMetadataVisitor {
+        rules: BTreeMap::new(),
+    };
+
+    biome_graphql_analyze::visit_registry(&mut visitor);
+    biome_html_analyze::visit_registry(&mut visitor);
+    biome_css_analyze::visit_registry(&mut visitor);
+    biome_json_analyze::visit_registry(&mut visitor);
+    biome_js_analyze::visit_registry(&mut visitor);
+
+    visitor.rules
+});
+
+/// Collects the metadata of the rules of a registry, indexed by their
+/// diagnostic category.
+struct RulesMetadataVisitor {
+    rules: BTreeMap<String, RuleMetadata>,
+}
+
+impl<L: Language> RegistryVisitor<L> for RulesMetadataVisitor {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Query: Queryable<Language = L, Output: Clone>> + 'static,
+    {
+        let category = <<R::Group as RuleGroup>::Category as GroupCategory>::CATEGORY
+            .as_suppression_category();
+        self.rules.insert(
+            format!(
+                "{category}/{group}/{rule}",
+                group = <R::Group as RuleGroup>::NAME,
+                rule = R::METADATA.name
+            ),
+            R::METADATA,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_directory_uri;
+    use camino::Utf8Path;
+
+    #[test]
+    fn directory_uri_of_unix_path() {
+        assert_eq!(
+            to_directory_uri(Utf8Path::new("/home/user/my repo")),
+            "file:///home/user/my%20repo/"
+        );
+    }
+
+    #[test]
+    fn directory_uri_of_windows_path() {
+        assert_eq!(
+            to_directory_uri(Utf8Path::new("C:\\Users\\user\\repo")),
+            "file:///C:/Users/user/repo/"
+        );
+        assert_eq!(
+            to_directory_uri(Utf8Path::new("D:/repo/")),
+            "file:///D:/repo/"
+        );
+    }
+
+    #[test]
+    fn directory_uri_of_relative_path() {
+        assert_eq!(to_directory_uri(Utf8Path::new("repo")), "repo/");
+    }
+}
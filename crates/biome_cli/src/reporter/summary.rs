@@ -109,6 +109,16 @@ impl ReporterVisitor for SummaryReporterVisitor<'_> {
 
                 if execution.is_check() || execution.is_lint() || execution.is_ci() {
                     if let Some(category) = category {
+                        if category.name() == "lint/nursery/noUnusedExports" {
+                            // Diagnostics without a span are reported for
+                            // the module as a whole.
+                            if diagnostic.location().span.is_none() {
+                                files_to_diagnostics.insert_unreachable_module(location);
+                            } else {
+                                files_to_diagnostics.insert_unused_exports(location);
+                            }
+                        }
+
                         if category.name().starts_with("lint/")
                             || category.name().starts_with("suppressions/")
                             || category.name().starts_with("assist/")
@@ -191,6 +201,8 @@ struct FileToDiagnostics {
     formats: BTreeSet<String>,
     rules: RulesByCategory,
     parse: BTreeSet<String>,
+    unused_exports: BTreeSet<String>,
+    unreachable_modules: BTreeSet<String>,
 }
 
 impl FileToDiagnostics {
@@ -206,6 +218,14 @@ impl FileToDiagnostics {
     fn insert_parse(&mut self, location: &str) {
         self.parse.insert(location.into());
     }
+
+    fn insert_unused_exports(&mut self, location: &str) {
+        self.unused_exports.insert(location.into());
+    }
+
+    fn insert_unreachable_module(&mut self, location: &str) {
+        self.unreachable_modules.insert(location.into());
+    }
 }
 
 #[derive(Debug, Diagnostic)]
@@ -278,6 +298,38 @@ impl Display for FileToDiagnostics {
             })?;
         }
 
+        if !self.unreachable_modules.is_empty() {
+            let diagnostic = SummaryListDiagnostic {
+                message: MessageAndDescription::from(
+                    markup! {
+                        <Warn>"The following files aren't reachable from any entry point."</Warn>
+                    }
+                    .to_owned(),
+                ),
+                list: SummaryListAdvice(&self.unreachable_modules),
+                category: category!("reporter/unreachableModules"),
+            };
+            fmt.write_markup(markup! {
+                {PrintDiagnostic::simple(&diagnostic)}
+            })?;
+        }
+
+        if !self.unused_exports.is_empty() {
+            let diagnostic = SummaryListDiagnostic {
+                message: MessageAndDescription::from(
+                    markup! {
+                        <Warn>"The following files have exports that no module imports."</Warn>
+                    }
+                    .to_owned(),
+                ),
+                list: SummaryListAdvice(&self.unused_exports),
+                category: category!("reporter/unusedExports"),
+            };
+            fmt.write_markup(markup! {
+                {PrintDiagnostic::simple(&diagnostic)}
+            })?;
+        }
+
         if !self.rules.0.is_empty() {
             let diagnostic = LintSummaryDiagnostic {
                 tables: &self.rules,
@@ -1,4 +1,5 @@
 use crate::Reporter;
+use crate::execute::baseline::BaselineMode;
 use crate::execute::{Execution, TraversalMode};
 use crate::reporter::{
     DiagnosticsPayload, EvaluatedPathsDiagnostic, FixedPathsDiagnostic, ReporterVisitor,
@@ -123,7 +124,7 @@ impl ReporterVisitor for ConsoleReporterVisitor<'_> {
         verbose: bool,
     ) -> io::Result<()> {
         for diagnostic in &diagnostics_payload.diagnostics {
-            if execution.is_search() {
+            if execution.is_search() && !execution.is_search_rewrite() {
                 self.0.log(markup! {{PrintDiagnostic::search(diagnostic)}});
                 continue;
             }
@@ -161,7 +162,7 @@ struct SummaryDetail<'a>(pub(crate) &'a TraversalMode, usize);
 impl fmt::Display for SummaryDetail<'_> {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
         let Self(mode, files) = self;
-        if let TraversalMode::Search { .. } = mode {
+        if let TraversalMode::Search { write: false, .. } = mode {
             return Ok(());
         }
 
@@ -267,6 +268,23 @@ impl fmt::Display for ConsoleTraversalSummary<'_> {
             }
         }
 
+        if summary.baselined > 0 {
+            let diagnostics = if summary.baselined == 1 {
+                "diagnostic"
+            } else {
+                "diagnostics"
+            };
+            if mode.baseline().is_some_and(BaselineMode::is_write) {
+                fmt.write_markup(markup!(
+                    "\n"<Info>"Recorded "{summary.baselined}" "{diagnostics}" in the baseline."</Info>
+                ))?;
+            } else {
+                fmt.write_markup(markup!(
+                    "\n"<Info>"Skipped "{summary.baselined}" "{diagnostics}" found in the baseline."</Info>
+                ))?;
+            }
+        }
+
         if let TraversalMode::Search { .. } = mode {
             if summary.matches == 1 {
                 fmt.write_markup(markup!(" "<Info>"Found "{summary.matches}" match."</Info>))?
@@ -0,0 +1,431 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot};
+use biome_console::BufferConsole;
+use biome_fs::{ChangedFile, FileSystemExt, MemoryFileSystem};
+use bpaf::Args;
+use camino::Utf8Path;
+
+const BASELINE: &str = "biome-baseline.json";
+
+/// Returns the paths of the files recorded in the baseline
+fn baseline_files(fs: &MemoryFileSystem) -> Vec<String> {
+    let mut file = fs
+        .open(Utf8Path::new(BASELINE))
+        .expect("the baseline to be written");
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("the baseline to be readable");
+    let baseline: serde_json::Value =
+        serde_json::from_str(&content).expect("the baseline to be valid");
+    baseline["files"]
+        .as_object()
+        .expect("the baseline to have files")
+        .keys()
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn write_baseline_records_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("a.js").into(),
+        "debugger;\ndebugger;\n".as_bytes(),
+    );
+    fs.insert(Utf8Path::new("b.js").into(), "a == b;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--write-baseline", BASELINE, "a.js", "b.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "write_baseline_records_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_reports_only_new_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), "debugger;\na == b;\n".as_bytes());
+
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--write-baseline", BASELINE, file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // The existing diagnostics moved to other lines, and a new one was added
+    fs.insert(
+        file_path.into(),
+        "function f() {\n    debugger;\n}\n\na == b;\nc == d;\n".as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--baseline", BASELINE, file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_reports_only_new_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_prunes_fixed_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8Path::new("a.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Utf8Path::new("b.js").into(), "debugger;\n".as_bytes());
+    fs.insert(Utf8Path::new("c.js").into(), "debugger;\n".as_bytes());
+
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--write-baseline", BASELINE, "a.js", "b.js", "c.js"].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_eq!(baseline_files(&fs), vec!["a.js", "b.js", "c.js"]);
+
+    fs.insert(Utf8Path::new("a.js").into(), "let a;\n".as_bytes());
+    fs.remove(Utf8Path::new("b.js"));
+
+    // `c.js` isn't processed, so its entries are kept
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--baseline", BASELINE, "a.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_eq!(baseline_files(&fs), vec!["c.js"]);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_prunes_fixed_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ci_baseline() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["ci", "--write-baseline", BASELINE, file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["ci", "--baseline", BASELINE, file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ci_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_does_not_exist() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", "--baseline", BASELINE, file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_does_not_exist",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_and_write_baseline_are_incompatible() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--baseline",
+                BASELINE,
+                "--write-baseline",
+                BASELINE,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_and_write_baseline_are_incompatible",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_with_changed_lines_keeps_unchanged_lines() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--write-baseline", BASELINE, file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // Only the second line was added, the diagnostic of the first line is still in the baseline
+    fs.set_on_get_changed_lines(Box::new(|| {
+        vec![ChangedFile {
+            path: String::from("main.js"),
+            lines: vec![2..=2],
+        }]
+    }));
+    fs.insert(file_path.into(), "debugger;\na == b;\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--changed",
+                "--since=main",
+                "--changed-lines",
+                "--baseline",
+                BASELINE,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_eq!(baseline_files(&fs), vec!["main.js"]);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_with_changed_lines_keeps_unchanged_lines",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn write_baseline_and_changed_lines_are_incompatible() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--changed",
+                "--since=main",
+                "--changed-lines",
+                "--write-baseline",
+                BASELINE,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "write_baseline_and_changed_lines_are_incompatible",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn write_baseline_skips_diagnostics_below_the_level() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } } }"#.as_bytes(),
+    );
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), "debugger;\na == b;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--diagnostic-level=error",
+                "--write-baseline",
+                BASELINE,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // The warning wasn't recorded, so it's reported with all the diagnostics
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--baseline", BASELINE, file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "write_baseline_skips_diagnostics_below_the_level",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn write_baseline_and_changed_are_incompatible() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--changed",
+                "--since=main",
+                "--write-baseline",
+                BASELINE,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "write_baseline_and_changed_are_incompatible",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn write_baseline_and_staged_are_incompatible() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "check",
+                "--staged",
+                "--write-baseline",
+                BASELINE,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "write_baseline_and_staged_are_incompatible",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,239 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::{ChangedFile, MemoryFileSystem};
+use bpaf::Args;
+use camino::Utf8Path;
+
+#[test]
+fn lint_reports_only_diagnostics_of_changed_lines() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.set_on_get_changed_lines(Box::new(|| {
+        vec![ChangedFile {
+            path: String::from("file.js"),
+            lines: vec![3..=4],
+        }]
+    }));
+    fs.insert(
+        Utf8Path::new("file.js").into(),
+        "debugger;\nlet a = 1;\nlet b = a == 2;\ndebugger;\n".as_bytes(),
+    );
+    fs.insert(Utf8Path::new("other.js").into(), "debugger;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--changed",
+                "--since=main",
+                "--changed-lines",
+                "file.js",
+                "other.js",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reports_only_diagnostics_of_changed_lines",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_ignores_diagnostics_outside_of_changed_lines() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.set_on_get_staged_lines(Box::new(|| {
+        vec![ChangedFile {
+            path: String::from("file.js"),
+            lines: vec![2..=2],
+        }]
+    }));
+    fs.insert(
+        Utf8Path::new("file.js").into(),
+        "debugger;\nlet a = 1;\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--staged", "--changed-lines"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_ignores_diagnostics_outside_of_changed_lines",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_writes_only_changed_lines() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.set_on_get_staged_lines(Box::new(|| {
+        vec![ChangedFile {
+            path: String::from("file.js"),
+            lines: vec![2..=2, 4..=4],
+        }]
+    }));
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "let   a  =  1;\nlet   b  =  2;\nlet   c  =  3;\nlet   d  =  4;\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", "--staged", "--changed-lines"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "let   a  =  1;\nlet b = 2;\nlet   c  =  3;\nlet d = 4;\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_writes_only_changed_lines",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_writes_adjacent_changed_lines_of_the_same_statement() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.set_on_get_staged_lines(Box::new(|| {
+        vec![ChangedFile {
+            path: String::from("file.js"),
+            lines: vec![2..=2, 3..=3],
+        }]
+    }));
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(
+        file_path.into(),
+        "let   a  =  1;\nfunction   f(  x  )  {\n  let   y  =  x;\n}\nlet   b  =  2;\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", "--staged", "--changed-lines"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "let   a  =  1;\nfunction f(x) {\n\tlet y = x;\n}\nlet   b  =  2;\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_writes_adjacent_changed_lines_of_the_same_statement",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ci_reports_format_differences_of_changed_lines() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.set_on_get_changed_lines(Box::new(|| {
+        vec![
+            ChangedFile {
+                path: String::from("changed.js"),
+                lines: vec![1..=1],
+            },
+            ChangedFile {
+                path: String::from("formatted.js"),
+                lines: vec![1..=1],
+            },
+        ]
+    }));
+    fs.insert(
+        Utf8Path::new("changed.js").into(),
+        "let   a  =  1;\nlet   b  =  2;\n".as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("formatted.js").into(),
+        "let a = 1;\nlet   b  =  2;\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "ci",
+                "--linter-enabled=false",
+                "--changed",
+                "--since=main",
+                "--changed-lines",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ci_reports_format_differences_of_changed_lines",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn changed_lines_requires_changed_or_staged() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8Path::new("file.js").into(), "debugger;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", "--changed-lines", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "changed_lines_requires_changed_or_staged",
+        fs,
+        console,
+        result,
+    ));
+}
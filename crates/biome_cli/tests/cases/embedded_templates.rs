@@ -0,0 +1,146 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const CONFIG: &str = r#"{
+    "javascript": {
+        "formatter": {
+            "embeddedLanguageFormatting": "auto"
+        }
+    }
+}"#;
+
+const UNFORMATTED: &str = r#"const Button = styled.button`
+color:   ${props => props.color};
+  &:hover { color : blue }
+`;
+
+const query = gql`query { user { id, ...UserFields } }`;
+"#;
+
+const FORMATTED: &str = r#"const Button = styled.button`
+	color: ${(props) => props.color};
+	&:hover {
+		color: blue;
+	}
+`;
+
+const query = gql`
+	query {
+		user {
+			id
+			...UserFields
+		}
+	}
+`;
+"#;
+
+const LINT_ERRORS: &str = r#"const Button = styled.button`
+  color: ${props => props.color};
+  color: blue;
+`;
+
+const query = gql`
+  query {
+    member @deprecated { id }
+  }
+`;
+"#;
+
+#[test]
+fn format_embedded_templates() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8Path::new("biome.json").into(), CONFIG.as_bytes());
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_embedded_templates",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_embedded_templates() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8Path::new("biome.json").into(), CONFIG.as_bytes());
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), LINT_ERRORS.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--only=suspicious/noDuplicateProperties",
+                "--only=style/useDeprecatedReason",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_embedded_templates",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_not_lint_embedded_templates_by_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), LINT_ERRORS.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--only=suspicious/noDuplicateProperties",
+                "--only=style/useDeprecatedReason",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_lint_embedded_templates_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,119 @@
+use crate::run_cli_with_server_workspace;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const CONFIGURATION: &str = r#"{
+    "linter": {
+        "rules": {
+            "nursery": {
+                "noInvalidArguments": "error",
+                "noUnknownFields": "error",
+                "noUnknownTypes": "error"
+            }
+        }
+    },
+    "graphql": { "schema": ["schema/*.graphql"] }
+}"#;
+
+const QUERY_TYPE: &str = r#"type Query {
+  user(id: ID!): User
+}
+"#;
+
+const USER_TYPE: &str = r#"type User {
+  id: ID!
+  name: String
+}
+"#;
+
+#[test]
+fn lint_operations_against_configured_schema() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8Path::new("biome.json").into(), CONFIGURATION.as_bytes());
+    fs.insert(
+        Utf8Path::new("schema/query.graphql").into(),
+        QUERY_TYPE.as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("schema/user.graphql").into(),
+        USER_TYPE.as_bytes(),
+    );
+    let file_path = Utf8Path::new("src/user.graphql");
+    fs.insert(
+        file_path.into(),
+        r#"query User($id: ID!) {
+  user(id: $id, first: 10) {
+    name
+    email
+  }
+}
+
+fragment UserFields on Person {
+  id
+}
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_operations_against_configured_schema",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_operations_without_schema() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "linter": { "rules": { "nursery": { "noUnknownFields": "error" } } }
+}"#
+        .as_bytes(),
+    );
+    let file_path = Utf8Path::new("src/user.graphql");
+    fs.insert(
+        file_path.into(),
+        r#"query User {
+  user {
+    email
+  }
+}
+"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_operations_without_schema",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -106,6 +106,85 @@ const slots = defineSlots<{
 </script>
 <template></template>"#;
 
+const VUE_FILE_STYLE_UNFORMATTED: &str = r#"<script setup lang="ts">
+const a     =     "a";
+</script>
+<template></template>
+<style>
+.a  {  color :red   }
+</style>
+<style lang="scss">
+.b  {  color :$red   }
+</style>
+<style lang="less">
+.d  {  color :@red   }
+</style>
+<style lang="stylus">
+.c  {  color :red   }
+</style>"#;
+
+const VUE_FILE_STYLE_FORMATTED: &str = r#"<script setup lang="ts">
+const a = "a";
+</script>
+<template></template>
+<style>
+.a {
+	color: red;
+}
+</style>
+<style lang="scss">
+.b {
+	color: $red;
+}
+</style>
+<style lang="less">
+.d {
+	color: @red;
+}
+</style>
+<style lang="stylus">
+.c  {  color :red   }
+</style>"#;
+
+const VUE_FILE_STYLE_NOT_LINTED: &str = r#"<template></template>
+<style>
+a {
+	width: 10pixels;
+}
+</style>"#;
+
+const VUE_FILE_TEMPLATE_REFERENCES: &str = r#"<script setup lang="ts">
+import { ref } from "vue";
+import MyButton from "./MyButton.vue";
+import Counter from "./Counter.vue";
+import { format } from "./format";
+import { unused } from "./unused";
+
+const count = ref(0);
+const title = "Hello";
+const show = true;
+function increment() {
+	count.value++;
+}
+const notUsed = 1;
+</script>
+<template>
+	<h1 v-if="show">{{ format(title) }}</h1>
+	<my-button @click="increment()">Increment</my-button>
+	<Counter :count="count" />
+</template>"#;
+
+const VUE_FILE_SCRIPT_AND_SCRIPT_SETUP: &str = r#"<script lang="ts">
+import { defineComponent } from "vue";
+const notUsed = 1;
+const shared = "shared";
+export default defineComponent({ inheritAttrs: false });
+</script>
+<script setup lang="ts">
+console.log(shared);
+</script>
+"#;
+
 #[test]
 fn format_vue_implicit_js_files() {
     let mut fs = MemoryFileSystem::default();
@@ -753,3 +832,127 @@ fn vue_compiler_macros_as_globals() {
         result,
     ));
 }
+
+#[test]
+fn format_vue_style_blocks_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Utf8Path::new("file.vue");
+    fs.insert(vue_file_path.into(), VUE_FILE_STYLE_UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", vue_file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, vue_file_path, VUE_FILE_STYLE_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_vue_style_blocks_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_vue_style_blocks() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Utf8Path::new("file.vue");
+    fs.insert(vue_file_path.into(), VUE_FILE_STYLE_NOT_LINTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", vue_file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_vue_style_blocks",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_vue_template_references() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Utf8Path::new("file.vue");
+    fs.insert(
+        vue_file_path.into(),
+        VUE_FILE_TEMPLATE_REFERENCES.as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--only=correctness/noUnusedImports",
+                "--only=correctness/noUnusedVariables",
+                vue_file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert_file_contents(&fs, vue_file_path, VUE_FILE_TEMPLATE_REFERENCES);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_vue_template_references",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_vue_script_and_script_setup() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let vue_file_path = Utf8Path::new("file.vue");
+    fs.insert(
+        vue_file_path.into(),
+        VUE_FILE_SCRIPT_AND_SCRIPT_SETUP.as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--only=correctness/noUnusedVariables",
+                "--only=correctness/noUndeclaredVariables",
+                vue_file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert_file_contents(&fs, vue_file_path, VUE_FILE_SCRIPT_AND_SCRIPT_SETUP);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_vue_script_and_script_setup",
+        fs,
+        console,
+        result,
+    ));
+}
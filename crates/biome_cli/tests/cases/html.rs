@@ -0,0 +1,148 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const INACCESSIBLE: &str = r#"<html>
+<body>
+<img src="cat.png">
+<div tabindex="1">Cat</div>
+</body>
+</html>
+"#;
+
+const INACCESSIBLE_FIXED: &str = r#"<html>
+<body>
+<img src="cat.png">
+<div tabindex="0">Cat</div>
+</body>
+</html>
+"#;
+
+#[test]
+fn lint_html_files_disabled_by_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.html");
+    fs.insert(file_path.into(), INACCESSIBLE.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_html_files_disabled_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_html_files_when_enabled() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{ "html": { "linter": { "enabled": true } } }"#.as_bytes(),
+    );
+    let file_path = Utf8Path::new("file.html");
+    fs.insert(file_path.into(), INACCESSIBLE.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_html_files_when_enabled",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_and_fix_html_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.html");
+    fs.insert(file_path.into(), INACCESSIBLE.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--html-linter-enabled=true",
+                "--only=a11y/noPositiveTabindex",
+                "--write",
+                "--unsafe",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, INACCESSIBLE_FIXED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_and_fix_html_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn suppress_html_files_is_not_supported() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.html");
+    fs.insert(file_path.into(), INACCESSIBLE.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--html-linter-enabled=true",
+                "--suppress",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, INACCESSIBLE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "suppress_html_files_is_not_supported",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,106 @@
+use crate::run_cli_with_server_workspace;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const SCHEMA: &str = r#"{
+    "type": "object",
+    "properties": {
+        "$schema": { "type": "string" },
+        "level": { "enum": ["info", "warn", "error"] },
+        "retries": { "type": "integer" }
+    },
+    "additionalProperties": false
+}"#;
+
+#[test]
+fn lint_json_files_with_configured_schemas() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "linter": { "rules": { "nursery": { "noJsonSchemaViolations": "error" } } },
+    "json": {
+        "schemas": [
+            { "includes": ["packages/*/settings.json"], "schema": "./schemas/settings.json" }
+        ]
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("schemas/settings.json").into(),
+        SCHEMA.as_bytes(),
+    );
+    let file_path = Utf8Path::new("packages/app/settings.json");
+    fs.insert(
+        file_path.into(),
+        r#"{ "level": "debug", "retries": "3" }"#.as_bytes(),
+    );
+    let other_file_path = Utf8Path::new("packages/app/other.json");
+    fs.insert(other_file_path.into(), r#"{ "level": "debug" }"#.as_bytes());
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str(), other_file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_json_files_with_configured_schemas",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_json_files_with_local_schema_reference() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "linter": { "rules": { "nursery": { "noJsonSchemaViolations": "error" } } },
+    "json": {
+        "schemas": [
+            { "includes": ["**/*.json"], "schema": "./schemas/missing.json" }
+        ]
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("config/schema.json").into(),
+        SCHEMA.as_bytes(),
+    );
+    let file_path = Utf8Path::new("config/settings.json");
+    fs.insert(
+        file_path.into(),
+        r#"{ "$schema": "./schema.json", "level": "warn", "verbose": true }"#.as_bytes(),
+    );
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_json_files_with_local_schema_reference",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,163 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const CONFIG: &str = r#"{ "markdown": { "formatter": { "enabled": true } } }"#;
+
+const UNFORMATTED: &str = r#"Title
+=====
+
+* one
+* two
+
+| a | b |
+|---|:-:|
+| long cell | c |
+
+```js
+const  a   =   {b:1}
+```
+
+```json
+{"key":   [1,2]}
+```
+
+```css
+a{color:red}
+```
+
+```ts
+const broken = ;
+```
+
+```python
+def  f():  pass
+```
+"#;
+
+const FORMATTED: &str = r#"# Title
+
+- one
+- two
+
+| a         |  b  |
+| --------- | :-: |
+| long cell |  c  |
+
+```js
+const a = { b: 1 };
+```
+
+```json
+{ "key": [1, 2] }
+```
+
+```css
+a {
+	color: red;
+}
+```
+
+```ts
+const broken = ;
+```
+
+```python
+def  f():  pass
+```
+"#;
+
+#[test]
+fn format_markdown_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8Path::new("biome.json").into(), CONFIG.as_bytes());
+    let file_path = Utf8Path::new("file.md");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_markdown_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_and_write_markdown_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8Path::new("biome.json").into(), CONFIG.as_bytes());
+    let file_path = Utf8Path::new("file.md");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_and_write_markdown_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn doesnt_format_code_blocks_when_language_formatter_is_disabled() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "markdown": { "formatter": { "enabled": true } },
+    "javascript": { "formatter": { "enabled": false } }
+}"#
+        .as_bytes(),
+    );
+    let file_path = Utf8Path::new("file.md");
+    let content = "```js\nconst  a   =   1\n```\n";
+    fs.insert(file_path.into(), content.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, content);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "doesnt_format_code_blocks_when_language_formatter_is_disabled",
+        fs,
+        console,
+        result,
+    ));
+}
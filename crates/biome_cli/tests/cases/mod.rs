@@ -2,20 +2,27 @@
 //! case that affects many commands
 
 mod assist;
+mod baseline;
 mod biome_json_support;
+mod changed_lines;
 mod config_extends;
 mod config_path;
 mod cts_files;
 mod diagnostics;
 mod editorconfig;
+mod embedded_templates;
 mod graphql;
+mod graphql_schema;
 mod handle_astro_files;
 mod handle_css_files;
 mod handle_svelte_files;
 mod handle_vue_files;
+mod html;
 mod included_files;
+mod json_schemas;
 mod linter_domains;
 mod linter_groups_plain;
+mod markdown;
 mod migrate_v2;
 mod monorepo;
 mod overrides_formatter;
@@ -23,12 +30,16 @@ mod overrides_linter;
 mod overrides_max_file_size;
 mod overrides_organize_imports;
 mod protected_files;
+mod reporter_checkstyle;
 mod reporter_github;
 mod reporter_gitlab;
 mod reporter_junit;
+mod reporter_sarif;
 mod reporter_summary;
 mod reporter_terminal;
+mod result_cache;
 mod rules_via_dependencies;
 mod suppressions;
 mod unknown_files;
 mod vcs_ignored_files;
+mod yaml;
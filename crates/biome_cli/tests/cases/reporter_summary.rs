@@ -1,7 +1,7 @@
-use crate::run_cli;
 use crate::snap_test::{SnapshotPayload, assert_cli_snapshot};
+use crate::{run_cli, run_cli_with_dyn_fs};
 use biome_console::BufferConsole;
-use biome_fs::MemoryFileSystem;
+use biome_fs::{MemoryFileSystem, TemporaryFs};
 use bpaf::Args;
 use camino::Utf8Path;
 
@@ -255,3 +255,45 @@ fn reports_diagnostics_summary_check_verbose_command() {
         result,
     ));
 }
+
+#[test]
+fn reports_unused_exports_and_unreachable_modules() {
+    let mut console = BufferConsole::default();
+    let mut fs = TemporaryFs::new("reports_unused_exports_and_unreachable_modules");
+
+    fs.create_file(
+        "biome.json",
+        r#"{ "linter": { "rules": { "nursery": { "noUnusedExports": "warn" } } } }
+"#,
+    );
+    fs.create_file(
+        "package.json",
+        r#"{ "main": "./index.js" }
+"#,
+    );
+    fs.create_file(
+        "index.js",
+        "import { used } from \"./utils.js\";\n\nused();\n",
+    );
+    fs.create_file(
+        "utils.js",
+        "export function used() {}\n\nexport function unused() {}\n",
+    );
+    fs.create_file("orphan.js", "export function orphan() {}\n");
+
+    let result = run_cli_with_dyn_fs(
+        Box::new(fs.create_os()),
+        &mut console,
+        Args::from(["lint", "--reporter=summary", fs.cli_path()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_unused_exports_and_unreachable_modules",
+        fs.create_mem(),
+        console,
+        result,
+    ));
+}
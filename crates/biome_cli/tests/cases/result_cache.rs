@@ -0,0 +1,531 @@
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot};
+use crate::{run_cli, run_cli_with_dyn_fs, run_cli_with_server_workspace};
+use biome_console::BufferConsole;
+use biome_fs::{FileSystemExt, MemoryFileSystem, TemporaryFs};
+use bpaf::Args;
+use camino::Utf8Path;
+
+const CACHE_LOCATION: &str = "biome-cache.json";
+
+/// Returns the paths of the files stored in the cache
+fn cached_files(fs: &MemoryFileSystem) -> Vec<String> {
+    let mut file = fs
+        .open(Utf8Path::new(CACHE_LOCATION))
+        .expect("the cache to be written");
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("the cache to be readable");
+    let cache: serde_json::Value = serde_json::from_str(&content).expect("the cache to be valid");
+    let mut files: Vec<_> = cache["entries"]
+        .as_object()
+        .expect("the cache to have entries")
+        .keys()
+        .cloned()
+        .collect();
+    files.sort();
+    files
+}
+
+#[test]
+fn check_replays_cached_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), "debugger;\nlet  a = 1;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "check",
+                "--cache-location",
+                CACHE_LOCATION,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_eq!(cached_files(&fs), vec!["main.js"]);
+
+    let mut console = BufferConsole::default();
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "check",
+                "--cache-location",
+                CACHE_LOCATION,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_eq!(cached_files(&fs), vec!["main.js"]);
+
+    fs.remove(Utf8Path::new(CACHE_LOCATION));
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_replays_cached_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_invalidates_cache_when_file_changes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "check",
+                "--cache-location",
+                CACHE_LOCATION,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.insert(file_path.into(), "let a = 1;\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "check",
+                "--cache-location",
+                CACHE_LOCATION,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.remove(Utf8Path::new(CACHE_LOCATION));
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_invalidates_cache_when_file_changes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_invalidates_cache_when_configuration_changes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "check",
+                "--cache-location",
+                CACHE_LOCATION,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "check",
+                "--cache-location",
+                CACHE_LOCATION,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.remove(Utf8Path::new(CACHE_LOCATION));
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_invalidates_cache_when_configuration_changes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_invalidates_cache_when_dependency_changes() {
+    let mut console = BufferConsole::default();
+    let mut fs = TemporaryFs::new("check_invalidates_cache_when_dependency_changes");
+
+    fs.create_file(
+        "biome.json",
+        r#"{ "linter": { "domains": { "project": "recommended" } } }
+"#,
+    );
+    fs.create_file(
+        "main.js",
+        "import { secret } from \"./secret.js\";\n\nsecret();\n",
+    );
+    fs.create_file("secret.js", "export function secret() {}\n");
+
+    let result = run_cli_with_dyn_fs(
+        Box::new(fs.create_os()),
+        &mut console,
+        Args::from(["check", "--cache-location", CACHE_LOCATION, fs.cli_path()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.create_file(
+        "secret.js",
+        "/** @private */\nexport function secret() {}\n",
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli_with_dyn_fs(
+        Box::new(fs.create_os()),
+        &mut console,
+        Args::from(["check", "--cache-location", CACHE_LOCATION, fs.cli_path()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_invalidates_cache_when_dependency_changes",
+        fs.create_mem(),
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_invalidates_cache_when_dependent_changes() {
+    let mut console = BufferConsole::default();
+    let mut fs = TemporaryFs::new("check_invalidates_cache_when_dependent_changes");
+
+    fs.create_file(
+        "biome.json",
+        r#"{ "linter": { "rules": { "nursery": { "noUnusedExports": "error" } } } }
+"#,
+    );
+    fs.create_file("package.json", "{\n\t\"main\": \"main.js\"\n}\n");
+    fs.create_file("main.js", "import { foo } from \"./lib.js\";\n\nfoo();\n");
+    fs.create_file("lib.js", "export function foo() {}\n");
+
+    let result = run_cli_with_dyn_fs(
+        Box::new(fs.create_os()),
+        &mut console,
+        Args::from(["check", "--cache-location", CACHE_LOCATION, fs.cli_path()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // Only the importer changes, but `foo` isn't used anymore
+    fs.create_file("main.js", "import \"./lib.js\";\n");
+
+    let mut console = BufferConsole::default();
+    let result = run_cli_with_dyn_fs(
+        Box::new(fs.create_os()),
+        &mut console,
+        Args::from(["check", "--cache-location", CACHE_LOCATION, fs.cli_path()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_invalidates_cache_when_dependent_changes",
+        fs.create_mem(),
+        console,
+        result,
+    ));
+
+    // Importing `foo` again makes the diagnostic disappear
+    fs.create_file("main.js", "import { foo } from \"./lib.js\";\n\nfoo();\n");
+
+    let mut console = BufferConsole::default();
+    let result = run_cli_with_dyn_fs(
+        Box::new(fs.create_os()),
+        &mut console,
+        Args::from(["check", "--cache-location", CACHE_LOCATION, fs.cli_path()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn check_invalidates_cache_when_manifest_changes() {
+    let mut console = BufferConsole::default();
+    let mut fs = TemporaryFs::new("check_invalidates_cache_when_manifest_changes");
+
+    fs.create_file(
+        "biome.json",
+        "{\n\t\"linter\": {\n\t\t\"rules\": { \"correctness\": { \"noUndeclaredDependencies\": \"error\" } }\n\t}\n}\n",
+    );
+    fs.create_file(
+        "package.json",
+        "{\n\t\"dependencies\": {\n\t\t\"react\": \"^19.0.0\"\n\t}\n}\n",
+    );
+    fs.create_file("main.js", "import React from \"react\";\n\nReact;\n");
+
+    let result = run_cli_with_dyn_fs(
+        Box::new(fs.create_os()),
+        &mut console,
+        Args::from(["check", "--cache-location", CACHE_LOCATION, fs.cli_path()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.create_file("package.json", "{\n\t\"dependencies\": {}\n}\n");
+
+    let mut console = BufferConsole::default();
+    let result = run_cli_with_dyn_fs(
+        Box::new(fs.create_os()),
+        &mut console,
+        Args::from(["check", "--cache-location", CACHE_LOCATION, fs.cli_path()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_invalidates_cache_when_manifest_changes",
+        fs.create_mem(),
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_invalidates_cache_when_json_schema_changes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "linter": { "rules": { "nursery": { "noJsonSchemaViolations": "error" } } },
+    "json": { "schemas": [{ "includes": ["*.json"], "schema": "./schema.json" }] }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("schema.json").into(),
+        r#"{ "properties": { "level": { "enum": ["info", "error"] } } }"#.as_bytes(),
+    );
+    let file_path = Utf8Path::new("settings.json");
+    fs.insert(file_path.into(), "{ \"level\": \"info\" }\n".as_bytes());
+
+    let (mut fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "check",
+                "--cache-location",
+                CACHE_LOCATION,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_eq!(cached_files(&fs), vec!["settings.json"]);
+
+    // Only the schema changes, but `info` isn't allowed anymore
+    fs.insert(
+        Utf8Path::new("schema.json").into(),
+        r#"{ "properties": { "level": { "enum": ["error"] } } }"#.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (mut fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "check",
+                "--cache-location",
+                CACHE_LOCATION,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.remove(Utf8Path::new(CACHE_LOCATION));
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_invalidates_cache_when_json_schema_changes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_invalidates_cache_when_graphql_schema_changes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "linter": { "rules": { "nursery": { "noUnknownFields": "error" } } },
+    "graphql": { "schema": ["schema/*.graphql"] }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("schema/schema.graphql").into(),
+        "type Query {\n  user: User\n}\n\ntype User {\n  id: ID!\n  name: String\n}\n".as_bytes(),
+    );
+    let file_path = Utf8Path::new("src/user.graphql");
+    fs.insert(
+        file_path.into(),
+        "query User {\n\tuser {\n\t\tname\n\t}\n}\n".as_bytes(),
+    );
+
+    let (mut fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "check",
+                "--cache-location",
+                CACHE_LOCATION,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_eq!(cached_files(&fs), vec!["src/user.graphql"]);
+
+    // Only the schema changes, but `name` isn't a field of `User` anymore
+    fs.insert(
+        Utf8Path::new("schema/schema.graphql").into(),
+        "type Query {\n  user: User\n}\n\ntype User {\n  id: ID!\n}\n".as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (mut fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "check",
+                "--cache-location",
+                CACHE_LOCATION,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.remove(Utf8Path::new(CACHE_LOCATION));
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_invalidates_cache_when_graphql_schema_changes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_doesnt_use_cache_when_writing() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), "let  a = 1;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "check",
+                "--write",
+                "--cache-location",
+                CACHE_LOCATION,
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(
+        fs.open(Utf8Path::new(CACHE_LOCATION)).is_err(),
+        "the cache shouldn't be written"
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_doesnt_use_cache_when_writing",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ci_replays_cached_format_diff() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), "let  a = 1;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["ci", "--cache-location", CACHE_LOCATION, file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_eq!(cached_files(&fs), vec!["main.js"]);
+
+    let mut console = BufferConsole::default();
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["ci", "--cache-location", CACHE_LOCATION, file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.remove(Utf8Path::new(CACHE_LOCATION));
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ci_replays_cached_format_diff",
+        fs,
+        console,
+        result,
+    ));
+}
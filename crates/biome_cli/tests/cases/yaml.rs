@@ -0,0 +1,146 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const CONFIG: &str = r#"{ "yaml": { "formatter": { "enabled": true } } }"#;
+
+const UNFORMATTED: &str = r#"key:   'value'
+nested:
+    list:
+    -   a
+    -   b
+flow: [ a,b ]
+"#;
+
+const FORMATTED: &str = r#"key: "value"
+nested:
+  list:
+    - a
+    - b
+flow: [a, b]
+"#;
+
+#[test]
+fn format_yaml_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8Path::new("biome.json").into(), CONFIG.as_bytes());
+    let file_path = Utf8Path::new("file.yaml");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_yaml_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_and_write_yaml_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Utf8Path::new("biome.json").into(), CONFIG.as_bytes());
+    let file_path = Utf8Path::new("file.yml");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_and_write_yaml_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_yaml_files_with_cli_options() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.yaml");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                "--yaml-formatter-enabled=true",
+                "--yaml-formatter-indent-width=4",
+                "--yaml-formatter-quote-style=single",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        "key: 'value'\nnested:\n    list:\n        - a\n        - b\nflow: [a, b]\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_yaml_files_with_cli_options",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn doesnt_format_yaml_files_by_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.yaml");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", file_path.as_str()].as_slice()),
+    );
+
+    assert_file_contents(&fs, file_path, UNFORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "doesnt_format_yaml_files_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -2568,6 +2568,33 @@ fn should_error_if_changed_flag_and_staged_flag_are_active_at_the_same_time() {
     ));
 }
 
+#[test]
+fn should_error_if_since_revision_does_not_exist() {
+    let mut console = BufferConsole::default();
+    let mut fs = TemporaryFs::new("should_error_if_since_revision_does_not_exist");
+
+    // A repository without any commit
+    fs.create_file(".git/HEAD", "ref: refs/heads/main\n");
+    fs.create_folder(".git/objects");
+    fs.create_file("file.js", r#"console.log('file');"#);
+
+    let result = run_cli_with_dyn_fs(
+        Box::new(fs.create_os()),
+        &mut console,
+        Args::from(["lint", "--changed", "--since=missing", fs.cli_path()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_error_if_since_revision_does_not_exist",
+        fs.create_mem(),
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn should_only_processes_staged_files_when_staged_flag_is_set() {
     let mut console = BufferConsole::default();
@@ -5,7 +5,7 @@ use camino::Utf8Path;
 
 use crate::{
     run_cli,
-    snap_test::{SnapshotPayload, assert_cli_snapshot},
+    snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents},
 };
 
 // Feel free to add content at the end of this dummy file. It shouldn't affect
@@ -169,3 +169,96 @@ fn search_css_pattern_skips_js_files() {
         result,
     ));
 }
+
+#[test]
+fn search_rewrite_js_pattern() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), "foo( 'a' );\nbaz('b');\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--rewrite",
+                "`foo($a)` => `bar($a)`",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "foo( 'a' );\nbaz('b');\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_rewrite_js_pattern",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_rewrite_js_pattern_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), "foo( 'a' );\nbaz('b');\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--rewrite",
+                "--write",
+                "`foo($a)` => `bar($a)`",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "bar(\"a\");\nbaz(\"b\");\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_rewrite_js_pattern_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_write_requires_rewrite() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["search", "--write", "`foo($a)` => `bar($a)`", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_write_requires_rewrite",
+        fs,
+        console,
+        result,
+    ));
+}
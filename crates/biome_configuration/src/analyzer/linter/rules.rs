@@ -191,13 +191,16 @@ pub enum RuleName {
     NoInferrableTypes,
     NoInnerDeclarations,
     NoInteractiveElementToNoninteractiveRole,
+    NoInvalidArguments,
     NoInvalidBuiltinInstantiation,
     NoInvalidConstructorSuper,
     NoInvalidDirectionInLinearGradient,
+    NoInvalidFragmentTypes,
     NoInvalidGridAreas,
     NoInvalidPositionAtImportRule,
     NoInvalidUseBeforeDeclaration,
     NoIrregularWhitespace,
+    NoJsonSchemaViolations,
     NoLabelVar,
     NoLabelWithoutControl,
     NoMagicNumbers,
@@ -262,13 +265,17 @@ pub enum RuleName {
     NoUnassignedVariables,
     NoUndeclaredDependencies,
     NoUndeclaredVariables,
+    NoUndefinedVariables,
     NoUnknownAtRule,
+    NoUnknownDirectives,
+    NoUnknownFields,
     NoUnknownFunction,
     NoUnknownMediaFeatureName,
     NoUnknownProperty,
     NoUnknownPseudoClass,
     NoUnknownPseudoElement,
     NoUnknownTypeSelector,
+    NoUnknownTypes,
     NoUnknownUnit,
     NoUnmatchableAnbSelector,
     NoUnreachable,
@@ -278,11 +285,13 @@ pub enum RuleName {
     NoUnsafeFinally,
     NoUnsafeNegation,
     NoUnsafeOptionalChaining,
+    NoUnusedExports,
     NoUnusedFunctionParameters,
     NoUnusedImports,
     NoUnusedLabels,
     NoUnusedPrivateClassMembers,
     NoUnusedTemplateLiteral,
+    NoUnusedVariableDefinitions,
     NoUnusedVariables,
     NoUnwantedPolyfillio,
     NoUselessBackrefInRegex,
@@ -528,13 +537,16 @@ impl RuleName {
             Self::NoInteractiveElementToNoninteractiveRole => {
                 "noInteractiveElementToNoninteractiveRole"
             }
+            Self::NoInvalidArguments => "noInvalidArguments",
             Self::NoInvalidBuiltinInstantiation => "noInvalidBuiltinInstantiation",
             Self::NoInvalidConstructorSuper => "noInvalidConstructorSuper",
             Self::NoInvalidDirectionInLinearGradient => "noInvalidDirectionInLinearGradient",
+            Self::NoInvalidFragmentTypes => "noInvalidFragmentTypes",
             Self::NoInvalidGridAreas => "noInvalidGridAreas",
             Self::NoInvalidPositionAtImportRule => "noInvalidPositionAtImportRule",
             Self::NoInvalidUseBeforeDeclaration => "noInvalidUseBeforeDeclaration",
             Self::NoIrregularWhitespace => "noIrregularWhitespace",
+            Self::NoJsonSchemaViolations => "noJsonSchemaViolations",
             Self::NoLabelVar => "noLabelVar",
             Self::NoLabelWithoutControl => "noLabelWithoutControl",
             Self::NoMagicNumbers => "noMagicNumbers",
@@ -601,13 +613,17 @@ impl RuleName {
             Self::NoUnassignedVariables => "noUnassignedVariables",
             Self::NoUndeclaredDependencies => "noUndeclaredDependencies",
             Self::NoUndeclaredVariables => "noUndeclaredVariables",
+            Self::NoUndefinedVariables => "noUndefinedVariables",
             Self::NoUnknownAtRule => "noUnknownAtRule",
+            Self::NoUnknownDirectives => "noUnknownDirectives",
+            Self::NoUnknownFields => "noUnknownFields",
             Self::NoUnknownFunction => "noUnknownFunction",
             Self::NoUnknownMediaFeatureName => "noUnknownMediaFeatureName",
             Self::NoUnknownProperty => "noUnknownProperty",
             Self::NoUnknownPseudoClass => "noUnknownPseudoClass",
             Self::NoUnknownPseudoElement => "noUnknownPseudoElement",
             Self::NoUnknownTypeSelector => "noUnknownTypeSelector",
+            Self::NoUnknownTypes => "noUnknownTypes",
             Self::NoUnknownUnit => "noUnknownUnit",
             Self::NoUnmatchableAnbSelector => "noUnmatchableAnbSelector",
             Self::NoUnreachable => "noUnreachable",
@@ -617,11 +633,13 @@ impl RuleName {
             Self::NoUnsafeFinally => "noUnsafeFinally",
             Self::NoUnsafeNegation => "noUnsafeNegation",
             Self::NoUnsafeOptionalChaining => "noUnsafeOptionalChaining",
+            Self::NoUnusedExports => "noUnusedExports",
             Self::NoUnusedFunctionParameters => "noUnusedFunctionParameters",
             Self::NoUnusedImports => "noUnusedImports",
             Self::NoUnusedLabels => "noUnusedLabels",
             Self::NoUnusedPrivateClassMembers => "noUnusedPrivateClassMembers",
             Self::NoUnusedTemplateLiteral => "noUnusedTemplateLiteral",
+            Self::NoUnusedVariableDefinitions => "noUnusedVariableDefinitions",
             Self::NoUnusedVariables => "noUnusedVariables",
             Self::NoUnwantedPolyfillio => "noUnwantedPolyfillio",
             Self::NoUselessBackrefInRegex => "noUselessBackrefInRegex",
@@ -865,13 +883,16 @@ impl RuleName {
             Self::NoInferrableTypes => RuleGroup::Style,
             Self::NoInnerDeclarations => RuleGroup::Correctness,
             Self::NoInteractiveElementToNoninteractiveRole => RuleGroup::A11y,
+            Self::NoInvalidArguments => RuleGroup::Nursery,
             Self::NoInvalidBuiltinInstantiation => RuleGroup::Correctness,
             Self::NoInvalidConstructorSuper => RuleGroup::Correctness,
             Self::NoInvalidDirectionInLinearGradient => RuleGroup::Correctness,
+            Self::NoInvalidFragmentTypes => RuleGroup::Nursery,
             Self::NoInvalidGridAreas => RuleGroup::Correctness,
             Self::NoInvalidPositionAtImportRule => RuleGroup::Correctness,
             Self::NoInvalidUseBeforeDeclaration => RuleGroup::Correctness,
             Self::NoIrregularWhitespace => RuleGroup::Suspicious,
+            Self::NoJsonSchemaViolations => RuleGroup::Nursery,
             Self::NoLabelVar => RuleGroup::Suspicious,
             Self::NoLabelWithoutControl => RuleGroup::A11y,
             Self::NoMagicNumbers => RuleGroup::Nursery,
@@ -936,13 +957,17 @@ impl RuleName {
             Self::NoUnassignedVariables => RuleGroup::Nursery,
             Self::NoUndeclaredDependencies => RuleGroup::Correctness,
             Self::NoUndeclaredVariables => RuleGroup::Correctness,
+            Self::NoUndefinedVariables => RuleGroup::Nursery,
             Self::NoUnknownAtRule => RuleGroup::Nursery,
+            Self::NoUnknownDirectives => RuleGroup::Nursery,
+            Self::NoUnknownFields => RuleGroup::Nursery,
             Self::NoUnknownFunction => RuleGroup::Correctness,
             Self::NoUnknownMediaFeatureName => RuleGroup::Correctness,
             Self::NoUnknownProperty => RuleGroup::Correctness,
             Self::NoUnknownPseudoClass => RuleGroup::Correctness,
             Self::NoUnknownPseudoElement => RuleGroup::Correctness,
             Self::NoUnknownTypeSelector => RuleGroup::Correctness,
+            Self::NoUnknownTypes => RuleGroup::Nursery,
             Self::NoUnknownUnit => RuleGroup::Correctness,
             Self::NoUnmatchableAnbSelector => RuleGroup::Correctness,
             Self::NoUnreachable => RuleGroup::Correctness,
@@ -952,11 +977,13 @@ impl RuleName {
             Self::NoUnsafeFinally => RuleGroup::Correctness,
             Self::NoUnsafeNegation => RuleGroup::Suspicious,
             Self::NoUnsafeOptionalChaining => RuleGroup::Correctness,
+            Self::NoUnusedExports => RuleGroup::Nursery,
             Self::NoUnusedFunctionParameters => RuleGroup::Correctness,
             Self::NoUnusedImports => RuleGroup::Correctness,
             Self::NoUnusedLabels => RuleGroup::Correctness,
             Self::NoUnusedPrivateClassMembers => RuleGroup::Correctness,
             Self::NoUnusedTemplateLiteral => RuleGroup::Style,
+            Self::NoUnusedVariableDefinitions => RuleGroup::Nursery,
             Self::NoUnusedVariables => RuleGroup::Correctness,
             Self::NoUnwantedPolyfillio => RuleGroup::Nursery,
             Self::NoUselessBackrefInRegex => RuleGroup::Nursery,
@@ -1207,13 +1234,16 @@ impl std::str::FromStr for RuleName {
             "noInteractiveElementToNoninteractiveRole" => {
                 Ok(Self::NoInteractiveElementToNoninteractiveRole)
             }
+            "noInvalidArguments" => Ok(Self::NoInvalidArguments),
             "noInvalidBuiltinInstantiation" => Ok(Self::NoInvalidBuiltinInstantiation),
             "noInvalidConstructorSuper" => Ok(Self::NoInvalidConstructorSuper),
             "noInvalidDirectionInLinearGradient" => Ok(Self::NoInvalidDirectionInLinearGradient),
+            "noInvalidFragmentTypes" => Ok(Self::NoInvalidFragmentTypes),
             "noInvalidGridAreas" => Ok(Self::NoInvalidGridAreas),
             "noInvalidPositionAtImportRule" => Ok(Self::NoInvalidPositionAtImportRule),
             "noInvalidUseBeforeDeclaration" => Ok(Self::NoInvalidUseBeforeDeclaration),
             "noIrregularWhitespace" => Ok(Self::NoIrregularWhitespace),
+            "noJsonSchemaViolations" => Ok(Self::NoJsonSchemaViolations),
             "noLabelVar" => Ok(Self::NoLabelVar),
             "noLabelWithoutControl" => Ok(Self::NoLabelWithoutControl),
             "noMagicNumbers" => Ok(Self::NoMagicNumbers),
@@ -1280,13 +1310,17 @@ impl std::str::FromStr for RuleName {
             "noUnassignedVariables" => Ok(Self::NoUnassignedVariables),
             "noUndeclaredDependencies" => Ok(Self::NoUndeclaredDependencies),
             "noUndeclaredVariables" => Ok(Self::NoUndeclaredVariables),
+            "noUndefinedVariables" => Ok(Self::NoUndefinedVariables),
             "noUnknownAtRule" => Ok(Self::NoUnknownAtRule),
+            "noUnknownDirectives" => Ok(Self::NoUnknownDirectives),
+            "noUnknownFields" => Ok(Self::NoUnknownFields),
             "noUnknownFunction" => Ok(Self::NoUnknownFunction),
             "noUnknownMediaFeatureName" => Ok(Self::NoUnknownMediaFeatureName),
             "noUnknownProperty" => Ok(Self::NoUnknownProperty),
             "noUnknownPseudoClass" => Ok(Self::NoUnknownPseudoClass),
             "noUnknownPseudoElement" => Ok(Self::NoUnknownPseudoElement),
             "noUnknownTypeSelector" => Ok(Self::NoUnknownTypeSelector),
+            "noUnknownTypes" => Ok(Self::NoUnknownTypes),
             "noUnknownUnit" => Ok(Self::NoUnknownUnit),
             "noUnmatchableAnbSelector" => Ok(Self::NoUnmatchableAnbSelector),
             "noUnreachable" => Ok(Self::NoUnreachable),
@@ -1296,11 +1330,13 @@ impl std::str::FromStr for RuleName {
             "noUnsafeFinally" => Ok(Self::NoUnsafeFinally),
             "noUnsafeNegation" => Ok(Self::NoUnsafeNegation),
             "noUnsafeOptionalChaining" => Ok(Self::NoUnsafeOptionalChaining),
+            "noUnusedExports" => Ok(Self::NoUnusedExports),
             "noUnusedFunctionParameters" => Ok(Self::NoUnusedFunctionParameters),
             "noUnusedImports" => Ok(Self::NoUnusedImports),
             "noUnusedLabels" => Ok(Self::NoUnusedLabels),
             "noUnusedPrivateClassMembers" => Ok(Self::NoUnusedPrivateClassMembers),
             "noUnusedTemplateLiteral" => Ok(Self::NoUnusedTemplateLiteral),
+            "noUnusedVariableDefinitions" => Ok(Self::NoUnusedVariableDefinitions),
             "noUnusedVariables" => Ok(Self::NoUnusedVariables),
             "noUnwantedPolyfillio" => Ok(Self::NoUnwantedPolyfillio),
             "noUselessBackrefInRegex" => Ok(Self::NoUselessBackrefInRegex),
@@ -4778,6 +4814,18 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_important_styles:
         Option<RuleFixConfiguration<biome_css_analyze::options::NoImportantStyles>>,
+    #[doc = "Disallow arguments that don't match their definition in the schema."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_invalid_arguments:
+        Option<RuleConfiguration<biome_graphql_analyze::options::NoInvalidArguments>>,
+    #[doc = "Disallow fragments whose type condition can never match."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_invalid_fragment_types:
+        Option<RuleConfiguration<biome_graphql_analyze::options::NoInvalidFragmentTypes>>,
+    #[doc = "Disallow values that don't conform to the JSON Schema of the document."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_json_schema_violations:
+        Option<RuleConfiguration<biome_json_analyze::options::NoJsonSchemaViolations>>,
     #[doc = "Reports usage of \"magic numbers\" — numbers used directly instead of being assigned to named constants."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_magic_numbers: Option<RuleConfiguration<biome_js_analyze::options::NoMagicNumbers>>,
@@ -4813,13 +4861,35 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unassigned_variables:
         Option<RuleConfiguration<biome_js_analyze::options::NoUnassignedVariables>>,
+    #[doc = "Disallow using variables that aren't defined by the operation."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_undefined_variables:
+        Option<RuleConfiguration<biome_graphql_analyze::options::NoUndefinedVariables>>,
     #[doc = "Disallow unknown at-rules."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unknown_at_rule: Option<RuleConfiguration<biome_css_analyze::options::NoUnknownAtRule>>,
+    #[doc = "Disallow using directives that aren't defined by the schema, or in locations where they aren't allowed."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_directives:
+        Option<RuleConfiguration<biome_graphql_analyze::options::NoUnknownDirectives>>,
+    #[doc = "Disallow selecting fields that aren't defined by the schema."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_fields:
+        Option<RuleConfiguration<biome_graphql_analyze::options::NoUnknownFields>>,
+    #[doc = "Disallow referencing types that aren't defined by the schema."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_types: Option<RuleConfiguration<biome_graphql_analyze::options::NoUnknownTypes>>,
     #[doc = "Warn when importing non-existing exports."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unresolved_imports:
         Option<RuleConfiguration<biome_js_analyze::options::NoUnresolvedImports>>,
+    #[doc = "Disallow exports that no module imports, and modules that no entry point reaches."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_exports: Option<RuleConfiguration<biome_js_analyze::options::NoUnusedExports>>,
+    #[doc = "Disallow variable definitions that aren't used by the operation."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_variable_definitions:
+        Option<RuleFixConfiguration<biome_graphql_analyze::options::NoUnusedVariableDefinitions>>,
     #[doc = "Prevent duplicate polyfills from Polyfill.io."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unwanted_polyfillio:
@@ -4933,6 +5003,9 @@ impl Nursery {
         "noImplicitCoercion",
         "noImportCycles",
         "noImportantStyles",
+        "noInvalidArguments",
+        "noInvalidFragmentTypes",
+        "noJsonSchemaViolations",
         "noMagicNumbers",
         "noNestedComponentDefinitions",
         "noNoninteractiveElementInteractions",
@@ -4943,8 +5016,14 @@ impl Nursery {
         "noShadow",
         "noTsIgnore",
         "noUnassignedVariables",
+        "noUndefinedVariables",
         "noUnknownAtRule",
+        "noUnknownDirectives",
+        "noUnknownFields",
+        "noUnknownTypes",
         "noUnresolvedImports",
+        "noUnusedExports",
+        "noUnusedVariableDefinitions",
         "noUnwantedPolyfillio",
         "noUselessBackrefInRegex",
         "noUselessEscapeInString",
@@ -4974,14 +5053,14 @@ impl Nursery {
     ];
     const RECOMMENDED_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]),
     ];
     const ALL_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
@@ -5032,6 +5111,15 @@ impl Nursery {
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]),
     ];
 }
 impl RuleGroupExt for Nursery {
@@ -5093,196 +5181,241 @@ impl RuleGroupExt for Nursery {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.no_magic_numbers.as_ref() {
+        if let Some(rule) = self.no_invalid_arguments.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.no_nested_component_definitions.as_ref() {
+        if let Some(rule) = self.no_invalid_fragment_types.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.no_noninteractive_element_interactions.as_ref() {
+        if let Some(rule) = self.no_json_schema_violations.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
-        if let Some(rule) = self.no_process_global.as_ref() {
+        if let Some(rule) = self.no_magic_numbers.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
             }
         }
-        if let Some(rule) = self.no_react_prop_assign.as_ref() {
+        if let Some(rule) = self.no_nested_component_definitions.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
             }
         }
-        if let Some(rule) = self.no_restricted_elements.as_ref() {
+        if let Some(rule) = self.no_noninteractive_element_interactions.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
             }
         }
-        if let Some(rule) = self.no_secrets.as_ref() {
+        if let Some(rule) = self.no_process_global.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
             }
         }
-        if let Some(rule) = self.no_shadow.as_ref() {
+        if let Some(rule) = self.no_react_prop_assign.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
             }
         }
-        if let Some(rule) = self.no_ts_ignore.as_ref() {
+        if let Some(rule) = self.no_restricted_elements.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
             }
         }
-        if let Some(rule) = self.no_unassigned_variables.as_ref() {
+        if let Some(rule) = self.no_secrets.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
             }
         }
-        if let Some(rule) = self.no_unknown_at_rule.as_ref() {
+        if let Some(rule) = self.no_shadow.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
             }
         }
-        if let Some(rule) = self.no_unresolved_imports.as_ref() {
+        if let Some(rule) = self.no_ts_ignore.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
             }
         }
-        if let Some(rule) = self.no_unwanted_polyfillio.as_ref() {
+        if let Some(rule) = self.no_unassigned_variables.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
             }
         }
-        if let Some(rule) = self.no_useless_backref_in_regex.as_ref() {
+        if let Some(rule) = self.no_undefined_variables.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
             }
         }
-        if let Some(rule) = self.no_useless_escape_in_string.as_ref() {
+        if let Some(rule) = self.no_unknown_at_rule.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
             }
         }
-        if let Some(rule) = self.no_useless_undefined.as_ref() {
+        if let Some(rule) = self.no_unknown_directives.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
             }
         }
-        if let Some(rule) = self.use_adjacent_getter_setter.as_ref() {
+        if let Some(rule) = self.no_unknown_fields.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
             }
         }
-        if let Some(rule) = self.use_consistent_object_definition.as_ref() {
+        if let Some(rule) = self.no_unknown_types.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
             }
         }
-        if let Some(rule) = self.use_consistent_response.as_ref() {
+        if let Some(rule) = self.no_unresolved_imports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
             }
         }
-        if let Some(rule) = self.use_exhaustive_switch_cases.as_ref() {
+        if let Some(rule) = self.no_unused_exports.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
             }
         }
-        if let Some(rule) = self.use_explicit_type.as_ref() {
+        if let Some(rule) = self.no_unused_variable_definitions.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
             }
         }
-        if let Some(rule) = self.use_exports_last.as_ref() {
+        if let Some(rule) = self.no_unwanted_polyfillio.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
             }
         }
-        if let Some(rule) = self.use_for_component.as_ref() {
+        if let Some(rule) = self.no_useless_backref_in_regex.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
             }
         }
-        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
+        if let Some(rule) = self.no_useless_escape_in_string.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
             }
         }
-        if let Some(rule) = self.use_index_of.as_ref() {
+        if let Some(rule) = self.no_useless_undefined.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
             }
         }
-        if let Some(rule) = self.use_iterable_callback_return.as_ref() {
+        if let Some(rule) = self.use_adjacent_getter_setter.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
             }
         }
-        if let Some(rule) = self.use_json_import_attribute.as_ref() {
+        if let Some(rule) = self.use_consistent_object_definition.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]));
             }
         }
-        if let Some(rule) = self.use_named_operation.as_ref() {
+        if let Some(rule) = self.use_consistent_response.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]));
             }
         }
-        if let Some(rule) = self.use_naming_convention.as_ref() {
+        if let Some(rule) = self.use_exhaustive_switch_cases.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]));
             }
         }
-        if let Some(rule) = self.use_numeric_separators.as_ref() {
+        if let Some(rule) = self.use_explicit_type.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]));
             }
         }
-        if let Some(rule) = self.use_object_spread.as_ref() {
+        if let Some(rule) = self.use_exports_last.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[40]));
             }
         }
-        if let Some(rule) = self.use_parse_int_radix.as_ref() {
+        if let Some(rule) = self.use_for_component.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]));
             }
         }
-        if let Some(rule) = self.use_readonly_class_properties.as_ref() {
+        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]));
             }
         }
-        if let Some(rule) = self.use_single_js_doc_asterisk.as_ref() {
+        if let Some(rule) = self.use_index_of.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]));
             }
         }
-        if let Some(rule) = self.use_sorted_classes.as_ref() {
+        if let Some(rule) = self.use_iterable_callback_return.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]));
             }
         }
-        if let Some(rule) = self.use_symbol_description.as_ref() {
+        if let Some(rule) = self.use_json_import_attribute.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]));
             }
         }
-        if let Some(rule) = self.use_unified_type_signature.as_ref() {
+        if let Some(rule) = self.use_named_operation.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]));
             }
         }
-        if let Some(rule) = self.use_unique_element_ids.as_ref() {
+        if let Some(rule) = self.use_naming_convention.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]));
             }
         }
+        if let Some(rule) = self.use_numeric_separators.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]));
+            }
+        }
+        if let Some(rule) = self.use_object_spread.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]));
+            }
+        }
+        if let Some(rule) = self.use_parse_int_radix.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]));
+            }
+        }
+        if let Some(rule) = self.use_readonly_class_properties.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]));
+            }
+        }
+        if let Some(rule) = self.use_single_js_doc_asterisk.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_classes.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]));
+            }
+        }
+        if let Some(rule) = self.use_symbol_description.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]));
+            }
+        }
+        if let Some(rule) = self.use_unified_type_signature.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]));
+            }
+        }
+        if let Some(rule) = self.use_unique_element_ids.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]));
+            }
+        }
         index_set
     }
     fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
@@ -5337,196 +5470,241 @@ impl RuleGroupExt for Nursery {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.no_magic_numbers.as_ref() {
+        if let Some(rule) = self.no_invalid_arguments.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.no_nested_component_definitions.as_ref() {
+        if let Some(rule) = self.no_invalid_fragment_types.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.no_noninteractive_element_interactions.as_ref() {
+        if let Some(rule) = self.no_json_schema_violations.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
-        if let Some(rule) = self.no_process_global.as_ref() {
+        if let Some(rule) = self.no_magic_numbers.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
             }
         }
-        if let Some(rule) = self.no_react_prop_assign.as_ref() {
+        if let Some(rule) = self.no_nested_component_definitions.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
             }
         }
-        if let Some(rule) = self.no_restricted_elements.as_ref() {
+        if let Some(rule) = self.no_noninteractive_element_interactions.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
             }
         }
-        if let Some(rule) = self.no_secrets.as_ref() {
+        if let Some(rule) = self.no_process_global.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
             }
         }
-        if let Some(rule) = self.no_shadow.as_ref() {
+        if let Some(rule) = self.no_react_prop_assign.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
             }
         }
-        if let Some(rule) = self.no_ts_ignore.as_ref() {
+        if let Some(rule) = self.no_restricted_elements.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
             }
         }
-        if let Some(rule) = self.no_unassigned_variables.as_ref() {
+        if let Some(rule) = self.no_secrets.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
             }
         }
-        if let Some(rule) = self.no_unknown_at_rule.as_ref() {
+        if let Some(rule) = self.no_shadow.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
             }
         }
-        if let Some(rule) = self.no_unresolved_imports.as_ref() {
+        if let Some(rule) = self.no_ts_ignore.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
             }
         }
-        if let Some(rule) = self.no_unwanted_polyfillio.as_ref() {
+        if let Some(rule) = self.no_unassigned_variables.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
             }
         }
-        if let Some(rule) = self.no_useless_backref_in_regex.as_ref() {
+        if let Some(rule) = self.no_undefined_variables.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
             }
         }
-        if let Some(rule) = self.no_useless_escape_in_string.as_ref() {
+        if let Some(rule) = self.no_unknown_at_rule.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
             }
         }
-        if let Some(rule) = self.no_useless_undefined.as_ref() {
+        if let Some(rule) = self.no_unknown_directives.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
             }
         }
-        if let Some(rule) = self.use_adjacent_getter_setter.as_ref() {
+        if let Some(rule) = self.no_unknown_fields.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
             }
         }
-        if let Some(rule) = self.use_consistent_object_definition.as_ref() {
+        if let Some(rule) = self.no_unknown_types.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
             }
         }
-        if let Some(rule) = self.use_consistent_response.as_ref() {
+        if let Some(rule) = self.no_unresolved_imports.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
             }
         }
-        if let Some(rule) = self.use_exhaustive_switch_cases.as_ref() {
+        if let Some(rule) = self.no_unused_exports.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
             }
         }
-        if let Some(rule) = self.use_explicit_type.as_ref() {
+        if let Some(rule) = self.no_unused_variable_definitions.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
             }
         }
-        if let Some(rule) = self.use_exports_last.as_ref() {
+        if let Some(rule) = self.no_unwanted_polyfillio.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
             }
         }
-        if let Some(rule) = self.use_for_component.as_ref() {
+        if let Some(rule) = self.no_useless_backref_in_regex.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
             }
         }
-        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
+        if let Some(rule) = self.no_useless_escape_in_string.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
             }
         }
-        if let Some(rule) = self.use_index_of.as_ref() {
+        if let Some(rule) = self.no_useless_undefined.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
             }
         }
-        if let Some(rule) = self.use_iterable_callback_return.as_ref() {
+        if let Some(rule) = self.use_adjacent_getter_setter.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
             }
         }
-        if let Some(rule) = self.use_json_import_attribute.as_ref() {
+        if let Some(rule) = self.use_consistent_object_definition.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]));
             }
         }
-        if let Some(rule) = self.use_named_operation.as_ref() {
+        if let Some(rule) = self.use_consistent_response.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]));
             }
         }
-        if let Some(rule) = self.use_naming_convention.as_ref() {
+        if let Some(rule) = self.use_exhaustive_switch_cases.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]));
             }
         }
-        if let Some(rule) = self.use_numeric_separators.as_ref() {
+        if let Some(rule) = self.use_explicit_type.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]));
             }
         }
-        if let Some(rule) = self.use_object_spread.as_ref() {
+        if let Some(rule) = self.use_exports_last.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[40]));
             }
         }
-        if let Some(rule) = self.use_parse_int_radix.as_ref() {
+        if let Some(rule) = self.use_for_component.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]));
             }
         }
-        if let Some(rule) = self.use_readonly_class_properties.as_ref() {
+        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]));
             }
         }
-        if let Some(rule) = self.use_single_js_doc_asterisk.as_ref() {
+        if let Some(rule) = self.use_index_of.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]));
             }
         }
-        if let Some(rule) = self.use_sorted_classes.as_ref() {
+        if let Some(rule) = self.use_iterable_callback_return.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]));
             }
         }
-        if let Some(rule) = self.use_symbol_description.as_ref() {
+        if let Some(rule) = self.use_json_import_attribute.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]));
             }
         }
-        if let Some(rule) = self.use_unified_type_signature.as_ref() {
+        if let Some(rule) = self.use_named_operation.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]));
             }
         }
-        if let Some(rule) = self.use_unique_element_ids.as_ref() {
+        if let Some(rule) = self.use_naming_convention.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]));
             }
         }
+        if let Some(rule) = self.use_numeric_separators.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]));
+            }
+        }
+        if let Some(rule) = self.use_object_spread.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]));
+            }
+        }
+        if let Some(rule) = self.use_parse_int_radix.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]));
+            }
+        }
+        if let Some(rule) = self.use_readonly_class_properties.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]));
+            }
+        }
+        if let Some(rule) = self.use_single_js_doc_asterisk.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_classes.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]));
+            }
+        }
+        if let Some(rule) = self.use_symbol_description.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]));
+            }
+        }
+        if let Some(rule) = self.use_unified_type_signature.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]));
+            }
+        }
+        if let Some(rule) = self.use_unique_element_ids.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]));
+            }
+        }
         index_set
     }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
@@ -5597,6 +5775,18 @@ impl RuleGroupExt for Nursery {
                 .no_important_styles
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noInvalidArguments" => self
+                .no_invalid_arguments
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noInvalidFragmentTypes" => self
+                .no_invalid_fragment_types
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noJsonSchemaViolations" => self
+                .no_json_schema_violations
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noMagicNumbers" => self
                 .no_magic_numbers
                 .as_ref()
@@ -5637,14 +5827,38 @@ impl RuleGroupExt for Nursery {
                 .no_unassigned_variables
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noUndefinedVariables" => self
+                .no_undefined_variables
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUnknownAtRule" => self
                 .no_unknown_at_rule
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownDirectives" => self
+                .no_unknown_directives
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownFields" => self
+                .no_unknown_fields
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownTypes" => self
+                .no_unknown_types
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUnresolvedImports" => self
                 .no_unresolved_imports
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedExports" => self
+                .no_unused_exports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedVariableDefinitions" => self
+                .no_unused_variable_definitions
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUnwantedPolyfillio" => self
                 .no_unwanted_polyfillio
                 .as_ref()
@@ -5767,6 +5981,9 @@ impl From<GroupPlainConfiguration> for Nursery {
             no_implicit_coercion: Some(value.into()),
             no_import_cycles: Some(value.into()),
             no_important_styles: Some(value.into()),
+            no_invalid_arguments: Some(value.into()),
+            no_invalid_fragment_types: Some(value.into()),
+            no_json_schema_violations: Some(value.into()),
             no_magic_numbers: Some(value.into()),
             no_nested_component_definitions: Some(value.into()),
             no_noninteractive_element_interactions: Some(value.into()),
@@ -5777,8 +5994,14 @@ impl From<GroupPlainConfiguration> for Nursery {
             no_shadow: Some(value.into()),
             no_ts_ignore: Some(value.into()),
             no_unassigned_variables: Some(value.into()),
+            no_undefined_variables: Some(value.into()),
             no_unknown_at_rule: Some(value.into()),
+            no_unknown_directives: Some(value.into()),
+            no_unknown_fields: Some(value.into()),
+            no_unknown_types: Some(value.into()),
             no_unresolved_imports: Some(value.into()),
+            no_unused_exports: Some(value.into()),
+            no_unused_variable_definitions: Some(value.into()),
             no_unwanted_polyfillio: Some(value.into()),
             no_useless_backref_in_regex: Some(value.into()),
             no_useless_escape_in_string: Some(value.into()),
@@ -27,6 +27,13 @@ pub struct GraphqlConfiguration {
     #[bpaf(external(graphql_assist_configuration), optional)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assist: Option<GraphqlAssistConfiguration>,
+
+    /// A list of glob patterns that match the files that define the schema of
+    /// the project. The schema is used to validate the operations and the
+    /// fragments of the other GraphQL files.
+    #[bpaf(hide, pure(Default::default()))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Vec<biome_glob::NormalizedGlob>>,
 }
 
 pub type GraphqlFormatterEnabled = Bool<true>;
@@ -25,6 +25,11 @@ pub struct HtmlConfiguration {
     #[bpaf(external(html_formatter_configuration), optional)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub formatter: Option<HtmlFormatterConfiguration>,
+
+    /// HTML linter options
+    #[bpaf(external(html_linter_configuration), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linter: Option<HtmlLinterConfiguration>,
 }
 
 pub type HtmlFormatterEnabled = Bool<false>; // Keep it disabled by default while experimental.
@@ -116,3 +121,16 @@ pub struct HtmlFormatterConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub self_close_void_elements: Option<SelfCloseVoidElements>,
 }
+
+/// Options that change how the HTML linter behaves.
+#[derive(
+    Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize, Bpaf, Deserializable, Merge,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct HtmlLinterConfiguration {
+    /// Control the linter for HTML (and its super languages) files.
+    #[bpaf(long("html-linter-enabled"), argument("true|false"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<HtmlLinterEnabled>,
+}
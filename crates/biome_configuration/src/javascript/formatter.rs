@@ -5,7 +5,8 @@ use biome_formatter::{
     LineEnding, LineWidth, QuoteStyle,
 };
 use biome_js_formatter::context::{
-    ArrowParentheses, QuoteProperties, Semicolons, trailing_commas::TrailingCommas,
+    ArrowParentheses, EmbeddedLanguageFormatting, QuoteProperties, Semicolons,
+    trailing_commas::TrailingCommas,
 };
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
@@ -116,6 +117,17 @@ pub struct JsFormatterConfiguration {
     #[bpaf(long("javascript-formatter-expand"), argument("auto|always|never"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expand: Option<Expand>,
+
+    /// Whether to format the content of well-known tagged templates with the formatter of the embedded language.
+    /// When set to `auto`, the CSS inside `css`, `keyframes`, `createGlobalStyle` and `styled` templates,
+    /// and the GraphQL inside `gql` and `graphql` templates, are formatted. Interpolations are kept as they are.
+    /// Defaults to "off".
+    #[bpaf(
+        long("javascript-formatter-embedded-language-formatting"),
+        argument("auto|off")
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedded_language_formatting: Option<EmbeddedLanguageFormatting>,
 }
 
 impl JsFormatterConfiguration {
@@ -154,4 +166,8 @@ impl JsFormatterConfiguration {
     pub fn expand_resolved(&self) -> Expand {
         self.expand.unwrap_or_default()
     }
+
+    pub fn embedded_language_formatting_resolved(&self) -> EmbeddedLanguageFormatting {
+        self.embedded_language_formatting.unwrap_or_default()
+    }
 }
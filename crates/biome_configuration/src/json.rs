@@ -31,6 +31,32 @@ pub struct JsonConfiguration {
     #[bpaf(external(json_assist_configuration), optional)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assist: Option<JsonAssistConfiguration>,
+
+    /// JSON Schemas used to validate JSON files, associated with the files
+    /// they apply to
+    #[bpaf(hide, pure(Default::default()))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schemas: Option<JsonSchemas>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct JsonSchemas(pub Vec<JsonSchemaAssociation>);
+
+/// Associates a JSON Schema with the JSON files it applies to
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsonSchemaAssociation {
+    /// A list of glob patterns. The schema is used to validate the JSON files
+    /// that match these patterns, unless they declare their own `$schema`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub includes: Option<Vec<biome_glob::NormalizedGlob>>,
+
+    /// The path to the JSON Schema, relative to the configuration file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
 }
 
 pub type JsonAllowCommentsEnabled = Bool<false>;
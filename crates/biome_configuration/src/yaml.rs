@@ -0,0 +1,59 @@
+use crate::bool::Bool;
+use biome_deserialize_macros::{Deserializable, Merge};
+use biome_formatter::{IndentWidth, LineEnding, LineWidth, QuoteStyle};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to YAML files
+#[derive(
+    Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize, Bpaf, Deserializable, Merge,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct YamlConfiguration {
+    /// YAML formatter options
+    #[bpaf(external(yaml_formatter_configuration), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatter: Option<YamlFormatterConfiguration>,
+}
+
+pub type YamlFormatterEnabled = Bool<false>; // Keep it disabled by default while experimental.
+
+/// Options that changes how the YAML formatter behaves.
+///
+/// YAML doesn't allow tabs in indentation, so YAML files are always indented with spaces.
+#[derive(
+    Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize, Bpaf, Deserializable, Merge,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct YamlFormatterConfiguration {
+    /// Control the formatter for YAML files.
+    #[bpaf(long("yaml-formatter-enabled"), argument("true|false"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<YamlFormatterEnabled>,
+
+    /// The size of the indentation applied to YAML files. Default to 2.
+    #[bpaf(long("yaml-formatter-indent-width"), argument("NUMBER"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent_width: Option<IndentWidth>,
+
+    /// The type of line ending applied to YAML files.
+    #[bpaf(long("yaml-formatter-line-ending"), argument("lf|crlf|cr"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to YAML files. Defaults to 80.
+    #[bpaf(long("yaml-formatter-line-width"), argument("NUMBER"), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_width: Option<LineWidth>,
+
+    /// The type of quotes used in YAML files. Defaults to double.
+    #[bpaf(
+        long("yaml-formatter-quote-style"),
+        argument("double|single"),
+        optional
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_style: Option<QuoteStyle>,
+}
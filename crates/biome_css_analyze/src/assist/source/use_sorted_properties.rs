@@ -247,6 +247,12 @@ impl RecessOrderMember {
                 AnyCssRule::CssBogusRule(_) => NodeKindOrder::UnknownKind,
                 AnyCssRule::CssNestedQualifiedRule(_) => NodeKindOrder::NestedRuleOrAtRule,
                 AnyCssRule::CssQualifiedRule(_) => NodeKindOrder::UnknownKind,
+                AnyCssRule::LessDetachedRulesetCall(_)
+                | AnyCssRule::LessDetachedRulesetDeclaration(_)
+                | AnyCssRule::LessMixinCall(_)
+                | AnyCssRule::LessVariableDeclaration(_)
+                | AnyCssRule::ScssDeclaration(_) => NodeKindOrder::UnknownKind,
+                AnyCssRule::LessMixinDeclaration(_) => NodeKindOrder::NestedRuleOrAtRule,
             },
             AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(decl_with_semicolon) => {
                 let Some(decl) = decl_with_semicolon.declaration().ok() else {
@@ -267,6 +273,10 @@ impl RecessOrderMember {
                                 NodeKindOrder::CustomProperty
                             }
                             AnyCssDeclarationName::CssIdentifier(_) => NodeKindOrder::Declaration,
+                            AnyCssDeclarationName::ScssInterpolatedIdentifier(_)
+                            | AnyCssDeclarationName::ScssInterpolation(_) => {
+                                NodeKindOrder::UnknownKind
+                            }
                         }
                     }
                 }
@@ -366,6 +376,37 @@ fn css_declaration_to_prop_text(
         })
 }
 
+/// SCSS and Less variables may be used by the properties following them, the
+/// name of an interpolated property isn't known until it's evaluated, and Less
+/// mixin calls expand to properties that aren't known either, so none of them
+/// can be moved safely.
+fn is_preprocessor_variable_or_unknown_property(node: &AnyCssDeclarationOrRule) -> bool {
+    match node {
+        AnyCssDeclarationOrRule::AnyCssRule(
+            AnyCssRule::ScssDeclaration(_)
+            | AnyCssRule::LessVariableDeclaration(_)
+            | AnyCssRule::LessDetachedRulesetDeclaration(_)
+            | AnyCssRule::LessDetachedRulesetCall(_)
+            | AnyCssRule::LessMixinCall(_),
+        ) => true,
+        AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(decl_with_semicolon) => {
+            decl_with_semicolon
+                .declaration()
+                .ok()
+                .and_then(|decl| decl.property().ok())
+                .and_then(|prop| prop.as_css_generic_property()?.name().ok())
+                .is_some_and(|name| {
+                    matches!(
+                        name,
+                        AnyCssDeclarationName::ScssInterpolatedIdentifier(_)
+                            | AnyCssDeclarationName::ScssInterpolation(_)
+                    )
+                })
+        }
+        _ => false,
+    }
+}
+
 /// Returns a declaration's vendor prefix in lowercase
 fn prop_text_to_prefix(tok_text: &TokenText) -> Option<&'static str> {
     let prop_lowercase = tok_text.text().to_lowercase_cow();
@@ -433,6 +474,10 @@ fn contains_shorthand_after_longhand(nodes: &[AnyCssDeclarationOrRule]) -> bool
 /// The noUnknownProperty rule will catch it instead.
 fn contains_unknown_property(nodes: &[AnyCssDeclarationOrRule]) -> bool {
     for node in nodes.iter() {
+        if is_preprocessor_variable_or_unknown_property(node) {
+            return true;
+        }
+
         let Some(prop_text) = &node
             .as_css_declaration_with_semicolon()
             .and_then(css_declaration_to_prop_text)
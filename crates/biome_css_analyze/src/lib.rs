@@ -14,8 +14,8 @@ pub use crate::registry::visit_registry;
 use crate::suppression_action::CssSuppressionAction;
 use biome_analyze::{
     AnalysisFilter, AnalyzerOptions, AnalyzerPluginSlice, AnalyzerSignal, AnalyzerSuppression,
-    ControlFlow, LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
-    to_analyzer_suppressions,
+    ControlFlow, LanguageRoot, MatchQueryParams, MetadataRegistry, PluginTargetLanguage,
+    RuleAction, RuleRegistry, to_analyzer_suppressions,
 };
 use biome_css_syntax::{CssLanguage, TextRange};
 use biome_diagnostics::Error;
@@ -112,11 +112,7 @@ where
         categories,
     );
 
-    for plugin in plugins {
-        if plugin.supports_css() {
-            analyzer.add_plugin(plugin.clone());
-        }
-    }
+    analyzer.add_plugins_for_language(plugins, PluginTargetLanguage::Css);
 
     for ((phase, _), visitor) in visitors {
         analyzer.add_visitor(phase, visitor);
@@ -2,7 +2,7 @@ use biome_analyze::{
     Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
 };
 use biome_console::markup;
-use biome_css_syntax::CssGenericProperty;
+use biome_css_syntax::{AnyCssDeclarationName, CssGenericProperty};
 use biome_diagnostics::Severity;
 use biome_rowan::{AstNode, TextRange};
 use biome_string_case::StrLikeExtension;
@@ -20,6 +20,7 @@ declare_lint_rule! {
     ///
     /// - custom variables e.g. `--custom-property`
     /// - vendor-prefixed properties (e.g., `-moz-align-self,` `-webkit-align-self`)
+    /// - SCSS interpolated properties (e.g., `margin-#{$side}`)
     ///
     /// ## Examples
     ///
@@ -75,7 +76,15 @@ impl Rule for NoUnknownProperty {
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let node = ctx.query();
-        let property_name = node.name().ok()?.to_trimmed_text();
+        let name = node.name().ok()?;
+        if matches!(
+            name,
+            AnyCssDeclarationName::ScssInterpolatedIdentifier(_)
+                | AnyCssDeclarationName::ScssInterpolation(_)
+        ) {
+            return None;
+        }
+        let property_name = name.to_trimmed_text();
         let property_name_lower = property_name.to_ascii_lowercase_cow();
         if !property_name_lower.starts_with("--")
             // Ignore `composes` property.
@@ -84,7 +93,7 @@ impl Rule for NoUnknownProperty {
             && !is_known_properties(&property_name_lower)
             && !vendor_prefixed(&property_name_lower)
         {
-            return Some(node.name().ok()?.range());
+            return Some(name.range());
         }
         None
     }
@@ -99,7 +99,9 @@ pub fn find_font_family(value: CssGenericComponentValueList) -> Vec<AnyCssValue>
         }
 
         match v {
-            AnyCssGenericComponentValue::CssGenericDelimiter(_) => {}
+            AnyCssGenericComponentValue::CssGenericDelimiter(_)
+            | AnyCssGenericComponentValue::LessOperator(_)
+            | AnyCssGenericComponentValue::ScssOperator(_) => {}
             AnyCssGenericComponentValue::AnyCssValue(css_value) => match css_value {
                 AnyCssValue::CssIdentifier(_) | AnyCssValue::CssString(_) => {
                     font_families.push(css_value)
@@ -18,7 +18,7 @@ use std::ops::Deref;
 use std::sync::Arc;
 use std::{fs::read_to_string, slice};
 
-tests_macros::gen_tests! {"tests/specs/**/*.{css,json,jsonc}", crate::run_test, "module"}
+tests_macros::gen_tests! {"tests/specs/**/*.{css,scss,less,json,jsonc}", crate::run_test, "module"}
 tests_macros::gen_tests! {"tests/suppression/**/*.{css,json,jsonc}", crate::run_suppression_test, "module"}
 tests_macros::gen_tests! {"tests/plugin/*.grit", crate::run_plugin_test, "module"}
 
@@ -57,6 +57,10 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
             css_modules: true,
             ..CssParserOptions::default()
         }
+    } else if extension == "scss" {
+        CssParserOptions::default().allow_scss()
+    } else if extension == "less" {
+        CssParserOptions::default().allow_less()
     } else {
         CssParserOptions::default()
     };
@@ -2332,6 +2332,1312 @@ pub fn css_view_transition_at_rule(
         ],
     ))
 }
+pub fn less_detached_ruleset_call(
+    name: LessIdentifier,
+    l_paren_token: SyntaxToken,
+    r_paren_token: SyntaxToken,
+) -> LessDetachedRulesetCallBuilder {
+    LessDetachedRulesetCallBuilder {
+        name,
+        l_paren_token,
+        r_paren_token,
+        semicolon_token: None,
+    }
+}
+pub struct LessDetachedRulesetCallBuilder {
+    name: LessIdentifier,
+    l_paren_token: SyntaxToken,
+    r_paren_token: SyntaxToken,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl LessDetachedRulesetCallBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> LessDetachedRulesetCall {
+        LessDetachedRulesetCall::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_DETACHED_RULESET_CALL,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Token(self.l_paren_token)),
+                Some(SyntaxElement::Token(self.r_paren_token)),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_detached_ruleset_declaration(
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> LessDetachedRulesetDeclarationBuilder {
+    LessDetachedRulesetDeclarationBuilder {
+        name,
+        colon_token,
+        block,
+        semicolon_token: None,
+    }
+}
+pub struct LessDetachedRulesetDeclarationBuilder {
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    block: AnyCssDeclarationOrRuleBlock,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl LessDetachedRulesetDeclarationBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> LessDetachedRulesetDeclaration {
+        LessDetachedRulesetDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_DETACHED_RULESET_DECLARATION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                Some(SyntaxElement::Node(self.block.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_escaped_value(tilde_token: SyntaxToken, value: CssString) -> LessEscapedValue {
+    LessEscapedValue::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_ESCAPED_VALUE,
+        [
+            Some(SyntaxElement::Token(tilde_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn less_guard_condition(
+    l_paren_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    r_paren_token: SyntaxToken,
+) -> LessGuardConditionBuilder {
+    LessGuardConditionBuilder {
+        l_paren_token,
+        value,
+        r_paren_token,
+        not_token: None,
+    }
+}
+pub struct LessGuardConditionBuilder {
+    l_paren_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    r_paren_token: SyntaxToken,
+    not_token: Option<SyntaxToken>,
+}
+impl LessGuardConditionBuilder {
+    pub fn with_not_token(mut self, not_token: SyntaxToken) -> Self {
+        self.not_token = Some(not_token);
+        self
+    }
+    pub fn build(self) -> LessGuardCondition {
+        LessGuardCondition::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_GUARD_CONDITION,
+            [
+                self.not_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Token(self.l_paren_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                Some(SyntaxElement::Token(self.r_paren_token)),
+            ],
+        ))
+    }
+}
+pub fn less_guard_logical_condition(
+    left: AnyLessGuardCondition,
+    operator_token: SyntaxToken,
+    right: LessGuardCondition,
+) -> LessGuardLogicalCondition {
+    LessGuardLogicalCondition::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_GUARD_LOGICAL_CONDITION,
+        [
+            Some(SyntaxElement::Node(left.into_syntax())),
+            Some(SyntaxElement::Token(operator_token)),
+            Some(SyntaxElement::Node(right.into_syntax())),
+        ],
+    ))
+}
+pub fn less_identifier(at_token: SyntaxToken, name: CssIdentifier) -> LessIdentifier {
+    LessIdentifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_IDENTIFIER,
+        [
+            Some(SyntaxElement::Token(at_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn less_import_at_rule(
+    import_token: SyntaxToken,
+    options: LessImportOptions,
+    url: AnyCssImportUrl,
+    media: CssMediaQueryList,
+    semicolon_token: SyntaxToken,
+) -> LessImportAtRule {
+    LessImportAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_IMPORT_AT_RULE,
+        [
+            Some(SyntaxElement::Token(import_token)),
+            Some(SyntaxElement::Node(options.into_syntax())),
+            Some(SyntaxElement::Node(url.into_syntax())),
+            Some(SyntaxElement::Node(media.into_syntax())),
+            Some(SyntaxElement::Token(semicolon_token)),
+        ],
+    ))
+}
+pub fn less_import_options(
+    l_paren_token: SyntaxToken,
+    items: LessImportOptionList,
+    r_paren_token: SyntaxToken,
+) -> LessImportOptions {
+    LessImportOptions::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_IMPORT_OPTIONS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn less_mixin_argument(value: CssGenericComponentValueList) -> LessMixinArgumentBuilder {
+    LessMixinArgumentBuilder {
+        value,
+        separator_token: None,
+    }
+}
+pub struct LessMixinArgumentBuilder {
+    value: CssGenericComponentValueList,
+    separator_token: Option<SyntaxToken>,
+}
+impl LessMixinArgumentBuilder {
+    pub fn with_separator_token(mut self, separator_token: SyntaxToken) -> Self {
+        self.separator_token = Some(separator_token);
+        self
+    }
+    pub fn build(self) -> LessMixinArgument {
+        LessMixinArgument::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_ARGUMENT,
+            [
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.separator_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_arguments(
+    l_paren_token: SyntaxToken,
+    items: LessMixinArgumentList,
+    r_paren_token: SyntaxToken,
+) -> LessMixinArguments {
+    LessMixinArguments::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_ARGUMENTS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn less_mixin_call(path: LessMixinPathSegmentList) -> LessMixinCallBuilder {
+    LessMixinCallBuilder {
+        path,
+        arguments: None,
+        important: None,
+        semicolon_token: None,
+    }
+}
+pub struct LessMixinCallBuilder {
+    path: LessMixinPathSegmentList,
+    arguments: Option<LessMixinArguments>,
+    important: Option<CssDeclarationImportant>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl LessMixinCallBuilder {
+    pub fn with_arguments(mut self, arguments: LessMixinArguments) -> Self {
+        self.arguments = Some(arguments);
+        self
+    }
+    pub fn with_important(mut self, important: CssDeclarationImportant) -> Self {
+        self.important = Some(important);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> LessMixinCall {
+        LessMixinCall::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_CALL,
+            [
+                Some(SyntaxElement::Node(self.path.into_syntax())),
+                self.arguments
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.important
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_declaration(
+    name: AnyLessMixinName,
+    parameters: LessMixinParameters,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> LessMixinDeclarationBuilder {
+    LessMixinDeclarationBuilder {
+        name,
+        parameters,
+        block,
+        guard: None,
+    }
+}
+pub struct LessMixinDeclarationBuilder {
+    name: AnyLessMixinName,
+    parameters: LessMixinParameters,
+    block: AnyCssDeclarationOrRuleBlock,
+    guard: Option<LessMixinGuard>,
+}
+impl LessMixinDeclarationBuilder {
+    pub fn with_guard(mut self, guard: LessMixinGuard) -> Self {
+        self.guard = Some(guard);
+        self
+    }
+    pub fn build(self) -> LessMixinDeclaration {
+        LessMixinDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_DECLARATION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Node(self.parameters.into_syntax())),
+                self.guard
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.block.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_guard(
+    when_token: SyntaxToken,
+    condition: AnyLessGuardCondition,
+) -> LessMixinGuard {
+    LessMixinGuard::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_GUARD,
+        [
+            Some(SyntaxElement::Token(when_token)),
+            Some(SyntaxElement::Node(condition.into_syntax())),
+        ],
+    ))
+}
+pub fn less_mixin_named_argument(
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+) -> LessMixinNamedArgumentBuilder {
+    LessMixinNamedArgumentBuilder {
+        name,
+        colon_token,
+        value,
+        separator_token: None,
+    }
+}
+pub struct LessMixinNamedArgumentBuilder {
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    separator_token: Option<SyntaxToken>,
+}
+impl LessMixinNamedArgumentBuilder {
+    pub fn with_separator_token(mut self, separator_token: SyntaxToken) -> Self {
+        self.separator_token = Some(separator_token);
+        self
+    }
+    pub fn build(self) -> LessMixinNamedArgument {
+        LessMixinNamedArgument::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_NAMED_ARGUMENT,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.separator_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_parameter(name: LessIdentifier) -> LessMixinParameterBuilder {
+    LessMixinParameterBuilder {
+        name,
+        default: None,
+        separator_token: None,
+    }
+}
+pub struct LessMixinParameterBuilder {
+    name: LessIdentifier,
+    default: Option<LessMixinParameterDefaultValue>,
+    separator_token: Option<SyntaxToken>,
+}
+impl LessMixinParameterBuilder {
+    pub fn with_default(mut self, default: LessMixinParameterDefaultValue) -> Self {
+        self.default = Some(default);
+        self
+    }
+    pub fn with_separator_token(mut self, separator_token: SyntaxToken) -> Self {
+        self.separator_token = Some(separator_token);
+        self
+    }
+    pub fn build(self) -> LessMixinParameter {
+        LessMixinParameter::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_PARAMETER,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.default
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.separator_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_parameter_default_value(
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+) -> LessMixinParameterDefaultValue {
+    LessMixinParameterDefaultValue::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_PARAMETER_DEFAULT_VALUE,
+        [
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn less_mixin_parameters(
+    l_paren_token: SyntaxToken,
+    items: LessMixinParameterList,
+    r_paren_token: SyntaxToken,
+) -> LessMixinParameters {
+    LessMixinParameters::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_PARAMETERS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn less_mixin_path_segment(name: AnyLessMixinName) -> LessMixinPathSegmentBuilder {
+    LessMixinPathSegmentBuilder {
+        name,
+        combinator_token: None,
+    }
+}
+pub struct LessMixinPathSegmentBuilder {
+    name: AnyLessMixinName,
+    combinator_token: Option<SyntaxToken>,
+}
+impl LessMixinPathSegmentBuilder {
+    pub fn with_combinator_token(mut self, combinator_token: SyntaxToken) -> Self {
+        self.combinator_token = Some(combinator_token);
+        self
+    }
+    pub fn build(self) -> LessMixinPathSegment {
+        LessMixinPathSegment::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_PATH_SEGMENT,
+            [
+                self.combinator_token
+                    .map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_pattern_parameter(value: AnyCssValue) -> LessMixinPatternParameterBuilder {
+    LessMixinPatternParameterBuilder {
+        value,
+        separator_token: None,
+    }
+}
+pub struct LessMixinPatternParameterBuilder {
+    value: AnyCssValue,
+    separator_token: Option<SyntaxToken>,
+}
+impl LessMixinPatternParameterBuilder {
+    pub fn with_separator_token(mut self, separator_token: SyntaxToken) -> Self {
+        self.separator_token = Some(separator_token);
+        self
+    }
+    pub fn build(self) -> LessMixinPatternParameter {
+        LessMixinPatternParameter::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_PATTERN_PARAMETER,
+            [
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.separator_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_rest_parameter(dotdotdot_token: SyntaxToken) -> LessMixinRestParameterBuilder {
+    LessMixinRestParameterBuilder {
+        dotdotdot_token,
+        name: None,
+        separator_token: None,
+    }
+}
+pub struct LessMixinRestParameterBuilder {
+    dotdotdot_token: SyntaxToken,
+    name: Option<LessIdentifier>,
+    separator_token: Option<SyntaxToken>,
+}
+impl LessMixinRestParameterBuilder {
+    pub fn with_name(mut self, name: LessIdentifier) -> Self {
+        self.name = Some(name);
+        self
+    }
+    pub fn with_separator_token(mut self, separator_token: SyntaxToken) -> Self {
+        self.separator_token = Some(separator_token);
+        self
+    }
+    pub fn build(self) -> LessMixinRestParameter {
+        LessMixinRestParameter::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_REST_PARAMETER,
+            [
+                self.name
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.dotdotdot_token)),
+                self.separator_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_operator(value_token: SyntaxToken) -> LessOperator {
+    LessOperator::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_OPERATOR,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn less_parenthesized_expression(
+    l_paren_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    r_paren_token: SyntaxToken,
+) -> LessParenthesizedExpression {
+    LessParenthesizedExpression::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_PARENTHESIZED_EXPRESSION,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn less_variable_declaration(
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+) -> LessVariableDeclarationBuilder {
+    LessVariableDeclarationBuilder {
+        name,
+        colon_token,
+        value,
+        semicolon_token: None,
+    }
+}
+pub struct LessVariableDeclarationBuilder {
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl LessVariableDeclarationBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> LessVariableDeclaration {
+        LessVariableDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_VARIABLE_DECLARATION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_argument(value: CssGenericComponentValueList) -> ScssArgumentBuilder {
+    ScssArgumentBuilder {
+        value,
+        dotdotdot_token: None,
+    }
+}
+pub struct ScssArgumentBuilder {
+    value: CssGenericComponentValueList,
+    dotdotdot_token: Option<SyntaxToken>,
+}
+impl ScssArgumentBuilder {
+    pub fn with_dotdotdot_token(mut self, dotdotdot_token: SyntaxToken) -> Self {
+        self.dotdotdot_token = Some(dotdotdot_token);
+        self
+    }
+    pub fn build(self) -> ScssArgument {
+        ScssArgument::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_ARGUMENT,
+            [
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.dotdotdot_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_arguments(
+    l_paren_token: SyntaxToken,
+    items: ScssArgumentList,
+    r_paren_token: SyntaxToken,
+) -> ScssArguments {
+    ScssArguments::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_ARGUMENTS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_content_at_rule(content_token: SyntaxToken) -> ScssContentAtRuleBuilder {
+    ScssContentAtRuleBuilder {
+        content_token,
+        arguments: None,
+        semicolon_token: None,
+    }
+}
+pub struct ScssContentAtRuleBuilder {
+    content_token: SyntaxToken,
+    arguments: Option<ScssArguments>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssContentAtRuleBuilder {
+    pub fn with_arguments(mut self, arguments: ScssArguments) -> Self {
+        self.arguments = Some(arguments);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssContentAtRule {
+        ScssContentAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_CONTENT_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.content_token)),
+                self.arguments
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_declaration(
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    modifiers: ScssVariableModifierList,
+) -> ScssDeclarationBuilder {
+    ScssDeclarationBuilder {
+        name,
+        colon_token,
+        value,
+        modifiers,
+        semicolon_token: None,
+    }
+}
+pub struct ScssDeclarationBuilder {
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    modifiers: ScssVariableModifierList,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssDeclarationBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssDeclaration {
+        ScssDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_DECLARATION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                Some(SyntaxElement::Node(self.modifiers.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_each_at_rule(
+    each_token: SyntaxToken,
+    bindings: ScssEachBindingList,
+    in_token: SyntaxToken,
+    iterable: CssGenericComponentValueList,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssEachAtRule {
+    ScssEachAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_EACH_AT_RULE,
+        [
+            Some(SyntaxElement::Token(each_token)),
+            Some(SyntaxElement::Node(bindings.into_syntax())),
+            Some(SyntaxElement::Token(in_token)),
+            Some(SyntaxElement::Node(iterable.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_else_clause(
+    at_token: SyntaxToken,
+    else_token: SyntaxToken,
+    body: AnyScssElseClauseBody,
+) -> ScssElseClause {
+    ScssElseClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_ELSE_CLAUSE,
+        [
+            Some(SyntaxElement::Token(at_token)),
+            Some(SyntaxElement::Token(else_token)),
+            Some(SyntaxElement::Node(body.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_extend_at_rule(
+    extend_token: SyntaxToken,
+    selectors: CssSelectorList,
+) -> ScssExtendAtRuleBuilder {
+    ScssExtendAtRuleBuilder {
+        extend_token,
+        selectors,
+        optional: None,
+        semicolon_token: None,
+    }
+}
+pub struct ScssExtendAtRuleBuilder {
+    extend_token: SyntaxToken,
+    selectors: CssSelectorList,
+    optional: Option<ScssExtendOptionalModifier>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssExtendAtRuleBuilder {
+    pub fn with_optional(mut self, optional: ScssExtendOptionalModifier) -> Self {
+        self.optional = Some(optional);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssExtendAtRule {
+        ScssExtendAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_EXTEND_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.extend_token)),
+                Some(SyntaxElement::Node(self.selectors.into_syntax())),
+                self.optional
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_extend_optional_modifier(
+    excl_token: SyntaxToken,
+    optional_token: SyntaxToken,
+) -> ScssExtendOptionalModifier {
+    ScssExtendOptionalModifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_EXTEND_OPTIONAL_MODIFIER,
+        [
+            Some(SyntaxElement::Token(excl_token)),
+            Some(SyntaxElement::Token(optional_token)),
+        ],
+    ))
+}
+pub fn scss_for_at_rule(
+    for_token: SyntaxToken,
+    variable: ScssIdentifier,
+    from_token: SyntaxToken,
+    start: CssGenericComponentValueList,
+    range_token: SyntaxToken,
+    end: CssGenericComponentValueList,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssForAtRule {
+    ScssForAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FOR_AT_RULE,
+        [
+            Some(SyntaxElement::Token(for_token)),
+            Some(SyntaxElement::Node(variable.into_syntax())),
+            Some(SyntaxElement::Token(from_token)),
+            Some(SyntaxElement::Node(start.into_syntax())),
+            Some(SyntaxElement::Token(range_token)),
+            Some(SyntaxElement::Node(end.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_forward_as_clause(
+    as_token: SyntaxToken,
+    prefix: CssIdentifier,
+    star_token: SyntaxToken,
+) -> ScssForwardAsClause {
+    ScssForwardAsClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FORWARD_AS_CLAUSE,
+        [
+            Some(SyntaxElement::Token(as_token)),
+            Some(SyntaxElement::Node(prefix.into_syntax())),
+            Some(SyntaxElement::Token(star_token)),
+        ],
+    ))
+}
+pub fn scss_forward_at_rule(
+    forward_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+) -> ScssForwardAtRuleBuilder {
+    ScssForwardAtRuleBuilder {
+        forward_token,
+        url,
+        semicolon_token,
+        as_clause: None,
+        visibility: None,
+        configuration: None,
+    }
+}
+pub struct ScssForwardAtRuleBuilder {
+    forward_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+    as_clause: Option<ScssForwardAsClause>,
+    visibility: Option<ScssForwardVisibilityClause>,
+    configuration: Option<ScssModuleConfiguration>,
+}
+impl ScssForwardAtRuleBuilder {
+    pub fn with_as_clause(mut self, as_clause: ScssForwardAsClause) -> Self {
+        self.as_clause = Some(as_clause);
+        self
+    }
+    pub fn with_visibility(mut self, visibility: ScssForwardVisibilityClause) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+    pub fn with_configuration(mut self, configuration: ScssModuleConfiguration) -> Self {
+        self.configuration = Some(configuration);
+        self
+    }
+    pub fn build(self) -> ScssForwardAtRule {
+        ScssForwardAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_FORWARD_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.forward_token)),
+                Some(SyntaxElement::Node(self.url.into_syntax())),
+                self.as_clause
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.visibility
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.configuration
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.semicolon_token)),
+            ],
+        ))
+    }
+}
+pub fn scss_forward_visibility_clause(
+    modifier_token: SyntaxToken,
+    members: ScssForwardMemberList,
+) -> ScssForwardVisibilityClause {
+    ScssForwardVisibilityClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FORWARD_VISIBILITY_CLAUSE,
+        [
+            Some(SyntaxElement::Token(modifier_token)),
+            Some(SyntaxElement::Node(members.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_function_at_rule(
+    function_token: SyntaxToken,
+    name: CssIdentifier,
+    parameters: ScssParameters,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssFunctionAtRule {
+    ScssFunctionAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FUNCTION_AT_RULE,
+        [
+            Some(SyntaxElement::Token(function_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Node(parameters.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_identifier(dollar_token: SyntaxToken, name: CssIdentifier) -> ScssIdentifier {
+    ScssIdentifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_IDENTIFIER,
+        [
+            Some(SyntaxElement::Token(dollar_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_if_at_rule(
+    if_token: SyntaxToken,
+    condition: CssGenericComponentValueList,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssIfAtRuleBuilder {
+    ScssIfAtRuleBuilder {
+        if_token,
+        condition,
+        block,
+        else_clause: None,
+    }
+}
+pub struct ScssIfAtRuleBuilder {
+    if_token: SyntaxToken,
+    condition: CssGenericComponentValueList,
+    block: AnyCssDeclarationOrRuleBlock,
+    else_clause: Option<ScssElseClause>,
+}
+impl ScssIfAtRuleBuilder {
+    pub fn with_else_clause(mut self, else_clause: ScssElseClause) -> Self {
+        self.else_clause = Some(else_clause);
+        self
+    }
+    pub fn build(self) -> ScssIfAtRule {
+        ScssIfAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_IF_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.if_token)),
+                Some(SyntaxElement::Node(self.condition.into_syntax())),
+                Some(SyntaxElement::Node(self.block.into_syntax())),
+                self.else_clause
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn scss_include_at_rule(
+    include_token: SyntaxToken,
+    name: AnyScssIncludeName,
+) -> ScssIncludeAtRuleBuilder {
+    ScssIncludeAtRuleBuilder {
+        include_token,
+        name,
+        arguments: None,
+        block: None,
+        semicolon_token: None,
+    }
+}
+pub struct ScssIncludeAtRuleBuilder {
+    include_token: SyntaxToken,
+    name: AnyScssIncludeName,
+    arguments: Option<ScssArguments>,
+    block: Option<AnyCssDeclarationOrRuleBlock>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssIncludeAtRuleBuilder {
+    pub fn with_arguments(mut self, arguments: ScssArguments) -> Self {
+        self.arguments = Some(arguments);
+        self
+    }
+    pub fn with_block(mut self, block: AnyCssDeclarationOrRuleBlock) -> Self {
+        self.block = Some(block);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssIncludeAtRule {
+        ScssIncludeAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_INCLUDE_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.include_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.arguments
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.block
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_interpolated_identifier(
+    items: ScssInterpolatedIdentifierPartList,
+) -> ScssInterpolatedIdentifier {
+    ScssInterpolatedIdentifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_INTERPOLATED_IDENTIFIER,
+        [Some(SyntaxElement::Node(items.into_syntax()))],
+    ))
+}
+pub fn scss_interpolation(
+    hash_token: SyntaxToken,
+    l_curly_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    r_curly_token: SyntaxToken,
+) -> ScssInterpolation {
+    ScssInterpolation::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_INTERPOLATION,
+        [
+            Some(SyntaxElement::Token(hash_token)),
+            Some(SyntaxElement::Token(l_curly_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Token(r_curly_token)),
+        ],
+    ))
+}
+pub fn scss_keyword_argument(
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    modifiers: ScssVariableModifierList,
+) -> ScssKeywordArgument {
+    ScssKeywordArgument::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_KEYWORD_ARGUMENT,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Node(modifiers.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_map_expression(
+    l_paren_token: SyntaxToken,
+    pairs: ScssMapExpressionPairList,
+    r_paren_token: SyntaxToken,
+) -> ScssMapExpression {
+    ScssMapExpression::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_MAP_EXPRESSION,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(pairs.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_map_expression_pair(
+    key: AnyCssValue,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+) -> ScssMapExpressionPair {
+    ScssMapExpressionPair::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_MAP_EXPRESSION_PAIR,
+        [
+            Some(SyntaxElement::Node(key.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_mixin_at_rule(
+    mixin_token: SyntaxToken,
+    name: CssIdentifier,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssMixinAtRuleBuilder {
+    ScssMixinAtRuleBuilder {
+        mixin_token,
+        name,
+        block,
+        parameters: None,
+    }
+}
+pub struct ScssMixinAtRuleBuilder {
+    mixin_token: SyntaxToken,
+    name: CssIdentifier,
+    block: AnyCssDeclarationOrRuleBlock,
+    parameters: Option<ScssParameters>,
+}
+impl ScssMixinAtRuleBuilder {
+    pub fn with_parameters(mut self, parameters: ScssParameters) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+    pub fn build(self) -> ScssMixinAtRule {
+        ScssMixinAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_MIXIN_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.mixin_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.parameters
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.block.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn scss_module_configuration(
+    with_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    items: ScssKeywordArgumentList,
+    r_paren_token: SyntaxToken,
+) -> ScssModuleConfiguration {
+    ScssModuleConfiguration::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_MODULE_CONFIGURATION,
+        [
+            Some(SyntaxElement::Token(with_token)),
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_namespaced_name(
+    namespace: CssIdentifier,
+    dot_token: SyntaxToken,
+    name: CssIdentifier,
+) -> ScssNamespacedName {
+    ScssNamespacedName::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_NAMESPACED_NAME,
+        [
+            Some(SyntaxElement::Node(namespace.into_syntax())),
+            Some(SyntaxElement::Token(dot_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_operator(value_token: SyntaxToken) -> ScssOperator {
+    ScssOperator::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_OPERATOR,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn scss_parameter(name: ScssIdentifier) -> ScssParameterBuilder {
+    ScssParameterBuilder {
+        name,
+        default_value: None,
+        dotdotdot_token: None,
+    }
+}
+pub struct ScssParameterBuilder {
+    name: ScssIdentifier,
+    default_value: Option<ScssParameterDefaultValue>,
+    dotdotdot_token: Option<SyntaxToken>,
+}
+impl ScssParameterBuilder {
+    pub fn with_default_value(mut self, default_value: ScssParameterDefaultValue) -> Self {
+        self.default_value = Some(default_value);
+        self
+    }
+    pub fn with_dotdotdot_token(mut self, dotdotdot_token: SyntaxToken) -> Self {
+        self.dotdotdot_token = Some(dotdotdot_token);
+        self
+    }
+    pub fn build(self) -> ScssParameter {
+        ScssParameter::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_PARAMETER,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.default_value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.dotdotdot_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_parameter_default_value(
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+) -> ScssParameterDefaultValue {
+    ScssParameterDefaultValue::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARAMETER_DEFAULT_VALUE,
+        [
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_parameters(
+    l_paren_token: SyntaxToken,
+    items: ScssParameterList,
+    r_paren_token: SyntaxToken,
+) -> ScssParameters {
+    ScssParameters::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARAMETERS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_parenthesized_expression(
+    l_paren_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    r_paren_token: SyntaxToken,
+) -> ScssParenthesizedExpression {
+    ScssParenthesizedExpression::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARENTHESIZED_EXPRESSION,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_placeholder_selector(
+    percent_token: SyntaxToken,
+    name: CssCustomIdentifier,
+) -> ScssPlaceholderSelector {
+    ScssPlaceholderSelector::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PLACEHOLDER_SELECTOR,
+        [
+            Some(SyntaxElement::Token(percent_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_qualified_name(
+    module: CssIdentifier,
+    dot_token: SyntaxToken,
+    member: AnyScssModuleMember,
+) -> ScssQualifiedName {
+    ScssQualifiedName::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_QUALIFIED_NAME,
+        [
+            Some(SyntaxElement::Node(module.into_syntax())),
+            Some(SyntaxElement::Token(dot_token)),
+            Some(SyntaxElement::Node(member.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_return_at_rule(
+    return_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+) -> ScssReturnAtRuleBuilder {
+    ScssReturnAtRuleBuilder {
+        return_token,
+        value,
+        semicolon_token: None,
+    }
+}
+pub struct ScssReturnAtRuleBuilder {
+    return_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssReturnAtRuleBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssReturnAtRule {
+        ScssReturnAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_RETURN_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.return_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_use_all_namespace(star_token: SyntaxToken) -> ScssUseAllNamespace {
+    ScssUseAllNamespace::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_USE_ALL_NAMESPACE,
+        [Some(SyntaxElement::Token(star_token))],
+    ))
+}
+pub fn scss_use_as_clause(
+    as_token: SyntaxToken,
+    namespace: AnyScssUseNamespace,
+) -> ScssUseAsClause {
+    ScssUseAsClause::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_USE_AS_CLAUSE,
+        [
+            Some(SyntaxElement::Token(as_token)),
+            Some(SyntaxElement::Node(namespace.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_use_at_rule(
+    use_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+) -> ScssUseAtRuleBuilder {
+    ScssUseAtRuleBuilder {
+        use_token,
+        url,
+        semicolon_token,
+        as_clause: None,
+        configuration: None,
+    }
+}
+pub struct ScssUseAtRuleBuilder {
+    use_token: SyntaxToken,
+    url: CssString,
+    semicolon_token: SyntaxToken,
+    as_clause: Option<ScssUseAsClause>,
+    configuration: Option<ScssModuleConfiguration>,
+}
+impl ScssUseAtRuleBuilder {
+    pub fn with_as_clause(mut self, as_clause: ScssUseAsClause) -> Self {
+        self.as_clause = Some(as_clause);
+        self
+    }
+    pub fn with_configuration(mut self, configuration: ScssModuleConfiguration) -> Self {
+        self.configuration = Some(configuration);
+        self
+    }
+    pub fn build(self) -> ScssUseAtRule {
+        ScssUseAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_USE_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.use_token)),
+                Some(SyntaxElement::Node(self.url.into_syntax())),
+                self.as_clause
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.configuration
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.semicolon_token)),
+            ],
+        ))
+    }
+}
+pub fn scss_variable_modifier(
+    excl_token: SyntaxToken,
+    value_token: SyntaxToken,
+) -> ScssVariableModifier {
+    ScssVariableModifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_VARIABLE_MODIFIER,
+        [
+            Some(SyntaxElement::Token(excl_token)),
+            Some(SyntaxElement::Token(value_token)),
+        ],
+    ))
+}
+pub fn scss_while_at_rule(
+    while_token: SyntaxToken,
+    condition: CssGenericComponentValueList,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> ScssWhileAtRule {
+    ScssWhileAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_WHILE_AT_RULE,
+        [
+            Some(SyntaxElement::Token(while_token)),
+            Some(SyntaxElement::Node(condition.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
 pub fn css_bracketed_value_list<I>(items: I) -> CssBracketedValueList
 where
     I: IntoIterator<Item = AnyCssCustomIdentifier>,
@@ -2821,6 +4127,213 @@ where
         }),
     ))
 }
+pub fn less_import_option_list<I, S>(items: I, separators: S) -> LessImportOptionList
+where
+    I: IntoIterator<Item = CssIdentifier>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    LessImportOptionList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_IMPORT_OPTION_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn less_mixin_argument_list<I>(items: I) -> LessMixinArgumentList
+where
+    I: IntoIterator<Item = AnyLessMixinArgument>,
+    I::IntoIter: ExactSizeIterator,
+{
+    LessMixinArgumentList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_ARGUMENT_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn less_mixin_parameter_list<I>(items: I) -> LessMixinParameterList
+where
+    I: IntoIterator<Item = AnyLessMixinParameter>,
+    I::IntoIter: ExactSizeIterator,
+{
+    LessMixinParameterList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_PARAMETER_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn less_mixin_path_segment_list<I>(items: I) -> LessMixinPathSegmentList
+where
+    I: IntoIterator<Item = LessMixinPathSegment>,
+    I::IntoIter: ExactSizeIterator,
+{
+    LessMixinPathSegmentList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_PATH_SEGMENT_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn scss_argument_list<I, S>(items: I, separators: S) -> ScssArgumentList
+where
+    I: IntoIterator<Item = AnyScssArgument>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssArgumentList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_ARGUMENT_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_each_binding_list<I, S>(items: I, separators: S) -> ScssEachBindingList
+where
+    I: IntoIterator<Item = ScssIdentifier>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssEachBindingList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_EACH_BINDING_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_forward_member_list<I, S>(items: I, separators: S) -> ScssForwardMemberList
+where
+    I: IntoIterator<Item = AnyScssForwardMember>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssForwardMemberList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_FORWARD_MEMBER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_interpolated_identifier_part_list<I>(items: I) -> ScssInterpolatedIdentifierPartList
+where
+    I: IntoIterator<Item = AnyScssInterpolatedIdentifierPart>,
+    I::IntoIter: ExactSizeIterator,
+{
+    ScssInterpolatedIdentifierPartList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_INTERPOLATED_IDENTIFIER_PART_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn scss_keyword_argument_list<I, S>(items: I, separators: S) -> ScssKeywordArgumentList
+where
+    I: IntoIterator<Item = ScssKeywordArgument>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssKeywordArgumentList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_KEYWORD_ARGUMENT_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_map_expression_pair_list<I, S>(items: I, separators: S) -> ScssMapExpressionPairList
+where
+    I: IntoIterator<Item = ScssMapExpressionPair>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssMapExpressionPairList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_MAP_EXPRESSION_PAIR_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_parameter_list<I, S>(items: I, separators: S) -> ScssParameterList
+where
+    I: IntoIterator<Item = ScssParameter>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    ScssParameterList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_PARAMETER_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn scss_variable_modifier_list<I>(items: I) -> ScssVariableModifierList
+where
+    I: IntoIterator<Item = ScssVariableModifier>,
+    I::IntoIter: ExactSizeIterator,
+{
+    ScssVariableModifierList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_VARIABLE_MODIFIER_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn css_bogus<I>(slots: I) -> CssBogus
 where
     I: IntoIterator<Item = Option<SyntaxElement>>,
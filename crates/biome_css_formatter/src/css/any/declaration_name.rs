@@ -10,6 +10,8 @@ impl FormatRule<AnyCssDeclarationName> for FormatAnyCssDeclarationName {
         match node {
             AnyCssDeclarationName::CssDashedIdentifier(node) => node.format().fmt(f),
             AnyCssDeclarationName::CssIdentifier(node) => node.format().fmt(f),
+            AnyCssDeclarationName::ScssInterpolatedIdentifier(node) => node.format().fmt(f),
+            AnyCssDeclarationName::ScssInterpolation(node) => node.format().fmt(f),
         }
     }
 }
@@ -13,6 +13,11 @@ impl FormatRule<AnyCssQueryFeatureValue> for FormatAnyCssQueryFeatureValue {
             AnyCssQueryFeatureValue::CssIdentifier(node) => node.format().fmt(f),
             AnyCssQueryFeatureValue::CssNumber(node) => node.format().fmt(f),
             AnyCssQueryFeatureValue::CssRatio(node) => node.format().fmt(f),
+            AnyCssQueryFeatureValue::LessIdentifier(node) => node.format().fmt(f),
+            AnyCssQueryFeatureValue::ScssIdentifier(node) => node.format().fmt(f),
+            AnyCssQueryFeatureValue::ScssInterpolatedIdentifier(node) => node.format().fmt(f),
+            AnyCssQueryFeatureValue::ScssInterpolation(node) => node.format().fmt(f),
+            AnyCssQueryFeatureValue::ScssQualifiedName(node) => node.format().fmt(f),
         }
     }
 }
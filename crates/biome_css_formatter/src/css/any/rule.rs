@@ -12,6 +12,12 @@ impl FormatRule<AnyCssRule> for FormatAnyCssRule {
             AnyCssRule::CssBogusRule(node) => node.format().fmt(f),
             AnyCssRule::CssNestedQualifiedRule(node) => node.format().fmt(f),
             AnyCssRule::CssQualifiedRule(node) => node.format().fmt(f),
+            AnyCssRule::LessDetachedRulesetCall(node) => node.format().fmt(f),
+            AnyCssRule::LessDetachedRulesetDeclaration(node) => node.format().fmt(f),
+            AnyCssRule::LessMixinCall(node) => node.format().fmt(f),
+            AnyCssRule::LessMixinDeclaration(node) => node.format().fmt(f),
+            AnyCssRule::LessVariableDeclaration(node) => node.format().fmt(f),
+            AnyCssRule::ScssDeclaration(node) => node.format().fmt(f),
         }
     }
 }
@@ -14,6 +14,8 @@ impl FormatRule<AnyCssSubSelector> for FormatAnyCssSubSelector {
             AnyCssSubSelector::CssIdSelector(node) => node.format().fmt(f),
             AnyCssSubSelector::CssPseudoClassSelector(node) => node.format().fmt(f),
             AnyCssSubSelector::CssPseudoElementSelector(node) => node.format().fmt(f),
+            AnyCssSubSelector::ScssInterpolation(node) => node.format().fmt(f),
+            AnyCssSubSelector::ScssPlaceholderSelector(node) => node.format().fmt(f),
         }
     }
 }
@@ -20,6 +20,15 @@ impl FormatRule<AnyCssValue> for FormatAnyCssValue {
             AnyCssValue::CssRatio(node) => node.format().fmt(f),
             AnyCssValue::CssString(node) => node.format().fmt(f),
             AnyCssValue::CssUnicodeRange(node) => node.format().fmt(f),
+            AnyCssValue::LessEscapedValue(node) => node.format().fmt(f),
+            AnyCssValue::LessIdentifier(node) => node.format().fmt(f),
+            AnyCssValue::LessParenthesizedExpression(node) => node.format().fmt(f),
+            AnyCssValue::ScssIdentifier(node) => node.format().fmt(f),
+            AnyCssValue::ScssInterpolatedIdentifier(node) => node.format().fmt(f),
+            AnyCssValue::ScssInterpolation(node) => node.format().fmt(f),
+            AnyCssValue::ScssMapExpression(node) => node.format().fmt(f),
+            AnyCssValue::ScssParenthesizedExpression(node) => node.format().fmt(f),
+            AnyCssValue::ScssQualifiedName(node) => node.format().fmt(f),
         }
     }
 }
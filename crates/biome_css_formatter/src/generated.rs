@@ -5438,6 +5438,2218 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssViewTransitionAtRule
         )
     }
 }
+impl FormatRule<biome_css_syntax::LessDetachedRulesetCall>
+    for crate::less::auxiliary::detached_ruleset_call::FormatLessDetachedRulesetCall
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessDetachedRulesetCall,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessDetachedRulesetCall>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessDetachedRulesetCall {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessDetachedRulesetCall,
+        crate::less::auxiliary::detached_ruleset_call::FormatLessDetachedRulesetCall,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::detached_ruleset_call::FormatLessDetachedRulesetCall::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessDetachedRulesetCall {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessDetachedRulesetCall,
+        crate::less::auxiliary::detached_ruleset_call::FormatLessDetachedRulesetCall,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::detached_ruleset_call::FormatLessDetachedRulesetCall::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessDetachedRulesetDeclaration>
+    for crate::less::auxiliary::detached_ruleset_declaration::FormatLessDetachedRulesetDeclaration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessDetachedRulesetDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessDetachedRulesetDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessDetachedRulesetDeclaration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessDetachedRulesetDeclaration,
+        crate::less::auxiliary::detached_ruleset_declaration::FormatLessDetachedRulesetDeclaration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: less :: auxiliary :: detached_ruleset_declaration :: FormatLessDetachedRulesetDeclaration :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessDetachedRulesetDeclaration {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessDetachedRulesetDeclaration,
+        crate::less::auxiliary::detached_ruleset_declaration::FormatLessDetachedRulesetDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: less :: auxiliary :: detached_ruleset_declaration :: FormatLessDetachedRulesetDeclaration :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::LessEscapedValue>
+    for crate::less::auxiliary::escaped_value::FormatLessEscapedValue
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessEscapedValue,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessEscapedValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessEscapedValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessEscapedValue,
+        crate::less::auxiliary::escaped_value::FormatLessEscapedValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::escaped_value::FormatLessEscapedValue::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessEscapedValue {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessEscapedValue,
+        crate::less::auxiliary::escaped_value::FormatLessEscapedValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::escaped_value::FormatLessEscapedValue::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessGuardCondition>
+    for crate::less::auxiliary::guard_condition::FormatLessGuardCondition
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessGuardCondition,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessGuardCondition>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessGuardCondition {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessGuardCondition,
+        crate::less::auxiliary::guard_condition::FormatLessGuardCondition,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::guard_condition::FormatLessGuardCondition::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessGuardCondition {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessGuardCondition,
+        crate::less::auxiliary::guard_condition::FormatLessGuardCondition,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::guard_condition::FormatLessGuardCondition::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessGuardLogicalCondition>
+    for crate::less::auxiliary::guard_logical_condition::FormatLessGuardLogicalCondition
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessGuardLogicalCondition,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessGuardLogicalCondition>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessGuardLogicalCondition {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessGuardLogicalCondition,
+        crate::less::auxiliary::guard_logical_condition::FormatLessGuardLogicalCondition,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: less :: auxiliary :: guard_logical_condition :: FormatLessGuardLogicalCondition :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessGuardLogicalCondition {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessGuardLogicalCondition,
+        crate::less::auxiliary::guard_logical_condition::FormatLessGuardLogicalCondition,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: less :: auxiliary :: guard_logical_condition :: FormatLessGuardLogicalCondition :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::LessIdentifier>
+    for crate::less::value::identifier::FormatLessIdentifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessIdentifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessIdentifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessIdentifier,
+        crate::less::value::identifier::FormatLessIdentifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::value::identifier::FormatLessIdentifier::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessIdentifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessIdentifier,
+        crate::less::value::identifier::FormatLessIdentifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::value::identifier::FormatLessIdentifier::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessImportAtRule>
+    for crate::less::statements::import_at_rule::FormatLessImportAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessImportAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessImportAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessImportAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessImportAtRule,
+        crate::less::statements::import_at_rule::FormatLessImportAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::statements::import_at_rule::FormatLessImportAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessImportAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessImportAtRule,
+        crate::less::statements::import_at_rule::FormatLessImportAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::statements::import_at_rule::FormatLessImportAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessImportOptions>
+    for crate::less::auxiliary::import_options::FormatLessImportOptions
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessImportOptions,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessImportOptions>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessImportOptions {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessImportOptions,
+        crate::less::auxiliary::import_options::FormatLessImportOptions,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::import_options::FormatLessImportOptions::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessImportOptions {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessImportOptions,
+        crate::less::auxiliary::import_options::FormatLessImportOptions,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::import_options::FormatLessImportOptions::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinArgument>
+    for crate::less::auxiliary::mixin_argument::FormatLessMixinArgument
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinArgument,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinArgument>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinArgument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinArgument,
+        crate::less::auxiliary::mixin_argument::FormatLessMixinArgument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_argument::FormatLessMixinArgument::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinArgument {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinArgument,
+        crate::less::auxiliary::mixin_argument::FormatLessMixinArgument,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_argument::FormatLessMixinArgument::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinArguments>
+    for crate::less::auxiliary::mixin_arguments::FormatLessMixinArguments
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinArguments,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinArguments>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinArguments {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinArguments,
+        crate::less::auxiliary::mixin_arguments::FormatLessMixinArguments,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_arguments::FormatLessMixinArguments::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinArguments {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinArguments,
+        crate::less::auxiliary::mixin_arguments::FormatLessMixinArguments,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_arguments::FormatLessMixinArguments::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinCall>
+    for crate::less::auxiliary::mixin_call::FormatLessMixinCall
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinCall,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinCall>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinCall {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinCall,
+        crate::less::auxiliary::mixin_call::FormatLessMixinCall,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_call::FormatLessMixinCall::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinCall {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinCall,
+        crate::less::auxiliary::mixin_call::FormatLessMixinCall,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_call::FormatLessMixinCall::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinDeclaration>
+    for crate::less::auxiliary::mixin_declaration::FormatLessMixinDeclaration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinDeclaration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinDeclaration,
+        crate::less::auxiliary::mixin_declaration::FormatLessMixinDeclaration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_declaration::FormatLessMixinDeclaration::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinDeclaration {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinDeclaration,
+        crate::less::auxiliary::mixin_declaration::FormatLessMixinDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_declaration::FormatLessMixinDeclaration::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinGuard>
+    for crate::less::auxiliary::mixin_guard::FormatLessMixinGuard
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinGuard,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinGuard>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinGuard {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinGuard,
+        crate::less::auxiliary::mixin_guard::FormatLessMixinGuard,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_guard::FormatLessMixinGuard::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinGuard {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinGuard,
+        crate::less::auxiliary::mixin_guard::FormatLessMixinGuard,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_guard::FormatLessMixinGuard::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinNamedArgument>
+    for crate::less::auxiliary::mixin_named_argument::FormatLessMixinNamedArgument
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinNamedArgument,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinNamedArgument>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinNamedArgument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinNamedArgument,
+        crate::less::auxiliary::mixin_named_argument::FormatLessMixinNamedArgument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_named_argument::FormatLessMixinNamedArgument::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinNamedArgument {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinNamedArgument,
+        crate::less::auxiliary::mixin_named_argument::FormatLessMixinNamedArgument,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_named_argument::FormatLessMixinNamedArgument::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinParameter>
+    for crate::less::auxiliary::mixin_parameter::FormatLessMixinParameter
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinParameter,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinParameter>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinParameter {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinParameter,
+        crate::less::auxiliary::mixin_parameter::FormatLessMixinParameter,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_parameter::FormatLessMixinParameter::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinParameter {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinParameter,
+        crate::less::auxiliary::mixin_parameter::FormatLessMixinParameter,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_parameter::FormatLessMixinParameter::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinParameterDefaultValue>
+    for crate::less::auxiliary::mixin_parameter_default_value::FormatLessMixinParameterDefaultValue
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinParameterDefaultValue,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinParameterDefaultValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinParameterDefaultValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinParameterDefaultValue,
+        crate::less::auxiliary::mixin_parameter_default_value::FormatLessMixinParameterDefaultValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: less :: auxiliary :: mixin_parameter_default_value :: FormatLessMixinParameterDefaultValue :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinParameterDefaultValue {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinParameterDefaultValue,
+        crate::less::auxiliary::mixin_parameter_default_value::FormatLessMixinParameterDefaultValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: less :: auxiliary :: mixin_parameter_default_value :: FormatLessMixinParameterDefaultValue :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinParameters>
+    for crate::less::auxiliary::mixin_parameters::FormatLessMixinParameters
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinParameters,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinParameters>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinParameters {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinParameters,
+        crate::less::auxiliary::mixin_parameters::FormatLessMixinParameters,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_parameters::FormatLessMixinParameters::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinParameters {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinParameters,
+        crate::less::auxiliary::mixin_parameters::FormatLessMixinParameters,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_parameters::FormatLessMixinParameters::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinPathSegment>
+    for crate::less::auxiliary::mixin_path_segment::FormatLessMixinPathSegment
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinPathSegment,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinPathSegment>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinPathSegment {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinPathSegment,
+        crate::less::auxiliary::mixin_path_segment::FormatLessMixinPathSegment,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_path_segment::FormatLessMixinPathSegment::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinPathSegment {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinPathSegment,
+        crate::less::auxiliary::mixin_path_segment::FormatLessMixinPathSegment,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_path_segment::FormatLessMixinPathSegment::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinPatternParameter>
+    for crate::less::auxiliary::mixin_pattern_parameter::FormatLessMixinPatternParameter
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinPatternParameter,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinPatternParameter>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinPatternParameter {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinPatternParameter,
+        crate::less::auxiliary::mixin_pattern_parameter::FormatLessMixinPatternParameter,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: less :: auxiliary :: mixin_pattern_parameter :: FormatLessMixinPatternParameter :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinPatternParameter {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinPatternParameter,
+        crate::less::auxiliary::mixin_pattern_parameter::FormatLessMixinPatternParameter,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: less :: auxiliary :: mixin_pattern_parameter :: FormatLessMixinPatternParameter :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinRestParameter>
+    for crate::less::auxiliary::mixin_rest_parameter::FormatLessMixinRestParameter
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinRestParameter,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinRestParameter>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinRestParameter {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinRestParameter,
+        crate::less::auxiliary::mixin_rest_parameter::FormatLessMixinRestParameter,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_rest_parameter::FormatLessMixinRestParameter::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinRestParameter {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinRestParameter,
+        crate::less::auxiliary::mixin_rest_parameter::FormatLessMixinRestParameter,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_rest_parameter::FormatLessMixinRestParameter::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessOperator>
+    for crate::less::auxiliary::operator::FormatLessOperator
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::LessOperator, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessOperator>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessOperator {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessOperator,
+        crate::less::auxiliary::operator::FormatLessOperator,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::operator::FormatLessOperator::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessOperator {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessOperator,
+        crate::less::auxiliary::operator::FormatLessOperator,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::operator::FormatLessOperator::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessParenthesizedExpression>
+    for crate::less::auxiliary::parenthesized_expression::FormatLessParenthesizedExpression
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessParenthesizedExpression,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessParenthesizedExpression>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessParenthesizedExpression {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessParenthesizedExpression,
+        crate::less::auxiliary::parenthesized_expression::FormatLessParenthesizedExpression,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: less :: auxiliary :: parenthesized_expression :: FormatLessParenthesizedExpression :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessParenthesizedExpression {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessParenthesizedExpression,
+        crate::less::auxiliary::parenthesized_expression::FormatLessParenthesizedExpression,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: less :: auxiliary :: parenthesized_expression :: FormatLessParenthesizedExpression :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::LessVariableDeclaration>
+    for crate::less::auxiliary::variable_declaration::FormatLessVariableDeclaration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessVariableDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessVariableDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessVariableDeclaration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessVariableDeclaration,
+        crate::less::auxiliary::variable_declaration::FormatLessVariableDeclaration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::variable_declaration::FormatLessVariableDeclaration::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessVariableDeclaration {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessVariableDeclaration,
+        crate::less::auxiliary::variable_declaration::FormatLessVariableDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::variable_declaration::FormatLessVariableDeclaration::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssArgument>
+    for crate::scss::auxiliary::argument::FormatScssArgument
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::ScssArgument, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssArgument>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssArgument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssArgument,
+        crate::scss::auxiliary::argument::FormatScssArgument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::argument::FormatScssArgument::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssArgument {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssArgument,
+        crate::scss::auxiliary::argument::FormatScssArgument,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::argument::FormatScssArgument::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssArguments>
+    for crate::scss::auxiliary::arguments::FormatScssArguments
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssArguments,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssArguments>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssArguments {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssArguments,
+        crate::scss::auxiliary::arguments::FormatScssArguments,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::arguments::FormatScssArguments::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssArguments {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssArguments,
+        crate::scss::auxiliary::arguments::FormatScssArguments,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::arguments::FormatScssArguments::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssContentAtRule>
+    for crate::scss::statements::content_at_rule::FormatScssContentAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssContentAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssContentAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssContentAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssContentAtRule,
+        crate::scss::statements::content_at_rule::FormatScssContentAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::content_at_rule::FormatScssContentAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssContentAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssContentAtRule,
+        crate::scss::statements::content_at_rule::FormatScssContentAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::content_at_rule::FormatScssContentAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssDeclaration>
+    for crate::scss::auxiliary::declaration::FormatScssDeclaration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssDeclaration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssDeclaration,
+        crate::scss::auxiliary::declaration::FormatScssDeclaration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::declaration::FormatScssDeclaration::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssDeclaration {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssDeclaration,
+        crate::scss::auxiliary::declaration::FormatScssDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::declaration::FormatScssDeclaration::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssEachAtRule>
+    for crate::scss::statements::each_at_rule::FormatScssEachAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssEachAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssEachAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssEachAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssEachAtRule,
+        crate::scss::statements::each_at_rule::FormatScssEachAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::each_at_rule::FormatScssEachAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssEachAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssEachAtRule,
+        crate::scss::statements::each_at_rule::FormatScssEachAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::each_at_rule::FormatScssEachAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssElseClause>
+    for crate::scss::auxiliary::else_clause::FormatScssElseClause
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssElseClause,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssElseClause>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssElseClause {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssElseClause,
+        crate::scss::auxiliary::else_clause::FormatScssElseClause,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::else_clause::FormatScssElseClause::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssElseClause {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssElseClause,
+        crate::scss::auxiliary::else_clause::FormatScssElseClause,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::else_clause::FormatScssElseClause::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssExtendAtRule>
+    for crate::scss::statements::extend_at_rule::FormatScssExtendAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssExtendAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssExtendAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssExtendAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssExtendAtRule,
+        crate::scss::statements::extend_at_rule::FormatScssExtendAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::extend_at_rule::FormatScssExtendAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssExtendAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssExtendAtRule,
+        crate::scss::statements::extend_at_rule::FormatScssExtendAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::extend_at_rule::FormatScssExtendAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssExtendOptionalModifier>
+    for crate::scss::auxiliary::extend_optional_modifier::FormatScssExtendOptionalModifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssExtendOptionalModifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssExtendOptionalModifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssExtendOptionalModifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssExtendOptionalModifier,
+        crate::scss::auxiliary::extend_optional_modifier::FormatScssExtendOptionalModifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: scss :: auxiliary :: extend_optional_modifier :: FormatScssExtendOptionalModifier :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssExtendOptionalModifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssExtendOptionalModifier,
+        crate::scss::auxiliary::extend_optional_modifier::FormatScssExtendOptionalModifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: scss :: auxiliary :: extend_optional_modifier :: FormatScssExtendOptionalModifier :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::ScssForAtRule>
+    for crate::scss::statements::for_at_rule::FormatScssForAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssForAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssForAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssForAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssForAtRule,
+        crate::scss::statements::for_at_rule::FormatScssForAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::for_at_rule::FormatScssForAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssForAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssForAtRule,
+        crate::scss::statements::for_at_rule::FormatScssForAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::for_at_rule::FormatScssForAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssForwardAsClause>
+    for crate::scss::auxiliary::forward_as_clause::FormatScssForwardAsClause
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssForwardAsClause,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssForwardAsClause>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssForwardAsClause {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssForwardAsClause,
+        crate::scss::auxiliary::forward_as_clause::FormatScssForwardAsClause,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::forward_as_clause::FormatScssForwardAsClause::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssForwardAsClause {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssForwardAsClause,
+        crate::scss::auxiliary::forward_as_clause::FormatScssForwardAsClause,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::forward_as_clause::FormatScssForwardAsClause::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssForwardAtRule>
+    for crate::scss::statements::forward_at_rule::FormatScssForwardAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssForwardAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssForwardAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssForwardAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssForwardAtRule,
+        crate::scss::statements::forward_at_rule::FormatScssForwardAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::forward_at_rule::FormatScssForwardAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssForwardAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssForwardAtRule,
+        crate::scss::statements::forward_at_rule::FormatScssForwardAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::forward_at_rule::FormatScssForwardAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssForwardVisibilityClause>
+    for crate::scss::auxiliary::forward_visibility_clause::FormatScssForwardVisibilityClause
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssForwardVisibilityClause,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssForwardVisibilityClause>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssForwardVisibilityClause {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssForwardVisibilityClause,
+        crate::scss::auxiliary::forward_visibility_clause::FormatScssForwardVisibilityClause,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: scss :: auxiliary :: forward_visibility_clause :: FormatScssForwardVisibilityClause :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssForwardVisibilityClause {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssForwardVisibilityClause,
+        crate::scss::auxiliary::forward_visibility_clause::FormatScssForwardVisibilityClause,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: scss :: auxiliary :: forward_visibility_clause :: FormatScssForwardVisibilityClause :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::ScssFunctionAtRule>
+    for crate::scss::statements::function_at_rule::FormatScssFunctionAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssFunctionAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssFunctionAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssFunctionAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssFunctionAtRule,
+        crate::scss::statements::function_at_rule::FormatScssFunctionAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::function_at_rule::FormatScssFunctionAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssFunctionAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssFunctionAtRule,
+        crate::scss::statements::function_at_rule::FormatScssFunctionAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::function_at_rule::FormatScssFunctionAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssIdentifier>
+    for crate::scss::value::identifier::FormatScssIdentifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssIdentifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssIdentifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssIdentifier,
+        crate::scss::value::identifier::FormatScssIdentifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::value::identifier::FormatScssIdentifier::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssIdentifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssIdentifier,
+        crate::scss::value::identifier::FormatScssIdentifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::value::identifier::FormatScssIdentifier::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssIfAtRule>
+    for crate::scss::statements::if_at_rule::FormatScssIfAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::ScssIfAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssIfAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssIfAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssIfAtRule,
+        crate::scss::statements::if_at_rule::FormatScssIfAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::if_at_rule::FormatScssIfAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssIfAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssIfAtRule,
+        crate::scss::statements::if_at_rule::FormatScssIfAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::if_at_rule::FormatScssIfAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssIncludeAtRule>
+    for crate::scss::statements::include_at_rule::FormatScssIncludeAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssIncludeAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssIncludeAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssIncludeAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssIncludeAtRule,
+        crate::scss::statements::include_at_rule::FormatScssIncludeAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::include_at_rule::FormatScssIncludeAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssIncludeAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssIncludeAtRule,
+        crate::scss::statements::include_at_rule::FormatScssIncludeAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::include_at_rule::FormatScssIncludeAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssInterpolatedIdentifier>
+    for crate::scss::value::interpolated_identifier::FormatScssInterpolatedIdentifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssInterpolatedIdentifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssInterpolatedIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssInterpolatedIdentifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssInterpolatedIdentifier,
+        crate::scss::value::interpolated_identifier::FormatScssInterpolatedIdentifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::value::interpolated_identifier::FormatScssInterpolatedIdentifier::default(
+            ),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssInterpolatedIdentifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssInterpolatedIdentifier,
+        crate::scss::value::interpolated_identifier::FormatScssInterpolatedIdentifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::value::interpolated_identifier::FormatScssInterpolatedIdentifier::default(
+            ),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssInterpolation>
+    for crate::scss::auxiliary::interpolation::FormatScssInterpolation
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssInterpolation,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssInterpolation>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssInterpolation {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssInterpolation,
+        crate::scss::auxiliary::interpolation::FormatScssInterpolation,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::interpolation::FormatScssInterpolation::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssInterpolation {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssInterpolation,
+        crate::scss::auxiliary::interpolation::FormatScssInterpolation,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::interpolation::FormatScssInterpolation::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssKeywordArgument>
+    for crate::scss::auxiliary::keyword_argument::FormatScssKeywordArgument
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssKeywordArgument,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssKeywordArgument>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssKeywordArgument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssKeywordArgument,
+        crate::scss::auxiliary::keyword_argument::FormatScssKeywordArgument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::keyword_argument::FormatScssKeywordArgument::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssKeywordArgument {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssKeywordArgument,
+        crate::scss::auxiliary::keyword_argument::FormatScssKeywordArgument,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::keyword_argument::FormatScssKeywordArgument::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssMapExpression>
+    for crate::scss::auxiliary::map_expression::FormatScssMapExpression
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssMapExpression,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssMapExpression>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssMapExpression {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssMapExpression,
+        crate::scss::auxiliary::map_expression::FormatScssMapExpression,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::map_expression::FormatScssMapExpression::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssMapExpression {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssMapExpression,
+        crate::scss::auxiliary::map_expression::FormatScssMapExpression,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::map_expression::FormatScssMapExpression::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssMapExpressionPair>
+    for crate::scss::auxiliary::map_expression_pair::FormatScssMapExpressionPair
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssMapExpressionPair,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssMapExpressionPair>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssMapExpressionPair {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssMapExpressionPair,
+        crate::scss::auxiliary::map_expression_pair::FormatScssMapExpressionPair,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::map_expression_pair::FormatScssMapExpressionPair::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssMapExpressionPair {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssMapExpressionPair,
+        crate::scss::auxiliary::map_expression_pair::FormatScssMapExpressionPair,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::map_expression_pair::FormatScssMapExpressionPair::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssMixinAtRule>
+    for crate::scss::statements::mixin_at_rule::FormatScssMixinAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssMixinAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssMixinAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssMixinAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssMixinAtRule,
+        crate::scss::statements::mixin_at_rule::FormatScssMixinAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::mixin_at_rule::FormatScssMixinAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssMixinAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssMixinAtRule,
+        crate::scss::statements::mixin_at_rule::FormatScssMixinAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::mixin_at_rule::FormatScssMixinAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssModuleConfiguration>
+    for crate::scss::auxiliary::module_configuration::FormatScssModuleConfiguration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssModuleConfiguration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssModuleConfiguration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssModuleConfiguration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssModuleConfiguration,
+        crate::scss::auxiliary::module_configuration::FormatScssModuleConfiguration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::module_configuration::FormatScssModuleConfiguration::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssModuleConfiguration {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssModuleConfiguration,
+        crate::scss::auxiliary::module_configuration::FormatScssModuleConfiguration,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::module_configuration::FormatScssModuleConfiguration::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssNamespacedName>
+    for crate::scss::auxiliary::namespaced_name::FormatScssNamespacedName
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssNamespacedName,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssNamespacedName>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssNamespacedName {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssNamespacedName,
+        crate::scss::auxiliary::namespaced_name::FormatScssNamespacedName,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::namespaced_name::FormatScssNamespacedName::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssNamespacedName {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssNamespacedName,
+        crate::scss::auxiliary::namespaced_name::FormatScssNamespacedName,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::namespaced_name::FormatScssNamespacedName::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssOperator>
+    for crate::scss::auxiliary::operator::FormatScssOperator
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::ScssOperator, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssOperator>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssOperator {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssOperator,
+        crate::scss::auxiliary::operator::FormatScssOperator,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::operator::FormatScssOperator::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssOperator {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssOperator,
+        crate::scss::auxiliary::operator::FormatScssOperator,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::operator::FormatScssOperator::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssParameter>
+    for crate::scss::auxiliary::parameter::FormatScssParameter
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssParameter,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssParameter>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssParameter {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssParameter,
+        crate::scss::auxiliary::parameter::FormatScssParameter,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::parameter::FormatScssParameter::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssParameter {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssParameter,
+        crate::scss::auxiliary::parameter::FormatScssParameter,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::parameter::FormatScssParameter::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssParameterDefaultValue>
+    for crate::scss::auxiliary::parameter_default_value::FormatScssParameterDefaultValue
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssParameterDefaultValue,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssParameterDefaultValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssParameterDefaultValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssParameterDefaultValue,
+        crate::scss::auxiliary::parameter_default_value::FormatScssParameterDefaultValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: scss :: auxiliary :: parameter_default_value :: FormatScssParameterDefaultValue :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssParameterDefaultValue {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssParameterDefaultValue,
+        crate::scss::auxiliary::parameter_default_value::FormatScssParameterDefaultValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: scss :: auxiliary :: parameter_default_value :: FormatScssParameterDefaultValue :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::ScssParameters>
+    for crate::scss::auxiliary::parameters::FormatScssParameters
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssParameters,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssParameters>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssParameters {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssParameters,
+        crate::scss::auxiliary::parameters::FormatScssParameters,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::parameters::FormatScssParameters::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssParameters {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssParameters,
+        crate::scss::auxiliary::parameters::FormatScssParameters,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::parameters::FormatScssParameters::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssParenthesizedExpression>
+    for crate::scss::auxiliary::parenthesized_expression::FormatScssParenthesizedExpression
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssParenthesizedExpression,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssParenthesizedExpression>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssParenthesizedExpression {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssParenthesizedExpression,
+        crate::scss::auxiliary::parenthesized_expression::FormatScssParenthesizedExpression,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: scss :: auxiliary :: parenthesized_expression :: FormatScssParenthesizedExpression :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssParenthesizedExpression {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssParenthesizedExpression,
+        crate::scss::auxiliary::parenthesized_expression::FormatScssParenthesizedExpression,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: scss :: auxiliary :: parenthesized_expression :: FormatScssParenthesizedExpression :: default ())
+    }
+}
+impl FormatRule<biome_css_syntax::ScssPlaceholderSelector>
+    for crate::scss::selectors::placeholder_selector::FormatScssPlaceholderSelector
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssPlaceholderSelector,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssPlaceholderSelector>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssPlaceholderSelector {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssPlaceholderSelector,
+        crate::scss::selectors::placeholder_selector::FormatScssPlaceholderSelector,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::selectors::placeholder_selector::FormatScssPlaceholderSelector::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssPlaceholderSelector {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssPlaceholderSelector,
+        crate::scss::selectors::placeholder_selector::FormatScssPlaceholderSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::selectors::placeholder_selector::FormatScssPlaceholderSelector::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssQualifiedName>
+    for crate::scss::auxiliary::qualified_name::FormatScssQualifiedName
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssQualifiedName,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssQualifiedName>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssQualifiedName {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssQualifiedName,
+        crate::scss::auxiliary::qualified_name::FormatScssQualifiedName,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::qualified_name::FormatScssQualifiedName::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssQualifiedName {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssQualifiedName,
+        crate::scss::auxiliary::qualified_name::FormatScssQualifiedName,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::qualified_name::FormatScssQualifiedName::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssReturnAtRule>
+    for crate::scss::statements::return_at_rule::FormatScssReturnAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssReturnAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssReturnAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssReturnAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssReturnAtRule,
+        crate::scss::statements::return_at_rule::FormatScssReturnAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::return_at_rule::FormatScssReturnAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssReturnAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssReturnAtRule,
+        crate::scss::statements::return_at_rule::FormatScssReturnAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::return_at_rule::FormatScssReturnAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssUseAllNamespace>
+    for crate::scss::auxiliary::use_all_namespace::FormatScssUseAllNamespace
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssUseAllNamespace,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssUseAllNamespace>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssUseAllNamespace {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssUseAllNamespace,
+        crate::scss::auxiliary::use_all_namespace::FormatScssUseAllNamespace,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::use_all_namespace::FormatScssUseAllNamespace::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssUseAllNamespace {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssUseAllNamespace,
+        crate::scss::auxiliary::use_all_namespace::FormatScssUseAllNamespace,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::use_all_namespace::FormatScssUseAllNamespace::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssUseAsClause>
+    for crate::scss::auxiliary::use_as_clause::FormatScssUseAsClause
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssUseAsClause,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssUseAsClause>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssUseAsClause {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssUseAsClause,
+        crate::scss::auxiliary::use_as_clause::FormatScssUseAsClause,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::use_as_clause::FormatScssUseAsClause::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssUseAsClause {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssUseAsClause,
+        crate::scss::auxiliary::use_as_clause::FormatScssUseAsClause,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::use_as_clause::FormatScssUseAsClause::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssUseAtRule>
+    for crate::scss::statements::use_at_rule::FormatScssUseAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssUseAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssUseAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssUseAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssUseAtRule,
+        crate::scss::statements::use_at_rule::FormatScssUseAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::use_at_rule::FormatScssUseAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssUseAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssUseAtRule,
+        crate::scss::statements::use_at_rule::FormatScssUseAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::use_at_rule::FormatScssUseAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssVariableModifier>
+    for crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssVariableModifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssVariableModifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssVariableModifier,
+        crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssVariableModifier,
+        crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssWhileAtRule>
+    for crate::scss::statements::while_at_rule::FormatScssWhileAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssWhileAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssWhileAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssWhileAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssWhileAtRule,
+        crate::scss::statements::while_at_rule::FormatScssWhileAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::while_at_rule::FormatScssWhileAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssWhileAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssWhileAtRule,
+        crate::scss::statements::while_at_rule::FormatScssWhileAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::while_at_rule::FormatScssWhileAtRule::default(),
+        )
+    }
+}
 impl AsFormat<CssFormatContext> for biome_css_syntax::CssBracketedValueList {
     type Format<'a> = FormatRefWithRule<
         'a,
@@ -5993,164 +8205,453 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssPseudoValueList {
     fn into_format(self) -> Self::Format {
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::pseudo_value_list::FormatCssPseudoValueList::default(),
+            crate::css::lists::pseudo_value_list::FormatCssPseudoValueList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssRelativeSelectorList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssRelativeSelectorList,
+        crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRelativeSelectorList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssRelativeSelectorList,
+        crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssRuleList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssRuleList,
+        crate::css::lists::rule_list::FormatCssRuleList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::rule_list::FormatCssRuleList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRuleList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssRuleList,
+        crate::css::lists::rule_list::FormatCssRuleList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::rule_list::FormatCssRuleList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSelectorList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssSelectorList,
+        crate::css::lists::selector_list::FormatCssSelectorList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::selector_list::FormatCssSelectorList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSelectorList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssSelectorList,
+        crate::css::lists::selector_list::FormatCssSelectorList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::selector_list::FormatCssSelectorList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssSubSelectorList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssSubSelectorList,
+        crate::css::lists::sub_selector_list::FormatCssSubSelectorList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::sub_selector_list::FormatCssSubSelectorList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSubSelectorList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssSubSelectorList,
+        crate::css::lists::sub_selector_list::FormatCssSubSelectorList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::sub_selector_list::FormatCssSubSelectorList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssUrlModifierList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssUrlModifierList,
+        crate::css::lists::url_modifier_list::FormatCssUrlModifierList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::url_modifier_list::FormatCssUrlModifierList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssUrlModifierList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssUrlModifierList,
+        crate::css::lists::url_modifier_list::FormatCssUrlModifierList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::url_modifier_list::FormatCssUrlModifierList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleImportSpecifierList {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssValueAtRuleImportSpecifierList , crate :: css :: lists :: value_at_rule_import_specifier_list :: FormatCssValueAtRuleImportSpecifierList > ;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: lists :: value_at_rule_import_specifier_list :: FormatCssValueAtRuleImportSpecifierList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleImportSpecifierList {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: CssValueAtRuleImportSpecifierList , crate :: css :: lists :: value_at_rule_import_specifier_list :: FormatCssValueAtRuleImportSpecifierList > ;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: lists :: value_at_rule_import_specifier_list :: FormatCssValueAtRuleImportSpecifierList :: default ())
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::CssValueAtRulePropertyList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::CssValueAtRulePropertyList,
+        crate::css::lists::value_at_rule_property_list::FormatCssValueAtRulePropertyList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: lists :: value_at_rule_property_list :: FormatCssValueAtRulePropertyList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::CssValueAtRulePropertyList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::CssValueAtRulePropertyList,
+        crate::css::lists::value_at_rule_property_list::FormatCssValueAtRulePropertyList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: lists :: value_at_rule_property_list :: FormatCssValueAtRulePropertyList :: default ())
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessImportOptionList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessImportOptionList,
+        crate::less::lists::import_option_list::FormatLessImportOptionList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::lists::import_option_list::FormatLessImportOptionList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessImportOptionList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessImportOptionList,
+        crate::less::lists::import_option_list::FormatLessImportOptionList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::lists::import_option_list::FormatLessImportOptionList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinArgumentList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinArgumentList,
+        crate::less::lists::mixin_argument_list::FormatLessMixinArgumentList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::lists::mixin_argument_list::FormatLessMixinArgumentList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinArgumentList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinArgumentList,
+        crate::less::lists::mixin_argument_list::FormatLessMixinArgumentList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::lists::mixin_argument_list::FormatLessMixinArgumentList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinParameterList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinParameterList,
+        crate::less::lists::mixin_parameter_list::FormatLessMixinParameterList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::lists::mixin_parameter_list::FormatLessMixinParameterList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinParameterList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinParameterList,
+        crate::less::lists::mixin_parameter_list::FormatLessMixinParameterList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::lists::mixin_parameter_list::FormatLessMixinParameterList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinPathSegmentList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinPathSegmentList,
+        crate::less::lists::mixin_path_segment_list::FormatLessMixinPathSegmentList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::lists::mixin_path_segment_list::FormatLessMixinPathSegmentList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinPathSegmentList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinPathSegmentList,
+        crate::less::lists::mixin_path_segment_list::FormatLessMixinPathSegmentList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::lists::mixin_path_segment_list::FormatLessMixinPathSegmentList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssArgumentList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssArgumentList,
+        crate::scss::lists::argument_list::FormatScssArgumentList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::lists::argument_list::FormatScssArgumentList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssArgumentList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssArgumentList,
+        crate::scss::lists::argument_list::FormatScssArgumentList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::lists::argument_list::FormatScssArgumentList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssRelativeSelectorList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssEachBindingList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssRelativeSelectorList,
-        crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList,
+        biome_css_syntax::ScssEachBindingList,
+        crate::scss::lists::each_binding_list::FormatScssEachBindingList,
     >;
     fn format(&self) -> Self::Format<'_> {
         FormatRefWithRule::new(
             self,
-            crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList::default(),
+            crate::scss::lists::each_binding_list::FormatScssEachBindingList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRelativeSelectorList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssEachBindingList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssRelativeSelectorList,
-        crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList,
+        biome_css_syntax::ScssEachBindingList,
+        crate::scss::lists::each_binding_list::FormatScssEachBindingList,
     >;
     fn into_format(self) -> Self::Format {
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::relative_selector_list::FormatCssRelativeSelectorList::default(),
+            crate::scss::lists::each_binding_list::FormatScssEachBindingList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssRuleList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssForwardMemberList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssRuleList,
-        crate::css::lists::rule_list::FormatCssRuleList,
+        biome_css_syntax::ScssForwardMemberList,
+        crate::scss::lists::forward_member_list::FormatScssForwardMemberList,
     >;
     fn format(&self) -> Self::Format<'_> {
         FormatRefWithRule::new(
             self,
-            crate::css::lists::rule_list::FormatCssRuleList::default(),
+            crate::scss::lists::forward_member_list::FormatScssForwardMemberList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssRuleList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssForwardMemberList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssRuleList,
-        crate::css::lists::rule_list::FormatCssRuleList,
+        biome_css_syntax::ScssForwardMemberList,
+        crate::scss::lists::forward_member_list::FormatScssForwardMemberList,
     >;
     fn into_format(self) -> Self::Format {
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::rule_list::FormatCssRuleList::default(),
+            crate::scss::lists::forward_member_list::FormatScssForwardMemberList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSelectorList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssInterpolatedIdentifierPartList {
+    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: ScssInterpolatedIdentifierPartList , crate :: scss :: lists :: interpolated_identifier_part_list :: FormatScssInterpolatedIdentifierPartList > ;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: scss :: lists :: interpolated_identifier_part_list :: FormatScssInterpolatedIdentifierPartList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssInterpolatedIdentifierPartList {
+    type Format = FormatOwnedWithRule < biome_css_syntax :: ScssInterpolatedIdentifierPartList , crate :: scss :: lists :: interpolated_identifier_part_list :: FormatScssInterpolatedIdentifierPartList > ;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: scss :: lists :: interpolated_identifier_part_list :: FormatScssInterpolatedIdentifierPartList :: default ())
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssKeywordArgumentList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSelectorList,
-        crate::css::lists::selector_list::FormatCssSelectorList,
+        biome_css_syntax::ScssKeywordArgumentList,
+        crate::scss::lists::keyword_argument_list::FormatScssKeywordArgumentList,
     >;
     fn format(&self) -> Self::Format<'_> {
         FormatRefWithRule::new(
             self,
-            crate::css::lists::selector_list::FormatCssSelectorList::default(),
+            crate::scss::lists::keyword_argument_list::FormatScssKeywordArgumentList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSelectorList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssKeywordArgumentList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSelectorList,
-        crate::css::lists::selector_list::FormatCssSelectorList,
+        biome_css_syntax::ScssKeywordArgumentList,
+        crate::scss::lists::keyword_argument_list::FormatScssKeywordArgumentList,
     >;
     fn into_format(self) -> Self::Format {
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::selector_list::FormatCssSelectorList::default(),
+            crate::scss::lists::keyword_argument_list::FormatScssKeywordArgumentList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssSubSelectorList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssMapExpressionPairList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssSubSelectorList,
-        crate::css::lists::sub_selector_list::FormatCssSubSelectorList,
+        biome_css_syntax::ScssMapExpressionPairList,
+        crate::scss::lists::map_expression_pair_list::FormatScssMapExpressionPairList,
     >;
     fn format(&self) -> Self::Format<'_> {
         FormatRefWithRule::new(
             self,
-            crate::css::lists::sub_selector_list::FormatCssSubSelectorList::default(),
+            crate::scss::lists::map_expression_pair_list::FormatScssMapExpressionPairList::default(
+            ),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssSubSelectorList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssMapExpressionPairList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssSubSelectorList,
-        crate::css::lists::sub_selector_list::FormatCssSubSelectorList,
+        biome_css_syntax::ScssMapExpressionPairList,
+        crate::scss::lists::map_expression_pair_list::FormatScssMapExpressionPairList,
     >;
     fn into_format(self) -> Self::Format {
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::sub_selector_list::FormatCssSubSelectorList::default(),
+            crate::scss::lists::map_expression_pair_list::FormatScssMapExpressionPairList::default(
+            ),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssUrlModifierList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssParameterList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssUrlModifierList,
-        crate::css::lists::url_modifier_list::FormatCssUrlModifierList,
+        biome_css_syntax::ScssParameterList,
+        crate::scss::lists::parameter_list::FormatScssParameterList,
     >;
     fn format(&self) -> Self::Format<'_> {
         FormatRefWithRule::new(
             self,
-            crate::css::lists::url_modifier_list::FormatCssUrlModifierList::default(),
+            crate::scss::lists::parameter_list::FormatScssParameterList::default(),
         )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssUrlModifierList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssParameterList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssUrlModifierList,
-        crate::css::lists::url_modifier_list::FormatCssUrlModifierList,
+        biome_css_syntax::ScssParameterList,
+        crate::scss::lists::parameter_list::FormatScssParameterList,
     >;
     fn into_format(self) -> Self::Format {
         FormatOwnedWithRule::new(
             self,
-            crate::css::lists::url_modifier_list::FormatCssUrlModifierList::default(),
+            crate::scss::lists::parameter_list::FormatScssParameterList::default(),
         )
     }
 }
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleImportSpecifierList {
-    type Format < 'a > = FormatRefWithRule < 'a , biome_css_syntax :: CssValueAtRuleImportSpecifierList , crate :: css :: lists :: value_at_rule_import_specifier_list :: FormatCssValueAtRuleImportSpecifierList > ;
-    fn format(&self) -> Self::Format<'_> {
-        FormatRefWithRule :: new (self , crate :: css :: lists :: value_at_rule_import_specifier_list :: FormatCssValueAtRuleImportSpecifierList :: default ())
-    }
-}
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssValueAtRuleImportSpecifierList {
-    type Format = FormatOwnedWithRule < biome_css_syntax :: CssValueAtRuleImportSpecifierList , crate :: css :: lists :: value_at_rule_import_specifier_list :: FormatCssValueAtRuleImportSpecifierList > ;
-    fn into_format(self) -> Self::Format {
-        FormatOwnedWithRule :: new (self , crate :: css :: lists :: value_at_rule_import_specifier_list :: FormatCssValueAtRuleImportSpecifierList :: default ())
-    }
-}
-impl AsFormat<CssFormatContext> for biome_css_syntax::CssValueAtRulePropertyList {
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifierList {
     type Format<'a> = FormatRefWithRule<
         'a,
-        biome_css_syntax::CssValueAtRulePropertyList,
-        crate::css::lists::value_at_rule_property_list::FormatCssValueAtRulePropertyList,
+        biome_css_syntax::ScssVariableModifierList,
+        crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList,
     >;
     fn format(&self) -> Self::Format<'_> {
-        FormatRefWithRule :: new (self , crate :: css :: lists :: value_at_rule_property_list :: FormatCssValueAtRulePropertyList :: default ())
+        FormatRefWithRule::new(
+            self,
+            crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList::default(),
+        )
     }
 }
-impl IntoFormat<CssFormatContext> for biome_css_syntax::CssValueAtRulePropertyList {
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifierList {
     type Format = FormatOwnedWithRule<
-        biome_css_syntax::CssValueAtRulePropertyList,
-        crate::css::lists::value_at_rule_property_list::FormatCssValueAtRulePropertyList,
+        biome_css_syntax::ScssVariableModifierList,
+        crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList,
     >;
     fn into_format(self) -> Self::Format {
-        FormatOwnedWithRule :: new (self , crate :: css :: lists :: value_at_rule_property_list :: FormatCssValueAtRulePropertyList :: default ())
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList::default(),
+        )
     }
 }
 impl FormatRule<biome_css_syntax::CssBogus> for crate::css::bogus::bogus::FormatCssBogus {
@@ -8976,3 +11477,272 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssValueAtRulePropert
         )
     }
 }
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyLessGuardCondition {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyLessGuardCondition,
+        crate::less::any::guard_condition::FormatAnyLessGuardCondition,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::any::guard_condition::FormatAnyLessGuardCondition::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyLessGuardCondition {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyLessGuardCondition,
+        crate::less::any::guard_condition::FormatAnyLessGuardCondition,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::any::guard_condition::FormatAnyLessGuardCondition::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyLessMixinArgument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyLessMixinArgument,
+        crate::less::any::mixin_argument::FormatAnyLessMixinArgument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::any::mixin_argument::FormatAnyLessMixinArgument::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyLessMixinArgument {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyLessMixinArgument,
+        crate::less::any::mixin_argument::FormatAnyLessMixinArgument,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::any::mixin_argument::FormatAnyLessMixinArgument::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyLessMixinName {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyLessMixinName,
+        crate::less::any::mixin_name::FormatAnyLessMixinName,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::any::mixin_name::FormatAnyLessMixinName::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyLessMixinName {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyLessMixinName,
+        crate::less::any::mixin_name::FormatAnyLessMixinName,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::any::mixin_name::FormatAnyLessMixinName::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyLessMixinParameter {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyLessMixinParameter,
+        crate::less::any::mixin_parameter::FormatAnyLessMixinParameter,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::less::any::mixin_parameter::FormatAnyLessMixinParameter::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyLessMixinParameter {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyLessMixinParameter,
+        crate::less::any::mixin_parameter::FormatAnyLessMixinParameter,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::any::mixin_parameter::FormatAnyLessMixinParameter::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyScssArgument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyScssArgument,
+        crate::scss::any::argument::FormatAnyScssArgument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::any::argument::FormatAnyScssArgument::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyScssArgument {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyScssArgument,
+        crate::scss::any::argument::FormatAnyScssArgument,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::any::argument::FormatAnyScssArgument::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyScssElseClauseBody {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyScssElseClauseBody,
+        crate::scss::any::else_clause_body::FormatAnyScssElseClauseBody,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::any::else_clause_body::FormatAnyScssElseClauseBody::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyScssElseClauseBody {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyScssElseClauseBody,
+        crate::scss::any::else_clause_body::FormatAnyScssElseClauseBody,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::any::else_clause_body::FormatAnyScssElseClauseBody::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyScssForwardMember {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyScssForwardMember,
+        crate::scss::any::forward_member::FormatAnyScssForwardMember,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::any::forward_member::FormatAnyScssForwardMember::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyScssForwardMember {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyScssForwardMember,
+        crate::scss::any::forward_member::FormatAnyScssForwardMember,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::any::forward_member::FormatAnyScssForwardMember::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyScssIncludeName {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyScssIncludeName,
+        crate::scss::any::include_name::FormatAnyScssIncludeName,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::any::include_name::FormatAnyScssIncludeName::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyScssIncludeName {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyScssIncludeName,
+        crate::scss::any::include_name::FormatAnyScssIncludeName,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::any::include_name::FormatAnyScssIncludeName::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyScssInterpolatedIdentifierPart {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyScssInterpolatedIdentifierPart,
+        crate::scss::any::interpolated_identifier_part::FormatAnyScssInterpolatedIdentifierPart,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: scss :: any :: interpolated_identifier_part :: FormatAnyScssInterpolatedIdentifierPart :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyScssInterpolatedIdentifierPart {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyScssInterpolatedIdentifierPart,
+        crate::scss::any::interpolated_identifier_part::FormatAnyScssInterpolatedIdentifierPart,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: scss :: any :: interpolated_identifier_part :: FormatAnyScssInterpolatedIdentifierPart :: default ())
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyScssModuleMember {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyScssModuleMember,
+        crate::scss::any::module_member::FormatAnyScssModuleMember,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::any::module_member::FormatAnyScssModuleMember::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyScssModuleMember {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyScssModuleMember,
+        crate::scss::any::module_member::FormatAnyScssModuleMember,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::any::module_member::FormatAnyScssModuleMember::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyScssUseNamespace {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyScssUseNamespace,
+        crate::scss::any::use_namespace::FormatAnyScssUseNamespace,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::scss::any::use_namespace::FormatAnyScssUseNamespace::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyScssUseNamespace {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyScssUseNamespace,
+        crate::scss::any::use_namespace::FormatAnyScssUseNamespace,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::any::use_namespace::FormatAnyScssUseNamespace::default(),
+        )
+    }
+}
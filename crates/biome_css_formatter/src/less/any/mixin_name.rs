@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyLessMixinName;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyLessMixinName;
+impl FormatRule<AnyLessMixinName> for FormatAnyLessMixinName {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyLessMixinName, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyLessMixinName::CssClassSelector(node) => node.format().fmt(f),
+            AnyLessMixinName::CssIdSelector(node) => node.format().fmt(f),
+        }
+    }
+}
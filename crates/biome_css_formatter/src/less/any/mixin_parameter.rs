@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyLessMixinParameter;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyLessMixinParameter;
+impl FormatRule<AnyLessMixinParameter> for FormatAnyLessMixinParameter {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyLessMixinParameter, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyLessMixinParameter::LessMixinParameter(node) => node.format().fmt(f),
+            AnyLessMixinParameter::LessMixinPatternParameter(node) => node.format().fmt(f),
+            AnyLessMixinParameter::LessMixinRestParameter(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,6 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod guard_condition;
+pub(crate) mod mixin_argument;
+pub(crate) mod mixin_name;
+pub(crate) mod mixin_parameter;
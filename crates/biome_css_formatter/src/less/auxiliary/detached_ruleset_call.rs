@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessDetachedRulesetCall, LessDetachedRulesetCallFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessDetachedRulesetCall;
+impl FormatNodeRule<LessDetachedRulesetCall> for FormatLessDetachedRulesetCall {
+    fn fmt_fields(&self, node: &LessDetachedRulesetCall, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessDetachedRulesetCallFields {
+            name,
+            l_paren_token,
+            r_paren_token,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                name.format(),
+                l_paren_token.format(),
+                r_paren_token.format()
+            ]
+        )?;
+
+        if let Some(semicolon_token) = semicolon_token {
+            write!(f, [semicolon_token.format()])
+        } else {
+            write!(f, [text(";")])
+        }
+    }
+}
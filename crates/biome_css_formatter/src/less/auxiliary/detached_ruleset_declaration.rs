@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessDetachedRulesetDeclaration, LessDetachedRulesetDeclarationFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessDetachedRulesetDeclaration;
+impl FormatNodeRule<LessDetachedRulesetDeclaration> for FormatLessDetachedRulesetDeclaration {
+    fn fmt_fields(
+        &self,
+        node: &LessDetachedRulesetDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let LessDetachedRulesetDeclarationFields {
+            name,
+            colon_token,
+            block,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                name.format(),
+                colon_token.format(),
+                space(),
+                block.format(),
+                semicolon_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessEscapedValue, LessEscapedValueFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessEscapedValue;
+impl FormatNodeRule<LessEscapedValue> for FormatLessEscapedValue {
+    fn fmt_fields(&self, node: &LessEscapedValue, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessEscapedValueFields { tilde_token, value } = node.as_fields();
+
+        write!(f, [tilde_token.format(), value.format()])
+    }
+}
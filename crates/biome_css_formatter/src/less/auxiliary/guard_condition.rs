@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessGuardCondition, LessGuardConditionFields};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessGuardCondition;
+impl FormatNodeRule<LessGuardCondition> for FormatLessGuardCondition {
+    fn fmt_fields(&self, node: &LessGuardCondition, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessGuardConditionFields {
+            not_token,
+            l_paren_token,
+            value,
+            r_paren_token,
+        } = node.as_fields();
+
+        if let Some(not_token) = not_token {
+            write!(f, [not_token.format(), space()])?;
+        }
+
+        write!(
+            f,
+            [group(&format_args![
+                l_paren_token.format(),
+                soft_block_indent(&value.format()),
+                r_paren_token.format()
+            ])]
+        )
+    }
+}
@@ -0,0 +1,33 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessGuardLogicalCondition, LessGuardLogicalConditionFields, T};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessGuardLogicalCondition;
+impl FormatNodeRule<LessGuardLogicalCondition> for FormatLessGuardLogicalCondition {
+    fn fmt_fields(
+        &self,
+        node: &LessGuardLogicalCondition,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let LessGuardLogicalConditionFields {
+            left,
+            operator,
+            right,
+        } = node.as_fields();
+
+        let operator = operator?;
+
+        write!(f, [left.format()])?;
+
+        // Commas are written like in a list, while `and` and `or` are surrounded
+        // by spaces like the logical operators of media queries.
+        if operator.kind() == T![,] {
+            write!(f, [operator.format(), soft_line_break_or_space()])?;
+        } else {
+            write!(f, [soft_line_break_or_space(), operator.format(), space()])?;
+        }
+
+        write!(f, [right.format()])
+    }
+}
@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use crate::utils::less_mixin::format_less_mixin_separator;
+use biome_css_syntax::{LessMixinArgument, LessMixinArgumentFields};
+use biome_formatter::write;
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinArgument;
+impl FormatNodeRule<LessMixinArgument> for FormatLessMixinArgument {
+    fn fmt_fields(&self, node: &LessMixinArgument, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinArgumentFields { value, separator } = node.as_fields();
+
+        write!(f, [value.format()])?;
+
+        format_less_mixin_separator(node.syntax(), separator, f)
+    }
+}
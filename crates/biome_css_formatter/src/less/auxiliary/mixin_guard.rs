@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinGuard, LessMixinGuardFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinGuard;
+impl FormatNodeRule<LessMixinGuard> for FormatLessMixinGuard {
+    fn fmt_fields(&self, node: &LessMixinGuard, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinGuardFields {
+            when_token,
+            condition,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                when_token.format(),
+                space(),
+                group(&indent(&condition.format()))
+            ]
+        )
+    }
+}
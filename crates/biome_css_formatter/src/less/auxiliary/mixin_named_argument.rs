@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use crate::utils::less_mixin::format_less_mixin_separator;
+use biome_css_syntax::{LessMixinNamedArgument, LessMixinNamedArgumentFields};
+use biome_formatter::write;
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinNamedArgument;
+impl FormatNodeRule<LessMixinNamedArgument> for FormatLessMixinNamedArgument {
+    fn fmt_fields(&self, node: &LessMixinNamedArgument, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinNamedArgumentFields {
+            name,
+            colon_token,
+            value,
+            separator,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [name.format(), colon_token.format(), space(), value.format()]
+        )?;
+
+        format_less_mixin_separator(node.syntax(), separator, f)
+    }
+}
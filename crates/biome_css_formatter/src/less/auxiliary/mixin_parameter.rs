@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use crate::utils::less_mixin::format_less_mixin_separator;
+use biome_css_syntax::{LessMixinParameter, LessMixinParameterFields};
+use biome_formatter::write;
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinParameter;
+impl FormatNodeRule<LessMixinParameter> for FormatLessMixinParameter {
+    fn fmt_fields(&self, node: &LessMixinParameter, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinParameterFields {
+            name,
+            default,
+            separator,
+        } = node.as_fields();
+
+        write!(f, [name.format(), default.format()])?;
+
+        format_less_mixin_separator(node.syntax(), separator, f)
+    }
+}
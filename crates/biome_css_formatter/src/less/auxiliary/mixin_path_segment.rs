@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinPathSegment, LessMixinPathSegmentFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinPathSegment;
+impl FormatNodeRule<LessMixinPathSegment> for FormatLessMixinPathSegment {
+    fn fmt_fields(&self, node: &LessMixinPathSegment, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinPathSegmentFields {
+            combinator_token,
+            name,
+        } = node.as_fields();
+
+        if let Some(combinator_token) = combinator_token {
+            write!(f, [space(), combinator_token.format(), space()])?;
+        }
+
+        write!(f, [name.format()])
+    }
+}
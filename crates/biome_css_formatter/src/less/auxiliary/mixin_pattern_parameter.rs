@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use crate::utils::less_mixin::format_less_mixin_separator;
+use biome_css_syntax::{LessMixinPatternParameter, LessMixinPatternParameterFields};
+use biome_formatter::write;
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinPatternParameter;
+impl FormatNodeRule<LessMixinPatternParameter> for FormatLessMixinPatternParameter {
+    fn fmt_fields(
+        &self,
+        node: &LessMixinPatternParameter,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let LessMixinPatternParameterFields { value, separator } = node.as_fields();
+
+        write!(f, [value.format()])?;
+
+        format_less_mixin_separator(node.syntax(), separator, f)
+    }
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use crate::utils::less_mixin::format_less_mixin_separator;
+use biome_css_syntax::{LessMixinRestParameter, LessMixinRestParameterFields};
+use biome_formatter::write;
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinRestParameter;
+impl FormatNodeRule<LessMixinRestParameter> for FormatLessMixinRestParameter {
+    fn fmt_fields(&self, node: &LessMixinRestParameter, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinRestParameterFields {
+            name,
+            dotdotdot_token,
+            separator,
+        } = node.as_fields();
+
+        write!(f, [name.format(), dotdotdot_token.format()])?;
+
+        format_less_mixin_separator(node.syntax(), separator, f)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessVariableDeclaration, LessVariableDeclarationFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessVariableDeclaration;
+impl FormatNodeRule<LessVariableDeclaration> for FormatLessVariableDeclaration {
+    fn fmt_fields(&self, node: &LessVariableDeclaration, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessVariableDeclarationFields {
+            name,
+            colon_token,
+            value,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [name.format(), colon_token.format(), space(), value.format()]
+        )?;
+
+        if let Some(semicolon_token) = semicolon_token {
+            write!(f, [semicolon_token.format()])
+        } else {
+            write!(f, [text(";")])
+        }
+    }
+}
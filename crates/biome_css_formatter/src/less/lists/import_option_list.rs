@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use biome_css_syntax::LessImportOptionList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessImportOptionList;
+impl FormatRule<LessImportOptionList> for FormatLessImportOptionList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &LessImportOptionList, f: &mut CssFormatter) -> FormatResult<()> {
+        let separator = space();
+        let mut joiner = f.join_with(&separator);
+
+        for formatted in node.format_separated(",") {
+            joiner.entry(&formatted);
+        }
+
+        joiner.finish()
+    }
+}
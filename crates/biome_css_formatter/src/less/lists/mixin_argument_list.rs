@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use biome_css_syntax::LessMixinArgumentList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinArgumentList;
+impl FormatRule<LessMixinArgumentList> for FormatLessMixinArgumentList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &LessMixinArgumentList, f: &mut CssFormatter) -> FormatResult<()> {
+        // Using `join_with` instead of `join_nodes_with_soft_line` to avoid
+        // preserving empty lines from the input source, like in [FormatCssParameterList].
+        let separator = soft_line_break_or_space();
+        let mut joiner = f.join_with(&separator);
+
+        for item in node {
+            joiner.entry(&item.format());
+        }
+
+        joiner.finish()
+    }
+}
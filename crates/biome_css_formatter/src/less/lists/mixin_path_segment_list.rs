@@ -0,0 +1,11 @@
+use crate::prelude::*;
+use biome_css_syntax::LessMixinPathSegmentList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinPathSegmentList;
+impl FormatRule<LessMixinPathSegmentList> for FormatLessMixinPathSegmentList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &LessMixinPathSegmentList, f: &mut CssFormatter) -> FormatResult<()> {
+        f.join().entries(node.iter().formatted()).finish()
+    }
+}
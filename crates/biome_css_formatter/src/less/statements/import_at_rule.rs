@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessImportAtRule, LessImportAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessImportAtRule;
+impl FormatNodeRule<LessImportAtRule> for FormatLessImportAtRule {
+    fn fmt_fields(&self, node: &LessImportAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessImportAtRuleFields {
+            import_token,
+            options,
+            url,
+            media,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                import_token.format(),
+                space(),
+                options.format(),
+                space(),
+                url.format()
+            ]
+        )?;
+
+        if media.len() > 0 {
+            write!(f, [space(), media.format()])?;
+        }
+
+        write!(f, [semicolon_token.format()])
+    }
+}
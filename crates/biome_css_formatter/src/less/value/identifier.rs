@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessIdentifier, LessIdentifierFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessIdentifier;
+impl FormatNodeRule<LessIdentifier> for FormatLessIdentifier {
+    fn fmt_fields(&self, node: &LessIdentifier, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessIdentifierFields { at_token, name } = node.as_fields();
+
+        write!(f, [at_token.format(), name.format()])
+    }
+}
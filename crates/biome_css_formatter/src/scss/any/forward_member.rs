@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_css_syntax::AnyScssForwardMember;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyScssForwardMember;
+impl FormatRule<AnyScssForwardMember> for FormatAnyScssForwardMember {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyScssForwardMember, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyScssForwardMember::CssIdentifier(node) => node.format().fmt(f),
+            AnyScssForwardMember::ScssIdentifier(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssArgument, ScssArgumentFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssArgument;
+impl FormatNodeRule<ScssArgument> for FormatScssArgument {
+    fn fmt_fields(&self, node: &ScssArgument, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssArgumentFields {
+            value,
+            dotdotdot_token,
+        } = node.as_fields();
+
+        write!(f, [value.format(), dotdotdot_token.format()])
+    }
+}
@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use biome_css_syntax::ScssDeclaration;
+use biome_css_syntax::{ScssMapExpression, ScssMapExpressionFields};
+use biome_formatter::{format_args, write};
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssMapExpression;
+impl FormatNodeRule<ScssMapExpression> for FormatScssMapExpression {
+    fn fmt_fields(&self, node: &ScssMapExpression, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssMapExpressionFields {
+            l_paren_token,
+            pairs,
+            r_paren_token,
+        } = node.as_fields();
+
+        // Maps assigned to variables are usually configuration, like a list
+        // of breakpoints, so they're printed with one entry per line.
+        let should_expand = node
+            .syntax()
+            .grand_parent()
+            .is_some_and(|grand_parent| ScssDeclaration::can_cast(grand_parent.kind()));
+
+        write!(
+            f,
+            [group(&format_args![
+                l_paren_token.format(),
+                soft_block_indent(&pairs.format()),
+                r_paren_token.format()
+            ])
+            .should_expand(should_expand)]
+        )
+    }
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssModuleConfiguration, ScssModuleConfigurationFields};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssModuleConfiguration;
+impl FormatNodeRule<ScssModuleConfiguration> for FormatScssModuleConfiguration {
+    fn fmt_fields(&self, node: &ScssModuleConfiguration, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssModuleConfigurationFields {
+            with_token,
+            l_paren_token,
+            items,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                with_token.format(),
+                space(),
+                group(&format_args![
+                    l_paren_token.format(),
+                    soft_block_indent(&items.format()),
+                    r_paren_token.format()
+                ])
+            ]
+        )
+    }
+}
@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssNamespacedName, ScssNamespacedNameFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssNamespacedName;
+impl FormatNodeRule<ScssNamespacedName> for FormatScssNamespacedName {
+    fn fmt_fields(&self, node: &ScssNamespacedName, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssNamespacedNameFields {
+            namespace,
+            dot_token,
+            name,
+        } = node.as_fields();
+
+        write!(f, [namespace.format(), dot_token.format(), name.format()])
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssOperator, ScssOperatorFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssOperator;
+impl FormatNodeRule<ScssOperator> for FormatScssOperator {
+    fn fmt_fields(&self, node: &ScssOperator, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssOperatorFields { value } = node.as_fields();
+
+        write!(f, [value.format()])
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_css_syntax::ScssEachBindingList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssEachBindingList;
+impl FormatRule<ScssEachBindingList> for FormatScssEachBindingList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &ScssEachBindingList, f: &mut CssFormatter) -> FormatResult<()> {
+        f.join_with(&space())
+            .entries(node.format_separated(","))
+            .finish()
+    }
+}
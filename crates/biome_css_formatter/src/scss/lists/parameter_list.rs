@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use biome_css_syntax::ScssParameterList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssParameterList;
+impl FormatRule<ScssParameterList> for FormatScssParameterList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &ScssParameterList, f: &mut CssFormatter) -> FormatResult<()> {
+        // Using `join_with` instead of `join_nodes_with_soft_line` to avoid
+        // preserving empty lines from the input source, like in [FormatCssParameterList].
+        let separator = soft_line_break_or_space();
+        let mut joiner = f.join_with(&separator);
+
+        for formatted in node.format_separated(",") {
+            joiner.entry(&formatted);
+        }
+
+        joiner.finish()
+    }
+}
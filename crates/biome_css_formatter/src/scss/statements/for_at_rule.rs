@@ -0,0 +1,38 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssForAtRule, ScssForAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssForAtRule;
+impl FormatNodeRule<ScssForAtRule> for FormatScssForAtRule {
+    fn fmt_fields(&self, node: &ScssForAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssForAtRuleFields {
+            for_token,
+            variable,
+            from_token,
+            start,
+            range,
+            end,
+            block,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                for_token.format(),
+                space(),
+                variable.format(),
+                space(),
+                from_token.format(),
+                space(),
+                start.format(),
+                space(),
+                range.format(),
+                space(),
+                end.format(),
+                space(),
+                block.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,14 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod content_at_rule;
+pub(crate) mod each_at_rule;
+pub(crate) mod extend_at_rule;
+pub(crate) mod for_at_rule;
+pub(crate) mod forward_at_rule;
+pub(crate) mod function_at_rule;
+pub(crate) mod if_at_rule;
+pub(crate) mod include_at_rule;
+pub(crate) mod mixin_at_rule;
+pub(crate) mod return_at_rule;
+pub(crate) mod use_at_rule;
+pub(crate) mod while_at_rule;
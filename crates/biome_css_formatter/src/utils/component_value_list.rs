@@ -1,7 +1,9 @@
 use crate::CssFormatter;
 use crate::comments::CssComments;
 use crate::prelude::*;
-use biome_css_syntax::{CssGenericDelimiter, CssGenericProperty, CssLanguage, CssSyntaxKind};
+use biome_css_syntax::{
+    CssGenericDelimiter, CssGenericProperty, CssLanguage, CssSyntaxKind, LessOperator, ScssOperator,
+};
 use biome_formatter::{CstFormatContext, format_args, write};
 use biome_formatter::{FormatOptions, FormatResult};
 use biome_rowan::{AstNode, AstNodeList, TextSize};
@@ -32,10 +34,36 @@ where
     let values = format_with(|f: &mut Formatter<'_, CssFormatContext>| {
         let mut fill = f.fill();
         let mut at_group_boundary = false;
+        let mut previous_end = None;
+        let mut previous_operator_end = None;
 
         for (element, formatted) in node.iter().zip(node.iter().formatted()) {
+            // SCSS signs written directly next to their operand but apart from
+            // the previous value, like the `-` in `-$spacing`, are unary and
+            // mustn't be separated from it, since `1 -$spacing` is a list
+            // while `1 - $spacing` is a subtraction.
+            let range = element.syntax().text_trimmed_range();
+            let is_operand_of_unary_operator =
+                previous_operator_end.is_some_and(|end| end == range.start());
+            previous_operator_end = ScssOperator::cast_ref(element.syntax())
+                .and_then(|operator| operator.value().ok())
+                .or_else(|| {
+                    LessOperator::cast_ref(element.syntax())
+                        .and_then(|operator| operator.value().ok())
+                })
+                .filter(|token| {
+                    matches!(token.kind(), CssSyntaxKind::MINUS | CssSyntaxKind::PLUS)
+                        && previous_end.is_none_or(|end| end != range.start())
+                })
+                .map(|_| range.end());
+            previous_end = Some(range.end());
+
             fill.entry(
                 &format_once(|f| {
+                    if is_operand_of_unary_operator {
+                        return Ok(());
+                    }
+
                     // If the current element is not a comma, insert a soft line break or a space.
                     // Consider the CSS example: `font: first , second;`
                     // The desired format is: `font: first, second;`
@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use biome_css_syntax::{CssSyntaxNode, CssSyntaxToken, T};
+use biome_formatter::write;
+
+/// Formats the separator following a parameter or an argument of a Less mixin.
+///
+/// A trailing comma is removed, but a trailing semicolon turns all the values
+/// before it into a single one, like in `.mixin(1, 2;)`, so it has to be kept.
+pub(crate) fn format_less_mixin_separator(
+    item: &CssSyntaxNode,
+    separator: Option<CssSyntaxToken>,
+    f: &mut CssFormatter,
+) -> FormatResult<()> {
+    let Some(separator) = separator else {
+        return Ok(());
+    };
+
+    if separator.kind() == T![,] && item.next_sibling().is_none() {
+        write!(f, [format_removed(&separator)])
+    } else {
+        write!(f, [separator.format()])
+    }
+}
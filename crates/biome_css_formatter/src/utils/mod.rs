@@ -1,3 +1,4 @@
 pub(crate) mod block_like;
 pub(crate) mod component_value_list;
+pub(crate) mod less_mixin;
 pub(crate) mod string_utils;
@@ -1,6 +1,7 @@
 use biome_css_formatter::format_node;
 use biome_css_formatter::{CssFormatLanguage, context::CssFormatOptions};
 use biome_css_parser::{CssParserOptions, parse_css};
+use biome_css_syntax::CssFileSource;
 use biome_formatter::{IndentStyle, LineWidth, QuoteStyle};
 use biome_formatter_test::check_reformat::CheckReformat;
 
@@ -30,7 +31,7 @@ fn quick_test() {
     let result = doc.print().unwrap();
 
     let root = &parse.syntax();
-    let language = language::CssTestFormatLanguage::default();
+    let language = language::CssTestFormatLanguage::new(CssFileSource::css());
 
     println!("{}", doc.into_document());
     eprintln!("{}", result.as_code());
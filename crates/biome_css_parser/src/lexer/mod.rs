@@ -311,6 +311,12 @@ impl<'src> CssLexer<'src> {
             PRD => {
                 if self.is_number_start() {
                     self.consume_number(current)
+                } else if (self.options.is_scss_enabled() || self.options.is_less_enabled())
+                    && self.peek_byte() == Some(b'.')
+                    && self.byte_at(2) == Some(b'.')
+                {
+                    self.advance(2);
+                    self.consume_byte(T![...])
                 } else {
                     self.consume_byte(T![.])
                 }
@@ -322,6 +328,7 @@ impl<'src> CssLexer<'src> {
                 self.advance(1);
                 self.consume_byte(T!["$="])
             }
+            DOL if self.options.is_scss_enabled() => self.consume_byte(T![$]),
             UNI if self.options.is_metavariable_enabled() && self.is_metavariable_start() => {
                 self.consume_metavariable(GRIT_METAVARIABLE)
             }
@@ -343,7 +350,15 @@ impl<'src> CssLexer<'src> {
             MOR => self.consume_mor(),
             TLD => self.consume_tilde(),
             PIP => self.consume_pipe(),
+            EQL if self.options.is_scss_enabled() && self.peek_byte() == Some(b'=') => {
+                self.advance(1);
+                self.consume_byte(T![==])
+            }
             EQL => self.consume_byte(T![=]),
+            EXL if self.options.is_scss_enabled() && self.peek_byte() == Some(b'=') => {
+                self.advance(1);
+                self.consume_byte(T![!=])
+            }
             EXL => self.consume_byte(T![!]),
             PRC => self.consume_byte(T![%]),
             Dispatch::AMP => self.consume_byte(T![&]),
@@ -919,6 +934,28 @@ impl<'src> CssLexer<'src> {
             b"regexp" => REGEXP_KW,
             b"value" => VALUE_KW,
             b"as" => AS_KW,
+            // SCSS keywords are only recognized when parsing SCSS, so that they
+            // remain regular identifiers in plain CSS.
+            b"use" if self.options.is_scss_enabled() => USE_KW,
+            b"forward" if self.options.is_scss_enabled() => FORWARD_KW,
+            b"with" if self.options.is_scss_enabled() => WITH_KW,
+            b"show" if self.options.is_scss_enabled() => SHOW_KW,
+            b"hide" if self.options.is_scss_enabled() => HIDE_KW,
+            b"mixin" if self.options.is_scss_enabled() => MIXIN_KW,
+            b"include" if self.options.is_scss_enabled() => INCLUDE_KW,
+            b"function" if self.options.is_scss_enabled() => FUNCTION_KW,
+            b"return" if self.options.is_scss_enabled() => RETURN_KW,
+            b"content" if self.options.is_scss_enabled() => CONTENT_KW,
+            b"extend" if self.options.is_scss_enabled() => EXTEND_KW,
+            b"optional" if self.options.is_scss_enabled() => OPTIONAL_KW,
+            b"if" if self.options.is_scss_enabled() => IF_KW,
+            b"else" if self.options.is_scss_enabled() => ELSE_KW,
+            b"each" if self.options.is_scss_enabled() => EACH_KW,
+            b"for" if self.options.is_scss_enabled() => FOR_KW,
+            b"through" if self.options.is_scss_enabled() => THROUGH_KW,
+            b"while" if self.options.is_scss_enabled() => WHILE_KW,
+            // Less keywords
+            b"when" if self.options.is_less_enabled() => WHEN_KW,
             b"composes" => COMPOSES_KW,
             b"position-try" => POSITION_TRY_KW,
             b"view-transition" => VIEW_TRANSITION_KW,
@@ -1093,7 +1130,11 @@ impl<'src> CssLexer<'src> {
                     COMMENT
                 }
             }
-            Some(b'/') if self.options.allow_wrong_line_comments => {
+            Some(b'/')
+                if self.options.allow_wrong_line_comments
+                    || self.options.is_scss_enabled()
+                    || self.options.is_less_enabled() =>
+            {
                 self.advance(2);
 
                 while let Some(chr) = self.current_byte() {
@@ -3,6 +3,7 @@ use crate::syntax::at_rule::layer::LayerNameList;
 use crate::syntax::at_rule::media::MediaQueryList;
 use crate::syntax::at_rule::supports::error::expected_any_supports_condition;
 use crate::syntax::at_rule::supports::parse_any_supports_condition;
+use crate::syntax::less::{expected_less_import_url, parse_less_import_options};
 use crate::syntax::value::url::{is_at_url_function, parse_url_function};
 use crate::syntax::{is_at_declaration, is_at_string, parse_declaration, parse_string};
 use biome_css_syntax::CssSyntaxKind::*;
@@ -37,6 +38,14 @@ pub(crate) fn parse_import_at_rule(p: &mut CssParser) -> ParsedSyntax {
 
     p.bump(T![import]);
 
+    if parse_less_import_options(p).is_present() {
+        parse_import_url(p).or_add_diagnostic(p, expected_less_import_url);
+        MediaQueryList::new(T![;]).parse_list(p);
+        p.expect(T![;]);
+
+        return Present(m.complete(p, LESS_IMPORT_AT_RULE));
+    }
+
     let kind = if is_at_import_url(p) {
         parse_import_url(p).ok();
         CSS_IMPORT_AT_RULE
@@ -48,6 +48,12 @@ use crate::syntax::at_rule::unknown::{is_at_unknown_at_rule, parse_unknown_at_ru
 use crate::syntax::at_rule::value::parse_value_at_rule;
 use crate::syntax::at_rule::view_transition::parse_view_transition_at_rule;
 use crate::syntax::parse_error::expected_any_at_rule;
+use crate::syntax::scss::{
+    parse_scss_content_at_rule, parse_scss_each_at_rule, parse_scss_extend_at_rule,
+    parse_scss_for_at_rule, parse_scss_forward_at_rule, parse_scss_function_at_rule,
+    parse_scss_if_at_rule, parse_scss_include_at_rule, parse_scss_mixin_at_rule,
+    parse_scss_return_at_rule, parse_scss_use_at_rule, parse_scss_while_at_rule,
+};
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::T;
 use biome_parser::prelude::ParsedSyntax::{Absent, Present};
@@ -106,6 +112,18 @@ pub(crate) fn parse_any_at_rule(p: &mut CssParser) -> ParsedSyntax {
         T![value] => parse_value_at_rule(p),
         T![position_try] => parse_position_try_at_rule(p),
         T![view_transition] => parse_view_transition_at_rule(p),
+        T![use] => parse_scss_use_at_rule(p),
+        T![forward] => parse_scss_forward_at_rule(p),
+        T![mixin] => parse_scss_mixin_at_rule(p),
+        T![include] => parse_scss_include_at_rule(p),
+        T![content] => parse_scss_content_at_rule(p),
+        T![extend] => parse_scss_extend_at_rule(p),
+        T![function] => parse_scss_function_at_rule(p),
+        T![return] => parse_scss_return_at_rule(p),
+        T![if] => parse_scss_if_at_rule(p),
+        T![each] => parse_scss_each_at_rule(p),
+        T![for] => parse_scss_for_at_rule(p),
+        T![while] => parse_scss_while_at_rule(p),
         _ if is_at_unknown_at_rule(p) => parse_unknown_at_rule(p),
         _ => Absent,
     }
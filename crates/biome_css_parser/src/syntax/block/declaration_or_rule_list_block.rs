@@ -1,7 +1,9 @@
 use crate::parser::CssParser;
 use crate::syntax::at_rule::{is_at_at_rule, parse_at_rule};
 use crate::syntax::block::ParseBlockBody;
+use crate::syntax::less::{is_at_less_rule, parse_less_rule};
 use crate::syntax::parse_error::expected_any_declaration_or_at_rule;
+use crate::syntax::scss::{is_at_scss_declaration, parse_scss_declaration};
 use crate::syntax::{
     is_at_declaration, is_at_declaration_semicolon, is_at_metavariable,
     is_at_nested_qualified_rule, parse_declaration_with_semicolon, parse_empty_declaration,
@@ -36,7 +38,9 @@ impl ParseBlockBody for DeclarationOrRuleListBlock {
 
 #[inline]
 fn is_at_declaration_or_rule_item(p: &mut CssParser) -> bool {
-    is_at_at_rule(p)
+    is_at_less_rule(p)
+        || is_at_at_rule(p)
+        || is_at_scss_declaration(p)
         || is_at_nested_qualified_rule(p)
         || is_at_declaration(p)
         || is_at_metavariable(p)
@@ -60,8 +64,12 @@ impl ParseNodeList for DeclarationOrRuleList {
     const LIST_KIND: Self::Kind = CSS_DECLARATION_OR_RULE_LIST;
 
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
-        if is_at_at_rule(p) {
+        if is_at_less_rule(p) {
+            parse_less_rule(p)
+        } else if is_at_at_rule(p) {
             parse_at_rule(p)
+        } else if is_at_scss_declaration(p) {
+            parse_scss_declaration(p)
         } else if is_at_declaration(p) {
             // if we are at a declaration,
             // we still can have a nested qualified rule or a declaration
@@ -0,0 +1,79 @@
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::less::expression::{is_at_less_identifier, parse_less_identifier};
+use crate::syntax::property::BoundedComponentValueList;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::T;
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, token_set};
+
+/// Checks if the current token is the start of a variable declaration, like
+/// `@primary: #333;`, or of a detached ruleset, like `@detached: { }`.
+#[inline]
+pub(crate) fn is_at_less_variable_declaration(p: &mut CssParser) -> bool {
+    // `@page :first {}` is a page rule with a pseudo-page selector.
+    is_at_less_identifier(p) && !p.nth_at(1, T![page]) && p.nth_at(2, T![:])
+}
+
+/// Parses a variable declaration, like `@primary: #333;`, or a detached
+/// ruleset, like `@detached: { background: red; }`.
+///
+/// As with regular declarations, the semicolon is optional if the declaration
+/// is the last one in a block.
+#[inline]
+pub(crate) fn parse_less_variable_declaration(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_variable_declaration(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    parse_less_identifier(p).ok();
+    p.bump(T![:]);
+
+    if p.at(T!['{']) {
+        parse_declaration_or_rule_list_block(p);
+        p.eat(T![;]);
+        return Present(m.complete(p, LESS_DETACHED_RULESET_DECLARATION));
+    }
+
+    BoundedComponentValueList::new(token_set![T![;], T!['}']]).parse_non_empty_list(p);
+    expect_less_statement_end(p);
+
+    Present(m.complete(p, LESS_VARIABLE_DECLARATION))
+}
+
+/// Checks if the current token is the start of a call of a detached ruleset,
+/// like `@detached();`.
+#[inline]
+pub(crate) fn is_at_less_detached_ruleset_call(p: &mut CssParser) -> bool {
+    // `@import () "foo.less";` is an import rule with missing options.
+    is_at_less_identifier(p)
+        && !p.nth_at(1, T![import])
+        && p.nth_at(2, T!['('])
+        && p.nth_at(3, T![')'])
+}
+
+#[inline]
+pub(crate) fn parse_less_detached_ruleset_call(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_detached_ruleset_call(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    parse_less_identifier(p).ok();
+    p.bump(T!['(']);
+    p.bump(T![')']);
+    expect_less_statement_end(p);
+
+    Present(m.complete(p, LESS_DETACHED_RULESET_CALL))
+}
+
+/// Expects the semicolon ending a statement, unless the statement is the last
+/// one in a block.
+#[inline]
+pub(super) fn expect_less_statement_end(p: &mut CssParser) {
+    if !p.at(T!['}']) && !p.at(EOF) {
+        p.expect(T![;]);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::parser::CssParser;
+use crate::syntax::property::BoundedComponentValueList;
+use crate::syntax::{is_nth_at_identifier, parse_regular_identifier, parse_string};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, TokenSet, token_set};
+
+/// Checks if the current token is the start of a Less variable, like `@color`.
+#[inline]
+pub(crate) fn is_at_less_identifier(p: &mut CssParser) -> bool {
+    is_nth_at_less_identifier(p, 0)
+}
+
+#[inline]
+pub(crate) fn is_nth_at_less_identifier(p: &mut CssParser, n: usize) -> bool {
+    p.options().is_less_enabled() && p.nth_at(n, T![@]) && is_nth_at_identifier(p, n + 1)
+}
+
+/// Parses a Less variable, like `@color`.
+#[inline]
+pub(crate) fn parse_less_identifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![@]);
+    parse_regular_identifier(p).ok();
+    Present(m.complete(p, LESS_IDENTIFIER))
+}
+
+/// Checks if the current token is the start of an escaped value, like
+/// `~"calc(100% - 10px)"`.
+#[inline]
+fn is_at_less_escaped_value(p: &mut CssParser) -> bool {
+    p.options().is_less_enabled() && p.at(T![~]) && p.nth_at(1, CSS_STRING_LITERAL)
+}
+
+/// Parses an escaped value, whose string is output as is.
+#[inline]
+fn parse_less_escaped_value(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_escaped_value(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![~]);
+    parse_string(p).ok();
+    Present(m.complete(p, LESS_ESCAPED_VALUE))
+}
+
+/// Checks if the current token is the start of a Less value that can be used
+/// anywhere a CSS value can.
+#[inline]
+pub(crate) fn is_at_less_value(p: &mut CssParser) -> bool {
+    is_at_less_identifier(p) || is_at_less_escaped_value(p)
+}
+
+/// Parses a Less value: a variable or an escaped value.
+#[inline]
+pub(crate) fn parse_less_value(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_less_identifier(p) {
+        parse_less_identifier(p)
+    } else {
+        parse_less_escaped_value(p)
+    }
+}
+
+const LESS_OPERATOR_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![+], T![-], T![*], T![=], T![<], T![<=], T![>], T![>=]];
+
+/// Checks if the current token is the start of a Less component value that
+/// can only be used in the value of a property or variable: an operator or a
+/// parenthesized expression.
+#[inline]
+pub(crate) fn is_at_less_component_value(p: &mut CssParser) -> bool {
+    p.options().is_less_enabled() && (p.at_ts(LESS_OPERATOR_SET) || p.at(T!['(']))
+}
+
+/// Parses an operator or a parenthesized expression.
+#[inline]
+pub(crate) fn parse_less_component_value(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_component_value(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    if p.at(T!['(']) {
+        p.bump(T!['(']);
+        BoundedComponentValueList::new(token_set![T![')'], T![;], T!['}']]).parse_list(p);
+        p.expect(T![')']);
+        Present(m.complete(p, LESS_PARENTHESIZED_EXPRESSION))
+    } else {
+        p.bump_ts(LESS_OPERATOR_SET);
+        Present(m.complete(p, LESS_OPERATOR))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::parser::CssParser;
+use crate::syntax::less::parse_error::expected_less_import_option;
+use crate::syntax::parse_regular_identifier;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseSeparatedList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, token_set};
+
+/// Parses the options of an `@import` rule, like the `(reference, optional)`
+/// in `@import (reference, optional) "foo.less";`.
+#[inline]
+pub(crate) fn parse_less_import_options(p: &mut CssParser) -> ParsedSyntax {
+    if !(p.options().is_less_enabled() && p.at(T!['('])) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['(']);
+    LessImportOptionList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, LESS_IMPORT_OPTIONS))
+}
+
+struct LessImportOptionList;
+
+impl ParseSeparatedList for LessImportOptionList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = LESS_IMPORT_OPTION_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_regular_identifier(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![')'], T![;]]),
+            expected_less_import_option,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_empty(&self) -> bool {
+        false
+    }
+}
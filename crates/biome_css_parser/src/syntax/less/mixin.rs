@@ -0,0 +1,400 @@
+use crate::lexer::CssLexContext;
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::less::declaration::expect_less_statement_end;
+use crate::syntax::less::expression::{is_at_less_identifier, parse_less_identifier};
+use crate::syntax::less::parse_error::{
+    expected_less_guard_condition, expected_less_mixin_argument, expected_less_mixin_name,
+    expected_less_mixin_parameter,
+};
+use crate::syntax::parse_error::expected_identifier;
+use crate::syntax::property::BoundedComponentValueList;
+use crate::syntax::{
+    is_at_any_value, is_nth_at_identifier, parse_any_value, parse_custom_identifier_with_keywords,
+    parse_declaration_important, try_parse,
+};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{CompletedMarker, Parser, TokenSet, token_set};
+
+/// Checks if the current token is the start of a mixin declaration, like
+/// `.bordered(@width) { }`, or of a mixin call, like `.bordered(2px);` or
+/// `#namespace > .mixin;`.
+///
+/// Mixins are written like class or ID selectors, so this looks past the
+/// names of the mixin and its namespaces for the parenthesis or the end of
+/// the statement that tells them apart from a qualified rule.
+#[inline]
+pub(crate) fn is_at_less_mixin(p: &mut CssParser) -> bool {
+    if !p.options().is_less_enabled() {
+        return false;
+    }
+
+    let mut n = 0;
+    loop {
+        if !is_nth_at_less_mixin_name(p, n) {
+            return false;
+        }
+        n += 2;
+
+        if p.nth_at(n, T![>]) {
+            n += 1;
+        } else if !p.nth_at(n, T![.]) && !p.nth_at(n, T![#]) {
+            break;
+        }
+    }
+
+    p.nth_at(n, T!['('])
+        || p.nth_at(n, T![;])
+        || p.nth_at(n, T!['}'])
+        || (p.nth_at(n, T![!]) && p.nth_at(n + 1, T![important]))
+}
+
+#[inline]
+fn is_nth_at_less_mixin_name(p: &mut CssParser, n: usize) -> bool {
+    (p.nth_at(n, T![.]) || p.nth_at(n, T![#])) && is_nth_at_identifier(p, n + 1)
+}
+
+/// Parses a mixin declaration or a mixin call.
+///
+/// Both start the same way, and the parameters of a declaration can look like
+/// the arguments of a call, so the mixin is first parsed as a declaration and
+/// then as a call if no block follows the parameters.
+#[inline]
+pub(crate) fn parse_less_mixin(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_mixin(p) {
+        return Absent;
+    }
+
+    if let Ok(declaration) = try_parse(p, try_parse_less_mixin_declaration) {
+        return declaration;
+    }
+
+    parse_less_mixin_call(p)
+}
+
+/// Parses a mixin declaration, like `.bordered(@width: 2px) when (@width > 0) { }`.
+fn try_parse_less_mixin_declaration(p: &mut CssParser) -> Result<ParsedSyntax, ()> {
+    let m = p.start();
+    parse_less_mixin_name(p).ok();
+
+    if parse_less_mixin_parameters(p).is_absent() {
+        m.abandon(p);
+        return Err(());
+    }
+
+    parse_less_mixin_guard(p).ok();
+
+    if !p.at(T!['{']) {
+        m.abandon(p);
+        return Err(());
+    }
+
+    parse_declaration_or_rule_list_block(p);
+
+    Ok(Present(m.complete(p, LESS_MIXIN_DECLARATION)))
+}
+
+/// Parses a mixin call, like `#namespace > .bordered(4px) !important;`.
+#[inline]
+fn parse_less_mixin_call(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+    parse_less_mixin_path(p);
+    parse_less_mixin_arguments(p).ok();
+    parse_declaration_important(p).ok();
+    expect_less_statement_end(p);
+
+    Present(m.complete(p, LESS_MIXIN_CALL))
+}
+
+/// Parses the name of a mixin, preceded by the names of its namespaces.
+#[inline]
+fn parse_less_mixin_path(p: &mut CssParser) -> CompletedMarker {
+    let m = p.start();
+
+    loop {
+        let segment = p.start();
+        p.eat(T![>]);
+        parse_less_mixin_name(p).or_add_diagnostic(p, expected_less_mixin_name);
+        segment.complete(p, LESS_MIXIN_PATH_SEGMENT);
+
+        if !p.at(T![>]) && !p.at(T![.]) && !p.at(T![#]) {
+            break;
+        }
+    }
+
+    m.complete(p, LESS_MIXIN_PATH_SEGMENT_LIST)
+}
+
+/// Parses the name of a mixin, which is written like a class or an ID
+/// selector.
+///
+/// Unlike in selectors, whitespace following the name isn't significant, so
+/// it's lexed using the regular context.
+#[inline]
+fn parse_less_mixin_name(p: &mut CssParser) -> ParsedSyntax {
+    let kind = if p.at(T![.]) {
+        CSS_CLASS_SELECTOR
+    } else if p.at(T![#]) {
+        CSS_ID_SELECTOR
+    } else {
+        return Absent;
+    };
+
+    let m = p.start();
+    p.bump_any();
+    parse_custom_identifier_with_keywords(p, CssLexContext::Regular, true)
+        .or_add_diagnostic(p, expected_identifier);
+
+    Present(m.complete(p, kind))
+}
+
+/// Returns the token separating the parameters or arguments of the mixin
+/// whose opening parenthesis is the current token.
+///
+/// Less separates them with semicolons if there's any, so that commas can be
+/// used in values like `Arial, sans-serif`, and with commas otherwise.
+fn less_mixin_separator(p: &mut CssParser) -> CssSyntaxKind {
+    let mut depth = 0usize;
+    let mut n = 0;
+
+    loop {
+        match p.nth(n) {
+            T!['('] | T!['['] | T!['{'] => depth += 1,
+            T![')'] | T![']'] | T!['}'] => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return T![,];
+                }
+            }
+            T![;] if depth == 1 => return T![;],
+            EOF => return T![,],
+            _ => {}
+        }
+        n += 1;
+    }
+}
+
+#[inline]
+fn parse_less_mixin_parameters(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    let separator = less_mixin_separator(p);
+    p.bump(T!['(']);
+    LessMixinParameterList { separator }.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, LESS_MIXIN_PARAMETERS))
+}
+
+/// Returns the tokens ending a parameter or an argument separated by
+/// `separator`.
+#[inline]
+fn less_mixin_item_end_set(separator: CssSyntaxKind) -> TokenSet<CssSyntaxKind> {
+    token_set![separator, T![')'], T!['{'], T!['}']]
+}
+
+struct LessMixinParameterList {
+    separator: CssSyntaxKind,
+}
+
+impl ParseNodeList for LessMixinParameterList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = LESS_MIXIN_PARAMETER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_less_mixin_parameter(p, self.separator)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(
+                CSS_BOGUS_PARAMETER,
+                less_mixin_item_end_set(self.separator),
+            ),
+            expected_less_mixin_parameter,
+        )
+    }
+}
+
+/// Parses a parameter of a mixin declaration: a variable with an optional
+/// default value, like `@width: 2px`, a rest parameter, like `@rest...`, or a
+/// pattern matched against the arguments, like `dark`, along with the
+/// separator following it.
+#[inline]
+fn parse_less_mixin_parameter(p: &mut CssParser, separator: CssSyntaxKind) -> ParsedSyntax {
+    if p.at(T![...]) || (is_at_less_identifier(p) && p.nth_at(2, T![...])) {
+        let m = p.start();
+        parse_less_identifier(p).ok();
+        p.bump(T![...]);
+        p.eat(separator);
+        return Present(m.complete(p, LESS_MIXIN_REST_PARAMETER));
+    }
+
+    if is_at_less_identifier(p) {
+        let m = p.start();
+        parse_less_identifier(p).ok();
+
+        if p.at(T![:]) {
+            let default_value = p.start();
+            p.bump(T![:]);
+            BoundedComponentValueList::new(less_mixin_item_end_set(separator))
+                .parse_non_empty_list(p);
+            default_value.complete(p, LESS_MIXIN_PARAMETER_DEFAULT_VALUE);
+        }
+
+        p.eat(separator);
+        return Present(m.complete(p, LESS_MIXIN_PARAMETER));
+    }
+
+    if is_at_any_value(p) {
+        let m = p.start();
+        parse_any_value(p).ok();
+        p.eat(separator);
+        return Present(m.complete(p, LESS_MIXIN_PATTERN_PARAMETER));
+    }
+
+    Absent
+}
+
+#[inline]
+fn parse_less_mixin_arguments(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    let separator = less_mixin_separator(p);
+    p.bump(T!['(']);
+    LessMixinArgumentList { separator }.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, LESS_MIXIN_ARGUMENTS))
+}
+
+struct LessMixinArgumentList {
+    separator: CssSyntaxKind,
+}
+
+impl ParseNodeList for LessMixinArgumentList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = LESS_MIXIN_ARGUMENT_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_less_mixin_argument(p, self.separator)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, less_mixin_item_end_set(self.separator)),
+            expected_less_mixin_argument,
+        )
+    }
+}
+
+/// Parses an argument of a mixin call, like `2px solid` or `@style: dashed`,
+/// along with the separator following it.
+#[inline]
+fn parse_less_mixin_argument(p: &mut CssParser, separator: CssSyntaxKind) -> ParsedSyntax {
+    let end_ts = less_mixin_item_end_set(separator);
+    if p.at_ts(end_ts) {
+        return Absent;
+    }
+
+    let m = p.start();
+    let kind = if is_at_less_identifier(p) && p.nth_at(2, T![:]) {
+        parse_less_identifier(p).ok();
+        p.bump(T![:]);
+        LESS_MIXIN_NAMED_ARGUMENT
+    } else {
+        LESS_MIXIN_ARGUMENT
+    };
+    BoundedComponentValueList::new(end_ts).parse_non_empty_list(p);
+    p.eat(separator);
+
+    Present(m.complete(p, kind))
+}
+
+/// Parses the guard of a mixin declaration, like `when (@width > 0)`.
+#[inline]
+fn parse_less_mixin_guard(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![when]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![when]);
+    parse_any_less_guard_condition(p).or_add_diagnostic(p, expected_less_guard_condition);
+
+    Present(m.complete(p, LESS_MIXIN_GUARD))
+}
+
+const LESS_GUARD_OPERATOR_SET: TokenSet<CssSyntaxKind> = token_set![T![and], T![or], T![,]];
+
+/// Parses the conditions of a guard, combined from left to right with `and`,
+/// `or` and `,`, which is the same as `or`.
+#[inline]
+fn parse_any_less_guard_condition(p: &mut CssParser) -> ParsedSyntax {
+    let Present(mut left) = parse_less_guard_condition(p) else {
+        return Absent;
+    };
+
+    while p.at_ts(LESS_GUARD_OPERATOR_SET) {
+        let m = left.precede(p);
+        p.bump_ts(LESS_GUARD_OPERATOR_SET);
+        parse_less_guard_condition(p).or_add_diagnostic(p, expected_less_guard_condition);
+        left = m.complete(p, LESS_GUARD_LOGICAL_CONDITION);
+    }
+
+    Present(left)
+}
+
+#[inline]
+fn is_at_less_guard_condition(p: &mut CssParser) -> bool {
+    p.at(T!['(']) || (p.at(T![not]) && p.nth_at(1, T!['(']))
+}
+
+/// Parses a single condition of a guard, like `(@width > 0)` or
+/// `not (iscolor(@c))`.
+#[inline]
+fn parse_less_guard_condition(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_guard_condition(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.eat(T![not]);
+    p.bump(T!['(']);
+    BoundedComponentValueList::new(token_set![T![')'], T!['{'], T![;], T!['}']])
+        .parse_non_empty_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, LESS_GUARD_CONDITION))
+}
@@ -0,0 +1,49 @@
+//! Parsing of the [Less](https://lesscss.org/features/) syntax.
+//!
+//! Like SCSS, Less is a superset of CSS, so these functions extend the regular
+//! CSS parser at the points where Less adds new syntax. They should only be
+//! used when [crate::CssParserOptions::is_less_enabled] returns `true`; the
+//! tokens Less relies on, such as the `when` keyword, aren't produced by the
+//! lexer otherwise.
+
+mod declaration;
+mod expression;
+mod import;
+mod mixin;
+mod parse_error;
+
+use crate::parser::CssParser;
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::Absent;
+pub(crate) use declaration::{
+    is_at_less_detached_ruleset_call, is_at_less_variable_declaration,
+    parse_less_detached_ruleset_call, parse_less_variable_declaration,
+};
+pub(crate) use expression::{
+    is_at_less_component_value, is_at_less_value, parse_less_component_value, parse_less_value,
+};
+pub(crate) use import::parse_less_import_options;
+pub(crate) use mixin::{is_at_less_mixin, parse_less_mixin};
+pub(crate) use parse_error::expected_less_import_url;
+
+/// Checks if the current token is the start of a Less statement that can be
+/// used both at the root of a stylesheet and inside of a block.
+#[inline]
+pub(crate) fn is_at_less_rule(p: &mut CssParser) -> bool {
+    is_at_less_variable_declaration(p) || is_at_less_detached_ruleset_call(p) || is_at_less_mixin(p)
+}
+
+/// Parses a variable declaration, a detached ruleset call, or a mixin
+/// declaration or call.
+#[inline]
+pub(crate) fn parse_less_rule(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_less_variable_declaration(p) {
+        parse_less_variable_declaration(p)
+    } else if is_at_less_detached_ruleset_call(p) {
+        parse_less_detached_ruleset_call(p)
+    } else if is_at_less_mixin(p) {
+        parse_less_mixin(p)
+    } else {
+        Absent
+    }
+}
@@ -0,0 +1,28 @@
+use crate::parser::CssParser;
+use biome_parser::diagnostic::{ToDiagnostic, expect_one_of, expected_any, expected_node};
+use biome_parser::prelude::ParseDiagnostic;
+use biome_rowan::TextRange;
+
+pub(crate) fn expected_less_mixin_name(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["class selector", "id selector"], range, p)
+}
+
+pub(crate) fn expected_less_mixin_parameter(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("parameter", range, p)
+}
+
+pub(crate) fn expected_less_mixin_argument(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("argument", range, p)
+}
+
+pub(crate) fn expected_less_guard_condition(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("guard condition", range, p)
+}
+
+pub(crate) fn expected_less_import_option(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("import option", range, p)
+}
+
+pub(crate) fn expected_less_import_url(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expect_one_of(&["url()", "<string-token>"], range).into_diagnostic(p)
+}
@@ -1,8 +1,10 @@
 mod at_rule;
 mod block;
 mod css_modules;
+mod less;
 mod parse_error;
 mod property;
+mod scss;
 mod selector;
 mod value;
 
@@ -10,10 +12,15 @@ use crate::lexer::CssLexContext;
 use crate::parser::CssParser;
 use crate::syntax::at_rule::{is_at_at_rule, parse_at_rule};
 use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::less::{is_at_less_rule, is_at_less_value, parse_less_rule, parse_less_value};
 use crate::syntax::parse_error::{expected_any_rule, expected_non_css_wide_keyword_identifier};
 use crate::syntax::property::color::{is_at_color, parse_color};
 use crate::syntax::property::unicode_range::{is_at_unicode_range, parse_unicode_range};
 use crate::syntax::property::{is_at_any_property, parse_any_property};
+use crate::syntax::scss::{
+    is_at_scss_declaration, is_at_scss_value, parse_scss_declaration,
+    parse_scss_interpolated_identifier, parse_scss_value,
+};
 use crate::syntax::selector::SelectorList;
 use crate::syntax::selector::is_nth_at_selector;
 use crate::syntax::selector::relative_selector::{RelativeSelectorList, is_at_relative_selector};
@@ -51,7 +58,7 @@ impl RuleList {
 
 #[inline]
 pub(crate) fn is_at_rule_list_element(p: &mut CssParser) -> bool {
-    is_at_at_rule(p) || is_at_qualified_rule(p)
+    is_at_less_rule(p) || is_at_at_rule(p) || is_at_scss_declaration(p) || is_at_qualified_rule(p)
 }
 
 struct RuleListParseRecovery {
@@ -80,8 +87,12 @@ impl ParseNodeList for RuleList {
     const LIST_KIND: Self::Kind = CSS_RULE_LIST;
 
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
-        if is_at_at_rule(p) {
+        if is_at_less_rule(p) {
+            parse_less_rule(p)
+        } else if is_at_at_rule(p) {
             parse_at_rule(p)
+        } else if is_at_scss_declaration(p) {
+            parse_scss_declaration(p)
         } else if is_at_qualified_rule(p) {
             parse_qualified_rule(p)
         } else {
@@ -254,7 +265,7 @@ pub(crate) fn is_at_declaration_semicolon(p: &mut CssParser) -> bool {
 }
 
 #[inline]
-fn parse_declaration_important(p: &mut CssParser) -> ParsedSyntax {
+pub(crate) fn parse_declaration_important(p: &mut CssParser) -> ParsedSyntax {
     if !is_at_declaration_important(p) {
         return Absent;
     }
@@ -286,7 +297,9 @@ fn parse_metavariable(p: &mut CssParser) -> ParsedSyntax {
 
 #[inline]
 pub(crate) fn is_at_any_value(p: &mut CssParser) -> bool {
-    is_at_any_function(p)
+    is_at_scss_value(p)
+        || is_at_less_value(p)
+        || is_at_any_function(p)
         || is_at_identifier(p)
         || p.at(CSS_STRING_LITERAL)
         || is_at_any_dimension(p)
@@ -300,14 +313,22 @@ pub(crate) fn is_at_any_value(p: &mut CssParser) -> bool {
 
 #[inline]
 pub(crate) fn parse_any_value(p: &mut CssParser) -> ParsedSyntax {
-    if is_at_any_function(p) {
+    if is_at_scss_value(p) {
+        parse_scss_value(p)
+    } else if is_at_less_value(p) {
+        parse_less_value(p)
+    } else if is_at_any_function(p) {
         parse_any_function(p)
     } else if is_at_dashed_identifier(p) {
         parse_dashed_identifier(p)
     } else if is_at_unicode_range(p) {
         parse_unicode_range(p)
     } else if is_at_identifier(p) {
-        parse_regular_identifier(p)
+        if p.options().is_scss_enabled() {
+            parse_scss_interpolated_identifier(p)
+        } else {
+            parse_regular_identifier(p)
+        }
     } else if p.at(CSS_STRING_LITERAL) {
         parse_string(p)
     } else if is_at_any_dimension(p) {
@@ -6,7 +6,12 @@ use crate::parser::CssParser;
 use crate::syntax::css_modules::{
     composes_not_allowed, expected_classes_list, expected_composes_import_source,
 };
+use crate::syntax::less::{is_at_less_component_value, parse_less_component_value};
 use crate::syntax::parse_error::{expected_component_value, expected_identifier};
+use crate::syntax::scss::{
+    is_at_scss_component_value, is_at_scss_interpolated_property_name, parse_scss_component_value,
+    parse_scss_interpolated_identifier,
+};
 use crate::syntax::{
     is_at_any_value, is_at_dashed_identifier, is_at_identifier, is_at_string, parse_any_value,
     parse_custom_identifier_with_keywords, parse_dashed_identifier, parse_regular_identifier,
@@ -18,7 +23,7 @@ use biome_parser::parse_lists::ParseNodeList;
 use biome_parser::parse_recovery::{ParseRecovery, ParseRecoveryTokenSet, RecoveryResult};
 use biome_parser::prelude::ParsedSyntax;
 use biome_parser::prelude::ParsedSyntax::{Absent, Present};
-use biome_parser::{Parser, TokenSet, token_set};
+use biome_parser::{CompletedMarker, Parser, TokenSet, token_set};
 
 #[inline]
 pub(crate) fn is_at_any_property(p: &mut CssParser) -> bool {
@@ -159,7 +164,7 @@ const END_OF_COMPOSES_CLASS_TOKEN_SET: TokenSet<CssSyntaxKind> =
 
 #[inline]
 fn is_at_generic_property(p: &mut CssParser) -> bool {
-    is_at_identifier(p) && p.nth_at(1, T![:])
+    is_at_identifier(p) && p.nth_at(1, T![:]) || is_at_scss_interpolated_property_name(p)
 }
 
 #[inline]
@@ -172,6 +177,8 @@ fn parse_generic_property(p: &mut CssParser) -> ParsedSyntax {
 
     if is_at_dashed_identifier(p) {
         parse_dashed_identifier(p).ok();
+    } else if p.options().is_scss_enabled() {
+        parse_scss_interpolated_identifier(p).ok();
     } else {
         parse_regular_identifier(p).ok();
     }
@@ -213,21 +220,80 @@ impl ParseNodeList for GenericComponentValueList {
     }
 }
 
+/// A list of component values, like the value of a property, that ends at
+/// any of the tokens in `end_ts`.
+///
+/// Preprocessors use component values in many places where CSS doesn't, such
+/// as in the arguments of a mixin or the condition of an `@if` rule, each of
+/// which ends at a different token.
+pub(crate) struct BoundedComponentValueList {
+    end_ts: TokenSet<CssSyntaxKind>,
+}
+
+impl BoundedComponentValueList {
+    pub(crate) fn new(end_ts: TokenSet<CssSyntaxKind>) -> Self {
+        Self { end_ts }
+    }
+
+    /// Parses the list, adding a diagnostic if it doesn't contain any value.
+    pub(crate) fn parse_non_empty_list(mut self, p: &mut CssParser) -> CompletedMarker {
+        let list = self.parse_list(p);
+        if list.range(p).is_empty() {
+            p.error(expected_component_value(p, p.cur_range()));
+        }
+        list
+    }
+}
+
+impl ParseNodeList for BoundedComponentValueList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = CSS_GENERIC_COMPONENT_VALUE_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_generic_component_value(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at_ts(self.end_ts)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS_PROPERTY_VALUE, self.end_ts)
+                .enable_recovery_on_line_break(),
+            expected_component_value,
+        )
+    }
+}
+
 #[inline]
 fn is_at_generic_component_value(p: &mut CssParser) -> bool {
-    is_at_any_value(p) || is_at_generic_delimiter(p)
+    is_at_any_value(p)
+        || is_at_generic_delimiter(p)
+        || is_at_scss_component_value(p)
+        || is_at_less_component_value(p)
 }
 
 #[inline]
-fn parse_generic_component_value(p: &mut CssParser) -> ParsedSyntax {
+pub(crate) fn parse_generic_component_value(p: &mut CssParser) -> ParsedSyntax {
     if !is_at_generic_component_value(p) {
         return Absent;
     }
 
     if is_at_generic_delimiter(p) {
         parse_generic_delimiter(p)
-    } else {
+    } else if is_at_any_value(p) {
         parse_any_value(p)
+    } else if is_at_scss_component_value(p) {
+        parse_scss_component_value(p)
+    } else {
+        parse_less_component_value(p)
     }
 }
 
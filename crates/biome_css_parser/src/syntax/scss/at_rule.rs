@@ -0,0 +1,657 @@
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::parse_error::{expected_identifier, expected_string};
+use crate::syntax::property::BoundedComponentValueList;
+use crate::syntax::scss::declaration::ScssVariableModifierList;
+use crate::syntax::scss::expression::{
+    expect_scss_identifier, is_at_scss_identifier, parse_scss_identifier,
+};
+use crate::syntax::scss::parse_error::{
+    expected_scss_argument, expected_scss_for_range, expected_scss_forward_member,
+    expected_scss_keyword_argument, expected_scss_parameter, expected_scss_variable,
+};
+use crate::syntax::selector::SelectorList;
+use crate::syntax::{
+    is_at_identifier, is_nth_at_identifier, parse_regular_identifier, parse_string,
+};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, TokenSet, token_set};
+
+/// Parses a `@use` rule, which loads members from another module.
+///
+/// ```scss
+/// @use "sass:math" as m;
+/// @use "library" with ($black: #222);
+/// ```
+#[inline]
+pub(crate) fn parse_scss_use_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![use]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![use]);
+    parse_string(p).or_add_diagnostic(p, expected_string);
+
+    if p.at(T![as]) {
+        let clause = p.start();
+        p.bump(T![as]);
+        if p.at(T![*]) {
+            let namespace = p.start();
+            p.bump(T![*]);
+            namespace.complete(p, SCSS_USE_ALL_NAMESPACE);
+        } else {
+            parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+        }
+        clause.complete(p, SCSS_USE_AS_CLAUSE);
+    }
+
+    parse_scss_module_configuration(p).ok();
+    p.expect(T![;]);
+
+    Present(m.complete(p, SCSS_USE_AT_RULE))
+}
+
+/// Parses a `@forward` rule, which makes the members of another module
+/// available to the modules that load this one.
+///
+/// ```scss
+/// @forward "src/list" as list-* hide list-reset, $horizontal-list-gap;
+/// ```
+#[inline]
+pub(crate) fn parse_scss_forward_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![forward]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![forward]);
+    parse_string(p).or_add_diagnostic(p, expected_string);
+
+    if p.at(T![as]) {
+        let clause = p.start();
+        p.bump(T![as]);
+        parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+        p.expect(T![*]);
+        clause.complete(p, SCSS_FORWARD_AS_CLAUSE);
+    }
+
+    if p.at(T![show]) || p.at(T![hide]) {
+        let clause = p.start();
+        p.bump_any();
+        ScssForwardMemberList.parse_list(p);
+        clause.complete(p, SCSS_FORWARD_VISIBILITY_CLAUSE);
+    }
+
+    parse_scss_module_configuration(p).ok();
+    p.expect(T![;]);
+
+    Present(m.complete(p, SCSS_FORWARD_AT_RULE))
+}
+
+struct ScssForwardMemberList;
+
+impl ParseSeparatedList for ScssForwardMemberList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_FORWARD_MEMBER_LIST;
+
+    fn allow_empty(&self) -> bool {
+        false
+    }
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if is_at_scss_identifier(p) {
+            parse_scss_identifier(p)
+        } else {
+            parse_regular_identifier(p)
+        }
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![;]) || p.at(T![with])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![;], T![with]])
+                .enable_recovery_on_line_break(),
+            expected_scss_forward_member,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+}
+
+/// Parses the `with (...)` clause of a `@use` or `@forward` rule.
+#[inline]
+fn parse_scss_module_configuration(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![with]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![with]);
+    p.expect(T!['(']);
+    ScssKeywordArgumentList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_MODULE_CONFIGURATION))
+}
+
+const SCSS_ARGUMENT_RECOVERY_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![,], T![')'], T![;], T!['}']];
+
+struct ScssKeywordArgumentList;
+
+impl ParseSeparatedList for ScssKeywordArgumentList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_KEYWORD_ARGUMENT_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_keyword_argument(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_ARGUMENT_RECOVERY_SET),
+            expected_scss_keyword_argument,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+#[inline]
+fn is_at_scss_keyword_argument(p: &mut CssParser) -> bool {
+    is_at_scss_identifier(p) && p.nth_at(2, T![:])
+}
+
+/// Parses a keyword argument, like `$color: white`.
+#[inline]
+fn parse_scss_keyword_argument(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_keyword_argument(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    parse_scss_identifier(p).ok();
+    p.bump(T![:]);
+    BoundedComponentValueList::new(token_set![T![,], T![')'], T![!], T![;], T!['}']])
+        .parse_non_empty_list(p);
+    ScssVariableModifierList.parse_list(p);
+
+    Present(m.complete(p, SCSS_KEYWORD_ARGUMENT))
+}
+
+/// Parses a `@mixin` rule.
+///
+/// ```scss
+/// @mixin theme($theme: DarkGray) {
+///   background: $theme;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_mixin_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![mixin]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![mixin]);
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+    parse_scss_parameters(p).ok();
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_MIXIN_AT_RULE))
+}
+
+/// Parses a `@function` rule.
+///
+/// ```scss
+/// @function double($value) {
+///   @return $value * 2;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_function_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![function]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![function]);
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+    parse_scss_parameters(p).or_add_diagnostic(p, expected_scss_parameter);
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_FUNCTION_AT_RULE))
+}
+
+#[inline]
+fn parse_scss_parameters(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['(']);
+    ScssParameterList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_PARAMETERS))
+}
+
+struct ScssParameterList;
+
+impl ParseSeparatedList for ScssParameterList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_PARAMETER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_parameter(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS_PARAMETER, SCSS_ARGUMENT_RECOVERY_SET),
+            expected_scss_parameter,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+/// Parses a parameter of a mixin or a function, like `$theme: DarkGray` or
+/// `$args...`.
+#[inline]
+fn parse_scss_parameter(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    parse_scss_identifier(p).ok();
+
+    if p.at(T![:]) {
+        let default_value = p.start();
+        p.bump(T![:]);
+        BoundedComponentValueList::new(token_set![T![,], T![')'], T![...], T![;], T!['}']])
+            .parse_non_empty_list(p);
+        default_value.complete(p, SCSS_PARAMETER_DEFAULT_VALUE);
+    }
+
+    p.eat(T![...]);
+
+    Present(m.complete(p, SCSS_PARAMETER))
+}
+
+/// Parses a `@return` rule, which can only be used inside of a `@function`.
+#[inline]
+pub(crate) fn parse_scss_return_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![return]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![return]);
+    BoundedComponentValueList::new(token_set![T![;], T!['}']]).parse_non_empty_list(p);
+    expect_statement_end(p);
+
+    Present(m.complete(p, SCSS_RETURN_AT_RULE))
+}
+
+/// Parses an `@include` rule, which includes the styles of a mixin.
+///
+/// ```scss
+/// @include button-variant($primary, $color: white);
+/// @include breakpoint(md) {
+///   width: 50%;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_include_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![include]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![include]);
+
+    if is_at_identifier(p) && p.nth_at(1, T![.]) && is_nth_at_identifier(p, 2) {
+        let name = p.start();
+        parse_regular_identifier(p).ok();
+        p.bump(T![.]);
+        parse_regular_identifier(p).ok();
+        name.complete(p, SCSS_NAMESPACED_NAME);
+    } else {
+        parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+    }
+
+    parse_scss_arguments(p).ok();
+
+    if p.at(T!['{']) {
+        parse_declaration_or_rule_list_block(p);
+    } else {
+        expect_statement_end(p);
+    }
+
+    Present(m.complete(p, SCSS_INCLUDE_AT_RULE))
+}
+
+#[inline]
+fn parse_scss_arguments(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['(']);
+    ScssArgumentList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, SCSS_ARGUMENTS))
+}
+
+struct ScssArgumentList;
+
+impl ParseSeparatedList for ScssArgumentList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_ARGUMENT_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if is_at_scss_keyword_argument(p) {
+            parse_scss_keyword_argument(p)
+        } else {
+            parse_scss_argument(p)
+        }
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_ARGUMENT_RECOVERY_SET),
+            expected_scss_argument,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+const SCSS_ARGUMENT_END_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![,], T![')'], T![...], T![;], T!['}']];
+
+/// Parses a positional argument, like `$primary` or `$args...`.
+#[inline]
+fn parse_scss_argument(p: &mut CssParser) -> ParsedSyntax {
+    if p.at_ts(SCSS_ARGUMENT_END_SET) {
+        return Absent;
+    }
+
+    let m = p.start();
+    BoundedComponentValueList::new(SCSS_ARGUMENT_END_SET).parse_list(p);
+    p.eat(T![...]);
+
+    Present(m.complete(p, SCSS_ARGUMENT))
+}
+
+/// Parses a `@content` rule, which includes the block passed to a mixin.
+#[inline]
+pub(crate) fn parse_scss_content_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![content]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![content]);
+    parse_scss_arguments(p).ok();
+    expect_statement_end(p);
+
+    Present(m.complete(p, SCSS_CONTENT_AT_RULE))
+}
+
+/// Parses an `@extend` rule, which makes a selector inherit the styles of
+/// another one.
+///
+/// ```scss
+/// @extend %message-shared !optional;
+/// ```
+#[inline]
+pub(crate) fn parse_scss_extend_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![extend]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![extend]);
+
+    let end_ts = token_set![T![;], T![!], T!['}']];
+    SelectorList::default()
+        .with_end_kind_ts(end_ts)
+        .with_recovery_ts(end_ts)
+        .parse_list(p);
+
+    if p.at(T![!]) && p.nth_at(1, T![optional]) {
+        let optional = p.start();
+        p.bump(T![!]);
+        p.bump(T![optional]);
+        optional.complete(p, SCSS_EXTEND_OPTIONAL_MODIFIER);
+    }
+
+    expect_statement_end(p);
+
+    Present(m.complete(p, SCSS_EXTEND_AT_RULE))
+}
+
+const SCSS_CONDITION_END_SET: TokenSet<CssSyntaxKind> = token_set![T!['{'], T!['}'], T![;]];
+
+/// Parses an `@if` rule, along with its `@else if` and `@else` clauses.
+///
+/// ```scss
+/// @if $condition {
+/// } @else if $other {
+/// } @else {
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_if_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![if]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![if]);
+    BoundedComponentValueList::new(SCSS_CONDITION_END_SET).parse_non_empty_list(p);
+    parse_declaration_or_rule_list_block(p);
+
+    if p.at(T![@]) && p.nth_at(1, T![else]) {
+        let clause = p.start();
+        p.bump(T![@]);
+        p.bump(T![else]);
+        if p.at(T![if]) {
+            parse_scss_if_at_rule(p).ok();
+        } else {
+            parse_declaration_or_rule_list_block(p);
+        }
+        clause.complete(p, SCSS_ELSE_CLAUSE);
+    }
+
+    Present(m.complete(p, SCSS_IF_AT_RULE))
+}
+
+/// Parses an `@each` rule.
+///
+/// ```scss
+/// @each $name, $glyph in $icons {
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_each_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![each]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![each]);
+    ScssEachBindingList.parse_list(p);
+    p.expect(T![in]);
+    BoundedComponentValueList::new(SCSS_CONDITION_END_SET).parse_non_empty_list(p);
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_EACH_AT_RULE))
+}
+
+struct ScssEachBindingList;
+
+impl ParseSeparatedList for ScssEachBindingList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_EACH_BINDING_LIST;
+
+    fn allow_empty(&self) -> bool {
+        false
+    }
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_identifier(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![in]) || p.at(T!['{'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![,], T![in], T!['{']]),
+            expected_scss_variable,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+}
+
+/// Parses a `@for` rule.
+///
+/// ```scss
+/// @for $i from 1 through 3 {
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_for_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![for]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![for]);
+    expect_scss_identifier(p);
+    p.expect(T![from]);
+    BoundedComponentValueList::new(SCSS_CONDITION_END_SET.union(token_set![T![through], T![to]]))
+        .parse_non_empty_list(p);
+
+    if p.at(T![through]) || p.at(T![to]) {
+        p.bump_any();
+    } else {
+        p.error(expected_scss_for_range(p, p.cur_range()));
+    }
+
+    BoundedComponentValueList::new(SCSS_CONDITION_END_SET).parse_non_empty_list(p);
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_FOR_AT_RULE))
+}
+
+/// Parses a `@while` rule.
+///
+/// ```scss
+/// @while $i > 0 {
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_while_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![while]) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![while]);
+    BoundedComponentValueList::new(SCSS_CONDITION_END_SET).parse_non_empty_list(p);
+    parse_declaration_or_rule_list_block(p);
+
+    Present(m.complete(p, SCSS_WHILE_AT_RULE))
+}
+
+/// Expects the semicolon that ends a statement, which is optional if the
+/// statement is the last one in a block.
+#[inline]
+fn expect_statement_end(p: &mut CssParser) {
+    if !p.at(T!['}']) {
+        p.expect(T![;]);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::parser::CssParser;
+use crate::syntax::property::BoundedComponentValueList;
+use crate::syntax::scss::expression::{is_at_scss_identifier, parse_scss_identifier};
+use crate::syntax::scss::parse_error::expected_scss_variable_modifier;
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{Parser, token_set};
+
+/// Checks if the current token is the start of a variable declaration, like
+/// `$primary: #333;`.
+#[inline]
+pub(crate) fn is_at_scss_declaration(p: &mut CssParser) -> bool {
+    is_at_scss_identifier(p) && p.nth_at(2, T![:])
+}
+
+/// Parses a variable declaration, like `$primary: #333 !default;`.
+///
+/// As with regular declarations, the semicolon is optional if the declaration
+/// is the last one in a block.
+#[inline]
+pub(crate) fn parse_scss_declaration(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_declaration(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    parse_scss_identifier(p).ok();
+    p.bump(T![:]);
+    BoundedComponentValueList::new(token_set![T![;], T!['}'], T![!]]).parse_non_empty_list(p);
+    ScssVariableModifierList.parse_list(p);
+
+    if !p.at(T!['}']) && !p.at(EOF) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, SCSS_DECLARATION))
+}
+
+/// The flags following the value of a variable, like `!default` and `!global`.
+pub(crate) struct ScssVariableModifierList;
+
+impl ParseNodeList for ScssVariableModifierList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_VARIABLE_MODIFIER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_variable_modifier(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        !p.at(T![!])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![;], T!['}'], T![,], T![')']]),
+            expected_scss_variable_modifier,
+        )
+    }
+}
+
+#[inline]
+fn parse_scss_variable_modifier(p: &mut CssParser) -> ParsedSyntax {
+    if !(p.at(T![!]) && (p.nth_at(1, T![default]) || p.nth_at(1, T![global]))) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![!]);
+    p.bump_any();
+    Present(m.complete(p, SCSS_VARIABLE_MODIFIER))
+}
@@ -0,0 +1,323 @@
+use crate::lexer::CssLexContext;
+use crate::parser::CssParser;
+use crate::syntax::parse_error::expected_identifier;
+use crate::syntax::property::BoundedComponentValueList;
+use crate::syntax::scss::parse_error::{expected_scss_map_entry, expected_scss_variable};
+use crate::syntax::value::function::{is_nth_at_function, parse_function};
+use crate::syntax::{
+    is_at_identifier, is_nth_at_identifier, parse_any_value, parse_regular_identifier,
+};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{CompletedMarker, Parser, TokenSet, token_set};
+
+/// Checks if the current token is the start of an SCSS variable, like `$color`.
+#[inline]
+pub(crate) fn is_at_scss_identifier(p: &mut CssParser) -> bool {
+    is_nth_at_scss_identifier(p, 0)
+}
+
+#[inline]
+pub(crate) fn is_nth_at_scss_identifier(p: &mut CssParser, n: usize) -> bool {
+    p.nth_at(n, T![$]) && is_nth_at_identifier(p, n + 1)
+}
+
+/// Parses an SCSS variable, like `$color`.
+#[inline]
+pub(crate) fn parse_scss_identifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![$]);
+    parse_regular_identifier(p).ok();
+    Present(m.complete(p, SCSS_IDENTIFIER))
+}
+
+/// Parses an SCSS variable, adding a diagnostic if the parser isn't at one.
+#[inline]
+pub(crate) fn expect_scss_identifier(p: &mut CssParser) -> Option<CompletedMarker> {
+    parse_scss_identifier(p).or_add_diagnostic(p, expected_scss_variable)
+}
+
+/// Checks if the current token is the start of a member of a module, like
+/// `math.$pi` or `math.div(10px, 3)`.
+#[inline]
+fn is_at_scss_qualified_name(p: &mut CssParser) -> bool {
+    is_at_identifier(p)
+        && p.nth_at(1, T![.])
+        && (is_nth_at_scss_identifier(p, 2) || is_nth_at_function(p, 2))
+}
+
+#[inline]
+fn parse_scss_qualified_name(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_qualified_name(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    parse_regular_identifier(p).ok();
+    p.bump(T![.]);
+    if is_at_scss_identifier(p) {
+        parse_scss_identifier(p).ok();
+    } else {
+        parse_function(p).ok();
+    }
+    Present(m.complete(p, SCSS_QUALIFIED_NAME))
+}
+
+/// Checks if the current token is the start of an interpolation, like `#{$name}`.
+#[inline]
+pub(crate) fn is_at_scss_interpolation(p: &mut CssParser) -> bool {
+    p.options().is_scss_enabled() && p.at(T![#]) && p.nth_at(1, T!['{'])
+}
+
+/// Parses an interpolation, like `#{$name}`.
+#[inline]
+fn parse_scss_interpolation(p: &mut CssParser) -> ParsedSyntax {
+    parse_scss_interpolation_with_close_context(p, |_| CssLexContext::Regular)
+}
+
+/// Parses an interpolation, lexing the token following the closing brace
+/// using the context returned by `close_context`.
+///
+/// This allows interpolations to be used in selectors, where whitespace is
+/// significant.
+#[inline]
+pub(crate) fn parse_scss_interpolation_with_close_context(
+    p: &mut CssParser,
+    close_context: impl FnOnce(&mut CssParser) -> CssLexContext,
+) -> ParsedSyntax {
+    if !is_at_scss_interpolation(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![#]);
+    p.bump(T!['{']);
+    BoundedComponentValueList::new(token_set![T!['}'], T![;]]).parse_non_empty_list(p);
+    let context = close_context(p);
+    p.expect_with_context(T!['}'], context);
+    Present(m.complete(p, SCSS_INTERPOLATION))
+}
+
+/// Checks if the current token is the start of a property name that contains
+/// an interpolation, like `#{$side}-margin` or `margin-#{$side}`.
+#[inline]
+pub(crate) fn is_at_scss_interpolated_property_name(p: &mut CssParser) -> bool {
+    is_at_scss_interpolation(p)
+        || (p.options().is_scss_enabled()
+            && is_at_identifier(p)
+            && p.nth_at(1, T![#])
+            && p.nth_at(2, T!['{']))
+}
+
+/// Parses an identifier that may contain interpolations, like `icon-#{$name}`.
+///
+/// If the identifier doesn't contain any interpolation, a regular identifier
+/// is returned instead.
+#[inline]
+pub(crate) fn parse_scss_interpolated_identifier(p: &mut CssParser) -> ParsedSyntax {
+    let first = if is_at_scss_interpolation(p) {
+        parse_scss_interpolation(p)
+    } else {
+        parse_regular_identifier(p)
+    };
+
+    first.map(|first| complete_scss_interpolated_identifier(p, first))
+}
+
+/// Continues parsing an identifier or an interpolation with any parts that
+/// directly follow it, without whitespace in between.
+///
+/// Returns `first` as is if no such parts follow it.
+fn complete_scss_interpolated_identifier(
+    p: &mut CssParser,
+    first: CompletedMarker,
+) -> CompletedMarker {
+    if !is_at_adjacent_interpolated_identifier_part(p) {
+        return first;
+    }
+
+    let parts = first.precede(p);
+    while is_at_adjacent_interpolated_identifier_part(p) {
+        if is_at_scss_interpolation(p) {
+            parse_scss_interpolation(p).ok();
+        } else {
+            parse_regular_identifier(p).ok();
+        }
+    }
+    let parts = parts.complete(p, SCSS_INTERPOLATED_IDENTIFIER_PART_LIST);
+
+    parts.precede(p).complete(p, SCSS_INTERPOLATED_IDENTIFIER)
+}
+
+#[inline]
+fn is_at_adjacent_interpolated_identifier_part(p: &mut CssParser) -> bool {
+    p.options().is_scss_enabled()
+        && p.last_end() == Some(p.cur_range().start())
+        && (is_at_scss_interpolation(p) || is_at_identifier(p))
+}
+
+/// Checks if the current token is the start of an SCSS value that can be used
+/// anywhere a CSS value can.
+#[inline]
+pub(crate) fn is_at_scss_value(p: &mut CssParser) -> bool {
+    p.options().is_scss_enabled()
+        && (is_at_scss_identifier(p) || is_at_scss_qualified_name(p) || is_at_scss_interpolation(p))
+}
+
+/// Parses an SCSS value: a variable, a member of a module or an
+/// interpolation.
+#[inline]
+pub(crate) fn parse_scss_value(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_value(p) {
+        return Absent;
+    }
+
+    if is_at_scss_identifier(p) {
+        parse_scss_identifier(p)
+    } else if is_at_scss_qualified_name(p) {
+        parse_scss_qualified_name(p)
+    } else {
+        parse_scss_interpolated_identifier(p)
+    }
+}
+
+const SCSS_OPERATOR_SET: TokenSet<CssSyntaxKind> = token_set![
+    T![+],
+    T![-],
+    T![*],
+    T![%],
+    T![==],
+    T![!=],
+    T![<],
+    T![<=],
+    T![>],
+    T![>=]
+];
+
+/// Checks if the current token is the start of an SCSS component value that
+/// can only be used in the value of a property or variable: an operator, or a
+/// parenthesized expression or map.
+#[inline]
+pub(crate) fn is_at_scss_component_value(p: &mut CssParser) -> bool {
+    p.options().is_scss_enabled() && (p.at_ts(SCSS_OPERATOR_SET) || p.at(T!['(']))
+}
+
+/// Parses an operator, a parenthesized expression or a map.
+#[inline]
+pub(crate) fn parse_scss_component_value(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_component_value(p) {
+        return Absent;
+    }
+
+    if p.at(T!['(']) {
+        if is_at_scss_map_expression(p) {
+            parse_scss_map_expression(p)
+        } else {
+            parse_scss_parenthesized_expression(p)
+        }
+    } else {
+        let m = p.start();
+        p.bump_ts(SCSS_OPERATOR_SET);
+        Present(m.complete(p, SCSS_OPERATOR))
+    }
+}
+
+#[inline]
+fn parse_scss_parenthesized_expression(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['(']);
+    BoundedComponentValueList::new(token_set![T![')'], T![;], T!['}']]).parse_list(p);
+    p.expect(T![')']);
+    Present(m.complete(p, SCSS_PARENTHESIZED_EXPRESSION))
+}
+
+/// Checks if the current token is the start of a map, like
+/// `(small: 576px, medium: 768px)`.
+///
+/// The keys of maps are usually simple values, so it's enough to look for
+/// the colon following the first key.
+#[inline]
+fn is_at_scss_map_expression(p: &mut CssParser) -> bool {
+    p.at(T!['('])
+        && (is_nth_at_identifier(p, 1)
+            || p.nth_at(1, CSS_STRING_LITERAL)
+            || p.nth_at(1, CSS_NUMBER_LITERAL))
+        && p.nth_at(2, T![:])
+}
+
+#[inline]
+fn parse_scss_map_expression(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_map_expression(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['(']);
+    ScssMapExpressionPairList.parse_list(p);
+    p.expect(T![')']);
+    Present(m.complete(p, SCSS_MAP_EXPRESSION))
+}
+
+const SCSS_MAP_EXPRESSION_PAIR_END_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![,], T![')'], T![;], T!['}']];
+
+struct ScssMapExpressionPairList;
+
+impl ParseSeparatedList for ScssMapExpressionPairList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_MAP_EXPRESSION_PAIR_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_map_expression_pair(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![')'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, SCSS_MAP_EXPRESSION_PAIR_END_SET),
+            expected_scss_map_entry,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+#[inline]
+fn parse_scss_map_expression_pair(p: &mut CssParser) -> ParsedSyntax {
+    if p.at(T![')']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    parse_any_value(p).or_add_diagnostic(p, expected_identifier);
+    p.expect(T![:]);
+    BoundedComponentValueList::new(SCSS_MAP_EXPRESSION_PAIR_END_SET).parse_non_empty_list(p);
+    Present(m.complete(p, SCSS_MAP_EXPRESSION_PAIR))
+}
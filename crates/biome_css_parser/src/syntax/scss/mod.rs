@@ -0,0 +1,30 @@
+//! Parsing of the [SCSS syntax](https://sass-lang.com/documentation/syntax/#scss)
+//! of Sass.
+//!
+//! SCSS is a superset of CSS, so these functions extend the regular CSS parser
+//! at the points where SCSS adds new syntax. They should only be used when
+//! [crate::CssParserOptions::is_scss_enabled] returns `true`; the tokens SCSS
+//! relies on, such as `$` or the SCSS keywords, aren't produced by the lexer
+//! otherwise.
+
+mod at_rule;
+mod declaration;
+mod expression;
+mod parse_error;
+mod selector;
+
+pub(crate) use at_rule::{
+    parse_scss_content_at_rule, parse_scss_each_at_rule, parse_scss_extend_at_rule,
+    parse_scss_for_at_rule, parse_scss_forward_at_rule, parse_scss_function_at_rule,
+    parse_scss_if_at_rule, parse_scss_include_at_rule, parse_scss_mixin_at_rule,
+    parse_scss_return_at_rule, parse_scss_use_at_rule, parse_scss_while_at_rule,
+};
+pub(crate) use declaration::{is_at_scss_declaration, parse_scss_declaration};
+pub(crate) use expression::{
+    is_at_scss_component_value, is_at_scss_interpolated_property_name, is_at_scss_value,
+    parse_scss_component_value, parse_scss_interpolated_identifier, parse_scss_value,
+};
+pub(crate) use selector::{
+    is_at_scss_selector_interpolation, is_nth_at_scss_placeholder_selector,
+    parse_scss_placeholder_selector, parse_scss_selector_interpolation,
+};
@@ -0,0 +1,36 @@
+use crate::parser::CssParser;
+use biome_parser::diagnostic::{expected_any, expected_node};
+use biome_parser::prelude::ParseDiagnostic;
+use biome_rowan::TextRange;
+
+pub(crate) fn expected_scss_variable(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("variable", range, p)
+}
+
+pub(crate) fn expected_scss_variable_modifier(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["!default", "!global"], range, p)
+}
+
+pub(crate) fn expected_scss_map_entry(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("map entry", range, p)
+}
+
+pub(crate) fn expected_scss_parameter(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("parameter", range, p)
+}
+
+pub(crate) fn expected_scss_argument(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("argument", range, p)
+}
+
+pub(crate) fn expected_scss_keyword_argument(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("keyword argument", range, p)
+}
+
+pub(crate) fn expected_scss_forward_member(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["identifier", "variable"], range, p)
+}
+
+pub(crate) fn expected_scss_for_range(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["through", "to"], range, p)
+}
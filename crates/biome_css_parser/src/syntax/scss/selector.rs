@@ -0,0 +1,52 @@
+use crate::parser::CssParser;
+use crate::syntax::is_nth_at_identifier;
+use crate::syntax::parse_error::expected_identifier;
+use crate::syntax::scss::expression::{
+    is_at_scss_interpolation, parse_scss_interpolation_with_close_context,
+};
+use crate::syntax::selector::{parse_selector_custom_identifier, selector_lex_context};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::T;
+use biome_parser::Parser;
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+
+/// Checks if the nth token is the start of a placeholder selector, like
+/// `%message-shared`.
+#[inline]
+pub(crate) fn is_nth_at_scss_placeholder_selector(p: &mut CssParser, n: usize) -> bool {
+    p.options().is_scss_enabled() && p.nth_at(n, T![%]) && is_nth_at_identifier(p, n + 1)
+}
+
+/// Parses a placeholder selector, like `%message-shared`, which is only
+/// included in the output when extended with `@extend`.
+#[inline]
+pub(crate) fn parse_scss_placeholder_selector(p: &mut CssParser) -> ParsedSyntax {
+    if !is_nth_at_scss_placeholder_selector(p, 0) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T![%]);
+    parse_selector_custom_identifier(p).or_add_diagnostic(p, expected_identifier);
+
+    Present(m.complete(p, SCSS_PLACEHOLDER_SELECTOR))
+}
+
+/// Checks if the current token is the start of an interpolation used as
+/// a sub-selector, like `.icon-#{$name}`.
+#[inline]
+pub(crate) fn is_at_scss_selector_interpolation(p: &mut CssParser) -> bool {
+    is_at_scss_interpolation(p)
+}
+
+/// Parses an interpolation used as a sub-selector. Whitespace following it is
+/// lexed as a descendant combinator, like after any other selector.
+#[inline]
+pub(crate) fn parse_scss_selector_interpolation(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_selector_interpolation(p) {
+        return Absent;
+    }
+
+    parse_scss_interpolation_with_close_context(p, selector_lex_context)
+}
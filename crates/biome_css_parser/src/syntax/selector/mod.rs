@@ -9,6 +9,10 @@ use crate::parser::CssParser;
 use crate::syntax::parse_error::{
     expected_any_sub_selector, expected_compound_selector, expected_identifier, expected_selector,
 };
+use crate::syntax::scss::{
+    is_at_scss_selector_interpolation, is_nth_at_scss_placeholder_selector,
+    parse_scss_placeholder_selector, parse_scss_selector_interpolation,
+};
 use crate::syntax::selector::attribute::parse_attribute_selector;
 use crate::syntax::selector::nested_selector::NestedSelectorList;
 use crate::syntax::selector::pseudo_class::parse_pseudo_class_selector;
@@ -37,9 +41,9 @@ use super::{is_nth_at_metavariable, parse_metavariable};
 /// context. The distinction is important for handling whitespaces, especially
 /// around combinators in CSS selectors.
 const SELECTOR_LEX_SET: TokenSet<CssSyntaxKind> =
-    COMPLEX_SELECTOR_COMBINATOR_SET.union(token_set![T!['{'], T![,], T![')']]);
+    COMPLEX_SELECTOR_COMBINATOR_SET.union(token_set![T!['{'], T![,], T![')'], T![;], T![!]]);
 #[inline]
-fn selector_lex_context(p: &mut CssParser) -> CssLexContext {
+pub(crate) fn selector_lex_context(p: &mut CssParser) -> CssLexContext {
     // It's an inverted logic for `is_nth_at_selector(p, 1)`.
     if p.nth_at_ts(1, SELECTOR_LEX_SET) {
         CssLexContext::Regular
@@ -277,6 +281,7 @@ fn is_nth_at_compound_selector(p: &mut CssParser, n: usize) -> bool {
     p.nth_at(n, T![&])
         || is_nth_at_simple_selector(p, n)
         || p.nth_at_ts(n, SubSelectorList::START_SET)
+        || is_nth_at_scss_placeholder_selector(p, n)
 }
 
 /// Parses a compound selector in CSS.
@@ -410,7 +415,7 @@ impl ParseNodeList for SubSelectorList {
     }
 
     fn is_at_list_end(&self, p: &mut CssParser) -> bool {
-        !p.at_ts(Self::START_SET)
+        !(p.at_ts(Self::START_SET) || is_nth_at_scss_placeholder_selector(p, 0))
     }
 
     fn recover(&mut self, p: &mut CssParser, parsed_element: ParsedSyntax) -> RecoveryResult {
@@ -431,7 +436,9 @@ impl ParseNodeList for SubSelectorList {
 fn parse_sub_selector(p: &mut CssParser) -> ParsedSyntax {
     match p.cur() {
         T![.] => parse_class_selector(p),
+        T![#] if is_at_scss_selector_interpolation(p) => parse_scss_selector_interpolation(p),
         T![#] => parse_id_selector(p),
+        T![%] => parse_scss_placeholder_selector(p),
         T!['['] => parse_attribute_selector(p),
         T![:] => parse_pseudo_class_selector(p),
         T![::] => parse_pseudo_element_selector(p),
@@ -528,7 +535,7 @@ fn parse_selector_identifier(p: &mut CssParser) -> ParsedSyntax {
 /// case-sensitive. These are distinguished from regular identifiers in
 /// selectors that are case-insensitive for safety in preserving the casing.
 #[inline]
-fn parse_selector_custom_identifier(p: &mut CssParser) -> ParsedSyntax {
+pub(crate) fn parse_selector_custom_identifier(p: &mut CssParser) -> ParsedSyntax {
     let context = selector_lex_context(p);
     // Class and ID selectors are technically `<ident>` _and_ case-sensitive.
     // To handle this, we use `<custom-ident>` instead, but also have to allow
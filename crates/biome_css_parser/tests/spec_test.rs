@@ -46,6 +46,15 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
         // TODO: find a way to make it configurable
         .allow_metavariables();
 
+    match test_case_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+    {
+        Some("scss") => options = options.allow_scss(),
+        Some("less") => options = options.allow_less(),
+        _ => {}
+    }
+
     let options_path = Utf8Path::new(test_directory).join("options.json");
 
     if options_path.exists() {
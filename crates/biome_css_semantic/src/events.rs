@@ -125,6 +125,10 @@ impl SemanticEventExtractor {
                                 AnyCssDeclarationName::CssIdentifier(name) => {
                                     CssProperty::from(name)
                                 }
+                                // The name of an interpolated property isn't
+                                // known until it's evaluated.
+                                AnyCssDeclarationName::ScssInterpolatedIdentifier(_)
+                                | AnyCssDeclarationName::ScssInterpolation(_) => return,
                             };
 
                             self.stash.push_back(SemanticEvent::PropertyDeclaration {
@@ -124,6 +124,11 @@ fn evaluate_any_subselector(selector: &AnyCssSubSelector) -> Specificity {
         AnyCssSubSelector::CssPseudoClassSelector(s) => evaluate_pseudo_selector(s),
         AnyCssSubSelector::CssPseudoElementSelector(_) => TYPE_SPECIFICITY,
         AnyCssSubSelector::CssBogusSubSelector(_) => ZERO_SPECIFICITY,
+        // Placeholders are replaced by the selectors extending them, so
+        // they count as much as a class does.
+        AnyCssSubSelector::ScssPlaceholderSelector(_) => CLASS_SPECIFICITY,
+        // The specificity of an interpolation isn't known until it's evaluated.
+        AnyCssSubSelector::ScssInterpolation(_) => ZERO_SPECIFICITY,
     }
 }
 
@@ -13,8 +13,8 @@ pub struct CssFileSource {
 
 /// The style of CSS contained in the file.
 ///
-/// Currently, Biome only supports plain CSS, and aims to be compatible with
-/// the latest Recommendation level standards.
+/// Biome aims to be compatible with the latest Recommendation level standards
+/// for plain CSS, and additionally supports the SCSS syntax of Sass and Less.
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
@@ -23,6 +23,10 @@ pub struct CssFileSource {
 enum CssVariant {
     #[default]
     Standard,
+    /// The SCSS syntax of [Sass](https://sass-lang.com/documentation/syntax/#scss).
+    Scss,
+    /// [Less](https://lesscss.org/features/).
+    Less,
 }
 
 impl CssFileSource {
@@ -32,6 +36,28 @@ impl CssFileSource {
         }
     }
 
+    pub fn scss() -> Self {
+        Self {
+            variant: CssVariant::Scss,
+        }
+    }
+
+    pub fn less() -> Self {
+        Self {
+            variant: CssVariant::Less,
+        }
+    }
+
+    /// Returns `true` if the file source is SCSS.
+    pub const fn is_scss(&self) -> bool {
+        matches!(self.variant, CssVariant::Scss)
+    }
+
+    /// Returns `true` if the file source is Less.
+    pub const fn is_less(&self) -> bool {
+        matches!(self.variant, CssVariant::Less)
+    }
+
     /// Try to return the CSS file source corresponding to this file name from well-known files
     pub fn try_from_well_known(_: &Utf8Path) -> Result<Self, FileSourceError> {
         // TODO: to be implemented
@@ -43,6 +69,8 @@ impl CssFileSource {
         // We assume the file extension is normalized to lowercase
         match extension {
             "css" => Ok(Self::css()),
+            "scss" => Ok(Self::scss()),
+            "less" => Ok(Self::less()),
             _ => Err(FileSourceError::UnknownExtension),
         }
     }
@@ -56,6 +84,8 @@ impl CssFileSource {
     pub fn try_from_language_id(language_id: &str) -> Result<Self, FileSourceError> {
         match language_id {
             "css" => Ok(Self::css()),
+            "scss" => Ok(Self::scss()),
+            "less" => Ok(Self::less()),
             _ => Err(FileSourceError::UnknownLanguageId),
         }
     }
@@ -696,6 +696,256 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssViewTransitionAtRule::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::LESS_DETACHED_RULESET_CALL => {
+                    let $pattern = unsafe { $crate::LessDetachedRulesetCall::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_DETACHED_RULESET_DECLARATION => {
+                    let $pattern =
+                        unsafe { $crate::LessDetachedRulesetDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_ESCAPED_VALUE => {
+                    let $pattern = unsafe { $crate::LessEscapedValue::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_GUARD_CONDITION => {
+                    let $pattern = unsafe { $crate::LessGuardCondition::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_GUARD_LOGICAL_CONDITION => {
+                    let $pattern =
+                        unsafe { $crate::LessGuardLogicalCondition::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_IDENTIFIER => {
+                    let $pattern = unsafe { $crate::LessIdentifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_IMPORT_AT_RULE => {
+                    let $pattern = unsafe { $crate::LessImportAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_IMPORT_OPTIONS => {
+                    let $pattern = unsafe { $crate::LessImportOptions::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_ARGUMENT => {
+                    let $pattern = unsafe { $crate::LessMixinArgument::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_ARGUMENTS => {
+                    let $pattern = unsafe { $crate::LessMixinArguments::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_CALL => {
+                    let $pattern = unsafe { $crate::LessMixinCall::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_DECLARATION => {
+                    let $pattern = unsafe { $crate::LessMixinDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_GUARD => {
+                    let $pattern = unsafe { $crate::LessMixinGuard::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_NAMED_ARGUMENT => {
+                    let $pattern = unsafe { $crate::LessMixinNamedArgument::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_PARAMETER => {
+                    let $pattern = unsafe { $crate::LessMixinParameter::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_PARAMETER_DEFAULT_VALUE => {
+                    let $pattern =
+                        unsafe { $crate::LessMixinParameterDefaultValue::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_PARAMETERS => {
+                    let $pattern = unsafe { $crate::LessMixinParameters::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_PATH_SEGMENT => {
+                    let $pattern = unsafe { $crate::LessMixinPathSegment::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_PATTERN_PARAMETER => {
+                    let $pattern =
+                        unsafe { $crate::LessMixinPatternParameter::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_REST_PARAMETER => {
+                    let $pattern = unsafe { $crate::LessMixinRestParameter::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_OPERATOR => {
+                    let $pattern = unsafe { $crate::LessOperator::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_PARENTHESIZED_EXPRESSION => {
+                    let $pattern =
+                        unsafe { $crate::LessParenthesizedExpression::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_VARIABLE_DECLARATION => {
+                    let $pattern = unsafe { $crate::LessVariableDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_ARGUMENT => {
+                    let $pattern = unsafe { $crate::ScssArgument::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_ARGUMENTS => {
+                    let $pattern = unsafe { $crate::ScssArguments::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_CONTENT_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssContentAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_DECLARATION => {
+                    let $pattern = unsafe { $crate::ScssDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_EACH_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssEachAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_ELSE_CLAUSE => {
+                    let $pattern = unsafe { $crate::ScssElseClause::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_EXTEND_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssExtendAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_EXTEND_OPTIONAL_MODIFIER => {
+                    let $pattern =
+                        unsafe { $crate::ScssExtendOptionalModifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_FOR_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssForAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_FORWARD_AS_CLAUSE => {
+                    let $pattern = unsafe { $crate::ScssForwardAsClause::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_FORWARD_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssForwardAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_FORWARD_VISIBILITY_CLAUSE => {
+                    let $pattern =
+                        unsafe { $crate::ScssForwardVisibilityClause::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_FUNCTION_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssFunctionAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_IDENTIFIER => {
+                    let $pattern = unsafe { $crate::ScssIdentifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_IF_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssIfAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_INCLUDE_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssIncludeAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_INTERPOLATED_IDENTIFIER => {
+                    let $pattern =
+                        unsafe { $crate::ScssInterpolatedIdentifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_INTERPOLATION => {
+                    let $pattern = unsafe { $crate::ScssInterpolation::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_KEYWORD_ARGUMENT => {
+                    let $pattern = unsafe { $crate::ScssKeywordArgument::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MAP_EXPRESSION => {
+                    let $pattern = unsafe { $crate::ScssMapExpression::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MAP_EXPRESSION_PAIR => {
+                    let $pattern = unsafe { $crate::ScssMapExpressionPair::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MIXIN_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssMixinAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MODULE_CONFIGURATION => {
+                    let $pattern = unsafe { $crate::ScssModuleConfiguration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_NAMESPACED_NAME => {
+                    let $pattern = unsafe { $crate::ScssNamespacedName::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_OPERATOR => {
+                    let $pattern = unsafe { $crate::ScssOperator::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETER => {
+                    let $pattern = unsafe { $crate::ScssParameter::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETER_DEFAULT_VALUE => {
+                    let $pattern =
+                        unsafe { $crate::ScssParameterDefaultValue::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETERS => {
+                    let $pattern = unsafe { $crate::ScssParameters::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARENTHESIZED_EXPRESSION => {
+                    let $pattern =
+                        unsafe { $crate::ScssParenthesizedExpression::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PLACEHOLDER_SELECTOR => {
+                    let $pattern = unsafe { $crate::ScssPlaceholderSelector::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_QUALIFIED_NAME => {
+                    let $pattern = unsafe { $crate::ScssQualifiedName::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_RETURN_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssReturnAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_USE_ALL_NAMESPACE => {
+                    let $pattern = unsafe { $crate::ScssUseAllNamespace::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_USE_AS_CLAUSE => {
+                    let $pattern = unsafe { $crate::ScssUseAsClause::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_USE_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssUseAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_VARIABLE_MODIFIER => {
+                    let $pattern = unsafe { $crate::ScssVariableModifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_WHILE_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssWhileAtRule::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_BOGUS => {
                     let $pattern = unsafe { $crate::CssBogus::new_unchecked(node) };
                     $body
@@ -936,6 +1186,56 @@ macro_rules! map_syntax_node {
                         unsafe { $crate::CssValueAtRulePropertyList::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::LESS_IMPORT_OPTION_LIST => {
+                    let $pattern = unsafe { $crate::LessImportOptionList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_ARGUMENT_LIST => {
+                    let $pattern = unsafe { $crate::LessMixinArgumentList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_PARAMETER_LIST => {
+                    let $pattern = unsafe { $crate::LessMixinParameterList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_PATH_SEGMENT_LIST => {
+                    let $pattern = unsafe { $crate::LessMixinPathSegmentList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_ARGUMENT_LIST => {
+                    let $pattern = unsafe { $crate::ScssArgumentList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_EACH_BINDING_LIST => {
+                    let $pattern = unsafe { $crate::ScssEachBindingList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_FORWARD_MEMBER_LIST => {
+                    let $pattern = unsafe { $crate::ScssForwardMemberList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_INTERPOLATED_IDENTIFIER_PART_LIST => {
+                    let $pattern =
+                        unsafe { $crate::ScssInterpolatedIdentifierPartList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_KEYWORD_ARGUMENT_LIST => {
+                    let $pattern = unsafe { $crate::ScssKeywordArgumentList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_MAP_EXPRESSION_PAIR_LIST => {
+                    let $pattern =
+                        unsafe { $crate::ScssMapExpressionPairList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_PARAMETER_LIST => {
+                    let $pattern = unsafe { $crate::ScssParameterList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_VARIABLE_MODIFIER_LIST => {
+                    let $pattern = unsafe { $crate::ScssVariableModifierList::new_unchecked(node) };
+                    $body
+                }
                 _ => unreachable!(),
             },
         }
@@ -96,7 +96,7 @@ impl DeserializableValue for AnyJsonValue {
                 let items = array.elements().iter().map(|x| x.ok());
                 visitor.visit_array(ctx, items, range, name)
             }
-            Self::JsonBogusValue(_) => {
+            Self::JsonBogusValue(_) | Self::JsonMetavariable(_) => {
                 // The parser should emit an error about this node
                 // No need to emit another diagnostic.
                 None
@@ -128,7 +128,7 @@ impl DeserializableValue for AnyJsonValue {
     fn visitable_type(&self) -> Option<DeserializableType> {
         match self {
             Self::JsonArrayValue(_) => Some(DeserializableType::Array),
-            Self::JsonBogusValue(_) => None,
+            Self::JsonBogusValue(_) | Self::JsonMetavariable(_) => None,
             Self::JsonBooleanValue(_) => Some(DeserializableType::Bool),
             Self::JsonNullValue(_) => Some(DeserializableType::Null),
             Self::JsonNumberValue(_) => Some(DeserializableType::Number),
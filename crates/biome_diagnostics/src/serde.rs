@@ -36,6 +36,11 @@ impl Diagnostic {
         Self::new_impl(diag.as_diagnostic())
     }
 
+    /// Creates a serializable copy of a diagnostic, without taking ownership of it.
+    pub fn from_diagnostic<D: AsDiagnostic + ?Sized>(diag: &D) -> Self {
+        Self::new_impl(diag.as_diagnostic())
+    }
+
     fn new_impl<D: super::Diagnostic + ?Sized>(diag: &D) -> Self {
         let category = diag.category();
 
@@ -75,6 +80,19 @@ impl Diagnostic {
         }
     }
 
+    /// Maps the range of the diagnostic, and the ranges of its code frames, with `map`.
+    ///
+    /// The code frames whose range can't be mapped are removed. Returns `None` if the
+    /// range of the diagnostic can't be mapped.
+    pub fn map_spans(mut self, map: impl Fn(TextRange) -> Option<TextRange>) -> Option<Self> {
+        if let Some(span) = self.location.span {
+            self.location.span = Some(map(span)?);
+        }
+        self.advices.map_spans(&map);
+        self.verbose_advices.map_spans(&map);
+        Some(self)
+    }
+
     pub fn with_offset(mut self, offset: TextSize) -> Self {
         self.location.span = self
             .location
@@ -175,6 +193,20 @@ impl Advices {
             advices: Vec::new(),
         }
     }
+
+    fn map_spans(&mut self, map: &impl Fn(TextRange) -> Option<TextRange>) {
+        self.advices.retain_mut(|advice| match advice {
+            Advice::Frame(location) => match location.span {
+                Some(span) => map(span).map(|span| location.span = Some(span)).is_some(),
+                None => true,
+            },
+            Advice::Group(_, advices) => {
+                advices.map_spans(map);
+                true
+            }
+            _ => true,
+        });
+    }
 }
 
 impl Visit for Advices {
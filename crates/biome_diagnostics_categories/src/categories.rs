@@ -161,9 +161,12 @@ define_categories! {
     "lint/nursery/noImportCycles": "https://biomejs.dev/linter/rules/no-import-cycles",
     "lint/nursery/noImportantInKeyframe": "https://biomejs.dev/linter/rules/no-important-in-keyframe",
     "lint/nursery/noImportantStyles": "https://biomejs.dev/linter/rules/no-important-styles",
+    "lint/nursery/noInvalidArguments": "https://biomejs.dev/linter/rules/no-invalid-arguments",
     "lint/nursery/noInvalidDirectionInLinearGradient": "https://biomejs.dev/linter/rules/no-invalid-direction-in-linear-gradient",
+    "lint/nursery/noInvalidFragmentTypes": "https://biomejs.dev/linter/rules/no-invalid-fragment-types",
     "lint/nursery/noInvalidGridAreas": "https://biomejs.dev/linter/rules/use-consistent-grid-areas",
     "lint/nursery/noInvalidPositionAtImportRule": "https://biomejs.dev/linter/rules/no-invalid-position-at-import-rule",
+    "lint/nursery/noJsonSchemaViolations": "https://biomejs.dev/linter/rules/no-json-schema-violations",
     "lint/nursery/noMagicNumbers": "https://biomejs.dev/linter/rules/no-magic-numbers",
     "lint/nursery/noMissingGenericFamilyKeyword": "https://biomejs.dev/linter/rules/no-missing-generic-family-keyword",
     "lint/nursery/noNestedComponentDefinitions": "https://biomejs.dev/linter/rules/no-nested-component-definitions",
@@ -177,16 +180,22 @@ define_categories! {
     "lint/nursery/noShorthandPropertyOverrides": "https://biomejs.dev/linter/rules/no-shorthand-property-overrides",
     "lint/nursery/noTsIgnore": "https://biomejs.dev/linter/rules/no-ts-ignore",
     "lint/nursery/noUnassignedVariables": "https://biomejs.dev/linter/rules/no-unassigned-variables",
+    "lint/nursery/noUndefinedVariables": "https://biomejs.dev/linter/rules/no-undefined-variables",
     "lint/nursery/noUndeclaredDependencies": "https://biomejs.dev/linter/rules/no-undeclared-dependencies",
     "lint/nursery/noUnknownAtRule": "https://biomejs.dev/linter/rules/no-unknown-at-rule",
+    "lint/nursery/noUnknownDirectives": "https://biomejs.dev/linter/rules/no-unknown-directives",
+    "lint/nursery/noUnknownFields": "https://biomejs.dev/linter/rules/no-unknown-fields",
     "lint/nursery/noUnknownFunction": "https://biomejs.dev/linter/rules/no-unknown-function",
     "lint/nursery/noUnknownMediaFeatureName": "https://biomejs.dev/linter/rules/no-unknown-media-feature-name",
     "lint/nursery/noUnknownProperty": "https://biomejs.dev/linter/rules/no-unknown-property",
     "lint/nursery/noUnknownSelectorPseudoElement": "https://biomejs.dev/linter/rules/no-unknown-selector-pseudo-element",
+    "lint/nursery/noUnknownTypes": "https://biomejs.dev/linter/rules/no-unknown-types",
     "lint/nursery/noUnknownUnit": "https://biomejs.dev/linter/rules/no-unknown-unit",
     "lint/nursery/noUnmatchableAnbSelector": "https://biomejs.dev/linter/rules/no-unmatchable-anb-selector",
     "lint/nursery/noUnresolvedImports": "https://biomejs.dev/linter/rules/no-unresolved-imports",
+    "lint/nursery/noUnusedExports": "https://biomejs.dev/linter/rules/no-unused-exports",
     "lint/nursery/noUnusedFunctionParameters": "https://biomejs.dev/linter/rules/no-unused-function-parameters",
+    "lint/nursery/noUnusedVariableDefinitions": "https://biomejs.dev/linter/rules/no-unused-variable-definitions",
     "lint/nursery/noUnwantedPolyfillio": "https://biomejs.dev/linter/rules/no-unwanted-polyfillio",
     "lint/nursery/noUselessBackrefInRegex": "https://biomejs.dev/linter/rules/no-useless-backref-in-regex",
     "lint/nursery/noUselessEscapeInString": "https://biomejs.dev/linter/rules/no-useless-escape-in-string",
@@ -408,6 +417,8 @@ define_categories! {
     "reporter/parse",
     "reporter/format",
     "reporter/violations",
+    "reporter/unreachableModules",
+    "reporter/unusedExports",
     // parse categories
     "parse",
 
@@ -430,6 +441,7 @@ define_categories! {
     "suppressions/unknownAction",
     "suppressions/unused",
     "suppressions/incorrect",
+    "suppressions/unsupported",
     // Used in tests and examples
     "args/fileNotFound",
     "flags/invalid",
@@ -2,6 +2,7 @@ use crate::{BiomePath, PathInterner};
 use biome_diagnostics::{Advices, Diagnostic, IoError, LogCategory, Visit, console};
 use biome_diagnostics::{Error, Severity};
 use camino::{Utf8Path, Utf8PathBuf};
+pub use diff::ChangedFile;
 pub use memory::{ErrorEntry, MemoryFileSystem};
 pub use os::{OsFileSystem, TemporaryFs};
 use serde::{Deserialize, Serialize};
@@ -13,6 +14,7 @@ use std::sync::Arc;
 use std::{fmt, io};
 use tracing::{error, info};
 
+mod diff;
 mod memory;
 mod os;
 
@@ -223,9 +225,27 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// Returns the resolution of a symbolic link.
     fn read_link(&self, path: &Utf8Path) -> io::Result<Utf8PathBuf>;
 
+    /// Returns the files of the git repository changed compared to `base`.
+    ///
+    /// This is only meant for the `git` VCS client.
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>>;
 
+    /// Returns the files staged in the git repository.
+    ///
+    /// This is only meant for the `git` VCS client.
     fn get_staged_files(&self) -> io::Result<Vec<String>>;
+
+    /// Returns the files of the git repository changed compared to `base`, with the lines
+    /// that were added or modified in each of them.
+    ///
+    /// This is only meant for the `git` VCS client.
+    fn get_changed_lines(&self, base: &str) -> io::Result<Vec<ChangedFile>>;
+
+    /// Returns the files staged in the git repository, with the lines that were added or
+    /// modified in each of them.
+    ///
+    /// This is only meant for the `git` VCS client.
+    fn get_staged_lines(&self) -> io::Result<Vec<ChangedFile>>;
 }
 
 /// Result of the auto search
@@ -432,6 +452,14 @@ where
         T::get_staged_files(self)
     }
 
+    fn get_changed_lines(&self, base: &str) -> io::Result<Vec<ChangedFile>> {
+        T::get_changed_lines(self, base)
+    }
+
+    fn get_staged_lines(&self) -> io::Result<Vec<ChangedFile>> {
+        T::get_staged_lines(self)
+    }
+
     fn read_link(&self, path: &Utf8Path) -> io::Result<Utf8PathBuf> {
         T::read_link(self, path)
     }
@@ -0,0 +1,39 @@
+//! The files changed according to the VCS, with the lines that changed in them.
+
+use std::ops::RangeInclusive;
+
+/// A file that was added or modified, with the lines that changed in it.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ChangedFile {
+    /// The path of the file, relative to the working directory
+    pub path: String,
+    /// The lines that were added or modified, 1-based and sorted
+    pub lines: Vec<RangeInclusive<u32>>,
+}
+
+impl ChangedFile {
+    /// Returns `true` if one of the lines in `range`, 1-based and inclusive, changed.
+    pub fn intersects(&self, range: RangeInclusive<u32>) -> bool {
+        self.lines
+            .iter()
+            .any(|lines| lines.start() <= range.end() && range.start() <= lines.end())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intersects_changed_lines() {
+        let file = ChangedFile {
+            path: "main.js".to_string(),
+            lines: vec![2..=2, 11..=13],
+        };
+
+        assert!(file.intersects(1..=2));
+        assert!(file.intersects(13..=20));
+        assert!(!file.intersects(3..=10));
+        assert!(!file.intersects(14..=14));
+    }
+}
@@ -12,7 +12,7 @@ use std::sync::Arc;
 use crate::fs::OpenOptions;
 use crate::{BiomePath, FileSystem, TraversalContext, TraversalScope};
 
-use super::{BoxedTraversal, File, FileSystemDiagnostic, FsErrorKind, PathKind};
+use super::{BoxedTraversal, ChangedFile, File, FileSystemDiagnostic, FsErrorKind, PathKind};
 
 type OnGetChangedFiles = Option<
     Arc<
@@ -22,6 +22,14 @@ type OnGetChangedFiles = Option<
     >,
 >;
 
+type OnGetChangedLines = Option<
+    Arc<
+        AssertUnwindSafe<
+            Mutex<Option<Box<dyn FnOnce() -> Vec<ChangedFile> + Send + 'static + RefUnwindSafe>>>,
+        >,
+    >,
+>;
+
 type Files = Arc<RwLock<FxHashMap<Utf8PathBuf, FileEntry>>>;
 
 /// Fully in-memory file system, stores the content of all known files in a hashmap
@@ -31,6 +39,8 @@ pub struct MemoryFileSystem {
     allow_write: bool,
     on_get_staged_files: OnGetChangedFiles,
     on_get_changed_files: OnGetChangedFiles,
+    on_get_staged_lines: OnGetChangedLines,
+    on_get_changed_lines: OnGetChangedLines,
 }
 
 impl Default for MemoryFileSystem {
@@ -45,6 +55,12 @@ impl Default for MemoryFileSystem {
             on_get_changed_files: Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(Box::new(
                 Vec::new,
             )))))),
+            on_get_staged_lines: Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(Box::new(
+                Vec::new,
+            )))))),
+            on_get_changed_lines: Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(Box::new(
+                Vec::new,
+            )))))),
         }
     }
 }
@@ -125,6 +141,20 @@ impl MemoryFileSystem {
     ) {
         self.on_get_staged_files = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
     }
+
+    pub fn set_on_get_changed_lines(
+        &mut self,
+        cfn: Box<dyn FnOnce() -> Vec<ChangedFile> + Send + RefUnwindSafe + 'static>,
+    ) {
+        self.on_get_changed_lines = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
+    }
+
+    pub fn set_on_get_staged_lines(
+        &mut self,
+        cfn: Box<dyn FnOnce() -> Vec<ChangedFile> + Send + RefUnwindSafe + 'static>,
+    ) {
+        self.on_get_staged_lines = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
+    }
 }
 
 impl FileSystem for MemoryFileSystem {
@@ -244,6 +274,26 @@ impl FileSystem for MemoryFileSystem {
         Ok(cb())
     }
 
+    fn get_changed_lines(&self, _base: &str) -> io::Result<Vec<ChangedFile>> {
+        let cb_arc = self.on_get_changed_lines.as_ref().unwrap().clone();
+
+        let mut cb_guard = cb_arc.lock();
+
+        let cb = cb_guard.take().unwrap();
+
+        Ok(cb())
+    }
+
+    fn get_staged_lines(&self) -> io::Result<Vec<ChangedFile>> {
+        let cb_arc = self.on_get_staged_lines.as_ref().unwrap().clone();
+
+        let mut cb_guard = cb_arc.lock();
+
+        let cb = cb_guard.take().unwrap();
+
+        Ok(cb())
+    }
+
     fn read_link(&self, _path: &Utf8Path) -> io::Result<Utf8PathBuf> {
         Err(io::Error::new(
             io::ErrorKind::Unsupported,
@@ -2,7 +2,6 @@
 
 use std::env::temp_dir;
 use std::fs::{FileType, Metadata};
-use std::process::Command;
 use std::{
     env, fs,
     io::{self, Read, Seek, Write},
@@ -10,6 +9,7 @@ use std::{
 };
 
 use biome_diagnostics::{DiagnosticExt, Error, IoError, Severity};
+use biome_git::{FileChange, Repository};
 use camino::{Utf8DirEntry, Utf8Path, Utf8PathBuf};
 use path_absolutize::Absolutize;
 use rayon::{Scope, scope};
@@ -22,7 +22,7 @@ use crate::{
     fs::{TraversalContext, TraversalScope},
 };
 
-use super::{BoxedTraversal, File, FileSystemDiagnostic, FsErrorKind, PathKind};
+use super::{BoxedTraversal, ChangedFile, File, FileSystemDiagnostic, FsErrorKind, PathKind};
 
 /// Implementation of [FileSystem] that directly calls through to the underlying OS
 pub struct OsFileSystem {
@@ -98,44 +98,53 @@ impl FileSystem for OsFileSystem {
     }
 
     fn get_changed_files(&self, base: &str) -> io::Result<Vec<String>> {
-        let output = Command::new("git")
-            .arg("diff")
-            .arg("--name-only")
-            .arg("--relative")
-            // A: added
-            // C: copied
-            // M: modified
-            // R: renamed
-            // Source: https://git-scm.com/docs/git-diff#Documentation/git-diff.txt---diff-filterACDMRTUXB82308203
-            .arg("--diff-filter=ACMR")
-            .arg(format!("{base}...HEAD"))
-            .output()?;
-
-        Ok(String::from_utf8_lossy(&output.stdout)
-            .lines()
-            .map(|l| l.to_string())
-            .collect())
+        let changes = self.open_repository()?.changed_files(base)?;
+        Ok(changes.into_iter().map(|change| change.path).collect())
     }
 
     fn get_staged_files(&self) -> io::Result<Vec<String>> {
-        let output = Command::new("git")
-            .arg("diff")
-            .arg("--name-only")
-            .arg("--relative")
-            .arg("--staged")
-            // A: added
-            // C: copied
-            // M: modified
-            // R: renamed
-            // Source: https://git-scm.com/docs/git-diff#Documentation/git-diff.txt---diff-filterACDMRTUXB82308203
-            .arg("--diff-filter=ACMR")
-            .output()?;
-
-        Ok(String::from_utf8_lossy(&output.stdout)
-            .lines()
-            .map(|l| l.to_string())
-            .collect())
+        let changes = self.open_repository()?.staged_files()?;
+        Ok(changes.into_iter().map(|change| change.path).collect())
     }
+
+    fn get_changed_lines(&self, base: &str) -> io::Result<Vec<ChangedFile>> {
+        let repository = self.open_repository()?;
+        let changes = repository.changed_files(base)?;
+        collect_changed_lines(&repository, changes)
+    }
+
+    fn get_staged_lines(&self) -> io::Result<Vec<ChangedFile>> {
+        let repository = self.open_repository()?;
+        let changes = repository.staged_files()?;
+        collect_changed_lines(&repository, changes)
+    }
+}
+
+impl OsFileSystem {
+    /// Opens the git repository that contains the working directory
+    fn open_repository(&self) -> io::Result<Repository> {
+        let working_directory = match &self.working_directory {
+            Some(working_directory) => working_directory.clone(),
+            None => Utf8PathBuf::from_path_buf(env::current_dir()?)
+                .map_err(|_| io::Error::other("the working directory isn't a UTF-8 path"))?,
+        };
+        Ok(Repository::discover(&working_directory)?)
+    }
+}
+
+fn collect_changed_lines(
+    repository: &Repository,
+    changes: Vec<FileChange>,
+) -> io::Result<Vec<ChangedFile>> {
+    changes
+        .into_iter()
+        .map(|change| {
+            Ok(ChangedFile {
+                lines: repository.changed_lines(&change)?,
+                path: change.path,
+            })
+        })
+        .collect()
 }
 
 #[derive(Debug)]
@@ -6,7 +6,7 @@ mod path;
 mod utils;
 
 pub use fs::{
-    AutoSearchResult, ConfigName, ErrorEntry, File, FileSystem, FileSystemDiagnostic,
+    AutoSearchResult, ChangedFile, ConfigName, ErrorEntry, File, FileSystem, FileSystemDiagnostic,
     FileSystemExt, FsErrorKind, MemoryFileSystem, OpenOptions, OsFileSystem, PathKind, TemporaryFs,
     TraversalContext, TraversalScope,
 };
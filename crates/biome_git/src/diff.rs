@@ -0,0 +1,118 @@
+//! Comparison of the content of files: the lines that changed, and how similar two files are
+//! to detect renames.
+
+use rustc_hash::{FxHashMap, FxHasher};
+use similar::{DiffTag, TextDiff};
+use std::hash::{Hash, Hasher};
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+/// How long a diff may take before falling back to a less precise result
+const DIFF_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Returns the ranges of the lines of `new`, starting at 1, that were added or modified
+/// compared to `old`.
+pub(crate) fn changed_lines(old: Option<&[u8]>, new: &[u8]) -> Vec<RangeInclusive<u32>> {
+    let new = normalize(new);
+    let Some(old) = old else {
+        let count = new.lines().count() as u32;
+        return if count == 0 {
+            Vec::new()
+        } else {
+            vec![1..=count]
+        };
+    };
+    let old = normalize(old);
+
+    let diff = TextDiff::configure()
+        .timeout(DIFF_TIMEOUT)
+        .diff_lines(old.as_str(), new.as_str());
+    let mut lines: Vec<RangeInclusive<u32>> = Vec::new();
+    for operation in diff.ops() {
+        let (tag, _, new_range) = operation.as_tag_tuple();
+        if tag == DiffTag::Equal || new_range.is_empty() {
+            continue;
+        }
+        let start = new_range.start as u32 + 1;
+        let end = new_range.end as u32;
+        match lines.last_mut() {
+            Some(last) if *last.end() + 1 >= start => *last = *last.start()..=end,
+            _ => lines.push(start..=end),
+        }
+    }
+    lines
+}
+
+/// Decodes `content` as UTF-8 with `\n` line endings, so that files checked out with
+/// `\r\n` line endings can be compared with their staged content.
+fn normalize(content: &[u8]) -> String {
+    String::from_utf8_lossy(content).replace("\r\n", "\n")
+}
+
+/// A summary of the content of a file, used to compute how similar two files are
+pub(crate) struct Fingerprint {
+    size: usize,
+    /// The number of bytes of the lines with the same hash
+    lines: FxHashMap<u64, usize>,
+}
+
+impl Fingerprint {
+    pub(crate) fn new(content: &[u8]) -> Self {
+        let mut lines: FxHashMap<u64, usize> = FxHashMap::default();
+        for line in content.split_inclusive(|byte| *byte == b'\n') {
+            let line = line.strip_suffix(b"\r\n").unwrap_or(line);
+            let mut hasher = FxHasher::default();
+            line.hash(&mut hasher);
+            *lines.entry(hasher.finish()).or_default() += line.len();
+        }
+        Self {
+            size: content.len(),
+            lines,
+        }
+    }
+
+    /// Returns the proportion, between 0 and 1, of the content shared by both files
+    pub(crate) fn similarity(&self, other: &Self) -> f64 {
+        let largest = self.size.max(other.size);
+        if largest == 0 {
+            return 1.0;
+        }
+        let (smaller, larger) = if self.lines.len() <= other.lines.len() {
+            (&self.lines, &other.lines)
+        } else {
+            (&other.lines, &self.lines)
+        };
+        let common: usize = smaller
+            .iter()
+            .filter_map(|(hash, size)| Some((*size).min(*larger.get(hash)?)))
+            .sum();
+        common as f64 / largest as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_added_and_modified_lines() {
+        let old = b"a\nb\nc\nd\n";
+        let new = b"a\nB\nc\nd\ne\nf\n";
+        assert_eq!(
+            changed_lines(Some(old), new),
+            vec![2..=2, 5..=6],
+            "removed lines aren't reported"
+        );
+        assert_eq!(changed_lines(None, new), vec![1..=6]);
+        assert_eq!(changed_lines(Some(old), b"a\r\nb\r\nc\r\nd\r\n"), vec![]);
+    }
+
+    #[test]
+    fn computes_similarity() {
+        let old = Fingerprint::new(b"first line\nsecond line\nthird line\n");
+        let renamed = Fingerprint::new(b"first line\nsecond line\nthird line!\n");
+        let unrelated = Fingerprint::new(b"something\nelse\n");
+        assert!(old.similarity(&renamed) > 0.5);
+        assert!(old.similarity(&unrelated) < 0.5);
+    }
+}
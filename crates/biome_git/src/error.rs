@@ -0,0 +1,67 @@
+use camino::Utf8PathBuf;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io;
+
+/// An error that happened while reading a repository
+#[derive(Debug)]
+pub enum GitError {
+    /// The directory isn't inside the working tree of a repository
+    NotARepository(Utf8PathBuf),
+    /// The revision doesn't match any branch, tag or commit of the repository
+    UnknownRevision(String),
+    /// The repository uses a format that isn't supported
+    Unsupported(String),
+    /// The content of the repository is invalid
+    Corrupted(String),
+    /// The repository couldn't be read
+    Io(io::Error),
+}
+
+impl GitError {
+    pub(crate) fn corrupted(reason: impl Into<String>) -> Self {
+        Self::Corrupted(reason.into())
+    }
+
+    pub(crate) fn unsupported(reason: impl Into<String>) -> Self {
+        Self::Unsupported(reason.into())
+    }
+}
+
+impl Display for GitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotARepository(path) => write!(f, "{path} isn't inside a git repository"),
+            Self::UnknownRevision(revision) => {
+                write!(f, "the revision {revision} doesn't exist in the repository")
+            }
+            Self::Unsupported(reason) => write!(f, "the repository isn't supported: {reason}"),
+            Self::Corrupted(reason) => write!(f, "the repository is corrupted: {reason}"),
+            Self::Io(error) => write!(f, "the repository can't be read: {error}"),
+        }
+    }
+}
+
+impl Error for GitError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for GitError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<GitError> for io::Error {
+    fn from(error: GitError) -> Self {
+        match error {
+            GitError::Io(error) => error,
+            error => Self::other(error),
+        }
+    }
+}
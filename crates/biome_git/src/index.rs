@@ -0,0 +1,171 @@
+//! The index, also known as the staging area: the list of the tracked files, with the ID of
+//! their staged content.
+
+use crate::GitError;
+use crate::object::{MODE_SUBMODULE, MODE_TREE, MODE_TYPE_MASK, ObjectId};
+use camino::Utf8Path;
+use std::fs;
+use std::io;
+
+#[derive(Debug)]
+pub(crate) struct IndexEntry {
+    /// The path of the file, relative to the root of the working tree
+    pub(crate) path: String,
+    pub(crate) id: ObjectId,
+    pub(crate) mode: u32,
+    /// The entries with conflicts have a stage between 1 and 3
+    pub(crate) stage: u8,
+}
+
+impl IndexEntry {
+    /// Submodules are stored as commits inside the index
+    pub(crate) const fn is_submodule(&self) -> bool {
+        self.mode & MODE_TYPE_MASK == MODE_SUBMODULE
+    }
+
+    /// Sparse indexes store the directories excluded by a sparse checkout as a single entry
+    pub(crate) const fn is_sparse_directory(&self) -> bool {
+        self.mode & MODE_TYPE_MASK == MODE_TREE
+    }
+}
+
+pub(crate) struct Index {
+    pub(crate) entries: Vec<IndexEntry>,
+}
+
+impl Index {
+    /// Reads the index at `path`. A missing index is empty.
+    pub(crate) fn read(path: &Utf8Path) -> Result<Self, GitError> {
+        let data = match fs::read(path) {
+            Ok(data) => data,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                return Ok(Self {
+                    entries: Vec::new(),
+                });
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        Ok(Self {
+            entries: parse_entries(&data)?,
+        })
+    }
+}
+
+/// Parses the entries of an index of version 2, 3 or 4
+fn parse_entries(data: &[u8]) -> Result<Vec<IndexEntry>, GitError> {
+    let truncated = || GitError::corrupted("the index is truncated");
+    let mut reader = Reader { data, position: 0 };
+
+    if reader.take(4).ok_or_else(truncated)? != b"DIRC" {
+        return Err(GitError::corrupted("the index has an invalid signature"));
+    }
+    let version = reader.u32().ok_or_else(truncated)?;
+    if !(2..=4).contains(&version) {
+        return Err(GitError::unsupported(format!(
+            "the version {version} of the index"
+        )));
+    }
+    let count = reader.u32().ok_or_else(truncated)?;
+
+    let mut entries = Vec::with_capacity(count as usize);
+    let mut previous_path: Vec<u8> = Vec::new();
+    for _ in 0..count {
+        let start = reader.position;
+        // ctime, mtime, dev and ino
+        reader.take(24).ok_or_else(truncated)?;
+        let mode = reader.u32().ok_or_else(truncated)?;
+        // uid, gid and size
+        reader.take(12).ok_or_else(truncated)?;
+        let id = reader
+            .take(20)
+            .and_then(ObjectId::from_bytes)
+            .ok_or_else(truncated)?;
+        let flags = reader.u16().ok_or_else(truncated)?;
+        if version >= 3 && flags & 0x4000 != 0 {
+            // extended flags
+            reader.u16().ok_or_else(truncated)?;
+        }
+
+        let path = if version == 4 {
+            // The path is stored as the number of bytes to remove from the end of the
+            // previous path, followed by the suffix to append.
+            let removed = reader.offset_varint().ok_or_else(truncated)?;
+            let suffix = reader.until_nul().ok_or_else(truncated)?;
+            let kept = previous_path
+                .len()
+                .checked_sub(removed)
+                .ok_or_else(|| GitError::corrupted("the index has an invalid path"))?;
+            previous_path.truncate(kept);
+            previous_path.extend_from_slice(suffix);
+            previous_path.clone()
+        } else {
+            let path = reader.until_nul().ok_or_else(truncated)?.to_vec();
+            // The entries are padded with 1 to 8 NUL bytes, so their length is a multiple
+            // of 8. The NUL terminating the path is part of the padding.
+            let len = reader.position - start;
+            reader.take((8 - len % 8) % 8).ok_or_else(truncated)?;
+            path
+        };
+
+        entries.push(IndexEntry {
+            path: String::from_utf8_lossy(&path).into_owned(),
+            id,
+            mode,
+            stage: ((flags >> 12) & 0b11) as u8,
+        });
+    }
+
+    // Extensions follow the entries, until the final checksum
+    while data.len().saturating_sub(reader.position) > 20 {
+        let signature = reader.take(4).ok_or_else(truncated)?;
+        if signature == b"link" {
+            return Err(GitError::unsupported("split indexes"));
+        }
+        let size = reader.u32().ok_or_else(truncated)?;
+        reader.take(size as usize).ok_or_else(truncated)?;
+    }
+
+    Ok(entries)
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self
+            .data
+            .get(self.position..self.position.checked_add(len)?)?;
+        self.position += len;
+        Some(bytes)
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_be_bytes(self.take(2)?.try_into().ok()?))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_be_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    fn until_nul(&mut self) -> Option<&'a [u8]> {
+        let rest = self.data.get(self.position..)?;
+        let nul = rest.iter().position(|byte| *byte == 0)?;
+        self.position += nul + 1;
+        Some(&rest[..nul])
+    }
+
+    /// Reads a big-endian number in groups of 7 bits, where each continuation adds one
+    fn offset_varint(&mut self) -> Option<usize> {
+        let mut byte = self.take(1)?[0];
+        let mut value = usize::from(byte & 0x7f);
+        while byte & 0x80 != 0 {
+            byte = self.take(1)?[0];
+            value = value.checked_add(1)?.checked_shl(7)? | usize::from(byte & 0x7f);
+        }
+        Some(value)
+    }
+}
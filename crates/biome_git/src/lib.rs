@@ -0,0 +1,17 @@
+//! A minimal, read-only implementation of git, used by the VCS integration when
+//! `vcs.clientKind` is `git`.
+//!
+//! It reads the index, the references and the object database of the repository directly,
+//! so that the `git` binary doesn't need to be installed.
+
+mod diff;
+mod error;
+mod index;
+mod object;
+mod odb;
+mod refs;
+mod repository;
+
+pub use error::GitError;
+pub use object::ObjectId;
+pub use repository::{ChangeKind, FileChange, Repository};
@@ -0,0 +1,209 @@
+use crate::GitError;
+use std::fmt::{self, Display, Formatter};
+
+/// The bits of a mode that contain the type of the entry
+pub(crate) const MODE_TYPE_MASK: u32 = 0o170_000;
+/// The type of the entries that are directories
+pub(crate) const MODE_TREE: u32 = 0o040_000;
+/// The type of the entries that are submodules, stored as the ID of a commit
+pub(crate) const MODE_SUBMODULE: u32 = 0o160_000;
+
+/// The name of an object of the repository, the SHA-1 of its content.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ObjectId([u8; 20]);
+
+impl ObjectId {
+    /// The length of an object ID in hexadecimal
+    pub(crate) const HEX_LEN: usize = 40;
+
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bytes.try_into().ok().map(Self)
+    }
+
+    /// Parses the 40 characters hexadecimal representation of an ID
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        if hex.len() != Self::HEX_LEN {
+            return None;
+        }
+        let mut bytes = [0; 20];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(hex.get(index * 2..index * 2 + 2)?, 16).ok()?;
+        }
+        Some(Self(bytes))
+    }
+
+    pub(crate) fn as_bytes(&self) -> &[u8; 20] {
+        &self.0
+    }
+
+    /// Returns `true` if the hexadecimal representation of the ID starts with `prefix`,
+    /// which must be lowercase.
+    pub(crate) fn starts_with_hex(&self, prefix: &str) -> bool {
+        self.to_string().starts_with(prefix)
+    }
+}
+
+impl Display for ObjectId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ObjectKind {
+    Commit,
+    Tree,
+    Blob,
+    Tag,
+}
+
+impl ObjectKind {
+    /// Parses the kind written in the header of loose objects
+    pub(crate) fn from_name(name: &[u8]) -> Option<Self> {
+        match name {
+            b"commit" => Some(Self::Commit),
+            b"tree" => Some(Self::Tree),
+            b"blob" => Some(Self::Blob),
+            b"tag" => Some(Self::Tag),
+            _ => None,
+        }
+    }
+
+    /// Parses the kind stored in the header of the entries of pack files
+    pub(crate) fn from_pack_type(pack_type: u8) -> Option<Self> {
+        match pack_type {
+            1 => Some(Self::Commit),
+            2 => Some(Self::Tree),
+            3 => Some(Self::Blob),
+            4 => Some(Self::Tag),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Object {
+    pub(crate) kind: ObjectKind,
+    pub(crate) data: Vec<u8>,
+}
+
+/// The parts of a commit needed to compare revisions
+#[derive(Debug)]
+pub(crate) struct Commit {
+    pub(crate) tree: ObjectId,
+    pub(crate) parents: Vec<ObjectId>,
+    /// The time of the commit, in seconds since the Unix epoch
+    pub(crate) time: i64,
+}
+
+impl Commit {
+    pub(crate) fn parse(id: ObjectId, data: &[u8]) -> Result<Self, GitError> {
+        let mut tree = None;
+        let mut parents = Vec::new();
+        let mut time = 0;
+        for line in data.split(|byte| *byte == b'\n') {
+            // The headers end at the first empty line, the message follows
+            if line.is_empty() {
+                break;
+            }
+            let Some((name, value)) = split_header(line) else {
+                continue;
+            };
+            match name {
+                b"tree" => tree = parse_id(value),
+                b"parent" => parents.extend(parse_id(value)),
+                // `committer Name <email> 1700000000 +0100`
+                b"committer" => {
+                    time = value
+                        .rsplitn(3, |byte| *byte == b' ')
+                        .nth(1)
+                        .and_then(|time| std::str::from_utf8(time).ok())
+                        .and_then(|time| time.parse().ok())
+                        .unwrap_or_default();
+                }
+                _ => {}
+            }
+        }
+
+        let tree =
+            tree.ok_or_else(|| GitError::corrupted(format!("the commit {id} has no tree")))?;
+        Ok(Self {
+            tree,
+            parents,
+            time,
+        })
+    }
+}
+
+/// Returns the object an annotated tag points to
+pub(crate) fn parse_tag_target(data: &[u8]) -> Option<ObjectId> {
+    data.split(|byte| *byte == b'\n')
+        .take_while(|line| !line.is_empty())
+        .filter_map(split_header)
+        .find_map(|(name, value)| (name == b"object").then(|| parse_id(value)).flatten())
+}
+
+/// An entry of a tree object
+#[derive(Debug)]
+pub(crate) struct TreeEntry {
+    pub(crate) mode: u32,
+    pub(crate) name: String,
+    pub(crate) id: ObjectId,
+}
+
+impl TreeEntry {
+    pub(crate) const fn is_tree(&self) -> bool {
+        self.mode & MODE_TYPE_MASK == MODE_TREE
+    }
+
+    /// Submodules are stored as commits inside the tree
+    pub(crate) const fn is_submodule(&self) -> bool {
+        self.mode & MODE_TYPE_MASK == MODE_SUBMODULE
+    }
+}
+
+/// Parses the entries of a tree, stored as `<octal mode> <name>\0<20 bytes ID>`
+pub(crate) fn parse_tree(id: ObjectId, mut data: &[u8]) -> Result<Vec<TreeEntry>, GitError> {
+    let corrupted = || GitError::corrupted(format!("the tree {id} is invalid"));
+    let mut entries = Vec::new();
+    while !data.is_empty() {
+        let space = data
+            .iter()
+            .position(|byte| *byte == b' ')
+            .ok_or_else(corrupted)?;
+        let mode = std::str::from_utf8(&data[..space])
+            .ok()
+            .and_then(|mode| u32::from_str_radix(mode, 8).ok())
+            .ok_or_else(corrupted)?;
+        data = &data[space + 1..];
+        let nul = data
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or_else(corrupted)?;
+        let name = String::from_utf8_lossy(&data[..nul]).into_owned();
+        data = &data[nul + 1..];
+        let entry_id = data
+            .get(..20)
+            .and_then(ObjectId::from_bytes)
+            .ok_or_else(corrupted)?;
+        data = &data[20..];
+        entries.push(TreeEntry {
+            mode,
+            name,
+            id: entry_id,
+        });
+    }
+    Ok(entries)
+}
+
+fn split_header(line: &[u8]) -> Option<(&[u8], &[u8])> {
+    let space = line.iter().position(|byte| *byte == b' ')?;
+    Some((&line[..space], &line[space + 1..]))
+}
+
+fn parse_id(value: &[u8]) -> Option<ObjectId> {
+    ObjectId::from_hex(std::str::from_utf8(value).ok()?)
+}
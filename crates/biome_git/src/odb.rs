@@ -0,0 +1,513 @@
+//! The object database of a repository: the loose objects, stored in their own file,
+//! and the pack files, which store many objects compressed against each other.
+
+use crate::GitError;
+use crate::object::{Object, ObjectId, ObjectKind};
+use camino::{Utf8Path, Utf8PathBuf};
+use flate2::bufread::ZlibDecoder;
+use rustc_hash::FxHashMap;
+use std::cell::RefCell;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+use std::rc::Rc;
+
+/// The maximum number of objects kept in memory to resolve the deltas of pack files
+const DELTA_BASE_CACHE_SIZE: usize = 512;
+
+/// How many levels of alternate object directories are followed
+const MAX_ALTERNATES_DEPTH: usize = 5;
+
+pub(crate) struct ObjectDatabase {
+    /// The directories that contain loose objects: the one of the repository, then its
+    /// alternates.
+    directories: Vec<Utf8PathBuf>,
+    packs: Vec<Pack>,
+    /// Objects used as the base of deltas, by pack and offset
+    delta_bases: RefCell<FxHashMap<(usize, u64), Rc<Object>>>,
+}
+
+impl ObjectDatabase {
+    pub(crate) fn open(objects_directory: &Utf8Path) -> Result<Self, GitError> {
+        let mut directories = Vec::new();
+        collect_object_directories(objects_directory, 0, &mut directories);
+
+        let mut packs = Vec::new();
+        for directory in &directories {
+            let Ok(entries) = directory.join("pack").read_dir_utf8() else {
+                continue;
+            };
+            for entry in entries {
+                let path = entry?.into_path();
+                if path.extension() == Some("idx") {
+                    let pack_path = path.with_extension("pack");
+                    if pack_path.is_file() {
+                        packs.push(Pack::open(&path, pack_path)?);
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            directories,
+            packs,
+            delta_bases: RefCell::default(),
+        })
+    }
+
+    /// Reads the object with the given ID
+    pub(crate) fn read(&self, id: ObjectId) -> Result<Object, GitError> {
+        self.try_read(id)?
+            .ok_or_else(|| GitError::corrupted(format!("the object {id} is missing")))
+    }
+
+    /// Reads the object with the given ID, if it exists
+    pub(crate) fn try_read(&self, id: ObjectId) -> Result<Option<Object>, GitError> {
+        for (pack_index, pack) in self.packs.iter().enumerate() {
+            if let Some(offset) = pack.index.find(id) {
+                return self.read_packed(pack_index, offset).map(Some);
+            }
+        }
+
+        let hex = id.to_string();
+        for directory in &self.directories {
+            let path = directory.join(&hex[..2]).join(&hex[2..]);
+            match fs::read(&path) {
+                Ok(content) => return read_loose(id, &content).map(Some),
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                Err(error) => return Err(error.into()),
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the IDs of the objects whose hexadecimal representation starts with `prefix`
+    pub(crate) fn find_by_prefix(&self, prefix: &str) -> Result<Vec<ObjectId>, GitError> {
+        let mut ids = Vec::new();
+        let Some(first_byte) = prefix
+            .get(..2)
+            .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+        else {
+            return Ok(ids);
+        };
+
+        for pack in &self.packs {
+            ids.extend(
+                pack.index
+                    .ids_starting_with(first_byte)
+                    .filter(|id| id.starts_with_hex(prefix)),
+            );
+        }
+        for directory in &self.directories {
+            let Ok(entries) = directory.join(&prefix[..2]).read_dir_utf8() else {
+                continue;
+            };
+            for entry in entries {
+                let entry = entry?;
+                let hex = format!("{}{}", &prefix[..2], entry.file_name());
+                if hex.starts_with(prefix) {
+                    ids.extend(ObjectId::from_hex(&hex));
+                }
+            }
+        }
+
+        ids.sort_unstable();
+        ids.dedup();
+        Ok(ids)
+    }
+
+    fn read_packed(&self, pack_index: usize, offset: u64) -> Result<Object, GitError> {
+        let pack = &self.packs[pack_index];
+        let corrupted = || {
+            GitError::corrupted(format!(
+                "the entry at offset {offset} of {} is invalid",
+                pack.path
+            ))
+        };
+
+        let mut reader = BufReader::new(&pack.file);
+        reader.seek(SeekFrom::Start(offset))?;
+
+        // The header stores the type in bits 4-6 of the first byte, and the size of the
+        // inflated data in the remaining bits, in little-endian groups of 7 bits.
+        let mut byte = read_byte(&mut reader)?;
+        let pack_type = (byte >> 4) & 0b111;
+        let mut size = u64::from(byte & 0b1111);
+        let mut shift = 4;
+        while byte & 0x80 != 0 {
+            byte = read_byte(&mut reader)?;
+            size |= u64::from(byte & 0x7f)
+                .checked_shl(shift)
+                .ok_or_else(corrupted)?;
+            shift += 7;
+        }
+
+        match pack_type {
+            // OFS_DELTA: the base is at a negative offset from this entry
+            6 => {
+                let mut byte = read_byte(&mut reader)?;
+                let mut distance = u64::from(byte & 0x7f);
+                while byte & 0x80 != 0 {
+                    byte = read_byte(&mut reader)?;
+                    distance = ((distance + 1) << 7) | u64::from(byte & 0x7f);
+                }
+                let delta = inflate(&mut reader, size)?;
+                let base_offset = offset.checked_sub(distance).ok_or_else(corrupted)?;
+                let base = self.read_delta_base(pack_index, base_offset)?;
+                Ok(Object {
+                    kind: base.kind,
+                    data: apply_delta(&base.data, &delta).ok_or_else(corrupted)?,
+                })
+            }
+            // REF_DELTA: the base is identified by its ID
+            7 => {
+                let mut base_id = [0; 20];
+                reader.read_exact(&mut base_id)?;
+                let delta = inflate(&mut reader, size)?;
+                let base = self.read(ObjectId::from_bytes(&base_id).ok_or_else(corrupted)?)?;
+                Ok(Object {
+                    kind: base.kind,
+                    data: apply_delta(&base.data, &delta).ok_or_else(corrupted)?,
+                })
+            }
+            pack_type => Ok(Object {
+                kind: ObjectKind::from_pack_type(pack_type).ok_or_else(corrupted)?,
+                data: inflate(&mut reader, size)?,
+            }),
+        }
+    }
+
+    fn read_delta_base(&self, pack_index: usize, offset: u64) -> Result<Rc<Object>, GitError> {
+        if let Some(object) = self.delta_bases.borrow().get(&(pack_index, offset)) {
+            return Ok(object.clone());
+        }
+
+        let object = Rc::new(self.read_packed(pack_index, offset)?);
+        let mut delta_bases = self.delta_bases.borrow_mut();
+        if delta_bases.len() >= DELTA_BASE_CACHE_SIZE {
+            delta_bases.clear();
+        }
+        delta_bases.insert((pack_index, offset), object.clone());
+        Ok(object)
+    }
+}
+
+/// Collects `objects_directory` and the alternate object directories it lists in
+/// `info/alternates`.
+fn collect_object_directories(
+    objects_directory: &Utf8Path,
+    depth: usize,
+    directories: &mut Vec<Utf8PathBuf>,
+) {
+    if directories
+        .iter()
+        .any(|directory| directory == objects_directory)
+    {
+        return;
+    }
+    directories.push(objects_directory.to_path_buf());
+    if depth >= MAX_ALTERNATES_DEPTH {
+        return;
+    }
+
+    let Ok(alternates) = fs::read_to_string(objects_directory.join("info/alternates")) else {
+        return;
+    };
+    for line in alternates.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        collect_object_directories(&objects_directory.join(line), depth + 1, directories);
+    }
+}
+
+/// Parses a loose object: the zlib compressed `<kind> <size>\0<data>`
+fn read_loose(id: ObjectId, content: &[u8]) -> Result<Object, GitError> {
+    let corrupted = || GitError::corrupted(format!("the object {id} is invalid"));
+
+    let mut inflated = Vec::new();
+    ZlibDecoder::new(content).read_to_end(&mut inflated)?;
+    let nul = inflated
+        .iter()
+        .position(|byte| *byte == 0)
+        .ok_or_else(corrupted)?;
+    let header = &inflated[..nul];
+    let space = header
+        .iter()
+        .position(|byte| *byte == b' ')
+        .ok_or_else(corrupted)?;
+    let kind = ObjectKind::from_name(&header[..space]).ok_or_else(corrupted)?;
+    let size: usize = std::str::from_utf8(&header[space + 1..])
+        .ok()
+        .and_then(|size| size.parse().ok())
+        .ok_or_else(corrupted)?;
+
+    let data = inflated.split_off(nul + 1);
+    if data.len() != size {
+        return Err(corrupted());
+    }
+    Ok(Object { kind, data })
+}
+
+fn read_byte(reader: &mut impl Read) -> io::Result<u8> {
+    let mut byte = [0];
+    reader.read_exact(&mut byte)?;
+    Ok(byte[0])
+}
+
+fn inflate(reader: &mut impl BufRead, size: u64) -> Result<Vec<u8>, GitError> {
+    let mut data = Vec::with_capacity(usize::try_from(size).unwrap_or_default());
+    ZlibDecoder::new(reader).take(size).read_to_end(&mut data)?;
+    if data.len() as u64 != size {
+        return Err(GitError::corrupted("a packed object is truncated"));
+    }
+    Ok(data)
+}
+
+/// Rebuilds an object from its `base` and a `delta`, which starts with the sizes of the
+/// base and of the result, followed by instructions that either copy a range of the base
+/// or insert new data.
+fn apply_delta(base: &[u8], delta: &[u8]) -> Option<Vec<u8>> {
+    let mut delta = delta.iter().copied();
+    let mut read_size = || {
+        let mut size = 0usize;
+        let mut shift = 0;
+        loop {
+            let byte = delta.next()?;
+            size |= usize::from(byte & 0x7f).checked_shl(shift)?;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                return Some(size);
+            }
+        }
+    };
+    let base_size = read_size()?;
+    let result_size = read_size()?;
+    if base_size != base.len() {
+        return None;
+    }
+
+    let mut result = Vec::with_capacity(result_size);
+    while let Some(instruction) = delta.next() {
+        if instruction & 0x80 != 0 {
+            // Copy: the bits 0-3 tell which bytes of the offset are present, and the
+            // bits 4-6 which bytes of the size are present.
+            let mut offset = 0usize;
+            for byte_index in 0..4 {
+                if instruction & (1 << byte_index) != 0 {
+                    offset |= usize::from(delta.next()?) << (8 * byte_index);
+                }
+            }
+            let mut size = 0usize;
+            for byte_index in 0..3 {
+                if instruction & (0x10 << byte_index) != 0 {
+                    size |= usize::from(delta.next()?) << (8 * byte_index);
+                }
+            }
+            if size == 0 {
+                size = 0x10000;
+            }
+            result.extend_from_slice(base.get(offset..offset.checked_add(size)?)?);
+        } else if instruction != 0 {
+            // Insert: the instruction is the number of bytes that follow
+            for _ in 0..instruction {
+                result.push(delta.next()?);
+            }
+        } else {
+            return None;
+        }
+    }
+
+    (result.len() == result_size).then_some(result)
+}
+
+struct Pack {
+    path: Utf8PathBuf,
+    file: fs::File,
+    index: PackIndex,
+}
+
+impl Pack {
+    fn open(index_path: &Utf8Path, path: Utf8PathBuf) -> Result<Self, GitError> {
+        Ok(Self {
+            file: fs::File::open(&path)?,
+            index: PackIndex::parse(index_path, fs::read(index_path)?)?,
+            path,
+        })
+    }
+}
+
+/// The index of a pack file, which maps the IDs of its objects to their offset.
+///
+/// The IDs are sorted, and a fan-out table gives the number of IDs whose first byte is
+/// lower or equal to each value.
+struct PackIndex {
+    data: Vec<u8>,
+    version: u8,
+    count: usize,
+}
+
+impl PackIndex {
+    const V2_MAGIC: &'static [u8] = b"\xfftOc";
+    const FANOUT_LEN: usize = 256 * 4;
+
+    fn parse(path: &Utf8Path, data: Vec<u8>) -> Result<Self, GitError> {
+        let version = if data.starts_with(Self::V2_MAGIC) {
+            match data.get(4..8) {
+                Some([0, 0, 0, 2]) => 2,
+                _ => {
+                    return Err(GitError::unsupported(format!(
+                        "the version of the pack index {path}"
+                    )));
+                }
+            }
+        } else {
+            1
+        };
+
+        let mut index = Self {
+            data,
+            version,
+            count: 0,
+        };
+        let count = index
+            .fanout(255)
+            .ok_or_else(|| GitError::corrupted(format!("the pack index {path} is truncated")))?;
+        index.count = count;
+
+        // Check the size once, so that the lookups can't read out of bounds
+        let minimum_len = index.ids_start() + count * index.id_stride() + index.offsets_len();
+        if index.data.len() < minimum_len {
+            return Err(GitError::corrupted(format!(
+                "the pack index {path} is truncated"
+            )));
+        }
+        Ok(index)
+    }
+
+    fn fanout_start(&self) -> usize {
+        if self.version == 2 { 8 } else { 0 }
+    }
+
+    /// Returns the number of IDs whose first byte is lower or equal to `byte`
+    fn fanout(&self, byte: u8) -> Option<usize> {
+        let start = self.fanout_start() + usize::from(byte) * 4;
+        let bytes = self.data.get(start..start + 4)?;
+        usize::try_from(u32::from_be_bytes(bytes.try_into().ok()?)).ok()
+    }
+
+    fn ids_start(&self) -> usize {
+        self.fanout_start() + Self::FANOUT_LEN
+    }
+
+    /// Version 1 stores the offset before each ID
+    fn id_stride(&self) -> usize {
+        if self.version == 2 { 20 } else { 24 }
+    }
+
+    fn offsets_len(&self) -> usize {
+        if self.version == 2 {
+            // CRC32 and offset of each object
+            self.count * 8
+        } else {
+            0
+        }
+    }
+
+    fn id_at(&self, position: usize) -> ObjectId {
+        let start =
+            self.ids_start() + position * self.id_stride() + if self.version == 2 { 0 } else { 4 };
+        ObjectId::from_bytes(&self.data[start..start + 20]).unwrap_or_default()
+    }
+
+    fn offset_at(&self, position: usize) -> Option<u64> {
+        let read_u32 = |start: usize| {
+            self.data
+                .get(start..start + 4)
+                .and_then(|bytes| bytes.try_into().ok())
+                .map(u32::from_be_bytes)
+        };
+        if self.version == 1 {
+            return read_u32(self.ids_start() + position * 24).map(u64::from);
+        }
+
+        let offsets_start = self.ids_start() + self.count * 24;
+        let offset = read_u32(offsets_start + position * 4)?;
+        if offset & 0x8000_0000 == 0 {
+            return Some(u64::from(offset));
+        }
+
+        // The offsets larger than 2GiB are stored in a separate table of 8-byte entries
+        let large_offsets_start = offsets_start + self.count * 4;
+        let start = large_offsets_start + usize::try_from(offset & 0x7fff_ffff).ok()? * 8;
+        self.data
+            .get(start..start + 8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u64::from_be_bytes)
+    }
+
+    /// Returns the range of positions of the IDs whose first byte is `byte`
+    fn bucket(&self, byte: u8) -> std::ops::Range<usize> {
+        let start = match byte.checked_sub(1) {
+            Some(previous) => self.fanout(previous).unwrap_or_default(),
+            None => 0,
+        };
+        let end = self.fanout(byte).unwrap_or_default().min(self.count);
+        start.min(end)..end
+    }
+
+    fn find(&self, id: ObjectId) -> Option<u64> {
+        let bucket = self.bucket(id.as_bytes()[0]);
+        let (mut low, mut high) = (bucket.start, bucket.end);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match self.id_at(middle).cmp(&id) {
+                std::cmp::Ordering::Less => low = middle + 1,
+                std::cmp::Ordering::Greater => high = middle,
+                std::cmp::Ordering::Equal => return self.offset_at(middle),
+            }
+        }
+        None
+    }
+
+    fn ids_starting_with(&self, byte: u8) -> impl Iterator<Item = ObjectId> + '_ {
+        self.bucket(byte).map(|position| self.id_at(position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::apply_delta;
+
+    #[test]
+    fn applies_copy_and_insert_instructions() {
+        let base = b"hello world";
+        let delta = [
+            // Base and result sizes
+            11,
+            12,
+            // Copy 6 bytes from offset 0
+            0b1001_0000,
+            6,
+            // Insert 2 bytes
+            2,
+            b'a',
+            b'l',
+            // Copy 4 bytes from offset 7
+            0b1001_0001,
+            7,
+            4,
+        ];
+
+        assert_eq!(
+            apply_delta(base, &delta).as_deref(),
+            Some(b"hello alorld".as_slice())
+        );
+    }
+
+    #[test]
+    fn rejects_delta_of_another_base() {
+        assert_eq!(apply_delta(b"base", &[5, 1, 1, b'a']), None);
+    }
+}
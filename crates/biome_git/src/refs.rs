@@ -0,0 +1,102 @@
+//! References: names that point to a commit, such as branches and tags, stored either in
+//! their own file or together in `packed-refs`.
+
+use crate::GitError;
+use crate::object::ObjectId;
+use camino::{Utf8Path, Utf8PathBuf};
+use std::fs;
+use std::io;
+
+/// How many symbolic references are followed, to break cycles
+const MAX_SYMBOLIC_DEPTH: usize = 5;
+
+pub(crate) struct References {
+    /// The directory of the repository, which contains `HEAD` and the references that are
+    /// specific to the working tree.
+    git_directory: Utf8PathBuf,
+    /// The directory shared by all the working trees of the repository
+    common_directory: Utf8PathBuf,
+}
+
+impl References {
+    pub(crate) fn new(git_directory: Utf8PathBuf, common_directory: Utf8PathBuf) -> Self {
+        Self {
+            git_directory,
+            common_directory,
+        }
+    }
+
+    /// Returns the ID the reference with the full `name` points to, following symbolic
+    /// references.
+    pub(crate) fn resolve(&self, name: &str) -> Result<Option<ObjectId>, GitError> {
+        let mut name = name.to_string();
+        for _ in 0..MAX_SYMBOLIC_DEPTH {
+            let Some(value) = self.read(&name)? else {
+                return Ok(None);
+            };
+            match value.strip_prefix("ref:") {
+                Some(target) => name = target.trim().to_string(),
+                None => {
+                    // `FETCH_HEAD` may contain several lines, that start with an ID
+                    let id = value.lines().next().unwrap_or_default();
+                    let id = id.get(..ObjectId::HEX_LEN).unwrap_or(id);
+                    return ObjectId::from_hex(id).map(Some).ok_or_else(|| {
+                        GitError::corrupted(format!("the reference {name} is invalid"))
+                    });
+                }
+            }
+        }
+        Err(GitError::corrupted(format!(
+            "the reference {name} is nested too deeply"
+        )))
+    }
+
+    /// Reads the value of the reference `name`: either an ID, or `ref: <name>`
+    fn read(&self, name: &str) -> Result<Option<String>, GitError> {
+        // The names come from the user, and must not escape the repository
+        if name.is_empty()
+            || name.starts_with('/')
+            || name.split('/').any(|part| part.is_empty() || part == "..")
+        {
+            return Ok(None);
+        }
+
+        for directory in [&self.git_directory, &self.common_directory] {
+            if let Some(value) = read_file(&directory.join(name))? {
+                return Ok(Some(value));
+            }
+        }
+
+        if !name.starts_with("refs/") {
+            return Ok(None);
+        }
+        let Some(packed_refs) = read_file(&self.common_directory.join("packed-refs"))? else {
+            return Ok(None);
+        };
+        // Each line is `<ID> <name>`. Lines starting with `^` contain the peeled value of
+        // the annotated tag on the previous line, and `#` starts the header.
+        Ok(packed_refs.lines().find_map(|line| {
+            let (id, reference) = line.split_once(' ')?;
+            (!line.starts_with(['#', '^']) && reference == name).then(|| id.to_string())
+        }))
+    }
+}
+
+fn read_file(path: &Utf8Path) -> Result<Option<String>, GitError> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(error)
+            if matches!(
+                error.kind(),
+                io::ErrorKind::NotFound
+                    | io::ErrorKind::IsADirectory
+                    | io::ErrorKind::NotADirectory
+            ) =>
+        {
+            Ok(None)
+        }
+        // Directories can't be read as files on Windows
+        Err(_) if path.is_dir() => Ok(None),
+        Err(error) => Err(error.into()),
+    }
+}
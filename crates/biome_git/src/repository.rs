@@ -0,0 +1,661 @@
+use crate::GitError;
+use crate::diff::{self, Fingerprint};
+use crate::index::Index;
+use crate::object::{Commit, ObjectId, ObjectKind, parse_tag_target, parse_tree};
+use crate::odb::ObjectDatabase;
+use crate::refs::References;
+use biome_string_case::StrLikeExtension;
+use camino::{Utf8Path, Utf8PathBuf};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fs;
+use std::io;
+use std::ops::RangeInclusive;
+
+/// Renames aren't detected by similarity when the number of added files multiplied by
+/// the number of deleted files exceeds this limit, like the `diff.renameLimit` of git.
+const RENAME_LIMIT: usize = 1000 * 1000;
+
+/// The minimum similarity between two files to consider that one was renamed to the other
+const RENAME_SIMILARITY: f64 = 0.5;
+
+/// How many annotated tags are followed to find a commit
+const MAX_TAG_DEPTH: usize = 10;
+
+/// A git repository, opened from a directory of its working tree
+pub struct Repository {
+    /// The directory of the repository, usually `.git`
+    git_directory: Utf8PathBuf,
+    /// The directory the repository was opened from, relative to the root of the working
+    /// tree, with a trailing slash. It's empty at the root.
+    prefix: String,
+    references: References,
+    objects: ObjectDatabase,
+}
+
+/// A file that was added or modified
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FileChange {
+    /// The path of the file, relative to the directory the repository was opened from
+    pub path: String,
+    pub kind: ChangeKind,
+    /// The path of the file, relative to the root of the working tree
+    repository_path: String,
+    /// The previous content of the file, or of the file it was renamed from
+    old: Option<ObjectId>,
+    new: ObjectId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ChangeKind {
+    Added,
+    Modified,
+    /// The file was moved from the path `from`, relative to the directory the repository
+    /// was opened from, possibly with modifications
+    Renamed {
+        from: String,
+    },
+}
+
+/// A file that exists on one side of a comparison, but not on the other
+struct UnpairedFile {
+    path: String,
+    id: ObjectId,
+}
+
+impl Repository {
+    /// Opens the repository whose working tree contains `directory`
+    pub fn discover(directory: &Utf8Path) -> Result<Self, GitError> {
+        for working_tree in directory.ancestors() {
+            let dot_git = working_tree.join(".git");
+            let git_directory = if dot_git.is_dir() {
+                dot_git
+            } else if dot_git.is_file() {
+                // Linked working trees and submodules contain a file `gitdir: <path>`
+                let content = fs::read_to_string(&dot_git)?;
+                let path = content
+                    .trim()
+                    .strip_prefix("gitdir:")
+                    .ok_or_else(|| GitError::corrupted(format!("{dot_git} is invalid")))?;
+                working_tree.join(path.trim())
+            } else {
+                continue;
+            };
+            if !git_directory.join("HEAD").is_file() {
+                continue;
+            }
+
+            let prefix = directory
+                .strip_prefix(working_tree)
+                .map(|relative| {
+                    relative
+                        .components()
+                        .map(|component| format!("{component}/"))
+                        .collect()
+                })
+                .unwrap_or_default();
+            return Self::open(git_directory, prefix);
+        }
+
+        Err(GitError::NotARepository(directory.to_path_buf()))
+    }
+
+    fn open(git_directory: Utf8PathBuf, prefix: String) -> Result<Self, GitError> {
+        // Linked working trees share the objects and the branches of the main repository
+        let common_directory = match fs::read_to_string(git_directory.join("commondir")) {
+            Ok(path) => git_directory.join(path.trim()),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => git_directory.clone(),
+            Err(error) => return Err(error.into()),
+        };
+        check_config(&common_directory)?;
+
+        Ok(Self {
+            objects: ObjectDatabase::open(&common_directory.join("objects"))?,
+            references: References::new(git_directory.clone(), common_directory),
+            git_directory,
+            prefix,
+        })
+    }
+
+    /// Returns the files added or modified on the current branch since `base`, a revision
+    /// such as a branch, a tag or a commit. Like `git diff <base>...HEAD`, the changes are
+    /// computed between the common ancestor of `base` and `HEAD`, and `HEAD`, so the
+    /// changes made on `base` since the current branch forked from it aren't included.
+    /// The changes that aren't committed aren't included either.
+    pub fn changed_files(&self, base: &str) -> Result<Vec<FileChange>, GitError> {
+        let base = self.resolve_revision(base)?;
+        let Some(head) = self.head()? else {
+            // Nothing was committed yet
+            return Ok(Vec::new());
+        };
+        // When there are several common ancestors, the most recent one is used, like git
+        let merge_base = self
+            .merge_bases(base, head)?
+            .into_iter()
+            .next()
+            .unwrap_or(base);
+        let mut old_files = self.tree_files(self.read_commit(merge_base)?.tree)?;
+        let mut new_files: Vec<_> = self
+            .tree_files(self.read_commit(head)?.tree)?
+            .into_iter()
+            .collect();
+        new_files.sort_unstable();
+
+        let mut changes = Vec::new();
+        let mut added = Vec::new();
+        for (path, id) in new_files {
+            match old_files.remove(&path) {
+                Some(old) if old == id => {}
+                Some(old) => changes.push(self.change(path, ChangeKind::Modified, Some(old), id)),
+                None => added.push(UnpairedFile { path, id }),
+            }
+        }
+
+        let deleted = old_files
+            .into_iter()
+            .map(|(path, id)| UnpairedFile { path, id })
+            .collect();
+        self.finish(changes, added, deleted)
+    }
+
+    /// Returns the files added or modified in the index since `HEAD`, like
+    /// `git diff --staged`
+    pub fn staged_files(&self) -> Result<Vec<FileChange>, GitError> {
+        let mut old_files = match self.head()? {
+            Some(head) => self.tree_files(self.read_commit(head)?.tree)?,
+            // Everything is added before the first commit
+            None => FxHashMap::default(),
+        };
+
+        let index = Index::read(&self.git_directory.join("index"))?;
+        let mut changes = Vec::new();
+        let mut added = Vec::new();
+        for entry in &index.entries {
+            // Files with conflicts aren't staged yet
+            if entry.is_submodule() || entry.is_sparse_directory() || entry.stage != 0 {
+                old_files.remove(&entry.path);
+                continue;
+            }
+
+            match old_files.remove(&entry.path) {
+                Some(old) if old == entry.id => {}
+                Some(old) => changes.push(self.change(
+                    entry.path.clone(),
+                    ChangeKind::Modified,
+                    Some(old),
+                    entry.id,
+                )),
+                None => added.push(UnpairedFile {
+                    path: entry.path.clone(),
+                    id: entry.id,
+                }),
+            }
+        }
+
+        let deleted = old_files
+            .into_iter()
+            .map(|(path, id)| UnpairedFile { path, id })
+            .collect();
+        self.finish(changes, added, deleted)
+    }
+
+    /// Returns the ranges of the lines of the file, starting at 1, that were added or
+    /// modified by `change`
+    pub fn changed_lines(&self, change: &FileChange) -> Result<Vec<RangeInclusive<u32>>, GitError> {
+        let new = self.read_blob(change.new)?;
+        let old = match change.old {
+            Some(id) => Some(self.read_blob(id)?),
+            None => None,
+        };
+        Ok(diff::changed_lines(old.as_deref(), &new))
+    }
+
+    /// Returns the commit designated by `revision`: `HEAD`, the name of a branch or a tag,
+    /// a full or abbreviated commit ID, optionally followed by `~<n>` or `^<n>` to select
+    /// an ancestor.
+    pub fn resolve_revision(&self, revision: &str) -> Result<ObjectId, GitError> {
+        let unknown = || GitError::UnknownRevision(revision.to_string());
+
+        let (name, mut suffixes) =
+            revision.split_at(revision.find(['~', '^']).unwrap_or(revision.len()));
+        let id = self.resolve_name(name)?.ok_or_else(unknown)?;
+        let mut id = self.peel_to_commit(id)?.ok_or_else(unknown)?;
+
+        while let Some(operator) = suffixes.chars().next() {
+            let rest = &suffixes[1..];
+            let digits = rest
+                .find(|character: char| !character.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let number: usize = if digits == 0 {
+                1
+            } else {
+                rest[..digits].parse().map_err(|_| unknown())?
+            };
+            suffixes = &rest[digits..];
+
+            match operator {
+                // `~<n>` selects the n-th first parent
+                '~' => {
+                    for _ in 0..number {
+                        id = *self.read_commit(id)?.parents.first().ok_or_else(unknown)?;
+                    }
+                }
+                // `^<n>` selects the n-th parent, and `^0` the commit itself
+                _ => {
+                    if number > 0 {
+                        id = *self
+                            .read_commit(id)?
+                            .parents
+                            .get(number - 1)
+                            .ok_or_else(unknown)?;
+                    }
+                }
+            }
+        }
+
+        Ok(id)
+    }
+
+    /// Returns the object designated by a name, without suffixes
+    fn resolve_name(&self, name: &str) -> Result<Option<ObjectId>, GitError> {
+        let name = if name.is_empty() || name == "@" {
+            "HEAD"
+        } else {
+            name
+        };
+
+        if let Some(id) = ObjectId::from_hex(name) {
+            if self.objects.try_read(id)?.is_some() {
+                return Ok(Some(id));
+            }
+        }
+
+        // The same order as git: `HEAD` and the other files at the root of the repository,
+        // then tags, branches and remote branches
+        let is_pseudo_reference = name
+            .chars()
+            .all(|character| character.is_ascii_uppercase() || character == '_');
+        let candidates = [
+            (is_pseudo_reference || name.starts_with("refs/")).then(|| name.to_string()),
+            Some(format!("refs/{name}")),
+            Some(format!("refs/tags/{name}")),
+            Some(format!("refs/heads/{name}")),
+            Some(format!("refs/remotes/{name}")),
+            Some(format!("refs/remotes/{name}/HEAD")),
+        ];
+        for candidate in candidates.into_iter().flatten() {
+            if let Some(id) = self.references.resolve(&candidate)? {
+                return Ok(Some(id));
+            }
+        }
+
+        // Abbreviated IDs must have at least 4 characters, and designate a single object
+        if name.len() >= 4 && name.chars().all(|character| character.is_ascii_hexdigit()) {
+            if let [id] = self
+                .objects
+                .find_by_prefix(&name.to_ascii_lowercase_cow())?[..]
+            {
+                return Ok(Some(id));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the commit `HEAD` points to, or `None` before the first commit
+    fn head(&self) -> Result<Option<ObjectId>, GitError> {
+        match self.references.resolve("HEAD")? {
+            Some(id) => self.peel_to_commit(id),
+            None => Ok(None),
+        }
+    }
+
+    /// Follows annotated tags until a commit
+    fn peel_to_commit(&self, mut id: ObjectId) -> Result<Option<ObjectId>, GitError> {
+        for _ in 0..MAX_TAG_DEPTH {
+            let Some(object) = self.objects.try_read(id)? else {
+                return Ok(None);
+            };
+            match object.kind {
+                ObjectKind::Commit => return Ok(Some(id)),
+                ObjectKind::Tag => match parse_tag_target(&object.data) {
+                    Some(target) => id = target,
+                    None => return Err(GitError::corrupted(format!("the tag {id} is invalid"))),
+                },
+                ObjectKind::Tree | ObjectKind::Blob => return Ok(None),
+            }
+        }
+        Ok(None)
+    }
+
+    fn read_commit(&self, id: ObjectId) -> Result<Commit, GitError> {
+        let object = self.objects.read(id)?;
+        if object.kind != ObjectKind::Commit {
+            return Err(GitError::corrupted(format!(
+                "the object {id} isn't a commit"
+            )));
+        }
+        Commit::parse(id, &object.data)
+    }
+
+    fn read_blob(&self, id: ObjectId) -> Result<Vec<u8>, GitError> {
+        let object = self.objects.read(id)?;
+        if object.kind != ObjectKind::Blob {
+            return Err(GitError::corrupted(format!("the object {id} isn't a file")));
+        }
+        Ok(object.data)
+    }
+
+    /// Returns the best common ancestors of two commits, the most recent first, like
+    /// `git merge-base --all`. A common ancestor is the best when it isn't an ancestor of
+    /// another common ancestor. There are several of them after criss-cross merges.
+    pub fn merge_bases(
+        &self,
+        first: ObjectId,
+        second: ObjectId,
+    ) -> Result<Vec<ObjectId>, GitError> {
+        const PARENT1: u8 = 1;
+        const PARENT2: u8 = 2;
+        /// The commit is an ancestor of a common ancestor, so it can't be a best one
+        const STALE: u8 = 4;
+        const RESULT: u8 = 8;
+
+        if first == second {
+            return Ok(vec![first]);
+        }
+
+        // The ancestors of each side are painted from the most recent, until only the
+        // ancestors of the common ancestors that were found remain
+        let mut flags: FxHashMap<ObjectId, u8> = FxHashMap::default();
+        let mut queue = BinaryHeap::new();
+        for (id, flag) in [(first, PARENT1), (second, PARENT2)] {
+            *flags.entry(id).or_default() |= flag;
+            queue.push(QueuedCommit {
+                time: self.read_commit(id)?.time,
+                id,
+            });
+        }
+
+        let mut candidates = Vec::new();
+        while queue.iter().any(|commit| flags[&commit.id] & STALE == 0) {
+            let Some(QueuedCommit { id, .. }) = queue.pop() else {
+                break;
+            };
+            let commit_flags = flags.entry(id).or_default();
+            let mut flag = *commit_flags & (PARENT1 | PARENT2 | STALE);
+            if flag == PARENT1 | PARENT2 {
+                if *commit_flags & RESULT == 0 {
+                    *commit_flags |= RESULT;
+                    candidates.push(id);
+                }
+                flag |= STALE;
+            }
+
+            for parent in self.read_commit(id)?.parents {
+                let parent_flags = flags.entry(parent).or_default();
+                if *parent_flags & flag == flag {
+                    continue;
+                }
+                *parent_flags |= flag;
+                queue.push(QueuedCommit {
+                    time: self.read_commit(parent)?.time,
+                    id: parent,
+                });
+            }
+        }
+
+        // Commit times can be wrong, so a candidate can be found before one of its
+        // descendants that is a common ancestor too
+        candidates.retain(|id| flags[id] & STALE == 0);
+        self.remove_redundant(candidates)
+    }
+
+    /// Removes the commits of `candidates` that are ancestors of another one
+    fn remove_redundant(&self, candidates: Vec<ObjectId>) -> Result<Vec<ObjectId>, GitError> {
+        if candidates.len() < 2 {
+            return Ok(candidates);
+        }
+
+        let candidate_ids: FxHashSet<ObjectId> = candidates.iter().copied().collect();
+        let mut redundant = FxHashSet::default();
+        // The ancestors are shared between the candidates, so each one is visited once
+        let mut visited = FxHashSet::default();
+        for &candidate in &candidates {
+            let mut stack = self.read_commit(candidate)?.parents;
+            while let Some(id) = stack.pop() {
+                if !visited.insert(id) {
+                    continue;
+                }
+                if candidate_ids.contains(&id) {
+                    redundant.insert(id);
+                }
+                stack.extend(self.read_commit(id)?.parents);
+            }
+        }
+
+        Ok(candidates
+            .into_iter()
+            .filter(|id| !redundant.contains(id))
+            .collect())
+    }
+
+    /// Returns the files of a tree and its subtrees, with their path relative to the root of
+    /// the tree. Submodules are ignored.
+    fn tree_files(&self, tree: ObjectId) -> Result<FxHashMap<String, ObjectId>, GitError> {
+        let mut files = FxHashMap::default();
+        let mut trees = vec![(tree, String::new())];
+        while let Some((tree, prefix)) = trees.pop() {
+            let object = self.objects.read(tree)?;
+            if object.kind != ObjectKind::Tree {
+                return Err(GitError::corrupted(format!(
+                    "the object {tree} isn't a tree"
+                )));
+            }
+            for entry in parse_tree(tree, &object.data)? {
+                let path = format!("{prefix}{}", entry.name);
+                if entry.is_tree() {
+                    trees.push((entry.id, format!("{path}/")));
+                } else if !entry.is_submodule() {
+                    files.insert(path, entry.id);
+                }
+            }
+        }
+        Ok(files)
+    }
+
+    fn change(
+        &self,
+        repository_path: String,
+        kind: ChangeKind,
+        old: Option<ObjectId>,
+        new: ObjectId,
+    ) -> FileChange {
+        FileChange {
+            path: String::new(),
+            kind,
+            repository_path,
+            old,
+            new,
+        }
+    }
+
+    /// Pairs the added and deleted files into renames, and returns the changes of the
+    /// files inside the directory the repository was opened from, sorted by path
+    fn finish(
+        &self,
+        mut changes: Vec<FileChange>,
+        added: Vec<UnpairedFile>,
+        deleted: Vec<UnpairedFile>,
+    ) -> Result<Vec<FileChange>, GitError> {
+        let renames = self.detect_renames(&added, &deleted)?;
+        for (index, file) in added.into_iter().enumerate() {
+            let change = match renames.get(&index) {
+                Some(&source) => {
+                    let source = &deleted[source];
+                    self.change(
+                        file.path,
+                        ChangeKind::Renamed {
+                            from: source.path.clone(),
+                        },
+                        Some(source.id),
+                        file.id,
+                    )
+                }
+                None => self.change(file.path, ChangeKind::Added, None, file.id),
+            };
+            changes.push(change);
+        }
+
+        let mut changes: Vec<_> = changes
+            .into_iter()
+            .filter_map(|mut change| {
+                change.path = self.relative_path(&change.repository_path)?;
+                if let ChangeKind::Renamed { from } = &mut change.kind {
+                    *from = self.relative_path(from).unwrap_or_else(|| {
+                        format!("{}{from}", "../".repeat(self.prefix.matches('/').count()))
+                    });
+                }
+                Some(change)
+            })
+            .collect();
+        changes.sort_by(|first, second| first.path.cmp(&second.path));
+        Ok(changes)
+    }
+
+    /// Returns, for the index of each added file that was renamed, the index of the
+    /// deleted file it was renamed from
+    fn detect_renames(
+        &self,
+        added: &[UnpairedFile],
+        deleted: &[UnpairedFile],
+    ) -> Result<FxHashMap<usize, usize>, GitError> {
+        let mut renames = FxHashMap::default();
+        if added.is_empty() || deleted.is_empty() {
+            return Ok(renames);
+        }
+
+        // Files with the same content are renames, whatever their number
+        let mut deleted_by_id: FxHashMap<ObjectId, Vec<usize>> = FxHashMap::default();
+        for (index, file) in deleted.iter().enumerate() {
+            deleted_by_id.entry(file.id).or_default().push(index);
+        }
+        let mut paired_deleted = FxHashSet::default();
+        for (index, file) in added.iter().enumerate() {
+            if let Some(source) = deleted_by_id
+                .get_mut(&file.id)
+                .and_then(|sources| sources.pop())
+            {
+                renames.insert(index, source);
+                paired_deleted.insert(source);
+            }
+        }
+
+        let remaining_added: Vec<_> = (0..added.len())
+            .filter(|index| !renames.contains_key(index))
+            .collect();
+        let remaining_deleted: Vec<_> = (0..deleted.len())
+            .filter(|index| !paired_deleted.contains(index))
+            .collect();
+        if remaining_added.is_empty()
+            || remaining_deleted.is_empty()
+            || remaining_added.len() * remaining_deleted.len() > RENAME_LIMIT
+        {
+            return Ok(renames);
+        }
+
+        // Then the most similar files are paired first
+        let fingerprints = |indexes: &[usize], files: &[UnpairedFile]| {
+            indexes
+                .iter()
+                .filter_map(|&index| {
+                    match self.read_blob(files[index].id) {
+                        // Binary and empty files aren't compared
+                        Ok(content) if content.is_empty() || content.contains(&0) => None,
+                        Ok(content) => Some(Ok((index, Fingerprint::new(&content)))),
+                        Err(error) => Some(Err(error)),
+                    }
+                })
+                .collect::<Result<Vec<_>, GitError>>()
+        };
+        let added_fingerprints = fingerprints(&remaining_added, added)?;
+        let deleted_fingerprints = fingerprints(&remaining_deleted, deleted)?;
+
+        let mut pairs = Vec::new();
+        for (added_index, added_fingerprint) in &added_fingerprints {
+            for (deleted_index, deleted_fingerprint) in &deleted_fingerprints {
+                let similarity = added_fingerprint.similarity(deleted_fingerprint);
+                if similarity >= RENAME_SIMILARITY {
+                    pairs.push((similarity, *added_index, *deleted_index));
+                }
+            }
+        }
+        pairs.sort_by(|first, second| second.0.total_cmp(&first.0));
+        for (_, added_index, deleted_index) in pairs {
+            if !renames.contains_key(&added_index) && paired_deleted.insert(deleted_index) {
+                renames.insert(added_index, deleted_index);
+            }
+        }
+
+        Ok(renames)
+    }
+
+    /// Returns `path`, relative to the root of the working tree, relative to the directory
+    /// the repository was opened from, if it's inside it
+    fn relative_path(&self, path: &str) -> Option<String> {
+        path.strip_prefix(&self.prefix).map(str::to_string)
+    }
+}
+
+/// Rejects the repositories that store their objects or references in formats that aren't
+/// supported
+fn check_config(common_directory: &Utf8Path) -> Result<(), GitError> {
+    let config = match fs::read_to_string(common_directory.join("config")) {
+        Ok(config) => config,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(error) => return Err(error.into()),
+    };
+
+    let mut section = "";
+    for line in config.lines().map(str::trim) {
+        if let Some(header) = line.strip_prefix('[') {
+            section = header.split([']', ' ', '"']).next().unwrap_or_default();
+            continue;
+        }
+        if !section.eq_ignore_ascii_case("extensions") {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim());
+        if key.eq_ignore_ascii_case("objectFormat") && value.eq_ignore_ascii_case("sha256") {
+            return Err(GitError::unsupported("SHA-256 object IDs"));
+        }
+        if key.eq_ignore_ascii_case("refStorage") && value.eq_ignore_ascii_case("reftable") {
+            return Err(GitError::unsupported("references stored as reftables"));
+        }
+    }
+    Ok(())
+}
+
+/// A commit waiting to be visited, ordered by commit time
+#[derive(Eq, PartialEq)]
+struct QueuedCommit {
+    time: i64,
+    id: ObjectId,
+}
+
+impl Ord for QueuedCommit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.time
+            .cmp(&other.time)
+            .then_with(|| self.id.cmp(&other.id))
+    }
+}
+
+impl PartialOrd for QueuedCommit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,353 @@
+use biome_git::{ChangeKind, GitError, ObjectId, Repository};
+use camino::Utf8PathBuf;
+use std::fs;
+use std::process::Command;
+
+/// A repository created with the `git` binary in a temporary directory
+struct TestRepository {
+    path: Utf8PathBuf,
+}
+
+impl TestRepository {
+    /// Returns `None` when `git` isn't installed
+    fn new(name: &str) -> Option<Self> {
+        let path = Utf8PathBuf::from_path_buf(std::env::temp_dir())
+            .unwrap()
+            .join(format!("biome_git_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+
+        let repository = Self { path };
+        let initialized = Command::new("git")
+            .args(["init", "--quiet", "--initial-branch=main"])
+            .current_dir(&repository.path)
+            .status()
+            .is_ok_and(|status| status.success());
+        if !initialized {
+            return None;
+        }
+        repository.git(&["config", "user.name", "Biome"]);
+        repository.git(&["config", "user.email", "biome@example.com"]);
+        repository.git(&["config", "commit.gpgSign", "false"]);
+        Some(repository)
+    }
+
+    /// Runs `git` with `args`, and returns its output
+    fn git(&self, args: &[&str]) -> String {
+        self.git_at(args, None)
+    }
+
+    /// Runs `git` with `args`, with the author and committer dates set to `time`, in
+    /// seconds since the Unix epoch, and returns its output
+    fn git_at(&self, args: &[&str], time: Option<i64>) -> String {
+        let mut command = Command::new("git");
+        command.args(args).current_dir(&self.path);
+        if let Some(time) = time {
+            let date = format!("{time} +0000");
+            command
+                .env("GIT_AUTHOR_DATE", &date)
+                .env("GIT_COMMITTER_DATE", &date);
+        }
+        let output = command.output().unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    fn write(&self, path: &str, content: &str) {
+        let path = self.path.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn commit(&self, message: &str) {
+        self.git(&["add", "--all"]);
+        self.git(&["commit", "--quiet", "--message", message]);
+    }
+
+    /// Creates a commit of the tree of `HEAD` with the given `parents`, at `time`
+    fn commit_tree(&self, parents: &[&str], time: i64) -> String {
+        let mut args = vec!["commit-tree", "HEAD^{tree}", "-m", "commit"];
+        for parent in parents {
+            args.extend(["-p", parent]);
+        }
+        self.git_at(&args, Some(time))
+    }
+
+    /// Returns the best common ancestors of `first` and `second` computed by `git`
+    fn git_merge_bases(&self, first: &str, second: &str) -> Vec<ObjectId> {
+        let mut ids: Vec<_> = self
+            .git(&["merge-base", "--all", first, second])
+            .lines()
+            .map(|line| ObjectId::from_hex(line).unwrap())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    fn open(&self) -> Repository {
+        Repository::discover(&self.path).unwrap()
+    }
+}
+
+impl Drop for TestRepository {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+const LONG_FILE: &str =
+    "function first() {}\nfunction second() {}\nfunction third() {}\nfunction fourth() {}\n";
+
+#[test]
+fn changed_files_since_branch() {
+    let Some(repository) = TestRepository::new("changed_files") else {
+        return;
+    };
+    repository.write(".gitignore", "ignored.js\n");
+    repository.write("committed.js", "a\n");
+    repository.write("modified.js", "b\n");
+    repository.write("moved.js", LONG_FILE);
+    repository.write("unchanged.js", "c\n");
+    repository.commit("initial");
+
+    repository.git(&["checkout", "--quiet", "-b", "feature"]);
+    repository.write("committed.js", "a\nb\n");
+    repository.write("added/file.js", "e\n");
+    fs::rename(
+        repository.path.join("moved.js"),
+        repository.path.join("renamed.js"),
+    )
+    .unwrap();
+    repository.write(
+        "renamed.js",
+        &LONG_FILE.replace("fourth() {}", "fourth() { return 4; }"),
+    );
+    repository.commit("feature");
+
+    // Changes made on the base branch after forking aren't reported
+    repository.git(&["checkout", "--quiet", "main"]);
+    repository.write("unchanged.js", "d\n");
+    repository.commit("main");
+    repository.git(&["checkout", "--quiet", "feature"]);
+
+    // Neither are the changes that aren't committed
+    repository.write("modified.js", "b\nc\n");
+    repository.write("untracked.js", "f\n");
+    repository.write("ignored.js", "g\n");
+
+    let changes = repository.open().changed_files("main").unwrap();
+    let changes: Vec<_> = changes
+        .iter()
+        .map(|change| (change.path.as_str(), &change.kind))
+        .collect();
+    assert_eq!(
+        changes,
+        [
+            ("added/file.js", &ChangeKind::Added),
+            ("committed.js", &ChangeKind::Modified),
+            (
+                "renamed.js",
+                &ChangeKind::Renamed {
+                    from: "moved.js".to_string()
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn staged_files() {
+    let Some(repository) = TestRepository::new("staged_files") else {
+        return;
+    };
+    repository.write("modified.js", "a\n");
+    repository.write("moved.js", LONG_FILE);
+    repository.write("unstaged.js", "b\n");
+    repository.commit("initial");
+
+    repository.write("modified.js", "a\nb\n");
+    repository.write("added.js", "c\n");
+    repository.git(&["mv", "moved.js", "renamed.js"]);
+    repository.git(&["add", "modified.js", "added.js"]);
+    repository.write("unstaged.js", "d\n");
+
+    let changes = repository.open().staged_files().unwrap();
+    let changes: Vec<_> = changes
+        .iter()
+        .map(|change| (change.path.as_str(), &change.kind))
+        .collect();
+    assert_eq!(
+        changes,
+        [
+            ("added.js", &ChangeKind::Added),
+            ("modified.js", &ChangeKind::Modified),
+            (
+                "renamed.js",
+                &ChangeKind::Renamed {
+                    from: "moved.js".to_string()
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn staged_files_before_first_commit() {
+    let Some(repository) = TestRepository::new("first_commit") else {
+        return;
+    };
+    repository.write("file.js", "a\n");
+    repository.git(&["add", "file.js"]);
+
+    let changes = repository.open().staged_files().unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].path, "file.js");
+    assert_eq!(changes[0].kind, ChangeKind::Added);
+}
+
+#[test]
+fn unknown_revision() {
+    let Some(repository) = TestRepository::new("unknown_revision") else {
+        return;
+    };
+    repository.write("file.js", "a\n");
+    repository.commit("initial");
+
+    let error = repository.open().changed_files("missing").unwrap_err();
+    assert!(
+        matches!(&error, GitError::UnknownRevision(revision) if revision == "missing"),
+        "{error}"
+    );
+}
+
+#[test]
+fn packed_repository() {
+    let Some(repository) = TestRepository::new("packed") else {
+        return;
+    };
+    repository.write("first.js", "a\n");
+    repository.commit("first");
+    repository.git(&["tag", "--annotate", "--message", "v1", "v1"]);
+    repository.write("second.js", "b\n");
+    repository.commit("second");
+    repository.write("third.js", "c\n");
+    repository.commit("third");
+    repository.git(&["gc", "--quiet", "--aggressive"]);
+    assert!(!repository.path.join(".git/refs/tags/v1").exists());
+
+    let opened = repository.open();
+    let paths = |base: &str| -> Vec<String> {
+        opened
+            .changed_files(base)
+            .unwrap()
+            .into_iter()
+            .map(|change| change.path)
+            .collect()
+    };
+    assert_eq!(paths("v1"), ["second.js", "third.js"]);
+    assert_eq!(paths("HEAD~1"), ["third.js"]);
+    assert_eq!(paths("main^"), ["third.js"]);
+    assert!(paths("HEAD").is_empty());
+}
+
+#[test]
+fn changed_lines() {
+    let Some(repository) = TestRepository::new("changed_lines") else {
+        return;
+    };
+    repository.write("file.js", "a\nb\nc\nd\n");
+    repository.commit("initial");
+    repository.write("file.js", "a\nB\nc\nd\ne\n");
+    repository.write("added.js", "a\nb\n");
+    repository.commit("second");
+
+    let opened = repository.open();
+    let changes = opened.changed_files("HEAD~1").unwrap();
+    let lines: Vec<_> = changes
+        .iter()
+        .map(|change| (change.path.as_str(), opened.changed_lines(change).unwrap()))
+        .collect();
+    assert_eq!(
+        lines,
+        [("added.js", vec![1..=2]), ("file.js", vec![2..=2, 5..=5])]
+    );
+}
+
+#[test]
+fn paths_relative_to_subdirectory() {
+    let Some(repository) = TestRepository::new("subdirectory") else {
+        return;
+    };
+    repository.write("root.js", "a\n");
+    repository.write("packages/app/index.js", "b\n");
+    repository.commit("initial");
+    repository.write("root.js", "c\n");
+    repository.write("packages/app/index.js", "d\n");
+    repository.commit("second");
+
+    let opened = Repository::discover(&repository.path.join("packages")).unwrap();
+    let changes = opened.changed_files("HEAD~1").unwrap();
+    let paths: Vec<_> = changes.iter().map(|change| change.path.as_str()).collect();
+    assert_eq!(paths, ["app/index.js"]);
+}
+
+#[test]
+fn merge_bases_of_criss_cross_merges() {
+    let Some(repository) = TestRepository::new("criss_cross") else {
+        return;
+    };
+    repository.write("file.js", "a\n");
+    repository.commit("initial");
+    repository.git(&["checkout", "--quiet", "-b", "left"]);
+    repository.write("left.js", "b\n");
+    repository.commit("left");
+    repository.git(&["checkout", "--quiet", "-b", "right", "main"]);
+    repository.write("right.js", "c\n");
+    repository.commit("right");
+
+    // Each branch merges the other one, before it merged the first one
+    repository.git(&["checkout", "--quiet", "left"]);
+    repository.git(&["merge", "--quiet", "--no-edit", "right"]);
+    repository.git(&["checkout", "--quiet", "right"]);
+    repository.git(&["merge", "--quiet", "--no-edit", "left~1"]);
+    repository.write("right.js", "d\n");
+    repository.commit("right again");
+
+    let opened = repository.open();
+    let left = opened.resolve_revision("left").unwrap();
+    let right = opened.resolve_revision("right").unwrap();
+    let mut merge_bases = opened.merge_bases(left, right).unwrap();
+    merge_bases.sort();
+    assert_eq!(merge_bases.len(), 2);
+    assert_eq!(merge_bases, repository.git_merge_bases("left", "right"));
+}
+
+#[test]
+fn merge_bases_with_wrong_commit_times() {
+    let Some(repository) = TestRepository::new("commit_times") else {
+        return;
+    };
+    repository.write("file.js", "a\n");
+    repository.commit("initial");
+
+    // The common ancestor `base` is older than its parent `skewed`, which both sides
+    // merge too, so `skewed` is visited first
+    let skewed = repository.commit_tree(&[], 2_000_000_000);
+    let base = repository.commit_tree(&[&skewed], 1_000_000_000);
+    let first = repository.commit_tree(&[&base, &skewed], 1_000_000_010);
+    let second = repository.commit_tree(&[&base, &skewed], 1_000_000_020);
+
+    let opened = repository.open();
+    let merge_bases = opened
+        .merge_bases(
+            opened.resolve_revision(&first).unwrap(),
+            opened.resolve_revision(&second).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(merge_bases, [ObjectId::from_hex(&base).unwrap()]);
+    assert_eq!(merge_bases, repository.git_merge_bases(&first, &second));
+}
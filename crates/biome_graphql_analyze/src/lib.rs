@@ -3,21 +3,24 @@
 mod lint;
 pub mod options;
 mod registry;
+mod services;
 mod suppression_action;
+mod utils;
 
 pub use crate::registry::visit_registry;
 use crate::suppression_action::GraphqlSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, AnalyzerSuppression, ControlFlow,
-    LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
-    to_analyzer_suppressions,
+    AnalysisFilter, AnalyzerOptions, AnalyzerPluginSlice, AnalyzerSignal, AnalyzerSuppression,
+    ControlFlow, LanguageRoot, MatchQueryParams, MetadataRegistry, PluginTargetLanguage,
+    RuleAction, RuleRegistry, to_analyzer_suppressions,
 };
 use biome_deserialize::TextRange;
 use biome_diagnostics::Error;
+use biome_graphql_semantic::GraphqlSchema;
 use biome_graphql_syntax::GraphqlLanguage;
 use biome_suppression::{SuppressionDiagnostic, parse_suppression_comment};
 use std::ops::Deref;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 
 pub(crate) type GraphqlRuleAction = RuleAction<GraphqlLanguage>;
 
@@ -27,6 +30,19 @@ pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
     metadata
 });
 
+#[derive(Debug, Clone, Default)]
+pub struct GraphqlAnalyzerServices {
+    schema: Option<Arc<GraphqlSchema>>,
+}
+
+impl GraphqlAnalyzerServices {
+    /// The schema of the project, which executable documents are validated against.
+    pub fn with_schema(mut self, schema: Option<Arc<GraphqlSchema>>) -> Self {
+        self.schema = schema;
+        self
+    }
+}
+
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
 /// then call `emit_signal` when an analysis rule emits a diagnostic or action
@@ -34,13 +50,23 @@ pub fn analyze<'a, F, B>(
     root: &LanguageRoot<GraphqlLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    plugins: AnalyzerPluginSlice<'a>,
+    services: GraphqlAnalyzerServices,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<GraphqlLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, emit_signal)
+    analyze_with_inspect_matcher(
+        root,
+        filter,
+        |_| {},
+        options,
+        plugins,
+        services,
+        emit_signal,
+    )
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -54,6 +80,8 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    plugins: AnalyzerPluginSlice<'a>,
+    services: GraphqlAnalyzerServices,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -90,7 +118,9 @@ where
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
-    let (registry, services, diagnostics, visitors, categories) = registry.build();
+    let GraphqlAnalyzerServices { schema } = services;
+
+    let (registry, mut services, diagnostics, visitors, categories) = registry.build();
 
     // Bail if we can't parse a rule option
     if !diagnostics.is_empty() {
@@ -106,10 +136,14 @@ where
         categories,
     );
 
+    analyzer.add_plugins_for_language(plugins, PluginTargetLanguage::Graphql);
+
     for ((phase, _), visitor) in visitors {
         analyzer.add_visitor(phase, visitor);
     }
 
+    services.insert_service(schema);
+
     (
         analyzer.run(biome_analyze::AnalyzerContext {
             root: root.clone(),
@@ -159,6 +193,8 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            &[],
+            Default::default(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
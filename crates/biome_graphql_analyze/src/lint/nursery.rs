@@ -3,6 +3,13 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
 use biome_analyze::declare_lint_group;
+pub mod no_invalid_arguments;
+pub mod no_invalid_fragment_types;
+pub mod no_undefined_variables;
+pub mod no_unknown_directives;
+pub mod no_unknown_fields;
+pub mod no_unknown_types;
+pub mod no_unused_variable_definitions;
 pub mod use_named_operation;
 pub mod use_naming_convention;
-declare_lint_group! { pub Nursery { name : "nursery" , rules : [self :: use_named_operation :: UseNamedOperation , self :: use_naming_convention :: UseNamingConvention ,] } }
+declare_lint_group! { pub Nursery { name : "nursery" , rules : [self :: no_invalid_arguments :: NoInvalidArguments , self :: no_invalid_fragment_types :: NoInvalidFragmentTypes , self :: no_undefined_variables :: NoUndefinedVariables , self :: no_unknown_directives :: NoUnknownDirectives , self :: no_unknown_fields :: NoUnknownFields , self :: no_unknown_types :: NoUnknownTypes , self :: no_unused_variable_definitions :: NoUnusedVariableDefinitions , self :: use_named_operation :: UseNamedOperation , self :: use_naming_convention :: UseNamingConvention ,] } }
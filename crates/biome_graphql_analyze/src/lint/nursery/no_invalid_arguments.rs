@@ -0,0 +1,603 @@
+use biome_analyze::{
+    Rule, RuleDiagnostic, RuleDomain, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_graphql_semantic::{
+    GraphqlSchema, InputValueDefinition, SchemaLocation, TypeKind, TypeReference,
+    is_in_executable_definition,
+};
+use biome_graphql_syntax::{
+    AnyGraphqlValue, GraphqlArguments, GraphqlDirective, GraphqlField, GraphqlOperationDefinition,
+    GraphqlVariableReference,
+};
+use biome_rowan::{AstNode, AstNodeList, TextRange, declare_node_union};
+
+use crate::services::schema::WithSchema;
+use crate::utils::with_schema_location;
+
+declare_lint_rule! {
+    /// Disallow arguments that don't match their definition in the schema.
+    ///
+    /// This rule checks the arguments of fields and directives:
+    /// - the arguments must be defined by the field or the directive;
+    /// - the arguments that are non-null and don't have a default value must be provided;
+    /// - literal values must be valid for the type of the argument, including the fields of input objects and the values of enums;
+    /// - variables must have a type that is compatible with the type of the argument.
+    ///   A nullable variable can only be passed to a non-null argument when either the variable or the argument has a default value.
+    ///
+    /// This rule requires the schema of the project, configured with `graphql.schema`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query {
+    ///   user(id: ID!): User
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    /// }
+    ///
+    /// query User {
+    ///   user {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query {
+    ///   users(first: Int): [User]
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    /// }
+    ///
+    /// query Users {
+    ///   users(first: "ten") {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query {
+    ///   user(id: ID!): User
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    /// }
+    ///
+    /// query User($id: ID) {
+    ///   user(id: $id) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// type Query {
+    ///   user(id: ID!): User
+    ///   users(first: Int): [User]
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    /// }
+    ///
+    /// query Users($id: ID!) {
+    ///   user(id: $id) {
+    ///     name
+    ///   }
+    ///   users(first: 10) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoInvalidArguments {
+        version: "next",
+        name: "noInvalidArguments",
+        language: "graphql",
+        sources: &[
+            RuleSource::EslintGraphql("known-argument-names").same(),
+            RuleSource::EslintGraphql("provided-required-arguments").same(),
+            RuleSource::EslintGraphql("value-literals-of-correct-type").same(),
+            RuleSource::EslintGraphql("variables-in-allowed-position").same(),
+        ],
+        recommended: false,
+        severity: Severity::Error,
+        domains: &[RuleDomain::Project],
+    }
+}
+
+declare_node_union! {
+    pub AnyGraphqlArgumentsOwner = GraphqlField | GraphqlDirective
+}
+
+pub enum InvalidArgument {
+    /// The argument isn't defined by the field or the directive
+    Unknown {
+        range: TextRange,
+        name: String,
+        owner_kind: &'static str,
+        owner: String,
+        location: Option<SchemaLocation>,
+    },
+    /// A required argument isn't provided
+    Missing {
+        range: TextRange,
+        name: String,
+        ty: String,
+        owner_kind: &'static str,
+        owner: String,
+        location: Option<SchemaLocation>,
+    },
+    /// A literal value isn't valid for its type
+    InvalidValue {
+        range: TextRange,
+        ty: String,
+        input_name: String,
+        location: Option<SchemaLocation>,
+    },
+    /// An input object value has a field that isn't defined by its type
+    UnknownInputField {
+        range: TextRange,
+        name: String,
+        ty: String,
+        location: Option<SchemaLocation>,
+    },
+    /// An input object value lacks a required field
+    MissingInputField {
+        range: TextRange,
+        name: String,
+        ty: String,
+        location: Option<SchemaLocation>,
+    },
+    /// The type of a variable isn't compatible with the expected type
+    InvalidVariable {
+        range: TextRange,
+        name: String,
+        variable_ty: String,
+        ty: String,
+        definition: TextRange,
+        input_name: String,
+        location: Option<SchemaLocation>,
+    },
+}
+
+impl Rule for NoInvalidArguments {
+    type Query = WithSchema<AnyGraphqlArgumentsOwner>;
+    type State = InvalidArgument;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let Some(schema) = ctx.schema() else {
+            return Box::default();
+        };
+        if !is_in_executable_definition(node.syntax()) {
+            return Box::default();
+        }
+        let Some((owner_kind, owner, range, arguments, definitions, owner_location)) =
+            resolve_owner(schema, node)
+        else {
+            return Box::default();
+        };
+
+        let mut signals = Vec::new();
+        let provided_arguments = arguments
+            .as_ref()
+            .map(GraphqlArguments::arguments)
+            .into_iter()
+            .flat_map(|arguments| arguments.iter());
+        let mut provided_names = Vec::new();
+        for argument in provided_arguments {
+            let Some(name) = argument
+                .name()
+                .ok()
+                .and_then(|name| name.value_token().ok())
+            else {
+                continue;
+            };
+            let name = name.text_trimmed();
+            provided_names.push(name.to_string());
+            let Some(definition) = definitions
+                .iter()
+                .find(|definition| definition.name() == name)
+            else {
+                signals.push(InvalidArgument::Unknown {
+                    range: argument
+                        .name()
+                        .map_or(argument.range(), |name| name.range()),
+                    name: name.to_string(),
+                    owner_kind,
+                    owner: owner.clone(),
+                    location: owner_location.clone(),
+                });
+                continue;
+            };
+            if let Ok(value) = argument.value() {
+                signals.extend(check_value(
+                    schema,
+                    &value,
+                    definition.ty(),
+                    definition,
+                    definition.has_default(),
+                ));
+            }
+        }
+
+        for definition in definitions {
+            if definition.is_required()
+                && !provided_names.iter().any(|name| name == definition.name())
+            {
+                signals.push(InvalidArgument::Missing {
+                    range,
+                    name: definition.name().to_string(),
+                    ty: definition.ty().to_string(),
+                    owner_kind,
+                    owner: owner.clone(),
+                    location: definition.location().cloned(),
+                });
+            }
+        }
+
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            InvalidArgument::Unknown {
+                range,
+                name,
+                owner_kind,
+                owner,
+                location,
+            } => with_schema_location(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "The argument "<Emphasis>{name}</Emphasis>" doesn't exist on the "{owner_kind}" "<Emphasis>{owner}</Emphasis>"."
+                    },
+                )
+                .note(markup! {
+                    "Remove the argument, or add it to the schema."
+                }),
+                ctx.file_path(),
+                location.as_ref(),
+                markup! { "The "{owner_kind}" "<Emphasis>{owner}</Emphasis> },
+            ),
+            InvalidArgument::Missing {
+                range,
+                name,
+                ty,
+                owner_kind,
+                owner,
+                location,
+            } => with_schema_location(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "The required argument "<Emphasis>{name}</Emphasis>" of the "{owner_kind}" "<Emphasis>{owner}</Emphasis>" isn't provided."
+                    },
+                )
+                .note(markup! {
+                    "Provide a value of type "<Emphasis>{ty}</Emphasis>" for the argument."
+                }),
+                ctx.file_path(),
+                location.as_ref(),
+                markup! { "The argument "<Emphasis>{name}</Emphasis> },
+            ),
+            InvalidArgument::InvalidValue {
+                range,
+                ty,
+                input_name,
+                location,
+            } => with_schema_location(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "This value isn't a valid "<Emphasis>{ty}</Emphasis>"."
+                    },
+                ),
+                ctx.file_path(),
+                location.as_ref(),
+                markup! { "The input value "<Emphasis>{input_name}</Emphasis> },
+            ),
+            InvalidArgument::UnknownInputField {
+                range,
+                name,
+                ty,
+                location,
+            } => with_schema_location(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "The field "<Emphasis>{name}</Emphasis>" doesn't exist on the input object type "<Emphasis>{ty}</Emphasis>"."
+                    },
+                ),
+                ctx.file_path(),
+                location.as_ref(),
+                markup! { "The input object type "<Emphasis>{ty}</Emphasis> },
+            ),
+            InvalidArgument::MissingInputField {
+                range,
+                name,
+                ty,
+                location,
+            } => with_schema_location(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "The required field "<Emphasis>{name}</Emphasis>" of the input object type "<Emphasis>{ty}</Emphasis>" isn't provided."
+                    },
+                ),
+                ctx.file_path(),
+                location.as_ref(),
+                markup! { "The field "<Emphasis>{name}</Emphasis> },
+            ),
+            InvalidArgument::InvalidVariable {
+                range,
+                name,
+                variable_ty,
+                ty,
+                definition,
+                input_name,
+                location,
+            } => with_schema_location(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "The variable "<Emphasis>"$"{name}</Emphasis>" of type "<Emphasis>{variable_ty}</Emphasis>" can't be used where a value of type "<Emphasis>{ty}</Emphasis>" is expected."
+                    },
+                )
+                .detail(
+                    definition,
+                    markup! { "The variable is defined here." },
+                )
+                .note(markup! {
+                    "Change the type of the variable, or give it a default value if only its nullability differs."
+                }),
+                ctx.file_path(),
+                location.as_ref(),
+                markup! { "The input value "<Emphasis>{input_name}</Emphasis> },
+            ),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns the kind, the name, the range of the name, the arguments and the
+/// argument definitions of a field or a directive, along with the location of
+/// its definition.
+#[expect(clippy::type_complexity)]
+fn resolve_owner<'a>(
+    schema: &'a GraphqlSchema,
+    node: &AnyGraphqlArgumentsOwner,
+) -> Option<(
+    &'static str,
+    String,
+    TextRange,
+    Option<GraphqlArguments>,
+    &'a [InputValueDefinition],
+    Option<SchemaLocation>,
+)> {
+    match node {
+        AnyGraphqlArgumentsOwner::GraphqlField(field) => {
+            let (parent_type, definition) = schema.field_definition(field)?;
+            Some((
+                "field",
+                format!("{}.{}", parent_type.name(), definition.name()),
+                field.name().ok()?.range(),
+                field.arguments(),
+                definition.arguments(),
+                Some(definition.location().clone()),
+            ))
+        }
+        AnyGraphqlArgumentsOwner::GraphqlDirective(directive) => {
+            let name = directive.name().ok()?;
+            let definition = schema.get_directive(name.value_token().ok()?.text_trimmed())?;
+            Some((
+                "directive",
+                format!("@{}", definition.name()),
+                TextRange::new(
+                    directive.at_token().ok()?.text_trimmed_range().start(),
+                    name.range().end(),
+                ),
+                directive.arguments(),
+                definition.arguments(),
+                definition.location().cloned(),
+            ))
+        }
+    }
+}
+
+/// Checks that `value` is a valid value of type `expected`.
+///
+/// `input` is the argument or the input object field that receives `value`.
+/// `has_default` tells whether the position of `value` has a default value,
+/// which allows passing a nullable variable to a non-null input value.
+fn check_value(
+    schema: &GraphqlSchema,
+    value: &AnyGraphqlValue,
+    expected: &TypeReference,
+    input: &InputValueDefinition,
+    has_default: bool,
+) -> Option<InvalidArgument> {
+    let invalid_value = || InvalidArgument::InvalidValue {
+        range: value.range(),
+        ty: expected.to_string(),
+        input_name: input.name().to_string(),
+        location: input.location().cloned(),
+    };
+    match value {
+        AnyGraphqlValue::GraphqlVariableReference(variable) => {
+            check_variable(variable, expected, input, has_default)
+        }
+        AnyGraphqlValue::GraphqlBogusValue(_) | AnyGraphqlValue::GraphqlMetavariable(_) => None,
+        AnyGraphqlValue::GraphqlNullValue(_) => expected.is_non_null().then(invalid_value),
+        _ => match expected {
+            TypeReference::NonNull(ty) => check_value(schema, value, ty, input, false),
+            TypeReference::List(ty) => match value {
+                // A single value is coerced to a list of one element
+                AnyGraphqlValue::GraphqlListValue(list) => list
+                    .elements()
+                    .iter()
+                    .find_map(|element| check_value(schema, &element, ty, input, false)),
+                _ => check_value(schema, value, ty, input, false),
+            },
+            TypeReference::Named(name) => {
+                // Unknown types are reported by `noUnknownTypes`
+                let ty = schema.get_type(name)?;
+                let is_valid = match ty.kind() {
+                    TypeKind::Scalar => match name.as_str() {
+                        "Int" => value
+                            .as_graphql_int_value()
+                            .and_then(|value| value.graphql_int_literal_token().ok())
+                            .is_some_and(|token| token.text_trimmed().parse::<i32>().is_ok()),
+                        "Float" => matches!(
+                            value,
+                            AnyGraphqlValue::GraphqlIntValue(_)
+                                | AnyGraphqlValue::GraphqlFloatValue(_)
+                        ),
+                        "String" => matches!(value, AnyGraphqlValue::GraphqlStringValue(_)),
+                        "Boolean" => matches!(value, AnyGraphqlValue::GraphqlBooleanValue(_)),
+                        "ID" => matches!(
+                            value,
+                            AnyGraphqlValue::GraphqlIntValue(_)
+                                | AnyGraphqlValue::GraphqlStringValue(_)
+                        ),
+                        // Custom scalars define their own coercion rules
+                        _ => true,
+                    },
+                    TypeKind::Enum => value
+                        .as_graphql_enum_value()
+                        .and_then(|value| value.value().ok())
+                        .and_then(|value| value.value_token().ok())
+                        .is_some_and(|token| {
+                            ty.values()
+                                .iter()
+                                .any(|value| value == token.text_trimmed())
+                        }),
+                    TypeKind::InputObject => {
+                        let Some(object) = value.as_graphql_object_value() else {
+                            return Some(invalid_value());
+                        };
+                        let mut provided_names = Vec::new();
+                        for member in object.members() {
+                            let Some(member_name) =
+                                member.name().ok().and_then(|name| name.value_token().ok())
+                            else {
+                                continue;
+                            };
+                            let member_name = member_name.text_trimmed();
+                            provided_names.push(member_name.to_string());
+                            let Some(field) = ty.input_field(member_name) else {
+                                return Some(InvalidArgument::UnknownInputField {
+                                    range: member
+                                        .name()
+                                        .map_or(member.range(), |name| name.range()),
+                                    name: member_name.to_string(),
+                                    ty: ty.name().to_string(),
+                                    location: ty.location().cloned(),
+                                });
+                            };
+                            if let Some(invalid) = member.value().ok().and_then(|value| {
+                                check_value(schema, &value, field.ty(), field, field.has_default())
+                            }) {
+                                return Some(invalid);
+                            }
+                        }
+                        return ty
+                            .input_fields()
+                            .iter()
+                            .find(|field| {
+                                field.is_required()
+                                    && !provided_names.iter().any(|name| name == field.name())
+                            })
+                            .map(|field| InvalidArgument::MissingInputField {
+                                range: value.range(),
+                                name: field.name().to_string(),
+                                ty: ty.name().to_string(),
+                                location: field.location().cloned(),
+                            });
+                    }
+                    // Output types can't be used for arguments
+                    TypeKind::Object | TypeKind::Interface | TypeKind::Union => true,
+                };
+                (!is_valid).then(invalid_value)
+            }
+        },
+    }
+}
+
+/// Checks that the type of `variable` can be used where a value of type
+/// `expected` is expected.
+fn check_variable(
+    variable: &GraphqlVariableReference,
+    expected: &TypeReference,
+    input: &InputValueDefinition,
+    has_default: bool,
+) -> Option<InvalidArgument> {
+    let name = variable.name().ok()?.value_token().ok()?;
+    let name = name.text_trimmed();
+    // Fragments can be spread in operations that define different variables
+    let operation = variable
+        .syntax()
+        .ancestors()
+        .find_map(GraphqlOperationDefinition::cast)?;
+    // Undefined variables are reported by `noUndefinedVariables`
+    let definition = operation
+        .variables()?
+        .elements()
+        .iter()
+        .find(|definition| {
+            definition
+                .variable()
+                .ok()
+                .and_then(|variable| variable.name().ok())
+                .and_then(|name| name.value_token().ok())
+                .is_some_and(|token| token.text_trimmed() == name)
+        })?;
+    let variable_ty = TypeReference::from_syntax(&definition.ty().ok()?)?;
+    let variable_has_default = definition
+        .default()
+        .and_then(|default| default.value().ok())
+        .is_some_and(|value| !matches!(value, AnyGraphqlValue::GraphqlNullValue(_)));
+
+    let is_compatible = if expected.is_non_null()
+        && !variable_ty.is_non_null()
+        && (has_default || variable_has_default)
+    {
+        variable_ty.is_compatible_with(expected.nullable())
+    } else {
+        variable_ty.is_compatible_with(expected)
+    };
+    if is_compatible {
+        return None;
+    }
+    Some(InvalidArgument::InvalidVariable {
+        range: variable.range(),
+        name: name.to_string(),
+        variable_ty: variable_ty.to_string(),
+        ty: expected.to_string(),
+        definition: definition.range(),
+        input_name: input.name().to_string(),
+        location: input.location().cloned(),
+    })
+}
@@ -0,0 +1,295 @@
+use biome_analyze::{
+    Rule, RuleDiagnostic, RuleDomain, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_graphql_semantic::{
+    GraphqlSchema, SchemaLocation, TypeDefinition, TypeKind, is_in_executable_definition,
+};
+use biome_graphql_syntax::{
+    GraphqlFragmentDefinition, GraphqlFragmentSpread, GraphqlInlineFragment, GraphqlRoot,
+    GraphqlTypeCondition,
+};
+use biome_rowan::{AstNode, TextRange, declare_node_union};
+
+use crate::services::schema::WithSchema;
+use crate::utils::with_schema_location;
+
+declare_lint_rule! {
+    /// Disallow fragments whose type condition can never match.
+    ///
+    /// Fragments can only be defined on object types, interfaces and unions, because only these types have fields to select.
+    /// Additionally, a fragment can only be spread in a selection set when the two types have at least one possible object type in common:
+    /// otherwise, the fields of the fragment would never be selected.
+    ///
+    /// Fragment spreads are checked against the fragment definitions of the same file.
+    ///
+    /// This rule requires the schema of the project, configured with `graphql.schema`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query {
+    ///   name: String
+    /// }
+    ///
+    /// fragment NameFields on String {
+    ///   length
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query {
+    ///   pet: Pet
+    /// }
+    ///
+    /// type Dog {
+    ///   name: String
+    /// }
+    ///
+    /// type Cat {
+    ///   name: String
+    /// }
+    ///
+    /// type Pet {
+    ///   name: String
+    /// }
+    ///
+    /// query Pet {
+    ///   pet {
+    ///     ... on Dog {
+    ///       name
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// type Query {
+    ///   pet: Pet
+    /// }
+    ///
+    /// type Dog {
+    ///   name: String
+    /// }
+    ///
+    /// type Cat {
+    ///   name: String
+    /// }
+    ///
+    /// union Pet = Dog | Cat
+    ///
+    /// query Pet {
+    ///   pet {
+    ///     ... on Dog {
+    ///       name
+    ///     }
+    ///     ...CatFields
+    ///   }
+    /// }
+    ///
+    /// fragment CatFields on Cat {
+    ///   name
+    /// }
+    /// ```
+    ///
+    pub NoInvalidFragmentTypes {
+        version: "next",
+        name: "noInvalidFragmentTypes",
+        language: "graphql",
+        sources: &[
+            RuleSource::EslintGraphql("fragments-on-composite-type").same(),
+            RuleSource::EslintGraphql("possible-fragment-spread").same(),
+        ],
+        recommended: false,
+        severity: Severity::Error,
+        domains: &[RuleDomain::Project],
+    }
+}
+
+declare_node_union! {
+    pub AnyGraphqlFragment = GraphqlFragmentDefinition | GraphqlInlineFragment | GraphqlFragmentSpread
+}
+
+pub enum InvalidFragmentType {
+    /// The type condition isn't an object type, an interface or a union
+    NotComposite {
+        range: TextRange,
+        type_name: String,
+        type_kind: TypeKind,
+        location: Option<SchemaLocation>,
+    },
+    /// The type condition has no possible type in common with the type of
+    /// the selection set
+    Impossible {
+        range: TextRange,
+        type_name: String,
+        parent_type_name: String,
+        parent_type_kind: TypeKind,
+        parent_location: Option<SchemaLocation>,
+        /// The type condition of the spread fragment
+        fragment_type_condition: Option<TextRange>,
+    },
+}
+
+impl Rule for NoInvalidFragmentTypes {
+    type Query = WithSchema<AnyGraphqlFragment>;
+    type State = InvalidFragmentType;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let node = ctx.query();
+        let schema = ctx.schema()?;
+        if !is_in_executable_definition(node.syntax()) {
+            return None;
+        }
+        match node {
+            AnyGraphqlFragment::GraphqlFragmentDefinition(fragment) => {
+                check_composite_type(schema, &fragment.type_condition().ok()?)
+            }
+            AnyGraphqlFragment::GraphqlInlineFragment(fragment) => {
+                let type_condition = fragment.type_condition()?;
+                if let Some(state) = check_composite_type(schema, &type_condition) {
+                    return Some(state);
+                }
+                let fragment_type = type_of_condition(schema, &type_condition)?;
+                check_possible_spread(schema, node, fragment_type, type_condition.range(), None)
+            }
+            AnyGraphqlFragment::GraphqlFragmentSpread(spread) => {
+                let name = spread.name().ok()?.value_token().ok()?;
+                let fragment = find_fragment_definition(&ctx.root(), name.text_trimmed())?;
+                let type_condition = fragment.type_condition().ok()?;
+                let fragment_type = type_of_condition(schema, &type_condition)?;
+                if !fragment_type.kind().is_composite() {
+                    // Reported on the fragment definition
+                    return None;
+                }
+                check_possible_spread(
+                    schema,
+                    node,
+                    fragment_type,
+                    spread.range(),
+                    Some(type_condition.range()),
+                )
+            }
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        match state {
+            InvalidFragmentType::NotComposite {
+                range,
+                type_name,
+                type_kind,
+                location,
+            } => {
+                let diagnostic = RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "Fragments can't be defined on the "{type_kind.to_string()}" "<Emphasis>{type_name}</Emphasis>"."
+                    },
+                )
+                .note(markup! {
+                    "Fragments can only be defined on object types, interfaces and unions, because only these types have fields to select."
+                });
+                Some(with_schema_location(
+                    diagnostic,
+                    ctx.file_path(),
+                    location.as_ref(),
+                    markup! { "The "{type_kind.to_string()}" "<Emphasis>{type_name}</Emphasis> },
+                ))
+            }
+            InvalidFragmentType::Impossible {
+                range,
+                type_name,
+                parent_type_name,
+                parent_type_kind,
+                parent_location,
+                fragment_type_condition,
+            } => {
+                let mut diagnostic = RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "This fragment can never match, because values of type "<Emphasis>{parent_type_name}</Emphasis>" can never be of type "<Emphasis>{type_name}</Emphasis>"."
+                    },
+                );
+                if let Some(fragment_type_condition) = fragment_type_condition {
+                    diagnostic = diagnostic.detail(
+                        fragment_type_condition,
+                        markup! { "The fragment is defined on "<Emphasis>{type_name}</Emphasis>" here." },
+                    );
+                }
+                let diagnostic = diagnostic.note(markup! {
+                    "Remove the fragment, or use a type condition that has a possible type in common with the "{parent_type_kind.to_string()}" "<Emphasis>{parent_type_name}</Emphasis>"."
+                });
+                Some(with_schema_location(
+                    diagnostic,
+                    ctx.file_path(),
+                    parent_location.as_ref(),
+                    markup! { "The "{parent_type_kind.to_string()}" "<Emphasis>{parent_type_name}</Emphasis> },
+                ))
+            }
+        }
+    }
+}
+
+fn type_of_condition<'a>(
+    schema: &'a GraphqlSchema,
+    type_condition: &GraphqlTypeCondition,
+) -> Option<&'a TypeDefinition> {
+    let name = type_condition.ty().ok()?.value_token().ok()?;
+    schema.get_type(name.text_trimmed())
+}
+
+fn check_composite_type(
+    schema: &GraphqlSchema,
+    type_condition: &GraphqlTypeCondition,
+) -> Option<InvalidFragmentType> {
+    // Unknown types are reported by `noUnknownTypes`
+    let ty = type_of_condition(schema, type_condition)?;
+    if ty.kind().is_composite() {
+        return None;
+    }
+    Some(InvalidFragmentType::NotComposite {
+        range: type_condition.ty().ok()?.range(),
+        type_name: ty.name().to_string(),
+        type_kind: ty.kind(),
+        location: ty.location().cloned(),
+    })
+}
+
+fn check_possible_spread(
+    schema: &GraphqlSchema,
+    node: &AnyGraphqlFragment,
+    fragment_type: &TypeDefinition,
+    range: TextRange,
+    fragment_type_condition: Option<TextRange>,
+) -> Option<InvalidFragmentType> {
+    let parent_type = schema.type_of_parent_selection_set(node.syntax())?;
+    if !parent_type.kind().is_composite() || schema.can_spread(parent_type, fragment_type) {
+        return None;
+    }
+    Some(InvalidFragmentType::Impossible {
+        range,
+        type_name: fragment_type.name().to_string(),
+        parent_type_name: parent_type.name().to_string(),
+        parent_type_kind: parent_type.kind(),
+        parent_location: parent_type.location().cloned(),
+        fragment_type_condition,
+    })
+}
+
+fn find_fragment_definition(root: &GraphqlRoot, name: &str) -> Option<GraphqlFragmentDefinition> {
+    root.definitions().into_iter().find_map(|definition| {
+        let fragment = definition.as_graphql_fragment_definition()?;
+        let fragment_name = fragment.name().ok()?.value_token().ok()?;
+        (fragment_name.text_trimmed() == name).then(|| fragment.clone())
+    })
+}
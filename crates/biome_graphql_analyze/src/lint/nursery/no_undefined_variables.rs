@@ -0,0 +1,137 @@
+use biome_analyze::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_rowan::{AstNode, TextRange};
+
+use crate::services::semantic::SemanticServices;
+
+declare_lint_rule! {
+    /// Disallow using variables that aren't defined by the operation.
+    ///
+    /// Every variable used by an operation, either directly or through the fragments that it spreads, must be defined by the operation.
+    ///
+    /// Variables used by fragments are only checked when the fragment is spread by an operation of the same file.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query User {
+    ///   user(id: $id) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query User {
+    ///   ...UserFields
+    /// }
+    ///
+    /// fragment UserFields on Query {
+    ///   user(id: $id) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query User($id: ID!) {
+    ///   ...UserFields
+    /// }
+    ///
+    /// fragment UserFields on Query {
+    ///   user(id: $id) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoUndefinedVariables {
+        version: "next",
+        name: "noUndefinedVariables",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("no-undefined-variables").same()],
+        recommended: false,
+        severity: Severity::Error,
+    }
+}
+
+pub struct UndefinedVariable {
+    range: TextRange,
+    name: String,
+    /// The range of the name of the operation, or of its type for anonymous
+    /// operations
+    operation_range: TextRange,
+    operation_name: Option<String>,
+}
+
+impl Rule for NoUndefinedVariables {
+    type Query = SemanticServices;
+    type State = UndefinedVariable;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.model();
+        model
+            .all_unresolved_variable_references()
+            .filter_map(|reference| {
+                // Variables of fragments that aren't spread by any operation
+                // may be defined by operations of other files
+                let operation = reference.referenced_operation()?;
+                let variable = reference.tree();
+                let name = variable.name().ok()?.value_token().ok()?;
+                let operation_name = operation
+                    .name()
+                    .and_then(|name| name.value_token().ok())
+                    .map(|name| name.text_trimmed().to_string());
+                let operation_range = match operation.name() {
+                    Some(name) => name.range(),
+                    None => operation.ty().ok()?.range(),
+                };
+                Some(UndefinedVariable {
+                    range: variable.range(),
+                    name: name.text_trimmed().to_string(),
+                    operation_range,
+                    operation_name,
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UndefinedVariable {
+            range,
+            name,
+            operation_range,
+            operation_name,
+        } = state;
+        let diagnostic = match operation_name {
+            Some(operation_name) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The variable "<Emphasis>"$"{name}</Emphasis>" isn't defined by the operation "<Emphasis>{operation_name}</Emphasis>"."
+                },
+            ),
+            None => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The variable "<Emphasis>"$"{name}</Emphasis>" isn't defined by the operation."
+                },
+            ),
+        };
+        Some(
+            diagnostic
+                .detail(operation_range, markup! { "The operation is defined here." })
+                .note(markup! {
+                    "Define the variable in the variable definitions of the operation, or remove its usage."
+                }),
+        )
+    }
+}
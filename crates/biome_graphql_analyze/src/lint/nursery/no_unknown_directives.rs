@@ -0,0 +1,183 @@
+use biome_analyze::{
+    Rule, RuleDiagnostic, RuleDomain, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_graphql_semantic::{OperationKind, SchemaLocation, is_in_executable_definition};
+use biome_graphql_syntax::{GraphqlDirective, GraphqlOperationDefinition, GraphqlSyntaxKind};
+use biome_rowan::{AstNode, SyntaxNodeCast, TextRange};
+
+use crate::services::schema::WithSchema;
+use crate::utils::with_schema_location;
+
+declare_lint_rule! {
+    /// Disallow using directives that aren't defined by the schema, or in locations where they aren't allowed.
+    ///
+    /// The directives used by operations and fragments must be defined by the schema, or be one of the built-in directives:
+    /// `@skip`, `@include`, `@deprecated` and `@specifiedBy`.
+    /// A directive can only be used in the locations listed by its definition, such as `FIELD` or `QUERY`.
+    ///
+    /// This rule requires the schema of the project, configured with `graphql.schema`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query {
+    ///   name: String
+    /// }
+    ///
+    /// query Name {
+    ///   name @uppercase
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query {
+    ///   name: String
+    /// }
+    ///
+    /// query Name @include(if: true) {
+    ///   name
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// directive @uppercase on FIELD
+    ///
+    /// type Query {
+    ///   name: String
+    /// }
+    ///
+    /// query Name($withName: Boolean!) {
+    ///   name @include(if: $withName) @uppercase
+    /// }
+    /// ```
+    ///
+    pub NoUnknownDirectives {
+        version: "next",
+        name: "noUnknownDirectives",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("known-directives").same()],
+        recommended: false,
+        severity: Severity::Error,
+        domains: &[RuleDomain::Project],
+    }
+}
+
+pub enum InvalidDirective {
+    /// The directive isn't defined by the schema
+    Unknown,
+    /// The directive isn't allowed at the location where it's used
+    Misplaced {
+        location: &'static str,
+        allowed_locations: Vec<String>,
+        definition: Option<SchemaLocation>,
+    },
+}
+
+impl Rule for NoUnknownDirectives {
+    type Query = WithSchema<GraphqlDirective>;
+    type State = InvalidDirective;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let node = ctx.query();
+        let schema = ctx.schema()?;
+        if !is_in_executable_definition(node.syntax()) {
+            return None;
+        }
+        let name = node.name().ok()?.value_token().ok()?;
+        let Some(directive) = schema.get_directive(name.text_trimmed()) else {
+            return Some(InvalidDirective::Unknown);
+        };
+        let location = directive_location(node)?;
+        if directive.is_allowed_on(location) {
+            return None;
+        }
+        Some(InvalidDirective::Misplaced {
+            location,
+            allowed_locations: directive.locations().to_vec(),
+            definition: directive.location().cloned(),
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let name = node.name().ok()?.value_token().ok()?;
+        let name = name.text_trimmed();
+        let range = directive_range(node)?;
+        match state {
+            InvalidDirective::Unknown => Some(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "The directive "<Emphasis>"@"{name}</Emphasis>" doesn't exist in the schema."
+                    },
+                )
+                .note(markup! {
+                    "Remove the directive, or add its definition to the schema."
+                }),
+            ),
+            InvalidDirective::Misplaced {
+                location,
+                allowed_locations,
+                definition,
+            } => {
+                let diagnostic = RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "The directive "<Emphasis>"@"{name}</Emphasis>" can't be used on "<Emphasis>{location}</Emphasis>"."
+                    },
+                )
+                .note(markup! {
+                    "The directive can only be used on "<Emphasis>{allowed_locations.join(", ")}</Emphasis>"."
+                });
+                Some(with_schema_location(
+                    diagnostic,
+                    ctx.file_path(),
+                    definition.as_ref(),
+                    markup! { "The directive "<Emphasis>"@"{name}</Emphasis> },
+                ))
+            }
+        }
+    }
+}
+
+/// Returns the range of the directive, from `@` to the end of its name.
+fn directive_range(directive: &GraphqlDirective) -> Option<TextRange> {
+    Some(TextRange::new(
+        directive.at_token().ok()?.text_trimmed_range().start(),
+        directive.name().ok()?.range().end(),
+    ))
+}
+
+/// Returns the location of `directive`, as named in the `on` clause of
+/// directive definitions.
+fn directive_location(directive: &GraphqlDirective) -> Option<&'static str> {
+    let directive_list = directive.syntax().parent()?;
+    let owner = directive_list.parent()?;
+    let location = match owner.kind() {
+        GraphqlSyntaxKind::GRAPHQL_OPERATION_DEFINITION => {
+            let operation = owner.cast::<GraphqlOperationDefinition>()?;
+            match OperationKind::from_syntax(&operation.ty().ok()?)? {
+                OperationKind::Query => "QUERY",
+                OperationKind::Mutation => "MUTATION",
+                OperationKind::Subscription => "SUBSCRIPTION",
+            }
+        }
+        GraphqlSyntaxKind::GRAPHQL_FIELD => "FIELD",
+        GraphqlSyntaxKind::GRAPHQL_FRAGMENT_DEFINITION => "FRAGMENT_DEFINITION",
+        GraphqlSyntaxKind::GRAPHQL_FRAGMENT_SPREAD => "FRAGMENT_SPREAD",
+        GraphqlSyntaxKind::GRAPHQL_INLINE_FRAGMENT => "INLINE_FRAGMENT",
+        GraphqlSyntaxKind::GRAPHQL_VARIABLE_DEFINITION => "VARIABLE_DEFINITION",
+        _ => return None,
+    };
+    Some(location)
+}
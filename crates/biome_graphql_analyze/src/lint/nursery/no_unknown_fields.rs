@@ -0,0 +1,149 @@
+use biome_analyze::{
+    Rule, RuleDiagnostic, RuleDomain, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_graphql_semantic::{SchemaLocation, TypeKind, is_in_executable_definition};
+use biome_graphql_syntax::GraphqlField;
+use biome_rowan::{AstNode, TextRange};
+
+use crate::services::schema::WithSchema;
+use crate::utils::with_schema_location;
+
+declare_lint_rule! {
+    /// Disallow selecting fields that aren't defined by the schema.
+    ///
+    /// The fields selected by an operation or a fragment must be defined by the type of their selection set.
+    /// Unions don't define any field: the fields of their members must be selected with an inline fragment.
+    ///
+    /// This rule requires the schema of the project, configured with `graphql.schema`:
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "graphql": {
+    ///     "schema": ["schema/**/*.graphql"]
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query {
+    ///   hero: Character
+    /// }
+    ///
+    /// type Character {
+    ///   name: String
+    /// }
+    ///
+    /// query Hero {
+    ///   hero {
+    ///     age
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// type Query {
+    ///   hero: Character
+    /// }
+    ///
+    /// type Character {
+    ///   name: String
+    /// }
+    ///
+    /// query Hero {
+    ///   hero {
+    ///     name
+    ///     __typename
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoUnknownFields {
+        version: "next",
+        name: "noUnknownFields",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("fields-on-correct-type").same()],
+        recommended: false,
+        severity: Severity::Error,
+        domains: &[RuleDomain::Project],
+    }
+}
+
+pub struct UnknownField {
+    range: TextRange,
+    name: String,
+    type_name: String,
+    type_kind: TypeKind,
+    location: Option<SchemaLocation>,
+}
+
+impl Rule for NoUnknownFields {
+    type Query = WithSchema<GraphqlField>;
+    type State = UnknownField;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let node = ctx.query();
+        let schema = ctx.schema()?;
+        if !is_in_executable_definition(node.syntax()) {
+            return None;
+        }
+        let name = node.name().ok()?.value_token().ok()?;
+        let name = name.text_trimmed();
+        // Introspection fields are defined by the GraphQL specification
+        if name.starts_with("__") {
+            return None;
+        }
+        let parent_type = schema.type_of_parent_selection_set(node.syntax())?;
+        if !parent_type.kind().is_composite() || parent_type.field(name).is_some() {
+            return None;
+        }
+        Some(UnknownField {
+            range: node.name().ok()?.range(),
+            name: name.to_string(),
+            type_name: parent_type.name().to_string(),
+            type_kind: parent_type.kind(),
+            location: parent_type.location().cloned(),
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UnknownField {
+            range,
+            name,
+            type_name,
+            type_kind,
+            location,
+        } = state;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            range,
+            markup! {
+                "The field "<Emphasis>{name}</Emphasis>" doesn't exist on the "{type_kind.to_string()}" "<Emphasis>{type_name}</Emphasis>"."
+            },
+        );
+        let diagnostic = if *type_kind == TypeKind::Union {
+            diagnostic.note(markup! {
+                "Unions don't have fields. Select the fields of its members with inline fragments, such as "<Emphasis>"... on Member { "{name}" }"</Emphasis>"."
+            })
+        } else {
+            diagnostic.note(markup! {
+                "Select a field that is defined by the "{type_kind.to_string()}", or add the field to the schema."
+            })
+        };
+        Some(with_schema_location(
+            diagnostic,
+            ctx.file_path(),
+            location.as_ref(),
+            markup! { "The "{type_kind.to_string()}" "<Emphasis>{type_name}</Emphasis> },
+        ))
+    }
+}
@@ -0,0 +1,130 @@
+use biome_analyze::{
+    Rule, RuleDiagnostic, RuleDomain, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_graphql_semantic::is_in_executable_definition;
+use biome_graphql_syntax::{GraphqlNameReference, GraphqlSyntaxKind};
+use biome_rowan::{AstNode, TokenText};
+
+use crate::services::schema::WithSchema;
+
+declare_lint_rule! {
+    /// Disallow referencing types that aren't defined by the schema.
+    ///
+    /// The types of variables and the type conditions of fragments must be defined by the schema, or be one of the built-in scalars:
+    /// `Int`, `Float`, `String`, `Boolean` and `ID`.
+    ///
+    /// This rule requires the schema of the project, configured with `graphql.schema`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query {
+    ///   user(id: ID!): User
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    /// }
+    ///
+    /// query User($id: UserId!) {
+    ///   user(id: $id) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type Query {
+    ///   user(id: ID!): User
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    /// }
+    ///
+    /// fragment UserFields on Person {
+    ///   name
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// type Query {
+    ///   user(id: ID!): User
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    /// }
+    ///
+    /// query User($id: ID!) {
+    ///   user(id: $id) {
+    ///     ...UserFields
+    ///   }
+    /// }
+    ///
+    /// fragment UserFields on User {
+    ///   name
+    /// }
+    /// ```
+    ///
+    pub NoUnknownTypes {
+        version: "next",
+        name: "noUnknownTypes",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("known-type-names").same()],
+        recommended: false,
+        severity: Severity::Error,
+        domains: &[RuleDomain::Project],
+    }
+}
+
+impl Rule for NoUnknownTypes {
+    type Query = WithSchema<GraphqlNameReference>;
+    type State = TokenText;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let node = ctx.query();
+        let schema = ctx.schema()?;
+        // Directives and fragment spreads also reference a name
+        let parent = node.syntax().parent()?;
+        if !matches!(
+            parent.kind(),
+            GraphqlSyntaxKind::GRAPHQL_TYPE_CONDITION
+                | GraphqlSyntaxKind::GRAPHQL_VARIABLE_DEFINITION
+                | GraphqlSyntaxKind::GRAPHQL_LIST_TYPE
+                | GraphqlSyntaxKind::GRAPHQL_NON_NULL_TYPE
+        ) || !is_in_executable_definition(node.syntax())
+        {
+            return None;
+        }
+        let name = node.value_token().ok()?.token_text_trimmed();
+        if schema.get_type(&name).is_some() {
+            return None;
+        }
+        Some(name)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "The type "<Emphasis>{name.text()}</Emphasis>" doesn't exist in the schema."
+                },
+            )
+            .note(markup! {
+                "Use a type that is defined by the schema, or add the type to the schema."
+            }),
+        )
+    }
+}
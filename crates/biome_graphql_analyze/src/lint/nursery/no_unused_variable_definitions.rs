@@ -0,0 +1,121 @@
+use biome_analyze::{
+    FixKind, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_graphql_semantic::IsBindingAstNode;
+use biome_graphql_syntax::{
+    GraphqlFragmentSpread, GraphqlVariableDefinition, GraphqlVariableDefinitions,
+};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt};
+
+use crate::GraphqlRuleAction;
+use crate::services::semantic::Semantic;
+
+declare_lint_rule! {
+    /// Disallow variable definitions that aren't used by the operation.
+    ///
+    /// Every variable defined by an operation must be used, either directly or through the fragments that it spreads.
+    ///
+    /// Operations of files that spread fragments defined in other files aren't checked,
+    /// because the fragments may use the variables.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query User($id: ID!, $first: Int) {
+    ///   user(id: $id) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query User($id: ID!) {
+    ///   ...UserFields
+    /// }
+    ///
+    /// fragment UserFields on Query {
+    ///   user(id: $id) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoUnusedVariableDefinitions {
+        version: "next",
+        name: "noUnusedVariableDefinitions",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("no-unused-variables").same()],
+        recommended: false,
+        severity: Severity::Error,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for NoUnusedVariableDefinitions {
+    type Query = Semantic<GraphqlVariableDefinition>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let node = ctx.query();
+        let model = ctx.model();
+        let variable = node.variable().ok()?;
+        if !variable.all_reference_nodes(model).is_empty() {
+            return None;
+        }
+        let spreads_external_fragment = model.all_unresolved_references().any(|reference| {
+            reference
+                .syntax()
+                .parent()
+                .is_some_and(|parent| GraphqlFragmentSpread::can_cast(parent.kind()))
+        });
+        if spreads_external_fragment {
+            return None;
+        }
+        Some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let variable = node.variable().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                variable.range(),
+                markup! {
+                    "The variable "<Emphasis>{variable.to_trimmed_string()}</Emphasis>" is never used by the operation."
+                },
+            )
+            .note(markup! {
+                "Unused variables are noise for the clients of the operation, which may still have to provide them. Use the variable, or remove its definition."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<GraphqlRuleAction> {
+        let node = ctx.query();
+        let variable_definitions = node
+            .syntax()
+            .ancestors()
+            .find_map(GraphqlVariableDefinitions::cast)?;
+        let mut mutation = ctx.root().begin();
+        if variable_definitions.elements().len() == 1 {
+            mutation.remove_node(variable_definitions);
+        } else {
+            mutation.remove_node(node.clone());
+        }
+        Some(GraphqlRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Remove the unused variable." },
+            mutation,
+        ))
+    }
+}
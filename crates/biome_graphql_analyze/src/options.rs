@@ -5,6 +5,18 @@
 use crate::lint;
 pub type NoDuplicateFields =
     <lint::suspicious::no_duplicate_fields::NoDuplicateFields as biome_analyze::Rule>::Options;
+pub type NoInvalidArguments =
+    <lint::nursery::no_invalid_arguments::NoInvalidArguments as biome_analyze::Rule>::Options;
+pub type NoInvalidFragmentTypes = < lint :: nursery :: no_invalid_fragment_types :: NoInvalidFragmentTypes as biome_analyze :: Rule > :: Options ;
+pub type NoUndefinedVariables =
+    <lint::nursery::no_undefined_variables::NoUndefinedVariables as biome_analyze::Rule>::Options;
+pub type NoUnknownDirectives =
+    <lint::nursery::no_unknown_directives::NoUnknownDirectives as biome_analyze::Rule>::Options;
+pub type NoUnknownFields =
+    <lint::nursery::no_unknown_fields::NoUnknownFields as biome_analyze::Rule>::Options;
+pub type NoUnknownTypes =
+    <lint::nursery::no_unknown_types::NoUnknownTypes as biome_analyze::Rule>::Options;
+pub type NoUnusedVariableDefinitions = < lint :: nursery :: no_unused_variable_definitions :: NoUnusedVariableDefinitions as biome_analyze :: Rule > :: Options ;
 pub type UseDeprecatedReason =
     <lint::style::use_deprecated_reason::UseDeprecatedReason as biome_analyze::Rule>::Options;
 pub type UseNamedOperation =
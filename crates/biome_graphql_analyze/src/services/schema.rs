@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use biome_analyze::{
+    AddVisitor, FromServices, Phase, Phases, QueryKey, Queryable, RuleKey, RuleMetadata,
+    ServiceBag, ServicesDiagnostic, SyntaxVisitor,
+};
+use biome_graphql_semantic::GraphqlSchema;
+use biome_graphql_syntax::{GraphqlLanguage, GraphqlRoot, GraphqlSyntaxNode};
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone)]
+pub struct GraphqlSchemaServices {
+    /// The schema of the project, if one is configured.
+    schema: Option<Arc<GraphqlSchema>>,
+}
+
+impl GraphqlSchemaServices {
+    pub fn schema(&self) -> Option<&GraphqlSchema> {
+        self.schema.as_deref()
+    }
+}
+
+impl FromServices for GraphqlSchemaServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        _rule_metadata: &RuleMetadata,
+        services: &ServiceBag,
+    ) -> biome_diagnostics::Result<Self, ServicesDiagnostic> {
+        let schema: &Option<Arc<GraphqlSchema>> = services
+            .get_service()
+            .ok_or_else(|| ServicesDiagnostic::new(rule_key.rule_name(), &["GraphqlSchema"]))?;
+
+        Ok(Self {
+            schema: schema.clone(),
+        })
+    }
+}
+
+impl Phase for GraphqlSchemaServices {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules **that validate executable documents
+/// against the schema of the project** and match on specific [AstNode] types.
+#[derive(Clone)]
+pub struct WithSchema<N>(pub N);
+
+impl<N> Queryable for WithSchema<N>
+where
+    N: AstNode<Language = GraphqlLanguage> + 'static,
+{
+    type Input = GraphqlSyntaxNode;
+    type Output = N;
+
+    type Language = GraphqlLanguage;
+    type Services = GraphqlSchemaServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<GraphqlLanguage>, _: &GraphqlRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
@@ -0,0 +1,26 @@
+use biome_analyze::RuleDiagnostic;
+use biome_console::{Markup, markup};
+use biome_graphql_semantic::SchemaLocation;
+use camino::Utf8Path;
+
+/// Points `diagnostic` at the definition of the schema that the reported
+/// code doesn't conform to.
+///
+/// When the definition is in the analyzed file, it's highlighted in the code
+/// frame. Otherwise, the file, line and column of the definition are printed.
+pub(crate) fn with_schema_location(
+    diagnostic: RuleDiagnostic,
+    file_path: &Utf8Path,
+    location: Option<&SchemaLocation>,
+    subject: Markup,
+) -> RuleDiagnostic {
+    match location {
+        Some(location) if file_path.ends_with(&location.path) => {
+            diagnostic.detail(location.range, markup! { {subject}" is defined here." })
+        }
+        Some(location) => diagnostic.note(markup! {
+            {subject}" is defined in "<Emphasis>{location.to_string()}</Emphasis>"."
+        }),
+        None => diagnostic,
+    }
+}
@@ -1,6 +1,8 @@
 use biome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
+use biome_graphql_analyze::GraphqlAnalyzerServices;
 use biome_graphql_parser::parse_graphql;
+use biome_graphql_semantic::{GraphqlSchema, GraphqlSchemaBuilder};
 use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
 use biome_rowan::AstNode;
 use biome_test_utils::{
@@ -11,6 +13,7 @@ use biome_test_utils::{
 };
 use camino::Utf8Path;
 use std::ops::Deref;
+use std::sync::Arc;
 use std::{fs::read_to_string, slice};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{graphql,json,jsonc}", crate::run_test, "module"}
@@ -22,6 +25,11 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     let input_file = Utf8Path::new(input);
     let file_name = input_file.file_name().unwrap();
 
+    // Schemas used by other test files aren't tests themselves.
+    if file_name == SCHEMA_FILE_NAME {
+        return;
+    }
+
     let (group, rule) = parse_test_path(input_file);
     if rule == "specs" || rule == "suppression" {
         panic!("the test file must be placed in the {rule}/<group-name>/<rule-name>/ directory");
@@ -100,38 +108,41 @@ pub(crate) fn analyze_and_snap(
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
 
-    let (_, errors) = biome_graphql_analyze::analyze(&root, filter, &options, |event| {
-        if let Some(mut diag) = event.diagnostic() {
-            for action in event.actions() {
-                if check_action_type.is_suppression() {
-                    if action.is_suppression() {
+    let services = GraphqlAnalyzerServices::default().with_schema(load_schema(input_file));
+
+    let (_, errors) =
+        biome_graphql_analyze::analyze(&root, filter, &options, &[], services, |event| {
+            if let Some(mut diag) = event.diagnostic() {
+                for action in event.actions() {
+                    if check_action_type.is_suppression() {
+                        if action.is_suppression() {
+                            check_code_action(input_file, input_code, source_type, &action);
+                            diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+                        }
+                    } else if !action.is_suppression() {
                         check_code_action(input_file, input_code, source_type, &action);
                         diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                     }
-                } else if !action.is_suppression() {
-                    check_code_action(input_file, input_code, source_type, &action);
-                    diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                 }
-            }
 
-            diagnostics.push(diagnostic_to_string(file_name, input_code, diag.into()));
-            return ControlFlow::Continue(());
-        }
+                diagnostics.push(diagnostic_to_string(file_name, input_code, diag.into()));
+                return ControlFlow::Continue(());
+            }
 
-        for action in event.actions() {
-            if check_action_type.is_suppression() {
-                if action.category.matches("quickfix.suppressRule") {
+            for action in event.actions() {
+                if check_action_type.is_suppression() {
+                    if action.category.matches("quickfix.suppressRule") {
+                        check_code_action(input_file, input_code, source_type, &action);
+                        code_fixes.push(code_fix_to_string(input_code, action));
+                    }
+                } else if !action.category.matches("quickfix.suppressRule") {
                     check_code_action(input_file, input_code, source_type, &action);
                     code_fixes.push(code_fix_to_string(input_code, action));
                 }
-            } else if !action.category.matches("quickfix.suppressRule") {
-                check_code_action(input_file, input_code, source_type, &action);
-                code_fixes.push(code_fix_to_string(input_code, action));
             }
-        }
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        });
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
@@ -148,6 +159,22 @@ pub(crate) fn analyze_and_snap(
     assert_diagnostics_expectation_comment(input_file, root.syntax(), diagnostics.len());
 }
 
+const SCHEMA_FILE_NAME: &str = "schema.graphql";
+
+/// Loads the schema defined by the `schema.graphql` file next to the test
+/// file, if it exists.
+fn load_schema(input_file: &Utf8Path) -> Option<Arc<GraphqlSchema>> {
+    let schema_file = input_file.parent()?.join(SCHEMA_FILE_NAME);
+    let content = read_to_string(&schema_file).ok()?;
+    let parsed = parse_graphql(&content);
+    assert_errors_are_absent(parsed.tree().syntax(), parsed.diagnostics(), &schema_file);
+
+    // A relative path keeps the snapshots independent of the checkout location
+    let mut builder = GraphqlSchemaBuilder::default();
+    builder.add_document(Utf8Path::new(SCHEMA_FILE_NAME), &parsed.tree());
+    Some(Arc::new(builder.build()))
+}
+
 fn check_code_action(
     path: &Utf8Path,
     source: &str,
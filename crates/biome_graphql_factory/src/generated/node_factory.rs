@@ -823,6 +823,12 @@ pub fn graphql_literal_name(value_token: SyntaxToken) -> GraphqlLiteralName {
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
+pub fn graphql_metavariable(value_token: SyntaxToken) -> GraphqlMetavariable {
+    GraphqlMetavariable::unwrap_cast(SyntaxNode::new_detached(
+        GraphqlSyntaxKind::GRAPHQL_METAVARIABLE,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
 pub fn graphql_name_binding(value_token: SyntaxToken) -> GraphqlNameBinding {
     GraphqlNameBinding::unwrap_cast(SyntaxNode::new_detached(
         GraphqlSyntaxKind::GRAPHQL_NAME_BINDING,
@@ -1136,6 +1136,44 @@ impl IntoFormat<GraphqlFormatContext> for biome_graphql_syntax::GraphqlLiteralNa
         )
     }
 }
+impl FormatRule<biome_graphql_syntax::GraphqlMetavariable>
+    for crate::graphql::auxiliary::metavariable::FormatGraphqlMetavariable
+{
+    type Context = GraphqlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_graphql_syntax::GraphqlMetavariable,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_graphql_syntax::GraphqlMetavariable>::fmt(self, node, f)
+    }
+}
+impl AsFormat<GraphqlFormatContext> for biome_graphql_syntax::GraphqlMetavariable {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_graphql_syntax::GraphqlMetavariable,
+        crate::graphql::auxiliary::metavariable::FormatGraphqlMetavariable,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::graphql::auxiliary::metavariable::FormatGraphqlMetavariable::default(),
+        )
+    }
+}
+impl IntoFormat<GraphqlFormatContext> for biome_graphql_syntax::GraphqlMetavariable {
+    type Format = FormatOwnedWithRule<
+        biome_graphql_syntax::GraphqlMetavariable,
+        crate::graphql::auxiliary::metavariable::FormatGraphqlMetavariable,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::graphql::auxiliary::metavariable::FormatGraphqlMetavariable::default(),
+        )
+    }
+}
 impl FormatRule<biome_graphql_syntax::GraphqlNameBinding>
     for crate::graphql::auxiliary::name_binding::FormatGraphqlNameBinding
 {
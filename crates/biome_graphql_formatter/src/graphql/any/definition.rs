@@ -13,6 +13,7 @@ impl FormatRule<AnyGraphqlDefinition> for FormatAnyGraphqlDefinition {
             AnyGraphqlDefinition::GraphqlBogusDefinition(node) => node.format().fmt(f),
             AnyGraphqlDefinition::GraphqlDirectiveDefinition(node) => node.format().fmt(f),
             AnyGraphqlDefinition::GraphqlFragmentDefinition(node) => node.format().fmt(f),
+            AnyGraphqlDefinition::GraphqlMetavariable(node) => node.format().fmt(f),
             AnyGraphqlDefinition::GraphqlOperationDefinition(node) => node.format().fmt(f),
             AnyGraphqlDefinition::GraphqlSchemaDefinition(node) => node.format().fmt(f),
             AnyGraphqlDefinition::GraphqlSchemaExtension(node) => node.format().fmt(f),
@@ -0,0 +1,10 @@
+use crate::prelude::*;
+use biome_graphql_syntax::GraphqlMetavariable;
+use biome_rowan::AstNode;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlMetavariable;
+impl FormatNodeRule<GraphqlMetavariable> for FormatGraphqlMetavariable {
+    fn fmt_fields(&self, node: &GraphqlMetavariable, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        format_verbatim_node(node.syntax()).fmt(f)
+    }
+}
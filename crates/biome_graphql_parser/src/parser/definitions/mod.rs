@@ -10,7 +10,9 @@ mod scalar;
 mod schema;
 mod union;
 
-use crate::parser::{GraphqlParser, parse_error::expected_any_definition};
+use crate::parser::{
+    GraphqlParser, is_at_metavariable, parse_error::expected_any_definition, parse_metavariable,
+};
 use biome_graphql_syntax::{
     GraphqlSyntaxKind::{self, *},
     T,
@@ -89,6 +91,7 @@ fn parse_definition(p: &mut GraphqlParser) -> ParsedSyntax {
         T![input] => parse_input_object_type_definition(p),
         T![directive] => parse_directive_definition(p),
         T![extend] => parse_extension(p),
+        GRIT_METAVARIABLE => parse_metavariable(p),
         _ => Absent,
     }
 }
@@ -126,5 +129,5 @@ fn is_at_definition(p: &mut GraphqlParser<'_>) -> bool {
             | T![input]
             | T![directive]
             | T![extend]
-    )
+    ) || is_at_metavariable(p)
 }
@@ -12,9 +12,9 @@ use biome_parser::{
 
 use super::{
     argument::is_at_argument_list_end,
-    is_nth_at_name,
+    is_at_metavariable, is_nth_at_name,
     parse_error::{expected_object_field, expected_value},
-    parse_literal_name,
+    parse_literal_name, parse_metavariable,
     variable::{is_at_variable, parse_variable_reference},
 };
 
@@ -132,6 +132,8 @@ pub(crate) fn parse_value(p: &mut GraphqlParser) -> ParsedSyntax {
         parse_list(p)
     } else if is_at_object(p) {
         parse_object(p)
+    } else if is_at_metavariable(p) {
+        parse_metavariable(p)
     } else {
         return Absent;
     }
@@ -244,6 +246,7 @@ fn is_at_value(p: &mut GraphqlParser) -> bool {
         || is_at_enum(p)
         || is_at_list(p)
         || is_at_object(p)
+        || is_at_metavariable(p)
 }
 
 #[inline]
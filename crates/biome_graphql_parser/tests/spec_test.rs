@@ -3,7 +3,7 @@ use biome_console::markup;
 use biome_diagnostics::DiagnosticExt;
 use biome_diagnostics::display::PrintDiagnostic;
 use biome_diagnostics::termcolor;
-use biome_graphql_parser::parse_graphql;
+use biome_graphql_parser::{GraphqlParserOptions, parse_graphql_with_options};
 use biome_rowan::SyntaxKind;
 use biome_test_utils::validate_eof_token;
 use std::fmt::Write;
@@ -34,7 +34,12 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let content = fs::read_to_string(test_case_path)
         .expect("Expected test path to be a readable file in UTF8 encoding");
 
-    let parsed = parse_graphql(&content);
+    let mut options = GraphqlParserOptions::default();
+    if test_case.contains("grit_metavariables") {
+        options = options.allow_metavariables();
+    }
+
+    let parsed = parse_graphql_with_options(&content, options);
     validate_eof_token(parsed.syntax());
 
     let formatted_ast = format!("{:#?}", parsed.tree());
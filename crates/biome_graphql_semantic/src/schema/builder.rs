@@ -0,0 +1,505 @@
+use biome_graphql_syntax::{
+    AnyGraphqlDefinition, AnyGraphqlTypeDefinition, AnyGraphqlTypeExtension,
+    GraphqlArgumentsDefinition, GraphqlDirectiveDefinition, GraphqlEnumValuesDefinition,
+    GraphqlFieldsDefinition, GraphqlImplementsInterfaces, GraphqlInputFieldsDefinition,
+    GraphqlInputValueDefinition, GraphqlRoot, GraphqlRootOperationTypes, GraphqlSyntaxToken,
+    GraphqlUnionMemberTypes,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, SyntaxResult};
+use camino::Utf8Path;
+use rustc_hash::FxHashMap;
+
+use super::{
+    DirectiveDefinition, FieldDefinition, GraphqlSchema, InputValueDefinition, OperationKind,
+    SchemaLocation, TypeDefinition, TypeKind, TypeReference,
+};
+
+/// Builds a [GraphqlSchema] from the type system definitions of one or more
+/// documents.
+///
+/// Type extensions are applied once all the documents have been added, so
+/// they can be declared in a different file than the type they extend.
+/// When a type or a directive is defined more than once, the first definition
+/// wins.
+///
+/// ```rust
+/// use biome_graphql_parser::parse_graphql;
+/// use biome_graphql_semantic::GraphqlSchemaBuilder;
+/// use camino::Utf8Path;
+///
+/// let schema = parse_graphql("type Query { hero: String }");
+/// let extension = parse_graphql("extend type Query { villain: String }");
+///
+/// let mut builder = GraphqlSchemaBuilder::default();
+/// builder.add_document(Utf8Path::new("schema.graphql"), &schema.tree());
+/// builder.add_document(Utf8Path::new("extension.graphql"), &extension.tree());
+/// let schema = builder.build();
+///
+/// let query = schema.get_type("Query").unwrap();
+/// assert!(query.field("hero").is_some());
+/// assert!(query.field("villain").is_some());
+/// ```
+#[derive(Debug, Default)]
+pub struct GraphqlSchemaBuilder {
+    types: FxHashMap<String, TypeDefinition>,
+    directives: FxHashMap<String, DirectiveDefinition>,
+    /// The extensions, applied when the schema is built
+    extensions: Vec<TypeDefinition>,
+    /// The root types declared by `schema` definitions and extensions
+    root_types: FxHashMap<OperationKind, String>,
+}
+
+impl GraphqlSchemaBuilder {
+    /// Adds the type system definitions and extensions of `root`, which is
+    /// the document at `path`.
+    ///
+    /// The executable definitions of the document, such as operations, are
+    /// ignored.
+    pub fn add_document(&mut self, path: &Utf8Path, root: &GraphqlRoot) {
+        let document = Document::new(path, root);
+        for definition in root.definitions() {
+            match definition {
+                AnyGraphqlDefinition::AnyGraphqlTypeDefinition(definition) => {
+                    if let Some(definition) = document.type_definition(&definition) {
+                        self.types
+                            .entry(definition.name.clone())
+                            .or_insert(definition);
+                    }
+                }
+                AnyGraphqlDefinition::AnyGraphqlTypeExtension(extension) => {
+                    if let Some(extension) = document.type_extension(&extension) {
+                        self.extensions.push(extension);
+                    }
+                }
+                AnyGraphqlDefinition::GraphqlDirectiveDefinition(definition) => {
+                    if let Some(definition) = document.directive_definition(&definition) {
+                        self.directives
+                            .entry(definition.name.clone())
+                            .or_insert(definition);
+                    }
+                }
+                AnyGraphqlDefinition::GraphqlSchemaDefinition(definition) => {
+                    if let Ok(root_operation_types) = definition.root_operation_types() {
+                        self.add_root_operation_types(&root_operation_types);
+                    }
+                }
+                AnyGraphqlDefinition::GraphqlSchemaExtension(extension) => {
+                    if let Some(root_operation_types) = extension.root_operation_types() {
+                        self.add_root_operation_types(&root_operation_types);
+                    }
+                }
+                AnyGraphqlDefinition::GraphqlOperationDefinition(_)
+                | AnyGraphqlDefinition::GraphqlSelectionSet(_)
+                | AnyGraphqlDefinition::GraphqlFragmentDefinition(_)
+                | AnyGraphqlDefinition::GraphqlMetavariable(_)
+                | AnyGraphqlDefinition::GraphqlBogusDefinition(_) => {}
+            }
+        }
+    }
+
+    fn add_root_operation_types(&mut self, root_operation_types: &GraphqlRootOperationTypes) {
+        for root_operation_type in root_operation_types.root_operation_type() {
+            let Some(kind) = root_operation_type
+                .operation_type()
+                .ok()
+                .and_then(|operation_type| OperationKind::from_syntax(&operation_type))
+            else {
+                continue;
+            };
+            let Ok(named_type) = root_operation_type.named_type() else {
+                continue;
+            };
+            if let Some(name) = token_text(named_type.value_token()) {
+                self.root_types.entry(kind).or_insert(name);
+            }
+        }
+    }
+
+    pub fn build(mut self) -> GraphqlSchema {
+        for extension in std::mem::take(&mut self.extensions) {
+            let Some(ty) = self.types.get_mut(&extension.name) else {
+                continue;
+            };
+            ty.fields.extend(extension.fields);
+            ty.input_fields.extend(extension.input_fields);
+            ty.values.extend(extension.values);
+            ty.members.extend(extension.members);
+            ty.interfaces.extend(extension.interfaces);
+        }
+
+        for name in ["Int", "Float", "String", "Boolean", "ID"] {
+            self.types
+                .entry(name.to_string())
+                .or_insert_with(|| builtin_scalar(name));
+        }
+        for directive in builtin_directives() {
+            self.directives
+                .entry(directive.name.clone())
+                .or_insert(directive);
+        }
+
+        // Without a `schema` definition, the root types are the types with the
+        // conventional names.
+        let mut root_type = |kind: OperationKind, default_name: &str| {
+            self.root_types.remove(&kind).or_else(|| {
+                self.types
+                    .contains_key(default_name)
+                    .then(|| default_name.to_string())
+            })
+        };
+        let query_type = root_type(OperationKind::Query, "Query");
+        let mutation_type = root_type(OperationKind::Mutation, "Mutation");
+        let subscription_type = root_type(OperationKind::Subscription, "Subscription");
+
+        GraphqlSchema {
+            types: self.types,
+            directives: self.directives,
+            query_type,
+            mutation_type,
+            subscription_type,
+        }
+    }
+}
+
+/// A schema file being added to the [GraphqlSchemaBuilder]
+struct Document<'a> {
+    path: &'a Utf8Path,
+    text: String,
+    /// The offsets of the start of each line
+    line_starts: Vec<usize>,
+}
+
+impl<'a> Document<'a> {
+    fn new(path: &'a Utf8Path, root: &GraphqlRoot) -> Self {
+        let text = root.syntax().to_string();
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        Self {
+            path,
+            text,
+            line_starts,
+        }
+    }
+
+    fn location(&self, token: &GraphqlSyntaxToken) -> SchemaLocation {
+        let range = token.text_trimmed_range();
+        let offset = usize::from(range.start());
+        let line = self
+            .line_starts
+            .partition_point(|line_start| *line_start <= offset);
+        let line_start = self.line_starts[line - 1];
+        let column = self.text[line_start..offset].chars().count() + 1;
+        SchemaLocation {
+            path: self.path.to_path_buf(),
+            range,
+            line,
+            column,
+        }
+    }
+
+    fn type_definition(&self, definition: &AnyGraphqlTypeDefinition) -> Option<TypeDefinition> {
+        let (name, kind) = match definition {
+            AnyGraphqlTypeDefinition::GraphqlScalarTypeDefinition(definition) => (
+                definition.name().ok()?.value_token().ok()?,
+                TypeKind::Scalar,
+            ),
+            AnyGraphqlTypeDefinition::GraphqlObjectTypeDefinition(definition) => (
+                definition.name().ok()?.value_token().ok()?,
+                TypeKind::Object,
+            ),
+            AnyGraphqlTypeDefinition::GraphqlInterfaceTypeDefinition(definition) => (
+                definition.name().ok()?.value_token().ok()?,
+                TypeKind::Interface,
+            ),
+            AnyGraphqlTypeDefinition::GraphqlUnionTypeDefinition(definition) => {
+                (definition.name().ok()?.value_token().ok()?, TypeKind::Union)
+            }
+            AnyGraphqlTypeDefinition::GraphqlEnumTypeDefinition(definition) => {
+                (definition.name().ok()?.value_token().ok()?, TypeKind::Enum)
+            }
+            AnyGraphqlTypeDefinition::GraphqlInputObjectTypeDefinition(definition) => (
+                definition.name().ok()?.value_token().ok()?,
+                TypeKind::InputObject,
+            ),
+        };
+
+        let mut ty = TypeDefinition {
+            name: name.text_trimmed().to_string(),
+            kind,
+            location: Some(self.location(&name)),
+            fields: Vec::new(),
+            input_fields: Vec::new(),
+            values: Vec::new(),
+            members: Vec::new(),
+            interfaces: Vec::new(),
+        };
+        match definition {
+            AnyGraphqlTypeDefinition::GraphqlScalarTypeDefinition(_) => {}
+            AnyGraphqlTypeDefinition::GraphqlObjectTypeDefinition(definition) => {
+                self.add_interfaces(&mut ty, definition.implements());
+                self.add_fields(&mut ty, definition.fields());
+            }
+            AnyGraphqlTypeDefinition::GraphqlInterfaceTypeDefinition(definition) => {
+                self.add_interfaces(&mut ty, definition.implements());
+                self.add_fields(&mut ty, definition.fields());
+            }
+            AnyGraphqlTypeDefinition::GraphqlUnionTypeDefinition(definition) => {
+                self.add_members(&mut ty, definition.union_members());
+            }
+            AnyGraphqlTypeDefinition::GraphqlEnumTypeDefinition(definition) => {
+                self.add_values(&mut ty, definition.enum_values());
+            }
+            AnyGraphqlTypeDefinition::GraphqlInputObjectTypeDefinition(definition) => {
+                self.add_input_fields(&mut ty, definition.input_fields());
+            }
+        }
+        Some(ty)
+    }
+
+    /// Returns the content of `extension`, as a definition that is merged with
+    /// the extended type when the schema is built.
+    fn type_extension(&self, extension: &AnyGraphqlTypeExtension) -> Option<TypeDefinition> {
+        let (name, kind) = match extension {
+            AnyGraphqlTypeExtension::GraphqlScalarTypeExtension(extension) => {
+                (extension.name().ok()?.value_token().ok()?, TypeKind::Scalar)
+            }
+            AnyGraphqlTypeExtension::GraphqlObjectTypeExtension(extension) => {
+                (extension.name().ok()?.value_token().ok()?, TypeKind::Object)
+            }
+            AnyGraphqlTypeExtension::GraphqlInterfaceTypeExtension(extension) => (
+                extension.name().ok()?.value_token().ok()?,
+                TypeKind::Interface,
+            ),
+            AnyGraphqlTypeExtension::GraphqlUnionTypeExtension(extension) => {
+                (extension.name().ok()?.value_token().ok()?, TypeKind::Union)
+            }
+            AnyGraphqlTypeExtension::GraphqlEnumTypeExtension(extension) => {
+                (extension.name().ok()?.value_token().ok()?, TypeKind::Enum)
+            }
+            AnyGraphqlTypeExtension::GraphqlInputObjectTypeExtension(extension) => (
+                extension.name().ok()?.value_token().ok()?,
+                TypeKind::InputObject,
+            ),
+        };
+
+        let mut ty = TypeDefinition {
+            name: name.text_trimmed().to_string(),
+            kind,
+            location: Some(self.location(&name)),
+            fields: Vec::new(),
+            input_fields: Vec::new(),
+            values: Vec::new(),
+            members: Vec::new(),
+            interfaces: Vec::new(),
+        };
+        match extension {
+            AnyGraphqlTypeExtension::GraphqlScalarTypeExtension(_) => {}
+            AnyGraphqlTypeExtension::GraphqlObjectTypeExtension(extension) => {
+                self.add_interfaces(&mut ty, extension.implements());
+                self.add_fields(&mut ty, extension.fields());
+            }
+            AnyGraphqlTypeExtension::GraphqlInterfaceTypeExtension(extension) => {
+                self.add_interfaces(&mut ty, extension.implements());
+                self.add_fields(&mut ty, extension.fields());
+            }
+            AnyGraphqlTypeExtension::GraphqlUnionTypeExtension(extension) => {
+                self.add_members(&mut ty, extension.union_members());
+            }
+            AnyGraphqlTypeExtension::GraphqlEnumTypeExtension(extension) => {
+                self.add_values(&mut ty, extension.enum_values());
+            }
+            AnyGraphqlTypeExtension::GraphqlInputObjectTypeExtension(extension) => {
+                self.add_input_fields(&mut ty, extension.input_fields());
+            }
+        }
+        Some(ty)
+    }
+
+    fn directive_definition(
+        &self,
+        definition: &GraphqlDirectiveDefinition,
+    ) -> Option<DirectiveDefinition> {
+        let name = definition.name().ok()?.value_token().ok()?;
+        let locations = definition
+            .locations()
+            .iter()
+            .filter_map(|location| token_text(location.ok()?.value_token()))
+            .collect();
+        Some(DirectiveDefinition {
+            name: name.text_trimmed().to_string(),
+            arguments: self.arguments(definition.arguments()),
+            locations,
+            location: Some(self.location(&name)),
+        })
+    }
+
+    fn add_interfaces(
+        &self,
+        ty: &mut TypeDefinition,
+        implements: Option<GraphqlImplementsInterfaces>,
+    ) {
+        let Some(implements) = implements else {
+            return;
+        };
+        ty.interfaces.extend(
+            implements
+                .interfaces()
+                .iter()
+                .filter_map(|interface| token_text(interface.ok()?.value_token())),
+        );
+    }
+
+    fn add_fields(&self, ty: &mut TypeDefinition, fields: Option<GraphqlFieldsDefinition>) {
+        let Some(fields) = fields else {
+            return;
+        };
+        ty.fields.extend(fields.fields().iter().filter_map(|field| {
+            let name = field.name().ok()?.value_token().ok()?;
+            Some(FieldDefinition {
+                name: name.text_trimmed().to_string(),
+                ty: TypeReference::from_syntax(&field.ty().ok()?)?,
+                arguments: self.arguments(field.arguments()),
+                location: self.location(&name),
+            })
+        }));
+    }
+
+    fn add_input_fields(
+        &self,
+        ty: &mut TypeDefinition,
+        input_fields: Option<GraphqlInputFieldsDefinition>,
+    ) {
+        let Some(input_fields) = input_fields else {
+            return;
+        };
+        ty.input_fields.extend(
+            input_fields
+                .fields()
+                .iter()
+                .filter_map(|input_field| self.input_value(&input_field)),
+        );
+    }
+
+    fn add_values(&self, ty: &mut TypeDefinition, values: Option<GraphqlEnumValuesDefinition>) {
+        let Some(values) = values else {
+            return;
+        };
+        ty.values.extend(
+            values
+                .values()
+                .iter()
+                .filter_map(|value| token_text(value.value().ok()?.value_token())),
+        );
+    }
+
+    fn add_members(&self, ty: &mut TypeDefinition, members: Option<GraphqlUnionMemberTypes>) {
+        let Some(members) = members else {
+            return;
+        };
+        ty.members.extend(
+            members
+                .members()
+                .iter()
+                .filter_map(|member| token_text(member.ok()?.value_token())),
+        );
+    }
+
+    fn arguments(
+        &self,
+        arguments: Option<GraphqlArgumentsDefinition>,
+    ) -> Vec<InputValueDefinition> {
+        let Some(arguments) = arguments else {
+            return Vec::new();
+        };
+        arguments
+            .arguments()
+            .iter()
+            .filter_map(|argument| self.input_value(&argument))
+            .collect()
+    }
+
+    fn input_value(
+        &self,
+        input_value: &GraphqlInputValueDefinition,
+    ) -> Option<InputValueDefinition> {
+        let name = input_value.name().ok()?.value_token().ok()?;
+        Some(InputValueDefinition {
+            name: name.text_trimmed().to_string(),
+            ty: TypeReference::from_syntax(&input_value.ty().ok()?)?,
+            has_default: input_value.default().is_some(),
+            location: Some(self.location(&name)),
+        })
+    }
+}
+
+fn token_text(token: SyntaxResult<GraphqlSyntaxToken>) -> Option<String> {
+    Some(token.ok()?.text_trimmed().to_string())
+}
+
+fn builtin_scalar(name: &str) -> TypeDefinition {
+    TypeDefinition {
+        name: name.to_string(),
+        kind: TypeKind::Scalar,
+        location: None,
+        fields: Vec::new(),
+        input_fields: Vec::new(),
+        values: Vec::new(),
+        members: Vec::new(),
+        interfaces: Vec::new(),
+    }
+}
+
+/// The directives defined by the GraphQL specification
+fn builtin_directives() -> [DirectiveDefinition; 4] {
+    let argument = |name: &str, ty: TypeReference| InputValueDefinition {
+        name: name.to_string(),
+        ty,
+        has_default: false,
+        location: None,
+    };
+    let non_null =
+        |name: &str| TypeReference::NonNull(Box::new(TypeReference::Named(name.to_string())));
+    let directive = |name: &str, arguments: Vec<InputValueDefinition>, locations: &[&str]| {
+        DirectiveDefinition {
+            name: name.to_string(),
+            arguments,
+            locations: locations
+                .iter()
+                .map(|location| (*location).to_string())
+                .collect(),
+            location: None,
+        }
+    };
+
+    [
+        directive(
+            "skip",
+            vec![argument("if", non_null("Boolean"))],
+            &["FIELD", "FRAGMENT_SPREAD", "INLINE_FRAGMENT"],
+        ),
+        directive(
+            "include",
+            vec![argument("if", non_null("Boolean"))],
+            &["FIELD", "FRAGMENT_SPREAD", "INLINE_FRAGMENT"],
+        ),
+        directive(
+            "deprecated",
+            vec![InputValueDefinition {
+                has_default: true,
+                ..argument("reason", TypeReference::Named("String".to_string()))
+            }],
+            &[
+                "FIELD_DEFINITION",
+                "ARGUMENT_DEFINITION",
+                "INPUT_FIELD_DEFINITION",
+                "ENUM_VALUE",
+            ],
+        ),
+        directive(
+            "specifiedBy",
+            vec![argument("url", non_null("String"))],
+            &["SCALAR"],
+        ),
+    ]
+}
@@ -0,0 +1,501 @@
+mod builder;
+
+use biome_graphql_syntax::{
+    AnyGraphqlDefinition, AnyGraphqlPrimitiveType, AnyGraphqlType, GraphqlField,
+    GraphqlFragmentDefinition, GraphqlInlineFragment, GraphqlOperationDefinition,
+    GraphqlOperationType, GraphqlSelectionSet, GraphqlSyntaxKind, GraphqlSyntaxNode,
+    GraphqlTypeCondition,
+};
+use biome_rowan::{AstNode, SyntaxNodeCast, TextRange};
+use camino::Utf8PathBuf;
+use rustc_hash::FxHashMap;
+use std::fmt::{Display, Formatter};
+
+pub use builder::*;
+
+/// The type system of a project, assembled from the type definitions, type
+/// extensions and directive definitions of all its schema files.
+///
+/// Unlike the [crate::SemanticModel], the schema doesn't hold any syntax node,
+/// so that it can be shared between the threads that analyze the executable
+/// documents of the project.
+///
+/// See [GraphqlSchemaBuilder] to build a schema.
+#[derive(Debug, Default)]
+pub struct GraphqlSchema {
+    pub(crate) types: FxHashMap<String, TypeDefinition>,
+    pub(crate) directives: FxHashMap<String, DirectiveDefinition>,
+    pub(crate) query_type: Option<String>,
+    pub(crate) mutation_type: Option<String>,
+    pub(crate) subscription_type: Option<String>,
+}
+
+impl GraphqlSchema {
+    /// Returns the definition of the type named `name`, including the
+    /// built-in scalars.
+    pub fn get_type(&self, name: &str) -> Option<&TypeDefinition> {
+        self.types.get(name)
+    }
+
+    /// Returns the definition of the directive named `name`, including the
+    /// built-in directives.
+    pub fn get_directive(&self, name: &str) -> Option<&DirectiveDefinition> {
+        self.directives.get(name)
+    }
+
+    /// Returns the type of the root field of the given kind of operation.
+    pub fn root_type(&self, kind: OperationKind) -> Option<&TypeDefinition> {
+        let name = match kind {
+            OperationKind::Query => self.query_type.as_deref(),
+            OperationKind::Mutation => self.mutation_type.as_deref(),
+            OperationKind::Subscription => self.subscription_type.as_deref(),
+        }?;
+        self.get_type(name)
+    }
+
+    /// Returns the names of the object types that a value of type `ty` can
+    /// be at runtime.
+    pub fn possible_types<'a>(&'a self, ty: &'a TypeDefinition) -> Vec<&'a str> {
+        match ty.kind {
+            TypeKind::Object => vec![ty.name.as_str()],
+            TypeKind::Union => ty.members.iter().map(String::as_str).collect(),
+            TypeKind::Interface => {
+                let mut possible_types: Vec<_> = self
+                    .types
+                    .values()
+                    .filter(|candidate| {
+                        candidate.kind == TypeKind::Object
+                            && candidate.interfaces.iter().any(|name| name == &ty.name)
+                    })
+                    .map(|candidate| candidate.name.as_str())
+                    .collect();
+                possible_types.sort_unstable();
+                possible_types
+            }
+            TypeKind::Scalar | TypeKind::Enum | TypeKind::InputObject => Vec::new(),
+        }
+    }
+
+    /// Returns whether a fragment with the type condition `fragment_type` can
+    /// be spread in a selection set of type `parent_type`, in other words
+    /// whether the two types have at least one possible type in common.
+    pub fn can_spread(&self, parent_type: &TypeDefinition, fragment_type: &TypeDefinition) -> bool {
+        let parent_types = self.possible_types(parent_type);
+        self.possible_types(fragment_type)
+            .iter()
+            .any(|name| parent_types.contains(name))
+    }
+
+    /// Returns the type of the values selected by `selection_set`.
+    ///
+    /// Returns [None] when the type can't be determined, for example because
+    /// a parent field doesn't exist in the schema.
+    pub fn type_of_selection_set(
+        &self,
+        selection_set: &GraphqlSelectionSet,
+    ) -> Option<&TypeDefinition> {
+        let parent = selection_set.syntax().parent()?;
+        match parent.kind() {
+            // `{ field }` is a shorthand for a query
+            GraphqlSyntaxKind::GRAPHQL_DEFINITION_LIST => self.root_type(OperationKind::Query),
+            GraphqlSyntaxKind::GRAPHQL_OPERATION_DEFINITION => {
+                let operation = parent.cast::<GraphqlOperationDefinition>()?;
+                self.root_type(OperationKind::from_syntax(&operation.ty().ok()?)?)
+            }
+            GraphqlSyntaxKind::GRAPHQL_FRAGMENT_DEFINITION => {
+                let fragment = parent.cast::<GraphqlFragmentDefinition>()?;
+                self.type_of_condition(&fragment.type_condition().ok()?)
+            }
+            GraphqlSyntaxKind::GRAPHQL_INLINE_FRAGMENT => {
+                let fragment = parent.cast::<GraphqlInlineFragment>()?;
+                match fragment.type_condition() {
+                    Some(type_condition) => self.type_of_condition(&type_condition),
+                    None => self.type_of_parent_selection_set(fragment.syntax()),
+                }
+            }
+            GraphqlSyntaxKind::GRAPHQL_FIELD => {
+                let field = parent.cast::<GraphqlField>()?;
+                let (_, definition) = self.field_definition(&field)?;
+                self.get_type(definition.ty.named_type())
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the definition of `field` in the schema, along with the type
+    /// that defines it.
+    ///
+    /// Introspection fields, such as `__typename`, aren't part of the schema.
+    pub fn field_definition(
+        &self,
+        field: &GraphqlField,
+    ) -> Option<(&TypeDefinition, &FieldDefinition)> {
+        let parent_type = self.type_of_parent_selection_set(field.syntax())?;
+        let name = field.name().ok()?.value_token().ok()?;
+        let definition = parent_type.field(name.text_trimmed())?;
+        Some((parent_type, definition))
+    }
+
+    /// Returns the type of the selection set that contains `selection`.
+    pub fn type_of_parent_selection_set(
+        &self,
+        selection: &GraphqlSyntaxNode,
+    ) -> Option<&TypeDefinition> {
+        let selection_set = selection
+            .ancestors()
+            .skip(1)
+            .find_map(GraphqlSelectionSet::cast)?;
+        self.type_of_selection_set(&selection_set)
+    }
+
+    fn type_of_condition(&self, type_condition: &GraphqlTypeCondition) -> Option<&TypeDefinition> {
+        let name = type_condition.ty().ok()?.value_token().ok()?;
+        self.get_type(name.text_trimmed())
+    }
+}
+
+/// The kind of an operation, which determines its root type.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OperationKind {
+    Query,
+    Mutation,
+    Subscription,
+}
+
+impl OperationKind {
+    pub fn from_syntax(operation_type: &GraphqlOperationType) -> Option<Self> {
+        match operation_type.value_token().ok()?.kind() {
+            GraphqlSyntaxKind::QUERY_KW => Some(Self::Query),
+            GraphqlSyntaxKind::MUTATION_KW => Some(Self::Mutation),
+            GraphqlSyntaxKind::SUBSCRIPTION_KW => Some(Self::Subscription),
+            _ => None,
+        }
+    }
+}
+
+impl Display for OperationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Query => f.write_str("query"),
+            Self::Mutation => f.write_str("mutation"),
+            Self::Subscription => f.write_str("subscription"),
+        }
+    }
+}
+
+/// Where a definition of the schema is located.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaLocation {
+    /// The path of the schema file that contains the definition
+    pub path: Utf8PathBuf,
+    /// The range of the name of the definition
+    pub range: TextRange,
+    /// The line of the name of the definition, starting at 1
+    pub line: usize,
+    /// The column of the name of the definition, starting at 1
+    pub column: usize,
+}
+
+impl Display for SchemaLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.path, self.line, self.column)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+}
+
+impl TypeKind {
+    /// Returns whether values of this type have fields that can be selected.
+    pub const fn is_composite(self) -> bool {
+        matches!(self, Self::Object | Self::Interface | Self::Union)
+    }
+
+    /// Returns whether values of this type can't have a selection set.
+    pub const fn is_leaf(self) -> bool {
+        matches!(self, Self::Scalar | Self::Enum)
+    }
+
+    /// Returns whether this type can be used for arguments and variables.
+    pub const fn is_input(self) -> bool {
+        matches!(self, Self::Scalar | Self::Enum | Self::InputObject)
+    }
+}
+
+impl Display for TypeKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Scalar => f.write_str("scalar"),
+            Self::Object => f.write_str("object type"),
+            Self::Interface => f.write_str("interface"),
+            Self::Union => f.write_str("union"),
+            Self::Enum => f.write_str("enum"),
+            Self::InputObject => f.write_str("input object type"),
+        }
+    }
+}
+
+/// A named type of the schema, with the content of all its extensions.
+#[derive(Debug)]
+pub struct TypeDefinition {
+    pub(crate) name: String,
+    pub(crate) kind: TypeKind,
+    /// [None] for the built-in scalars
+    pub(crate) location: Option<SchemaLocation>,
+    /// The fields of object types and interfaces
+    pub(crate) fields: Vec<FieldDefinition>,
+    /// The fields of input object types
+    pub(crate) input_fields: Vec<InputValueDefinition>,
+    /// The values of enums
+    pub(crate) values: Vec<String>,
+    /// The members of unions
+    pub(crate) members: Vec<String>,
+    /// The interfaces implemented by object types and interfaces
+    pub(crate) interfaces: Vec<String>,
+}
+
+impl TypeDefinition {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> TypeKind {
+        self.kind
+    }
+
+    pub fn location(&self) -> Option<&SchemaLocation> {
+        self.location.as_ref()
+    }
+
+    pub fn fields(&self) -> &[FieldDefinition] {
+        &self.fields
+    }
+
+    pub fn field(&self, name: &str) -> Option<&FieldDefinition> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    pub fn input_fields(&self) -> &[InputValueDefinition] {
+        &self.input_fields
+    }
+
+    pub fn input_field(&self, name: &str) -> Option<&InputValueDefinition> {
+        self.input_fields.iter().find(|field| field.name == name)
+    }
+
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+
+    pub fn members(&self) -> &[String] {
+        &self.members
+    }
+
+    pub fn interfaces(&self) -> &[String] {
+        &self.interfaces
+    }
+
+    pub fn is_builtin(&self) -> bool {
+        self.location.is_none()
+    }
+}
+
+/// A field of an object type or an interface.
+#[derive(Debug)]
+pub struct FieldDefinition {
+    pub(crate) name: String,
+    pub(crate) ty: TypeReference,
+    pub(crate) arguments: Vec<InputValueDefinition>,
+    pub(crate) location: SchemaLocation,
+}
+
+impl FieldDefinition {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn ty(&self) -> &TypeReference {
+        &self.ty
+    }
+
+    pub fn arguments(&self) -> &[InputValueDefinition] {
+        &self.arguments
+    }
+
+    pub fn argument(&self, name: &str) -> Option<&InputValueDefinition> {
+        self.arguments.iter().find(|argument| argument.name == name)
+    }
+
+    pub fn location(&self) -> &SchemaLocation {
+        &self.location
+    }
+}
+
+/// An argument of a field or a directive, or a field of an input object type.
+#[derive(Debug)]
+pub struct InputValueDefinition {
+    pub(crate) name: String,
+    pub(crate) ty: TypeReference,
+    pub(crate) has_default: bool,
+    /// [None] for the arguments of the built-in directives
+    pub(crate) location: Option<SchemaLocation>,
+}
+
+impl InputValueDefinition {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn ty(&self) -> &TypeReference {
+        &self.ty
+    }
+
+    pub fn has_default(&self) -> bool {
+        self.has_default
+    }
+
+    /// Returns whether a value must be provided for this input value.
+    pub fn is_required(&self) -> bool {
+        self.ty.is_non_null() && !self.has_default
+    }
+
+    pub fn location(&self) -> Option<&SchemaLocation> {
+        self.location.as_ref()
+    }
+}
+
+/// A directive of the schema.
+#[derive(Debug)]
+pub struct DirectiveDefinition {
+    pub(crate) name: String,
+    pub(crate) arguments: Vec<InputValueDefinition>,
+    /// The locations where the directive can be used, such as `FIELD`
+    pub(crate) locations: Vec<String>,
+    /// [None] for the built-in directives
+    pub(crate) location: Option<SchemaLocation>,
+}
+
+impl DirectiveDefinition {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn arguments(&self) -> &[InputValueDefinition] {
+        &self.arguments
+    }
+
+    pub fn argument(&self, name: &str) -> Option<&InputValueDefinition> {
+        self.arguments.iter().find(|argument| argument.name == name)
+    }
+
+    pub fn locations(&self) -> &[String] {
+        &self.locations
+    }
+
+    /// Returns whether the directive can be used at `location`, such as `FIELD`.
+    pub fn is_allowed_on(&self, location: &str) -> bool {
+        self.locations.iter().any(|allowed| allowed == location)
+    }
+
+    pub fn location(&self) -> Option<&SchemaLocation> {
+        self.location.as_ref()
+    }
+}
+
+/// The type of a field, an argument or a variable, such as `[String!]!`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TypeReference {
+    Named(String),
+    List(Box<Self>),
+    NonNull(Box<Self>),
+}
+
+impl TypeReference {
+    pub fn from_syntax(ty: &AnyGraphqlType) -> Option<Self> {
+        match ty {
+            AnyGraphqlType::AnyGraphqlPrimitiveType(ty) => Self::from_primitive(ty),
+            AnyGraphqlType::GraphqlNonNullType(ty) => Some(Self::NonNull(Box::new(
+                Self::from_primitive(&ty.base().ok()?)?,
+            ))),
+            AnyGraphqlType::GraphqlBogusType(_) => None,
+        }
+    }
+
+    fn from_primitive(ty: &AnyGraphqlPrimitiveType) -> Option<Self> {
+        match ty {
+            AnyGraphqlPrimitiveType::GraphqlNameReference(name) => Some(Self::Named(
+                name.value_token().ok()?.text_trimmed().to_string(),
+            )),
+            AnyGraphqlPrimitiveType::GraphqlListType(list) => Some(Self::List(Box::new(
+                Self::from_syntax(&list.element().ok()?)?,
+            ))),
+        }
+    }
+
+    /// Returns the name of the innermost type, such as `String` for `[String!]!`.
+    pub fn named_type(&self) -> &str {
+        match self {
+            Self::Named(name) => name,
+            Self::List(ty) | Self::NonNull(ty) => ty.named_type(),
+        }
+    }
+
+    pub fn is_non_null(&self) -> bool {
+        matches!(self, Self::NonNull(_))
+    }
+
+    /// Returns the type without its non-null modifier.
+    pub fn nullable(&self) -> &Self {
+        match self {
+            Self::NonNull(ty) => ty,
+            ty => ty,
+        }
+    }
+
+    /// Returns whether a value of this type can be used where a value of type
+    /// `expected` is expected, in other words whether a variable of this type
+    /// can be passed to an argument of type `expected`.
+    pub fn is_compatible_with(&self, expected: &Self) -> bool {
+        match (self, expected) {
+            (Self::NonNull(ty), Self::NonNull(expected)) => ty.is_compatible_with(expected),
+            (Self::NonNull(ty), expected) => ty.is_compatible_with(expected),
+            (_, Self::NonNull(_)) => false,
+            (Self::List(ty), Self::List(expected)) => ty.is_compatible_with(expected),
+            (Self::Named(name), Self::Named(expected)) => name == expected,
+            (Self::List(_), Self::Named(_)) | (Self::Named(_), Self::List(_)) => false,
+        }
+    }
+}
+
+impl Display for TypeReference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Named(name) => f.write_str(name),
+            Self::List(ty) => write!(f, "[{ty}]"),
+            Self::NonNull(ty) => write!(f, "{ty}!"),
+        }
+    }
+}
+
+/// Returns whether `node` belongs to an executable document, as opposed to
+/// the type system, in other words whether it's part of an operation or a
+/// fragment.
+pub fn is_in_executable_definition(node: &GraphqlSyntaxNode) -> bool {
+    node.ancestors()
+        .find_map(AnyGraphqlDefinition::cast)
+        .is_some_and(|definition| {
+            matches!(
+                definition,
+                AnyGraphqlDefinition::GraphqlOperationDefinition(_)
+                    | AnyGraphqlDefinition::GraphqlSelectionSet(_)
+                    | AnyGraphqlDefinition::GraphqlFragmentDefinition(_)
+            )
+        })
+}
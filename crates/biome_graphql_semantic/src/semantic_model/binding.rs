@@ -2,7 +2,8 @@ use std::rc::Rc;
 
 use biome_graphql_syntax::{
     GraphqlDirective, GraphqlDirectiveDefinition, GraphqlFragmentDefinition, GraphqlFragmentSpread,
-    GraphqlNameBinding, GraphqlNameReference, GraphqlSyntaxNode,
+    GraphqlNameBinding, GraphqlNameReference, GraphqlSyntaxNode, GraphqlVariableBinding,
+    GraphqlVariableReference,
 };
 use biome_rowan::{AstNode, SyntaxNodeCast, TextRange};
 
@@ -84,6 +85,20 @@ impl IsBindingAstNode for GraphqlNameBinding {
     }
 }
 
+impl IsBindingAstNode for GraphqlVariableBinding {
+    type ReferenceAstNode = GraphqlVariableReference;
+    fn all_reference_nodes(&self, model: &SemanticModel) -> Vec<Self::ReferenceAstNode> {
+        let Some(binding) = model.as_variable_binding(self) else {
+            return vec![];
+        };
+        binding
+            .all_references()
+            .iter()
+            .filter_map(|r| r.syntax().clone().cast())
+            .collect()
+    }
+}
+
 impl IsBindingAstNode for GraphqlDirectiveDefinition {
     type ReferenceAstNode = GraphqlDirective;
     fn all_reference_nodes(&self, model: &SemanticModel) -> Vec<Self::ReferenceAstNode> {
@@ -2,7 +2,7 @@ use std::rc::Rc;
 
 use biome_graphql_syntax::{
     GraphqlNameBinding, GraphqlNameReference, GraphqlRoot, GraphqlSyntaxNode,
-    GraphqlVariableReference,
+    GraphqlVariableBinding, GraphqlVariableReference,
 };
 use biome_rowan::{AstNode, TextRange, TextSize};
 use rustc_hash::FxHashMap;
@@ -196,4 +196,14 @@ impl SemanticModel {
             index: id.into(),
         }
     }
+
+    /// Returns the [Binding] of a variable definition.
+    /// Returns [None] if the variable isn't defined by an operation.
+    pub fn as_variable_binding(&self, binding: &GraphqlVariableBinding) -> Option<Binding> {
+        let id = self.data.bindings_by_start.get(&binding.range().start())?;
+        Some(Binding {
+            data: self.data.clone(),
+            index: (*id).into(),
+        })
+    }
 }
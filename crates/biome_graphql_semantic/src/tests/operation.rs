@@ -4,8 +4,10 @@ use biome_graphql_syntax::GraphqlOperationDefinition;
 use biome_graphql_syntax::GraphqlVariableBinding;
 use biome_graphql_syntax::GraphqlVariableDefinition;
 use biome_graphql_syntax::GraphqlVariableReference;
+use biome_rowan::AstNode;
 
 use crate::HasDeclarationAstNodes;
+use crate::IsBindingAstNode;
 use crate::semantic_model;
 use crate::tests::extract_nodes;
 
@@ -187,3 +189,21 @@ query First {
     let expected_operation_definition = extract_node::<GraphqlOperationDefinition>(&parse_result);
     assert_eq!(referenced_operation, expected_operation_definition);
 }
+
+#[test]
+fn unused_variable_in_operation() {
+    let src = r#"
+query ($used: ID, $unused: ID) {
+	likeStory(storyId: $used)
+}
+"#;
+    let parse_result = parse_graphql(src);
+    let model = semantic_model(&parse_result.tree());
+
+    let unused_variables = extract_nodes::<GraphqlVariableBinding>(&parse_result)
+        .into_iter()
+        .filter(|binding| binding.all_reference_nodes(&model).is_empty())
+        .map(|binding| binding.to_trimmed_string())
+        .collect::<Vec<_>>();
+    assert_eq!(unused_variables, vec!["$unused"]);
+}
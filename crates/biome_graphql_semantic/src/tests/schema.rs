@@ -0,0 +1,152 @@
+use biome_graphql_parser::parse_graphql;
+use biome_graphql_syntax::GraphqlField;
+use biome_graphql_syntax::GraphqlInlineFragment;
+use biome_rowan::AstNode;
+use camino::Utf8Path;
+
+use crate::GraphqlSchema;
+use crate::GraphqlSchemaBuilder;
+use crate::OperationKind;
+use crate::TypeKind;
+use crate::TypeReference;
+
+use super::extract_node;
+use super::extract_node_by_name;
+
+fn build_schema(documents: &[(&str, &str)]) -> GraphqlSchema {
+    let mut builder = GraphqlSchemaBuilder::default();
+    for (path, src) in documents {
+        builder.add_document(Utf8Path::new(path), &parse_graphql(src).tree());
+    }
+    builder.build()
+}
+
+#[test]
+fn ok_types_and_extensions() {
+    let schema = build_schema(&[
+        (
+            "schema.graphql",
+            r#"
+interface Node { id: ID! }
+type User implements Node {
+  id: ID!
+  friends(first: Int = 10, after: String): [User!]!
+}
+enum Role { ADMIN }
+union SearchResult = User
+input UserFilter { role: Role! }
+"#,
+        ),
+        (
+            "extensions.graphql",
+            r#"
+extend enum Role { GUEST }
+extend type User { role: Role }
+type Query { search(filter: UserFilter): [SearchResult] }
+"#,
+        ),
+    ]);
+
+    let user = schema.get_type("User").unwrap();
+    assert_eq!(user.kind(), TypeKind::Object);
+    assert_eq!(user.interfaces(), ["Node"]);
+    assert!(user.field("role").is_some());
+
+    let friends = user.field("friends").unwrap();
+    assert_eq!(friends.ty().to_string(), "[User!]!");
+    assert_eq!(friends.ty().named_type(), "User");
+    assert!(!friends.argument("first").unwrap().is_required());
+
+    let location = friends.location();
+    assert_eq!(location.path, "schema.graphql");
+    assert_eq!((location.line, location.column), (5, 3));
+
+    assert_eq!(
+        schema.get_type("Role").unwrap().values(),
+        ["ADMIN", "GUEST"]
+    );
+    assert!(schema.get_type("String").unwrap().is_builtin());
+    assert!(
+        schema
+            .get_directive("include")
+            .unwrap()
+            .is_allowed_on("FIELD")
+    );
+    assert_eq!(
+        schema.root_type(OperationKind::Query).unwrap().name(),
+        "Query"
+    );
+    assert!(schema.root_type(OperationKind::Mutation).is_none());
+
+    let node = schema.get_type("Node").unwrap();
+    let search_result = schema.get_type("SearchResult").unwrap();
+    assert_eq!(schema.possible_types(node), ["User"]);
+    assert!(schema.can_spread(node, search_result));
+}
+
+#[test]
+fn ok_schema_definition() {
+    let schema = build_schema(&[(
+        "schema.graphql",
+        r#"
+schema { query: RootQuery }
+type RootQuery { hero: String }
+type Query { villain: String }
+"#,
+    )]);
+
+    assert_eq!(
+        schema.root_type(OperationKind::Query).unwrap().name(),
+        "RootQuery"
+    );
+}
+
+#[test]
+fn ok_type_of_selections() {
+    let schema = build_schema(&[(
+        "schema.graphql",
+        r#"
+type Query { hero: Character }
+interface Character { name: String }
+type Droid implements Character { name: String, primaryFunction: String }
+"#,
+    )]);
+    let parse_result = parse_graphql(
+        r#"
+query {
+  hero {
+    name
+    ... on Droid { primaryFunction }
+  }
+}
+"#,
+    );
+
+    let name = extract_node_by_name::<GraphqlField>(&parse_result, "name");
+    let (parent_type, definition) = schema.field_definition(&name).unwrap();
+    assert_eq!(parent_type.name(), "Character");
+    assert_eq!(definition.ty(), &TypeReference::Named("String".to_string()));
+
+    let primary_function = extract_node_by_name::<GraphqlField>(&parse_result, "primaryFunction");
+    let (parent_type, _) = schema.field_definition(&primary_function).unwrap();
+    assert_eq!(parent_type.name(), "Droid");
+
+    let fragment = extract_node::<GraphqlInlineFragment>(&parse_result);
+    let parent_type = schema
+        .type_of_parent_selection_set(fragment.syntax())
+        .unwrap();
+    assert_eq!(parent_type.name(), "Character");
+}
+
+#[test]
+fn ok_variable_types() {
+    let named = |name: &str| TypeReference::Named(name.to_string());
+    let non_null = |ty: TypeReference| TypeReference::NonNull(Box::new(ty));
+    let list = |ty: TypeReference| TypeReference::List(Box::new(ty));
+
+    assert!(non_null(named("ID")).is_compatible_with(&named("ID")));
+    assert!(!named("ID").is_compatible_with(&non_null(named("ID"))));
+    assert!(list(non_null(named("ID"))).is_compatible_with(&list(named("ID"))));
+    assert!(!list(named("ID")).is_compatible_with(&named("ID")));
+    assert!(!named("ID").is_compatible_with(&named("String")));
+}
@@ -764,7 +764,7 @@ impl SyntaxFactory for GritSyntaxFactory {
                 if let Some(element) = &current_element {
                     if matches!(
                         element.kind(),
-                        T![js] | T![css] | T![json] | T![grit] | T![html]
+                        T![js] | T![css] | T![json] | T![grit] | T![html] | T![graphql]
                     ) {
                         slots.mark_present();
                         current_element = elements.next();
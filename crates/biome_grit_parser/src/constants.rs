@@ -55,9 +55,10 @@ pub(crate) const SUPPORTED_ENGINE_SET: TokenSet<GritSyntaxKind> =
     token_set![T![biome], T![marzano]];
 
 pub(crate) const SUPPORTED_LANGUAGE_SET: TokenSet<GritSyntaxKind> =
-    token_set![T![js], T![json], T![css], T![grit], T![html]];
+    token_set![T![js], T![json], T![css], T![grit], T![html], T![graphql]];
 
-pub(crate) const SUPPORTED_LANGUAGE_SET_STR: &[&str] = &["js", "json", "css", "grit", "html"];
+pub(crate) const SUPPORTED_LANGUAGE_SET_STR: &[&str] =
+    &["js", "json", "css", "grit", "html", "graphql"];
 
 pub(crate) const SUPPORTED_LANGUAGE_FLAVOR_SET: TokenSet<GritSyntaxKind> =
     token_set![T![typescript], T![jsx]];
@@ -5,10 +5,13 @@ use crate::{
 use biome_diagnostics::{SourceCode, display::SourceFile};
 use biome_rowan::TextRange;
 use grit_pattern_matcher::{
-    binding::Binding, constant::Constant, effects::Effect, pattern::FileRegistry,
+    binding::Binding,
+    constant::Constant,
+    effects::Effect,
+    pattern::{FileRegistry, ResolvedPattern},
 };
 use grit_util::{
-    AnalysisLogBuilder, AnalysisLogs, AstNode, ByteRange, CodeRange, Range,
+    AnalysisLogBuilder, AnalysisLogs, AstNode, ByteRange, CodeRange, EffectKind, Range,
     error::{GritPatternError, GritResult},
 };
 use std::{borrow::Cow, collections::HashMap, path::Path};
@@ -157,14 +160,32 @@ impl<'a> Binding<'a, GritQueryContext> for GritBinding<'a> {
 
     fn linearized_text(
         &self,
-        _language: &GritTargetLanguage,
-        _effects: &[Effect<'a, GritQueryContext>],
-        _files: &FileRegistry<'a, GritQueryContext>,
-        _memo: &mut HashMap<grit_util::CodeRange, Option<String>>,
+        language: &GritTargetLanguage,
+        effects: &[Effect<'a, GritQueryContext>],
+        files: &FileRegistry<'a, GritQueryContext>,
+        memo: &mut HashMap<grit_util::CodeRange, Option<String>>,
         _distributed_indent: Option<usize>,
-        _logs: &mut AnalysisLogs,
+        logs: &mut AnalysisLogs,
     ) -> GritResult<Cow<'a, str>> {
-        Err(GritPatternError::new("Not implemented")) // TODO: Implement rewriting
+        match self {
+            Self::Node(node) => linearize_range(
+                node.source(),
+                node.text_trimmed_range(),
+                language,
+                effects,
+                files,
+                memo,
+                logs,
+            )
+            .map(Cow::Owned),
+            Self::Range(range, source) => {
+                linearize_range(source, *range, language, effects, files, memo, logs)
+                    .map(Cow::Owned)
+            }
+            Self::Empty(..) | Self::File(..) | Self::Constant(..) => {
+                Ok(self.text(language)?.into_owned().into())
+            }
+        }
     }
 
     fn text(&self, _language: &GritTargetLanguage) -> GritResult<Cow<'a, str>> {
@@ -285,6 +306,96 @@ impl<'a> Binding<'a, GritQueryContext> for GritBinding<'a> {
 /// Potential improvements:
 /// 1. Use cursors that are passed as arguments -- not clear if this would be faster.
 /// 2. Precompute hashes on all nodes, which define the equivalence relation. The check then becomes O(1).
+impl GritBinding<'_> {
+    /// Returns the range of the source text this binding is bound to, if any.
+    fn text_range(&self) -> Option<TextRange> {
+        match self {
+            Self::Node(node) => Some(node.text_trimmed_range()),
+            Self::Range(range, _) => Some(*range),
+            Self::File(..) | Self::Empty(..) | Self::Constant(..) => None,
+        }
+    }
+}
+
+/// Returns the text in the given `range` of `source`, after applying the
+/// effects that target bindings inside the range.
+///
+/// Replacements may refer to bindings that are rewritten themselves, so the
+/// text of each replacement is linearized recursively. The `memo` caches the
+/// replacements by range, and also prevents infinite recursion when a
+/// replacement refers to the binding it replaces: while it is being computed,
+/// the range maps to `None` and the binding is left unchanged.
+pub(crate) fn linearize_range<'a>(
+    source: &'a str,
+    range: TextRange,
+    language: &GritTargetLanguage,
+    effects: &[Effect<'a, GritQueryContext>],
+    files: &FileRegistry<'a, GritQueryContext>,
+    memo: &mut HashMap<CodeRange, Option<String>>,
+    logs: &mut AnalysisLogs,
+) -> GritResult<String> {
+    let mut replacements: Vec<(TextRange, String)> = Vec::new();
+    for effect in effects {
+        let binding = &effect.binding;
+        let Some(binding_range) = binding.text_range() else {
+            continue;
+        };
+        if !binding.source().is_some_and(|binding_source| {
+            std::ptr::eq(binding_source, source) && range.contains_range(binding_range)
+        }) {
+            continue;
+        }
+
+        match effect.kind {
+            EffectKind::Rewrite => {
+                let key = binding_range.to_code_range(source);
+                let replacement = match memo.get(&key) {
+                    Some(Some(replacement)) => replacement.clone(),
+                    Some(None) => continue,
+                    None => {
+                        memo.insert(key.clone(), None);
+                        let replacement = effect
+                            .pattern
+                            .linearized_text(language, effects, files, memo, false, logs)?
+                            .into_owned();
+                        memo.insert(key, Some(replacement.clone()));
+                        replacement
+                    }
+                };
+                replacements.push((binding_range, replacement));
+            }
+            EffectKind::Insert => {
+                let insertion = effect.pattern.text(files, language)?.into_owned();
+                replacements.push((TextRange::empty(binding_range.end()), insertion));
+            }
+        }
+    }
+
+    // Outer rewrites come first, so the rewrites nested inside them are
+    // skipped below. Those are already part of the outer replacement if it
+    // refers to them.
+    replacements.sort_by(|(a, _), (b, _)| {
+        a.start()
+            .cmp(&b.start())
+            .then_with(|| b.len().cmp(&a.len()))
+    });
+
+    let mut text = String::with_capacity(range.len().into());
+    let mut offset = range.start();
+    for (replaced_range, replacement) in replacements {
+        if replaced_range.start() < offset {
+            continue;
+        }
+
+        text.push_str(&source[TextRange::new(offset, replaced_range.start())]);
+        text.push_str(&replacement);
+        offset = replaced_range.end();
+    }
+    text.push_str(&source[TextRange::new(offset, range.end())]);
+
+    Ok(text)
+}
+
 fn are_equivalent(node1: &GritTargetNode, node2: &GritTargetNode) -> bool {
     // If the source is identical, we consider the nodes equivalent.
     // This covers most cases of constant nodes.
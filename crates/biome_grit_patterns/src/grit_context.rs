@@ -1,14 +1,16 @@
-use crate::grit_binding::GritBinding;
+use crate::grit_binding::{GritBinding, linearize_range};
 use crate::grit_built_in_functions::BuiltIns;
 use crate::grit_code_snippet::GritCodeSnippet;
 use crate::grit_file::GritFile;
 use crate::grit_node_patterns::{GritLeafNodePattern, GritNodePattern};
+use crate::grit_query::GritDiagnostic;
 use crate::grit_resolved_pattern::GritResolvedPattern;
 use crate::grit_target_language::GritTargetLanguage;
 use crate::grit_target_node::GritTargetNode;
 use crate::grit_tree::GritTargetTree;
-use biome_analyze::RuleDiagnostic;
+use biome_analyze::{FixKind, RuleDiagnostic};
 use biome_parser::AnyParse;
+use biome_rowan::{TextRange, TextSize};
 use camino::Utf8PathBuf;
 use grit_pattern_matcher::constants::{GLOBAL_VARS_SCOPE_INDEX, NEW_FILES_INDEX};
 use grit_pattern_matcher::context::{ExecContext, QueryContext};
@@ -20,7 +22,7 @@ use grit_pattern_matcher::pattern::{
 use grit_util::error::GritPatternError;
 use grit_util::{AnalysisLogs, FileOrigin, InputRanges, MatchRanges, error::GritResult};
 use path_absolutize::Absolutize;
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
@@ -54,11 +56,11 @@ pub struct GritExecContext<'a> {
     pub patterns: &'a [PatternDefinition<GritQueryContext>],
     pub predicates: &'a [PredicateDefinition<GritQueryContext>],
 
-    pub diagnostics: Mutex<Vec<RuleDiagnostic>>,
+    pub diagnostics: Mutex<Vec<GritDiagnostic>>,
 }
 
 impl GritExecContext<'_> {
-    pub fn add_diagnostic(&self, diagnostic: RuleDiagnostic) {
+    pub fn add_diagnostic(&self, diagnostic: RuleDiagnostic, fix_kind: FixKind) {
         let mut diagnostics = self.diagnostics.lock().unwrap();
         // Make sure we don't add multiple messages for the same span.
         // I think this happens when a node and its child(ren) both match the
@@ -67,13 +69,16 @@ impl GritExecContext<'_> {
         // Grit pattern matcher.
         if diagnostics
             .last()
-            .is_none_or(|last| last.span() != diagnostic.span())
+            .is_none_or(|last| last.diagnostic.span() != diagnostic.span())
         {
-            diagnostics.push(diagnostic);
+            diagnostics.push(GritDiagnostic {
+                diagnostic,
+                fix_kind,
+            });
         }
     }
 
-    pub fn into_diagnostics(self) -> Vec<RuleDiagnostic> {
+    pub fn into_diagnostics(self) -> Vec<GritDiagnostic> {
         self.diagnostics.into_inner().unwrap()
     }
 }
@@ -183,8 +188,46 @@ impl<'a> ExecContext<'a, GritQueryContext> for GritExecContext<'a> {
             if match_log.input_matches.is_none() {
                 match_log.input_matches = Some(input_ranges.clone());
             }
+            drop(match_log);
+
+            if state.effects.is_empty() {
+                continue;
+            }
+
+            let source = file.tree.text();
+            let mut memo = HashMap::new();
+            let new_source = linearize_range(
+                source,
+                TextRange::up_to(TextSize::of(source)),
+                &self.lang,
+                &state.effects,
+                &state.files,
+                &mut memo,
+                logs,
+            )?;
+            if new_source == source {
+                continue;
+            }
+
+            let Some(tree) = self.lang.get_parser().parse_file(
+                &new_source,
+                Some(&file.name),
+                logs,
+                FileOrigin::Mutated,
+            ) else {
+                continue;
+            };
 
-            // TODO: Implement effect application
+            self.files.push(FileOwner {
+                name: file.name.clone(),
+                absolute_path: file.absolute_path.clone(),
+                tree,
+                matches: Default::default(),
+                new: false,
+            });
+            state
+                .files
+                .push_revision(&file_ptr, self.files.last().unwrap());
         }
 
         let new_files_binding = &mut state.bindings[GLOBAL_VARS_SCOPE_INDEX as usize]
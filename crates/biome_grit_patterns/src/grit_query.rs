@@ -13,7 +13,7 @@ use crate::pattern_compiler::{
 };
 use crate::variables::{VarRegistry, VariableLocations};
 use crate::{BuiltInFunction, CompileError};
-use biome_analyze::RuleDiagnostic;
+use biome_analyze::{FixKind, RuleDiagnostic};
 use biome_grit_syntax::{GritRoot, GritRootExt};
 use camino::Utf8Path;
 use grit_pattern_matcher::constants::{
@@ -197,22 +197,27 @@ impl GritQuery {
         })
     }
 
-    pub fn supports_css(&self) -> bool {
-        matches!(self.language, GritTargetLanguage::CssTargetLanguage(_))
-    }
-
-    pub fn supports_js(&self) -> bool {
-        matches!(self.language, GritTargetLanguage::JsTargetLanguage(_))
+    /// Returns the language the query targets.
+    pub fn language(&self) -> &GritTargetLanguage {
+        &self.language
     }
 }
 
 #[derive(Debug)]
 pub struct GritQueryResult {
     pub effects: Vec<GritQueryEffect>,
-    pub diagnostics: Vec<RuleDiagnostic>,
+    pub diagnostics: Vec<GritDiagnostic>,
     pub logs: AnalysisLogs,
 }
 
+/// Diagnostic registered by a query, along with the kind of fix its rewrites
+/// are allowed to apply.
+#[derive(Debug)]
+pub struct GritDiagnostic {
+    pub diagnostic: RuleDiagnostic,
+    pub fix_kind: FixKind,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum GritQueryEffect {
     Match(Match),
@@ -302,6 +307,8 @@ impl Match {
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Rewrite {
     pub original: Match,
+    /// Content of the file before it was rewritten.
+    pub original_content: String,
     pub rewritten: OutputFile,
 }
 
@@ -312,9 +319,10 @@ impl From<Rewrite> for GritQueryEffect {
 }
 
 impl Rewrite {
-    fn new(original: Match, rewritten: OutputFile) -> Self {
+    fn new(original: Match, original_content: String, rewritten: OutputFile) -> Self {
         Self {
             original,
+            original_content,
             rewritten,
         }
     }
@@ -329,7 +337,11 @@ impl Rewrite {
             return Err(GritPatternError::new("cannot have rewrite without matches"));
         };
         let rewritten = OutputFile::from_file(rewritten_file);
-        Ok(Self::new(original, rewritten))
+        Ok(Self::new(
+            original,
+            initial.tree.source().into_owned(),
+            rewritten,
+        ))
     }
 }
 
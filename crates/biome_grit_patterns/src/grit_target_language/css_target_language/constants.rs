@@ -3,5 +3,15 @@
 use crate::grit_target_language::DisregardedSlotCondition::{self, *};
 use biome_css_syntax::CssSyntaxKind::{self, *};
 
-pub(crate) const DISREGARDED_SNIPPET_SLOTS: &[(CssSyntaxKind, u32, DisregardedSlotCondition)] =
-    &[(CSS_DECLARATION_WITH_SEMICOLON, 1, Always)];
+pub(crate) const DISREGARDED_SNIPPET_SLOTS: &[(CssSyntaxKind, u32, DisregardedSlotCondition)] = &[
+    (CSS_DECLARATION_WITH_SEMICOLON, 1, Always),
+    (LESS_DETACHED_RULESET_CALL, 3, Always),
+    (LESS_DETACHED_RULESET_DECLARATION, 3, Always),
+    (LESS_MIXIN_CALL, 3, Always),
+    (LESS_VARIABLE_DECLARATION, 3, Always),
+    (SCSS_CONTENT_AT_RULE, 2, Always),
+    (SCSS_DECLARATION, 4, Always),
+    (SCSS_EXTEND_AT_RULE, 3, Always),
+    (SCSS_INCLUDE_AT_RULE, 4, Always),
+    (SCSS_RETURN_AT_RULE, 2, Always),
+];
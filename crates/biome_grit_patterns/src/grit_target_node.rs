@@ -1,7 +1,10 @@
 use crate::grit_tree::GritTargetTree;
 use crate::util::TextRangeGritExt;
 use biome_css_syntax::{CssSyntaxKind, CssSyntaxNode, CssSyntaxToken};
+use biome_graphql_syntax::{GraphqlSyntaxKind, GraphqlSyntaxNode, GraphqlSyntaxToken};
+use biome_html_syntax::{HtmlSyntaxKind, HtmlSyntaxNode, HtmlSyntaxToken};
 use biome_js_syntax::{JsSyntaxKind, JsSyntaxNode, JsSyntaxToken};
+use biome_json_syntax::{JsonSyntaxKind, JsonSyntaxNode, JsonSyntaxToken};
 use biome_rowan::{NodeOrToken, SyntaxKind, SyntaxSlot, TextRange};
 use grit_util::{AstCursor, AstNode as GritAstNode, ByteRange, CodeRange, error::GritResult};
 use std::{borrow::Cow, fmt::Debug, ops::Deref};
@@ -183,7 +186,10 @@ macro_rules! generate_target_node {
 
 generate_target_node! {
     [CssLanguage, CssSyntaxNode, CssSyntaxToken, CssSyntaxKind],
-    [JsLanguage, JsSyntaxNode, JsSyntaxToken, JsSyntaxKind]
+    [GraphqlLanguage, GraphqlSyntaxNode, GraphqlSyntaxToken, GraphqlSyntaxKind],
+    [HtmlLanguage, HtmlSyntaxNode, HtmlSyntaxToken, HtmlSyntaxKind],
+    [JsLanguage, JsSyntaxNode, JsSyntaxToken, JsSyntaxKind],
+    [JsonLanguage, JsonSyntaxNode, JsonSyntaxToken, JsonSyntaxKind]
 }
 
 #[derive(Clone, PartialEq)]
@@ -395,12 +401,33 @@ impl GritTargetSyntaxKind {
         }
     }
 
+    pub fn as_graphql_kind(&self) -> Option<GraphqlSyntaxKind> {
+        match self {
+            Self::GraphqlSyntaxKind(kind) => Some(*kind),
+            _ => None,
+        }
+    }
+
+    pub fn as_html_kind(&self) -> Option<HtmlSyntaxKind> {
+        match self {
+            Self::HtmlSyntaxKind(kind) => Some(*kind),
+            _ => None,
+        }
+    }
+
     pub fn as_js_kind(&self) -> Option<JsSyntaxKind> {
         match self {
             Self::JsSyntaxKind(kind) => Some(*kind),
             _ => None,
         }
     }
+
+    pub fn as_json_kind(&self) -> Option<JsonSyntaxKind> {
+        match self {
+            Self::JsonSyntaxKind(kind) => Some(*kind),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
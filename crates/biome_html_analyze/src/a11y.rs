@@ -0,0 +1,20 @@
+use biome_html_syntax::element_ext::AnyHtmlTagElement;
+
+/// Check the element is hidden from screen readers with `aria-hidden="true"`.
+///
+/// Ref:
+/// - https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Attributes/aria-hidden
+pub(crate) fn is_aria_hidden(element: &AnyHtmlTagElement) -> bool {
+    element
+        .find_attribute_by_name("aria-hidden")
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"))
+}
+
+/// Check the element has an attribute named `name` whose value isn't empty.
+pub(crate) fn has_non_empty_attribute(element: &AnyHtmlTagElement, name: &str) -> bool {
+    element
+        .find_attribute_by_name(name)
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| !value.trim().is_empty())
+}
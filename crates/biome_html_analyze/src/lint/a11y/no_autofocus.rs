@@ -0,0 +1,113 @@
+use crate::HtmlRuleAction;
+use biome_analyze::{
+    Ast, FixKind, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_html_syntax::{HtmlAttribute, HtmlElement, element_ext::AnyHtmlTagElement};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_lint_rule! {
+    /// Enforce that the `autofocus` attribute is not used on elements.
+    ///
+    /// Autofocusing elements can cause usability issues for sighted and non-sighted users, alike.
+    /// But the autofocus attribute should be added to the element the user is expected to
+    /// interact with immediately upon opening a modal dialog or popover.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <input autofocus>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <input autofocus="false">
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <input>
+    /// ```
+    ///
+    /// ```html
+    /// <div popover><input autofocus></div>
+    /// ```
+    ///
+    /// ```html
+    /// <dialog><input autofocus></dialog>
+    /// ```
+    ///
+    /// ## Resources
+    ///
+    /// - [WHATWG HTML Standard, The autofocus attribute](https://html.spec.whatwg.org/multipage/interaction.html#attr-fe-autofocus)
+    /// - [The accessibility of HTML 5 autofocus](https://brucelawson.co.uk/2009/the-accessibility-of-html-5-autofocus/)
+    ///
+    pub NoAutofocus {
+        version: "next",
+        name: "noAutofocus",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("no-autofocus").same()],
+        recommended: true,
+        severity: Severity::Error,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for NoAutofocus {
+    type Query = Ast<AnyHtmlTagElement>;
+    type State = HtmlAttribute;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+        let attribute = element.find_attribute_by_name("autofocus")?;
+        if is_inside_dialog_or_popover(element) {
+            return None;
+        }
+        Some(attribute)
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, attribute: &Self::State) -> Option<RuleDiagnostic> {
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            attribute.syntax().text_trimmed_range(),
+            markup! {
+                "Avoid the "<Emphasis>"autofocus"</Emphasis>" attribute."
+            },
+        ))
+    }
+
+    fn action(ctx: &RuleContext<Self>, attribute: &Self::State) -> Option<HtmlRuleAction> {
+        let mut mutation = ctx.root().begin();
+        mutation.remove_node(attribute.clone());
+        Some(HtmlRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Remove the "<Emphasis>"autofocus"</Emphasis>" attribute." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if one of the ancestors of the element is a `dialog` element
+/// or an element with the `popover` attribute
+fn is_inside_dialog_or_popover(element: &AnyHtmlTagElement) -> bool {
+    // The opening tag of an element belongs to the element itself, which isn't an ancestor
+    let parent = match element.parent_element() {
+        Some(element) => element.syntax().parent(),
+        None => element.syntax().parent(),
+    };
+    parent
+        .into_iter()
+        .flat_map(|parent| parent.ancestors())
+        .filter_map(HtmlElement::cast)
+        .filter_map(|ancestor| ancestor.opening_element().ok())
+        .map(AnyHtmlTagElement::from)
+        .any(|ancestor| {
+            ancestor.has_name("dialog") || ancestor.find_attribute_by_name("popover").is_some()
+        })
+}
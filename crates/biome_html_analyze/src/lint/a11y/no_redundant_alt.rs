@@ -0,0 +1,93 @@
+use crate::a11y::is_aria_hidden;
+use biome_analyze::{
+    Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_html_syntax::{HtmlString, element_ext::AnyHtmlTagElement};
+use biome_rowan::AstNode;
+use biome_string_case::StrLikeExtension;
+
+declare_lint_rule! {
+    /// Enforce `img` alt attribute does not contain the word "image", "picture", or "photo".
+    ///
+    /// The rule will first check if `aria-hidden` is `true` to determine whether to enforce the rule. If the image is
+    /// hidden, then the rule will always succeed.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <img src="src" alt="photo content">
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <img alt="picture of cool person" aria-hidden="false">
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <img src="src" alt="alt">
+    /// ```
+    ///
+    /// ```html
+    /// <img src="bar" aria-hidden="true" alt="Picture of me taking a photo of an image">
+    /// ```
+    ///
+    pub NoRedundantAlt {
+        version: "next",
+        name: "noRedundantAlt",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("img-redundant-alt").same()],
+        recommended: true,
+        severity: Severity::Error,
+    }
+}
+
+impl Rule for NoRedundantAlt {
+    type Query = Ast<AnyHtmlTagElement>;
+    type State = HtmlString;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if !node.has_name("img") || is_aria_hidden(node) {
+            return None;
+        }
+
+        let alt = node
+            .find_attribute_by_name("alt")?
+            .initializer()?
+            .value()
+            .ok()?;
+        let inner_string_text = alt.inner_string_text().ok()?;
+        is_redundant_alt(inner_string_text.text()).then_some(alt)
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range(),
+                markup! {
+                    "Avoid the words \"image\", \"picture\", or \"photo\" in " <Emphasis>"img"</Emphasis>" element alt text."
+                },
+            )
+            .note(markup! {
+                "Screen readers announce img elements as \"images\", so it is not necessary to redeclare this in alternative text."
+            }),
+        )
+    }
+}
+
+const REDUNDANT_WORDS: [&str; 3] = ["image", "photo", "picture"];
+
+fn is_redundant_alt(alt: &str) -> bool {
+    REDUNDANT_WORDS.into_iter().any(|word| {
+        alt.split_whitespace()
+            .any(|x| x.to_ascii_lowercase_cow() == word)
+    })
+}
@@ -0,0 +1,147 @@
+use crate::a11y::{has_non_empty_attribute, is_aria_hidden};
+use biome_analyze::{
+    Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::{fmt::Display, fmt::Formatter, markup};
+use biome_diagnostics::Severity;
+use biome_html_syntax::{AnyHtmlElement, element_ext::AnyHtmlTagElement};
+use biome_rowan::{AstNode, TextRange};
+use biome_string_case::StrLikeExtension;
+
+declare_lint_rule! {
+    /// Enforce that all elements that require alternative text have meaningful information to relay back to the end user.
+    ///
+    /// This is a critical component of accessibility for screen reader users in order for them to understand the content's purpose on the page.
+    /// By default, this rule checks for alternative text on the following elements: `<img>`, `<area>`, `<input type="image">`, and `<object>`.
+    ///
+    /// An empty `alt` attribute is valid: it marks the image as decorative.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <img src="image.png">
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <input type="image" src="image.png">
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <img src="image.png" alt="image alt">
+    /// ```
+    ///
+    /// ```html
+    /// <img src="divider.png" alt="">
+    /// ```
+    ///
+    /// ```html
+    /// <input type="image" src="image.png" aria-label="alt text">
+    /// ```
+    ///
+    /// ```html
+    /// <object data="movie.mp4">A movie about cats</object>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 1.1.1](https://www.w3.org/WAI/WCAG21/Understanding/non-text-content.html)
+    ///
+    pub UseAltText {
+        version: "next",
+        name: "useAltText",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("alt-text").same()],
+        recommended: true,
+        severity: Severity::Error,
+    }
+}
+
+pub enum ValidatedElement {
+    Object,
+    Img,
+    Area,
+    Input,
+}
+
+impl Display for ValidatedElement {
+    fn fmt(&self, fmt: &mut Formatter) -> std::io::Result<()> {
+        match self {
+            Self::Object => fmt.write_markup(markup!(<Emphasis>"title"</Emphasis>)),
+            _ => fmt.write_markup(markup!(<Emphasis>"alt"</Emphasis>)),
+        }
+    }
+}
+
+impl Rule for UseAltText {
+    type Query = Ast<AnyHtmlTagElement>;
+    type State = (ValidatedElement, TextRange);
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+        let name = element.name_value_token().ok()?;
+        let validated_element = match name.text_trimmed().to_ascii_lowercase_cow().as_ref() {
+            "img" => ValidatedElement::Img,
+            "area" => ValidatedElement::Area,
+            "input" if has_type_image_attribute(element) => ValidatedElement::Input,
+            "object" => ValidatedElement::Object,
+            _ => return None,
+        };
+
+        if has_non_empty_attribute(element, "aria-label")
+            || has_non_empty_attribute(element, "aria-labelledby")
+            || is_aria_hidden(element)
+        {
+            return None;
+        }
+
+        let has_alternative_text = match validated_element {
+            ValidatedElement::Object => {
+                has_non_empty_attribute(element, "title") || has_accessible_child(element)
+            }
+            _ => element.find_attribute_by_name("alt").is_some(),
+        };
+
+        if has_alternative_text {
+            None
+        } else {
+            Some((validated_element, element.syntax().text_trimmed_range()))
+        }
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (validate_element, range) = state;
+        let message = markup!(
+            "Provide a text alternative through the "{{validate_element}}", "<Emphasis>"aria-label"</Emphasis>" or "<Emphasis>"aria-labelledby"</Emphasis>" attribute"
+        ).to_owned();
+        Some(
+            RuleDiagnostic::new(rule_category!(), range, message).note(markup! {
+                "Meaningful alternative text on elements helps users relying on screen readers to understand content's purpose within a page."
+            }).note(markup! { "If the content is decorative, redundant, or obscured, consider hiding it from assistive technologies with the "<Emphasis>"aria-hidden"</Emphasis>" attribute."}),
+        )
+    }
+}
+
+fn has_type_image_attribute(element: &AnyHtmlTagElement) -> bool {
+    element
+        .find_attribute_by_name("type")
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| value.eq_ignore_ascii_case("image"))
+}
+
+/// Returns `true` if the element has children that describe its content,
+/// such as the fallback text of an `<object>` element
+fn has_accessible_child(element: &AnyHtmlTagElement) -> bool {
+    element.parent_element().is_some_and(|element| {
+        element.children().into_iter().any(|child| match child {
+            AnyHtmlElement::HtmlContent(content) => !content.syntax().text_trimmed().is_empty(),
+            AnyHtmlElement::HtmlElement(_) | AnyHtmlElement::HtmlSelfClosingElement(_) => true,
+            _ => false,
+        })
+    })
+}
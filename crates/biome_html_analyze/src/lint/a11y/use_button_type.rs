@@ -0,0 +1,102 @@
+use biome_analyze::{
+    Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_html_syntax::element_ext::AnyHtmlTagElement;
+use biome_rowan::{AstNode, TextRange};
+
+declare_lint_rule! {
+    /// Enforces the usage of the attribute `type` for the element `button`
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <button>Do something</button>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <button type="incorrectType">Do something</button>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <button type="button">Do something</button>
+    /// ```
+    pub UseButtonType {
+        version: "next",
+        name: "useButtonType",
+        language: "html",
+        sources: &[RuleSource::EslintReact("button-has-type").same()],
+        recommended: true,
+        severity: Severity::Error,
+    }
+}
+
+const ALLOWED_BUTTON_TYPES: [&str; 3] = ["submit", "button", "reset"];
+
+pub struct UseButtonTypeState {
+    range: TextRange,
+    missing_prop: bool,
+}
+
+impl Rule for UseButtonType {
+    type Query = Ast<AnyHtmlTagElement>;
+    type State = UseButtonTypeState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+        if !element.has_name("button") {
+            return None;
+        }
+
+        let Some(attribute) = element.find_attribute_by_name("type") else {
+            return Some(UseButtonTypeState {
+                range: element.syntax().text_trimmed_range(),
+                missing_prop: true,
+            });
+        };
+        let Some(value) = attribute.value() else {
+            return Some(UseButtonTypeState {
+                range: attribute.syntax().text_trimmed_range(),
+                missing_prop: false,
+            });
+        };
+        let is_allowed = ALLOWED_BUTTON_TYPES
+            .iter()
+            .any(|allowed| value.trim().eq_ignore_ascii_case(allowed));
+        (!is_allowed).then(|| UseButtonTypeState {
+            range: attribute.syntax().text_trimmed_range(),
+            missing_prop: false,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let message = if state.missing_prop {
+            (markup! {
+                "Provide an explicit "<Emphasis>"type"</Emphasis>" attribute for the "<Emphasis>"button"</Emphasis>" element."
+            }).to_owned()
+        } else {
+            (markup!{
+                "Provide a valid "<Emphasis>"type"</Emphasis>" attribute for the "<Emphasis>"button"</Emphasis>" element."
+            }).to_owned()
+        };
+        Some(RuleDiagnostic::new(rule_category!(),
+            state.range,
+            message
+        )
+            .note(markup! {
+                "The default "<Emphasis>"type"</Emphasis>" of a button is "<Emphasis>"submit"</Emphasis>", which causes the submission of a form when placed inside a `form` element."
+            })
+            .note(
+            markup! {
+                "Allowed button types are: "<Emphasis>"submit"</Emphasis>", "<Emphasis>"button"</Emphasis>" or "<Emphasis>"reset"</Emphasis>""
+            }
+        ))
+    }
+}
@@ -0,0 +1,73 @@
+use crate::a11y::has_non_empty_attribute;
+use biome_analyze::{
+    Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_html_syntax::element_ext::AnyHtmlTagElement;
+use biome_rowan::AstNode;
+
+declare_lint_rule! {
+    /// Enforces the usage of the attribute `title` for the element `iframe`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <iframe></iframe>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <iframe title=""></iframe>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <iframe title="This is a unique title"></iframe>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 2.4.1](https://www.w3.org/WAI/WCAG21/Understanding/bypass-blocks)
+    /// - [WCAG 4.1.2](https://www.w3.org/WAI/WCAG21/Understanding/name-role-value)
+    ///
+    pub UseIframeTitle {
+        version: "next",
+        name: "useIframeTitle",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("iframe-has-title").same()],
+        recommended: true,
+        severity: Severity::Error,
+    }
+}
+
+impl Rule for UseIframeTitle {
+    type Query = Ast<AnyHtmlTagElement>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+
+        (element.has_name("iframe") && !has_non_empty_attribute(element, "title")).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.syntax().text_trimmed_range(),
+                markup! {
+                    "Provide a "<Emphasis>"title"</Emphasis>" attribute when using "<Emphasis>"iframe"</Emphasis>" elements."
+                }
+            )
+            .note(markup! {
+                "Screen readers rely on the title set on an iframe to describe the content being displayed."
+            }),
+        )
+    }
+}
@@ -0,0 +1,24 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::lint;
+pub type NoAccessKey = <lint::a11y::no_access_key::NoAccessKey as biome_analyze::Rule>::Options;
+pub type NoAutofocus = <lint::a11y::no_autofocus::NoAutofocus as biome_analyze::Rule>::Options;
+pub type NoDistractingElements =
+    <lint::a11y::no_distracting_elements::NoDistractingElements as biome_analyze::Rule>::Options;
+pub type NoHeaderScope =
+    <lint::a11y::no_header_scope::NoHeaderScope as biome_analyze::Rule>::Options;
+pub type NoPositiveTabindex =
+    <lint::a11y::no_positive_tabindex::NoPositiveTabindex as biome_analyze::Rule>::Options;
+pub type NoRedundantAlt =
+    <lint::a11y::no_redundant_alt::NoRedundantAlt as biome_analyze::Rule>::Options;
+pub type NoRedundantRoles =
+    <lint::a11y::no_redundant_roles::NoRedundantRoles as biome_analyze::Rule>::Options;
+pub type UseAltText = <lint::a11y::use_alt_text::UseAltText as biome_analyze::Rule>::Options;
+pub type UseButtonType =
+    <lint::a11y::use_button_type::UseButtonType as biome_analyze::Rule>::Options;
+pub type UseHtmlLang = <lint::a11y::use_html_lang::UseHtmlLang as biome_analyze::Rule>::Options;
+pub type UseIframeTitle =
+    <lint::a11y::use_iframe_title::UseIframeTitle as biome_analyze::Rule>::Options;
+pub type UseValidLang = <lint::a11y::use_valid_lang::UseValidLang as biome_analyze::Rule>::Options;
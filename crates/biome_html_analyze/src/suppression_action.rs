@@ -0,0 +1,42 @@
+use biome_analyze::{ApplySuppression, SuppressionAction};
+use biome_html_syntax::{HtmlLanguage, HtmlSyntaxToken};
+use biome_rowan::BatchMutation;
+
+/// The HTML parser emits comments as nodes instead of trivia, and the analyzer only
+/// reads suppression comments from the trivia of tokens.
+///
+/// Until the analyzer can read them, this action doesn't insert any suppression comment,
+/// because it wouldn't suppress anything.
+pub(crate) struct HtmlSuppressionAction;
+
+impl SuppressionAction for HtmlSuppressionAction {
+    type Language = HtmlLanguage;
+
+    fn suppression_top_level_comment(&self, suppression_text: &str) -> String {
+        format!("<!-- {suppression_text}: <explanation> -->")
+    }
+
+    fn find_token_for_inline_suppression(
+        &self,
+        _token: HtmlSyntaxToken,
+    ) -> Option<ApplySuppression<Self::Language>> {
+        None
+    }
+
+    fn apply_inline_suppression(
+        &self,
+        _mutation: &mut BatchMutation<Self::Language>,
+        _apply_suppression: ApplySuppression<Self::Language>,
+        _suppression_text: &str,
+        _suppression_reason: &str,
+    ) {
+    }
+
+    fn apply_top_level_suppression(
+        &self,
+        _mutation: &mut BatchMutation<Self::Language>,
+        _token: HtmlSyntaxToken,
+        _suppression_text: &str,
+    ) {
+    }
+}
@@ -176,6 +176,12 @@ pub fn html_element(
         ],
     ))
 }
+pub fn html_metavariable(value_token: SyntaxToken) -> HtmlMetavariable {
+    HtmlMetavariable::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::HTML_METAVARIABLE,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
 pub fn html_opening_element(
     l_angle_token: SyntaxToken,
     name: HtmlTagName,
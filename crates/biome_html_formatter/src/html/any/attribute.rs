@@ -10,6 +10,7 @@ impl FormatRule<AnyHtmlAttribute> for FormatAnyHtmlAttribute {
         match node {
             AnyHtmlAttribute::HtmlAttribute(node) => node.format().fmt(f),
             AnyHtmlAttribute::HtmlBogusAttribute(node) => node.format().fmt(f),
+            AnyHtmlAttribute::HtmlMetavariable(node) => node.format().fmt(f),
         }
     }
 }
@@ -0,0 +1,10 @@
+use crate::prelude::*;
+use biome_html_syntax::HtmlMetavariable;
+use biome_rowan::AstNode;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatHtmlMetavariable;
+impl FormatNodeRule<HtmlMetavariable> for FormatHtmlMetavariable {
+    fn fmt_fields(&self, node: &HtmlMetavariable, f: &mut HtmlFormatter) -> FormatResult<()> {
+        format_verbatim_node(node.syntax()).fmt(f)
+    }
+}
@@ -1,5 +1,5 @@
 use biome_diagnostics::{DiagnosticExt, print_diagnostic_to_string};
-use biome_html_parser::{parse_html, parse_html_with_cache};
+use biome_html_parser::{HtmlParserOptions, parse_html, parse_html_with_cache};
 use biome_rowan::NodeCache;
 use biome_test_utils::BenchCase;
 use criterion::{
@@ -59,11 +59,19 @@ fn bench_parser(criterion: &mut Criterion) {
                         b.iter_batched(
                             || {
                                 let mut cache = NodeCache::default();
-                                parse_html_with_cache(code, &mut cache);
+                                parse_html_with_cache(
+                                    code,
+                                    &mut cache,
+                                    HtmlParserOptions::default(),
+                                );
                                 cache
                             },
                             |mut cache| {
-                                parse_html_with_cache(code, &mut cache);
+                                parse_html_with_cache(
+                                    code,
+                                    &mut cache,
+                                    HtmlParserOptions::default(),
+                                );
                             },
                             BatchSize::SmallInput,
                         )
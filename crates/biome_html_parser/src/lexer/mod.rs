@@ -1,9 +1,10 @@
 mod tests;
 
+use crate::HtmlParserOptions;
 use crate::token_source::{HtmlEmbededLanguage, HtmlLexContext};
 use biome_html_syntax::HtmlSyntaxKind::{
-    DOCTYPE_KW, EOF, ERROR_TOKEN, HTML_KW, HTML_LITERAL, HTML_STRING_LITERAL, NEWLINE, TOMBSTONE,
-    UNICODE_BOM, WHITESPACE,
+    DOCTYPE_KW, EOF, ERROR_TOKEN, GRIT_METAVARIABLE, HTML_KW, HTML_LITERAL, HTML_STRING_LITERAL,
+    NEWLINE, TOMBSTONE, UNICODE_BOM, WHITESPACE,
 };
 use biome_html_syntax::{HtmlSyntaxKind, T, TextLen, TextSize};
 use biome_parser::diagnostic::ParseDiagnostic;
@@ -33,6 +34,8 @@ pub(crate) struct HtmlLexer<'src> {
     after_newline: bool,
 
     unicode_bom_length: usize,
+
+    options: HtmlParserOptions,
 }
 
 impl<'src> HtmlLexer<'src> {
@@ -47,9 +50,18 @@ impl<'src> HtmlLexer<'src> {
             after_newline: false,
             current_flags: TokenFlags::empty(),
             unicode_bom_length: 0,
+            options: HtmlParserOptions::default(),
         }
     }
 
+    pub(crate) fn with_options(self, options: HtmlParserOptions) -> Self {
+        Self { options, ..self }
+    }
+
+    fn is_at_metavariable(&mut self) -> bool {
+        self.options.grit_metavariables && self.is_metavariable_start()
+    }
+
     /// Consume a token in the [HtmlLexContext::Regular] context.
     fn consume_token(&mut self, current: u8) -> HtmlSyntaxKind {
         match current {
@@ -60,6 +72,7 @@ impl<'src> HtmlLexer<'src> {
             b'=' => self.consume_byte(T![=]),
             b'!' => self.consume_byte(T![!]),
             b'\'' | b'"' => self.consume_string_literal(current),
+            _ if self.is_at_metavariable() => self.consume_metavariable(GRIT_METAVARIABLE),
             _ if self.current_kind == T![<] && is_tag_name_byte(current) => {
                 // tag names must immediately follow a `<`
                 // https://html.spec.whatwg.org/multipage/syntax.html#start-tags
@@ -103,6 +116,7 @@ impl<'src> HtmlLexer<'src> {
                     self.consume_byte(HTML_LITERAL)
                 }
             }
+            _ if self.is_at_metavariable() => self.consume_metavariable(GRIT_METAVARIABLE),
             _ => self.consume_html_text(),
         }
     }
@@ -12,9 +12,15 @@ use biome_parser::AnyParse;
 use biome_parser::diagnostic::ParseDiagnostic;
 use biome_rowan::{AstNode, NodeCache};
 
+pub use crate::parser::HtmlParserOptions;
+
 /// Parses the provided string as HTML program using the provided node cache.
-pub fn parse_html_with_cache(source: &str, cache: &mut NodeCache) -> HtmlParse {
-    let mut parser = HtmlParser::new(source);
+pub fn parse_html_with_cache(
+    source: &str,
+    cache: &mut NodeCache,
+    options: HtmlParserOptions,
+) -> HtmlParse {
+    let mut parser = HtmlParser::new(source, options);
 
     parse_root(&mut parser);
 
@@ -27,8 +33,13 @@ pub fn parse_html_with_cache(source: &str, cache: &mut NodeCache) -> HtmlParse {
     HtmlParse::new(green, diagnostics)
 }
 pub fn parse_html(source: &str) -> HtmlParse {
+    parse_html_with_options(source, HtmlParserOptions::default())
+}
+
+/// Parses the provided string as HTML program using the provided options.
+pub fn parse_html_with_options(source: &str, options: HtmlParserOptions) -> HtmlParse {
     let mut cache = NodeCache::default();
-    parse_html_with_cache(source, &mut cache)
+    parse_html_with_cache(source, &mut cache, options)
 }
 
 /// A utility struct for managing the result of a parser job
@@ -10,16 +10,31 @@ use biome_parser::{Parser, ParserContext};
 pub(crate) type HtmlLosslessTreeSink<'source> =
     LosslessTreeSink<'source, HtmlLanguage, HtmlSyntaxFactory>;
 
+#[derive(Debug, Default, Copy, Clone)]
+pub struct HtmlParserOptions {
+    /// Enables parsing of Grit metavariables.
+    /// Defaults to `false`.
+    pub grit_metavariables: bool,
+}
+
+impl HtmlParserOptions {
+    /// Enables parsing of Grit metavariables.
+    pub fn allow_metavariables(mut self) -> Self {
+        self.grit_metavariables = true;
+        self
+    }
+}
+
 pub(crate) struct HtmlParser<'source> {
     context: ParserContext<HtmlSyntaxKind>,
     source: HtmlTokenSource<'source>,
 }
 
 impl<'source> HtmlParser<'source> {
-    pub fn new(source: &'source str) -> Self {
+    pub fn new(source: &'source str, options: HtmlParserOptions) -> Self {
         Self {
             context: ParserContext::default(),
-            source: HtmlTokenSource::from_str(source),
+            source: HtmlTokenSource::from_str(source, options),
         }
     }
 
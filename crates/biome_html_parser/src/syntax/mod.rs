@@ -173,6 +173,11 @@ impl ParseNodeList for ElementList {
                 p.bump_with_context(HTML_LITERAL, HtmlLexContext::OutsideTag);
                 Present(m.complete(p, HTML_CONTENT))
             }
+            GRIT_METAVARIABLE => {
+                let m = p.start();
+                p.bump_with_context(GRIT_METAVARIABLE, HtmlLexContext::OutsideTag);
+                Present(m.complete(p, HTML_METAVARIABLE))
+            }
             _ => Absent,
         }
     }
@@ -228,6 +233,12 @@ impl ParseNodeList for AttributeList {
 }
 
 fn parse_attribute(p: &mut HtmlParser) -> ParsedSyntax {
+    if p.at(GRIT_METAVARIABLE) {
+        let m = p.start();
+        p.bump(GRIT_METAVARIABLE);
+        return Present(m.complete(p, HTML_METAVARIABLE));
+    }
+
     if !p.at(HTML_LITERAL) {
         return Absent;
     }
@@ -1,7 +1,7 @@
 use biome_console::fmt::{Formatter, Termcolor};
 use biome_console::markup;
 use biome_diagnostics::{DiagnosticExt, PrintDiagnostic, termcolor};
-use biome_html_parser::parse_html;
+use biome_html_parser::{HtmlParserOptions, parse_html_with_options};
 use biome_rowan::SyntaxKind;
 use biome_test_utils::{has_bogus_nodes_or_empty_slots, validate_eof_token};
 use std::fmt::Write;
@@ -34,7 +34,12 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let content = fs::read_to_string(test_case_path)
         .expect("Expected test path to be a readable file in UTF8 encoding");
 
-    let parsed = parse_html(&content);
+    let mut options = HtmlParserOptions::default();
+    if test_case.contains("grit_metavariables") {
+        options = options.allow_metavariables();
+    }
+
+    let parsed = parse_html_with_options(&content, options);
     validate_eof_token(parsed.syntax());
 
     let formatted_ast = format!("{:#?}", parsed.tree());
@@ -0,0 +1,56 @@
+use crate::{HtmlAttribute, HtmlAttributeList, HtmlString, inner_string_text};
+use biome_rowan::{AstNodeList, SyntaxResult, TokenText};
+use biome_string_case::StrLikeExtension;
+
+impl HtmlString {
+    /// Returns the inner text of a string, without its quotes if it's quoted.
+    pub fn inner_string_text(&self) -> SyntaxResult<TokenText> {
+        Ok(inner_string_text(&self.value_token()?))
+    }
+}
+
+impl HtmlAttributeList {
+    /// Returns the first attribute named `name_to_lookup`.
+    ///
+    /// Attribute names are compared case-insensitively, `alt` and `ALT` are the same attribute.
+    pub fn find_by_name(&self, name_to_lookup: &str) -> Option<HtmlAttribute> {
+        self.iter().find_map(|attribute| {
+            let attribute = attribute.as_html_attribute()?;
+            attribute
+                .name()
+                .ok()?
+                .value_token()
+                .ok()?
+                .text_trimmed()
+                .eq_ignore_ascii_case(name_to_lookup)
+                .then(|| attribute.clone())
+        })
+    }
+}
+
+impl HtmlAttribute {
+    /// Returns the value of the attribute without its quotes,
+    /// or `None` if the attribute doesn't have a value, like `disabled` in `<button disabled>`.
+    pub fn value(&self) -> Option<TokenText> {
+        self.initializer()?.value().ok()?.inner_string_text().ok()
+    }
+}
+
+impl biome_aria::Attribute for HtmlAttribute {
+    fn name(&self) -> Option<impl AsRef<str>> {
+        // The ARIA metadata uses lowercase attribute names
+        Some(
+            Self::name(self)
+                .ok()?
+                .value_token()
+                .ok()?
+                .text_trimmed()
+                .to_ascii_lowercase_cow()
+                .into_owned(),
+        )
+    }
+
+    fn value(&self) -> Option<impl AsRef<str>> {
+        Self::value(self)
+    }
+}
@@ -1,5 +1,9 @@
-use crate::HtmlSelfClosingElement;
-use biome_rowan::SyntaxResult;
+use crate::{
+    HtmlAttribute, HtmlAttributeList, HtmlElement, HtmlOpeningElement, HtmlSelfClosingElement,
+    HtmlSyntaxToken, HtmlTagName,
+};
+use biome_rowan::{AstNode, SyntaxResult, declare_node_union};
+use biome_string_case::StrLikeExtension;
 
 /// https://html.spec.whatwg.org/#void-elements
 const VOID_ELEMENTS: &[&str] = &[
@@ -18,3 +22,69 @@ impl HtmlSelfClosingElement {
             .is_ok())
     }
 }
+
+declare_node_union! {
+    /// An element that can have attributes: the opening tag of an element with children,
+    /// such as `<div>` in `<div></div>`, or a self-closing element, such as `<img />`.
+    pub AnyHtmlTagElement = HtmlOpeningElement | HtmlSelfClosingElement
+}
+
+impl AnyHtmlTagElement {
+    pub fn name(&self) -> SyntaxResult<HtmlTagName> {
+        match self {
+            Self::HtmlOpeningElement(element) => element.name(),
+            Self::HtmlSelfClosingElement(element) => element.name(),
+        }
+    }
+
+    pub fn attributes(&self) -> HtmlAttributeList {
+        match self {
+            Self::HtmlOpeningElement(element) => element.attributes(),
+            Self::HtmlSelfClosingElement(element) => element.attributes(),
+        }
+    }
+
+    pub fn name_value_token(&self) -> SyntaxResult<HtmlSyntaxToken> {
+        self.name()?.value_token()
+    }
+
+    /// Returns `true` if the name of the element is `name`.
+    ///
+    /// Tag names are compared case-insensitively, `<IMG>` and `<img>` are the same element.
+    pub fn has_name(&self, name: &str) -> bool {
+        self.name_value_token()
+            .is_ok_and(|token| token.text_trimmed().eq_ignore_ascii_case(name))
+    }
+
+    pub fn find_attribute_by_name(&self, name_to_lookup: &str) -> Option<HtmlAttribute> {
+        self.attributes().find_by_name(name_to_lookup)
+    }
+
+    /// Returns the element that contains the children of the current element,
+    /// or `None` if it's a self-closing element.
+    pub fn parent_element(&self) -> Option<HtmlElement> {
+        match self {
+            Self::HtmlOpeningElement(element) => element.parent::<HtmlElement>(),
+            Self::HtmlSelfClosingElement(_) => None,
+        }
+    }
+}
+
+impl biome_aria::Element for AnyHtmlTagElement {
+    fn name(&self) -> Option<impl AsRef<str>> {
+        // The ARIA metadata uses lowercase element names
+        Some(
+            self.name_value_token()
+                .ok()?
+                .text_trimmed()
+                .to_ascii_lowercase_cow()
+                .into_owned(),
+        )
+    }
+
+    fn attributes(&self) -> impl Iterator<Item = impl biome_aria::Attribute> {
+        Self::attributes(self)
+            .into_iter()
+            .filter_map(|attribute| attribute.as_html_attribute().cloned())
+    }
+}
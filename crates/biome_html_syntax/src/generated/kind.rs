@@ -34,6 +34,7 @@ pub enum HtmlSyntaxKind {
     WHITESPACE,
     IDENT,
     HTML_IDENT,
+    GRIT_METAVARIABLE,
     HTML_ROOT,
     HTML_DIRECTIVE,
     HTML_SELF_CLOSING_TAG,
@@ -51,6 +52,7 @@ pub enum HtmlSyntaxKind {
     HTML_CONTENT,
     HTML_COMMENT,
     HTML_CDATA_SECTION,
+    HTML_METAVARIABLE,
     HTML_BOGUS,
     HTML_BOGUS_ELEMENT,
     HTML_BOGUS_ATTRIBUTE,
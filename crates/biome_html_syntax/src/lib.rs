@@ -2,6 +2,7 @@
 
 #[macro_use]
 mod generated;
+pub mod attr_ext;
 pub mod element_ext;
 mod file_source;
 mod syntax_node;
@@ -109,12 +110,13 @@ impl TryFrom<HtmlSyntaxKind> for TriviaPieceKind {
     }
 }
 
-/// Text of `token`, excluding all trivia and removing quotes if `token` is a string literal.
+/// Text of `token`, excluding all trivia and removing quotes if `token` is a quoted string literal.
 pub fn inner_string_text(token: &HtmlSyntaxToken) -> TokenText {
     let mut text = token.token_text_trimmed();
-    if token.kind() == HtmlSyntaxKind::HTML_STRING_LITERAL {
+    // Attribute values can be unquoted, for example `type=button`
+    let is_quoted = text.len() >= TextSize::from(2) && text.starts_with(['"', '\'']);
+    if token.kind() == HtmlSyntaxKind::HTML_STRING_LITERAL && is_quoted {
         // remove string delimiters
-        // SAFETY: string literal token have a delimiters at the start and the end of the string
         let range = TextRange::new(1.into(), text.len() - TextSize::from(1));
         text = text.slice(range);
     }
@@ -1,11 +1,13 @@
 #![deny(clippy::use_self)]
 #![warn(clippy::needless_pass_by_value)]
 
+use crate::services::semantic::EmbeddedReferences;
 use crate::suppression_action::JsSuppressionAction;
 use biome_analyze::{
     AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerPluginSlice,
     AnalyzerSignal, AnalyzerSuppression, ControlFlow, InspectMatcher, LanguageRoot,
-    MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry, to_analyzer_suppressions,
+    MatchQueryParams, MetadataRegistry, PluginTargetLanguage, RuleAction, RuleRegistry,
+    to_analyzer_suppressions,
 };
 use biome_aria::AriaRoles;
 use biome_diagnostics::Error as DiagnosticError;
@@ -47,6 +49,17 @@ pub struct JsAnalyzerServices {
     module_graph: Arc<ModuleGraph>,
     project_layout: Arc<ProjectLayout>,
     source_type: JsFileSource,
+    embedded_references: EmbeddedReferences,
+}
+
+impl JsAnalyzerServices {
+    /// Names referenced by code that is embedded next to the analyzed root, such as
+    /// the template of a Vue component. Top-level bindings with these names are
+    /// considered referenced by the semantic model.
+    pub fn with_embedded_references(mut self, names: impl IntoIterator<Item = String>) -> Self {
+        self.embedded_references = EmbeddedReferences(names.into_iter().collect());
+        self
+    }
 }
 
 impl From<(Arc<ModuleGraph>, Arc<ProjectLayout>, JsFileSource)> for JsAnalyzerServices {
@@ -61,6 +74,7 @@ impl From<(Arc<ModuleGraph>, Arc<ProjectLayout>, JsFileSource)> for JsAnalyzerSe
             module_graph,
             project_layout,
             source_type,
+            embedded_references: EmbeddedReferences::default(),
         }
     }
 }
@@ -118,6 +132,7 @@ where
         module_graph,
         project_layout,
         source_type,
+        embedded_references,
     } = services;
 
     let (registry, mut services, diagnostics, visitors, categories) = registry.build();
@@ -136,11 +151,7 @@ where
         categories,
     );
 
-    for plugin in plugins {
-        if plugin.supports_js() {
-            analyzer.add_plugin(plugin.clone());
-        }
-    }
+    analyzer.add_plugins_for_language(plugins, PluginTargetLanguage::JavaScript);
 
     for ((phase, _), visitor) in visitors {
         analyzer.add_visitor(phase, visitor);
@@ -168,6 +179,7 @@ where
     services.insert_service(file_path);
     services.insert_service(type_resolver);
     services.insert_service(project_layout);
+    services.insert_service(embedded_references);
 
     (
         analyzer.run(AnalyzerContext {
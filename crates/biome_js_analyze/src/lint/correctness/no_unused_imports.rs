@@ -621,5 +621,10 @@ fn is_unused(ctx: &RuleContext<NoUnusedImports>, local_name: &AnyJsBinding) -> b
     }
 
     let model = ctx.semantic_model();
+    // Imports referenced by embedded code, such as a Vue template, are used
+    if model.as_binding(binding).is_referenced_by_embedded_code() {
+        return false;
+    }
+
     binding.all_references(model).next().is_none()
 }
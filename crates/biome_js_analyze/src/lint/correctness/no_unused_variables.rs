@@ -427,6 +427,11 @@ pub fn is_unused(model: &SemanticModel, binding: &AnyJsIdentifierBinding) -> boo
         return false;
     }
 
+    // Bindings referenced by embedded code, such as a Vue template, are used
+    if model.as_binding(binding).is_referenced_by_embedded_code() {
+        return false;
+    }
+
     // We need to check if all uses of this binding are somehow recursive or unused
     let Some(declaration) = binding.declaration() else {
         return false;
@@ -0,0 +1,325 @@
+use std::sync::Arc;
+
+use biome_analyze::{
+    Rule, RuleDiagnostic, RuleDomain, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Severity;
+use biome_js_syntax::{
+    AnyJsExportClause, AnyJsExportNamedSpecifier, AnyJsRoot, JsExport, JsExportAsClause,
+};
+use biome_module_graph::{JsModuleInfo, JsOwnExport, UsedExports};
+use biome_package::PackageJson;
+use biome_rowan::{AstNode, AstSeparatedList, Text, TextRange};
+use camino::{Utf8Path, Utf8PathBuf};
+
+use crate::services::module_graph::ResolvedModule;
+
+declare_lint_rule! {
+    /// Disallow exports that no module imports, and modules that no entry point reaches.
+    ///
+    /// Exports that aren't imported anywhere are dead code. So are modules
+    /// that can't be reached by following the imports of the entry points of
+    /// the package.
+    ///
+    /// The entry points are the files declared by the `main`, `bin`, and
+    /// `exports` fields of the `package.json`, along with the files that match
+    /// the `entryPoints` option. The exports of entry points are the public
+    /// API of the package, so they're never reported.
+    ///
+    /// Subpath patterns of the `exports` field, such as
+    /// `"./*": "./src/*.js"`, make all the files that they match entry points.
+    ///
+    /// The rule doesn't report anything if the package doesn't declare any
+    /// entry point.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// **`package.json`**
+    /// ```json
+    /// { "main": "./index.js" }
+    /// ```
+    ///
+    /// **`index.js`**
+    /// ```js
+    /// import { foo } from "./utils.js";
+    ///
+    /// foo();
+    /// ```
+    ///
+    /// **`utils.js`**
+    /// ```js
+    /// export function foo() {}
+    ///
+    /// export function bar() {}
+    /// ```
+    ///
+    /// **`orphan.js`**
+    /// ```js
+    /// export function baz() {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// **`index.js`**
+    /// ```js
+    /// import { foo, bar } from "./utils.js";
+    ///
+    /// foo();
+    /// bar();
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `entryPoints`
+    ///
+    /// Globs of the files that are entry points, in addition to the ones
+    /// declared by the `package.json`. The globs are relative to the directory
+    /// of the `package.json`.
+    ///
+    /// ```json
+    /// {
+    ///   "options": {
+    ///     "entryPoints": ["src/main.ts", "scripts/**"]
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoUnusedExports {
+        version: "next",
+        name: "noUnusedExports",
+        language: "js",
+        sources: &[
+            RuleSource::EslintImport("no-unused-modules").same(),
+        ],
+        severity: Severity::Warning,
+        recommended: false,
+        domains: &[RuleDomain::Project],
+    }
+}
+
+/// Options for the rule `noUnusedExports`.
+#[derive(
+    Clone, Debug, Default, Deserializable, Eq, PartialEq, serde::Deserialize, serde::Serialize,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoUnusedExportsOptions {
+    /// Globs of the files that are entry points, in addition to the ones
+    /// declared by the `package.json`.
+    #[serde(default)]
+    entry_points: Box<[biome_glob::Glob]>,
+}
+
+pub enum NoUnusedExportsState {
+    /// The module isn't reachable from any entry point.
+    UnreachableModule,
+
+    /// The export isn't imported by any module.
+    UnusedExport { name: Text, range: TextRange },
+}
+
+impl Rule for NoUnusedExports {
+    type Query = ResolvedModule<AnyJsRoot>;
+    type State = NoUnusedExportsState;
+    type Signals = Box<[Self::State]>;
+    type Options = NoUnusedExportsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some((package_path, manifest)) = ctx
+            .get_service::<Option<(Utf8PathBuf, Arc<PackageJson>)>>()
+            .and_then(Option::as_ref)
+        else {
+            return Box::default();
+        };
+
+        let globs = &ctx.options().entry_points;
+        let mut entry_points = Vec::new();
+        let mut entry_point_patterns = Vec::new();
+        for entry_point in manifest.entry_points() {
+            let entry_point = entry_point.trim_start_matches("./");
+            if entry_point.contains('*') {
+                entry_point_patterns.extend(subpath_pattern_to_glob(entry_point));
+            } else {
+                entry_points.push(package_path.join(entry_point));
+            }
+        }
+        if entry_points.is_empty() && entry_point_patterns.is_empty() && globs.is_empty() {
+            return Box::default();
+        }
+
+        let is_entry_point = |path: &Utf8Path| {
+            entry_points.iter().any(|entry_point| entry_point == path)
+                || path.strip_prefix(package_path).is_ok_and(|path| {
+                    let candidate = biome_glob::CandidatePath::new(path);
+                    entry_point_patterns
+                        .iter()
+                        .any(|pattern| candidate.matches(pattern))
+                        || candidate.matches_with_exceptions(globs)
+                })
+        };
+
+        let path = ctx.file_path();
+        if is_entry_point(path) {
+            return Box::default();
+        }
+
+        let module_graph = ctx.module_graph();
+        if !module_graph.is_reachable(path, &is_entry_point) {
+            return Box::new([NoUnusedExportsState::UnreachableModule]);
+        }
+
+        let UsedExports::Names(used_names) = module_graph.find_used_exports(path, &is_entry_point)
+        else {
+            return Box::default();
+        };
+        let Some(module_info) = ctx.module_info_for_path(path) else {
+            return Box::default();
+        };
+
+        ctx.query()
+            .syntax()
+            .descendants()
+            .filter_map(JsExport::cast)
+            .flat_map(|export| exported_names(&export, &module_info))
+            .filter(|(name, _)| !used_names.contains(name))
+            .map(|(name, range)| NoUnusedExportsState::UnusedExport { name, range })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            NoUnusedExportsState::UnreachableModule => RuleDiagnostic::new(
+                rule_category!(),
+                None as Option<TextRange>,
+                markup! {
+                    "This module isn't reachable from any entry point."
+                },
+            )
+            .note(markup! {
+                "None of the entry points import this module, either directly or through other modules."
+            })
+            .note(markup! {
+                "Remove the module, or add it to the "<Emphasis>"entryPoints"</Emphasis>" option if it's used by other means."
+            }),
+            NoUnusedExportsState::UnusedExport { name, range } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The export "<Emphasis>{name.text()}</Emphasis>" isn't imported by any module."
+                },
+            )
+            .note(markup! {
+                "Remove the export, or the exported declaration if the module doesn't use it either."
+            }),
+        };
+
+        Some(diagnostic)
+    }
+}
+
+/// Turns the target of a subpath pattern of the `exports` field, such as
+/// `src/*.js`, into a glob that matches the files it exposes.
+///
+/// The `*` of a subpath pattern can match `/`, so it becomes `**/*` when it
+/// starts a path segment. Otherwise, it only matches within the segment.
+fn subpath_pattern_to_glob(pattern: &str) -> Option<biome_glob::Glob> {
+    let mut glob = String::with_capacity(pattern.len() + 3);
+    let mut segment_start = true;
+    for char in pattern.chars() {
+        match char {
+            '*' if segment_start => glob.push_str("**/*"),
+            '*' => glob.push('*'),
+            '?' | '[' | ']' | '{' | '}' | '\\' => {
+                glob.push('\\');
+                glob.push(char);
+            }
+            _ => glob.push(char),
+        }
+        segment_start = char == '/';
+    }
+    glob.parse().ok()
+}
+
+/// Returns the names of the symbols exported by `export`, along with the
+/// range to report for each of them.
+fn exported_names(export: &JsExport, module_info: &JsModuleInfo) -> Vec<(Text, TextRange)> {
+    let Ok(clause) = export.export_clause() else {
+        return Vec::new();
+    };
+
+    let export_as_name = |export_as: JsExportAsClause| {
+        let name = export_as.exported_name().ok()?;
+        Some((name.inner_string_text().ok()?.into(), name.range()))
+    };
+
+    match clause {
+        AnyJsExportClause::AnyJsDeclarationClause(_)
+        | AnyJsExportClause::TsExportDeclareClause(_) => {
+            // The module info already knows the names of the declared bindings,
+            // including those from destructuring patterns.
+            let clause_range = clause.range();
+            module_info
+                .exports
+                .iter()
+                .filter_map(|(name, export)| match export.as_own_export()? {
+                    JsOwnExport::Binding(binding_id) => {
+                        let range = module_info.binding(*binding_id).range;
+                        clause_range
+                            .contains_range(range)
+                            .then(|| (name.clone(), range))
+                    }
+                    JsOwnExport::Type(_) => None,
+                })
+                .collect()
+        }
+        AnyJsExportClause::JsExportDefaultDeclarationClause(clause) => clause
+            .default_token()
+            .map(|token| vec![("default".into(), token.text_trimmed_range())])
+            .unwrap_or_default(),
+        AnyJsExportClause::JsExportDefaultExpressionClause(clause) => clause
+            .default_token()
+            .map(|token| vec![("default".into(), token.text_trimmed_range())])
+            .unwrap_or_default(),
+        AnyJsExportClause::TsExportAssignmentClause(_) => {
+            vec![("default".into(), export.range())]
+        }
+        AnyJsExportClause::JsExportNamedClause(clause) => clause
+            .specifiers()
+            .iter()
+            .filter_map(|specifier| match specifier.ok()? {
+                AnyJsExportNamedSpecifier::JsExportNamedShorthandSpecifier(specifier) => {
+                    let name = specifier.name().ok()?;
+                    Some((name.name().ok()?.into(), name.range()))
+                }
+                AnyJsExportNamedSpecifier::JsExportNamedSpecifier(specifier) => {
+                    let name = specifier.exported_name().ok()?;
+                    Some((name.inner_string_text().ok()?.into(), name.range()))
+                }
+            })
+            .collect(),
+        AnyJsExportClause::JsExportNamedFromClause(clause) => clause
+            .specifiers()
+            .iter()
+            .filter_map(|specifier| {
+                let specifier = specifier.ok()?;
+                match specifier.export_as() {
+                    Some(export_as) => export_as_name(export_as),
+                    None => {
+                        let name = specifier.source_name().ok()?;
+                        Some((name.inner_string_text().ok()?.into(), name.range()))
+                    }
+                }
+            })
+            .collect(),
+        AnyJsExportClause::JsExportFromClause(clause) => clause
+            .export_as()
+            .and_then(export_as_name)
+            .into_iter()
+            .collect(),
+        AnyJsExportClause::TsExportAsNamespaceClause(_) => Vec::new(),
+    }
+}
@@ -11,6 +11,7 @@ mod nodes;
 mod visitor;
 
 pub(crate) use self::visitor::AnyJsControlFlowRoot;
+pub(crate) use self::visitor::ControlFlowGraphCollector;
 pub(crate) use self::visitor::make_visitor;
 
 pub struct ControlFlowGraph {
@@ -4,13 +4,14 @@ use biome_analyze::{Visitor, VisitorContext, merge_node_visitors};
 use biome_js_syntax::{
     AnyJsFunction, JsConstructorClassMember, JsGetterClassMember, JsGetterObjectMember, JsLanguage,
     JsMethodClassMember, JsMethodObjectMember, JsModule, JsScript, JsSetterClassMember,
-    JsSetterObjectMember, JsStaticInitializationBlockClassMember, TsModuleDeclaration,
+    JsSetterObjectMember, JsStaticInitializationBlockClassMember, JsSyntaxNode,
+    TsModuleDeclaration,
 };
-use biome_rowan::{AstNode, SyntaxError, SyntaxResult, declare_node_union};
+use biome_rowan::{AstNode, SyntaxError, SyntaxResult, WalkEvent, declare_node_union};
 
 use crate::ControlFlowGraph;
 
-use super::{FunctionBuilder, nodes::*};
+use super::{FunctionBuilder, JsControlFlowGraph, nodes::*};
 
 /// Return a new instance of the [ControlFlowVisitor]
 pub(crate) fn make_visitor() -> impl Visitor<Language = JsLanguage> {
@@ -195,6 +196,51 @@ impl biome_analyze::NodeVisitor<ControlFlowVisitor> for FunctionVisitor {
     }
 }
 
+/// Builds the control flow graphs of all the functions in a file and collects
+/// them, instead of emitting them as query matches
+pub(crate) struct ControlFlowGraphCollector {
+    visitor: ControlFlowVisitor,
+    graphs: Vec<JsControlFlowGraph>,
+}
+
+impl Default for ControlFlowGraphCollector {
+    fn default() -> Self {
+        Self {
+            visitor: ControlFlowVisitor::new(),
+            graphs: Vec::new(),
+        }
+    }
+}
+
+impl ControlFlowGraphCollector {
+    pub(crate) fn visit(
+        &mut self,
+        event: &WalkEvent<JsSyntaxNode>,
+        ctx: VisitorContext<JsLanguage>,
+    ) {
+        if let WalkEvent::Leave(node) = event {
+            if AnyJsControlFlowRoot::can_cast(node.kind()) {
+                // Take the builder out of the function visitor before it exits,
+                // so it doesn't emit the graph as a query match
+                let builder = self
+                    .visitor
+                    .function
+                    .last_mut()
+                    .and_then(|(_, function)| function.builder.take());
+                if let Some(builder) = builder {
+                    self.graphs.push(builder.finish());
+                }
+            }
+        }
+
+        self.visitor.visit(event, ctx);
+    }
+
+    pub(crate) fn into_graphs(self) -> Vec<JsControlFlowGraph> {
+        self.graphs
+    }
+}
+
 /// Wrapper trait for [biome_analyze::NodeVisitor] adding control flow specific
 /// utilities (error handling and automatic [FunctionBuilder] injection)
 pub(super) trait NodeVisitor: Sized {
@@ -89,3 +89,42 @@ where
         N::unwrap_cast(node.clone())
     }
 }
+
+/// Query type usable by lint rules that matches the root of a module and uses
+/// the [ModuleGraph] to inspect how other modules use it.
+#[derive(Clone)]
+pub struct ResolvedModule<N>(N);
+
+impl<N, L> QueryMatch for ResolvedModule<N>
+where
+    L: Language,
+    N: AstNode<Language = L> + 'static,
+{
+    fn text_range(&self) -> TextRange {
+        self.0.range()
+    }
+}
+
+impl<N, L> Queryable for ResolvedModule<N>
+where
+    L: Language + 'static,
+    N: AstNode<Language = L> + 'static,
+{
+    type Input = SyntaxNode<L>;
+    type Output = N;
+
+    type Language = L;
+    type Services = ModuleGraphService;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<L>, _: &L::Root) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
@@ -89,6 +89,12 @@ where
     }
 }
 
+/// Names referenced by code that is embedded next to the analyzed root.
+///
+/// They're pushed to the [SemanticModel] when it's built.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct EmbeddedReferences(pub(crate) Vec<String>);
+
 pub struct SemanticModelBuilderVisitor {
     extractor: SemanticEventExtractor,
     builder: SemanticModelBuilder,
@@ -122,7 +128,13 @@ impl Visitor for SemanticModelBuilderVisitor {
         }
     }
 
-    fn finish(self: Box<Self>, ctx: VisitorFinishContext<JsLanguage>) {
+    fn finish(mut self: Box<Self>, ctx: VisitorFinishContext<JsLanguage>) {
+        if let Some(EmbeddedReferences(names)) = ctx.services.get_service::<EmbeddedReferences>() {
+            for name in names {
+                self.builder.push_embedded_reference(name.as_str());
+            }
+        }
+
         let model = self.builder.build();
         ctx.services.insert_service(model);
     }
@@ -8,6 +8,10 @@ use biome_module_graph::ScopedResolver;
 use biome_rowan::{AstNode, TextRange};
 use std::sync::Arc;
 
+use self::narrowing::TypeNarrowingVisitor;
+
+mod narrowing;
+
 /// Service for use with type inference rules.
 #[derive(Clone, Debug)]
 pub struct TypedService {
@@ -78,6 +82,7 @@ where
     type Services = TypedService;
 
     fn build_visitor(analyzer: &mut impl AddVisitor<JsLanguage>, _root: &AnyJsRoot) {
+        analyzer.add_visitor(Phases::Syntax, TypeNarrowingVisitor::default);
         analyzer.add_visitor(Phases::Semantic, SyntaxVisitor::default);
     }
 
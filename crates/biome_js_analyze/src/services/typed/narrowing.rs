@@ -0,0 +1,641 @@
+use std::sync::Arc;
+
+use biome_analyze::{Visitor, VisitorContext, VisitorFinishContext};
+use biome_control_flow::InstructionKind;
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsExpression, AnyJsInProperty, JsBinaryOperator, JsCaseClause,
+    JsComputedMemberAssignment, JsConditionalExpression, JsIdentifierAssignment, JsLanguage,
+    JsLogicalExpression, JsLogicalOperator, JsStaticMemberAssignment, JsSwitchStatement,
+    JsSyntaxNode, JsUnaryOperator,
+};
+use biome_js_type_info::{
+    ReturnType, TypeData, TypeGuard, TypeGuardKind, TypeReference, TypeResolver,
+};
+use biome_module_graph::ScopedResolver;
+use biome_rowan::{AstNode, AstSeparatedList, Text, WalkEvent};
+
+use crate::services::control_flow::{
+    AnyJsControlFlowRoot, ControlFlowGraphCollector, JsControlFlowGraph,
+};
+
+/// Visitor that narrows the types of expressions inside the [`ScopedResolver`]
+/// based on the control flow of the functions they are in.
+///
+/// The control flow graphs of all functions are built during the syntax phase,
+/// so that the types are narrowed by the time rules using the
+/// [`TypedService`](super::TypedService) run.
+#[derive(Default)]
+pub(crate) struct TypeNarrowingVisitor {
+    graphs: ControlFlowGraphCollector,
+}
+
+impl Visitor for TypeNarrowingVisitor {
+    type Language = JsLanguage;
+
+    fn visit(&mut self, event: &WalkEvent<JsSyntaxNode>, ctx: VisitorContext<JsLanguage>) {
+        self.graphs.visit(event, ctx);
+    }
+
+    fn finish(self: Box<Self>, ctx: VisitorFinishContext<JsLanguage>) {
+        let Some(Some(resolver)) = ctx
+            .services
+            .get_service_mut::<Option<Arc<ScopedResolver>>>()
+        else {
+            return;
+        };
+
+        // Nothing else holds on to the resolver until the rules of the
+        // semantic phase start running.
+        let Some(resolver) = Arc::get_mut(resolver) else {
+            return;
+        };
+
+        for graph in self.graphs.into_graphs() {
+            narrow_types_in_graph(&graph, resolver);
+        }
+    }
+}
+
+/// A condition that is known to hold, or not to hold, at a given point of the
+/// control flow.
+#[derive(Clone, Debug, PartialEq)]
+struct Fact {
+    condition: JsSyntaxNode,
+    holds: bool,
+}
+
+fn narrow_types_in_graph(graph: &JsControlFlowGraph, resolver: &mut ScopedResolver) {
+    let entry_facts = facts_at_block_entries(graph);
+
+    for (block, facts) in graph.blocks.iter().zip(entry_facts) {
+        // Blocks without facts are unreachable.
+        let Some(mut facts) = facts else {
+            continue;
+        };
+
+        for instruction in &block.instructions {
+            let node = instruction
+                .node
+                .as_ref()
+                .and_then(|element| element.as_node().cloned());
+            if let Some(node) = &node {
+                narrow_types_in_node(node, &facts, resolver);
+            }
+
+            match instruction.kind {
+                InstructionKind::Statement => {
+                    if let Some(node) = &node {
+                        remove_reassigned_facts(&mut facts, node);
+                    }
+                }
+                InstructionKind::Jump {
+                    conditional: true, ..
+                } => {
+                    if let Some(node) = node {
+                        remove_reassigned_facts(&mut facts, &node);
+                        facts.push(Fact {
+                            condition: node,
+                            holds: false,
+                        });
+                    }
+                }
+                InstructionKind::Jump { .. } | InstructionKind::Return => break,
+            }
+        }
+    }
+}
+
+/// Computes the facts that are known at the start of every block in the
+/// graph.
+///
+/// A fact is only known at the start of a block if it is known on every edge
+/// leading into it. Blocks that are unreachable have no facts at all.
+fn facts_at_block_entries(graph: &JsControlFlowGraph) -> Vec<Option<Vec<Fact>>> {
+    let mut entries: Vec<Option<Vec<Fact>>> = vec![None; graph.blocks.len()];
+    let mut queue = Vec::new();
+    if !entries.is_empty() {
+        entries[0] = Some(Vec::new());
+        queue.push(0);
+    }
+
+    while let Some(index) = queue.pop() {
+        let Some(mut facts) = entries[index].clone() else {
+            continue;
+        };
+
+        let block = &graph.blocks[index];
+
+        // Exceptions may be thrown from anywhere within the block.
+        for handler in block
+            .exception_handlers
+            .iter()
+            .chain(&block.cleanup_handlers)
+        {
+            merge(
+                &mut entries,
+                &mut queue,
+                handler.target.index() as usize,
+                Vec::new(),
+            );
+        }
+
+        for instruction in &block.instructions {
+            let node = instruction
+                .node
+                .as_ref()
+                .and_then(|element| element.as_node().cloned());
+
+            match instruction.kind {
+                InstructionKind::Statement => {
+                    if let Some(node) = &node {
+                        remove_reassigned_facts(&mut facts, node);
+                    }
+                }
+                InstructionKind::Jump {
+                    conditional: true,
+                    block,
+                    ..
+                } => {
+                    let jump_facts = match node {
+                        Some(node) => {
+                            remove_reassigned_facts(&mut facts, &node);
+                            let mut jump_facts = facts.clone();
+                            jump_facts.push(Fact {
+                                condition: node.clone(),
+                                holds: true,
+                            });
+                            facts.push(Fact {
+                                condition: node,
+                                holds: false,
+                            });
+                            jump_facts
+                        }
+                        None => facts.clone(),
+                    };
+
+                    merge(&mut entries, &mut queue, block.index() as usize, jump_facts);
+                }
+                InstructionKind::Jump { block, .. } => {
+                    merge(&mut entries, &mut queue, block.index() as usize, facts);
+                    break;
+                }
+                InstructionKind::Return => break,
+            }
+        }
+    }
+
+    entries
+}
+
+/// Merges the `incoming` facts into those at the entry of the block with the
+/// given `index`, queueing the block for another visit if they changed.
+fn merge(
+    entries: &mut [Option<Vec<Fact>>],
+    queue: &mut Vec<usize>,
+    index: usize,
+    incoming: Vec<Fact>,
+) {
+    let changed = match &mut entries[index] {
+        Some(facts) => {
+            let len = facts.len();
+            facts.retain(|fact| incoming.contains(fact));
+            facts.len() != len
+        }
+        entry @ None => {
+            *entry = Some(incoming);
+            true
+        }
+    };
+
+    if changed {
+        queue.push(index);
+    }
+}
+
+/// Removes the facts that may no longer be valid because a binding they test
+/// is reassigned within the given `node`.
+fn remove_reassigned_facts(facts: &mut Vec<Fact>, node: &JsSyntaxNode) {
+    if facts.is_empty() {
+        return;
+    }
+
+    let reassigned: Vec<Text> = descendants_in_function(node)
+        .filter_map(|node| {
+            if let Some(assignment) = JsIdentifierAssignment::cast_ref(&node) {
+                assignment
+                    .name_token()
+                    .ok()
+                    .map(|token| token.token_text_trimmed().into())
+            } else if let Some(assignment) = JsStaticMemberAssignment::cast_ref(&node) {
+                assignment.object().ok().as_ref().and_then(root_name)
+            } else if let Some(assignment) = JsComputedMemberAssignment::cast_ref(&node) {
+                assignment.object().ok().as_ref().and_then(root_name)
+            } else {
+                None
+            }
+        })
+        .collect();
+    if reassigned.is_empty() {
+        return;
+    }
+
+    facts.retain(|fact| {
+        !descendants_in_function(&fact.condition)
+            .filter_map(AnyJsExpression::cast)
+            .filter_map(|expression| root_name(&expression))
+            .any(|name| reassigned.contains(&name))
+    });
+}
+
+/// Narrows the types of all references inside `node`, given the facts that
+/// are known at the start of it.
+fn narrow_types_in_node(node: &JsSyntaxNode, facts: &[Fact], resolver: &mut ScopedResolver) {
+    for expression in descendants_in_function(node).filter_map(AnyJsExpression::cast) {
+        let Some(path) = reference_path(&expression) else {
+            continue;
+        };
+
+        let mut guards = Vec::new();
+        for fact in facts
+            .iter()
+            .cloned()
+            .chain(facts_within_node(&expression, node))
+        {
+            guards_for_condition(&fact.condition, fact.holds, &path, resolver, &mut guards);
+        }
+
+        if !guards.is_empty() {
+            resolver.narrow_type_for_expression(&expression, &guards);
+        }
+    }
+}
+
+/// Returns the facts that are known for `expression` because of the logical
+/// and conditional expressions it is nested in, up to `node`.
+fn facts_within_node(expression: &AnyJsExpression, node: &JsSyntaxNode) -> Vec<Fact> {
+    let mut facts = Vec::new();
+    let mut child = expression.syntax().clone();
+    for ancestor in expression.syntax().ancestors().skip(1) {
+        if child == *node {
+            break;
+        }
+
+        if let Some(logical) = JsLogicalExpression::cast_ref(&ancestor) {
+            let is_right = logical.right().is_ok_and(|right| *right.syntax() == child);
+            if let (true, Ok(left), Ok(operator)) = (is_right, logical.left(), logical.operator()) {
+                match operator {
+                    JsLogicalOperator::LogicalAnd => facts.push(Fact {
+                        condition: left.into_syntax(),
+                        holds: true,
+                    }),
+                    JsLogicalOperator::LogicalOr => facts.push(Fact {
+                        condition: left.into_syntax(),
+                        holds: false,
+                    }),
+                    JsLogicalOperator::NullishCoalescing => {}
+                }
+            }
+        } else if let Some(conditional) = JsConditionalExpression::cast_ref(&ancestor) {
+            if let Ok(test) = conditional.test() {
+                if conditional
+                    .consequent()
+                    .is_ok_and(|consequent| *consequent.syntax() == child)
+                {
+                    facts.push(Fact {
+                        condition: test.into_syntax(),
+                        holds: true,
+                    });
+                } else if conditional
+                    .alternate()
+                    .is_ok_and(|alternate| *alternate.syntax() == child)
+                {
+                    facts.push(Fact {
+                        condition: test.into_syntax(),
+                        holds: false,
+                    });
+                }
+            }
+        }
+
+        child = ancestor;
+    }
+
+    facts
+}
+
+/// Collects the guards that apply to the reference with the given `path` if
+/// the `condition` is known to hold, or not to hold.
+fn guards_for_condition(
+    condition: &JsSyntaxNode,
+    holds: bool,
+    path: &str,
+    resolver: &ScopedResolver,
+    guards: &mut Vec<TypeGuard>,
+) {
+    let Some(expression) = AnyJsExpression::cast_ref(condition) else {
+        return;
+    };
+
+    // The tests of `case` clauses are compared against the discriminant of
+    // their `switch` statement.
+    if let Some(case) = condition.parent().and_then(JsCaseClause::cast) {
+        let discriminant = case
+            .syntax()
+            .ancestors()
+            .find_map(JsSwitchStatement::cast)
+            .and_then(|switch| switch.discriminant().ok());
+        let Some(discriminant) = discriminant else {
+            return;
+        };
+
+        if matches!(
+            discriminant.as_static_value(),
+            Some(biome_js_syntax::static_value::StaticValue::Boolean(token)) if token.text_trimmed() == "true"
+        ) {
+            if holds {
+                guards_for_expression(&expression, true, path, resolver, guards);
+            }
+        } else {
+            equality_guards(
+                &discriminant,
+                &expression,
+                true,
+                holds,
+                path,
+                resolver,
+                guards,
+            );
+        }
+
+        return;
+    }
+
+    guards_for_expression(&expression, holds, path, resolver, guards);
+}
+
+fn guards_for_expression(
+    expression: &AnyJsExpression,
+    holds: bool,
+    path: &str,
+    resolver: &ScopedResolver,
+    guards: &mut Vec<TypeGuard>,
+) {
+    let expression = expression.clone().omit_parentheses();
+    if reference_path(&expression).is_some_and(|reference| reference == path) {
+        guards.push(TypeGuard::new(TypeGuardKind::Truthy, holds));
+        return;
+    }
+
+    match expression {
+        AnyJsExpression::JsUnaryExpression(unary) => {
+            if let (Ok(JsUnaryOperator::LogicalNot), Ok(argument)) =
+                (unary.operator(), unary.argument())
+            {
+                guards_for_expression(&argument, !holds, path, resolver, guards);
+            }
+        }
+        AnyJsExpression::JsLogicalExpression(logical) => {
+            let (Ok(left), Ok(right), Ok(operator)) =
+                (logical.left(), logical.right(), logical.operator())
+            else {
+                return;
+            };
+
+            match (operator, holds) {
+                (JsLogicalOperator::LogicalAnd, true) | (JsLogicalOperator::LogicalOr, false) => {
+                    guards_for_expression(&left, holds, path, resolver, guards);
+                    guards_for_expression(&right, holds, path, resolver, guards);
+                }
+                _ => {}
+            }
+        }
+        AnyJsExpression::JsBinaryExpression(binary) => {
+            let (Ok(left), Ok(right), Ok(operator)) =
+                (binary.left(), binary.right(), binary.operator())
+            else {
+                return;
+            };
+
+            match operator {
+                JsBinaryOperator::StrictEquality => {
+                    equality_guards(&left, &right, true, holds, path, resolver, guards)
+                }
+                JsBinaryOperator::StrictInequality => {
+                    equality_guards(&left, &right, true, !holds, path, resolver, guards)
+                }
+                JsBinaryOperator::Equality => {
+                    equality_guards(&left, &right, false, holds, path, resolver, guards)
+                }
+                JsBinaryOperator::Inequality => {
+                    equality_guards(&left, &right, false, !holds, path, resolver, guards)
+                }
+                _ => {}
+            }
+        }
+        AnyJsExpression::JsInstanceofExpression(instanceof) => {
+            let (Ok(left), Ok(right)) = (instanceof.left(), instanceof.right()) else {
+                return;
+            };
+
+            if reference_path(&left).is_some_and(|reference| reference == path) {
+                let class = resolver.resolved_id_for_expression(&right);
+                guards.push(TypeGuard::new(
+                    TypeGuardKind::InstanceOf(class.into()),
+                    holds,
+                ));
+            }
+        }
+        AnyJsExpression::JsInExpression(in_expression) => {
+            let (Ok(AnyJsInProperty::AnyJsExpression(property)), Ok(object)) =
+                (in_expression.property(), in_expression.object())
+            else {
+                return;
+            };
+
+            let name = property.as_static_value().and_then(|value| {
+                value
+                    .as_string_constant()
+                    .map(|name| Text::Owned(name.into()))
+            });
+            if let (Some(name), true) = (
+                name,
+                reference_path(&object).is_some_and(|reference| reference == path),
+            ) {
+                guards.push(TypeGuard::new(TypeGuardKind::HasMember(name), holds));
+            }
+        }
+        AnyJsExpression::JsCallExpression(call) => {
+            if let Some(ty) = predicate_type_for_call(&call, path, resolver) {
+                guards.push(TypeGuard::new(TypeGuardKind::Predicate(ty), holds));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Collects the guards that apply to the reference with the given `path` if
+/// `left` and `right` are known to be equal, or not to be equal.
+fn equality_guards(
+    left: &AnyJsExpression,
+    right: &AnyJsExpression,
+    strict: bool,
+    holds: bool,
+    path: &str,
+    resolver: &ScopedResolver,
+    guards: &mut Vec<TypeGuard>,
+) {
+    let is_path = |expression: &AnyJsExpression| {
+        reference_path(expression).is_some_and(|reference| reference == path)
+    };
+
+    for (subject, other) in [(left, right), (right, left)] {
+        let subject = subject.clone().omit_parentheses();
+        let other_value = other.as_static_value();
+
+        if is_path(&subject) {
+            if !strict {
+                if other_value.is_some_and(|value| value.is_null_or_undefined()) {
+                    guards.push(TypeGuard::new(TypeGuardKind::Nullish, holds));
+                }
+            } else {
+                let value = resolver.resolved_id_for_expression(other);
+                guards.push(TypeGuard::new(TypeGuardKind::Equals(value.into()), holds));
+            }
+
+            continue;
+        }
+
+        match subject {
+            AnyJsExpression::JsUnaryExpression(unary) => {
+                let is_typeof_path = unary
+                    .operator()
+                    .is_ok_and(|operator| operator == JsUnaryOperator::Typeof)
+                    && unary.argument().is_ok_and(|argument| is_path(&argument));
+                let name = other_value
+                    .as_ref()
+                    .and_then(|value| value.as_string_constant())
+                    .map(|name| Text::Owned(name.into()));
+                if let (true, Some(name)) = (is_typeof_path, name) {
+                    guards.push(TypeGuard::new(TypeGuardKind::Typeof(name), holds));
+                }
+            }
+            AnyJsExpression::JsStaticMemberExpression(member) if strict => {
+                let is_member_of_path = member.object().is_ok_and(|object| is_path(&object));
+                let name = member
+                    .member()
+                    .ok()
+                    .and_then(|name| name.as_js_name()?.value_token().ok());
+                if let (true, Some(name)) = (is_member_of_path, name) {
+                    let value = resolver.resolved_id_for_expression(other);
+                    guards.push(TypeGuard::new(
+                        TypeGuardKind::MemberEquals {
+                            member: name.token_text_trimmed().into(),
+                            value: value.into(),
+                        },
+                        holds,
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the type asserted by a call to a user-defined type guard, if the
+/// reference with the given `path` is the argument being tested.
+fn predicate_type_for_call(
+    call: &biome_js_syntax::JsCallExpression,
+    path: &str,
+    resolver: &ScopedResolver,
+) -> Option<TypeReference> {
+    let callee = call.callee().ok()?;
+    let callee_id = resolver.resolved_id_for_expression(&callee);
+    let callee_ty = resolver.get_by_resolved_id(callee_id)?;
+    let TypeData::Function(function) = callee_ty.as_raw_data() else {
+        return None;
+    };
+    let ReturnType::Predicate(predicate) = &function.return_type else {
+        return None;
+    };
+
+    let index = function
+        .parameters
+        .iter()
+        .position(|param| param.name.as_ref() == Some(&predicate.parameter_name))?;
+    let argument = call.arguments().ok()?.args().iter().nth(index)?.ok()?;
+    let AnyJsCallArgument::AnyJsExpression(argument) = argument else {
+        return None;
+    };
+
+    reference_path(&argument)
+        .is_some_and(|reference| reference == path)
+        .then(|| {
+            callee_ty
+                .apply_module_id_to_reference(&predicate.ty)
+                .into_owned()
+        })
+}
+
+/// Returns a textual path for references that can be narrowed, such as `value`
+/// or `value.kind`.
+fn reference_path(expression: &AnyJsExpression) -> Option<String> {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsIdentifierExpression(identifier) => Some(
+            identifier
+                .name()
+                .ok()?
+                .value_token()
+                .ok()?
+                .text_trimmed()
+                .to_string(),
+        ),
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            let object = reference_path(&member.object().ok()?)?;
+            let name = member.member().ok()?;
+            let name = name.as_js_name()?.value_token().ok()?;
+            Some(format!("{object}.{}", name.text_trimmed()))
+        }
+        AnyJsExpression::JsThisExpression(_) => Some("this".to_string()),
+        _ => None,
+    }
+}
+
+/// Returns the name of the binding at the root of a reference, such as `value`
+/// for `value.kind`.
+fn root_name(expression: &AnyJsExpression) -> Option<Text> {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsIdentifierExpression(identifier) => Some(
+            identifier
+                .name()
+                .ok()?
+                .value_token()
+                .ok()?
+                .token_text_trimmed()
+                .into(),
+        ),
+        AnyJsExpression::JsStaticMemberExpression(member) => root_name(&member.object().ok()?),
+        AnyJsExpression::JsComputedMemberExpression(member) => root_name(&member.object().ok()?),
+        _ => None,
+    }
+}
+
+/// Iterates the descendants of `node`, without descending into nested
+/// functions, which have control flow graphs of their own.
+fn descendants_in_function(node: &JsSyntaxNode) -> impl Iterator<Item = JsSyntaxNode> {
+    let mut preorder = node.preorder();
+    std::iter::from_fn(move || {
+        loop {
+            match preorder.next()? {
+                WalkEvent::Enter(descendant) => {
+                    if descendant != *node && AnyJsControlFlowRoot::can_cast(descendant.kind()) {
+                        preorder.skip_subtree();
+                        continue;
+                    }
+
+                    return Some(descendant);
+                }
+                WalkEvent::Leave(_) => {}
+            }
+        }
+    })
+}
@@ -0,0 +1,7 @@
+console.log("first");
+
+function log(value) {
+	console.log(value);
+}
+
+console.info("ignored");
@@ -345,3 +345,13 @@ function returnMaybePromise(): Promise<void> | undefined {
 }
 
 returnMaybePromise();
+
+async function fetchUser() {
+	return { name: "Jane" };
+}
+
+function fetchUserAgain(): ReturnType<typeof fetchUser> {
+	return fetchUser();
+}
+
+fetchUserAgain();
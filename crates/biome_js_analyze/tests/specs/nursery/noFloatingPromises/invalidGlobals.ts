@@ -0,0 +1,7 @@
+fetch("https://biomejs.dev");
+
+function parseBody(response: Response) {
+	response.json();
+}
+
+new Response("{}").text();
@@ -0,0 +1,12 @@
+function typeofGuard(value: string | Promise<string>) {
+	if (typeof value !== 'string') {
+		value;
+	}
+}
+
+function afterReassignment(value: string | Promise<string>, other: Promise<string>) {
+	if (typeof value === 'string') {
+		value = other;
+		value;
+	}
+}
@@ -0,0 +1,11 @@
+/* should not generate diagnostics */
+await fetch("https://biomejs.dev");
+
+const controller = new AbortController();
+controller.abort();
+
+const cache = new Map<string, string>();
+cache.set("key", "value");
+cache.get("key");
+
+setTimeout(() => {}, 100);
@@ -0,0 +1,31 @@
+/* should not generate diagnostics */
+
+function typeofGuard(value: string | Promise<string>) {
+	if (typeof value === 'string') {
+		value;
+	}
+}
+
+function instanceofGuard(value: Date | Promise<Date>) {
+	if (!(value instanceof Promise)) {
+		value;
+	}
+}
+
+function isPromise(value: unknown): value is Promise<unknown> {
+	return value instanceof Promise;
+}
+
+function predicateGuard(value: number | Promise<number>) {
+	if (isPromise(value)) {
+		return;
+	}
+
+	value;
+}
+
+function inGuard(value: { name: string } | Promise<string>) {
+	if ('name' in value) {
+		value;
+	}
+}
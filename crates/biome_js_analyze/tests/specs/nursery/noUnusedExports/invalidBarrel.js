@@ -0,0 +1 @@
+export { fromBarrel, notFromBarrel } from "./invalidReexported.js";
@@ -0,0 +1,5 @@
+import { used } from "./invalidUtils.js";
+
+export function orphan() {
+	return used;
+}
@@ -0,0 +1,5 @@
+export const fromBarrel = 1;
+
+export const notFromBarrel = 2;
+
+export const neverReexported = 3;
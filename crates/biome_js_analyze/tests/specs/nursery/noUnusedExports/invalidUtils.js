@@ -0,0 +1,11 @@
+export function used() {}
+
+export function unused() {}
+
+export const [first, { second }] = [1, { second: 2 }];
+
+function helper() {}
+
+export { helper as renamed };
+
+export default 1;
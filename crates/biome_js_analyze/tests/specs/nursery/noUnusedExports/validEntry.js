@@ -0,0 +1,9 @@
+/* should not generate diagnostics */
+import { used } from "./invalidUtils.js";
+import component from "./validComponent.js";
+import * as namespace from "./validNamespace.js";
+import { fromBarrel } from "./invalidBarrel.js";
+
+export function entry() {
+	return [used, component, namespace, fromBarrel];
+}
@@ -0,0 +1,4 @@
+/* should not generate diagnostics */
+export const a = 1;
+
+export const b = 2;
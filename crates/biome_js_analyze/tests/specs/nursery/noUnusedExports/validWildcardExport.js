@@ -0,0 +1,4 @@
+/* should not generate diagnostics */
+export function feature() {}
+
+export default feature;
@@ -0,0 +1,29 @@
+type Fruit = 'apple' | 'banana' | 'orange';
+
+function reassigned(fruit: Fruit, other: Fruit): number {
+	if (fruit === 'apple') {
+		fruit = other;
+	}
+
+	if (fruit === 'orange') {
+		return 2;
+	}
+
+	switch (fruit) {
+		case 'banana':
+			return 1;
+	}
+}
+
+function notReturning(fruit: Fruit): number {
+	if (fruit === 'apple') {
+		console.log(fruit);
+	}
+
+	switch (fruit) {
+		case 'banana':
+			return 1;
+		case 'orange':
+			return 2;
+	}
+}
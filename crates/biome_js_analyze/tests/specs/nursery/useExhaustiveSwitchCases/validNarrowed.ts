@@ -0,0 +1,70 @@
+/* should not generate diagnostics */
+
+type Fruit = 'apple' | 'banana' | 'orange';
+
+function earlyReturn(fruit: Fruit): number {
+	if (fruit === 'apple') {
+		return 0;
+	}
+
+	switch (fruit) {
+		case 'banana':
+			return 1;
+		case 'orange':
+			return 2;
+	}
+}
+
+function nestedInCondition(fruit: Fruit): number {
+	if (fruit !== 'orange') {
+		switch (fruit) {
+			case 'apple':
+				return 0;
+			case 'banana':
+				return 1;
+		}
+	}
+
+	return 2;
+}
+
+function nullable(fruit: Fruit | null): number {
+	if (!fruit) {
+		return -1;
+	}
+
+	switch (fruit) {
+		case 'apple':
+			return 0;
+		case 'banana':
+			return 1;
+		case 'orange':
+			return 2;
+	}
+}
+
+type Shape = { kind: 'circle'; radius: number } | { kind: 'square'; size: number };
+
+function discriminant(shape: Shape, shapeKind: Shape['kind']): number {
+	if (shapeKind === 'circle') {
+		return shape.kind === 'circle' ? shape.radius : 0;
+	}
+
+	switch (shapeKind) {
+		case 'square':
+			return 1;
+	}
+}
+
+function typeofGuard(value: string | 1 | 2): number {
+	if (typeof value === 'string') {
+		return 0;
+	}
+
+	switch (value) {
+		case 1:
+			return 1;
+		case 2:
+			return 2;
+	}
+}
@@ -176,6 +176,9 @@ pub struct JsFormatOptions {
 
     /// Whether to expand object and array literals to multiple lines. Defaults to "auto".
     expand: Expand,
+
+    /// Whether to format the content of well-known tagged templates, such as `css` and `graphql`. Defaults to "off".
+    embedded_language_formatting: EmbeddedLanguageFormatting,
 }
 
 impl JsFormatOptions {
@@ -196,6 +199,7 @@ impl JsFormatOptions {
             bracket_same_line: BracketSameLine::default(),
             attribute_position: AttributePosition::default(),
             expand: Expand::default(),
+            embedded_language_formatting: EmbeddedLanguageFormatting::default(),
         }
     }
 
@@ -269,6 +273,14 @@ impl JsFormatOptions {
         self
     }
 
+    pub fn with_embedded_language_formatting(
+        mut self,
+        embedded_language_formatting: EmbeddedLanguageFormatting,
+    ) -> Self {
+        self.embedded_language_formatting = embedded_language_formatting;
+        self
+    }
+
     pub fn set_arrow_parentheses(&mut self, arrow_parentheses: ArrowParentheses) {
         self.arrow_parentheses = arrow_parentheses;
     }
@@ -321,6 +333,13 @@ impl JsFormatOptions {
         self.expand = expand;
     }
 
+    pub fn set_embedded_language_formatting(
+        &mut self,
+        embedded_language_formatting: EmbeddedLanguageFormatting,
+    ) {
+        self.embedded_language_formatting = embedded_language_formatting;
+    }
+
     pub fn set_semicolons(&mut self, semicolons: Semicolons) {
         self.semicolons = semicolons;
     }
@@ -372,6 +391,10 @@ impl JsFormatOptions {
     pub fn expand(&self) -> Expand {
         self.expand
     }
+
+    pub fn embedded_language_formatting(&self) -> EmbeddedLanguageFormatting {
+        self.embedded_language_formatting
+    }
 }
 
 impl FormatOptions for JsFormatOptions {
@@ -411,7 +434,12 @@ impl fmt::Display for JsFormatOptions {
         writeln!(f, "Bracket spacing: {}", self.bracket_spacing.value())?;
         writeln!(f, "Bracket same line: {}", self.bracket_same_line.value())?;
         writeln!(f, "Attribute Position: {}", self.attribute_position)?;
-        writeln!(f, "Expand lists: {}", self.expand)
+        writeln!(f, "Expand lists: {}", self.expand)?;
+        writeln!(
+            f,
+            "Embedded language formatting: {}",
+            self.embedded_language_formatting
+        )
     }
 }
 
@@ -542,3 +570,52 @@ impl fmt::Display for ArrowParentheses {
         }
     }
 }
+
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum EmbeddedLanguageFormatting {
+    /// Format the content of well-known tagged templates, such as `css`, `styled.div` and `graphql`.
+    Auto,
+    /// Print the content of tagged templates as is.
+    #[default]
+    Off,
+}
+
+impl EmbeddedLanguageFormatting {
+    pub const fn is_auto(&self) -> bool {
+        matches!(self, Self::Auto)
+    }
+
+    pub const fn is_off(&self) -> bool {
+        matches!(self, Self::Off)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for EmbeddedLanguageFormatting {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "off" => Ok(Self::Off),
+            _ => Err(
+                "Value not supported for Embedded language formatting. Supported values are 'auto' and 'off'.",
+            ),
+        }
+    }
+}
+
+impl fmt::Display for EmbeddedLanguageFormatting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Auto => write!(f, "Auto"),
+            Self::Off => write!(f, "Off"),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::js::lists::template_element_list::FormatJsTemplateElementListOptions;
 use crate::prelude::*;
+use crate::utils::embedded_template::FormatEmbeddedTemplate;
 
 use biome_formatter::write;
 use biome_js_syntax::parentheses::NeedsParentheses;
@@ -12,6 +13,22 @@ pub(crate) struct FormatJsTemplateExpression;
 
 impl FormatNodeRule<JsTemplateExpression> for FormatJsTemplateExpression {
     fn fmt_fields(&self, node: &JsTemplateExpression, f: &mut JsFormatter) -> FormatResult<()> {
+        if f.options().embedded_language_formatting().is_auto() {
+            if let Some(content) = FormatEmbeddedTemplate::try_new(node, f) {
+                return write!(
+                    f,
+                    [
+                        node.tag().format(),
+                        node.type_arguments().format(),
+                        line_suffix_boundary(),
+                        node.l_tick_token().format(),
+                        content,
+                        node.r_tick_token().format()
+                    ]
+                );
+            }
+        }
+
         AnyJsTemplate::from(node.clone()).fmt(f)
     }
 
@@ -0,0 +1,254 @@
+//! Formatting of template literals that contain code of another language, e.g.
+//!
+//! ```js
+//! const Button = styled.button`
+//!   color: ${(props) => props.color};
+//!   ${mixin};
+//! `;
+//! ```
+//!
+//! The content of the template is formatted with the formatter of the embedded language,
+//! after the interpolations have been replaced by placeholders. The formatted content is then
+//! printed verbatim, and the placeholders are replaced by the formatted interpolations.
+
+use crate::context::JsFormatOptions;
+use crate::prelude::*;
+use biome_css_formatter::context::CssFormatOptions;
+use biome_css_parser::{CssParserOptions, parse_css};
+use biome_css_syntax::CssFileSource;
+use biome_formatter::{CstFormatContext, FormatOptions, IndentStyle, LineEnding, LineWidth, write};
+use biome_graphql_formatter::context::GraphqlFormatOptions;
+use biome_graphql_parser::parse_graphql;
+use biome_graphql_syntax::GraphqlFileSource;
+use biome_js_syntax::embedded_template::{EmbeddedLanguage, EmbeddedTemplate};
+use biome_js_syntax::{AnyJsTemplateElement, JsTemplateElement, JsTemplateExpression};
+use biome_rowan::{AstNode, AstNodeList, TextSize};
+use std::ops::Range;
+
+/// Formats the content of a template literal tagged with a well-known tag of an
+/// embedded language, such as `css` or `graphql`.
+pub(crate) struct FormatEmbeddedTemplate {
+    template: JsTemplateExpression,
+    /// The content formatted by the formatter of the embedded language
+    content: String,
+    /// The interpolations, in the order of their placeholders in the formatted content
+    interpolations: Vec<Interpolation>,
+}
+
+impl FormatEmbeddedTemplate {
+    /// Formats the content of `template`.
+    ///
+    /// Returns `None` if the template isn't an embedded template, or if its content
+    /// can't be formatted safely, e.g. because it contains syntax errors.
+    pub(crate) fn try_new(template: &JsTemplateExpression, f: &JsFormatter) -> Option<Self> {
+        let embedded = template.as_embedded_template()?;
+        let text = embedded.text();
+        // Escape sequences have a different meaning in the embedded language
+        if text.contains('\\') || is_empty_content(template) {
+            return None;
+        }
+
+        let formatted = format_embedded_text(&embedded, f.options())?;
+        let content = if embedded.is_wrapped() {
+            unwrap_content(&formatted, f.options())?
+        } else {
+            formatted
+        };
+        // The template breaks before and after its content
+        let content = content.trim_start_matches('\n').trim_end();
+
+        let elements = template
+            .elements()
+            .iter()
+            .filter_map(|element| element.as_js_template_element().cloned());
+        let mut interpolations = Vec::new();
+        for (placeholder, element) in embedded.placeholders().iter().zip(elements) {
+            // Each placeholder must be printed once, otherwise the interpolation would be
+            // lost or duplicated.
+            let mut matches = content.match_indices(placeholder.text());
+            let (start, _) = matches.next()?;
+            if matches.next().is_some() {
+                return None;
+            }
+            interpolations.push(Interpolation {
+                range: start..start + placeholder.text().len(),
+                element,
+                suffix: placeholder.suffix(),
+            });
+        }
+        if interpolations.len() != embedded.placeholders().len() {
+            return None;
+        }
+        interpolations.sort_by_key(|interpolation| interpolation.range.start);
+
+        Some(Self {
+            template: template.clone(),
+            content: content.to_string(),
+            interpolations,
+        })
+    }
+}
+
+/// An interpolation of the template, and the range of its placeholder in the formatted content
+struct Interpolation {
+    range: Range<usize>,
+    element: JsTemplateElement,
+    /// The text printed after the interpolation
+    suffix: &'static str,
+}
+
+impl Format<JsFormatContext> for FormatEmbeddedTemplate {
+    fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
+        for element in self.template.elements() {
+            if let AnyJsTemplateElement::JsTemplateChunkElement(chunk) = element {
+                // The chunks are replaced by the formatted content, and they can't contain comments
+                f.context()
+                    .comments()
+                    .mark_suppression_checked(chunk.syntax());
+                write!(f, [format_removed(&chunk.template_chunk_token()?)])?;
+            }
+        }
+
+        let source_position = self.template.syntax().text_trimmed_range().start();
+        let format_content = format_with(|f| {
+            let mut lines = ContentLines::default();
+            let mut start = 0;
+            for interpolation in &self.interpolations {
+                lines.write_text(
+                    &self.content[start..interpolation.range.start],
+                    source_position,
+                    f,
+                )?;
+                lines.flush(f)?;
+                write!(f, [interpolation.element.format()])?;
+                if !interpolation.suffix.is_empty() {
+                    write!(f, [text(interpolation.suffix)])?;
+                }
+                start = interpolation.range.end;
+            }
+            lines.write_text(&self.content[start..], source_position, f)
+        });
+
+        write!(f, [block_indent(&format_content)])
+    }
+}
+
+/// Tracks the line breaks between the lines of the formatted content
+#[derive(Default)]
+struct ContentLines {
+    pending_line_breaks: usize,
+}
+
+impl ContentLines {
+    fn write_text(
+        &mut self,
+        content: &str,
+        source_position: TextSize,
+        f: &mut JsFormatter,
+    ) -> FormatResult<()> {
+        for (index, line) in content.split('\n').enumerate() {
+            if index > 0 {
+                self.pending_line_breaks += 1;
+            }
+            if !line.is_empty() {
+                self.flush(f)?;
+                write!(f, [dynamic_text(line, source_position)])?;
+            }
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self, f: &mut JsFormatter) -> FormatResult<()> {
+        match self.pending_line_breaks {
+            0 => {}
+            1 => write!(f, [hard_line_break()])?,
+            _ => write!(f, [empty_line()])?,
+        }
+        self.pending_line_breaks = 0;
+        Ok(())
+    }
+}
+
+/// Returns `true` if the template only contains whitespace
+fn is_empty_content(template: &JsTemplateExpression) -> bool {
+    template.elements().iter().all(|element| {
+        element.as_js_template_chunk_element().is_some_and(|chunk| {
+            chunk
+                .template_chunk_token()
+                .is_ok_and(|token| token.text_trimmed().trim().is_empty())
+        })
+    })
+}
+
+/// Formats the text of `embedded` with the formatter of its language
+fn format_embedded_text(embedded: &EmbeddedTemplate, options: &JsFormatOptions) -> Option<String> {
+    // The content is indented by one level inside the template
+    let line_width = if embedded.is_wrapped() {
+        options.line_width()
+    } else {
+        LineWidth::try_from(
+            options
+                .line_width()
+                .value()
+                .saturating_sub(options.indent_width().value().into()),
+        )
+        .ok()?
+    };
+
+    let printed = match embedded.language() {
+        EmbeddedLanguage::Css => {
+            let parse = parse_css(embedded.text(), CssParserOptions::default());
+            if parse.has_errors() {
+                return None;
+            }
+            let options = CssFormatOptions::new(CssFileSource::css())
+                .with_indent_style(options.indent_style())
+                .with_indent_width(options.indent_width())
+                .with_line_ending(LineEnding::Lf)
+                .with_line_width(line_width)
+                .with_quote_style(options.quote_style());
+            biome_css_formatter::format_node(options, &parse.syntax())
+                .ok()?
+                .print()
+                .ok()?
+        }
+        EmbeddedLanguage::GraphQL => {
+            let parse = parse_graphql(embedded.text());
+            if parse.has_errors() {
+                return None;
+            }
+            let options = GraphqlFormatOptions::new(GraphqlFileSource::graphql())
+                .with_indent_style(options.indent_style())
+                .with_indent_width(options.indent_width())
+                .with_line_ending(LineEnding::Lf)
+                .with_line_width(line_width)
+                .with_bracket_spacing(options.bracket_spacing());
+            biome_graphql_formatter::format_node(options, &parse.syntax())
+                .ok()?
+                .print()
+                .ok()?
+        }
+    };
+
+    Some(printed.into_code())
+}
+
+/// Removes the rule that wraps the formatted content, and the indentation it added
+fn unwrap_content(formatted: &str, options: &JsFormatOptions) -> Option<String> {
+    let indent = match options.indent_style() {
+        IndentStyle::Tab => "\t".to_string(),
+        IndentStyle::Space => " ".repeat(options.indent_width().value().into()),
+    };
+    let formatted = formatted.trim_end();
+    let (_, block) = formatted.split_once('\n')?;
+    let (block, closing) = block.rsplit_once('\n')?;
+    if closing != "}" {
+        return None;
+    }
+
+    let lines: Vec<_> = block
+        .split('\n')
+        .map(|line| line.strip_prefix(indent.as_str()).unwrap_or(line))
+        .collect();
+    Some(lines.join("\n"))
+}
@@ -1,6 +1,7 @@
 pub(crate) mod array;
 mod assignment_like;
 mod conditional;
+pub(crate) mod embedded_template;
 mod format_binary_like_expression;
 pub mod string_utils;
 
@@ -0,0 +1,33 @@
+const Button = styled.button`
+color:   ${props => props.color};
+  background: red;${mixin}
+     &:hover { color : blue }
+`;
+
+const Title = styled(Heading).attrs({ level: 1 })`font-size:2em;margin:0 ${spacing}px`;
+
+const primary = css`
+  ${base};
+  border:1px solid ${({theme}) => theme.border};
+
+
+  padding: 4px
+`;
+
+const fadeIn = keyframes`from{opacity:0}to{opacity:1}`;
+
+const GlobalStyle = createGlobalStyle`
+body{margin:0}
+  ${Button}:hover{color:red}
+`;
+
+// Templates with invalid CSS are printed as is
+const Invalid = styled.div`
+  color: red
+  {
+`;
+
+const Empty = styled.div``;
+
+// Other tags are printed as is
+const other = html`<div>${content}</div>`;
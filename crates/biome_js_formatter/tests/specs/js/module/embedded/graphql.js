@@ -0,0 +1,9 @@
+const query = gql`
+  query User($id: ID!) { user(id: $id) { id, name ...UserFields } }
+  ${userFields}
+`;
+
+const mutation = graphql`mutation { like(id: 1) { count } }`;
+
+// Interpolations inside definitions aren't supported
+const inline = gql`query { ${field} }`;
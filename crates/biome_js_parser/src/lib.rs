@@ -66,6 +66,7 @@ mod lexer;
 pub mod options;
 mod parse;
 mod prelude;
+mod reparse;
 mod rewrite;
 mod span;
 mod state;
@@ -78,6 +79,7 @@ pub use crate::{
     lexer::{JsLexContext, JsReLexContext},
     options::JsParserOptions,
     parse::*,
+    reparse::{reparse_statement, reparse_token},
 };
 use biome_js_factory::JsSyntaxFactory;
 use biome_js_syntax::{JsSyntaxKind, LanguageVariant};
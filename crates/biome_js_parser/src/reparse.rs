@@ -0,0 +1,501 @@
+//! Incremental reparsing of edited JavaScript files.
+
+use crate::lexer::{JsLexContext, JsLexer};
+use crate::{JsParserOptions, parse};
+use biome_js_syntax::{JsFileSource, JsModule, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken};
+use biome_parser::lexer::Lexer;
+use biome_rowan::{AstNode, AstNodeList, Direction, NodeOrToken, TextRange, TextSize};
+
+/// Names that the parser treats differently from other identifiers, either
+/// as identifiers or as the content of string literals.
+const SPECIAL_NAMES: &[&str] = &["arguments", "constructor", "eval", "target"];
+
+/// Returns a new version of `root` where the text in `range` is replaced with
+/// `text`, without reparsing the file.
+///
+/// This only works if the edit happens within a single identifier or string
+/// literal, and the edited text still lexes as a single token of the same
+/// kind. The token is then replaced in place, and all the other nodes of the
+/// tree are shared with `root`.
+///
+/// `root` must come from a parse that didn't emit any diagnostic. Returns
+/// `None` if the file needs to be reparsed from scratch.
+pub fn reparse_token(root: &JsSyntaxNode, range: TextRange, text: &str) -> Option<JsSyntaxNode> {
+    let token = root
+        .token_at_offset(range.start())
+        .find(|token| token.text_trimmed_range().contains_range(range))?;
+    if !is_reparsable(&token) {
+        return None;
+    }
+
+    let token_range = token.text_trimmed_range();
+    let old_text = token.text_trimmed();
+    let start = usize::from(range.start() - token_range.start());
+    let end = usize::from(range.end() - token_range.start());
+    let new_text = format!("{}{text}{}", old_text.get(..start)?, old_text.get(end..)?);
+    if !lexes_as_single_token(&new_text, token.kind()) || is_special_name(&new_text) {
+        return None;
+    }
+
+    let new_token = token.with_text_trimmed(&new_text);
+    root.clone().replace_child(token.into(), new_token.into())
+}
+
+/// Returns a new version of `root` where the text in `range` is replaced with
+/// `text`, by parsing again only the smallest statement that contains the
+/// edit.
+///
+/// The statement is parsed on its own, with the same `source_type` and
+/// `options` as `root`. Its new version replaces the old one if it parses
+/// without errors, and if the edit doesn't change anything the parser checks
+/// against its context: keywords, blocks, arrow functions and checked names.
+/// Otherwise, the enclosing statements are tried, up to the top level.
+///
+/// This only works for modules, because their parsing mode doesn't depend on
+/// directives. `root` must come from a parse that didn't emit any diagnostic.
+/// Returns `None` if the file needs to be reparsed from scratch.
+pub fn reparse_statement(
+    root: &JsSyntaxNode,
+    range: TextRange,
+    text: &str,
+    source_type: JsFileSource,
+    options: JsParserOptions,
+) -> Option<JsSyntaxNode> {
+    if root.kind() != JsSyntaxKind::JS_MODULE
+        || !root.text_range_with_trivia().contains_range(range)
+    {
+        return None;
+    }
+
+    let node = match root.covering_element(range) {
+        NodeOrToken::Node(node) => node,
+        NodeOrToken::Token(token) => token.parent()?,
+    };
+    let new_statement = node
+        .ancestors()
+        .filter(|node| {
+            node.parent().is_some_and(|parent| {
+                matches!(
+                    parent.kind(),
+                    JsSyntaxKind::JS_STATEMENT_LIST | JsSyntaxKind::JS_MODULE_ITEM_LIST
+                )
+            })
+        })
+        .find_map(|statement| {
+            let new_statement =
+                reparse_node(&statement, range, text, source_type, options.clone())?;
+            Some((statement, new_statement))
+        });
+    let (statement, new_statement) = new_statement?;
+
+    root.clone()
+        .replace_child(statement.into(), new_statement.into())
+}
+
+/// Parses `statement` again with the edit applied, and returns its new
+/// version if it can replace the old one.
+fn reparse_node(
+    statement: &JsSyntaxNode,
+    range: TextRange,
+    text: &str,
+    source_type: JsFileSource,
+    options: JsParserOptions,
+) -> Option<JsSyntaxNode> {
+    // Ambient contexts allow statements that aren't valid anywhere else.
+    if statement.ancestors().skip(1).any(|node| {
+        matches!(
+            node.kind(),
+            JsSyntaxKind::TS_DECLARE_STATEMENT
+                | JsSyntaxKind::TS_MODULE_DECLARATION
+                | JsSyntaxKind::TS_GLOBAL_DECLARATION
+                | JsSyntaxKind::TS_EXTERNAL_MODULE_DECLARATION
+        )
+    }) {
+        return None;
+    }
+
+    // The first and last tokens must be left untouched, so that the edit
+    // doesn't change where the statement starts and ends.
+    let first_token = statement.first_token()?;
+    let last_token = statement.last_token()?;
+    if range.start() <= first_token.text_trimmed_range().end()
+        || range.end() > last_token.text_trimmed_range().start()
+        || !is_statement_end(&last_token)
+    {
+        return None;
+    }
+
+    let statement_range = statement.text_range_with_trivia();
+    let mut new_text = statement.to_string();
+    new_text.replace_range(
+        usize::from(range.start() - statement_range.start())
+            ..usize::from(range.end() - statement_range.start()),
+        text,
+    );
+
+    let parse = parse(&new_text, source_type, options);
+    if !parse.diagnostics().is_empty() {
+        return None;
+    }
+    let module = JsModule::cast(parse.syntax())?;
+    if !module.directives().is_empty() || module.items().len() != 1 {
+        return None;
+    }
+    let new_statement = module.items().first()?.into_syntax();
+    if new_statement.text_range_with_trivia().len() != TextSize::of(&new_text) {
+        return None;
+    }
+
+    let new_first_token = new_statement.first_token()?;
+    let new_last_token = new_statement.last_token()?;
+    if new_first_token.kind() != first_token.kind()
+        || new_first_token.text() != first_token.text()
+        || !is_statement_end(&new_last_token)
+        || !has_same_checked_tokens(statement, &new_statement)
+    {
+        return None;
+    }
+
+    Some(new_statement.detach())
+}
+
+/// Returns `true` if `token` ends a statement regardless of the token that
+/// follows it.
+fn is_statement_end(token: &JsSyntaxToken) -> bool {
+    match token.kind() {
+        JsSyntaxKind::SEMICOLON => true,
+        JsSyntaxKind::R_CURLY => token.parent().is_some_and(|parent| match parent.kind() {
+            JsSyntaxKind::JS_BLOCK_STATEMENT
+            | JsSyntaxKind::JS_CLASS_DECLARATION
+            | JsSyntaxKind::JS_SWITCH_STATEMENT
+            | JsSyntaxKind::TS_INTERFACE_DECLARATION
+            | JsSyntaxKind::TS_ENUM_DECLARATION
+            | JsSyntaxKind::TS_MODULE_BLOCK => true,
+            // Function expressions and arrow functions can be followed by
+            // the rest of an expression.
+            JsSyntaxKind::JS_FUNCTION_BODY => parent.parent().is_some_and(|grand_parent| {
+                grand_parent.kind() == JsSyntaxKind::JS_FUNCTION_DECLARATION
+            }),
+            _ => false,
+        }),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `old` and `new` have the same sequence of the tokens
+/// that the parser checks against the context of a statement.
+///
+/// Keywords such as `await`, `yield` or `return`, and the names that the
+/// parser compares with other names, are valid or not depending on the
+/// enclosing functions, classes and declarations. Blocks and arrow functions
+/// change which declarations are compared with each other.
+fn has_same_checked_tokens(old: &JsSyntaxNode, new: &JsSyntaxNode) -> bool {
+    let checked_tokens = |node: &JsSyntaxNode| {
+        node.descendants_tokens(Direction::Next)
+            .filter(|token| {
+                let kind = token.kind();
+                kind.is_keyword()
+                    || matches!(
+                        kind,
+                        JsSyntaxKind::L_CURLY | JsSyntaxKind::R_CURLY | JsSyntaxKind::FAT_ARROW
+                    )
+                    || is_checked_name(token)
+                    || (matches!(kind, JsSyntaxKind::IDENT | JsSyntaxKind::JS_STRING_LITERAL)
+                        && is_special_name(token.text_trimmed()))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let old_tokens = checked_tokens(old);
+    let new_tokens = checked_tokens(new);
+    old_tokens.len() == new_tokens.len()
+        && old_tokens
+            .iter()
+            .zip(&new_tokens)
+            .all(|(old, new)| old.kind() == new.kind() && old.text_trimmed() == new.text_trimmed())
+}
+
+/// Returns `true` if the text of `token` can be changed without affecting the
+/// shape of the tree.
+fn is_reparsable(token: &JsSyntaxToken) -> bool {
+    if is_checked_name(token) {
+        return false;
+    }
+
+    let text = token.text_trimmed();
+    match token.kind() {
+        JsSyntaxKind::IDENT => {
+            // Contextual keywords are lexed as keywords, and remapped to
+            // identifiers by the parser.
+            lexes_as_single_token(text, JsSyntaxKind::IDENT) && !is_special_name(text)
+        }
+        JsSyntaxKind::JS_STRING_LITERAL => {
+            // Directives can change the parsing mode of the whole file.
+            token
+                .parent()
+                .is_some_and(|parent| parent.kind() != JsSyntaxKind::JS_DIRECTIVE)
+                && !is_special_name(text)
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `token` is a name that the parser compares with other
+/// names to report errors, such as duplicate declarations or undefined
+/// labels. Changing it may add or remove these errors.
+fn is_checked_name(token: &JsSyntaxToken) -> bool {
+    let Some(parent) = token.parent() else {
+        return false;
+    };
+    match parent.kind() {
+        // Labels of statements, and targets of `break` and `continue`
+        JsSyntaxKind::JS_LABEL
+        // Declarators, parameters and import bindings
+        | JsSyntaxKind::JS_IDENTIFIER_BINDING
+        | JsSyntaxKind::TS_IDENTIFIER_BINDING
+        // Keys of import attributes
+        | JsSyntaxKind::JS_IMPORT_ASSERTION_ENTRY
+        // Names of exports
+        | JsSyntaxKind::JS_LITERAL_EXPORT_NAME => true,
+        JsSyntaxKind::JS_REFERENCE_IDENTIFIER => parent.parent().is_some_and(|grand_parent| {
+            grand_parent.kind() == JsSyntaxKind::JS_EXPORT_NAMED_SHORTHAND_SPECIFIER
+        }),
+        _ => false,
+    }
+}
+
+fn is_special_name(text: &str) -> bool {
+    // Escape sequences are validated differently depending on the context.
+    if text.contains('\\') {
+        return true;
+    }
+
+    let name = text.trim_matches(['"', '\'']);
+    SPECIAL_NAMES.contains(&name)
+}
+
+/// Returns `true` if `text` is a single token of the given `kind`, without
+/// any trivia or lexing error.
+fn lexes_as_single_token(text: &str, kind: JsSyntaxKind) -> bool {
+    let mut lexer = JsLexer::from_str(text);
+    if lexer.next_token(JsLexContext::Regular) != kind
+        || lexer.current_range().end() != TextSize::of(text)
+        || lexer.next_token(JsLexContext::Regular) != JsSyntaxKind::EOF
+    {
+        return false;
+    }
+
+    lexer.finish().is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{reparse_statement, reparse_token};
+    use crate::{JsParserOptions, parse};
+    use biome_js_syntax::JsFileSource;
+    use biome_rowan::{TextRange, TextSize};
+
+    fn assert_reparse(source: &str, range: TextRange, text: &str) {
+        let parse = parse(source, JsFileSource::tsx(), JsParserOptions::default());
+        assert!(!parse.has_errors());
+
+        let mut new_source = source.to_string();
+        new_source.replace_range(std::ops::Range::<usize>::from(range), text);
+        let expected = crate::parse(&new_source, JsFileSource::tsx(), JsParserOptions::default());
+
+        let reparsed = reparse_token(&parse.syntax(), range, text).expect("reparse the token");
+        assert_eq!(reparsed.to_string(), new_source);
+        assert_eq!(
+            format!("{reparsed:#?}"),
+            format!("{:#?}", expected.syntax())
+        );
+    }
+
+    fn assert_no_reparse(source: &str, range: TextRange, text: &str) {
+        let parse = parse(source, JsFileSource::tsx(), JsParserOptions::default());
+        assert!(reparse_token(&parse.syntax(), range, text).is_none());
+    }
+
+    /// Asserts that the edit isn't reparsed in place, because a full parse of
+    /// the edited source reports an error.
+    #[track_caller]
+    fn assert_full_reparse_has_errors(source: &str, range: TextRange, text: &str) {
+        let parse = parse(source, JsFileSource::ts(), JsParserOptions::default());
+        assert!(!parse.has_errors());
+
+        let mut new_source = source.to_string();
+        new_source.replace_range(std::ops::Range::<usize>::from(range), text);
+        let expected = crate::parse(&new_source, JsFileSource::ts(), JsParserOptions::default());
+        assert!(expected.has_errors());
+
+        assert!(reparse_token(&parse.syntax(), range, text).is_none());
+    }
+
+    #[track_caller]
+    fn assert_statement_reparse(source: &str, range: TextRange, text: &str) {
+        let parse = parse(source, JsFileSource::tsx(), JsParserOptions::default());
+        assert!(!parse.has_errors());
+
+        let mut new_source = source.to_string();
+        new_source.replace_range(std::ops::Range::<usize>::from(range), text);
+        let expected = crate::parse(&new_source, JsFileSource::tsx(), JsParserOptions::default());
+        assert!(!expected.has_errors());
+
+        let reparsed = reparse_statement(
+            &parse.syntax(),
+            range,
+            text,
+            JsFileSource::tsx(),
+            JsParserOptions::default(),
+        )
+        .expect("reparse the statement");
+        assert_eq!(reparsed.to_string(), new_source);
+        assert_eq!(
+            format!("{reparsed:#?}"),
+            format!("{:#?}", expected.syntax())
+        );
+    }
+
+    #[track_caller]
+    fn assert_no_statement_reparse(source: &str, range: TextRange, text: &str) {
+        let parse = parse(source, JsFileSource::tsx(), JsParserOptions::default());
+        assert!(!parse.has_errors());
+        assert!(
+            reparse_statement(
+                &parse.syntax(),
+                range,
+                text,
+                JsFileSource::tsx(),
+                JsParserOptions::default(),
+            )
+            .is_none()
+        );
+    }
+
+    fn range(start: u32, end: u32) -> TextRange {
+        TextRange::new(TextSize::from(start), TextSize::from(end))
+    }
+
+    #[test]
+    fn reparses_identifiers() {
+        assert_reparse("let foo = bar;\nfoo();", range(15, 18), "baz");
+        assert_reparse("let foo = bar;\nfoo();", range(13, 13), "2");
+        assert_reparse("let foo = bar;\nfoo();", range(10, 10), "_");
+        assert_reparse("const a = { b: 1 };", range(12, 13), "longer");
+    }
+
+    #[test]
+    fn reparses_string_literals() {
+        assert_reparse("import a from \"./a\";", range(17, 18), "b/c");
+        assert_reparse("f('a', \"b\");", range(3, 3), "x y");
+    }
+
+    #[test]
+    fn doesnt_reparse_other_edits() {
+        // Keywords
+        assert_no_reparse("let foo = bar;", range(4, 7), "if");
+        assert_no_reparse("let foo = bar;", range(4, 7), "async");
+        // More than one token
+        assert_no_reparse("let foo = bar;", range(4, 7), "a.b");
+        assert_no_reparse("let foo = bar;", range(3, 7), "baz");
+        // Names that the parser handles differently
+        assert_no_reparse("let foo = bar;", range(4, 7), "eval");
+        assert_no_reparse("class A { \"foo\"() {} }", range(11, 14), "constructor");
+        // Unterminated strings and escapes
+        assert_no_reparse("f('a');", range(3, 4), "'");
+        assert_no_reparse("f('a');", range(3, 4), "\\x");
+        // Directives
+        assert_no_reparse("\"use strict\";", range(1, 4), "no");
+        // Numbers
+        assert_no_reparse("f(1);", range(2, 3), "2");
+    }
+
+    #[test]
+    fn doesnt_reparse_checked_names() {
+        // Undefined labels
+        assert_full_reparse_has_errors("foo: while (1) break foo;", range(21, 24), "bar");
+        assert_full_reparse_has_errors("foo: while (1) continue foo;", range(24, 27), "bar");
+        assert_full_reparse_has_errors("foo: while (1) break foo;", range(0, 3), "bar");
+        // Duplicate labels
+        assert_full_reparse_has_errors("foo: bar: while (1) {}", range(5, 8), "foo");
+        // Duplicate declarations
+        assert_full_reparse_has_errors("let a, b;", range(7, 8), "a");
+        assert_full_reparse_has_errors("import { a, b } from \"./a\";", range(12, 13), "a");
+        // Duplicate import attribute keys
+        assert_full_reparse_has_errors(
+            "import a from \"./a\" with { type: \"json\", mode: \"x\" };",
+            range(41, 45),
+            "type",
+        );
+        // Export names
+        assert_no_reparse("let a;\nexport { a as \"b\" };", range(22, 23), "default");
+        assert_no_reparse("let a, b;\nexport { a };", range(19, 20), "b");
+    }
+
+    #[test]
+    fn reparses_statements() {
+        // Edits spanning several tokens
+        assert_statement_reparse("let a = 1;\nfoo(a);\nlet b = 2;\n", range(15, 16), "a, b.c");
+        assert_statement_reparse("let a = 1;\nfoo(a);\n", range(8, 9), "[1, 2]");
+        assert_statement_reparse("foo(a + b);", range(4, 9), "(a, b) * c");
+        // Nested statements
+        assert_statement_reparse(
+            "function f(a) {\n  let b = a;\n  return b;\n}\n",
+            range(26, 27),
+            "a.b(1)",
+        );
+        assert_statement_reparse(
+            "async function f() {\n  await foo(1);\n}\n",
+            range(33, 34),
+            "1, 2",
+        );
+        // Statements that end with a block
+        assert_statement_reparse("if (a) {\n  b();\n}\n", range(4, 5), "a && c");
+        assert_statement_reparse("class A {\n  b = 1;\n}\n", range(16, 17), "2 + 3");
+        // Enclosing statements, when a statement can't be parsed on its own
+        assert_statement_reparse("function f(a) {\n  return a;\n}\n", range(25, 26), "a + 1");
+        assert_statement_reparse("while (a) {\n  if (b) break;\n}\n", range(18, 19), "b || c");
+        // Ambient contexts, which are parsed as a whole
+        assert_statement_reparse(
+            "declare namespace A {\n  let b: c;\n}\n",
+            range(31, 32),
+            "d[]",
+        );
+    }
+
+    #[test]
+    fn doesnt_reparse_statements_with_other_edits() {
+        // Edits of the first or last token
+        assert_no_statement_reparse("foo(a);", range(0, 3), "bar");
+        assert_no_statement_reparse("foo(a);", range(5, 7), ")");
+        // Statements that can be continued by the next one
+        assert_no_statement_reparse("let a = b\nfoo(a)", range(8, 9), "c");
+        assert_no_statement_reparse("let a = () => {}", range(8, 10), "(b)");
+        // Edits that split or merge statements
+        assert_no_statement_reparse("foo(a);", range(4, 5), "); bar(");
+        // Keywords, blocks and arrow functions
+        assert_no_statement_reparse("function f() {\n  foo(a);\n}\n", range(21, 22), "await a");
+        assert_no_statement_reparse("function* f() {\n  foo(a);\n}\n", range(22, 23), "yield");
+        assert_no_statement_reparse("foo(a);", range(4, 5), "{ a }");
+        assert_no_statement_reparse("foo(a);", range(4, 5), "a => a");
+        // Checked and special names
+        assert_no_statement_reparse("let a = 1;\nlet b = 2;", range(15, 16), "a, c");
+        assert_no_statement_reparse("class A {\n  b = c;\n}\n", range(16, 17), "arguments");
+        // Scripts
+        let parse = parse(
+            "foo(a);",
+            JsFileSource::js_script(),
+            JsParserOptions::default(),
+        );
+        assert!(
+            reparse_statement(
+                &parse.syntax(),
+                range(4, 5),
+                "a, b",
+                JsFileSource::js_script(),
+                JsParserOptions::default(),
+            )
+            .is_none()
+        );
+    }
+}
@@ -134,6 +134,25 @@ impl Binding {
     pub fn is_imported(&self) -> bool {
         super::is_imported(self.syntax())
     }
+
+    /// Returns `true` if this is a top-level binding referenced by code that is
+    /// embedded next to the root, such as the template of a Vue single-file component.
+    pub fn is_referenced_by_embedded_code(&self) -> bool {
+        if self.data.embedded_references.is_empty() {
+            return false;
+        }
+        let name = self.syntax().text_trimmed().to_string();
+        // Function declarations are hoisted, so the scope of their range isn't
+        // the scope they're declared in.
+        let global_scope = Scope {
+            data: self.data.clone(),
+            id: ScopeId::new(0),
+        };
+        self.data.embedded_references.contains(name.as_str())
+            && global_scope
+                .get_binding(name)
+                .is_some_and(|binding| binding.id == self.id)
+    }
 }
 
 impl PartialEq for Binding {
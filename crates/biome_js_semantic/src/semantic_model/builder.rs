@@ -26,6 +26,8 @@ pub struct SemanticModelBuilder {
     declared_at_by_start: FxHashMap<TextSize, BindingId>,
     exported: FxHashSet<TextSize>,
     unresolved_references: Vec<SemanticModelUnresolvedReference>,
+    /// Names referenced from code embedded next to the JavaScript root
+    embedded_references: FxHashSet<String>,
 }
 
 impl SemanticModelBuilder {
@@ -44,6 +46,7 @@ impl SemanticModelBuilder {
             declared_at_by_start: FxHashMap::default(),
             exported: FxHashSet::default(),
             unresolved_references: Vec::new(),
+            embedded_references: FxHashSet::default(),
         }
     }
 
@@ -124,6 +127,13 @@ impl SemanticModelBuilder {
         self.globals_by_name.insert(name.into(), None);
     }
 
+    /// Records that `name` is referenced by code that is embedded next to the
+    /// root, such as the template of a Vue single-file component.
+    #[inline]
+    pub fn push_embedded_reference(&mut self, name: impl Into<String>) {
+        self.embedded_references.insert(name.into());
+    }
+
     #[inline]
     pub fn push_event(&mut self, e: SemanticEvent) {
         use SemanticEvent::*;
@@ -354,6 +364,7 @@ impl SemanticModelBuilder {
             exported: self.exported,
             unresolved_references: self.unresolved_references,
             globals: self.globals,
+            embedded_references: self.embedded_references,
         };
         SemanticModel::new(data)
     }
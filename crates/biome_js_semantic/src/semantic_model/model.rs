@@ -89,6 +89,8 @@ pub(crate) struct SemanticModelData {
     pub(crate) unresolved_references: Vec<SemanticModelUnresolvedReference>,
     /// All globals references
     pub(crate) globals: Vec<SemanticModelGlobalBindingData>,
+    /// Names referenced from code embedded next to the root
+    pub(crate) embedded_references: FxHashSet<String>,
 }
 
 impl SemanticModelData {
@@ -0,0 +1,361 @@
+//! Detection of template literals that contain code of another language, such as
+//! `` styled.div`color: red;` `` or `` gql`query { id }` ``.
+
+use crate::{AnyJsExpression, AnyJsTemplateElement, JsCallExpression, JsTemplateExpression};
+use biome_rowan::{AstNodeList, TextLen, TextRange, TextSize};
+
+/// The language of the content of an [EmbeddedTemplate]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum EmbeddedLanguage {
+    Css,
+    GraphQL,
+}
+
+/// How the content of a CSS template is wrapped, so that it can be parsed as a stylesheet
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum EmbeddedWrapper {
+    /// The content is a stylesheet, e.g. `` createGlobalStyle`body { margin: 0; }` ``
+    None,
+    /// The content is the block of a rule, e.g. `` styled.div`color: red;` ``
+    Rule,
+    /// The content is the block of a `@keyframes` at-rule, e.g. `` keyframes`from {} to {}` ``
+    Keyframes,
+}
+
+impl EmbeddedWrapper {
+    const fn prefix(self) -> &'static str {
+        match self {
+            Self::None => "",
+            Self::Rule => "__biome_embed__ {\n",
+            Self::Keyframes => "@keyframes __biome_embed__ {\n",
+        }
+    }
+
+    const fn suffix(self) -> &'static str {
+        match self {
+            Self::None => "",
+            Self::Rule | Self::Keyframes => "\n}",
+        }
+    }
+}
+
+/// An interpolation `${...}` of an [EmbeddedTemplate], replaced by a placeholder
+/// in the text of the embedded document.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EmbeddedPlaceholder {
+    /// The text of the placeholder, as it's expected to be printed by the formatter
+    /// of the embedded language.
+    text: String,
+    /// The text that follows the interpolation when it's printed back.
+    suffix: &'static str,
+}
+
+impl EmbeddedPlaceholder {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn suffix(&self) -> &'static str {
+        self.suffix
+    }
+}
+
+/// A template literal whose content is written in an [EmbeddedLanguage].
+///
+/// The content is turned in a document of the embedded language by replacing the
+/// interpolations with placeholders that are valid in that language.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EmbeddedTemplate {
+    language: EmbeddedLanguage,
+    wrapper: EmbeddedWrapper,
+    /// The text of the embedded document
+    text: String,
+    /// The placeholders of the interpolations, in source order
+    placeholders: Vec<EmbeddedPlaceholder>,
+    /// The ranges of the text of the embedded document that are copied from the
+    /// template, with the offset of the copy in the source file
+    chunks: Vec<(TextRange, TextSize)>,
+}
+
+impl EmbeddedTemplate {
+    pub fn language(&self) -> EmbeddedLanguage {
+        self.language
+    }
+
+    /// Returns the text of the embedded document
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn placeholders(&self) -> &[EmbeddedPlaceholder] {
+        &self.placeholders
+    }
+
+    /// Returns `true` if the text is wrapped in a rule, which must be removed from
+    /// the formatted document.
+    pub fn is_wrapped(&self) -> bool {
+        self.wrapper != EmbeddedWrapper::None
+    }
+
+    /// Maps a range of the text of the embedded document to the corresponding range
+    /// of the source file.
+    ///
+    /// Returns `None` if the range overlaps a placeholder or the wrapper of the text.
+    pub fn to_source_range(&self, range: TextRange) -> Option<TextRange> {
+        self.chunks
+            .iter()
+            .find(|(chunk, _)| chunk.contains_range(range))
+            .map(|(chunk, offset)| range - chunk.start() + *offset)
+    }
+
+    fn new(template: &JsTemplateExpression) -> Option<Self> {
+        let (language, wrapper) = Self::language_of_tag(&template.tag()?)?;
+        let mut result = Self {
+            language,
+            wrapper,
+            text: wrapper.prefix().to_string(),
+            placeholders: Vec::new(),
+            chunks: Vec::new(),
+        };
+        let elements: Vec<_> = template.elements().iter().collect();
+        for (index, element) in elements.iter().enumerate() {
+            match element {
+                AnyJsTemplateElement::JsTemplateChunkElement(chunk) => {
+                    let token = chunk.template_chunk_token().ok()?;
+                    let range =
+                        TextRange::at(result.text.text_len(), token.text_trimmed().text_len());
+                    result
+                        .chunks
+                        .push((range, token.text_trimmed_range().start()));
+                    result.text.push_str(token.text_trimmed());
+                }
+                AnyJsTemplateElement::JsTemplateElement(_) => {
+                    let before = &result.text[wrapper.prefix().len()..];
+                    let after = match elements.get(index + 1) {
+                        Some(AnyJsTemplateElement::JsTemplateChunkElement(chunk)) => chunk
+                            .template_chunk_token()
+                            .ok()?
+                            .text_trimmed()
+                            .to_string(),
+                        _ => String::new(),
+                    };
+                    let line_before = before.rsplit('\n').next().unwrap_or_default();
+                    let line_after = after.split('\n').next().unwrap_or_default();
+                    let name = format!("__biome_embed_{}__", result.placeholders.len());
+                    // The text in the embedded document, the text printed by the formatter,
+                    // and the text that follows the interpolation when it's printed back
+                    let (text, printed, suffix) = match language {
+                        EmbeddedLanguage::Css => {
+                            // An interpolation that stands alone is a declaration, e.g. a mixin
+                            let is_declaration = matches!(
+                                before.trim_end().chars().last(),
+                                None | Some('{' | '}' | ';')
+                            ) && (line_after.trim().is_empty()
+                                || line_after.trim_start().starts_with([';', '}']));
+                            if !is_declaration {
+                                (name.clone(), name, "")
+                            } else if line_after.trim_start().starts_with(';') {
+                                // The semicolon of the template ends the declaration
+                                (format!("{name}: 0"), format!("{name}: 0;"), ";")
+                            } else {
+                                let declaration = format!("{name}: 0;");
+                                (declaration.clone(), declaration, "")
+                            }
+                        }
+                        EmbeddedLanguage::GraphQL => {
+                            // Interpolations are only supported between definitions,
+                            // where they're replaced by a comment
+                            if !line_before.trim().is_empty() || !line_after.trim().is_empty() {
+                                return None;
+                            }
+                            let comment = format!("# {name}");
+                            (comment.clone(), comment, "")
+                        }
+                    };
+                    result.text.push_str(&text);
+                    result.placeholders.push(EmbeddedPlaceholder {
+                        text: printed,
+                        suffix,
+                    });
+                }
+            }
+        }
+        result.text.push_str(wrapper.suffix());
+
+        Some(result)
+    }
+
+    /// Returns the language of the template tagged with `tag`, and how its content
+    /// must be wrapped.
+    ///
+    /// The following tags are recognized:
+    /// - `css`, `keyframes`, `createGlobalStyle` and `injectGlobal`
+    /// - `styled.div`, `styled(Component)` and their `.attrs(...)` variants
+    /// - `gql` and `graphql`
+    fn language_of_tag(tag: &AnyJsExpression) -> Option<(EmbeddedLanguage, EmbeddedWrapper)> {
+        match tag.clone().omit_parentheses() {
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                let name = identifier.name().ok()?.value_token().ok()?;
+                match name.text_trimmed() {
+                    "css" => Some((EmbeddedLanguage::Css, EmbeddedWrapper::Rule)),
+                    "keyframes" => Some((EmbeddedLanguage::Css, EmbeddedWrapper::Keyframes)),
+                    "createGlobalStyle" | "injectGlobal" => {
+                        Some((EmbeddedLanguage::Css, EmbeddedWrapper::None))
+                    }
+                    "gql" | "graphql" => Some((EmbeddedLanguage::GraphQL, EmbeddedWrapper::None)),
+                    _ => None,
+                }
+            }
+            AnyJsExpression::JsStaticMemberExpression(member) => is_styled(&member.object().ok()?)
+                .then_some((EmbeddedLanguage::Css, EmbeddedWrapper::Rule)),
+            AnyJsExpression::JsCallExpression(call) => {
+                let is_styled_call = is_styled_component_call(&call)
+                    || call
+                        .callee()
+                        .ok()?
+                        .as_js_static_member_expression()
+                        .is_some_and(|member| {
+                            member
+                                .member()
+                                .ok()
+                                .and_then(|member| member.as_js_name()?.value_token().ok())
+                                .is_some_and(|member| member.text_trimmed() == "attrs")
+                                && member.object().is_ok_and(|object| {
+                                    object
+                                        .as_js_call_expression()
+                                        .is_some_and(is_styled_component_call)
+                                        || object.as_js_static_member_expression().is_some_and(
+                                            |member| member.object().is_ok_and(|o| is_styled(&o)),
+                                        )
+                                })
+                        });
+                is_styled_call.then_some((EmbeddedLanguage::Css, EmbeddedWrapper::Rule))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Returns `true` if `expression` is the identifier `styled`
+fn is_styled(expression: &AnyJsExpression) -> bool {
+    expression
+        .as_js_identifier_expression()
+        .and_then(|identifier| identifier.name().ok())
+        .and_then(|name| name.value_token().ok())
+        .is_some_and(|name| name.text_trimmed() == "styled")
+}
+
+/// Returns `true` if `call` is `styled(Component)`
+fn is_styled_component_call(call: &JsCallExpression) -> bool {
+    call.callee().is_ok_and(|callee| is_styled(&callee))
+}
+
+impl JsTemplateExpression {
+    /// Returns the [EmbeddedTemplate] of this template, if its tag is one of the
+    /// well-known tags of an embedded language.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biome_js_syntax::embedded_template::EmbeddedLanguage;
+    /// use biome_js_syntax::JsTemplateExpression;
+    /// use biome_js_parser::{JsParserOptions, parse_module};
+    /// use biome_rowan::AstNode;
+    ///
+    /// let parse = parse_module("styled.div`color: ${color};`", JsParserOptions::default());
+    /// let template = parse.syntax().descendants().find_map(JsTemplateExpression::cast).unwrap();
+    /// let embedded = template.as_embedded_template().unwrap();
+    /// assert_eq!(embedded.language(), EmbeddedLanguage::Css);
+    /// assert_eq!(embedded.text(), "__biome_embed__ {\ncolor: __biome_embed_0__;\n}");
+    /// ```
+    pub fn as_embedded_template(&self) -> Option<EmbeddedTemplate> {
+        EmbeddedTemplate::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_js_factory::syntax::JsTemplateExpression;
+    use biome_js_factory::syntax::embedded_template::{EmbeddedLanguage, EmbeddedTemplate};
+    use biome_js_parser::{JsParserOptions, parse_module};
+    use biome_rowan::{AstNode, TextRange, TextSize};
+
+    fn embedded(source: &str) -> Option<EmbeddedTemplate> {
+        let parse = parse_module(source, JsParserOptions::default());
+        parse
+            .syntax()
+            .descendants()
+            .find_map(JsTemplateExpression::cast)
+            .unwrap()
+            .as_embedded_template()
+    }
+
+    #[test]
+    fn recognizes_tags() {
+        for source in [
+            "css`color: red;`",
+            "keyframes`from {}`",
+            "createGlobalStyle`body {}`",
+            "styled.div`color: red;`",
+            "styled(Button)`color: red;`",
+            "styled.div.attrs({})`color: red;`",
+            "styled(Button).attrs({})`color: red;`",
+        ] {
+            assert_eq!(
+                embedded(source).map(|template| template.language()),
+                Some(EmbeddedLanguage::Css),
+                "{source}"
+            );
+        }
+        for source in ["gql`query { id }`", "graphql`query { id }`"] {
+            assert_eq!(
+                embedded(source).map(|template| template.language()),
+                Some(EmbeddedLanguage::GraphQL),
+                "{source}"
+            );
+        }
+        for source in [
+            "html`<div></div>`",
+            "styles.div`color: red;`",
+            "`color: red;`",
+        ] {
+            assert!(embedded(source).is_none(), "{source}");
+        }
+    }
+
+    #[test]
+    fn replaces_declaration_interpolations() {
+        let template = embedded("css`${mixin};\n${other}\ncolor: red;`").unwrap();
+        assert_eq!(
+            template.text(),
+            "__biome_embed__ {\n__biome_embed_0__: 0;\n__biome_embed_1__: 0;\ncolor: red;\n}"
+        );
+        assert_eq!(template.placeholders()[0].suffix(), ";");
+        assert_eq!(template.placeholders()[1].suffix(), "");
+    }
+
+    #[test]
+    fn rejects_inline_graphql_interpolations() {
+        assert!(embedded("gql`query { ${field} }`").is_none());
+        assert_eq!(
+            embedded("gql`query { id }\n${fragment}\n`").unwrap().text(),
+            "query { id }\n# __biome_embed_0__\n"
+        );
+    }
+
+    #[test]
+    fn maps_ranges_to_the_source() {
+        let template = embedded("css`color: ${color}; width: 1px;`").unwrap();
+        let text = template.text();
+        let start = TextSize::from(text.find("width").unwrap() as u32);
+        assert_eq!(
+            template.to_source_range(TextRange::at(start, TextSize::from(5))),
+            Some(TextRange::at(TextSize::from(21), TextSize::from(5)))
+        );
+        let start = TextSize::from(text.find("__biome_embed_0__").unwrap() as u32);
+        assert_eq!(
+            template.to_source_range(TextRange::at(start, TextSize::from(3))),
+            None
+        );
+    }
+}
@@ -189,9 +189,10 @@ impl AnyJsNamedImportSpecifier {
     /// ```
     pub fn imported_name(&self) -> Option<JsSyntaxToken> {
         match self {
-            specifier @ (Self::JsNamedImportSpecifier(_)
-            | Self::JsShorthandNamedImportSpecifier(_)) => specifier
-                .local_name()?
+            Self::JsNamedImportSpecifier(specifier) => specifier.name().ok()?.value().ok(),
+            Self::JsShorthandNamedImportSpecifier(specifier) => specifier
+                .local_name()
+                .ok()?
                 .as_js_identifier_binding()?
                 .name_token()
                 .ok(),
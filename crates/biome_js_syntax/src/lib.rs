@@ -11,6 +11,7 @@ pub mod binary_like_expression;
 pub mod binding_ext;
 pub mod declaration_ext;
 pub mod directive_ext;
+pub mod embedded_template;
 pub mod export_ext;
 pub mod expr_ext;
 pub mod expression_left_side;
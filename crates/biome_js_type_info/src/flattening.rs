@@ -6,9 +6,11 @@ use std::{
 use biome_rowan::Text;
 
 use crate::{
-    CallArgumentType, DestructureField, GLOBAL_UNKNOWN_ID, Literal, ResolvedTypeData,
-    ResolvedTypeMember, ResolverId, TypeData, TypeInstance, TypeMemberKind, TypeReference,
-    TypeResolver, TypeofCallExpression, TypeofExpression, TypeofStaticMemberExpression,
+    CallArgumentType, ConditionalType, DestructureField, GLOBAL_UNKNOWN_ID, IndexedAccessType,
+    Literal, MappedType, MappedTypeModifier, Resolvable, ResolvedTypeData, ResolvedTypeMember,
+    ResolverId, ReturnType, TupleElementType, TypeData, TypeInstance, TypeMember, TypeMemberKind,
+    TypeOperator, TypeReference, TypeResolver, TypeofCallExpression, TypeofExpression,
+    TypeofStaticMemberExpression,
     globals::{
         GLOBAL_BIGINT_STRING_LITERAL_ID, GLOBAL_BOOLEAN_STRING_LITERAL_ID,
         GLOBAL_FUNCTION_STRING_LITERAL_ID, GLOBAL_NUMBER_STRING_LITERAL_ID,
@@ -65,7 +67,7 @@ impl TypeData {
 }
 
 fn flattened(mut ty: TypeData, resolver: &mut dyn TypeResolver, depth: usize) -> TypeData {
-    const MAX_FLATTEN_DEPTH: usize = 10; // Arbitrary depth, may require tweaking.
+    const MAX_FLATTEN_DEPTH: usize = 20; // Arbitrary depth, may require tweaking.
 
     for depth in depth + 1..=MAX_FLATTEN_DEPTH {
         match &ty {
@@ -84,31 +86,70 @@ fn flattened(mut ty: TypeData, resolver: &mut dyn TypeResolver, depth: usize) ->
                     _ => return ty,
                 }
             }
-            TypeData::InstanceOf(instance_of) => match resolver.resolve_and_get(&instance_of.ty) {
-                Some(resolved) => match resolved.as_raw_data() {
-                    TypeData::InstanceOf(resolved_instance) => {
-                        return resolved.apply_module_id_to_data(TypeData::instance_of(
-                            TypeInstance {
-                                ty: resolved_instance.ty.clone(),
-                                type_parameters: TypeReference::merge_parameters(
-                                    &resolved_instance.type_parameters,
-                                    &instance_of.type_parameters,
-                                ),
-                            },
-                        ));
-                    }
-                    TypeData::Global
-                    | TypeData::Function(_)
-                    | TypeData::Literal(_)
-                    | TypeData::Object(_) => ty = resolved.to_data(),
-                    _ => return ty,
-                },
+            TypeData::InstanceOf(instance_of) => {
+                if let Some(instantiated) = instantiated_type_alias(instance_of, resolver) {
+                    ty = instantiated;
+                    continue;
+                }
+
+                match resolver.resolve_and_get(&instance_of.ty) {
+                    Some(resolved) => match resolved.as_raw_data() {
+                        TypeData::InstanceOf(resolved_instance) => {
+                            let instance = resolved.apply_module_id_to_data(TypeData::instance_of(
+                                TypeInstance {
+                                    ty: resolved_instance.ty.clone(),
+                                    type_parameters: TypeReference::merge_parameters(
+                                        &resolved_instance.type_parameters,
+                                        &instance_of.type_parameters,
+                                    ),
+                                },
+                            ));
+                            let instantiated = match &instance {
+                                TypeData::InstanceOf(instance) => {
+                                    instantiated_type_alias(instance, resolver)
+                                }
+                                _ => None,
+                            };
+                            match instantiated {
+                                Some(instantiated) => ty = instantiated,
+                                None => return instance,
+                            }
+                        }
+                        TypeData::Global
+                        | TypeData::Function(_)
+                        | TypeData::Literal(_)
+                        | TypeData::Object(_) => ty = resolved.to_data(),
+                        _ => return ty,
+                    },
+                    None => return ty,
+                }
+            }
+            TypeData::Conditional(conditional) => {
+                match flattened_conditional(conditional, resolver, depth) {
+                    Some(flattened_ty) => ty = flattened_ty,
+                    None => return ty,
+                }
+            }
+            TypeData::IndexedAccess(access) => {
+                match flattened_indexed_access(access, resolver, depth) {
+                    Some(flattened_ty) => ty = flattened_ty,
+                    None => return ty,
+                }
+            }
+            TypeData::Mapped(mapped) => match flattened_mapped(mapped, resolver, depth) {
+                Some(flattened_ty) => ty = flattened_ty,
                 None => return ty,
             },
             TypeData::Reference(reference) => match resolver.resolve_and_get(reference) {
                 Some(reference) => ty = reference.to_data(),
                 None => return ty,
             },
+            TypeData::TypeOperator(operator) if operator.operator == TypeOperator::Keyof => {
+                match flattened_keyof(&operator.ty, resolver, depth) {
+                    Some(flattened_ty) => ty = flattened_ty,
+                    None => return ty,
+                }
+            }
             TypeData::TypeofExpression(expr) => match flattened_expression(expr, resolver, depth) {
                 Some(flattened_ty) => {
                     ty = flattened_ty;
@@ -356,6 +397,44 @@ fn flattened_expression(
                         .unwrap_or_else(|| expr.callee.clone());
                     TypeData::instance_of(constructed_ty)
                 }),
+                // Constructors declared through interfaces, such as
+                // `declare var Map: MapConstructor`.
+                Some(TypeData::InstanceOf(instance)) => {
+                    let members = match resolver
+                        .resolve_and_get(&instance.ty)
+                        .map(ResolvedTypeData::to_data)
+                    {
+                        Some(TypeData::Interface(interface)) => interface.members,
+                        Some(TypeData::Object(object)) => object.members,
+                        _ => return None,
+                    };
+
+                    let num_args = expr.arguments.len();
+                    let constructed_ty = members.iter().find_map(|member| match member.kind {
+                        TypeMemberKind::Constructor => {
+                            match resolver.resolve_and_get(&member.ty)?.to_data() {
+                                // Overloads are matched by the number of
+                                // required parameters only.
+                                TypeData::Constructor(constructor)
+                                    if constructor
+                                        .parameters
+                                        .iter()
+                                        .filter(|param| !param.is_optional && !param.is_rest)
+                                        .count()
+                                        <= num_args =>
+                                {
+                                    constructor.return_type
+                                }
+                                _ => None,
+                            }
+                        }
+                        _ => None,
+                    })?;
+
+                    // The return type of a construct signature already
+                    // describes an instance.
+                    Some(TypeData::reference(constructed_ty))
+                }
                 // TODO: Handle objects with call signatures.
                 _ => None,
             }
@@ -547,6 +626,624 @@ fn flattened_function_call(
     }
 }
 
+/// Evaluates a conditional type, such as `T extends U ? V : W`.
+///
+/// Returns `None` if the check type cannot be evaluated yet, for instance
+/// because it still refers to a generic.
+fn flattened_conditional(
+    conditional: &ConditionalType,
+    resolver: &mut dyn TypeResolver,
+    depth: usize,
+) -> Option<TypeData> {
+    let check = flattened_reference(&conditional.check_type, resolver, depth);
+    if let (true, TypeData::Union(union)) = (conditional.is_distributive, &check) {
+        let variants = union.types().to_vec();
+        let mut types = Vec::with_capacity(variants.len());
+        for variant in variants {
+            let variant = flattened(
+                TypeData::from(ConditionalType {
+                    check_type: variant,
+                    ..conditional.clone()
+                }),
+                resolver,
+                depth,
+            );
+            if matches!(variant, TypeData::Conditional(_)) {
+                return None;
+            }
+
+            types.push(resolver.reference_to_owned_data(variant));
+        }
+
+        return Some(TypeData::union_of(types));
+    }
+
+    let extends = flattened_reference(&conditional.extends_type, resolver, depth);
+    let mut inferred = Vec::new();
+    let branch = match extends_type(&check, &extends, &mut inferred, resolver, depth)? {
+        true => &conditional.true_type,
+        false => &conditional.false_type,
+    };
+
+    let data = resolver.resolve_and_get(branch)?.to_data();
+    Some(if inferred.is_empty() {
+        data
+    } else {
+        substituted_data(&data, &inferred, resolver, 0)
+    })
+}
+
+/// Determines whether `source` extends `target`, as required for evaluating
+/// conditional types.
+///
+/// Types that are inferred through `infer` declarations inside `target` are
+/// pushed onto `inferred`. Returns `None` if the outcome cannot be determined.
+pub(crate) fn extends_type(
+    source: &TypeData,
+    target: &TypeData,
+    inferred: &mut Vec<(Text, TypeReference)>,
+    resolver: &mut dyn TypeResolver,
+    depth: usize,
+) -> Option<bool> {
+    match (source, target) {
+        (_, TypeData::Infer(param)) => {
+            if param.constraint.is_known() {
+                let constraint = flattened_reference(&param.constraint, resolver, depth);
+                if !extends_type(source, &constraint, inferred, resolver, depth)? {
+                    return Some(false);
+                }
+            }
+
+            let ty = match source {
+                TypeData::Unknown => TypeReference::Unknown,
+                _ => resolver.reference_to_owned_data(source.clone()),
+            };
+            inferred.push((param.name.clone(), ty));
+            Some(true)
+        }
+        (_, TypeData::AnyKeyword | TypeData::UnknownKeyword) | (TypeData::NeverKeyword, _) => {
+            Some(true)
+        }
+        (
+            TypeData::AnyKeyword | TypeData::Generic(_) | TypeData::Infer(_) | TypeData::Unknown,
+            _,
+        )
+        | (_, TypeData::Generic(_) | TypeData::Unknown) => None,
+        (TypeData::Union(union), _) => {
+            let mut result = Some(true);
+            for variant in union.types().to_vec() {
+                let variant = flattened_reference(&variant, resolver, depth);
+                match extends_type(&variant, target, inferred, resolver, depth) {
+                    Some(true) => {}
+                    Some(false) => return Some(false),
+                    None => result = None,
+                }
+            }
+            result
+        }
+        (_, TypeData::Union(union)) => {
+            let mut result = Some(false);
+            for variant in union.types().to_vec() {
+                let variant = flattened_reference(&variant, resolver, depth);
+                let mut variant_inferred = Vec::new();
+                match extends_type(source, &variant, &mut variant_inferred, resolver, depth) {
+                    Some(true) => {
+                        inferred.extend(variant_inferred);
+                        return Some(true);
+                    }
+                    Some(false) => {}
+                    None => result = None,
+                }
+            }
+            result
+        }
+        (_, TypeData::Intersection(intersection)) => {
+            let mut result = Some(true);
+            for variant in intersection.types().to_vec() {
+                let variant = flattened_reference(&variant, resolver, depth);
+                match extends_type(source, &variant, inferred, resolver, depth) {
+                    Some(true) => {}
+                    Some(false) => return Some(false),
+                    None => result = None,
+                }
+            }
+            result
+        }
+        (TypeData::Intersection(_), _) => None,
+        (_, TypeData::NeverKeyword) => Some(false),
+        (_, TypeData::VoidKeyword) => Some(matches!(
+            source,
+            TypeData::Undefined | TypeData::VoidKeyword
+        )),
+        (_, TypeData::Literal(target)) => Some(matches!(
+            source,
+            TypeData::Literal(source) if source == target
+        )),
+        (
+            _,
+            TypeData::BigInt
+            | TypeData::Boolean
+            | TypeData::Null
+            | TypeData::Number
+            | TypeData::String
+            | TypeData::Symbol
+            | TypeData::Undefined,
+        ) => Some(match source {
+            TypeData::Literal(literal) => matches!(
+                (literal.as_ref(), target),
+                (Literal::BigInt(_), TypeData::BigInt)
+                    | (Literal::Boolean(_), TypeData::Boolean)
+                    | (Literal::Null, TypeData::Null)
+                    | (Literal::Number(_), TypeData::Number)
+                    | (Literal::String(_) | Literal::Template(_), TypeData::String)
+            ),
+            _ => source == target,
+        }),
+        (_, TypeData::ObjectKeyword) => Some(match source {
+            TypeData::Literal(literal) => {
+                matches!(literal.as_ref(), Literal::Object(_) | Literal::RegExp(_))
+            }
+            _ => !is_primitive(source),
+        }),
+        (_, TypeData::Function(target)) => match source {
+            TypeData::Function(source) => {
+                // TODO: Match the function parameters too.
+                let source_return = match &source.return_type {
+                    ReturnType::Type(ty) => flattened_reference(ty, resolver, depth),
+                    ReturnType::Predicate(_) => TypeData::Boolean,
+                    ReturnType::Asserts(_) => TypeData::VoidKeyword,
+                };
+                let target_return =
+                    flattened_reference(target.return_type.as_type()?, resolver, depth);
+                extends_type(&source_return, &target_return, inferred, resolver, depth)
+            }
+            TypeData::Class(_) | TypeData::Literal(_) | TypeData::Tuple(_) => Some(false),
+            _ if is_primitive(source) => Some(false),
+            _ => None,
+        },
+        (_, TypeData::InstanceOf(target)) => match source {
+            TypeData::InstanceOf(source) => {
+                let source_id = resolver.resolve_reference(&source.ty);
+                if source_id.is_none() || source_id != resolver.resolve_reference(&target.ty) {
+                    return None;
+                }
+
+                let mut result = Some(true);
+                for (index, target_param) in target.type_parameters.iter().enumerate() {
+                    let target_param = flattened_reference(target_param, resolver, depth);
+                    let source_param = source
+                        .type_parameters
+                        .get(index)
+                        .map(|param| flattened_reference(param, resolver, depth))
+                        .unwrap_or_default();
+                    match extends_type(&source_param, &target_param, inferred, resolver, depth) {
+                        Some(true) => {}
+                        Some(false) => return Some(false),
+                        None => result = None,
+                    }
+                }
+                result
+            }
+            TypeData::Function(_) | TypeData::Literal(_) | TypeData::Tuple(_) => Some(false),
+            _ if is_primitive(source) => Some(false),
+            _ => None,
+        },
+        (_, TypeData::Tuple(target)) => match source {
+            TypeData::Tuple(source) => {
+                let has_variable_length = |elements: &[TupleElementType]| {
+                    elements
+                        .iter()
+                        .any(|element| element.is_optional || element.is_rest)
+                };
+                if has_variable_length(source.elements()) || has_variable_length(target.elements())
+                {
+                    return None;
+                }
+
+                if source.elements().len() != target.elements().len() {
+                    return Some(false);
+                }
+
+                let mut result = Some(true);
+                for (source_element, target_element) in
+                    source.elements().iter().zip(target.elements())
+                {
+                    let source_element = flattened_reference(&source_element.ty, resolver, depth);
+                    let target_element = flattened_reference(&target_element.ty, resolver, depth);
+                    match extends_type(&source_element, &target_element, inferred, resolver, depth)
+                    {
+                        Some(true) => {}
+                        Some(false) => return Some(false),
+                        None => result = None,
+                    }
+                }
+                result
+            }
+            TypeData::Function(_) | TypeData::Literal(_) => Some(false),
+            _ if is_primitive(source) => Some(false),
+            _ => None,
+        },
+        (_, TypeData::Object(target)) => {
+            let source_members = instance_members(source, resolver)?;
+            let mut result = Some(true);
+            for member in target.members.iter() {
+                let TypeMemberKind::Named(name) = &member.kind else {
+                    return None;
+                };
+
+                let Some((_, source_ty)) = source_members
+                    .iter()
+                    .find(|(source_name, _)| source_name == name)
+                else {
+                    return Some(false);
+                };
+
+                let source_member = flattened_reference(source_ty, resolver, depth);
+                let target_member = flattened_reference(&member.ty, resolver, depth);
+                match extends_type(&source_member, &target_member, inferred, resolver, depth) {
+                    Some(true) => {}
+                    Some(false) => return Some(false),
+                    None => result = None,
+                }
+            }
+            result
+        }
+        _ => None,
+    }
+}
+
+/// Evaluates an indexed access type, such as `T[K]`.
+fn flattened_indexed_access(
+    access: &IndexedAccessType,
+    resolver: &mut dyn TypeResolver,
+    depth: usize,
+) -> Option<TypeData> {
+    let object = flattened_reference(&access.object_type, resolver, depth);
+    let index = flattened_reference(&access.index_type, resolver, depth);
+    indexed_type(&object, &index, resolver, depth)
+}
+
+fn indexed_type(
+    object: &TypeData,
+    index: &TypeData,
+    resolver: &mut dyn TypeResolver,
+    depth: usize,
+) -> Option<TypeData> {
+    match index {
+        TypeData::Literal(literal) => match (object, literal.as_ref()) {
+            (TypeData::Tuple(tuple), Literal::Number(number)) => {
+                let index = number.to_f64().filter(|index| index.fract() == 0.)?;
+                let element = tuple.elements().get(index as usize)?.ty.clone();
+                Some(TypeData::reference(element))
+            }
+            (_, Literal::Number(_)) => array_element_type(object, resolver),
+            (_, Literal::String(name)) => {
+                let members = instance_members(object, resolver)?;
+                members
+                    .into_iter()
+                    .find(|(member_name, _)| *member_name == name.as_str())
+                    .map(|(_, ty)| TypeData::reference(ty))
+            }
+            _ => None,
+        },
+        TypeData::Number => match object {
+            TypeData::Tuple(tuple) => Some(TypeData::union_of(
+                tuple
+                    .elements()
+                    .iter()
+                    .map(|element| element.ty.clone())
+                    .collect(),
+            )),
+            _ => array_element_type(object, resolver),
+        },
+        TypeData::Union(union) => {
+            let mut types = Vec::with_capacity(union.types().len());
+            for variant in union.types().to_vec() {
+                let variant = flattened_reference(&variant, resolver, depth);
+                let ty = indexed_type(object, &variant, resolver, depth)?;
+                types.push(resolver.reference_to_owned_data(ty));
+            }
+            Some(TypeData::union_of(types))
+        }
+        _ => None,
+    }
+}
+
+/// Evaluates a mapped type, such as `{ [K in keyof T]?: T[K] }`.
+///
+/// Only mapped types that iterate over a known set of keys can be evaluated.
+fn flattened_mapped(
+    mapped: &MappedType,
+    resolver: &mut dyn TypeResolver,
+    depth: usize,
+) -> Option<TypeData> {
+    if mapped.as_type.is_some() {
+        return None; // TODO: Support key remapping.
+    }
+
+    let keys = match flattened_reference(&mapped.keys_type, resolver, depth) {
+        TypeData::Union(union) => union
+            .types()
+            .to_vec()
+            .into_iter()
+            .map(|variant| literal_key(&flattened_reference(&variant, resolver, depth)))
+            .collect::<Option<Vec<_>>>()?,
+        TypeData::NeverKeyword => Vec::new(),
+        keys => vec![literal_key(&keys)?],
+    };
+
+    let members = keys
+        .into_iter()
+        .map(|key| {
+            let key_ty = resolver
+                .reference_to_owned_data(TypeData::from(Literal::String(key.clone().into())));
+            let substitutions = [(mapped.key_name.clone(), key_ty)];
+            let ty = substituted_reference(&mapped.property_type, &substitutions, resolver, 0);
+            let ty = match mapped.optional_modifier {
+                Some(MappedTypeModifier::Add) => {
+                    let id = resolver.optional(ty);
+                    resolver.reference_to_id(id)
+                }
+                _ => ty,
+            };
+            TypeMember {
+                kind: TypeMemberKind::Named(key),
+                is_static: false,
+                ty,
+            }
+        })
+        .collect();
+
+    Some(TypeData::object_with_members(members))
+}
+
+/// Evaluates a `keyof` operator into a union of string literals.
+fn flattened_keyof(
+    ty: &TypeReference,
+    resolver: &mut dyn TypeResolver,
+    depth: usize,
+) -> Option<TypeData> {
+    let object = flattened_reference(ty, resolver, depth);
+    let keys: Vec<_> = instance_members(&object, resolver)?
+        .into_iter()
+        .map(|(name, _)| {
+            resolver.reference_to_owned_data(TypeData::from(Literal::String(name.into())))
+        })
+        .collect();
+
+    Some(match keys.is_empty() {
+        true => TypeData::NeverKeyword,
+        false => TypeData::union_of(keys),
+    })
+}
+
+/// Instantiates a generic type alias that is defined as a conditional, indexed
+/// access or mapped type, such as `ReturnType<T>`.
+///
+/// Returns the definition of the alias with its type parameters substituted
+/// by those of the given `instance`.
+fn instantiated_type_alias(
+    instance: &TypeInstance,
+    resolver: &mut dyn TypeResolver,
+) -> Option<TypeData> {
+    if instance.type_parameters.is_empty() {
+        return None;
+    }
+
+    let TypeData::InstanceOf(definition) = resolver.resolve_and_get(&instance.ty)?.to_data() else {
+        return None;
+    };
+
+    let body = resolver.resolve_and_get(&definition.ty)?;
+    if !matches!(
+        body.as_raw_data(),
+        TypeData::Conditional(_) | TypeData::IndexedAccess(_) | TypeData::Mapped(_)
+    ) {
+        return None;
+    }
+
+    let body = body.to_data();
+    let substitutions = definition
+        .type_parameters
+        .iter()
+        .enumerate()
+        .map(|(index, param)| {
+            let TypeData::Generic(generic) = resolver.resolve_and_get(param)?.to_data() else {
+                return None;
+            };
+
+            let ty = instance
+                .type_parameters
+                .get(index)
+                .filter(|param| param.is_known())
+                .cloned()
+                .unwrap_or(generic.default);
+            Some((generic.name, ty))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(substituted_data(&body, &substitutions, resolver, 0))
+}
+
+/// Substitutes generics and inferred type parameters inside `data` by name.
+fn substituted_data(
+    data: &TypeData,
+    substitutions: &[(Text, TypeReference)],
+    resolver: &mut dyn TypeResolver,
+    depth: usize,
+) -> TypeData {
+    match data {
+        TypeData::Generic(generic) | TypeData::Infer(generic) => substitutions
+            .iter()
+            .find(|(name, _)| *name == generic.name)
+            .map_or_else(|| data.clone(), |(_, ty)| TypeData::reference(ty.clone())),
+        TypeData::InstanceOf(instance) => {
+            // Instances of a generic, such as `T` in `T extends U ? T : never`,
+            // are substituted directly.
+            let substitution = if instance.type_parameters.is_empty() {
+                resolver.resolve_and_get(&instance.ty).and_then(|resolved| {
+                    match resolved.as_raw_data() {
+                        TypeData::Generic(generic) | TypeData::Infer(generic) => substitutions
+                            .iter()
+                            .find(|(name, _)| *name == generic.name)
+                            .map(|(_, ty)| ty.clone()),
+                        _ => None,
+                    }
+                })
+            } else {
+                None
+            };
+
+            match substitution {
+                Some(ty) => TypeData::reference(ty),
+                None => TypeData::instance_of(TypeInstance {
+                    ty: instance.ty.clone(),
+                    type_parameters: instance
+                        .type_parameters
+                        .iter()
+                        .map(|param| {
+                            substituted_reference(param, substitutions, resolver, depth + 1)
+                        })
+                        .collect(),
+                }),
+            }
+        }
+        TypeData::Conditional(_)
+        | TypeData::Function(_)
+        | TypeData::IndexedAccess(_)
+        | TypeData::Intersection(_)
+        | TypeData::Mapped(_)
+        | TypeData::Object(_)
+        | TypeData::Tuple(_)
+        | TypeData::TypeOperator(_)
+        | TypeData::Union(_) => data.resolved_with_mapped_references(
+            |reference, resolver| {
+                substituted_reference(&reference, substitutions, resolver, depth + 1)
+            },
+            resolver,
+        ),
+        _ => data.clone(),
+    }
+}
+
+fn substituted_reference(
+    reference: &TypeReference,
+    substitutions: &[(Text, TypeReference)],
+    resolver: &mut dyn TypeResolver,
+    depth: usize,
+) -> TypeReference {
+    const MAX_SUBSTITUTION_DEPTH: usize = 5; // Arbitrary depth, may require tweaking.
+
+    if depth > MAX_SUBSTITUTION_DEPTH {
+        return reference.clone();
+    }
+
+    let Some(data) = resolver
+        .resolve_and_get(reference)
+        .map(ResolvedTypeData::to_data)
+    else {
+        return reference.clone();
+    };
+
+    let substituted = substituted_data(&data, substitutions, resolver, depth);
+    if substituted == data {
+        reference.clone()
+    } else {
+        resolver.reference_to_owned_data(substituted)
+    }
+}
+
+/// Resolves and flattens the given `reference`.
+fn flattened_reference(
+    reference: &TypeReference,
+    resolver: &mut dyn TypeResolver,
+    depth: usize,
+) -> TypeData {
+    match resolver
+        .resolve_and_get(reference)
+        .map(ResolvedTypeData::to_data)
+    {
+        Some(data) => flattened(data, resolver, depth),
+        None => TypeData::Unknown,
+    }
+}
+
+/// Returns the element type if `data` is an instance of `Array`.
+fn array_element_type(data: &TypeData, resolver: &dyn TypeResolver) -> Option<TypeData> {
+    ResolvedTypeData::from((ResolverId::from_level(resolver.level()), data))
+        .find_array_element_type(resolver)
+        .map(ResolvedTypeData::to_data)
+}
+
+/// Returns the names and types of the named, non-static members of `data`.
+///
+/// Members that are overridden further down the prototype chain are only
+/// returned once.
+fn instance_members(
+    data: &TypeData,
+    resolver: &dyn TypeResolver,
+) -> Option<Vec<(Text, TypeReference)>> {
+    let has_known_members = match data {
+        TypeData::InstanceOf(instance) => {
+            resolver
+                .resolve_and_get(&instance.ty)
+                .is_some_and(|resolved| {
+                    matches!(
+                        resolved.as_raw_data(),
+                        TypeData::Class(_) | TypeData::Interface(_) | TypeData::Object(_)
+                    )
+                })
+        }
+        TypeData::Interface(_) | TypeData::Object(_) => true,
+        _ => false,
+    };
+    if !has_known_members {
+        return None;
+    }
+
+    let mut members: Vec<(Text, TypeReference)> = Vec::new();
+    for member in ResolvedTypeData::from((ResolverId::from_level(resolver.level()), data))
+        .all_members(resolver)
+        .filter(|member| !member.is_static())
+    {
+        let member = member.to_member();
+        if let TypeMemberKind::Named(name) = member.kind {
+            if !members.iter().any(|(existing, _)| *existing == name) {
+                members.push((name, member.ty));
+            }
+        }
+    }
+
+    Some(members)
+}
+
+#[inline]
+fn is_primitive(data: &TypeData) -> bool {
+    matches!(
+        data,
+        TypeData::BigInt
+            | TypeData::Boolean
+            | TypeData::Null
+            | TypeData::Number
+            | TypeData::String
+            | TypeData::Symbol
+            | TypeData::Undefined
+            | TypeData::VoidKeyword
+    )
+}
+
+#[inline]
+fn literal_key(data: &TypeData) -> Option<Text> {
+    match data {
+        TypeData::Literal(literal) => match literal.as_ref() {
+            Literal::Number(number) => Some(number.text().clone()),
+            Literal::String(string) => Some(string.clone().into()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 #[inline]
 fn flattened_typeof_data(resolved: ResolvedTypeData) -> TypeData {
     match resolved.as_raw_data() {
@@ -1,10 +1,11 @@
 use crate::globals::global_type_name;
 use crate::{
-    CallArgumentType, Class, DestructureField, Function, FunctionParameter,
-    FunctionParameterBinding, GenericTypeParameter, ImportSymbol, Interface, Literal,
-    MergedReference, NUM_PREDEFINED_TYPES, Object, ObjectLiteral, ReturnType, Type, TypeData,
-    TypeId, TypeImportQualifier, TypeInstance, TypeMember, TypeMemberKind, TypeReference,
-    TypeReferenceQualifier, TypeResolverLevel, TypeofAwaitExpression, TypeofExpression, Union,
+    CallArgumentType, Class, ConditionalType, DestructureField, Function, FunctionParameter,
+    FunctionParameterBinding, GenericTypeParameter, ImportSymbol, IndexedAccessType, Interface,
+    Literal, MappedType, MappedTypeModifier, MergedReference, NUM_PREDEFINED_TYPES, Object,
+    ObjectLiteral, ReturnType, Type, TypeData, TypeId, TypeImportQualifier, TypeInstance,
+    TypeMember, TypeMemberKind, TypeReference, TypeReferenceQualifier, TypeResolverLevel,
+    TypeofAwaitExpression, TypeofExpression, Union,
 };
 use biome_formatter::prelude::*;
 use biome_formatter::{
@@ -101,9 +102,18 @@ impl Format<FormatTypeContext> for TypeData {
             Self::Object(object) => write!(f, [object.as_ref()]),
             Self::Tuple(ty) => write!(f, [FmtVerbatim(&ty.as_ref())]),
             Self::Generic(generic) => write!(f, [&generic.as_ref()]),
+            Self::Infer(generic) => {
+                write!(
+                    f,
+                    [&format_args![text("infer"), space(), &generic.as_ref()]]
+                )
+            }
             Self::Intersection(ty) => write!(f, [FmtVerbatim(&ty.as_ref())]),
             Self::Union(union) => write!(f, [&union.as_ref()]),
             Self::TypeOperator(ty) => write!(f, [FmtVerbatim(&ty.as_ref())]),
+            Self::Conditional(ty) => write!(f, [&ty.as_ref()]),
+            Self::IndexedAccess(ty) => write!(f, [&ty.as_ref()]),
+            Self::Mapped(ty) => write!(f, [&ty.as_ref()]),
             Self::Literal(ty) => write!(f, [&ty.as_ref()]),
             Self::InstanceOf(ty) => write!(
                 f,
@@ -740,6 +750,87 @@ impl Format<FormatTypeContext> for TypeInstance {
     }
 }
 
+impl Format<FormatTypeContext> for ConditionalType {
+    fn fmt(&self, f: &mut Formatter<FormatTypeContext>) -> FormatResult<()> {
+        write!(
+            f,
+            [&format_args![
+                &self.check_type,
+                space(),
+                text("extends"),
+                space(),
+                &self.extends_type,
+                space(),
+                text("?"),
+                space(),
+                &self.true_type,
+                space(),
+                text(":"),
+                space(),
+                &self.false_type
+            ]]
+        )
+    }
+}
+
+impl Format<FormatTypeContext> for IndexedAccessType {
+    fn fmt(&self, f: &mut Formatter<FormatTypeContext>) -> FormatResult<()> {
+        write!(
+            f,
+            [&format_args![
+                &self.object_type,
+                text("["),
+                &self.index_type,
+                text("]")
+            ]]
+        )
+    }
+}
+
+impl Format<FormatTypeContext> for MappedType {
+    fn fmt(&self, f: &mut Formatter<FormatTypeContext>) -> FormatResult<()> {
+        let readonly = format_with(|f| match self.readonly_modifier {
+            Some(MappedTypeModifier::Add) => write!(f, [text("readonly"), space()]),
+            Some(MappedTypeModifier::Remove) => write!(f, [text("-readonly"), space()]),
+            None => Ok(()),
+        });
+
+        let as_type = format_with(|f| match &self.as_type {
+            Some(as_type) => write!(f, [space(), text("as"), space(), as_type]),
+            None => Ok(()),
+        });
+
+        let optional = format_with(|f| match self.optional_modifier {
+            Some(MappedTypeModifier::Add) => write!(f, [text("?")]),
+            Some(MappedTypeModifier::Remove) => write!(f, [text("-?")]),
+            None => Ok(()),
+        });
+
+        write!(
+            f,
+            [&format_args![
+                text("{"),
+                space(),
+                readonly,
+                text("["),
+                dynamic_text(&self.key_name, TextSize::default()),
+                space(),
+                text("in"),
+                space(),
+                &self.keys_type,
+                as_type,
+                text("]"),
+                optional,
+                text(":"),
+                space(),
+                &self.property_type,
+                space(),
+                text("}")
+            ]]
+        )
+    }
+}
+
 impl Format<FormatTypeContext> for Union {
     fn fmt(&self, f: &mut Formatter<FormatTypeContext>) -> FormatResult<()> {
         let references = format_with(|f| {
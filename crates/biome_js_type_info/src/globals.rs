@@ -1,4 +1,8 @@
 //! Hardcoded global definitions.
+//!
+//! The global declarations that the module graph loads from TypeScript's
+//! `lib.*.d.ts` files take precedence over these, so they're only used when
+//! TypeScript isn't installed.
 
 // FIXME: Implement inference from type definitions.
 
@@ -8,9 +12,10 @@ use biome_js_syntax::AnyJsExpression;
 use biome_rowan::Text;
 
 use crate::{
-    Class, Function, GenericTypeParameter, Literal, Resolvable, ResolvedTypeData, ResolvedTypeId,
-    ReturnType, ScopeId, TypeData, TypeId, TypeMember, TypeMemberKind, TypeReference,
-    TypeReferenceQualifier, TypeResolver, TypeResolverLevel,
+    Class, ConditionalType, Function, FunctionParameter, GenericTypeParameter, IndexedAccessType,
+    Literal, MappedType, MappedTypeModifier, Resolvable, ResolvedTypeData, ResolvedTypeId,
+    ReturnType, ScopeId, TypeData, TypeId, TypeInstance, TypeMember, TypeMemberKind, TypeOperator,
+    TypeOperatorType, TypeReference, TypeReferenceQualifier, TypeResolver, TypeResolverLevel,
 };
 
 const GLOBAL_LEVEL: TypeResolverLevel = TypeResolverLevel::Global;
@@ -56,7 +61,26 @@ pub const UNDEFINED_STRING_LITERAL_ID: TypeId = TypeId::new(24);
 pub const TYPEOF_OPERATOR_RETURN_UNION_ID: TypeId = TypeId::new(25);
 pub const STRING_ID: TypeId = TypeId::new(26);
 pub const T_ID: TypeId = TypeId::new(27);
-pub const NUM_PREDEFINED_TYPES: usize = 28; // Most be one more than the highest `TypeId` above.
+pub const ANY_ID: TypeId = TypeId::new(28);
+pub const P_ID: TypeId = TypeId::new(29);
+pub const INFER_R_ID: TypeId = TypeId::new(30);
+pub const INFER_U_ID: TypeId = TypeId::new(31);
+pub const KEYOF_T_ID: TypeId = TypeId::new(32);
+pub const T_INDEXED_BY_P_ID: TypeId = TypeId::new(33);
+pub const FUNCTION_RETURNING_INFER_R_ID: TypeId = TypeId::new(34);
+pub const RETURN_TYPE_CONDITIONAL_ID: TypeId = TypeId::new(35);
+pub const RETURN_TYPE_ID: TypeId = TypeId::new(36);
+pub const PROMISE_OF_INFER_U_ID: TypeId = TypeId::new(37);
+pub const AWAITED_OF_U_ID: TypeId = TypeId::new(38);
+pub const AWAITED_CONDITIONAL_ID: TypeId = TypeId::new(39);
+pub const AWAITED_ID: TypeId = TypeId::new(40);
+pub const PARTIAL_MAPPED_ID: TypeId = TypeId::new(41);
+pub const PARTIAL_ID: TypeId = TypeId::new(42);
+pub const REQUIRED_MAPPED_ID: TypeId = TypeId::new(43);
+pub const REQUIRED_ID: TypeId = TypeId::new(44);
+pub const READONLY_MAPPED_ID: TypeId = TypeId::new(45);
+pub const READONLY_ID: TypeId = TypeId::new(46);
+pub const NUM_PREDEFINED_TYPES: usize = 47; // Most be one more than the highest `TypeId` above.
 
 pub const GLOBAL_UNKNOWN_ID: ResolvedTypeId = ResolvedTypeId::new(GLOBAL_LEVEL, UNKNOWN_ID);
 pub const GLOBAL_UNDEFINED_ID: ResolvedTypeId = ResolvedTypeId::new(GLOBAL_LEVEL, UNDEFINED_ID);
@@ -86,6 +110,34 @@ pub const GLOBAL_TYPEOF_OPERATOR_RETURN_UNION_ID: ResolvedTypeId =
     ResolvedTypeId::new(GLOBAL_LEVEL, TYPEOF_OPERATOR_RETURN_UNION_ID);
 pub const GLOBAL_STRING_ID: ResolvedTypeId = ResolvedTypeId::new(GLOBAL_LEVEL, STRING_ID);
 pub const GLOBAL_T_ID: ResolvedTypeId = ResolvedTypeId::new(GLOBAL_LEVEL, T_ID);
+pub const GLOBAL_ANY_ID: ResolvedTypeId = ResolvedTypeId::new(GLOBAL_LEVEL, ANY_ID);
+pub const GLOBAL_P_ID: ResolvedTypeId = ResolvedTypeId::new(GLOBAL_LEVEL, P_ID);
+pub const GLOBAL_INFER_R_ID: ResolvedTypeId = ResolvedTypeId::new(GLOBAL_LEVEL, INFER_R_ID);
+pub const GLOBAL_INFER_U_ID: ResolvedTypeId = ResolvedTypeId::new(GLOBAL_LEVEL, INFER_U_ID);
+pub const GLOBAL_KEYOF_T_ID: ResolvedTypeId = ResolvedTypeId::new(GLOBAL_LEVEL, KEYOF_T_ID);
+pub const GLOBAL_T_INDEXED_BY_P_ID: ResolvedTypeId =
+    ResolvedTypeId::new(GLOBAL_LEVEL, T_INDEXED_BY_P_ID);
+pub const GLOBAL_FUNCTION_RETURNING_INFER_R_ID: ResolvedTypeId =
+    ResolvedTypeId::new(GLOBAL_LEVEL, FUNCTION_RETURNING_INFER_R_ID);
+pub const GLOBAL_RETURN_TYPE_CONDITIONAL_ID: ResolvedTypeId =
+    ResolvedTypeId::new(GLOBAL_LEVEL, RETURN_TYPE_CONDITIONAL_ID);
+pub const GLOBAL_RETURN_TYPE_ID: ResolvedTypeId = ResolvedTypeId::new(GLOBAL_LEVEL, RETURN_TYPE_ID);
+pub const GLOBAL_PROMISE_OF_INFER_U_ID: ResolvedTypeId =
+    ResolvedTypeId::new(GLOBAL_LEVEL, PROMISE_OF_INFER_U_ID);
+pub const GLOBAL_AWAITED_OF_U_ID: ResolvedTypeId =
+    ResolvedTypeId::new(GLOBAL_LEVEL, AWAITED_OF_U_ID);
+pub const GLOBAL_AWAITED_CONDITIONAL_ID: ResolvedTypeId =
+    ResolvedTypeId::new(GLOBAL_LEVEL, AWAITED_CONDITIONAL_ID);
+pub const GLOBAL_AWAITED_ID: ResolvedTypeId = ResolvedTypeId::new(GLOBAL_LEVEL, AWAITED_ID);
+pub const GLOBAL_PARTIAL_MAPPED_ID: ResolvedTypeId =
+    ResolvedTypeId::new(GLOBAL_LEVEL, PARTIAL_MAPPED_ID);
+pub const GLOBAL_PARTIAL_ID: ResolvedTypeId = ResolvedTypeId::new(GLOBAL_LEVEL, PARTIAL_ID);
+pub const GLOBAL_REQUIRED_MAPPED_ID: ResolvedTypeId =
+    ResolvedTypeId::new(GLOBAL_LEVEL, REQUIRED_MAPPED_ID);
+pub const GLOBAL_REQUIRED_ID: ResolvedTypeId = ResolvedTypeId::new(GLOBAL_LEVEL, REQUIRED_ID);
+pub const GLOBAL_READONLY_MAPPED_ID: ResolvedTypeId =
+    ResolvedTypeId::new(GLOBAL_LEVEL, READONLY_MAPPED_ID);
+pub const GLOBAL_READONLY_ID: ResolvedTypeId = ResolvedTypeId::new(GLOBAL_LEVEL, READONLY_ID);
 
 /// Returns a string for formatting global IDs in test snapshots.
 pub fn global_type_name(id: TypeId) -> &'static str {
@@ -121,6 +173,25 @@ pub fn global_type_name(id: TypeId) -> &'static str {
         }
         26 => "string",
         27 => "T",
+        28 => "any",
+        29 => "P",
+        30 => "infer R",
+        31 => "infer U",
+        32 => "keyof T",
+        33 => "T[P]",
+        34 => "(...args: any) => infer R",
+        35 => "T extends (...args: any) => infer R ? R : any",
+        36 => "ReturnType",
+        37 => "Promise<infer U>",
+        38 => "Awaited<U>",
+        39 => "T extends Promise<infer U> ? Awaited<U> : T",
+        40 => "Awaited",
+        41 => "{ [P in keyof T]?: T[P] }",
+        42 => "Partial",
+        43 => "{ [P in keyof T]-?: T[P] }",
+        44 => "Required",
+        45 => "{ readonly [P in keyof T]: T[P] }",
+        46 => "Readonly",
         _ => "inferred type",
     }
 }
@@ -163,6 +234,30 @@ impl Default for GlobalsResolver {
             TypeData::from(Literal::String(Text::Static(value).into()))
         };
 
+        let generic = |name: &'static str| GenericTypeParameter {
+            name: Text::Static(name),
+            constraint: TypeReference::Unknown,
+            default: TypeReference::Unknown,
+        };
+
+        let mapped_over_keys_of_t = |optional_modifier, readonly_modifier| {
+            TypeData::from(MappedType {
+                key_name: Text::Static("P"),
+                keys_type: GLOBAL_KEYOF_T_ID.into(),
+                as_type: None,
+                property_type: GLOBAL_T_INDEXED_BY_P_ID.into(),
+                optional_modifier,
+                readonly_modifier,
+            })
+        };
+
+        let type_alias_of_t = |id: ResolvedTypeId| {
+            TypeData::instance_of(TypeInstance {
+                ty: id.into(),
+                type_parameters: Box::new([GLOBAL_T_ID.into()]),
+            })
+        };
+
         let types = vec![
             TypeData::Unknown,
             TypeData::Undefined,
@@ -227,11 +322,62 @@ impl Default for GlobalsResolver {
                 GLOBAL_UNDEFINED_STRING_LITERAL_ID.into(),
             ]),
             TypeData::String,
-            TypeData::from(GenericTypeParameter {
-                name: Text::Static("T"),
-                constraint: TypeReference::Unknown,
-                default: TypeReference::Unknown,
+            TypeData::from(generic("T")),
+            TypeData::AnyKeyword,
+            TypeData::from(generic("P")),
+            TypeData::infer(generic("R")),
+            TypeData::infer(generic("U")),
+            TypeData::TypeOperator(Box::new(TypeOperatorType {
+                operator: TypeOperator::Keyof,
+                ty: GLOBAL_T_ID.into(),
+            })),
+            TypeData::from(IndexedAccessType {
+                object_type: GLOBAL_T_ID.into(),
+                index_type: GLOBAL_P_ID.into(),
+            }),
+            TypeData::from(Function {
+                is_async: false,
+                type_parameters: Default::default(),
+                name: None,
+                parameters: Box::new([FunctionParameter {
+                    name: Some(Text::Static("args")),
+                    ty: GLOBAL_ANY_ID.into(),
+                    bindings: Default::default(),
+                    is_optional: false,
+                    is_rest: true,
+                }]),
+                return_type: ReturnType::Type(GLOBAL_INFER_R_ID.into()),
+            }),
+            TypeData::from(ConditionalType {
+                check_type: GLOBAL_T_ID.into(),
+                extends_type: GLOBAL_FUNCTION_RETURNING_INFER_R_ID.into(),
+                true_type: GLOBAL_INFER_R_ID.into(),
+                false_type: GLOBAL_ANY_ID.into(),
+                is_distributive: true,
+            }),
+            type_alias_of_t(GLOBAL_RETURN_TYPE_CONDITIONAL_ID),
+            TypeData::instance_of(TypeInstance {
+                ty: GLOBAL_PROMISE_ID.into(),
+                type_parameters: Box::new([GLOBAL_INFER_U_ID.into()]),
+            }),
+            TypeData::instance_of(TypeInstance {
+                ty: GLOBAL_AWAITED_ID.into(),
+                type_parameters: Box::new([GLOBAL_INFER_U_ID.into()]),
+            }),
+            TypeData::from(ConditionalType {
+                check_type: GLOBAL_T_ID.into(),
+                extends_type: GLOBAL_PROMISE_OF_INFER_U_ID.into(),
+                true_type: GLOBAL_AWAITED_OF_U_ID.into(),
+                false_type: GLOBAL_T_ID.into(),
+                is_distributive: true,
             }),
+            type_alias_of_t(GLOBAL_AWAITED_CONDITIONAL_ID),
+            mapped_over_keys_of_t(Some(MappedTypeModifier::Add), None),
+            type_alias_of_t(GLOBAL_PARTIAL_MAPPED_ID),
+            mapped_over_keys_of_t(Some(MappedTypeModifier::Remove), None),
+            type_alias_of_t(GLOBAL_REQUIRED_MAPPED_ID),
+            mapped_over_keys_of_t(None, Some(MappedTypeModifier::Add)),
+            type_alias_of_t(GLOBAL_READONLY_MAPPED_ID),
         ];
 
         Self { types }
@@ -318,6 +464,8 @@ impl TypeResolver for GlobalsResolver {
             Some(GLOBAL_ARRAY_ID)
         } else if qualifier.is_promise() && !qualifier.has_known_type_parameters() {
             Some(GLOBAL_PROMISE_ID)
+        } else if let Some(id) = resolve_utility_type(qualifier) {
+            Some(id)
         } else if !qualifier.type_only && qualifier.path.len() == 1 {
             self.resolve_type_of(&qualifier.path[0], qualifier.scope_id)
         } else {
@@ -340,3 +488,22 @@ impl TypeResolver for GlobalsResolver {
         &self.types[NUM_PREDEFINED_TYPES..]
     }
 }
+
+/// Resolves the utility types for which we have hardcoded definitions.
+///
+/// Like `Array` and `Promise`, these only resolve without type parameters, so
+/// that the resolver creates an instantiation for them.
+fn resolve_utility_type(qualifier: &TypeReferenceQualifier) -> Option<ResolvedTypeId> {
+    if qualifier.path.len() != 1 || qualifier.has_known_type_parameters() {
+        return None;
+    }
+
+    match qualifier.path[0].text() {
+        "Awaited" => Some(GLOBAL_AWAITED_ID),
+        "Partial" => Some(GLOBAL_PARTIAL_ID),
+        "Readonly" => Some(GLOBAL_READONLY_ID),
+        "Required" => Some(GLOBAL_REQUIRED_ID),
+        "ReturnType" => Some(GLOBAL_RETURN_TYPE_ID),
+        _ => None,
+    }
+}
@@ -77,7 +77,7 @@ impl<'a> ResolvedTypeData<'a> {
                 break;
             };
 
-            if next_id == id {
+            if next_id == id || resolver.is_declaration_of_global(next_id, id) {
                 return true;
             }
 
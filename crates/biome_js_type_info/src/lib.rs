@@ -5,11 +5,16 @@ mod format_type_info;
 mod globals;
 mod helpers;
 mod local_inference;
+mod narrowing;
 mod resolver;
 mod type_info;
 mod type_store;
 
-pub use globals::{GLOBAL_RESOLVER, GLOBAL_UNKNOWN_ID, GlobalsResolver, NUM_PREDEFINED_TYPES};
+pub use globals::{
+    GLOBAL_ARRAY_ID, GLOBAL_PROMISE_ID, GLOBAL_RESOLVER, GLOBAL_UNKNOWN_ID, GlobalsResolver,
+    NUM_PREDEFINED_TYPES,
+};
+pub use narrowing::{TypeGuard, TypeGuardKind};
 pub use resolver::*;
 pub use type_info::*;
 pub use type_store::*;
@@ -13,20 +13,22 @@ use biome_js_syntax::{
     JsClassExpression, JsFormalParameter, JsFunctionDeclaration, JsFunctionExpression,
     JsNewExpression, JsObjectBindingPattern, JsObjectExpression, JsParameters,
     JsReferenceIdentifier, JsSyntaxToken, JsUnaryExpression, JsUnaryOperator,
-    JsVariableDeclaration, JsVariableDeclarator, TsDeclareFunctionDeclaration,
-    TsExternalModuleDeclaration, TsInterfaceDeclaration, TsModuleDeclaration, TsReferenceType,
-    TsReturnTypeAnnotation, TsTypeAliasDeclaration, TsTypeAnnotation, TsTypeArguments, TsTypeList,
-    TsTypeParameter, TsTypeParameters, TsTypeofType, inner_string_text, unescape_js_string,
+    JsVariableDeclaration, JsVariableDeclarator, TsConditionalType, TsDeclareFunctionDeclaration,
+    TsExternalModuleDeclaration, TsImportType, TsInferType, TsInterfaceDeclaration, TsMappedType,
+    TsModuleDeclaration, TsReferenceType, TsReturnTypeAnnotation, TsTypeAliasDeclaration,
+    TsTypeAnnotation, TsTypeArguments, TsTypeList, TsTypeParameter, TsTypeParameters, TsTypeofType,
+    inner_string_text, unescape_js_string,
 };
 use biome_rowan::{AstNode, SyntaxResult, Text, TokenText};
 
 use crate::globals::{GLOBAL_INSTANCEOF_PROMISE_ID, GLOBAL_NUMBER_ID, GLOBAL_STRING_ID};
 use crate::literal::{BooleanLiteral, NumberLiteral, StringLiteral};
 use crate::{
-    AssertsReturnType, CallArgumentType, Class, Constructor, DestructureField, Function,
-    FunctionParameter, FunctionParameterBinding, GenericTypeParameter, Interface, Literal, Module,
-    Namespace, Object, PredicateReturnType, ResolvedTypeId, ReturnType, ScopeId, Tuple,
-    TupleElementType, TypeData, TypeInstance, TypeMember, TypeMemberKind, TypeOperator,
+    AssertsReturnType, CallArgumentType, Class, ConditionalType, Constructor, DestructureField,
+    Function, FunctionParameter, FunctionParameterBinding, GenericTypeParameter, ImportSymbol,
+    IndexedAccessType, Interface, Literal, MappedType, MappedTypeModifier, Module, Namespace,
+    Object, PredicateReturnType, ResolvedTypeId, ReturnType, ScopeId, Tuple, TupleElementType,
+    TypeData, TypeImportQualifier, TypeInstance, TypeMember, TypeMemberKind, TypeOperator,
     TypeOperatorType, TypeReference, TypeReferenceQualifier, TypeResolver,
     TypeofBitwiseNotExpression, TypeofCallExpression, TypeofDestructureExpression,
     TypeofExpression, TypeofNewExpression, TypeofStaticMemberExpression,
@@ -608,9 +610,8 @@ impl TypeData {
                 Err(_) => Self::Unknown,
             },
             AnyTsType::TsBooleanType(_) => Self::Boolean,
-            AnyTsType::TsConditionalType(_) => {
-                // TODO: Handle conditional types (`T extends U ? V : W`).
-                Self::Unknown
+            AnyTsType::TsConditionalType(ty) => {
+                Self::from_ts_conditional_type(resolver, scope_id, ty).unwrap_or_default()
             }
             AnyTsType::TsConstructorType(ty) => Self::Constructor(Box::new(Constructor {
                 type_parameters: generic_params_from_ts_type_params(
@@ -639,17 +640,19 @@ impl TypeData {
                     .and_then(|ty| ReturnType::from_any_ts_return_type(resolver, scope_id, &ty))
                     .unwrap_or_default(),
             })),
-            AnyTsType::TsImportType(_) => {
-                // TODO: Handle import types (`import("./module").T`).
-                Self::Unknown
+            AnyTsType::TsImportType(ty) => {
+                Self::from_ts_import_type(resolver, scope_id, ty).unwrap_or_default()
             }
-            AnyTsType::TsIndexedAccessType(_) => {
-                // TODO: Handle type indexing (`T[U]`).
-                Self::Unknown
-            }
-            AnyTsType::TsInferType(_) => {
-                // TODO: Handle `infer T` syntax.
-                Self::Unknown
+            AnyTsType::TsIndexedAccessType(ty) => match (ty.object_type(), ty.index_type()) {
+                (Ok(object_type), Ok(index_type)) => Self::from(IndexedAccessType {
+                    object_type: TypeReference::from_any_ts_type(resolver, scope_id, &object_type),
+                    index_type: TypeReference::from_any_ts_type(resolver, scope_id, &index_type),
+                }),
+                _ => Self::Unknown,
+            },
+            AnyTsType::TsInferType(ty) => {
+                GenericTypeParameter::from_ts_infer_type(resolver, scope_id, ty)
+                    .map_or(Self::Unknown, Self::infer)
             }
             AnyTsType::TsIntersectionType(ty) => Self::intersection_of(
                 ty.types()
@@ -658,9 +661,8 @@ impl TypeData {
                     .map(|ty| TypeReference::from_any_ts_type(resolver, scope_id, &ty))
                     .collect(),
             ),
-            AnyTsType::TsMappedType(_) => {
-                // TODO: Handle mapped types (`type T<U> = { [K in keyof U]: V }`).
-                Self::Unknown
+            AnyTsType::TsMappedType(ty) => {
+                Self::from_ts_mapped_type(resolver, scope_id, ty).unwrap_or_default()
             }
             AnyTsType::TsNeverType(_) => Self::NeverKeyword,
             AnyTsType::TsNonPrimitiveType(_) => Self::ObjectKeyword,
@@ -1154,6 +1156,109 @@ impl TypeData {
         Some(namespace.into())
     }
 
+    pub fn from_ts_conditional_type(
+        resolver: &mut dyn TypeResolver,
+        scope_id: ScopeId,
+        ty: &TsConditionalType,
+    ) -> Option<Self> {
+        let check_type = ty.check_type().ok()?;
+        let is_distributive = check_type.as_ts_reference_type().is_some_and(|reference| {
+            reference.type_arguments().is_none()
+                && reference
+                    .name()
+                    .is_ok_and(|name| name.as_js_reference_identifier().is_some())
+        });
+
+        Some(Self::from(ConditionalType {
+            check_type: TypeReference::from_any_ts_type(resolver, scope_id, &check_type),
+            extends_type: TypeReference::from_any_ts_type(
+                resolver,
+                scope_id,
+                &ty.extends_type().ok()?,
+            ),
+            true_type: TypeReference::from_any_ts_type(resolver, scope_id, &ty.true_type().ok()?),
+            false_type: TypeReference::from_any_ts_type(resolver, scope_id, &ty.false_type().ok()?),
+            is_distributive,
+        }))
+    }
+
+    pub fn from_ts_import_type(
+        resolver: &mut dyn TypeResolver,
+        scope_id: ScopeId,
+        ty: &TsImportType,
+    ) -> Option<Self> {
+        let argument = ty.arguments().ok()?.argument().ok()?;
+        let specifier = argument
+            .as_ts_string_literal_type()?
+            .inner_string_text()
+            .ok()?;
+        let resolved_path = resolver.resolve_import_specifier(specifier.text())?;
+
+        let symbol = match ty.qualifier_clause() {
+            Some(qualifier) => match qualifier.right().ok()? {
+                AnyTsName::JsReferenceIdentifier(identifier) => {
+                    ImportSymbol::Named(text_from_token(identifier.value_token())?)
+                }
+                // TODO: Handle qualified names (`import("./module").A.B`).
+                AnyTsName::TsQualifiedName(_) => return None,
+            },
+            None => ImportSymbol::All,
+        };
+
+        let is_typeof = ty.typeof_token().is_some();
+        let reference = TypeReference::from(TypeImportQualifier {
+            symbol,
+            resolved_path,
+            type_only: !is_typeof,
+        });
+
+        Some(if is_typeof {
+            Self::reference(reference)
+        } else {
+            Self::instance_of(TypeInstance {
+                ty: reference,
+                type_parameters: TypeReference::types_from_ts_type_arguments(
+                    resolver,
+                    scope_id,
+                    ty.type_arguments(),
+                ),
+            })
+        })
+    }
+
+    pub fn from_ts_mapped_type(
+        resolver: &mut dyn TypeResolver,
+        scope_id: ScopeId,
+        ty: &TsMappedType,
+    ) -> Option<Self> {
+        let modifier_from_operator = |operator: Option<JsSyntaxToken>| match operator {
+            Some(operator) if operator.text_trimmed() == "-" => MappedTypeModifier::Remove,
+            _ => MappedTypeModifier::Add,
+        };
+
+        Some(Self::from(MappedType {
+            key_name: text_from_token(ty.property_name().ok()?.ident_token())?,
+            keys_type: TypeReference::from_any_ts_type(resolver, scope_id, &ty.keys_type().ok()?),
+            as_type: ty
+                .as_clause()
+                .and_then(|clause| clause.ty().ok())
+                .map(|as_type| TypeReference::from_any_ts_type(resolver, scope_id, &as_type)),
+            property_type: ty
+                .mapped_type()
+                .and_then(|annotation| annotation.ty().ok())
+                .map(|property_ty| {
+                    TypeReference::from_any_ts_type(resolver, scope_id, &property_ty)
+                })
+                .unwrap_or_default(),
+            optional_modifier: ty
+                .optional_modifier()
+                .map(|modifier| modifier_from_operator(modifier.operator_token())),
+            readonly_modifier: ty
+                .readonly_modifier()
+                .map(|modifier| modifier_from_operator(modifier.operator_token())),
+        }))
+    }
+
     pub fn from_ts_reference_type(
         resolver: &mut dyn TypeResolver,
         scope_id: ScopeId,
@@ -1493,6 +1598,38 @@ impl GenericTypeParameter {
             .ok()
     }
 
+    pub fn from_ts_infer_type(
+        resolver: &mut dyn TypeResolver,
+        scope_id: ScopeId,
+        ty: &TsInferType,
+    ) -> Option<Self> {
+        Some(Self {
+            name: text_from_token(ty.name().ok()?.ident_token())?,
+            constraint: ty
+                .constraint()
+                .and_then(|constraint| constraint.ty().ok())
+                .map(|constraint_ty| {
+                    TypeReference::from_any_ts_type(resolver, scope_id, &constraint_ty)
+                })
+                .unwrap_or_default(),
+            default: TypeReference::Unknown,
+        })
+    }
+
+    /// Creates the type parameter that iterates over the keys of a mapped
+    /// type.
+    pub fn from_ts_mapped_type(
+        resolver: &mut dyn TypeResolver,
+        scope_id: ScopeId,
+        ty: &TsMappedType,
+    ) -> Option<Self> {
+        Some(Self {
+            name: text_from_token(ty.property_name().ok()?.ident_token())?,
+            constraint: TypeReference::from_any_ts_type(resolver, scope_id, &ty.keys_type().ok()?),
+            default: TypeReference::Unknown,
+        })
+    }
+
     pub fn params_from_ts_type_parameters(
         resolver: &mut dyn TypeResolver,
         scope_id: ScopeId,
@@ -0,0 +1,497 @@
+//! Narrowing of types through type guards.
+//!
+//! Type guards are conditions such as `typeof value === "string"` or
+//! `value instanceof Date` that are known to either hold or not hold at a
+//! given point in the program. Narrowing applies such guards to the declared
+//! type of a value, so that consumers can see a more precise type than the
+//! one the value was declared with.
+
+use biome_rowan::Text;
+
+use crate::{
+    Literal, ResolvedTypeData, ResolvedTypeId, TypeData, TypeReference, TypeResolver,
+    flattening::extends_type,
+};
+
+/// A type guard that is known to either hold or not hold for a value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TypeGuard {
+    pub kind: TypeGuardKind,
+
+    /// Whether the guard is known to hold.
+    ///
+    /// If `false`, the guard is known **not** to hold, such as in the `else`
+    /// branch of an `if` statement that tested it.
+    pub holds: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TypeGuardKind {
+    /// The value is tested for truthiness, such as in `if (value)`.
+    Truthy,
+
+    /// The value is tested using strict equality, such as in `value === "a"`.
+    ///
+    /// References the type of the value it is compared against.
+    Equals(TypeReference),
+
+    /// The value is tested against `null` using loose equality, such as in
+    /// `value == null`, which matches both `null` and `undefined`.
+    Nullish,
+
+    /// A member of the value is tested using strict equality, such as in
+    /// `value.kind === "a"`.
+    MemberEquals { member: Text, value: TypeReference },
+
+    /// The value is tested to be an instance of a class, such as in
+    /// `value instanceof Date`.
+    ///
+    /// References the type of the class.
+    InstanceOf(TypeReference),
+
+    /// The value is tested to have a member, such as in `"kind" in value`.
+    HasMember(Text),
+
+    /// The value is tested using the `typeof` operator, such as in
+    /// `typeof value === "string"`.
+    Typeof(Text),
+
+    /// The value is tested using a user-defined type guard, such as in
+    /// `isString(value)` where `isString()` returns `value is string`.
+    ///
+    /// References the type asserted by the predicate.
+    Predicate(TypeReference),
+}
+
+impl TypeGuard {
+    pub fn new(kind: TypeGuardKind, holds: bool) -> Self {
+        Self { kind, holds }
+    }
+
+    /// Returns the type with the given `id` after this guard has been applied.
+    ///
+    /// Returns `None` if the guard doesn't affect the type.
+    pub fn narrow(
+        &self,
+        id: ResolvedTypeId,
+        resolver: &mut dyn TypeResolver,
+    ) -> Option<ResolvedTypeId> {
+        let variants = union_variants(id, resolver).unwrap_or_else(|| vec![id.into()]);
+
+        let mut kept = Vec::with_capacity(variants.len());
+        let mut has_definite_match = false;
+        for variant in &variants {
+            let matches = self.kind.matches(variant, resolver);
+            has_definite_match |= matches == Some(true);
+            if matches.is_none_or(|matches| matches == self.holds) {
+                kept.push(variant.clone());
+            }
+        }
+
+        if self.holds && !has_definite_match && !kept.is_empty() {
+            if let Some(refined) = self.kind.refined_type(resolver) {
+                return Some(refined);
+            }
+        }
+
+        if kept.len() == variants.len() {
+            return None;
+        }
+
+        match kept.len() {
+            0 => Some(resolver.register_and_resolve(TypeData::NeverKeyword)),
+            1 => resolver.resolve_reference(&kept[0]),
+            _ => Some(resolver.register_and_resolve(TypeData::union_of(kept))),
+        }
+    }
+}
+
+impl TypeGuardKind {
+    /// Returns `Some(true)` if a value of the given `ty` is known to pass this
+    /// guard, `Some(false)` if it is known to fail it, and `None` if it may do
+    /// either.
+    fn matches(&self, ty: &TypeReference, resolver: &mut dyn TypeResolver) -> Option<bool> {
+        let data = resolver.resolve_and_get(ty)?.to_data();
+        let data = dereferenced_data(data, resolver);
+        if is_indeterminate(&data) {
+            return None;
+        }
+
+        match self {
+            Self::Truthy => is_truthy(&data),
+            Self::Equals(value) => {
+                let value = resolver.resolve_and_get(value)?.to_data();
+                equals(&data, &dereferenced_data(value, resolver))
+            }
+            Self::Nullish => Some(is_null(&data) || is_undefined(&data)),
+            Self::MemberEquals { member, value } => {
+                let member_ty = member_type(ty, member, resolver)?;
+                let member_data = resolver.resolve_and_get(&member_ty)?.to_data();
+                let value = resolver.resolve_and_get(value)?.to_data();
+                equals(
+                    &dereferenced_data(member_data, resolver),
+                    &dereferenced_data(value, resolver),
+                )
+            }
+            Self::InstanceOf(class) => {
+                let class_id = dereferenced_id(class, resolver)?;
+                if typeof_name(&data).is_some_and(|name| name != "object" && name != "function") {
+                    return Some(false);
+                }
+
+                is_instance_of(ty, class_id, resolver).then_some(true)
+            }
+            Self::HasMember(name) => match data {
+                TypeData::Class(_)
+                | TypeData::Interface(_)
+                | TypeData::Object(_)
+                | TypeData::InstanceOf(_) => Some(member_type(ty, name, resolver).is_some()),
+                _ => None,
+            },
+            Self::Typeof(name) => typeof_name(&data)
+                .or_else(|| instance_typeof_name(&data, resolver))
+                .map(|type_name| type_name == name.text()),
+            Self::Predicate(target) => {
+                let target = resolver.resolve_and_get(target)?.to_data();
+                if extends_type(&data, &target, &mut Vec::new(), resolver, 0)? {
+                    Some(true)
+                } else if extends_type(&target, &data, &mut Vec::new(), resolver, 0)? {
+                    None
+                } else {
+                    Some(false)
+                }
+            }
+        }
+    }
+
+    /// Returns a more precise type for a value that is known to pass this
+    /// guard, if the guard itself implies one.
+    ///
+    /// This is used when none of the variants of the value's type is known to
+    /// pass the guard, such as when testing a value of type `unknown`.
+    fn refined_type(&self, resolver: &mut dyn TypeResolver) -> Option<ResolvedTypeId> {
+        match self {
+            Self::Equals(value) => {
+                let id = resolver.resolve_reference(value)?;
+                let data = resolver.get_by_resolved_id(id)?.to_data();
+                is_unit(&data).then_some(id)
+            }
+            Self::InstanceOf(class) => {
+                let class = dereferenced_id(class, resolver)?;
+                Some(
+                    resolver
+                        .register_and_resolve(TypeData::instance_of(TypeReference::from(class))),
+                )
+            }
+            Self::Typeof(name) => {
+                let data = match name.text() {
+                    "bigint" => TypeData::BigInt,
+                    "boolean" => TypeData::Boolean,
+                    "number" => TypeData::Number,
+                    "string" => TypeData::String,
+                    "symbol" => TypeData::Symbol,
+                    "undefined" => TypeData::Undefined,
+                    _ => return None,
+                };
+                Some(resolver.register_and_resolve(data))
+            }
+            Self::Predicate(target) => resolver.resolve_reference(target),
+            Self::Truthy | Self::Nullish | Self::MemberEquals { .. } | Self::HasMember(_) => None,
+        }
+    }
+}
+
+/// Resolves the given `reference`, following any plain references it leads to.
+fn dereferenced_id(
+    reference: &TypeReference,
+    resolver: &dyn TypeResolver,
+) -> Option<ResolvedTypeId> {
+    const MAX_DEREFERENCE_DEPTH: usize = 10; // Arbitrary depth, may require tweaking.
+
+    let mut id = resolver.resolve_reference(reference)?;
+    for _ in 0..MAX_DEREFERENCE_DEPTH {
+        let resolved = resolver.get_by_resolved_id(id)?;
+        let TypeData::Reference(reference) = resolved.as_raw_data() else {
+            break;
+        };
+
+        let reference = resolved.apply_module_id_to_reference(reference);
+        id = resolver.resolve_reference(&reference)?;
+    }
+
+    Some(id)
+}
+
+/// Returns the variants of the union referenced by `id`, following any plain
+/// references and type aliases that lead to it.
+fn union_variants(id: ResolvedTypeId, resolver: &dyn TypeResolver) -> Option<Vec<TypeReference>> {
+    const MAX_DEREFERENCE_DEPTH: usize = 10; // Arbitrary depth, may require tweaking.
+
+    let mut resolved = resolver.get_by_resolved_id(id)?;
+    for _ in 0..MAX_DEREFERENCE_DEPTH {
+        let reference = match resolved.as_raw_data() {
+            TypeData::Union(union) => {
+                return Some(
+                    union
+                        .types()
+                        .iter()
+                        .map(|ty| resolved.apply_module_id_to_reference(ty).into_owned())
+                        .collect(),
+                );
+            }
+            TypeData::InstanceOf(instance) if instance.type_parameters.is_empty() => &instance.ty,
+            TypeData::Reference(reference) => reference,
+            _ => return None,
+        };
+        let reference = resolved
+            .apply_module_id_to_reference(reference)
+            .into_owned();
+        resolved = resolver.resolve_and_get(&reference)?;
+    }
+
+    None
+}
+
+/// Follows instances of primitive types, as well as plain references, so that
+/// the data can be classified.
+fn dereferenced_data(data: TypeData, resolver: &mut dyn TypeResolver) -> TypeData {
+    let reference = match &data {
+        TypeData::InstanceOf(instance) if instance.type_parameters.is_empty() => &instance.ty,
+        TypeData::Reference(reference) => reference,
+        _ => return data,
+    };
+
+    match resolver
+        .resolve_and_get(reference)
+        .map(ResolvedTypeData::to_data)
+    {
+        Some(
+            resolved @ (TypeData::BigInt
+            | TypeData::Boolean
+            | TypeData::Null
+            | TypeData::Number
+            | TypeData::String
+            | TypeData::Symbol
+            | TypeData::Undefined
+            | TypeData::Literal(_)),
+        ) => resolved,
+        _ => data,
+    }
+}
+
+/// Returns whether the data is such that no guard could tell anything about
+/// it.
+fn is_indeterminate(data: &TypeData) -> bool {
+    matches!(
+        data,
+        TypeData::Unknown
+            | TypeData::AnyKeyword
+            | TypeData::UnknownKeyword
+            | TypeData::Generic(_)
+            | TypeData::Infer(_)
+            | TypeData::ThisKeyword
+    )
+}
+
+/// Returns whether the data describes a single value, such that a value that
+/// compares equal to it must be of the same type.
+fn is_unit(data: &TypeData) -> bool {
+    match data {
+        TypeData::Null | TypeData::Undefined => true,
+        TypeData::Literal(literal) => matches!(
+            literal.as_ref(),
+            Literal::BigInt(_)
+                | Literal::Boolean(_)
+                | Literal::Null
+                | Literal::Number(_)
+                | Literal::String(_)
+        ),
+        _ => false,
+    }
+}
+
+fn equals(data: &TypeData, value: &TypeData) -> Option<bool> {
+    if !is_unit(value) {
+        return None;
+    }
+
+    match (data, value) {
+        (TypeData::Literal(literal), TypeData::Literal(value)) => {
+            match (literal.as_ref(), value.as_ref()) {
+                (Literal::Number(literal), Literal::Number(value)) => {
+                    match (literal.to_f64(), value.to_f64()) {
+                        (Some(literal), Some(value)) => Some(literal == value),
+                        _ => None,
+                    }
+                }
+                (Literal::Template(_), _) => None,
+                (literal, value) => Some(literal == value),
+            }
+        }
+        (TypeData::Literal(_), TypeData::Null) | (TypeData::Null, _) => {
+            Some(is_null(data) && is_null(value))
+        }
+        (TypeData::Undefined | TypeData::VoidKeyword, _) => Some(is_undefined(value)),
+        (TypeData::BigInt, TypeData::Literal(literal)) => {
+            (!matches!(literal.as_ref(), Literal::BigInt(_))).then_some(false)
+        }
+        (TypeData::Boolean, TypeData::Literal(literal)) => {
+            (!matches!(literal.as_ref(), Literal::Boolean(_))).then_some(false)
+        }
+        (TypeData::Number, TypeData::Literal(literal)) => {
+            (!matches!(literal.as_ref(), Literal::Number(_))).then_some(false)
+        }
+        (TypeData::String, TypeData::Literal(literal)) => {
+            (!matches!(literal.as_ref(), Literal::String(_))).then_some(false)
+        }
+        (
+            TypeData::Literal(_)
+            | TypeData::BigInt
+            | TypeData::Boolean
+            | TypeData::Number
+            | TypeData::String
+            | TypeData::Symbol,
+            _,
+        ) => Some(false),
+        (
+            TypeData::Class(_)
+            | TypeData::Constructor(_)
+            | TypeData::Function(_)
+            | TypeData::Interface(_)
+            | TypeData::Object(_)
+            | TypeData::Tuple(_)
+            | TypeData::InstanceOf(_),
+            _,
+        ) => Some(false),
+        _ => None,
+    }
+}
+
+fn is_null(data: &TypeData) -> bool {
+    match data {
+        TypeData::Null => true,
+        TypeData::Literal(literal) => matches!(literal.as_ref(), Literal::Null),
+        _ => false,
+    }
+}
+
+fn is_undefined(data: &TypeData) -> bool {
+    matches!(data, TypeData::Undefined | TypeData::VoidKeyword)
+}
+
+fn is_truthy(data: &TypeData) -> Option<bool> {
+    match data {
+        TypeData::Null | TypeData::Undefined | TypeData::VoidKeyword => Some(false),
+        TypeData::Literal(literal) => match literal.as_ref() {
+            Literal::BigInt(text) => Some(text.trim_end_matches('n') != "0"),
+            Literal::Boolean(boolean) => Some(boolean.as_bool()),
+            Literal::Null => Some(false),
+            Literal::Number(number) => number
+                .to_f64()
+                .map(|number| number != 0.0 && !number.is_nan()),
+            Literal::Object(_) | Literal::RegExp(_) => Some(true),
+            Literal::String(string) => Some(!string.as_str().is_empty()),
+            Literal::Template(_) => None,
+        },
+        TypeData::Class(_)
+        | TypeData::Constructor(_)
+        | TypeData::Function(_)
+        | TypeData::Interface(_)
+        | TypeData::Object(_)
+        | TypeData::Tuple(_)
+        | TypeData::InstanceOf(_)
+        | TypeData::Symbol
+        | TypeData::ObjectKeyword => Some(true),
+        _ => None,
+    }
+}
+
+/// Returns the result of the `typeof` operator for a value of the given type.
+fn typeof_name(data: &TypeData) -> Option<&'static str> {
+    match data {
+        TypeData::BigInt => Some("bigint"),
+        TypeData::Boolean => Some("boolean"),
+        TypeData::Null => Some("object"),
+        TypeData::Number => Some("number"),
+        TypeData::String => Some("string"),
+        TypeData::Symbol => Some("symbol"),
+        TypeData::Undefined | TypeData::VoidKeyword => Some("undefined"),
+        TypeData::Class(_) | TypeData::Constructor(_) | TypeData::Function(_) => Some("function"),
+        TypeData::Object(_) | TypeData::Tuple(_) | TypeData::ObjectKeyword => Some("object"),
+        TypeData::Literal(literal) => match literal.as_ref() {
+            Literal::BigInt(_) => Some("bigint"),
+            Literal::Boolean(_) => Some("boolean"),
+            Literal::Null | Literal::Object(_) | Literal::RegExp(_) => Some("object"),
+            Literal::Number(_) => Some("number"),
+            Literal::String(_) | Literal::Template(_) => Some("string"),
+        },
+        _ => None,
+    }
+}
+
+/// Returns whether the type referenced by `ty` is an instance of the class
+/// with the given `class_id`.
+///
+/// Besides comparing IDs, this also compares the classes themselves, since
+/// the class may have been copied into the scope it is referenced from.
+fn is_instance_of(
+    ty: &TypeReference,
+    class_id: ResolvedTypeId,
+    resolver: &dyn TypeResolver,
+) -> bool {
+    let Some(class) = resolver.get_by_resolved_id(class_id) else {
+        return false;
+    };
+
+    let mut seen_types = Vec::new();
+    let mut current = resolver.resolve_and_get(ty);
+    while let Some(resolved) = current {
+        let Some(next_id) = resolved
+            .prototype(resolver)
+            .and_then(|prototype| resolver.resolve_reference(&prototype))
+        else {
+            break;
+        };
+        if seen_types.contains(&next_id) {
+            break;
+        }
+
+        current = resolver.get_by_resolved_id(next_id);
+        if next_id == class_id
+            || current.is_some_and(|next| next.as_raw_data() == class.as_raw_data())
+        {
+            return true;
+        }
+
+        seen_types.push(next_id);
+    }
+
+    false
+}
+
+/// Returns the result of `typeof` for instances of classes and interfaces,
+/// which can only be objects.
+fn instance_typeof_name(data: &TypeData, resolver: &dyn TypeResolver) -> Option<&'static str> {
+    let TypeData::InstanceOf(instance) = data else {
+        return None;
+    };
+
+    match resolver.resolve_and_get(&instance.ty)?.as_raw_data() {
+        TypeData::Class(_) | TypeData::Interface(_) | TypeData::Object(_) => Some("object"),
+        _ => None,
+    }
+}
+
+/// Returns the type of the member with the given `name`, if the type
+/// referenced by `ty` has one.
+fn member_type(
+    ty: &TypeReference,
+    name: &Text,
+    resolver: &mut dyn TypeResolver,
+) -> Option<TypeReference> {
+    let resolved = resolver.resolve_and_get(ty)?;
+    let member = resolved
+        .all_members(resolver)
+        .find(|member| !member.is_static() && member.has_name(name.text()))?;
+    Some(member.ty().into_owned())
+}
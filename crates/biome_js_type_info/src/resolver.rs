@@ -1,6 +1,7 @@
 use std::{borrow::Cow, fmt::Debug};
 
 use biome_js_syntax::AnyJsExpression;
+use biome_resolver::ResolvedPath;
 use biome_rowan::Text;
 
 use crate::{
@@ -550,6 +551,15 @@ pub trait TypeResolver {
         None
     }
 
+    /// Returns the resolved path for the given import `specifier`, if the
+    /// resolver is aware of it.
+    ///
+    /// This is used for creating references to types imported through import
+    /// types, such as `import("./module").T`.
+    fn resolve_import_specifier(&self, _specifier: &str) -> Option<ResolvedPath> {
+        None
+    }
+
     /// Resolves the given `expression` in the given `scope_id` to a type.
     ///
     /// Depending on the resolver, this may return owned type data based on
@@ -569,6 +579,13 @@ pub trait TypeResolver {
     /// Resolves the type of a value by its `identifier` in a specific scope.
     fn resolve_type_of(&self, identifier: &Text, scope_id: ScopeId) -> Option<ResolvedTypeId>;
 
+    /// Returns whether the type with the given `id` is a global declaration
+    /// that stands in for the hardcoded global with the given `global_id`,
+    /// such as the `Promise` interface from TypeScript's `lib.*.d.ts` files.
+    fn is_declaration_of_global(&self, _id: ResolvedTypeId, _global_id: ResolvedTypeId) -> bool {
+        false
+    }
+
     // #region Utilities for test inspection
 
     /// Returns the resolver's fallback, if it has one.
@@ -632,6 +649,38 @@ impl Resolvable for TypeReference {
             Self::Qualifier(qualifier) => {
                 let resolved_id = resolver.resolve_qualifier(qualifier);
                 match resolved_id {
+                    Some(resolved_id) if qualifier.has_known_type_parameters() => {
+                        // Resolvers may resolve qualifiers while ignoring their
+                        // type parameters. If the qualifier resolved to a
+                        // generic type alias, we create an instantiation for it
+                        // so the type parameters don't get lost.
+                        let parameters =
+                            resolver
+                                .get_by_resolved_id(resolved_id)
+                                .and_then(|resolved| match resolved.to_data() {
+                                    TypeData::InstanceOf(instance)
+                                        if !instance.type_parameters.is_empty() =>
+                                    {
+                                        Some(instance.type_parameters)
+                                    }
+                                    _ => None,
+                                });
+                        match parameters {
+                            Some(parameters) => {
+                                let type_parameters = self.resolved_params(resolver);
+                                Self::Resolved(resolver.register_and_resolve(
+                                    TypeData::instance_of(TypeInstance {
+                                        ty: resolved_id.into(),
+                                        type_parameters: Self::merge_parameters(
+                                            &parameters,
+                                            &type_parameters,
+                                        ),
+                                    }),
+                                ))
+                            }
+                            None => Self::Resolved(resolved_id),
+                        }
+                    }
                     Some(resolved_id) => Self::Resolved(resolved_id),
                     None => {
                         // If we can't resolve the qualifier as is, attempt to
@@ -106,6 +106,9 @@ impl Type {
     /// Returns whether this type is the `Promise` class.
     pub fn is_promise(&self) -> bool {
         self.id == GLOBAL_PROMISE_ID
+            || self
+                .resolver
+                .is_declaration_of_global(self.id, GLOBAL_PROMISE_ID)
     }
 
     /// Returns whether this type is an instance of a `Promise`.
@@ -193,6 +196,10 @@ pub enum TypeData {
     // Definition of a generic type argument.
     Generic(Box<GenericTypeParameter>),
 
+    /// Type parameter introduced through the `infer` keyword in the `extends`
+    /// clause of a conditional type.
+    Infer(Box<GenericTypeParameter>),
+
     // Compound types
     Intersection(Box<Intersection>),
     Union(Box<Union>),
@@ -200,6 +207,15 @@ pub enum TypeData {
     /// Type derived from another through a built-in operator.
     TypeOperator(Box<TypeOperatorType>),
 
+    /// Conditional type, such as `T extends U ? V : W`.
+    Conditional(Box<ConditionalType>),
+
+    /// Type of a property looked up through another type, such as `T[K]`.
+    IndexedAccess(Box<IndexedAccessType>),
+
+    /// Mapped type, such as `{ [K in keyof T]: T[K] }`.
+    Mapped(Box<MappedType>),
+
     /// Literal value used as a type.
     Literal(Box<Literal>),
 
@@ -261,6 +277,12 @@ pub enum TypeData {
     VoidKeyword,
 }
 
+impl From<ConditionalType> for TypeData {
+    fn from(value: ConditionalType) -> Self {
+        Self::Conditional(Box::new(value))
+    }
+}
+
 impl From<Constructor> for TypeData {
     fn from(value: Constructor) -> Self {
         Self::Constructor(Box::new(value))
@@ -279,6 +301,12 @@ impl From<GenericTypeParameter> for TypeData {
     }
 }
 
+impl From<IndexedAccessType> for TypeData {
+    fn from(value: IndexedAccessType) -> Self {
+        Self::IndexedAccess(Box::new(value))
+    }
+}
+
 impl From<Interface> for TypeData {
     fn from(value: Interface) -> Self {
         Self::Interface(Box::new(value))
@@ -291,6 +319,12 @@ impl From<Literal> for TypeData {
     }
 }
 
+impl From<MappedType> for TypeData {
+    fn from(value: MappedType) -> Self {
+        Self::Mapped(Box::new(value))
+    }
+}
+
 impl From<Module> for TypeData {
     fn from(value: Module) -> Self {
         Self::Module(Box::new(value))
@@ -359,6 +393,11 @@ impl TypeData {
         self.resolved(resolver).flattened(resolver)
     }
 
+    #[inline]
+    pub fn infer(param: GenericTypeParameter) -> Self {
+        Self::Infer(Box::new(param))
+    }
+
     #[inline]
     pub fn instance_of(instance: impl Into<TypeInstance>) -> Self {
         Self::InstanceOf(Box::new(instance.into()))
@@ -453,6 +492,33 @@ impl Debug for Class {
     }
 }
 
+/// A conditional type of the form `check_type extends extends_type ? true_type
+/// : false_type`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Resolvable)]
+pub struct ConditionalType {
+    /// The type being checked.
+    pub check_type: TypeReference,
+
+    /// The type the checked type is tested against.
+    ///
+    /// May contain [`TypeData::Infer`] types whose values are inferred from
+    /// the checked type.
+    pub extends_type: TypeReference,
+
+    /// Type to use if the checked type extends the `extends_type`.
+    pub true_type: TypeReference,
+
+    /// Type to use if the checked type does not extend the `extends_type`.
+    pub false_type: TypeReference,
+
+    /// Whether the conditional distributes over unions in the checked type.
+    ///
+    /// TypeScript only does this when the checked type is a naked type
+    /// parameter, so we approximate this by checking whether it is a plain
+    /// reference without type arguments.
+    pub is_distributive: bool,
+}
+
 /// A constructor definition.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Resolvable)]
 pub struct Constructor {
@@ -553,6 +619,16 @@ pub struct GenericTypeParameter {
     pub default: TypeReference,
 }
 
+/// Type of a property looked up through another type, such as `T[K]`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Resolvable)]
+pub struct IndexedAccessType {
+    /// The type whose property is looked up.
+    pub object_type: TypeReference,
+
+    /// The type of the key used for the lookup.
+    pub index_type: TypeReference,
+}
+
 /// An interface definition.
 #[derive(Clone, Hash, Eq, PartialEq, Resolvable)]
 pub struct Interface {
@@ -602,6 +678,38 @@ pub enum Literal {
     Template(Text), // TODO: Custom impl of PartialEq for template literals
 }
 
+/// A mapped type, such as `{ readonly [K in keyof T]?: T[K] }`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Resolvable)]
+pub struct MappedType {
+    /// Name of the type parameter that iterates over the keys.
+    pub key_name: Text,
+
+    /// Type of the keys being iterated over.
+    pub keys_type: TypeReference,
+
+    /// Optional type the keys are remapped to using an `as` clause.
+    pub as_type: Option<TypeReference>,
+
+    /// Type of the mapped properties.
+    pub property_type: TypeReference,
+
+    /// Modifier applied to the optionality of the mapped properties.
+    pub optional_modifier: Option<MappedTypeModifier>,
+
+    /// Modifier applied to the `readonly` flag of the mapped properties.
+    pub readonly_modifier: Option<MappedTypeModifier>,
+}
+
+/// Modifier that can be applied to the properties of a mapped type.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Resolvable)]
+pub enum MappedTypeModifier {
+    /// Adds the modifier, such as `?` or `+?`.
+    Add,
+
+    /// Removes the modifier, such as `-?`.
+    Remove,
+}
+
 /// A module definition.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Resolvable)]
 pub struct Module {
@@ -63,7 +63,8 @@ fn bench_analyzer(criterion: &mut Criterion) {
                                 &parse.tree(),
                                 filter,
                                 &options,
-                                file_source,
+                                &[],
+                                file_source.into(),
                                 |event| {
                                     black_box(event.diagnostic());
                                     black_box(event.actions());
@@ -5,21 +5,23 @@ mod lint;
 
 pub mod options;
 mod registry;
+mod services;
 mod suppression_action;
 pub mod utils;
 
 pub use crate::registry::visit_registry;
 use crate::suppression_action::JsonSuppressionAction;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, AnalyzerSuppression, ControlFlow,
-    LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
-    to_analyzer_suppressions,
+    AnalysisFilter, AnalyzerOptions, AnalyzerPluginSlice, AnalyzerSignal, AnalyzerSuppression,
+    ControlFlow, LanguageRoot, MatchQueryParams, MetadataRegistry, PluginTargetLanguage,
+    RuleAction, RuleRegistry, to_analyzer_suppressions,
 };
 use biome_diagnostics::Error;
+use biome_json_schema::JsonSchema;
 use biome_json_syntax::{JsonFileSource, JsonLanguage, TextRange};
 use biome_suppression::{SuppressionDiagnostic, parse_suppression_comment};
 use std::ops::Deref;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 
 pub(crate) type JsonRuleAction = RuleAction<JsonLanguage>;
 
@@ -29,6 +31,29 @@ pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
     metadata
 });
 
+#[derive(Debug, Clone)]
+pub struct JsonAnalyzerServices {
+    file_source: JsonFileSource,
+    schema: Option<Arc<JsonSchema>>,
+}
+
+impl JsonAnalyzerServices {
+    /// The JSON Schema the analyzed document should conform to, if any.
+    pub fn with_schema(mut self, schema: Option<Arc<JsonSchema>>) -> Self {
+        self.schema = schema;
+        self
+    }
+}
+
+impl From<JsonFileSource> for JsonAnalyzerServices {
+    fn from(file_source: JsonFileSource) -> Self {
+        Self {
+            file_source,
+            schema: None,
+        }
+    }
+}
+
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
 /// then call `emit_signal` when an analysis rule emits a diagnostic or action
@@ -36,14 +61,23 @@ pub fn analyze<'a, F, B>(
     root: &LanguageRoot<JsonLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
-    file_source: JsonFileSource,
+    plugins: AnalyzerPluginSlice<'a>,
+    services: JsonAnalyzerServices,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<JsonLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, file_source, emit_signal)
+    analyze_with_inspect_matcher(
+        root,
+        filter,
+        |_| {},
+        options,
+        plugins,
+        services,
+        emit_signal,
+    )
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -57,7 +91,8 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
-    file_source: JsonFileSource,
+    plugins: AnalyzerPluginSlice<'a>,
+    services: JsonAnalyzerServices,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -93,6 +128,11 @@ where
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
+    let JsonAnalyzerServices {
+        file_source,
+        schema,
+    } = services;
+
     let (registry, mut services, diagnostics, visitors, categories) = registry.build();
 
     // Bail if we can't parse a rule option
@@ -109,11 +149,14 @@ where
         categories,
     );
 
+    analyzer.add_plugins_for_language(plugins, PluginTargetLanguage::Json);
+
     for ((phase, _), visitor) in visitors {
         analyzer.add_visitor(phase, visitor);
     }
 
     services.insert_service(file_source);
+    services.insert_service(schema);
 
     (
         analyzer.run(biome_analyze::AnalyzerContext {
@@ -170,7 +213,8 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
-            JsonFileSource::json(),
+            &[],
+            JsonFileSource::json().into(),
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
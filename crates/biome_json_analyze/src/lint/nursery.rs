@@ -0,0 +1,7 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_lint_group;
+pub mod no_json_schema_violations;
+declare_lint_group! { pub Nursery { name : "nursery" , rules : [self :: no_json_schema_violations :: NoJsonSchemaViolations ,] } }
@@ -0,0 +1,108 @@
+use biome_analyze::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_json_schema::SchemaViolation;
+use biome_json_syntax::JsonRoot;
+
+use crate::services::json_schema::WithSchema;
+
+declare_lint_rule! {
+    /// Disallow values that don't conform to the JSON Schema of the document.
+    ///
+    /// The schema of a document is taken from its top-level `$schema`
+    /// property, when it points to a local file. The path is resolved relative
+    /// to the document. Otherwise, the first schema of the `json.schemas`
+    /// configuration whose `includes` match the document is used:
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "json": {
+    ///     "schemas": [
+    ///       {
+    ///         "includes": ["**/tsconfig*.json"],
+    ///         "schema": "./schemas/tsconfig.schema.json"
+    ///       }
+    ///     ]
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Remote schemas, such as `https://` URLs, aren't downloaded and are
+    /// ignored. The rule supports references through `$ref`, as well as the
+    /// `type`, `enum`, `const`, `required`, `properties`,
+    /// `additionalProperties`, `items`, `anyOf`, `oneOf`, `allOf` and `not`
+    /// keywords, among others.
+    ///
+    /// ## Examples
+    ///
+    /// Given the following schema in `schema.json`:
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "type": "object",
+    ///   "properties": {
+    ///     "level": { "enum": ["info", "warn", "error"] }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "$schema": "./schema.json",
+    ///   "level": "debug"
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///   "$schema": "./schema.json",
+    ///   "level": "warn"
+    /// }
+    /// ```
+    ///
+    pub NoJsonSchemaViolations {
+        version: "next",
+        name: "noJsonSchemaViolations",
+        language: "json",
+        recommended: false,
+        severity: Severity::Error,
+    }
+}
+
+impl Rule for NoJsonSchemaViolations {
+    type Query = WithSchema<JsonRoot>;
+    type State = SchemaViolation;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some(schema) = ctx.schema() else {
+            return Box::default();
+        };
+
+        match ctx.query().value() {
+            Ok(value) => schema.validate(&value).into_boxed_slice(),
+            Err(_) => Box::default(),
+        }
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let message = state.kind().to_string();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range(),
+                markup! {
+                    {message}
+                },
+            )
+            .note(markup! {
+                "Update the value to conform to the JSON Schema of the document, or update the schema."
+            }),
+        )
+    }
+}
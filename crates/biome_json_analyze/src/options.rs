@@ -5,5 +5,6 @@
 use crate::assist;
 use crate::lint;
 pub type NoDuplicateObjectKeys = < lint :: suspicious :: no_duplicate_object_keys :: NoDuplicateObjectKeys as biome_analyze :: Rule > :: Options ;
+pub type NoJsonSchemaViolations = < lint :: nursery :: no_json_schema_violations :: NoJsonSchemaViolations as biome_analyze :: Rule > :: Options ;
 pub type UseSortedKeys =
     <assist::source::use_sorted_keys::UseSortedKeys as biome_analyze::Rule>::Options;
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use biome_analyze::{
+    AddVisitor, FromServices, Phase, Phases, QueryKey, Queryable, RuleKey, RuleMetadata,
+    ServiceBag, ServicesDiagnostic, SyntaxVisitor,
+};
+use biome_json_schema::JsonSchema;
+use biome_json_syntax::{JsonLanguage, JsonRoot, JsonSyntaxNode};
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone)]
+pub struct JsonSchemaServices {
+    /// The schema the document should conform to, if one could be resolved.
+    schema: Option<Arc<JsonSchema>>,
+}
+
+impl JsonSchemaServices {
+    pub fn schema(&self) -> Option<&JsonSchema> {
+        self.schema.as_deref()
+    }
+}
+
+impl FromServices for JsonSchemaServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        _rule_metadata: &RuleMetadata,
+        services: &ServiceBag,
+    ) -> biome_diagnostics::Result<Self, ServicesDiagnostic> {
+        let schema: &Option<Arc<JsonSchema>> = services
+            .get_service()
+            .ok_or_else(|| ServicesDiagnostic::new(rule_key.rule_name(), &["JsonSchema"]))?;
+
+        Ok(Self {
+            schema: schema.clone(),
+        })
+    }
+}
+
+impl Phase for JsonSchemaServices {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules **that use the JSON Schema of the
+/// document** and match on specific [AstNode] types.
+#[derive(Clone)]
+pub struct WithSchema<N>(pub N);
+
+impl<N> Queryable for WithSchema<N>
+where
+    N: AstNode<Language = JsonLanguage> + 'static,
+{
+    type Input = JsonSyntaxNode;
+    type Output = N;
+
+    type Language = JsonLanguage;
+    type Services = JsonSchemaServices;
+
+    fn build_visitor(analyzer: &mut impl AddVisitor<JsonLanguage>, _: &JsonRoot) {
+        analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default);
+    }
+
+    fn key() -> QueryKey<Self::Language> {
+        QueryKey::Syntax(N::KIND_SET)
+    }
+
+    fn unwrap_match(_: &ServiceBag, node: &Self::Input) -> Self::Output {
+        N::unwrap_cast(node.clone())
+    }
+}
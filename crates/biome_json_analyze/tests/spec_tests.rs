@@ -1,7 +1,13 @@
-use biome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
+use biome_analyze::{
+    AnalysisFilter, AnalyzerAction, AnalyzerPluginSlice, ControlFlow, Never, RuleFilter,
+};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
+use biome_fs::OsFileSystem;
+use biome_json_analyze::JsonAnalyzerServices;
 use biome_json_parser::{JsonParserOptions, parse_json};
-use biome_json_syntax::{JsonFileSource, JsonLanguage};
+use biome_json_schema::{JsonSchema, find_schema_reference, is_remote_reference};
+use biome_json_syntax::{JsonFileSource, JsonLanguage, JsonRoot};
+use biome_plugin_loader::AnalyzerGritPlugin;
 use biome_rowan::AstNode;
 use biome_test_utils::{
     CheckActionType, assert_diagnostics_expectation_comment, assert_errors_are_absent,
@@ -11,10 +17,12 @@ use biome_test_utils::{
 };
 use camino::Utf8Path;
 use std::ops::Deref;
+use std::sync::Arc;
 use std::{fs::read_to_string, slice};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{json,jsonc}", crate::run_test, "module"}
 tests_macros::gen_tests! {"tests/suppression/**/*.{json,jsonc}", crate::run_suppression_test, "module"}
+tests_macros::gen_tests! {"tests/plugin/*.grit", crate::run_plugin_test, "module"}
 
 fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     register_leak_checker();
@@ -22,6 +30,11 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     let input_file = Utf8Path::new(input);
     let file_name = input_file.file_name().unwrap();
 
+    // Schemas referenced by other test files aren't tests themselves.
+    if file_name.ends_with(".schema.json") {
+        return;
+    }
+
     let parser_options = match input_file.extension() {
         Some("json") => JsonParserOptions::default(),
         Some("jsonc") => JsonParserOptions::default()
@@ -73,6 +86,7 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
         input_file,
         CheckActionType::Lint,
         parser_options,
+        &[],
     );
 
     insta::with_settings!({
@@ -121,6 +135,7 @@ fn run_suppression_test(input: &'static str, _: &str, _: &str, _: &str) {
         input_file,
         CheckActionType::Suppression,
         parser_options,
+        &[],
     );
 
     insta::with_settings!({
@@ -141,6 +156,7 @@ pub(crate) fn analyze_and_snap(
     input_file: &Utf8Path,
     action_type: CheckActionType,
     parser_options: JsonParserOptions,
+    plugins: AnalyzerPluginSlice,
 ) {
     let parsed = parse_json(input_code, parser_options);
     let root = parsed.tree();
@@ -149,33 +165,37 @@ pub(crate) fn analyze_and_snap(
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
 
-    let (_, errors) = biome_json_analyze::analyze(&root, filter, &options, file_source, |event| {
-        if let Some(mut diag) = event.diagnostic() {
-            for action in event.actions() {
-                if action.is_suppression() {
-                    if action_type.is_suppression() {
+    let services =
+        JsonAnalyzerServices::from(file_source).with_schema(load_schema(&root, input_file));
+
+    let (_, errors) =
+        biome_json_analyze::analyze(&root, filter, &options, plugins, services, |event| {
+            if let Some(mut diag) = event.diagnostic() {
+                for action in event.actions() {
+                    if action.is_suppression() {
+                        if action_type.is_suppression() {
+                            check_code_action(input_file, input_code, &action, parser_options);
+                            diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+                        }
+                    } else if !action.is_suppression() {
                         check_code_action(input_file, input_code, &action, parser_options);
                         diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                     }
-                } else if !action.is_suppression() {
-                    check_code_action(input_file, input_code, &action, parser_options);
-                    diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
                 }
-            }
 
-            diagnostics.push(diagnostic_to_string(file_name, input_code, diag.into()));
-            return ControlFlow::Continue(());
-        }
+                diagnostics.push(diagnostic_to_string(file_name, input_code, diag.into()));
+                return ControlFlow::Continue(());
+            }
 
-        for action in event.actions() {
-            if !action.is_suppression() {
-                check_code_action(input_file, input_code, &action, parser_options);
-                code_fixes.push(code_fix_to_string(input_code, action));
+            for action in event.actions() {
+                if !action.is_suppression() {
+                    check_code_action(input_file, input_code, &action, parser_options);
+                    code_fixes.push(code_fix_to_string(input_code, action));
+                }
             }
-        }
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        });
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
@@ -192,6 +212,22 @@ pub(crate) fn analyze_and_snap(
     assert_diagnostics_expectation_comment(input_file, root.syntax(), diagnostics.len());
 }
 
+/// Loads the schema referenced by the `$schema` property of the test file, if
+/// it points to a local file.
+fn load_schema(root: &JsonRoot, input_file: &Utf8Path) -> Option<Arc<JsonSchema>> {
+    let reference = find_schema_reference(root)?;
+    if is_remote_reference(reference.as_str()) {
+        return None;
+    }
+
+    let directory = input_file.parent()?;
+    let fs = OsFileSystem::new(directory.to_path_buf());
+    let schema = JsonSchema::load(&fs, &directory.join(reference.as_str()))
+        .unwrap_or_else(|err| panic!("failed to load the schema of {input_file:?}: {err:?}"));
+
+    Some(Arc::new(schema))
+}
+
 fn check_code_action(
     path: &Utf8Path,
     source: &str,
@@ -226,3 +262,47 @@ fn check_code_action(
     let re_parse = parse_json(&output, parser_options);
     assert_errors_are_absent(re_parse.tree().syntax(), re_parse.diagnostics(), path);
 }
+
+fn run_plugin_test(input: &'static str, _: &str, _: &str, _: &str) {
+    register_leak_checker();
+
+    let plugin_path = Utf8Path::new(input);
+    let file_name = plugin_path.file_name().unwrap();
+    let input_path = plugin_path.with_extension("json");
+
+    let plugin = match AnalyzerGritPlugin::load(
+        &OsFileSystem::new(plugin_path.to_owned()),
+        Utf8Path::new(plugin_path),
+    ) {
+        Ok(plugin) => plugin,
+        Err(err) => panic!("Cannot load plugin: {err:?}"),
+    };
+
+    let filter = AnalysisFilter {
+        enabled_rules: Some(&[]),
+        ..AnalysisFilter::default()
+    };
+
+    let mut snapshot = String::new();
+
+    let input_code = read_to_string(&input_path)
+        .unwrap_or_else(|err| panic!("failed to read {input_path:?}: {err:?}"));
+    analyze_and_snap(
+        &mut snapshot,
+        &input_code,
+        JsonFileSource::json(),
+        filter,
+        file_name,
+        &input_path,
+        CheckActionType::Lint,
+        JsonParserOptions::default(),
+        &[Arc::new(Box::new(plugin))],
+    );
+
+    insta::with_settings!({
+        prepend_module_to_snapshot => false,
+        snapshot_path => plugin_path.parent().unwrap(),
+    }, {
+        insta::assert_snapshot!(file_name, snapshot, file_name);
+    });
+}
@@ -319,7 +319,9 @@ impl<'src> Lexer<'src> {
             UNI => {
                 let chr = self.current_char_unchecked();
 
-                if is_js_id_start(chr) {
+                if self.options.grit_metavariables && self.is_metavariable_start() {
+                    self.consume_metavariable()
+                } else if is_js_id_start(chr) {
                     self.lex_identifier(current)
                 } else if self.position == 0 && self.consume_potential_bom().is_some() {
                     // A BOM can only appear at the start of a file, so if we haven't advanced at all yet,
@@ -677,6 +679,44 @@ impl<'src> Lexer<'src> {
 
     /// Implements basic lexing of identifiers without support for escape sequences.
     /// This is merely for improved error recovery as identifiers are not valid in JSON.
+    /// Checks if the lexer is at the start of a Grit metavariable.
+    fn is_metavariable_start(&self) -> bool {
+        let current_char = self.current_char_unchecked();
+        if current_char != 'µ' {
+            return false;
+        }
+
+        let current_char_length = current_char.len_utf8();
+        match self.byte_at(current_char_length) {
+            // µ[a-zA-Z_][a-zA-Z0-9_]*
+            Some(b'a'..=b'z' | b'A'..=b'Z' | b'_') => true,
+            // µ...
+            Some(b'.') => {
+                self.byte_at(current_char_length + 1) == Some(b'.')
+                    && self.byte_at(current_char_length + 2) == Some(b'.')
+            }
+            _ => false,
+        }
+    }
+
+    /// Consumes a Grit metavariable (`µ[a-zA-Z_][a-zA-Z0-9_]*` or `µ...`).
+    fn consume_metavariable(&mut self) -> JsonSyntaxKind {
+        debug_assert!(self.is_metavariable_start());
+
+        self.advance('µ'.len_utf8());
+
+        if self.current_byte() == Some(b'.') {
+            self.advance(3);
+        } else {
+            self.advance(1);
+            while let Some(b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_') = self.current_byte() {
+                self.advance(1);
+            }
+        }
+
+        GRIT_METAVARIABLE
+    }
+
     fn lex_identifier(&mut self, first: u8) -> JsonSyntaxKind {
         self.assert_at_char_boundary();
 
@@ -16,6 +16,7 @@ const VALUE_START: TokenSet<JsonSyntaxKind> = token_set![
     JSON_NUMBER_LITERAL,
     T!['['],
     T!['{'],
+    GRIT_METAVARIABLE,
 ];
 
 const VALUE_RECOVERY_SET: TokenSet<JsonSyntaxKind> =
@@ -73,6 +74,12 @@ fn parse_value(p: &mut JsonParser) -> ParsedSyntax {
         T!['{'] => parse_sequence(p, SequenceKind::Object),
         T!['['] => parse_sequence(p, SequenceKind::Array),
 
+        GRIT_METAVARIABLE => {
+            let m = p.start();
+            p.bump(GRIT_METAVARIABLE);
+            Present(m.complete(p, JSON_METAVARIABLE))
+        }
+
         IDENT => {
             let m = p.start();
             p.error(p.err_builder("String values must be double quoted.", p.cur_range()));
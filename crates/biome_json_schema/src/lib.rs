@@ -0,0 +1,18 @@
+//! Validation of JSON documents against [JSON Schemas](https://json-schema.org/).
+//!
+//! Schemas are represented as [`biome_json_value::JsonValue`]s, while the
+//! validated documents are syntax trees from `biome_json_syntax`, so that
+//! violations can be reported with precise ranges.
+//!
+//! The validator implements the subset of the specification that is relevant
+//! for validating configuration files: `$ref`, `type`, `enum`, `const`, the
+//! object, array, string and number keywords, and the `allOf`, `anyOf`,
+//! `oneOf`, `not` and `if`/`then`/`else` combinators. Annotations such as
+//! `format` and `description` are ignored.
+#![deny(clippy::use_self)]
+
+mod schema;
+mod validator;
+
+pub use schema::*;
+pub use validator::{SchemaViolation, ViolationKind};
@@ -0,0 +1,226 @@
+use biome_fs::{FileSystem, FileSystemDiagnostic, normalize_path};
+use biome_json_parser::{JsonParserOptions, parse_json};
+use biome_json_syntax::{AnyJsonValue, JsonRoot};
+use biome_json_value::{JsonString, JsonValue};
+use biome_rowan::AstSeparatedList;
+use camino::{Utf8Path, Utf8PathBuf};
+use rustc_hash::FxHashMap;
+
+use crate::validator::{SchemaViolation, Validator};
+
+/// A JSON Schema, together with the schema documents it references.
+///
+/// Schemas are loaded from the file system. References to other files, such
+/// as `"$ref": "./definitions.json#/$defs/name"`, are resolved relative to the
+/// document that contains them and loaded eagerly, so that validation doesn't
+/// require access to the file system anymore. Remote references, such as
+/// `https://` URLs, aren't supported and are ignored during validation.
+#[derive(Clone, Debug)]
+pub struct JsonSchema {
+    /// Path of the root schema document.
+    root: Utf8PathBuf,
+
+    /// All the loaded schema documents, including the root, indexed by their
+    /// normalized path.
+    documents: FxHashMap<Utf8PathBuf, JsonValue>,
+}
+
+impl JsonSchema {
+    /// Loads the schema at the given `path`, together with all the local
+    /// schema documents it references.
+    ///
+    /// Only a failure to read the root document results in an error.
+    /// References to files that cannot be read are ignored.
+    pub fn load(fs: &dyn FileSystem, path: &Utf8Path) -> Result<Self, FileSystemDiagnostic> {
+        let root = normalize_path(path);
+        let content = fs.read_file_from_path(&root)?;
+
+        let mut schema = Self::from_documents(root.clone(), [(root, parse_document(&content))]);
+
+        let mut queue = vec![schema.root.clone()];
+        while let Some(document_path) = queue.pop() {
+            let mut references = Vec::new();
+            collect_file_references(&schema.documents[&document_path], &mut references);
+
+            for reference in references {
+                let path = resolve_file_reference(&document_path, &reference);
+                if schema.documents.contains_key(&path) {
+                    continue;
+                }
+
+                if let Ok(content) = fs.read_file_from_path(&path) {
+                    schema
+                        .documents
+                        .insert(path.clone(), parse_document(&content));
+                    queue.push(path);
+                }
+            }
+        }
+
+        Ok(schema)
+    }
+
+    /// Creates a schema from documents that have already been loaded.
+    ///
+    /// `root` must be the path of one of the given `documents`.
+    pub fn from_documents(
+        root: Utf8PathBuf,
+        documents: impl IntoIterator<Item = (Utf8PathBuf, JsonValue)>,
+    ) -> Self {
+        Self {
+            root,
+            documents: documents.into_iter().collect(),
+        }
+    }
+
+    /// Returns the path of the root schema document.
+    pub fn path(&self) -> &Utf8Path {
+        &self.root
+    }
+
+    /// Returns the paths of the documents this schema has been loaded from,
+    /// including the root.
+    pub fn document_paths(&self) -> impl Iterator<Item = &Utf8Path> {
+        self.documents.keys().map(Utf8PathBuf::as_path)
+    }
+
+    /// Returns whether the given `path` is one of the documents this schema
+    /// has been loaded from.
+    ///
+    /// Can be used to invalidate cached schemas when a file changes.
+    pub fn depends_on(&self, path: &Utf8Path) -> bool {
+        self.documents.contains_key(path)
+    }
+
+    /// Validates the given JSON `value` against the schema.
+    pub fn validate(&self, value: &AnyJsonValue) -> Vec<SchemaViolation> {
+        let mut validator = Validator::new(self);
+        validator.validate(value);
+        validator.into_violations()
+    }
+
+    /// Returns the root document of the schema.
+    pub(crate) fn root_document(&self) -> (&Utf8Path, &JsonValue) {
+        (&self.root, &self.documents[&self.root])
+    }
+
+    /// Resolves a `$ref` found in the document at `document_path`.
+    ///
+    /// Returns the path of the document that contains the referenced schema,
+    /// together with the schema itself.
+    pub(crate) fn resolve_reference<'a>(
+        &'a self,
+        document_path: &'a Utf8Path,
+        reference: &str,
+    ) -> Option<(&'a Utf8Path, &'a JsonValue)> {
+        let (file, fragment) = reference.split_once('#').unwrap_or((reference, ""));
+
+        let (path, document) = if file.is_empty() {
+            (document_path, self.documents.get(document_path)?)
+        } else if is_remote_reference(file) {
+            return None;
+        } else {
+            let path = resolve_file_reference(document_path, file);
+            let (path, document) = self.documents.get_key_value(&path)?;
+            (path.as_path(), document)
+        };
+
+        if fragment.is_empty() {
+            return Some((path, document));
+        }
+
+        // Anchors (`#name`) aren't supported, only JSON pointers.
+        let pointer = fragment.strip_prefix('/')?;
+        let mut value = document;
+        for token in pointer.split('/') {
+            let token = token.replace("~1", "/").replace("~0", "~");
+            value = match value {
+                JsonValue::Object(object) => object.get(token.as_str())?,
+                JsonValue::Array(array) => array.get(token.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+
+        Some((path, value))
+    }
+}
+
+/// Returns the value of the top-level `$schema` key of the given document,
+/// if any.
+pub fn find_schema_reference(root: &JsonRoot) -> Option<JsonString> {
+    let AnyJsonValue::JsonObjectValue(object) = root.value().ok()? else {
+        return None;
+    };
+
+    object.json_member_list().iter().find_map(|member| {
+        let member = member.ok()?;
+        let name = member.name().ok()?.inner_string_text().ok()?;
+        if name.text() != "$schema" {
+            return None;
+        }
+
+        match member.value().ok()? {
+            AnyJsonValue::JsonStringValue(value) => Some(value.into()),
+            _ => None,
+        }
+    })
+}
+
+/// Returns whether the given schema reference points to a remote resource,
+/// such as `https://json.schemastore.org/package.json`.
+pub fn is_remote_reference(reference: &str) -> bool {
+    reference.contains("://")
+}
+
+fn parse_document(content: &str) -> JsonValue {
+    let parsed = parse_json(
+        content,
+        JsonParserOptions::default()
+            .with_allow_comments()
+            .with_allow_trailing_commas(),
+    );
+
+    match parsed.tree().value() {
+        Ok(value) => value.into(),
+        Err(_) => JsonValue::Bogus,
+    }
+}
+
+fn resolve_file_reference(document_path: &Utf8Path, reference: &str) -> Utf8PathBuf {
+    let file = reference
+        .split_once('#')
+        .map_or(reference, |(file, _)| file);
+    let directory = document_path.parent().unwrap_or(document_path);
+    normalize_path(&directory.join(file))
+}
+
+/// Collects the file part of all the `$ref`s found in the given schema
+/// document that point to other local files.
+fn collect_file_references(value: &JsonValue, references: &mut Vec<String>) {
+    match value {
+        JsonValue::Object(object) => {
+            for (key, value) in object.iter() {
+                if key.as_str() == "$ref" {
+                    if let Some(reference) = value.as_string() {
+                        let file = reference
+                            .as_str()
+                            .split_once('#')
+                            .map_or(reference.as_str(), |(file, _)| file);
+                        if !file.is_empty() && !is_remote_reference(file) {
+                            references.push(file.to_string());
+                        }
+                        continue;
+                    }
+                }
+
+                collect_file_references(value, references);
+            }
+        }
+        JsonValue::Array(array) => {
+            for value in array.iter() {
+                collect_file_references(value, references);
+            }
+        }
+        _ => {}
+    }
+}
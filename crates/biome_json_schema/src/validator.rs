@@ -0,0 +1,727 @@
+use std::fmt::{self, Display, Formatter, Write};
+
+use biome_json_syntax::{AnyJsonValue, JsonArrayValue, JsonObjectValue};
+use biome_json_value::{JsonObject, JsonString, JsonValue};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+use camino::Utf8Path;
+use regex::Regex;
+use rustc_hash::FxHashMap;
+
+use crate::JsonSchema;
+
+/// Maximum depth of nested schemas the validator descends into.
+///
+/// This protects against reference cycles such as `{ "$ref": "#" }`, which
+/// would otherwise never terminate.
+const MAX_DEPTH: usize = 128;
+
+/// A location in a JSON document that doesn't conform to a schema.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaViolation {
+    range: TextRange,
+    kind: ViolationKind,
+}
+
+impl SchemaViolation {
+    /// The range of the offending value, property name or object.
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// The reason why the document doesn't conform to the schema.
+    pub fn kind(&self) -> &ViolationKind {
+        &self.kind
+    }
+}
+
+/// The reason why a document doesn't conform to a schema.
+///
+/// Its [Display] implementation renders the message shown to users.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ViolationKind {
+    /// The value doesn't have any of the types allowed by `type`.
+    InvalidType {
+        expected: Box<[Box<str>]>,
+        found: &'static str,
+    },
+    /// The value isn't one of the values listed in `enum`.
+    NotInEnum { allowed: Box<[JsonValue]> },
+    /// The value isn't equal to the value of `const`.
+    NotConst { expected: JsonValue },
+    /// A property listed in `required` is missing.
+    MissingProperty { name: Box<str> },
+    /// A property isn't allowed because of `additionalProperties: false`.
+    UnexpectedProperty { name: Box<str> },
+    /// The object has fewer properties than `minProperties`.
+    TooFewProperties { min: f64 },
+    /// The object has more properties than `maxProperties`.
+    TooManyProperties { max: f64 },
+    /// The array has fewer items than `minItems`.
+    TooFewItems { min: f64 },
+    /// The array has more items than `maxItems`.
+    TooManyItems { max: f64 },
+    /// The item is equal to a previous item, despite `uniqueItems`.
+    DuplicateItem,
+    /// None of the items match the schema of `contains`.
+    MissingContainedItem,
+    /// The string is shorter than `minLength`.
+    TooShort { min: f64 },
+    /// The string is longer than `maxLength`.
+    TooLong { max: f64 },
+    /// The string doesn't match the regular expression of `pattern`.
+    PatternMismatch { pattern: Box<str> },
+    /// The number is smaller than `minimum` or `exclusiveMinimum`.
+    BelowMinimum { min: f64, exclusive: bool },
+    /// The number is larger than `maximum` or `exclusiveMaximum`.
+    AboveMaximum { max: f64, exclusive: bool },
+    /// The number isn't a multiple of `multipleOf`.
+    NotMultipleOf { divisor: f64 },
+    /// The value doesn't match any of the schemas of `anyOf` or `oneOf`.
+    NoMatchingSchema,
+    /// The value matches more than one of the schemas of `oneOf`.
+    MultipleMatchingSchemas,
+    /// The value matches the schema of `not`.
+    MatchesNegatedSchema,
+    /// The value is validated against the `false` schema.
+    NotAllowed,
+}
+
+impl Display for ViolationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidType { expected, found } => {
+                write!(f, "Incorrect type: expected ")?;
+                for (index, ty) in expected.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, " or ")?;
+                    }
+                    write!(f, "{ty}")?;
+                }
+                write!(f, ", but found {found}.")
+            }
+            Self::NotInEnum { allowed } => {
+                write!(f, "Value must be one of ")?;
+                for (index, value) in allowed.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write_value(f, value)?;
+                }
+                write!(f, ".")
+            }
+            Self::NotConst { expected } => {
+                write!(f, "Value must be ")?;
+                write_value(f, expected)?;
+                write!(f, ".")
+            }
+            Self::MissingProperty { name } => write!(f, "Missing required property \"{name}\"."),
+            Self::UnexpectedProperty { name } => write!(f, "Property \"{name}\" is not allowed."),
+            Self::TooFewProperties { min } => {
+                write!(f, "Object must have at least {min} properties.")
+            }
+            Self::TooManyProperties { max } => {
+                write!(f, "Object must have at most {max} properties.")
+            }
+            Self::TooFewItems { min } => write!(f, "Array must have at least {min} items."),
+            Self::TooManyItems { max } => write!(f, "Array must have at most {max} items."),
+            Self::DuplicateItem => write!(f, "Array items must be unique."),
+            Self::MissingContainedItem => {
+                write!(f, "Array must contain an item matching the schema.")
+            }
+            Self::TooShort { min } => {
+                write!(f, "String must be at least {min} characters long.")
+            }
+            Self::TooLong { max } => write!(f, "String must be at most {max} characters long."),
+            Self::PatternMismatch { pattern } => {
+                write!(f, "String doesn't match the pattern \"{pattern}\".")
+            }
+            Self::BelowMinimum { min, exclusive } => {
+                if *exclusive {
+                    write!(f, "Value must be greater than {min}.")
+                } else {
+                    write!(f, "Value must be greater than or equal to {min}.")
+                }
+            }
+            Self::AboveMaximum { max, exclusive } => {
+                if *exclusive {
+                    write!(f, "Value must be less than {max}.")
+                } else {
+                    write!(f, "Value must be less than or equal to {max}.")
+                }
+            }
+            Self::NotMultipleOf { divisor } => write!(f, "Value must be a multiple of {divisor}."),
+            Self::NoMatchingSchema => write!(f, "Value doesn't match any of the allowed schemas."),
+            Self::MultipleMatchingSchemas => {
+                write!(f, "Value matches more than one of the exclusive schemas.")
+            }
+            Self::MatchesNegatedSchema => write!(f, "Value matches a disallowed schema."),
+            Self::NotAllowed => write!(f, "Value is not allowed here."),
+        }
+    }
+}
+
+/// Writes a compact JSON representation of `value`.
+fn write_value(f: &mut impl Write, value: &JsonValue) -> fmt::Result {
+    match value {
+        JsonValue::Array(array) => {
+            f.write_char('[')?;
+            for (index, value) in array.iter().enumerate() {
+                if index > 0 {
+                    f.write_str(", ")?;
+                }
+                write_value(f, value)?;
+            }
+            f.write_char(']')
+        }
+        JsonValue::Bool(bool) => write!(f, "{bool}"),
+        JsonValue::Null => f.write_str("null"),
+        JsonValue::Number(number) => write!(f, "{number}"),
+        JsonValue::Object(object) => {
+            f.write_char('{')?;
+            for (index, (key, value)) in object.iter().enumerate() {
+                if index > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "\"{}\": ", key.as_str())?;
+                write_value(f, value)?;
+            }
+            f.write_char('}')
+        }
+        JsonValue::String(string) => write!(f, "\"{}\"", string.as_str()),
+        JsonValue::Bogus => Ok(()),
+    }
+}
+
+/// Validates a JSON document against a [JsonSchema], collecting all the
+/// violations it finds.
+pub(crate) struct Validator<'a> {
+    schema: &'a JsonSchema,
+    violations: Vec<SchemaViolation>,
+    regexes: FxHashMap<&'a str, Option<Regex>>,
+    depth: usize,
+}
+
+impl<'a> Validator<'a> {
+    pub(crate) fn new(schema: &'a JsonSchema) -> Self {
+        Self {
+            schema,
+            violations: Vec::new(),
+            regexes: FxHashMap::default(),
+            depth: 0,
+        }
+    }
+
+    pub(crate) fn into_violations(self) -> Vec<SchemaViolation> {
+        self.violations
+    }
+
+    pub(crate) fn validate(&mut self, value: &AnyJsonValue) {
+        let (document, schema) = self.schema.root_document();
+        self.validate_value(document, schema, value);
+    }
+
+    /// Returns whether `value` matches `schema`, without reporting any
+    /// violations.
+    fn matches(
+        &mut self,
+        document: &'a Utf8Path,
+        schema: &'a JsonValue,
+        value: &AnyJsonValue,
+    ) -> bool {
+        let len = self.violations.len();
+        self.validate_value(document, schema, value);
+        let matches = self.violations.len() == len;
+        self.violations.truncate(len);
+        matches
+    }
+
+    /// Returns the violations of `schema` by `value`, without reporting them.
+    fn violations_of(
+        &mut self,
+        document: &'a Utf8Path,
+        schema: &'a JsonValue,
+        value: &AnyJsonValue,
+    ) -> Vec<SchemaViolation> {
+        let len = self.violations.len();
+        self.validate_value(document, schema, value);
+        self.violations.split_off(len)
+    }
+
+    /// Reports a value that doesn't match any of the schemas of `anyOf` or
+    /// `oneOf`.
+    ///
+    /// `candidates` contains the violations of each of the schemas. If only
+    /// one of the schemas accepts the type of the value, its violations are
+    /// likely more helpful than a generic message, so they're reported
+    /// instead.
+    fn report_no_matching_schema(
+        &mut self,
+        range: TextRange,
+        candidates: Vec<Vec<SchemaViolation>>,
+    ) {
+        let mut plausible = candidates.into_iter().filter(|violations| {
+            !violations.iter().any(|violation| {
+                violation.range == range
+                    && matches!(violation.kind, ViolationKind::InvalidType { .. })
+            })
+        });
+
+        match (plausible.next(), plausible.next()) {
+            (Some(violations), None) => self.violations.extend(violations),
+            _ => self.report(range, ViolationKind::NoMatchingSchema),
+        }
+    }
+
+    fn report(&mut self, range: TextRange, kind: ViolationKind) {
+        self.violations.push(SchemaViolation { range, kind });
+    }
+
+    /// Validates `value` against `schema`.
+    ///
+    /// `document` is the path of the schema document that contains `schema`,
+    /// and is used to resolve relative references.
+    fn validate_value(
+        &mut self,
+        document: &'a Utf8Path,
+        schema: &'a JsonValue,
+        value: &AnyJsonValue,
+    ) {
+        let schema = match schema {
+            JsonValue::Bool(false) => {
+                self.report(value.range(), ViolationKind::NotAllowed);
+                return;
+            }
+            JsonValue::Object(schema) => schema,
+            _ => return,
+        };
+
+        if self.depth >= MAX_DEPTH
+            || matches!(
+                value,
+                AnyJsonValue::JsonBogusValue(_) | AnyJsonValue::JsonMetavariable(_)
+            )
+        {
+            return;
+        }
+
+        self.depth += 1;
+
+        if let Some(reference) = get_string(schema, "$ref") {
+            if let Some((document, schema)) =
+                self.schema.resolve_reference(document, reference.as_str())
+            {
+                self.validate_value(document, schema, value);
+            }
+        }
+
+        self.validate_type(schema, value);
+        self.validate_enum(schema, value);
+
+        match value {
+            AnyJsonValue::JsonObjectValue(object) => self.validate_object(document, schema, object),
+            AnyJsonValue::JsonArrayValue(array) => self.validate_array(document, schema, array),
+            AnyJsonValue::JsonStringValue(string) => {
+                let string = JsonString::from(string.clone());
+                self.validate_string(schema, string.as_str(), value.range());
+            }
+            AnyJsonValue::JsonNumberValue(_) => {
+                if let Some(number) = number_value(value) {
+                    self.validate_number(schema, number, value.range());
+                }
+            }
+            _ => {}
+        }
+
+        self.validate_combinators(document, schema, value);
+
+        self.depth -= 1;
+    }
+
+    fn validate_type(&mut self, schema: &'a JsonObject, value: &AnyJsonValue) {
+        let expected: Vec<&str> = match schema.get("type") {
+            Some(JsonValue::String(ty)) => vec![ty.as_str()],
+            Some(JsonValue::Array(types)) => types
+                .iter()
+                .filter_map(|ty| ty.as_string().map(JsonString::as_str))
+                .collect(),
+            _ => return,
+        };
+
+        let Some(found) = value_type(value) else {
+            return;
+        };
+
+        let matches = expected.iter().any(|&ty| {
+            ty == found
+                || (ty == "integer"
+                    && found == "number"
+                    && number_value(value).is_some_and(|number| number.fract() == 0.0))
+        });
+        if !matches {
+            self.report(
+                value.range(),
+                ViolationKind::InvalidType {
+                    expected: expected.into_iter().map(Box::from).collect(),
+                    found,
+                },
+            );
+        }
+    }
+
+    fn validate_enum(&mut self, schema: &'a JsonObject, value: &AnyJsonValue) {
+        let allowed = schema.get("enum").and_then(JsonValue::as_array);
+        let expected = schema.get("const");
+        if allowed.is_none() && expected.is_none() {
+            return;
+        }
+
+        let json_value = JsonValue::from(value.clone());
+        if let Some(allowed) = allowed {
+            if !allowed.contains(&json_value) {
+                self.report(
+                    value.range(),
+                    ViolationKind::NotInEnum {
+                        allowed: allowed.iter().cloned().collect(),
+                    },
+                );
+            }
+        }
+
+        if let Some(expected) = expected {
+            if expected != &json_value {
+                self.report(
+                    value.range(),
+                    ViolationKind::NotConst {
+                        expected: expected.clone(),
+                    },
+                );
+            }
+        }
+    }
+
+    fn validate_object(
+        &mut self,
+        document: &'a Utf8Path,
+        schema: &'a JsonObject,
+        object: &JsonObjectValue,
+    ) {
+        let properties = schema.get("properties").and_then(JsonValue::as_object);
+        let pattern_properties = schema
+            .get("patternProperties")
+            .and_then(JsonValue::as_object);
+        let additional_properties = schema.get("additionalProperties");
+
+        let mut names = Vec::new();
+        for member in object.json_member_list().iter().flatten() {
+            let (Ok(name), Ok(value)) = (member.name(), member.value()) else {
+                continue;
+            };
+            let Ok(text) = name.inner_string_text() else {
+                continue;
+            };
+            let name_text = JsonString::from(text);
+
+            let mut evaluated = false;
+            if let Some(property_schema) =
+                properties.and_then(|properties| properties.get(name_text.as_str()))
+            {
+                evaluated = true;
+                self.validate_value(document, property_schema, &value);
+            }
+
+            if let Some(pattern_properties) = pattern_properties {
+                for (pattern, property_schema) in pattern_properties.iter() {
+                    if self.is_match(pattern.as_str(), name_text.as_str()) {
+                        evaluated = true;
+                        self.validate_value(document, property_schema, &value);
+                    }
+                }
+            }
+
+            if !evaluated {
+                match additional_properties {
+                    Some(JsonValue::Bool(false)) => self.report(
+                        name.range(),
+                        ViolationKind::UnexpectedProperty {
+                            name: name_text.as_str().into(),
+                        },
+                    ),
+                    Some(additional_properties) => {
+                        self.validate_value(document, additional_properties, &value);
+                    }
+                    None => {}
+                }
+            }
+
+            names.push(name_text);
+        }
+
+        let opening_range = object
+            .l_curly_token()
+            .map_or_else(|_| object.range(), |token| token.text_trimmed_range());
+
+        if let Some(required) = schema.get("required").and_then(JsonValue::as_array) {
+            for name in required.iter().filter_map(JsonValue::as_string) {
+                if !names.contains(name) {
+                    self.report(
+                        opening_range,
+                        ViolationKind::MissingProperty {
+                            name: name.as_str().into(),
+                        },
+                    );
+                }
+            }
+        }
+
+        let count = names.len() as f64;
+        if let Some(min) = get_number(schema, "minProperties") {
+            if count < min {
+                self.report(opening_range, ViolationKind::TooFewProperties { min });
+            }
+        }
+        if let Some(max) = get_number(schema, "maxProperties") {
+            if count > max {
+                self.report(opening_range, ViolationKind::TooManyProperties { max });
+            }
+        }
+    }
+
+    fn validate_array(
+        &mut self,
+        document: &'a Utf8Path,
+        schema: &'a JsonObject,
+        array: &JsonArrayValue,
+    ) {
+        let items: Vec<AnyJsonValue> = array.elements().iter().flatten().collect();
+
+        // Draft 2020-12 uses `prefixItems` for tuples, while older drafts use
+        // an array in `items`, in which case `additionalItems` validates the
+        // remaining items.
+        let (prefix_items, rest_items) =
+            if let Some(prefix_items) = schema.get("prefixItems").and_then(JsonValue::as_array) {
+                (prefix_items.as_slice(), schema.get("items"))
+            } else if let Some(items) = schema.get("items").and_then(JsonValue::as_array) {
+                (items.as_slice(), schema.get("additionalItems"))
+            } else {
+                (&[][..], schema.get("items"))
+            };
+
+        for (index, item) in items.iter().enumerate() {
+            if let Some(item_schema) = prefix_items.get(index).or(rest_items) {
+                self.validate_value(document, item_schema, item);
+            }
+        }
+
+        let count = items.len() as f64;
+        if let Some(min) = get_number(schema, "minItems") {
+            if count < min {
+                self.report(array.range(), ViolationKind::TooFewItems { min });
+            }
+        }
+        if let Some(max) = get_number(schema, "maxItems") {
+            if count > max {
+                self.report(array.range(), ViolationKind::TooManyItems { max });
+            }
+        }
+
+        if schema.get("uniqueItems").and_then(JsonValue::as_bool) == Some(true) {
+            let values: Vec<JsonValue> = items.iter().cloned().map(JsonValue::from).collect();
+            for (index, value) in values.iter().enumerate() {
+                if values[..index].contains(value) {
+                    self.report(items[index].range(), ViolationKind::DuplicateItem);
+                }
+            }
+        }
+
+        if let Some(contains) = schema.get("contains") {
+            if !items
+                .iter()
+                .any(|item| self.matches(document, contains, item))
+            {
+                self.report(array.range(), ViolationKind::MissingContainedItem);
+            }
+        }
+    }
+
+    fn validate_string(&mut self, schema: &'a JsonObject, string: &str, range: TextRange) {
+        let length = string.chars().count() as f64;
+        if let Some(min) = get_number(schema, "minLength") {
+            if length < min {
+                self.report(range, ViolationKind::TooShort { min });
+            }
+        }
+        if let Some(max) = get_number(schema, "maxLength") {
+            if length > max {
+                self.report(range, ViolationKind::TooLong { max });
+            }
+        }
+
+        if let Some(pattern) = get_string(schema, "pattern") {
+            if !self.is_match(pattern.as_str(), string) {
+                self.report(
+                    range,
+                    ViolationKind::PatternMismatch {
+                        pattern: pattern.as_str().into(),
+                    },
+                );
+            }
+        }
+    }
+
+    fn validate_number(&mut self, schema: &'a JsonObject, number: f64, range: TextRange) {
+        // Draft 4 uses booleans for `exclusiveMinimum` and `exclusiveMaximum`,
+        // which modify `minimum` and `maximum`. Later drafts use numbers.
+        let exclusive_minimum = schema.get("exclusiveMinimum");
+        if let Some(min) = get_number(schema, "minimum") {
+            let exclusive = exclusive_minimum.and_then(JsonValue::as_bool) == Some(true);
+            if number < min || (exclusive && number == min) {
+                self.report(range, ViolationKind::BelowMinimum { min, exclusive });
+            }
+        }
+        if let Some(min) = exclusive_minimum.and_then(JsonValue::as_number) {
+            if number <= min {
+                self.report(
+                    range,
+                    ViolationKind::BelowMinimum {
+                        min,
+                        exclusive: true,
+                    },
+                );
+            }
+        }
+
+        let exclusive_maximum = schema.get("exclusiveMaximum");
+        if let Some(max) = get_number(schema, "maximum") {
+            let exclusive = exclusive_maximum.and_then(JsonValue::as_bool) == Some(true);
+            if number > max || (exclusive && number == max) {
+                self.report(range, ViolationKind::AboveMaximum { max, exclusive });
+            }
+        }
+        if let Some(max) = exclusive_maximum.and_then(JsonValue::as_number) {
+            if number >= max {
+                self.report(
+                    range,
+                    ViolationKind::AboveMaximum {
+                        max,
+                        exclusive: true,
+                    },
+                );
+            }
+        }
+
+        if let Some(divisor) = get_number(schema, "multipleOf") {
+            let quotient = number / divisor;
+            if divisor > 0.0 && (quotient - quotient.round()).abs() > f64::EPSILON * quotient.abs()
+            {
+                self.report(range, ViolationKind::NotMultipleOf { divisor });
+            }
+        }
+    }
+
+    fn validate_combinators(
+        &mut self,
+        document: &'a Utf8Path,
+        schema: &'a JsonObject,
+        value: &AnyJsonValue,
+    ) {
+        if let Some(all_of) = schema.get("allOf").and_then(JsonValue::as_array) {
+            for sub_schema in all_of.iter() {
+                self.validate_value(document, sub_schema, value);
+            }
+        }
+
+        if let Some(any_of) = schema.get("anyOf").and_then(JsonValue::as_array) {
+            let mut candidates = Vec::new();
+            for sub_schema in any_of.iter() {
+                let violations = self.violations_of(document, sub_schema, value);
+                if violations.is_empty() {
+                    candidates.clear();
+                    break;
+                }
+                candidates.push(violations);
+            }
+            if !candidates.is_empty() {
+                self.report_no_matching_schema(value.range(), candidates);
+            }
+        }
+
+        if let Some(one_of) = schema.get("oneOf").and_then(JsonValue::as_array) {
+            let mut candidates = Vec::new();
+            let mut matching = 0;
+            for sub_schema in one_of.iter() {
+                let violations = self.violations_of(document, sub_schema, value);
+                if violations.is_empty() {
+                    matching += 1;
+                } else {
+                    candidates.push(violations);
+                }
+            }
+            match matching {
+                0 => self.report_no_matching_schema(value.range(), candidates),
+                1 => {}
+                _ => self.report(value.range(), ViolationKind::MultipleMatchingSchemas),
+            }
+        }
+
+        if let Some(not) = schema.get("not") {
+            if self.matches(document, not, value) {
+                self.report(value.range(), ViolationKind::MatchesNegatedSchema);
+            }
+        }
+
+        if let Some(condition) = schema.get("if") {
+            let branch = if self.matches(document, condition, value) {
+                schema.get("then")
+            } else {
+                schema.get("else")
+            };
+            if let Some(branch) = branch {
+                self.validate_value(document, branch, value);
+            }
+        }
+    }
+
+    /// Returns whether `text` matches the regular expression `pattern`.
+    ///
+    /// Patterns that aren't supported by the `regex` crate are ignored, and
+    /// are considered to match everything.
+    fn is_match(&mut self, pattern: &'a str, text: &str) -> bool {
+        self.regexes
+            .entry(pattern)
+            .or_insert_with(|| Regex::new(pattern).ok())
+            .as_ref()
+            .is_none_or(|regex| regex.is_match(text))
+    }
+}
+
+fn get_number(schema: &JsonObject, key: &str) -> Option<f64> {
+    schema.get(key).and_then(JsonValue::as_number)
+}
+
+fn get_string<'a>(schema: &'a JsonObject, key: &str) -> Option<&'a JsonString> {
+    schema.get(key).and_then(JsonValue::as_string)
+}
+
+fn number_value(value: &AnyJsonValue) -> Option<f64> {
+    let AnyJsonValue::JsonNumberValue(number) = value else {
+        return None;
+    };
+    number.value_token().ok()?.text_trimmed().parse().ok()
+}
+
+/// Returns the name of the JSON Schema type of `value`.
+fn value_type(value: &AnyJsonValue) -> Option<&'static str> {
+    match value {
+        AnyJsonValue::JsonArrayValue(_) => Some("array"),
+        AnyJsonValue::JsonBooleanValue(_) => Some("boolean"),
+        AnyJsonValue::JsonNullValue(_) => Some("null"),
+        AnyJsonValue::JsonNumberValue(_) => Some("number"),
+        AnyJsonValue::JsonObjectValue(_) => Some("object"),
+        AnyJsonValue::JsonStringValue(_) => Some("string"),
+        AnyJsonValue::JsonBogusValue(_) | AnyJsonValue::JsonMetavariable(_) => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "validator.tests.rs"]
+mod tests;
@@ -0,0 +1,167 @@
+use biome_fs::MemoryFileSystem;
+use biome_json_parser::{JsonParserOptions, parse_json};
+use camino::Utf8PathBuf;
+
+use super::*;
+
+fn schema_from_str(schema: &str) -> JsonSchema {
+    let root = parse_json(schema, JsonParserOptions::default()).tree();
+    let path = Utf8PathBuf::from("/schema.json");
+    JsonSchema::from_documents(path.clone(), [(path, root.value().unwrap().into())])
+}
+
+fn validate(schema: &JsonSchema, document: &str) -> Vec<(String, String)> {
+    let root = parse_json(document, JsonParserOptions::default()).tree();
+    schema
+        .validate(&root.value().unwrap())
+        .into_iter()
+        .map(|violation| {
+            (
+                document[violation.range()].to_string(),
+                violation.kind().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_types_and_required_properties() {
+    let schema = schema_from_str(
+        r#"{
+            "type": "object",
+            "required": ["name", "version"],
+            "properties": {
+                "name": { "type": "string", "minLength": 1 },
+                "version": { "type": "string" },
+                "private": { "type": "boolean" },
+                "workers": { "type": "integer", "minimum": 1 }
+            },
+            "additionalProperties": false
+        }"#,
+    );
+
+    assert_eq!(
+        validate(
+            &schema,
+            r#"{ "name": "", "private": "yes", "workers": 1.5, "extra": 1 }"#
+        ),
+        vec![
+            (
+                r#""""#.to_string(),
+                "String must be at least 1 characters long.".to_string()
+            ),
+            (
+                r#""yes""#.to_string(),
+                "Incorrect type: expected boolean, but found string.".to_string()
+            ),
+            (
+                "1.5".to_string(),
+                "Incorrect type: expected integer, but found number.".to_string()
+            ),
+            (
+                r#""extra""#.to_string(),
+                r#"Property "extra" is not allowed."#.to_string()
+            ),
+            (
+                "{".to_string(),
+                r#"Missing required property "version"."#.to_string()
+            ),
+        ]
+    );
+    assert!(validate(&schema, r#"{ "name": "biome", "version": "2.0.0" }"#).is_empty());
+}
+
+#[test]
+fn test_local_references_and_enums() {
+    let schema = schema_from_str(
+        r##"{
+            "type": "array",
+            "items": { "$ref": "#/$defs/level" },
+            "uniqueItems": true,
+            "$defs": {
+                "level": { "enum": ["off", "warn", "error"] }
+            }
+        }"##,
+    );
+
+    assert_eq!(
+        validate(&schema, r#"["warn", "info", "warn"]"#),
+        vec![
+            (
+                r#""info""#.to_string(),
+                r#"Value must be one of "off", "warn", "error"."#.to_string()
+            ),
+            (
+                r#""warn""#.to_string(),
+                "Array items must be unique.".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_combinators() {
+    let schema = schema_from_str(
+        r#"{
+            "properties": {
+                "any": { "anyOf": [{ "type": "string" }, { "type": "number" }] },
+                "one": { "oneOf": [{ "type": "number" }, { "multipleOf": 2 }] },
+                "not": { "not": { "const": 0 } }
+            }
+        }"#,
+    );
+
+    assert_eq!(
+        validate(&schema, r#"{ "any": true, "one": 4, "not": 0 }"#),
+        vec![
+            (
+                "true".to_string(),
+                "Value doesn't match any of the allowed schemas.".to_string()
+            ),
+            (
+                "4".to_string(),
+                "Value matches more than one of the exclusive schemas.".to_string()
+            ),
+            (
+                "0".to_string(),
+                "Value matches a disallowed schema.".to_string()
+            ),
+        ]
+    );
+    assert!(validate(&schema, r#"{ "any": "text", "one": 3, "not": 1 }"#).is_empty());
+}
+
+#[test]
+fn test_recursive_reference_terminates() {
+    let schema = schema_from_str(r##"{ "$ref": "#" }"##);
+
+    assert!(validate(&schema, r#"{ "key": [1, 2, 3] }"#).is_empty());
+}
+
+#[test]
+fn test_load_with_file_references() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        "/project/schemas/config.json".into(),
+        r#"{
+            "properties": {
+                "level": { "$ref": "./definitions/level.json#/definitions/level" }
+            }
+        }"#,
+    );
+    fs.insert(
+        "/project/schemas/definitions/level.json".into(),
+        r#"{ "definitions": { "level": { "type": "string", "pattern": "^[a-z]+$" } } }"#,
+    );
+
+    let schema = JsonSchema::load(&fs, "/project/schemas/config.json".into()).unwrap();
+
+    assert!(schema.depends_on("/project/schemas/definitions/level.json".into()));
+    assert_eq!(
+        validate(&schema, r#"{ "level": "Error" }"#),
+        vec![(
+            r#""Error""#.to_string(),
+            r#"String doesn't match the pattern "^[a-z]+$"."#.to_string()
+        )]
+    );
+}
@@ -180,6 +180,41 @@ pub struct JsonMemberNameFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct JsonMetavariable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl JsonMetavariable {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> JsonMetavariableFields {
+        JsonMetavariableFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for JsonMetavariable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct JsonMetavariableFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct JsonNullValue {
     pub(crate) syntax: SyntaxNode,
 }
@@ -379,6 +414,7 @@ pub enum AnyJsonValue {
     JsonArrayValue(JsonArrayValue),
     JsonBogusValue(JsonBogusValue),
     JsonBooleanValue(JsonBooleanValue),
+    JsonMetavariable(JsonMetavariable),
     JsonNullValue(JsonNullValue),
     JsonNumberValue(JsonNumberValue),
     JsonObjectValue(JsonObjectValue),
@@ -403,6 +439,12 @@ impl AnyJsonValue {
             _ => None,
         }
     }
+    pub fn as_json_metavariable(&self) -> Option<&JsonMetavariable> {
+        match &self {
+            Self::JsonMetavariable(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_json_null_value(&self) -> Option<&JsonNullValue> {
         match &self {
             Self::JsonNullValue(item) => Some(item),
@@ -635,6 +677,56 @@ impl From<JsonMemberName> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for JsonMetavariable {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(JSON_METAVARIABLE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == JSON_METAVARIABLE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for JsonMetavariable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("JsonMetavariable")
+                .field(
+                    "value_token",
+                    &support::DebugSyntaxResult(self.value_token()),
+                )
+                .finish()
+        } else {
+            f.debug_struct("JsonMetavariable").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<JsonMetavariable> for SyntaxNode {
+    fn from(n: JsonMetavariable) -> Self {
+        n.syntax
+    }
+}
+impl From<JsonMetavariable> for SyntaxElement {
+    fn from(n: JsonMetavariable) -> Self {
+        n.syntax.into()
+    }
+}
 impl AstNode for JsonNullValue {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -907,6 +999,11 @@ impl From<JsonBooleanValue> for AnyJsonValue {
         Self::JsonBooleanValue(node)
     }
 }
+impl From<JsonMetavariable> for AnyJsonValue {
+    fn from(node: JsonMetavariable) -> Self {
+        Self::JsonMetavariable(node)
+    }
+}
 impl From<JsonNullValue> for AnyJsonValue {
     fn from(node: JsonNullValue) -> Self {
         Self::JsonNullValue(node)
@@ -932,6 +1029,7 @@ impl AstNode for AnyJsonValue {
     const KIND_SET: SyntaxKindSet<Language> = JsonArrayValue::KIND_SET
         .union(JsonBogusValue::KIND_SET)
         .union(JsonBooleanValue::KIND_SET)
+        .union(JsonMetavariable::KIND_SET)
         .union(JsonNullValue::KIND_SET)
         .union(JsonNumberValue::KIND_SET)
         .union(JsonObjectValue::KIND_SET)
@@ -942,6 +1040,7 @@ impl AstNode for AnyJsonValue {
             JSON_ARRAY_VALUE
                 | JSON_BOGUS_VALUE
                 | JSON_BOOLEAN_VALUE
+                | JSON_METAVARIABLE
                 | JSON_NULL_VALUE
                 | JSON_NUMBER_VALUE
                 | JSON_OBJECT_VALUE
@@ -953,6 +1052,7 @@ impl AstNode for AnyJsonValue {
             JSON_ARRAY_VALUE => Self::JsonArrayValue(JsonArrayValue { syntax }),
             JSON_BOGUS_VALUE => Self::JsonBogusValue(JsonBogusValue { syntax }),
             JSON_BOOLEAN_VALUE => Self::JsonBooleanValue(JsonBooleanValue { syntax }),
+            JSON_METAVARIABLE => Self::JsonMetavariable(JsonMetavariable { syntax }),
             JSON_NULL_VALUE => Self::JsonNullValue(JsonNullValue { syntax }),
             JSON_NUMBER_VALUE => Self::JsonNumberValue(JsonNumberValue { syntax }),
             JSON_OBJECT_VALUE => Self::JsonObjectValue(JsonObjectValue { syntax }),
@@ -966,6 +1066,7 @@ impl AstNode for AnyJsonValue {
             Self::JsonArrayValue(it) => &it.syntax,
             Self::JsonBogusValue(it) => &it.syntax,
             Self::JsonBooleanValue(it) => &it.syntax,
+            Self::JsonMetavariable(it) => &it.syntax,
             Self::JsonNullValue(it) => &it.syntax,
             Self::JsonNumberValue(it) => &it.syntax,
             Self::JsonObjectValue(it) => &it.syntax,
@@ -977,6 +1078,7 @@ impl AstNode for AnyJsonValue {
             Self::JsonArrayValue(it) => it.syntax,
             Self::JsonBogusValue(it) => it.syntax,
             Self::JsonBooleanValue(it) => it.syntax,
+            Self::JsonMetavariable(it) => it.syntax,
             Self::JsonNullValue(it) => it.syntax,
             Self::JsonNumberValue(it) => it.syntax,
             Self::JsonObjectValue(it) => it.syntax,
@@ -990,6 +1092,7 @@ impl std::fmt::Debug for AnyJsonValue {
             Self::JsonArrayValue(it) => std::fmt::Debug::fmt(it, f),
             Self::JsonBogusValue(it) => std::fmt::Debug::fmt(it, f),
             Self::JsonBooleanValue(it) => std::fmt::Debug::fmt(it, f),
+            Self::JsonMetavariable(it) => std::fmt::Debug::fmt(it, f),
             Self::JsonNullValue(it) => std::fmt::Debug::fmt(it, f),
             Self::JsonNumberValue(it) => std::fmt::Debug::fmt(it, f),
             Self::JsonObjectValue(it) => std::fmt::Debug::fmt(it, f),
@@ -1003,6 +1106,7 @@ impl From<AnyJsonValue> for SyntaxNode {
             AnyJsonValue::JsonArrayValue(it) => it.into(),
             AnyJsonValue::JsonBogusValue(it) => it.into(),
             AnyJsonValue::JsonBooleanValue(it) => it.into(),
+            AnyJsonValue::JsonMetavariable(it) => it.into(),
             AnyJsonValue::JsonNullValue(it) => it.into(),
             AnyJsonValue::JsonNumberValue(it) => it.into(),
             AnyJsonValue::JsonObjectValue(it) => it.into(),
@@ -1041,6 +1145,11 @@ impl std::fmt::Display for JsonMemberName {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for JsonMetavariable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for JsonNullValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
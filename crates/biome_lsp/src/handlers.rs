@@ -1,3 +1,4 @@
 pub(crate) mod analysis;
 pub(crate) mod formatting;
+pub(crate) mod navigation;
 pub(crate) mod text_document;
@@ -0,0 +1,285 @@
+#![expect(clippy::mutable_key_type)]
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use crate::utils;
+use anyhow::Context;
+use biome_fs::BiomePath;
+use biome_lsp_converters::line_index::LineIndex;
+use biome_lsp_converters::{PositionEncoding, from_proto, to_proto};
+use biome_service::WorkspaceError;
+use biome_service::projects::ProjectKey;
+use biome_service::workspace::{FindReferencesParams, GetFileContentParams, SymbolLocation};
+use std::collections::HashMap;
+use tower_lsp_server::UriExt;
+use tower_lsp_server::lsp_types::*;
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn hover(session: &Session, params: HoverParams) -> Result<Option<Hover>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in {}", url.as_str()))?;
+
+    let result = session
+        .workspace
+        .hover(biome_service::workspace::HoverParams {
+            project_key: doc.project_key,
+            path,
+            symbol_at: offset,
+        });
+    let result = match result {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let Some(contents) = result.contents else {
+        return Ok(None);
+    };
+
+    let range = result
+        .range
+        .map(|range| to_proto::range(&doc.line_index, range, position_encoding))
+        .transpose()?;
+
+    Ok(Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: contents,
+        }),
+        range,
+    }))
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn goto_definition(
+    session: &Session,
+    params: GotoDefinitionParams,
+) -> Result<Option<GotoDefinitionResponse>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in {}", url.as_str()))?;
+
+    let result =
+        session
+            .workspace
+            .goto_definition(biome_service::workspace::GotoDefinitionParams {
+                project_key: doc.project_key,
+                path,
+                symbol_at: offset,
+            });
+    let result = match result {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let locations = to_locations(
+        session,
+        doc.project_key,
+        result.definitions,
+        position_encoding,
+    )?;
+    if locations.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(GotoDefinitionResponse::Array(locations)))
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn references(
+    session: &Session,
+    params: ReferenceParams,
+) -> Result<Option<Vec<Location>>, LspError> {
+    let url = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in {}", url.as_str()))?;
+
+    let result = session.workspace.find_references(FindReferencesParams {
+        project_key: doc.project_key,
+        path,
+        symbol_at: offset,
+        include_declaration: params.context.include_declaration,
+    });
+    let result = match result {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let locations = to_locations(
+        session,
+        doc.project_key,
+        result.references,
+        position_encoding,
+    )?;
+
+    Ok(Some(locations))
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn prepare_rename(
+    session: &Session,
+    params: TextDocumentPositionParams,
+) -> Result<Option<PrepareRenameResponse>, LspError> {
+    let url = params.text_document.uri;
+    let position = params.position;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in {}", url.as_str()))?;
+
+    // Only the symbols that can be found by their references can be renamed
+    let result = session.workspace.find_references(FindReferencesParams {
+        project_key: doc.project_key,
+        path: path.clone(),
+        symbol_at: offset,
+        include_declaration: true,
+    });
+    let result = match result {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let Some(reference) = result
+        .references
+        .into_iter()
+        .find(|reference| reference.path == path && reference.range.contains_inclusive(offset))
+    else {
+        return Ok(None);
+    };
+
+    let range = to_proto::range(&doc.line_index, reference.range, position_encoding)?;
+    Ok(Some(PrepareRenameResponse::Range(range)))
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn rename(
+    session: &Session,
+    params: RenameParams,
+) -> Result<Option<WorkspaceEdit>, LspError> {
+    let url = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in {}", url.as_str()))?;
+
+    let result = session
+        .workspace
+        .rename(biome_service::workspace::RenameParams {
+            project_key: doc.project_key,
+            path,
+            symbol_at: offset,
+            new_name: params.new_name,
+        });
+    let result = match result {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut changes = HashMap::new();
+    if !result.indels.is_empty() {
+        let edits = utils::text_edit(&doc.line_index, result.indels, position_encoding, None)?;
+        changes.insert(url, edits);
+    }
+
+    // The other modules don't need to be open in the editor
+    for module_edit in result.module_edits {
+        let Some(url) = Uri::from_file_path(module_edit.path.as_path()) else {
+            continue;
+        };
+        let line_index = line_index(session, doc.project_key, &url, &module_edit.path)?;
+        let edits = utils::text_edit(&line_index, module_edit.indels, position_encoding, None)?;
+        changes.insert(url, edits);
+    }
+
+    Ok(Some(WorkspaceEdit {
+        changes: Some(changes),
+        ..Default::default()
+    }))
+}
+
+/// Converts the locations returned by the workspace into LSP locations.
+///
+/// The files of the locations don't need to be open in the editor, in which
+/// case their content is retrieved from the workspace.
+fn to_locations(
+    session: &Session,
+    project_key: ProjectKey,
+    locations: Vec<SymbolLocation>,
+    position_encoding: PositionEncoding,
+) -> Result<Vec<Location>, LspError> {
+    let mut result = Vec::with_capacity(locations.len());
+    let mut current: Option<(Uri, LineIndex)> = None;
+    for location in locations {
+        let Some(url) = Uri::from_file_path(location.path.as_path()) else {
+            continue;
+        };
+
+        // Locations are grouped by file, so the line index of the previous
+        // location can be reused most of the time.
+        if current
+            .as_ref()
+            .is_none_or(|(current_url, _)| *current_url != url)
+        {
+            let line_index = line_index(session, project_key, &url, &location.path)?;
+            current = Some((url.clone(), line_index));
+        }
+
+        let Some((_, line_index)) = &current else {
+            continue;
+        };
+        let range = to_proto::range(line_index, location.range, position_encoding)?;
+        result.push(Location::new(url, range));
+    }
+
+    Ok(result)
+}
+
+/// Returns the line index of the document at `url`, which is retrieved from
+/// the workspace if the document isn't open in the editor.
+fn line_index(
+    session: &Session,
+    project_key: ProjectKey,
+    url: &Uri,
+    path: &BiomePath,
+) -> Result<LineIndex, LspError> {
+    let line_index = match session.document(url) {
+        Some(doc) => doc.line_index,
+        None => {
+            let content = session.workspace.get_file_content(GetFileContentParams {
+                project_key,
+                path: path.clone(),
+            })?;
+            LineIndex::new(&content)
+        }
+    };
+
+    Ok(line_index)
+}
@@ -121,7 +121,7 @@ pub(crate) async fn did_change(
     debug!("old document: {:?}", old_text);
     debug!("content changes: {:?}", params.content_changes);
 
-    let text = apply_document_changes(
+    let (text, edits) = apply_document_changes(
         session.position_encoding(),
         old_text,
         params.content_changes,
@@ -136,6 +136,7 @@ pub(crate) async fn did_change(
         path,
         version,
         content: text,
+        edits,
     })?;
 
     if let Err(err) = session.update_diagnostics(url).await {
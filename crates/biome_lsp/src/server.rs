@@ -490,6 +490,52 @@ impl LanguageServer for LSPServer {
 
         self.map_op_error(result).await
     }
+
+    async fn hover(&self, params: HoverParams) -> LspResult<Option<Hover>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::hover(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> LspResult<Option<GotoDefinitionResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::goto_definition(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn references(&self, params: ReferenceParams) -> LspResult<Option<Vec<Location>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::references(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> LspResult<Option<PrepareRenameResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::prepare_rename(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn rename(&self, params: RenameParams) -> LspResult<Option<WorkspaceEdit>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::rename(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
 }
 
 impl Drop for LSPServer {
@@ -670,6 +716,7 @@ impl ServerFactory {
         workspace_method!(builder, change_file);
         workspace_method!(builder, check_file_size);
         workspace_method!(builder, get_file_content);
+        workspace_method!(builder, get_module_dependencies);
         workspace_method!(builder, close_file);
         workspace_method!(builder, pull_diagnostics);
         workspace_method!(builder, pull_actions);
@@ -678,6 +725,9 @@ impl ServerFactory {
         workspace_method!(builder, format_on_type);
         workspace_method!(builder, fix_file);
         workspace_method!(builder, rename);
+        workspace_method!(builder, hover);
+        workspace_method!(builder, goto_definition);
+        workspace_method!(builder, find_references);
         workspace_method!(builder, parse_pattern);
         workspace_method!(builder, search_pattern);
         workspace_method!(builder, drop_pattern);
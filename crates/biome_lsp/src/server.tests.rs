@@ -33,8 +33,8 @@ use tower_lsp_server::lsp_types::{
     DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
     DocumentFormattingParams, FormattingOptions, InitializeParams, InitializeResult,
     InitializedParams, Position, PublishDiagnosticsParams, Range, TextDocumentContentChangeEvent,
-    TextDocumentIdentifier, TextDocumentItem, TextEdit, Uri, VersionedTextDocumentIdentifier,
-    WorkDoneProgressParams, WorkspaceFolder,
+    TextDocumentIdentifier, TextDocumentItem, TextDocumentPositionParams, TextEdit, Uri,
+    VersionedTextDocumentIdentifier, WorkDoneProgressParams, WorkspaceFolder,
 };
 
 use crate::WorkspaceSettings;
@@ -865,6 +865,170 @@ async fn document_range_formatting() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn navigate_to_symbols() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("const answer = 42;\nconsole.log(answer);\n")
+        .await?;
+
+    let position = TextDocumentPositionParams {
+        text_document: TextDocumentIdentifier {
+            uri: uri!("document.js"),
+        },
+        position: Position::new(1, 14),
+    };
+    let declaration = lsp::Location::new(
+        uri!("document.js"),
+        Range::new(Position::new(0, 6), Position::new(0, 12)),
+    );
+    let reference = lsp::Location::new(
+        uri!("document.js"),
+        Range::new(Position::new(1, 12), Position::new(1, 18)),
+    );
+
+    let hover: lsp::Hover = server
+        .request(
+            "textDocument/hover",
+            "hover",
+            lsp::HoverParams {
+                text_document_position_params: position.clone(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?
+        .context("hover returned None")?;
+    assert_eq!(
+        hover.contents,
+        lsp::HoverContents::Markup(lsp::MarkupContent {
+            kind: lsp::MarkupKind::Markdown,
+            value: String::from("```ts\nvalue: 42\n```"),
+        })
+    );
+    assert_eq!(hover.range, Some(reference.range));
+
+    let definition: lsp::GotoDefinitionResponse = server
+        .request(
+            "textDocument/definition",
+            "definition",
+            lsp::GotoDefinitionParams {
+                text_document_position_params: position.clone(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("definition returned None")?;
+    assert_eq!(
+        definition,
+        lsp::GotoDefinitionResponse::Array(vec![declaration.clone()])
+    );
+
+    let references: Vec<lsp::Location> = server
+        .request(
+            "textDocument/references",
+            "references",
+            lsp::ReferenceParams {
+                text_document_position: position,
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+                context: lsp::ReferenceContext {
+                    include_declaration: true,
+                },
+            },
+        )
+        .await?
+        .context("references returned None")?;
+    assert_eq!(references, vec![declaration, reference]);
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rename_symbol() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("const answer = 42;\nconsole.log(answer);\n")
+        .await?;
+
+    let position = TextDocumentPositionParams {
+        text_document: TextDocumentIdentifier {
+            uri: uri!("document.js"),
+        },
+        position: Position::new(1, 14),
+    };
+
+    let range: lsp::PrepareRenameResponse = server
+        .request(
+            "textDocument/prepareRename",
+            "prepare_rename",
+            position.clone(),
+        )
+        .await?
+        .context("prepareRename returned None")?;
+    assert_eq!(
+        range,
+        lsp::PrepareRenameResponse::Range(Range::new(Position::new(1, 12), Position::new(1, 18)))
+    );
+
+    let edit: lsp::WorkspaceEdit = server
+        .request(
+            "textDocument/rename",
+            "rename",
+            lsp::RenameParams {
+                text_document_position: position,
+                new_name: String::from("question"),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?
+        .context("rename returned None")?;
+    assert_eq!(
+        edit.changes,
+        Some(HashMap::from([(
+            uri!("document.js"),
+            vec![
+                TextEdit {
+                    range: Range::new(Position::new(0, 6), Position::new(0, 12)),
+                    new_text: String::from("question"),
+                },
+                TextEdit {
+                    range: Range::new(Position::new(1, 12), Position::new(1, 18)),
+                    new_text: String::from("question"),
+                },
+            ]
+        )]))
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn pull_diagnostics() -> Result<()> {
     let factory = ServerFactory::default();
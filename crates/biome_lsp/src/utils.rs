@@ -12,7 +12,7 @@ use biome_diagnostics::{
 use biome_lsp_converters::line_index::LineIndex;
 use biome_lsp_converters::{PositionEncoding, from_proto, to_proto};
 use biome_rowan::{TextRange, TextSize};
-use biome_service::workspace::CodeAction;
+use biome_service::workspace::{ChangeFileEdit, CodeAction};
 use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
 use std::any::Any;
 use std::borrow::Cow;
@@ -358,11 +358,16 @@ pub(crate) fn panic_to_lsp_error(err: Box<dyn Any + Send>) -> LspError {
     error
 }
 
+/// Applies the changes of a `textDocument/didChange` notification to the
+/// content of a document.
+///
+/// Returns the new content, along with the edits that produced it. The edits
+/// are empty if one of the changes replaced the whole document.
 pub(crate) fn apply_document_changes(
     position_encoding: PositionEncoding,
     current_content: String,
     mut content_changes: Vec<lsp_types::TextDocumentContentChangeEvent>,
-) -> String {
+) -> (String, Vec<ChangeFileEdit>) {
     // Skip to the last full document change, as it invalidates all previous changes anyways.
     let mut start = content_changes
         .iter()
@@ -380,14 +385,17 @@ pub(crate) fn apply_document_changes(
 
             // The only change is a full document update
             if start == content_changes.len() {
-                return text;
+                return (text, Vec::new());
             }
             text
         }
         Some(_) => current_content,
         // we received no content changes
-        None => return current_content,
+        None => return (current_content, Vec::new()),
     };
+    // The edits are relative to the previous content of the document, so
+    // they're meaningless after a full document update.
+    let mut edits = (start == 0).then(Vec::new);
 
     let mut line_index = LineIndex::new(&text);
 
@@ -405,10 +413,16 @@ pub(crate) fn apply_document_changes(
             index_valid = range.start.line;
             if let Ok(range) = from_proto::text_range(&line_index, range, position_encoding) {
                 text.replace_range(Range::<usize>::from(range), &change.text);
+                if let Some(edits) = edits.as_mut() {
+                    edits.push(ChangeFileEdit {
+                        range,
+                        text: change.text,
+                    });
+                }
             }
         }
     }
-    text
+    (text, edits.unwrap_or_default())
 }
 
 #[cfg(test)]
@@ -542,7 +556,7 @@ line 7 new";
             text: String::new(),
         };
 
-        let output = apply_document_changes(encoding, input, vec![change]);
+        let (output, _) = apply_document_changes(encoding, input, vec![change]);
         let expected = "(\"Jan 1, 2018\u{2009}–\u{2009}Jan 1, 2019\");(\"Jan 1, 2018\u{2009}–\u{2009}Jan 1, 2019\");\nisSpreadAssignment;\n";
 
         assert_eq!(output, expected);
@@ -6,16 +6,11 @@ use biome_markdown_syntax::{
     MarkdownSyntaxToken as SyntaxToken, *,
 };
 use biome_rowan::AstNode;
-pub fn md_bullet(
-    bullet_token: SyntaxToken,
-    space_token: SyntaxToken,
-    content: MdInlineItemList,
-) -> MdBullet {
+pub fn md_bullet(bullet_token: SyntaxToken, content: MdBlockList) -> MdBullet {
     MdBullet::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_BULLET,
         [
             Some(SyntaxElement::Token(bullet_token)),
-            Some(SyntaxElement::Token(space_token)),
             Some(SyntaxElement::Node(content.into_syntax())),
         ],
     ))
@@ -56,23 +51,42 @@ impl MdDocumentBuilder {
 }
 pub fn md_fenced_code_block(
     l_fence_token: SyntaxToken,
-    code_list: MdCodeNameList,
-    l_hard_line: MdHardLine,
-    content: MdTextual,
-    r_hard_line: MdHardLine,
-    r_fence_token: SyntaxToken,
-) -> MdFencedCodeBlock {
-    MdFencedCodeBlock::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_FENCED_CODE_BLOCK,
-        [
-            Some(SyntaxElement::Token(l_fence_token)),
-            Some(SyntaxElement::Node(code_list.into_syntax())),
-            Some(SyntaxElement::Node(l_hard_line.into_syntax())),
-            Some(SyntaxElement::Node(content.into_syntax())),
-            Some(SyntaxElement::Node(r_hard_line.into_syntax())),
-            Some(SyntaxElement::Token(r_fence_token)),
-        ],
-    ))
+    content: MdInlineItemList,
+) -> MdFencedCodeBlockBuilder {
+    MdFencedCodeBlockBuilder {
+        l_fence_token,
+        content,
+        info: None,
+        r_fence_token: None,
+    }
+}
+pub struct MdFencedCodeBlockBuilder {
+    l_fence_token: SyntaxToken,
+    content: MdInlineItemList,
+    info: Option<MdTextual>,
+    r_fence_token: Option<SyntaxToken>,
+}
+impl MdFencedCodeBlockBuilder {
+    pub fn with_info(mut self, info: MdTextual) -> Self {
+        self.info = Some(info);
+        self
+    }
+    pub fn with_r_fence_token(mut self, r_fence_token: SyntaxToken) -> Self {
+        self.r_fence_token = Some(r_fence_token);
+        self
+    }
+    pub fn build(self) -> MdFencedCodeBlock {
+        MdFencedCodeBlock::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_FENCED_CODE_BLOCK,
+            [
+                Some(SyntaxElement::Token(self.l_fence_token)),
+                self.info
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.content.into_syntax())),
+                self.r_fence_token.map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
 }
 pub fn md_hard_line(value_token: SyntaxToken) -> MdHardLine {
     MdHardLine::unwrap_cast(SyntaxNode::new_detached(
@@ -115,16 +129,10 @@ impl MdHeaderBuilder {
         ))
     }
 }
-pub fn md_html_block(md_textual: MdTextual) -> MdHtmlBlock {
+pub fn md_html_block(content: MdInlineItemList) -> MdHtmlBlock {
     MdHtmlBlock::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_HTML_BLOCK,
-        [Some(SyntaxElement::Node(md_textual.into_syntax()))],
-    ))
-}
-pub fn md_indent(value_token: SyntaxToken) -> MdIndent {
-    MdIndent::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INDENT,
-        [Some(SyntaxElement::Token(value_token))],
+        [Some(SyntaxElement::Node(content.into_syntax()))],
     ))
 }
 pub fn md_indent_code_block(lines: MdIndentedCodeLineList) -> MdIndentCodeBlock {
@@ -133,13 +141,10 @@ pub fn md_indent_code_block(lines: MdIndentedCodeLineList) -> MdIndentCodeBlock
         [Some(SyntaxElement::Node(lines.into_syntax()))],
     ))
 }
-pub fn md_indented_code_line(indentation: MdIndent, content: MdTextual) -> MdIndentedCodeLine {
+pub fn md_indented_code_line(content: MdTextual) -> MdIndentedCodeLine {
     MdIndentedCodeLine::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_INDENTED_CODE_LINE,
-        [
-            Some(SyntaxElement::Node(indentation.into_syntax())),
-            Some(SyntaxElement::Node(content.into_syntax())),
-        ],
+        [Some(SyntaxElement::Node(content.into_syntax()))],
     ))
 }
 pub fn md_inline_code(
@@ -171,87 +176,23 @@ pub fn md_inline_emphasis(
     ))
 }
 pub fn md_inline_image(
-    l_brack_token: SyntaxToken,
     excl_token: SyntaxToken,
-    alt: MdInlineImageAlt,
-    source: MdInlineImageSource,
-    r_brack_token: SyntaxToken,
-) -> MdInlineImageBuilder {
-    MdInlineImageBuilder {
-        l_brack_token,
-        excl_token,
-        alt,
-        source,
-        r_brack_token,
-        link: None,
-    }
-}
-pub struct MdInlineImageBuilder {
     l_brack_token: SyntaxToken,
-    excl_token: SyntaxToken,
-    alt: MdInlineImageAlt,
-    source: MdInlineImageSource,
+    alt: MdInlineItemList,
     r_brack_token: SyntaxToken,
-    link: Option<MdInlineImageLink>,
-}
-impl MdInlineImageBuilder {
-    pub fn with_link(mut self, link: MdInlineImageLink) -> Self {
-        self.link = Some(link);
-        self
-    }
-    pub fn build(self) -> MdInlineImage {
-        MdInlineImage::unwrap_cast(SyntaxNode::new_detached(
-            MarkdownSyntaxKind::MD_INLINE_IMAGE,
-            [
-                Some(SyntaxElement::Token(self.l_brack_token)),
-                Some(SyntaxElement::Token(self.excl_token)),
-                Some(SyntaxElement::Node(self.alt.into_syntax())),
-                Some(SyntaxElement::Node(self.source.into_syntax())),
-                Some(SyntaxElement::Token(self.r_brack_token)),
-                self.link
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
-            ],
-        ))
-    }
-}
-pub fn md_inline_image_alt(
-    l_brack_token: SyntaxToken,
-    content: MdInlineItemList,
-    r_brack_token: SyntaxToken,
-) -> MdInlineImageAlt {
-    MdInlineImageAlt::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INLINE_IMAGE_ALT,
-        [
-            Some(SyntaxElement::Token(l_brack_token)),
-            Some(SyntaxElement::Node(content.into_syntax())),
-            Some(SyntaxElement::Token(r_brack_token)),
-        ],
-    ))
-}
-pub fn md_inline_image_link(
-    l_paren_token: SyntaxToken,
-    content: MdInlineItemList,
-    r_paren_token: SyntaxToken,
-) -> MdInlineImageLink {
-    MdInlineImageLink::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INLINE_IMAGE_LINK,
-        [
-            Some(SyntaxElement::Token(l_paren_token)),
-            Some(SyntaxElement::Node(content.into_syntax())),
-            Some(SyntaxElement::Token(r_paren_token)),
-        ],
-    ))
-}
-pub fn md_inline_image_source(
     l_paren_token: SyntaxToken,
-    content: MdInlineItemList,
+    source: MdInlineItemList,
     r_paren_token: SyntaxToken,
-) -> MdInlineImageSource {
-    MdInlineImageSource::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INLINE_IMAGE_SOURCE,
+) -> MdInlineImage {
+    MdInlineImage::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INLINE_IMAGE,
         [
+            Some(SyntaxElement::Token(excl_token)),
+            Some(SyntaxElement::Token(l_brack_token)),
+            Some(SyntaxElement::Node(alt.into_syntax())),
+            Some(SyntaxElement::Token(r_brack_token)),
             Some(SyntaxElement::Token(l_paren_token)),
-            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Node(source.into_syntax())),
             Some(SyntaxElement::Token(r_paren_token)),
         ],
     ))
@@ -290,6 +231,20 @@ pub fn md_inline_link(
         ],
     ))
 }
+pub fn md_inline_strikethrough(
+    l_fence_token: SyntaxToken,
+    content: MdInlineItemList,
+    r_fence_token: SyntaxToken,
+) -> MdInlineStrikethrough {
+    MdInlineStrikethrough::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INLINE_STRIKETHROUGH,
+        [
+            Some(SyntaxElement::Token(l_fence_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Token(r_fence_token)),
+        ],
+    ))
+}
 pub fn md_link_block(label: MdTextual, url: MdTextual) -> MdLinkBlockBuilder {
     MdLinkBlockBuilder {
         label,
@@ -325,25 +280,55 @@ pub fn md_order_list_item(md_bullet_list: MdBulletList) -> MdOrderListItem {
         [Some(SyntaxElement::Node(md_bullet_list.into_syntax()))],
     ))
 }
-pub fn md_paragraph(list: MdInlineItemList, hard_line: MdHardLine) -> MdParagraph {
+pub fn md_paragraph(list: MdInlineItemList) -> MdParagraph {
     MdParagraph::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_PARAGRAPH,
-        [
-            Some(SyntaxElement::Node(list.into_syntax())),
-            Some(SyntaxElement::Node(hard_line.into_syntax())),
-        ],
+        [Some(SyntaxElement::Node(list.into_syntax()))],
     ))
 }
-pub fn md_quote(any_md_block: AnyMdBlock) -> MdQuote {
+pub fn md_quote(lines: MdQuoteLineList) -> MdQuote {
     MdQuote::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_QUOTE,
-        [Some(SyntaxElement::Node(any_md_block.into_syntax()))],
+        [Some(SyntaxElement::Node(lines.into_syntax()))],
     ))
 }
-pub fn md_setext_header(md_paragraph: MdParagraph) -> MdSetextHeader {
+pub fn md_quote_line() -> MdQuoteLineBuilder {
+    MdQuoteLineBuilder {
+        marker_token: None,
+        content: None,
+    }
+}
+pub struct MdQuoteLineBuilder {
+    marker_token: Option<SyntaxToken>,
+    content: Option<MdTextual>,
+}
+impl MdQuoteLineBuilder {
+    pub fn with_marker_token(mut self, marker_token: SyntaxToken) -> Self {
+        self.marker_token = Some(marker_token);
+        self
+    }
+    pub fn with_content(mut self, content: MdTextual) -> Self {
+        self.content = Some(content);
+        self
+    }
+    pub fn build(self) -> MdQuoteLine {
+        MdQuoteLine::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_QUOTE_LINE,
+            [
+                self.marker_token.map(|token| SyntaxElement::Token(token)),
+                self.content
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn md_setext_header(content: MdParagraph, underline_token: SyntaxToken) -> MdSetextHeader {
     MdSetextHeader::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_SETEXT_HEADER,
-        [Some(SyntaxElement::Node(md_paragraph.into_syntax()))],
+        [
+            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Token(underline_token)),
+        ],
     ))
 }
 pub fn md_soft_break(value_token: SyntaxToken) -> MdSoftBreak {
@@ -352,6 +337,61 @@ pub fn md_soft_break(value_token: SyntaxToken) -> MdSoftBreak {
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
+pub fn md_table(header: MdTableRow, delimiter: MdTableRow, rows: MdTableRowList) -> MdTable {
+    MdTable::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE,
+        [
+            Some(SyntaxElement::Node(header.into_syntax())),
+            Some(SyntaxElement::Node(delimiter.into_syntax())),
+            Some(SyntaxElement::Node(rows.into_syntax())),
+        ],
+    ))
+}
+pub fn md_table_cell() -> MdTableCellBuilder {
+    MdTableCellBuilder { content: None }
+}
+pub struct MdTableCellBuilder {
+    content: Option<MdTextual>,
+}
+impl MdTableCellBuilder {
+    pub fn with_content(mut self, content: MdTextual) -> Self {
+        self.content = Some(content);
+        self
+    }
+    pub fn build(self) -> MdTableCell {
+        MdTableCell::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_TABLE_CELL,
+            [self
+                .content
+                .map(|token| SyntaxElement::Node(token.into_syntax()))],
+        ))
+    }
+}
+pub fn md_table_row(cells: MdTableCellList) -> MdTableRowBuilder {
+    MdTableRowBuilder {
+        cells,
+        l_pipe_token: None,
+    }
+}
+pub struct MdTableRowBuilder {
+    cells: MdTableCellList,
+    l_pipe_token: Option<SyntaxToken>,
+}
+impl MdTableRowBuilder {
+    pub fn with_l_pipe_token(mut self, l_pipe_token: SyntaxToken) -> Self {
+        self.l_pipe_token = Some(l_pipe_token);
+        self
+    }
+    pub fn build(self) -> MdTableRow {
+        MdTableRow::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_TABLE_ROW,
+            [
+                self.l_pipe_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Node(self.cells.into_syntax())),
+            ],
+        ))
+    }
+}
 pub fn md_textual(value_token: SyntaxToken) -> MdTextual {
     MdTextual::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_TEXTUAL,
@@ -388,27 +428,6 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_code_name_list<I, S>(items: I, separators: S) -> MdCodeNameList
-where
-    I: IntoIterator<Item = MdTextual>,
-    I::IntoIter: ExactSizeIterator,
-    S: IntoIterator<Item = MarkdownSyntaxToken>,
-    S::IntoIter: ExactSizeIterator,
-{
-    let mut items = items.into_iter();
-    let mut separators = separators.into_iter();
-    let length = items.len() + separators.len();
-    MdCodeNameList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_CODE_NAME_LIST,
-        (0..length).map(|index| {
-            if index % 2 == 0 {
-                Some(items.next()?.into_syntax().into())
-            } else {
-                Some(separators.next()?.into())
-            }
-        }),
-    ))
-}
 pub fn md_hash_list<I>(items: I) -> MdHashList
 where
     I: IntoIterator<Item = MdHash>,
@@ -445,13 +464,46 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_order_list<I>(items: I) -> MdOrderList
+pub fn md_quote_line_list<I>(items: I) -> MdQuoteLineList
+where
+    I: IntoIterator<Item = MdQuoteLine>,
+    I::IntoIter: ExactSizeIterator,
+{
+    MdQuoteLineList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_QUOTE_LINE_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn md_table_cell_list<I, S>(items: I, separators: S) -> MdTableCellList
+where
+    I: IntoIterator<Item = MdTableCell>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = MarkdownSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    MdTableCellList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE_CELL_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn md_table_row_list<I>(items: I) -> MdTableRowList
 where
-    I: IntoIterator<Item = AnyCodeBlock>,
+    I: IntoIterator<Item = MdTableRow>,
     I::IntoIter: ExactSizeIterator,
 {
-    MdOrderList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_ORDER_LIST,
+    MdTableRowList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE_ROW_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
@@ -17,24 +17,20 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             MD_BOGUS => RawSyntaxNode::new(kind, children.into_iter().map(Some)),
             MD_BULLET => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [-] | T ! [*]) {
+                    if matches!(
+                        element.kind(),
+                        T ! [-] | T ! [*] | T ! [+] | MD_ORDERED_LIST_MARKER
+                    ) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == MD_TEXTUAL_LITERAL {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if MdInlineItemList::can_cast(element.kind()) {
+                    if MdBlockList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -102,24 +98,10 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_FENCED_CODE_BLOCK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!["```"] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if MdCodeNameList::can_cast(element.kind()) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if MdHardLine::can_cast(element.kind()) {
+                    if element.kind() == MD_CODE_FENCE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -133,14 +115,14 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdHardLine::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!["```"] {
+                    if element.kind() == MD_CODE_FENCE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -227,7 +209,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -241,25 +223,6 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.into_node(MD_HTML_BLOCK, children)
             }
-            MD_INDENT => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
-                if let Some(element) = &current_element {
-                    if element.kind() == MD_INDENT_CHUNK_LITERAL {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        MD_INDENT.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(MD_INDENT, children)
-            }
             MD_INDENT_CODE_BLOCK => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
@@ -281,15 +244,8 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_INDENTED_CODE_LINE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
-                if let Some(element) = &current_element {
-                    if MdIndent::can_cast(element.kind()) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
                 if let Some(element) = &current_element {
                     if MdTextual::can_cast(element.kind()) {
                         slots.mark_present();
@@ -310,7 +266,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['`'] {
+                    if element.kind() == MD_CODE_SPAN_DELIMITER_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -324,7 +280,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['`'] {
+                    if element.kind() == MD_CODE_SPAN_DELIMITER_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -373,8 +329,15 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_INLINE_IMAGE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<7usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![!] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
                 if let Some(element) = &current_element {
                     if element.kind() == T!['['] {
                         slots.mark_present();
@@ -383,35 +346,35 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![!] {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdInlineImageAlt::can_cast(element.kind()) {
+                    if element.kind() == T![']'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdInlineImageSource::can_cast(element.kind()) {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![']'] {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdInlineImageLink::can_cast(element.kind()) {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -425,12 +388,12 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.into_node(MD_INLINE_IMAGE, children)
             }
-            MD_INLINE_IMAGE_ALT => {
+            MD_INLINE_ITALIC => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['['] {
+                    if matches!(element.kind(), T ! [*] | T!["_"]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -444,7 +407,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![']'] {
+                    if matches!(element.kind(), T ! [*] | T!["_"]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -452,18 +415,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE_ALT.to_bogus(),
+                        MD_INLINE_ITALIC.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_IMAGE_ALT, children)
+                slots.into_node(MD_INLINE_ITALIC, children)
             }
-            MD_INLINE_IMAGE_LINK => {
+            MD_INLINE_LINK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if element.kind() == T!['['] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -477,24 +440,12 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if element.kind() == T![']'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE_LINK.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(MD_INLINE_IMAGE_LINK, children)
-            }
-            MD_INLINE_IMAGE_SOURCE => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if element.kind() == T!['('] {
                         slots.mark_present();
@@ -518,18 +469,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE_SOURCE.to_bogus(),
+                        MD_INLINE_LINK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_IMAGE_SOURCE, children)
+                slots.into_node(MD_INLINE_LINK, children)
             }
-            MD_INLINE_ITALIC => {
+            MD_INLINE_STRIKETHROUGH => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [*] | T!["_"]) {
+                    if matches!(element.kind(), T ! [~] | T ! [~~]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -543,7 +494,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [*] | T!["_"]) {
+                    if matches!(element.kind(), T ! [~] | T ! [~~]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -551,44 +502,68 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_ITALIC.to_bogus(),
+                        MD_INLINE_STRIKETHROUGH.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_ITALIC, children)
+                slots.into_node(MD_INLINE_STRIKETHROUGH, children)
             }
-            MD_INLINE_LINK => {
+            MD_LINK_BLOCK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['['] {
+                    if MdTextual::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdInlineItemList::can_cast(element.kind()) {
+                    if MdTextual::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![']'] {
+                    if MdTextual::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_LINK_BLOCK.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_LINK_BLOCK, children)
+            }
+            MD_ORDER_LIST_ITEM => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if MdBulletList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_ORDER_LIST_ITEM.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_ORDER_LIST_ITEM, children)
+            }
+            MD_PARAGRAPH => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
@@ -596,27 +571,36 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                     }
                 }
                 slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_PARAGRAPH.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_PARAGRAPH, children)
+            }
+            MD_QUOTE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if MdQuoteLineList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        MD_INLINE_LINK.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
+                    return RawSyntaxNode::new(MD_QUOTE.to_bogus(), children.into_iter().map(Some));
                 }
-                slots.into_node(MD_INLINE_LINK, children)
+                slots.into_node(MD_QUOTE, children)
             }
-            MD_LINK_BLOCK => {
+            MD_QUOTE_LINE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == T ! [>] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -629,8 +613,27 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                     }
                 }
                 slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_QUOTE_LINE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_QUOTE_LINE, children)
+            }
+            MD_SETEXT_HEADER => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdParagraph::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == MD_SETEXT_UNDERLINE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -638,18 +641,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_LINK_BLOCK.to_bogus(),
+                        MD_SETEXT_HEADER.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_LINK_BLOCK, children)
+                slots.into_node(MD_SETEXT_HEADER, children)
             }
-            MD_ORDER_LIST_ITEM => {
+            MD_SOFT_BREAK => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdBulletList::can_cast(element.kind()) {
+                    if element.kind() == MD_SOFT_BREAK_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -657,60 +660,48 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_ORDER_LIST_ITEM.to_bogus(),
+                        MD_SOFT_BREAK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_ORDER_LIST_ITEM, children)
+                slots.into_node(MD_SOFT_BREAK, children)
             }
-            MD_PARAGRAPH => {
+            MD_TABLE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdInlineItemList::can_cast(element.kind()) {
+                    if MdTableRow::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdHardLine::can_cast(element.kind()) {
+                    if MdTableRow::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        MD_PARAGRAPH.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(MD_PARAGRAPH, children)
-            }
-            MD_QUOTE => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyMdBlock::can_cast(element.kind()) {
+                    if MdTableRowList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if current_element.is_some() {
-                    return RawSyntaxNode::new(MD_QUOTE.to_bogus(), children.into_iter().map(Some));
+                    return RawSyntaxNode::new(MD_TABLE.to_bogus(), children.into_iter().map(Some));
                 }
-                slots.into_node(MD_QUOTE, children)
+                slots.into_node(MD_TABLE, children)
             }
-            MD_SETEXT_HEADER => {
+            MD_TABLE_CELL => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdParagraph::can_cast(element.kind()) {
+                    if MdTextual::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -718,18 +709,25 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_SETEXT_HEADER.to_bogus(),
+                        MD_TABLE_CELL.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_SETEXT_HEADER, children)
+                slots.into_node(MD_TABLE_CELL, children)
             }
-            MD_SOFT_BREAK => {
+            MD_TABLE_ROW => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == MD_SOFT_BREAK_LITERAL {
+                    if element.kind() == T ! [|] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdTableCellList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -737,11 +735,11 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_SOFT_BREAK.to_bogus(),
+                        MD_TABLE_ROW.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_SOFT_BREAK, children)
+                slots.into_node(MD_TABLE_ROW, children)
             }
             MD_TEXTUAL => {
                 let mut elements = (&children).into_iter();
@@ -783,13 +781,6 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_BLOCK_LIST => Self::make_node_list_syntax(kind, children, AnyMdBlock::can_cast),
             MD_BULLET_LIST => Self::make_node_list_syntax(kind, children, MdBullet::can_cast),
-            MD_CODE_NAME_LIST => Self::make_separated_list_syntax(
-                kind,
-                children,
-                MdTextual::can_cast,
-                T ! [,],
-                false,
-            ),
             MD_HASH_LIST => Self::make_node_list_syntax(kind, children, MdHash::can_cast),
             MD_INDENTED_CODE_LINE_LIST => {
                 Self::make_node_list_syntax(kind, children, MdIndentedCodeLine::can_cast)
@@ -797,7 +788,17 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             MD_INLINE_ITEM_LIST => {
                 Self::make_node_list_syntax(kind, children, AnyMdInline::can_cast)
             }
-            MD_ORDER_LIST => Self::make_node_list_syntax(kind, children, AnyCodeBlock::can_cast),
+            MD_QUOTE_LINE_LIST => {
+                Self::make_node_list_syntax(kind, children, MdQuoteLine::can_cast)
+            }
+            MD_TABLE_CELL_LIST => Self::make_separated_list_syntax(
+                kind,
+                children,
+                MdTableCell::can_cast,
+                T ! [|],
+                true,
+            ),
+            MD_TABLE_ROW_LIST => Self::make_node_list_syntax(kind, children, MdTableRow::can_cast),
             _ => unreachable!("Is {:?} a token?", kind),
         }
     }
@@ -0,0 +1,48 @@
+use crate::MarkdownFormatContext;
+use biome_formatter::comments::{
+    CommentKind, CommentPlacement, CommentStyle, Comments, DecoratedComment, SourceComment,
+};
+use biome_formatter::prelude::*;
+use biome_formatter::{FormatResult, FormatRule, write};
+use biome_markdown_syntax::MarkdownLanguage;
+use biome_rowan::SyntaxTriviaPieceComments;
+
+pub type MarkdownComments = Comments<MarkdownLanguage>;
+
+/// Markdown doesn't have comments: HTML comments are part of the content of the document.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct MarkdownCommentStyle;
+
+impl CommentStyle for MarkdownCommentStyle {
+    type Language = MarkdownLanguage;
+
+    fn is_suppression(_text: &str) -> bool {
+        false
+    }
+
+    fn get_comment_kind(_comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind {
+        CommentKind::Block
+    }
+
+    fn place_comment(
+        &self,
+        comment: DecoratedComment<Self::Language>,
+    ) -> CommentPlacement<Self::Language> {
+        CommentPlacement::Default(comment)
+    }
+}
+
+#[derive(Default)]
+pub struct FormatMarkdownLeadingComment;
+
+impl FormatRule<SourceComment<MarkdownLanguage>> for FormatMarkdownLeadingComment {
+    type Context = MarkdownFormatContext;
+
+    fn fmt(
+        &self,
+        comment: &SourceComment<MarkdownLanguage>,
+        f: &mut Formatter<Self::Context>,
+    ) -> FormatResult<()> {
+        write!(f, [comment.piece().as_piece()])
+    }
+}
@@ -0,0 +1,160 @@
+use crate::comments::{FormatMarkdownLeadingComment, MarkdownCommentStyle, MarkdownComments};
+use biome_formatter::printer::PrinterOptions;
+use biome_formatter::{
+    CstFormatContext, FormatContext, FormatOptions, IndentStyle, IndentWidth, LineEnding,
+    LineWidth, TransformSourceMap,
+};
+use biome_markdown_syntax::{MarkdownFileSource, MarkdownLanguage};
+use biome_rowan::TextRange;
+use rustc_hash::FxHashMap;
+use std::fmt::Display;
+use std::rc::Rc;
+
+/// The formatted code of fenced code blocks, keyed by the range of the code in the document
+pub type FormattedCodeBlocks = FxHashMap<TextRange, String>;
+
+#[derive(Debug, Clone)]
+pub struct MarkdownFormatContext {
+    options: MarkdownFormatOptions,
+    comments: Rc<MarkdownComments>,
+    source_map: Option<TransformSourceMap>,
+    code_blocks: Rc<FormattedCodeBlocks>,
+}
+
+impl MarkdownFormatContext {
+    pub fn new(options: MarkdownFormatOptions, comments: MarkdownComments) -> Self {
+        Self {
+            options,
+            comments: Rc::new(comments),
+            source_map: None,
+            code_blocks: Rc::default(),
+        }
+    }
+
+    pub fn with_source_map(mut self, source_map: Option<TransformSourceMap>) -> Self {
+        self.source_map = source_map;
+        self
+    }
+
+    pub fn with_code_blocks(mut self, code_blocks: Rc<FormattedCodeBlocks>) -> Self {
+        self.code_blocks = code_blocks;
+        self
+    }
+
+    /// Returns the formatted code of the fenced code block whose code spans `range`, if any
+    pub fn formatted_code_block(&self, range: TextRange) -> Option<&str> {
+        self.code_blocks.get(&range).map(String::as_str)
+    }
+}
+
+impl FormatContext for MarkdownFormatContext {
+    type Options = MarkdownFormatOptions;
+
+    fn options(&self) -> &Self::Options {
+        &self.options
+    }
+
+    fn source_map(&self) -> Option<&TransformSourceMap> {
+        self.source_map.as_ref()
+    }
+}
+
+impl CstFormatContext for MarkdownFormatContext {
+    type Language = MarkdownLanguage;
+
+    type Style = MarkdownCommentStyle;
+
+    type CommentRule = FormatMarkdownLeadingComment;
+
+    fn comments(&self) -> &MarkdownComments {
+        &self.comments
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MarkdownFormatOptions {
+    indent_style: IndentStyle,
+    indent_width: IndentWidth,
+    line_ending: LineEnding,
+    line_width: LineWidth,
+    _file_source: MarkdownFileSource,
+}
+
+impl MarkdownFormatOptions {
+    pub fn new(file_source: MarkdownFileSource) -> Self {
+        Self {
+            _file_source: file_source,
+            indent_style: IndentStyle::default(),
+            indent_width: IndentWidth::default(),
+            line_ending: LineEnding::default(),
+            line_width: LineWidth::default(),
+        }
+    }
+
+    pub fn with_indent_style(mut self, indent_style: IndentStyle) -> Self {
+        self.indent_style = indent_style;
+        self
+    }
+
+    pub fn with_indent_width(mut self, indent_width: IndentWidth) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: LineWidth) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
+        self.indent_style = indent_style;
+    }
+
+    pub fn set_indent_width(&mut self, indent_width: IndentWidth) {
+        self.indent_width = indent_width;
+    }
+
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    pub fn set_line_width(&mut self, line_width: LineWidth) {
+        self.line_width = line_width;
+    }
+}
+
+impl Display for MarkdownFormatOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Indent style: {}", self.indent_style)?;
+        writeln!(f, "Indent width: {}", self.indent_width.value())?;
+        writeln!(f, "Line ending: {}", self.line_ending)?;
+        writeln!(f, "Line width: {}", self.line_width.value())
+    }
+}
+
+impl FormatOptions for MarkdownFormatOptions {
+    fn indent_style(&self) -> IndentStyle {
+        self.indent_style
+    }
+
+    fn indent_width(&self) -> IndentWidth {
+        self.indent_width
+    }
+
+    fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    fn as_print_options(&self) -> PrinterOptions {
+        PrinterOptions::from(self)
+    }
+}
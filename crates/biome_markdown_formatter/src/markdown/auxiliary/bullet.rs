@@ -0,0 +1,79 @@
+use crate::prelude::*;
+use crate::utils::list_sibling_index;
+use biome_formatter::write;
+use biome_markdown_syntax::{
+    AnyMdBlock, MarkdownSyntaxKind, MarkdownSyntaxToken, MdBullet, MdBulletFields, MdBulletList,
+};
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBullet;
+impl FormatNodeRule<MdBullet> for FormatMdBullet {
+    fn fmt_fields(&self, node: &MdBullet, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdBulletFields { bullet, content } = node.as_fields();
+        let bullet = bullet?;
+
+        let marker = list_item_marker(node, &bullet);
+        write!(
+            f,
+            [format_replaced(
+                &bullet,
+                &dynamic_text(&marker, bullet.text_trimmed_range().start())
+            )]
+        )?;
+
+        if !content.is_empty() {
+            // The content of the item is aligned with the first character after the marker
+            let width = u8::try_from(marker.len() + 1).unwrap_or(u8::MAX);
+            write!(f, [space(), align(width, &content.format())])?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the marker of a list item.
+///
+/// Bullet lists use `-`, and ordered lists use `.` after the number. Adjacent lists
+/// alternate with `*` and `)` respectively, so they remain distinct lists.
+/// Ordered lists are numbered from their first number. When the first two items
+/// have the same number, all the items keep it.
+fn list_item_marker(node: &MdBullet, bullet: &MarkdownSyntaxToken) -> String {
+    let list = node.parent::<MdBulletList>();
+    let sibling_index = list
+        .as_ref()
+        .and_then(|list| list.syntax().parent())
+        .and_then(AnyMdBlock::cast)
+        .map_or(0, |block| list_sibling_index(&block));
+    let is_alternate = sibling_index % 2 == 1;
+
+    if bullet.kind() != MarkdownSyntaxKind::MD_ORDERED_LIST_MARKER {
+        return if is_alternate { "*" } else { "-" }.to_string();
+    }
+
+    let mut numbers = list
+        .iter()
+        .flatten()
+        .filter_map(|bullet| bullet.bullet().ok())
+        .map(|bullet| marker_number(&bullet));
+    let start = numbers.next().unwrap_or_default();
+    let increment = match numbers.next() {
+        Some(second) if second == start => 0,
+        _ => 1,
+    };
+    let index = list
+        .iter()
+        .flatten()
+        .position(|bullet| &bullet == node)
+        .unwrap_or_default() as u64;
+
+    let delimiter = if is_alternate { ')' } else { '.' };
+    std::format!("{}{delimiter}", start + index * increment)
+}
+
+fn marker_number(marker: &MarkdownSyntaxToken) -> u64 {
+    marker
+        .text_trimmed()
+        .trim_end_matches(['.', ')'])
+        .parse()
+        .unwrap_or_default()
+}
@@ -0,0 +1,79 @@
+use crate::prelude::*;
+use crate::utils::{mark_formatted, write_code_lines};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdFencedCodeBlock, MdFencedCodeBlockFields, strip_indentation};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdFencedCodeBlock;
+impl FormatNodeRule<MdFencedCodeBlock> for FormatMdFencedCodeBlock {
+    fn fmt_fields(&self, node: &MdFencedCodeBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdFencedCodeBlockFields {
+            l_fence_token,
+            info,
+            content,
+            r_fence_token,
+        } = node.as_fields();
+        let l_fence_token = l_fence_token?;
+
+        write!(f, [l_fence_token.format(), info.format()])?;
+
+        mark_formatted(f, content.syntax());
+
+        if let Some(code_token) = node.code_token() {
+            let range = code_token.text_trimmed_range();
+            let formatted_code = f
+                .context()
+                .formatted_code_block(range)
+                .map(ToString::to_string);
+            let indentation = node.fence_indentation();
+            // The blank lines at the start of the code are in the leading trivia of the token
+            let leading_blank_lines = get_lines_before_token(&code_token).saturating_sub(1);
+
+            let code = format_with(|f| match &formatted_code {
+                Some(code) => write_code_lines(f, code.lines(), range.start()),
+                // The code is kept as it is, including the blank lines at its end
+                None => write_code_lines(
+                    f,
+                    std::iter::repeat_n("", leading_blank_lines).chain(
+                        code_token.text_trimmed().split('\n').map(|line| {
+                            strip_indentation(line.strip_suffix('\r').unwrap_or(line), indentation)
+                        }),
+                    ),
+                    range.start(),
+                ),
+            });
+            write!(f, [format_replaced(&code_token, &code)])?;
+        } else if let Some(r_fence_token) = &r_fence_token {
+            // The code only contains blank lines, which are in the leading trivia of the
+            // closing fence
+            let blank_lines = get_lines_before_token(r_fence_token).saturating_sub(1);
+            if blank_lines > 0 {
+                let position = r_fence_token.text_trimmed_range().start();
+                write!(f, [dynamic_text(&"\n".repeat(blank_lines + 1), position)])?;
+            }
+        }
+
+        // The closing fence is optional, and may be longer than the opening fence
+        let fence = l_fence_token.text_trimmed();
+        write!(f, [hard_line_break()])?;
+        match r_fence_token {
+            Some(r_fence_token) => {
+                let position = r_fence_token.text_trimmed_range().start();
+                write!(
+                    f,
+                    [format_replaced(
+                        &r_fence_token,
+                        &dynamic_text(fence, position)
+                    )]
+                )
+            }
+            None => write!(
+                f,
+                [dynamic_text(
+                    fence,
+                    node.syntax().text_trimmed_range().end()
+                )]
+            ),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use crate::utils::is_in_setext_header;
+use biome_formatter::{format_args, write};
+use biome_markdown_syntax::{MdHardLine, MdHardLineFields};
+
+/// Hard line breaks keep their backslash or their two trailing spaces
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHardLine;
+impl FormatNodeRule<MdHardLine> for FormatMdHardLine {
+    fn fmt_fields(&self, node: &MdHardLine, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHardLineFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        if is_in_setext_header(node.syntax()) {
+            return write!(f, [format_replaced(&value_token, &space())]);
+        }
+
+        let marker = if value_token.text_trimmed().starts_with('\\') {
+            "\\"
+        } else {
+            "  "
+        };
+        write!(
+            f,
+            [format_replaced(
+                &value_token,
+                &format_args![text(marker), hard_line_break()]
+            )]
+        )
+    }
+}
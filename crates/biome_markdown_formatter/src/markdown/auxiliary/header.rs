@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use crate::utils::mark_formatted;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdHeader, MdHeaderFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHeader;
+impl FormatNodeRule<MdHeader> for FormatMdHeader {
+    fn fmt_fields(&self, node: &MdHeader, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHeaderFields {
+            before,
+            content,
+            after,
+        } = node.as_fields();
+
+        write!(f, [before.format()])?;
+        if let Some(content) = content {
+            write!(f, [space(), content.format()])?;
+        }
+
+        // The closing sequence is optional
+        mark_formatted(f, after.syntax());
+        for hash in after {
+            write!(f, [format_removed(&hash.hash_token()?)])?;
+        }
+        Ok(())
+    }
+}
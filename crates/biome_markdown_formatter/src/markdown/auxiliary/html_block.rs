@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use crate::utils::{mark_formatted, write_indented_lines};
+use biome_markdown_syntax::{AnyMdInline, MdHtmlBlock, MdHtmlBlockFields};
+use biome_rowan::SyntaxResult;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHtmlBlock;
+impl FormatNodeRule<MdHtmlBlock> for FormatMdHtmlBlock {
+    fn fmt_fields(&self, node: &MdHtmlBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHtmlBlockFields { content } = node.as_fields();
+
+        // HTML is kept as it is
+        let tokens = content
+            .iter()
+            .filter_map(|line| match line {
+                AnyMdInline::MdTextual(textual) => Some(textual.value_token()),
+                _ => None,
+            })
+            .collect::<SyntaxResult<Vec<_>>>()?;
+
+        mark_formatted(f, content.syntax());
+        write_indented_lines(f, &tokens, "")
+    }
+}
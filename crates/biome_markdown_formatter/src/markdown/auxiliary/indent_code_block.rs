@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use crate::utils::{mark_formatted, write_indented_lines};
+use biome_markdown_syntax::{MdIndentCodeBlock, MdIndentCodeBlockFields};
+use biome_rowan::SyntaxResult;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdIndentCodeBlock;
+impl FormatNodeRule<MdIndentCodeBlock> for FormatMdIndentCodeBlock {
+    fn fmt_fields(&self, node: &MdIndentCodeBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdIndentCodeBlockFields { lines } = node.as_fields();
+
+        let tokens = lines
+            .iter()
+            .map(|line| line.content()?.value_token())
+            .collect::<SyntaxResult<Vec<_>>>()?;
+
+        mark_formatted(f, lines.syntax());
+        write_indented_lines(f, &tokens, "    ")
+    }
+}
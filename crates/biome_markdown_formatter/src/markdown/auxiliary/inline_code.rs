@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineCode, MdInlineCodeFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineCode;
+impl FormatNodeRule<MdInlineCode> for FormatMdInlineCode {
+    fn fmt_fields(&self, node: &MdInlineCode, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdInlineCodeFields {
+            l_tick_token,
+            content,
+            r_tick_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_tick_token.format(),
+                content.format(),
+                r_tick_token.format()
+            ]
+        )
+    }
+}
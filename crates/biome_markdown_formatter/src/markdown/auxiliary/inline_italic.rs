@@ -0,0 +1,48 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{AnyMdInline, MdInlineItalic, MdInlineItalicFields};
+use biome_rowan::{AstNode, SyntaxResult};
+
+/// Italic text is delimited with `_`, unless it's part of a word where `_` can't delimit it
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineItalic;
+impl FormatNodeRule<MdInlineItalic> for FormatMdInlineItalic {
+    fn fmt_fields(&self, node: &MdInlineItalic, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdInlineItalicFields {
+            l_fence,
+            content,
+            r_fence,
+        } = node.as_fields();
+
+        let fence = if is_within_word(node)? { "*" } else { "_" };
+        write!(
+            f,
+            [
+                format_replaced(&l_fence?, &text(fence)),
+                content.format(),
+                format_replaced(&r_fence?, &text(fence))
+            ]
+        )
+    }
+}
+
+/// Returns `true` if the text right before or right after `node` is a word character
+fn is_within_word(node: &MdInlineItalic) -> SyntaxResult<bool> {
+    let is_word_char =
+        |char: Option<char>| char.is_some_and(|char| char.is_alphanumeric() || char == '_');
+
+    let before = match node.syntax().prev_sibling().and_then(AnyMdInline::cast) {
+        Some(AnyMdInline::MdTextual(textual)) => {
+            textual.value_token()?.text_trimmed().chars().next_back()
+        }
+        _ => None,
+    };
+    let after = match node.syntax().next_sibling().and_then(AnyMdInline::cast) {
+        Some(AnyMdInline::MdTextual(textual)) => {
+            textual.value_token()?.text_trimmed().chars().next()
+        }
+        _ => None,
+    };
+
+    Ok(is_word_char(before) || is_word_char(after))
+}
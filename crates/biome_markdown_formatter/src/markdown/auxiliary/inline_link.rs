@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineLink, MdInlineLinkFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineLink;
+impl FormatNodeRule<MdInlineLink> for FormatMdInlineLink {
+    fn fmt_fields(&self, node: &MdInlineLink, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdInlineLinkFields {
+            l_brack_token,
+            text,
+            r_brack_token,
+            l_paren_token,
+            source,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_brack_token.format(),
+                text.format(),
+                r_brack_token.format(),
+                l_paren_token.format(),
+                source.format(),
+                r_paren_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdInlineStrikethrough, MdInlineStrikethroughFields};
+
+/// Strikethrough text is always delimited with `~~`
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineStrikethrough;
+impl FormatNodeRule<MdInlineStrikethrough> for FormatMdInlineStrikethrough {
+    fn fmt_fields(
+        &self,
+        node: &MdInlineStrikethrough,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MdInlineStrikethroughFields {
+            l_fence,
+            content,
+            r_fence,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                format_replaced(&l_fence?, &text("~~")),
+                content.format(),
+                format_replaced(&r_fence?, &text("~~"))
+            ]
+        )
+    }
+}
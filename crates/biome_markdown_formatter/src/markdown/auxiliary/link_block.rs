@@ -0,0 +1,10 @@
+use crate::prelude::*;
+use biome_markdown_syntax::MdLinkBlock;
+use biome_rowan::AstNode;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdLinkBlock;
+impl FormatNodeRule<MdLinkBlock> for FormatMdLinkBlock {
+    fn fmt_fields(&self, node: &MdLinkBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        format_verbatim_node(node.syntax()).fmt(f)
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdOrderListItem, MdOrderListItemFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdOrderListItem;
+impl FormatNodeRule<MdOrderListItem> for FormatMdOrderListItem {
+    fn fmt_fields(&self, node: &MdOrderListItem, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdOrderListItemFields { md_bullet_list } = node.as_fields();
+
+        write!(f, [md_bullet_list.format()])
+    }
+}
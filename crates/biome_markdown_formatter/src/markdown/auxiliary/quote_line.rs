@@ -0,0 +1,48 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdQuoteLine, MdQuoteLineFields, token_column};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdQuoteLine;
+impl FormatNodeRule<MdQuoteLine> for FormatMdQuoteLine {
+    fn fmt_fields(&self, node: &MdQuoteLine, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdQuoteLineFields {
+            marker_token,
+            content,
+        } = node.as_fields();
+
+        // Lazy continuation lines get a marker too
+        let content_column = match &marker_token {
+            Some(marker) => {
+                write!(f, [marker.format()])?;
+                token_column(marker) + 2
+            }
+            None => {
+                write!(f, [text(">")])?;
+                0
+            }
+        };
+
+        if let Some(content) = content {
+            let token = content.value_token()?;
+            // Keep the indentation of the content, which can start an indented code block
+            let indentation = if marker_token.is_some() {
+                token_column(&token).saturating_sub(content_column)
+            } else {
+                0
+            };
+            write!(f, [space()])?;
+            if indentation > 0 {
+                write!(
+                    f,
+                    [dynamic_text(
+                        &" ".repeat(indentation),
+                        token.text_trimmed_range().start()
+                    )]
+                )?;
+            }
+            write!(f, [content.format()])?;
+        }
+        Ok(())
+    }
+}
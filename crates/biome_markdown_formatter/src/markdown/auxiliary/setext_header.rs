@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdSetextHeader, MdSetextHeaderFields};
+
+/// Setext headings are printed as ATX headings, with their lines joined
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdSetextHeader;
+impl FormatNodeRule<MdSetextHeader> for FormatMdSetextHeader {
+    fn fmt_fields(&self, node: &MdSetextHeader, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdSetextHeaderFields {
+            content,
+            underline_token,
+        } = node.as_fields();
+        let underline_token = underline_token?;
+
+        let level = if underline_token.text_trimmed().starts_with('=') {
+            "#"
+        } else {
+            "##"
+        };
+        write!(f, [text(level), space()])?;
+
+        write!(f, [content.format(), format_removed(&underline_token)])
+    }
+}
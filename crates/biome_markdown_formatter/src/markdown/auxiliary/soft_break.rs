@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use crate::utils::is_in_setext_header;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdSoftBreak, MdSoftBreakFields};
+
+/// Soft line breaks are kept, except in setext headings which are printed on a single line
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdSoftBreak;
+impl FormatNodeRule<MdSoftBreak> for FormatMdSoftBreak {
+    fn fmt_fields(&self, node: &MdSoftBreak, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdSoftBreakFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        if is_in_setext_header(node.syntax()) {
+            write!(f, [format_replaced(&value_token, &space())])
+        } else {
+            write!(f, [format_replaced(&value_token, &hard_line_break())])
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::prelude::*;
+use crate::utils::mark_formatted;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownSyntaxToken, MdTable, MdTableFields, MdTableRow};
+use biome_rowan::{AstNode, AstSeparatedList, SyntaxResult};
+use std::iter;
+use unicode_width::UnicodeWidthStr;
+
+/// Tables are aligned: all the cells of a column are padded to the same width.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTable;
+impl FormatNodeRule<MdTable> for FormatMdTable {
+    fn fmt_fields(&self, node: &MdTable, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTableFields {
+            header,
+            delimiter,
+            rows,
+        } = node.as_fields();
+        mark_formatted(f, node.syntax());
+        let header = header?;
+        let delimiter = delimiter?;
+
+        let delimiter_cells = row_cells(&delimiter)?;
+        let alignments: Vec<_> = delimiter_cells
+            .iter()
+            .map(|cell| {
+                cell.as_ref().map_or(ColumnAlignment::None, |token| {
+                    ColumnAlignment::from_delimiter(token.text_trimmed())
+                })
+            })
+            .collect();
+
+        let rows: Vec<_> = iter::once(header).chain(rows.iter()).collect();
+        let cells = rows
+            .iter()
+            .map(row_cells)
+            .collect::<SyntaxResult<Vec<_>>>()?;
+
+        let columns = cells
+            .iter()
+            .map(Vec::len)
+            .chain(iter::once(alignments.len()))
+            .max()
+            .unwrap_or_default();
+        // Delimiter cells need at least three characters
+        let widths: Vec<_> = (0..columns)
+            .map(|column| {
+                cells
+                    .iter()
+                    .filter_map(|row| row.get(column)?.as_ref())
+                    .map(|token| token.text_trimmed().width())
+                    .max()
+                    .unwrap_or_default()
+                    .max(3)
+            })
+            .collect();
+        let alignment = |column: usize| {
+            alignments
+                .get(column)
+                .copied()
+                .unwrap_or(ColumnAlignment::None)
+        };
+
+        for (index, (row, cells)) in rows.iter().zip(&cells).enumerate() {
+            if index > 0 {
+                write!(f, [hard_line_break()])?;
+            }
+            write_row(f, row, cells, columns, |column, text| {
+                alignment(column).pad(text, widths[column])
+            })?;
+
+            if index == 0 {
+                write!(f, [hard_line_break()])?;
+                write_row(f, &delimiter, &delimiter_cells, columns, |column, _| {
+                    alignment(column).delimiter(widths[column])
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ColumnAlignment {
+    None,
+    Left,
+    Center,
+    Right,
+}
+
+impl ColumnAlignment {
+    fn from_delimiter(delimiter: &str) -> Self {
+        match (delimiter.starts_with(':'), delimiter.ends_with(':')) {
+            (true, true) => Self::Center,
+            (true, false) => Self::Left,
+            (false, true) => Self::Right,
+            (false, false) => Self::None,
+        }
+    }
+
+    /// Returns the delimiter of a column of the given width
+    fn delimiter(self, width: usize) -> String {
+        match self {
+            Self::None => "-".repeat(width),
+            Self::Left => std::format!(":{}", "-".repeat(width - 1)),
+            Self::Center => std::format!(":{}:", "-".repeat(width - 2)),
+            Self::Right => std::format!("{}:", "-".repeat(width - 1)),
+        }
+    }
+
+    /// Pads `text` to the given width
+    fn pad(self, text: &str, width: usize) -> String {
+        let padding = width.saturating_sub(text.width());
+        let (before, after) = match self {
+            Self::None | Self::Left => (0, padding),
+            Self::Center => (padding / 2, padding - padding / 2),
+            Self::Right => (padding, 0),
+        };
+        std::format!("{}{text}{}", " ".repeat(before), " ".repeat(after))
+    }
+}
+
+/// Returns the content token of each cell of the row. Empty cells don't have any.
+fn row_cells(row: &MdTableRow) -> SyntaxResult<Vec<Option<MarkdownSyntaxToken>>> {
+    row.cells()
+        .iter()
+        .map(|cell| {
+            cell?
+                .content()
+                .map(|content| content.value_token())
+                .transpose()
+        })
+        .collect()
+}
+
+/// Writes a row with leading and trailing pipes, and `columns` cells. Missing cells are added.
+fn write_row(
+    f: &mut MarkdownFormatter,
+    row: &MdTableRow,
+    cells: &[Option<MarkdownSyntaxToken>],
+    columns: usize,
+    cell_text: impl Fn(usize, &str) -> String,
+) -> FormatResult<()> {
+    if let Some(l_pipe_token) = row.l_pipe_token() {
+        write!(f, [format_removed(&l_pipe_token)])?;
+    }
+    for separator in row.cells().separators() {
+        write!(f, [format_removed(&separator?)])?;
+    }
+
+    write!(f, [text("|")])?;
+    for column in 0..columns {
+        let cell = cells.get(column).and_then(Option::as_ref);
+        let content = std::format!(
+            " {} |",
+            cell_text(column, cell.map_or("", |token| token.text_trimmed()))
+        );
+        match cell {
+            Some(token) => {
+                let position = token.text_trimmed_range().start();
+                write!(
+                    f,
+                    [format_replaced(token, &dynamic_text(&content, position))]
+                )?;
+            }
+            None => {
+                let position = row.syntax().text_trimmed_range().end();
+                write!(f, [dynamic_text(&content, position)])?;
+            }
+        }
+    }
+    Ok(())
+}
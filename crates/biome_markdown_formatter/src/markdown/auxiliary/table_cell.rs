@@ -0,0 +1,10 @@
+use crate::prelude::*;
+use biome_markdown_syntax::MdTableCell;
+use biome_rowan::AstNode;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTableCell;
+impl FormatNodeRule<MdTableCell> for FormatMdTableCell {
+    fn fmt_fields(&self, node: &MdTableCell, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        format_verbatim_node(node.syntax()).fmt(f)
+    }
+}
@@ -0,0 +1,10 @@
+use crate::prelude::*;
+use biome_markdown_syntax::MdTableRow;
+use biome_rowan::AstNode;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTableRow;
+impl FormatNodeRule<MdTableRow> for FormatMdTableRow {
+    fn fmt_fields(&self, node: &MdTableRow, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        format_verbatim_node(node.syntax()).fmt(f)
+    }
+}
@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdThematicBreakBlock, MdThematicBreakBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdThematicBreakBlock;
+impl FormatNodeRule<MdThematicBreakBlock> for FormatMdThematicBreakBlock {
+    fn fmt_fields(
+        &self,
+        node: &MdThematicBreakBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MdThematicBreakBlockFields { value_token } = node.as_fields();
+
+        write!(f, [format_replaced(&value_token?, &text("---"))])
+    }
+}
@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use crate::utils::is_loose_list;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdBlockList, MdBullet, MdBulletList};
+use biome_rowan::AstNode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBlockList;
+impl FormatRule<MdBlockList> for FormatMdBlockList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdBlockList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        // The blocks of the items of a tight list aren't separated by blank lines
+        let is_tight = node
+            .parent::<MdBullet>()
+            .and_then(|bullet| bullet.parent::<MdBulletList>())
+            .is_some_and(|list| !is_loose_list(&list));
+
+        for (index, block) in node.iter().enumerate() {
+            if index > 0 {
+                if is_tight {
+                    write!(f, [hard_line_break()])?;
+                } else {
+                    write!(f, [empty_line()])?;
+                }
+            }
+            write!(f, [block.format()])?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use crate::utils::is_loose_list;
+use biome_formatter::write;
+use biome_markdown_syntax::MdBulletList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBulletList;
+impl FormatRule<MdBulletList> for FormatMdBulletList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdBulletList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let is_loose = is_loose_list(node);
+
+        for (index, bullet) in node.iter().enumerate() {
+            if index > 0 {
+                if is_loose {
+                    write!(f, [empty_line()])?;
+                } else {
+                    write!(f, [hard_line_break()])?;
+                }
+            }
+            write!(f, [bullet.format()])?;
+        }
+        Ok(())
+    }
+}
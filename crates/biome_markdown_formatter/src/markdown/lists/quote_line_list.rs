@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_markdown_syntax::MdQuoteLineList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdQuoteLineList;
+impl FormatRule<MdQuoteLineList> for FormatMdQuoteLineList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdQuoteLineList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        f.join_with(hard_line_break())
+            .entries(node.iter().formatted())
+            .finish()
+    }
+}
@@ -0,0 +1,140 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{
+    AnyMdBlock, MarkdownSyntaxKind, MarkdownSyntaxNode, MarkdownSyntaxToken, MdBlockList,
+    MdBulletList, token_column,
+};
+use biome_rowan::{AstNode, TextSize};
+
+/// Marks `node` and its descendants as checked for suppressions. Used for nodes whose tokens
+/// are printed by one of their ancestors rather than by their own formatting rule.
+pub(crate) fn mark_formatted(f: &MarkdownFormatter, node: &MarkdownSyntaxNode) {
+    let comments = f.comments();
+    for node in node.descendants() {
+        comments.mark_suppression_checked(&node);
+    }
+}
+
+/// Returns `true` if the items of the list are separated by blank lines, or if any
+/// item contains blocks separated by blank lines.
+///
+/// See https://spec.commonmark.org/0.31.2/#loose
+pub(crate) fn is_loose_list(list: &MdBulletList) -> bool {
+    list.iter().enumerate().any(|(index, bullet)| {
+        (index > 0 && get_lines_before(bullet.syntax()) > 1)
+            || bullet
+                .content()
+                .iter()
+                .skip(1)
+                .any(|block| get_lines_before(block.syntax()) > 1)
+    })
+}
+
+/// Returns the number of lists that directly precede the list `block` in the same container,
+/// without any other block in between. Adjacent lists alternate their markers, so they
+/// don't merge into a single list.
+pub(crate) fn list_sibling_index(block: &AnyMdBlock) -> usize {
+    let Some(list) = block.parent::<MdBlockList>() else {
+        return 0;
+    };
+    let is_ordered = is_ordered_list(block);
+    let position = list
+        .iter()
+        .position(|sibling| &sibling == block)
+        .unwrap_or_default();
+    list.iter()
+        .take(position)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .take_while(|sibling| is_list(sibling) && is_ordered_list(sibling) == is_ordered)
+        .count()
+}
+
+fn is_list(block: &AnyMdBlock) -> bool {
+    matches!(
+        block,
+        AnyMdBlock::AnyMdContainerBlock(container)
+            if container.as_md_bullet_list_item().is_some() || container.as_md_order_list_item().is_some()
+    )
+}
+
+fn is_ordered_list(block: &AnyMdBlock) -> bool {
+    matches!(
+        block,
+        AnyMdBlock::AnyMdContainerBlock(container) if container.as_md_order_list_item().is_some()
+    )
+}
+
+/// Returns `true` if `node` is part of the content of a setext heading, which is printed
+/// as an ATX heading on a single line
+pub(crate) fn is_in_setext_header(node: &MarkdownSyntaxNode) -> bool {
+    node.ancestors()
+        .any(|ancestor| ancestor.kind() == MarkdownSyntaxKind::MD_SETEXT_HEADER)
+}
+
+/// Writes a line break followed by `blank_lines` empty lines.
+///
+/// The empty lines are written as text because the printer collapses consecutive line breaks.
+/// Unlike line breaks, they don't get indented, so they don't contain trailing whitespace.
+pub(crate) fn write_line_break(
+    f: &mut MarkdownFormatter,
+    blank_lines: usize,
+    position: TextSize,
+) -> FormatResult<()> {
+    if blank_lines > 0 {
+        write!(f, [dynamic_text(&"\n".repeat(blank_lines + 1), position)])?;
+    }
+    write!(f, [hard_line_break()])
+}
+
+/// Writes the lines of a code block, each one preceded by a line break. Empty lines are kept,
+/// and lines that only contain whitespace are written as they are.
+pub(crate) fn write_code_lines<'a>(
+    f: &mut MarkdownFormatter,
+    lines: impl IntoIterator<Item = &'a str>,
+    position: TextSize,
+) -> FormatResult<()> {
+    let mut blank_lines = 0;
+    for line in lines {
+        if line.is_empty() {
+            blank_lines += 1;
+            continue;
+        }
+        write_line_break(f, blank_lines, position)?;
+        write!(f, [dynamic_text(line, position)])?;
+        blank_lines = 0;
+    }
+
+    if blank_lines > 0 {
+        write!(f, [dynamic_text(&"\n".repeat(blank_lines + 1), position)])?;
+    }
+    Ok(())
+}
+
+/// Writes each token on its own line, keeping the blank lines between them and their
+/// indentation relative to the least indented one. `prefix` is written at the start of each line.
+pub(crate) fn write_indented_lines(
+    f: &mut MarkdownFormatter,
+    tokens: &[MarkdownSyntaxToken],
+    prefix: &str,
+) -> FormatResult<()> {
+    let columns: Vec<_> = tokens.iter().map(token_column).collect();
+    let base = columns.iter().copied().min().unwrap_or_default();
+
+    for (index, (token, column)) in tokens.iter().zip(columns).enumerate() {
+        let position = token.text_trimmed_range().start();
+        if index > 0 {
+            let blank_lines = get_lines_before_token(token).saturating_sub(1);
+            write_line_break(f, blank_lines, position)?;
+        }
+
+        let line = std::format!(
+            "{prefix}{}{}",
+            " ".repeat(column - base),
+            token.text_trimmed()
+        );
+        write!(f, [format_replaced(token, &dynamic_text(&line, position))])?;
+    }
+    Ok(())
+}
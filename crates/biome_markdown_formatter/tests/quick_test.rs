@@ -0,0 +1,42 @@
+use biome_formatter::{IndentStyle, LineWidth};
+use biome_formatter_test::check_reformat::CheckReformat;
+use biome_markdown_formatter::context::MarkdownFormatOptions;
+use biome_markdown_formatter::{MarkdownFormatLanguage, format_node};
+use biome_markdown_parser::parse_markdown;
+use biome_markdown_syntax::MarkdownFileSource;
+
+mod language {
+    include!("language.rs");
+}
+
+#[ignore]
+#[test]
+// use this test check if your snippet prints as you wish, without using a snapshot
+fn quick_test() {
+    let src = r#"Title
+=====
+
+* one
+* two
+"#;
+    let tree = parse_markdown(src);
+    dbg!(tree.tree());
+    let options = MarkdownFormatOptions::new(MarkdownFileSource::markdown())
+        .with_indent_style(IndentStyle::Space)
+        .with_line_width(LineWidth::try_from(80).unwrap());
+
+    let doc = format_node(options.clone(), &tree.syntax()).unwrap();
+    let result = doc.print().unwrap();
+
+    println!("{}", doc.into_document());
+    eprintln!("{}", result.as_code());
+
+    CheckReformat::new(
+        &tree.syntax(),
+        result.as_code(),
+        "testing",
+        &language::MarkdownTestFormatLanguage,
+        MarkdownFormatLanguage::new(options),
+    )
+    .check_reformat();
+}
@@ -0,0 +1,9 @@
+mod quick_test;
+mod spec_test;
+
+mod formatter {
+
+    mod markdown {
+        tests_macros::gen_tests! {"tests/specs/markdown/**/*.md", crate::spec_test::run, ""}
+    }
+}
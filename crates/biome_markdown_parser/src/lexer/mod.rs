@@ -1,10 +1,10 @@
-//! An extremely fast, lookup table based, JSON lexer which yields SyntaxKind tokens used by the rome-json parser.
+//! An extremely fast, lookup table based, Markdown lexer which yields SyntaxKind tokens used by the biome-markdown parser.
 
 #[rustfmt::skip]
 mod tests;
 
-use biome_markdown_syntax::MarkdownSyntaxKind;
 use biome_markdown_syntax::MarkdownSyntaxKind::*;
+use biome_markdown_syntax::{MarkdownSyntaxKind, T};
 use biome_parser::diagnostic::ParseDiagnostic;
 use biome_parser::lexer::{
     LexContext, Lexer, LexerCheckpoint, LexerWithCheckpoint, ReLexer, TokenFlags,
@@ -14,8 +14,25 @@ use biome_unicode_table::{Dispatch::*, lookup_byte};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
 pub enum MarkdownLexContext {
+    /// The start of a block: block markers such as `#`, `>`, `-` or fences are recognised,
+    /// everything else is lexed as a textual token spanning the rest of the line.
     #[default]
     Regular,
+    /// The rest of the line is lexed as a single textual token.
+    Inline,
+    /// The content of an ATX heading: the optional closing sequence of `#` is lexed as separate tokens.
+    Heading,
+    /// The content of a fenced code block opened by `length` times the `fence` character.
+    ///
+    /// `indent` is the column where the content of the enclosing container starts: a
+    /// non-blank line indented less than that closes the code block.
+    FencedCode { fence: u8, length: u16, indent: u16 },
+    /// A row of a GFM table, where cells are separated by `|`.
+    TableRow,
+    /// The inline content of a paragraph, whose tokens are delimited by the parser with
+    /// [MarkdownReLexContext::Span]. Line endings are tokens, while the indentation and
+    /// the trailing whitespace of the lines are trivia.
+    InlineContent,
 }
 
 impl LexContext for MarkdownLexContext {
@@ -28,9 +45,12 @@ impl LexContext for MarkdownLexContext {
 /// Context in which the [MarkdownLexContext]'s current should be re-lexed.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum MarkdownReLexContext {
-    #[expect(dead_code)]
-    Regular,
-    // UnicodeRange,
+    /// Re-lexes the current token as a textual token spanning the rest of the line.
+    Line,
+    /// Re-lexes the current token as the start of a GFM table row.
+    TableRow,
+    /// Re-lexes the current token as a textual token that ends at the given byte offset.
+    Span { end: usize },
 }
 
 /// An extremely fast, lookup table based, lossless Markdown lexer
@@ -90,12 +110,23 @@ impl<'src> Lexer<'src> for MarkdownLexer<'src> {
         self.diagnostics.push(diagnostic);
     }
 
-    fn next_token(&mut self, _context: Self::LexContext) -> Self::Kind {
+    fn next_token(&mut self, context: Self::LexContext) -> Self::Kind {
         self.current_start = self.text_position();
         self.current_flags = TokenFlags::empty();
 
         let kind = match self.current_byte() {
-            Some(current) => self.consume_token(current),
+            Some(current) => match context {
+                MarkdownLexContext::Regular => self.consume_token(current),
+                MarkdownLexContext::Inline => self.consume_inline_token(current),
+                MarkdownLexContext::Heading => self.consume_heading_token(current),
+                MarkdownLexContext::FencedCode {
+                    fence,
+                    length,
+                    indent,
+                } => self.consume_fenced_code_token(current, fence, length, indent),
+                MarkdownLexContext::TableRow => self.consume_table_row_token(current),
+                MarkdownLexContext::InlineContent => self.consume_inline_content_token(current),
+            },
             None => EOF,
         };
 
@@ -176,12 +207,341 @@ impl<'src> MarkdownLexer<'src> {
         }
     }
 
+    /// Lexes a token at the start of a block
     pub(crate) fn consume_token(&mut self, current: u8) -> MarkdownSyntaxKind {
         let dispatched = lookup_byte(current);
         match dispatched {
             WHS => self.consume_newline_or_whitespace(),
-            MUL | MIN | IDT => self.consume_thematic_break_literal(),
-            _ => self.consume_textual(),
+            HAS if self.is_at_atx_heading_marker() => self.consume_byte(T![#]),
+            MOR => self.consume_byte(T![>]),
+            MUL | MIN | IDT => self.consume_thematic_break_or_bullet(current),
+            PLS if self.is_followed_by_whitespace_or_eol(1) => self.consume_byte(T![+]),
+            EQL => self.consume_setext_underline_literal(),
+            TPL | TLD => self.consume_code_fence_literal(current),
+            ZER | DIG => self.consume_ordered_list_marker(),
+            _ => self.consume_line(),
+        }
+    }
+
+    /// Lexes a token inside a line, where the rest of the line is textual
+    fn consume_inline_token(&mut self, current: u8) -> MarkdownSyntaxKind {
+        match current {
+            b'\n' | b'\r' | b' ' | b'\t' => self.consume_newline_or_whitespace(),
+            // A new line starts a new block
+            _ if self.after_newline => self.consume_token(current),
+            _ => self.consume_line(),
+        }
+    }
+
+    /// Lexes a token inside an ATX heading, separating the closing sequence from the content
+    fn consume_heading_token(&mut self, current: u8) -> MarkdownSyntaxKind {
+        match current {
+            b'\n' | b'\r' | b' ' | b'\t' => self.consume_newline_or_whitespace(),
+            _ if self.after_newline => self.consume_token(current),
+            b'#' if self.is_at_heading_closing_sequence() => self.consume_byte(T![#]),
+            _ => {
+                let start = self.position;
+                let end = self.line_end();
+                let mut content_end = trim_end_whitespace(self.source, start, end);
+
+                // Strip the closing sequence: a run of `#` preceded by a space or a tab
+                let mut hashes_start = content_end;
+                while hashes_start > start && self.source.as_bytes()[hashes_start - 1] == b'#' {
+                    hashes_start -= 1;
+                }
+                if hashes_start < content_end
+                    && hashes_start > start
+                    && matches!(self.source.as_bytes()[hashes_start - 1], b' ' | b'\t')
+                {
+                    content_end = trim_end_whitespace(self.source, start, hashes_start);
+                }
+
+                self.position = content_end;
+                MD_TEXTUAL_LITERAL
+            }
+        }
+    }
+
+    /// Lexes a token inside a fenced code block.
+    ///
+    /// The content of the block is lexed as a single textual token that spans all the lines
+    /// up to the closing fence, or up to the end of the enclosing container.
+    fn consume_fenced_code_token(
+        &mut self,
+        current: u8,
+        fence: u8,
+        length: u16,
+        indent: u16,
+    ) -> MarkdownSyntaxKind {
+        if matches!(current, b'\n' | b'\r') {
+            return self.consume_newline();
+        }
+
+        if !self.after_newline {
+            return if matches!(current, b' ' | b'\t') {
+                self.consume_newline_or_whitespace()
+            } else {
+                self.consume_token(current)
+            };
+        }
+
+        let line_start = self.line_start();
+        if self.is_closing_fence(line_start, fence, length, indent) {
+            return if current == fence {
+                self.consume_fence()
+            } else {
+                self.consume_newline_or_whitespace()
+            };
+        }
+
+        if self.is_dedented(line_start, indent) {
+            return self.consume_token(current);
+        }
+
+        loop {
+            self.position = self.line_end();
+            let next_line_start = match self.current_byte() {
+                Some(b'\r') if self.peek_byte() == Some(b'\n') => self.position + 2,
+                Some(b'\n' | b'\r') => self.position + 1,
+                _ => break,
+            };
+            if next_line_start >= self.source.len()
+                || self.is_closing_fence(next_line_start, fence, length, indent)
+                || self.is_dedented(next_line_start, indent)
+            {
+                break;
+            }
+            self.position = next_line_start;
+        }
+
+        MD_TEXTUAL_LITERAL
+    }
+
+    /// Lexes a token inside a GFM table row
+    fn consume_table_row_token(&mut self, current: u8) -> MarkdownSyntaxKind {
+        match current {
+            b'\n' | b'\r' | b' ' | b'\t' => self.consume_newline_or_whitespace(),
+            _ if self.after_newline => self.consume_token(current),
+            b'|' => self.consume_byte(T![|]),
+            _ => self.consume_table_cell(),
+        }
+    }
+
+    /// Lexes a token of the inline content of a paragraph.
+    ///
+    /// The parser re-lexes the token with the range it computed from the inline structure
+    /// of the paragraph, so the token only needs to start at the right position.
+    fn consume_inline_content_token(&mut self, current: u8) -> MarkdownSyntaxKind {
+        match current {
+            b'\n' | b'\r' => {
+                self.consume_newline();
+                // The line ending belongs to the paragraph, it isn't trivia
+                self.after_newline = false;
+                MD_SOFT_BREAK_LITERAL
+            }
+            b' ' | b'\t'
+                if self.is_at_line_indentation() || self.is_at_line_trailing_whitespace() =>
+            {
+                self.consume_newline_or_whitespace()
+            }
+            _ => self.consume_line(),
+        }
+    }
+
+    /// Returns `true` if the current position is preceded only by whitespace on its line
+    fn is_at_line_indentation(&self) -> bool {
+        self.source.as_bytes()[self.line_start()..self.position]
+            .iter()
+            .all(|byte| matches!(byte, b' ' | b'\t'))
+    }
+
+    /// Returns `true` if the current position is followed only by whitespace on its line
+    fn is_at_line_trailing_whitespace(&self) -> bool {
+        self.source.as_bytes()[self.position..self.line_end()]
+            .iter()
+            .all(|byte| matches!(byte, b' ' | b'\t'))
+    }
+
+    /// Consumes the content of a table cell, up to the next unescaped `|` or the end of the line
+    fn consume_table_cell(&mut self) -> MarkdownSyntaxKind {
+        let start = self.position;
+        let end = self.line_end();
+        let bytes = self.source.as_bytes();
+        let mut position = start;
+        while position < end {
+            match bytes[position] {
+                b'\\' if position + 1 < end => position += 2,
+                b'|' => break,
+                _ => position += 1,
+            }
+        }
+        self.position = trim_end_whitespace(self.source, start, position);
+        MD_TEXTUAL_LITERAL
+    }
+
+    /// Consumes the rest of the line, without its trailing whitespace
+    fn consume_line(&mut self) -> MarkdownSyntaxKind {
+        self.assert_at_char_boundary();
+        let end = self.line_end();
+        self.position = trim_end_whitespace(self.source, self.position, end);
+        MD_TEXTUAL_LITERAL
+    }
+
+    /// Returns the byte offset of the end of the current line, excluding the line break
+    fn line_end(&self) -> usize {
+        self.source.as_bytes()[self.position..]
+            .iter()
+            .position(|byte| matches!(byte, b'\n' | b'\r'))
+            .map_or(self.source.len(), |offset| self.position + offset)
+    }
+
+    /// Returns the byte offset of the start of the current line
+    fn line_start(&self) -> usize {
+        self.source.as_bytes()[..self.position]
+            .iter()
+            .rposition(|byte| matches!(byte, b'\n' | b'\r'))
+            .map_or(0, |offset| offset + 1)
+    }
+
+    /// Returns `true` if the byte at `offset` from the current position is a whitespace,
+    /// a line break, or the end of the file
+    fn is_followed_by_whitespace_or_eol(&self, offset: usize) -> bool {
+        matches!(
+            self.byte_at(offset),
+            None | Some(b' ' | b'\t' | b'\n' | b'\r')
+        )
+    }
+
+    /// Returns `true` if the lexer is at a `#` of the opening sequence of an ATX heading
+    ///
+    /// See https://spec.commonmark.org/0.31.2/#atx-headings
+    fn is_at_atx_heading_marker(&self) -> bool {
+        let count = self.source.as_bytes()[self.position..]
+            .iter()
+            .take_while(|byte| **byte == b'#')
+            .count();
+        count <= 6 && self.is_followed_by_whitespace_or_eol(count)
+    }
+
+    /// Returns `true` if the lexer is at the closing sequence of an ATX heading
+    fn is_at_heading_closing_sequence(&self) -> bool {
+        let end = self.line_end();
+        self.source.as_bytes()[self.position..end]
+            .iter()
+            .skip_while(|byte| **byte == b'#')
+            .all(|byte| matches!(byte, b' ' | b'\t'))
+    }
+
+    /// Consumes a thematic break, or a bullet list marker if the line isn't a thematic break
+    fn consume_thematic_break_or_bullet(&mut self, current: u8) -> MarkdownSyntaxKind {
+        let checkpoint = self.position;
+        if self.consume_thematic_break_literal() == MD_THEMATIC_BREAK_LITERAL {
+            return MD_THEMATIC_BREAK_LITERAL;
+        }
+        self.position = checkpoint;
+
+        match current {
+            b'-' if self.is_followed_by_whitespace_or_eol(1) => self.consume_byte(T![-]),
+            b'*' if self.is_followed_by_whitespace_or_eol(1) => self.consume_byte(T![*]),
+            _ => self.consume_line(),
+        }
+    }
+
+    /// Consumes the underline of a setext heading made of `=`
+    ///
+    /// See https://spec.commonmark.org/0.31.2/#setext-headings
+    fn consume_setext_underline_literal(&mut self) -> MarkdownSyntaxKind {
+        let start = self.position;
+        let end = self.line_end();
+        let content_end = trim_end_whitespace(self.source, start, end);
+        if self.source.as_bytes()[start..content_end]
+            .iter()
+            .all(|byte| *byte == b'=')
+        {
+            self.position = content_end;
+            MD_SETEXT_UNDERLINE_LITERAL
+        } else {
+            self.consume_line()
+        }
+    }
+
+    /// Consumes an opening code fence, made of at least three backticks or tildes
+    ///
+    /// See https://spec.commonmark.org/0.31.2/#fenced-code-blocks
+    fn consume_code_fence_literal(&mut self, current: u8) -> MarkdownSyntaxKind {
+        let length = self.source.as_bytes()[self.position..]
+            .iter()
+            .take_while(|byte| **byte == current)
+            .count();
+        let end = self.line_end();
+        // The info string of a backtick fence can't contain backticks
+        let is_fence = length >= 3
+            && (current == b'~'
+                || !self.source.as_bytes()[self.position + length..end].contains(&b'`'));
+
+        if is_fence {
+            self.consume_fence()
+        } else {
+            self.consume_line()
+        }
+    }
+
+    /// Consumes a run of the current fence character
+    fn consume_fence(&mut self) -> MarkdownSyntaxKind {
+        let fence = self.source.as_bytes()[self.position];
+        while self.current_byte() == Some(fence) {
+            self.advance(1);
+        }
+        MD_CODE_FENCE_LITERAL
+    }
+
+    /// Returns `true` if the line starting at `line_start` closes a code block
+    /// opened by `length` times the `fence` character.
+    fn is_closing_fence(&self, line_start: usize, fence: u8, length: u16, indent: u16) -> bool {
+        let (column, offset) = line_indentation(self.source, line_start);
+        if column < usize::from(indent) || column - usize::from(indent) > 3 {
+            return false;
+        }
+        let end = self.source.as_bytes()[offset..]
+            .iter()
+            .position(|byte| matches!(byte, b'\n' | b'\r'))
+            .map_or(self.source.len(), |position| offset + position);
+        let line = &self.source.as_bytes()[offset..end];
+        let count = line.iter().take_while(|byte| **byte == fence).count();
+
+        count >= usize::from(length)
+            && line[count..]
+                .iter()
+                .all(|byte| matches!(byte, b' ' | b'\t'))
+    }
+
+    /// Returns `true` if the line starting at `line_start` isn't blank and is indented less than `indent`
+    fn is_dedented(&self, line_start: usize, indent: u16) -> bool {
+        let (column, offset) = line_indentation(self.source, line_start);
+        column < usize::from(indent)
+            && !matches!(
+                self.source.as_bytes().get(offset),
+                None | Some(b'\n' | b'\r')
+            )
+    }
+
+    /// Consumes the marker of an ordered list item, such as `1.` or `2)`
+    ///
+    /// See https://spec.commonmark.org/0.31.2/#ordered-list-items
+    fn consume_ordered_list_marker(&mut self) -> MarkdownSyntaxKind {
+        let digits = self.source.as_bytes()[self.position..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+
+        if digits <= 9
+            && matches!(self.byte_at(digits), Some(b'.' | b')'))
+            && self.is_followed_by_whitespace_or_eol(digits + 1)
+        {
+            self.advance(digits + 1);
+            MD_ORDERED_LIST_MARKER
+        } else {
+            self.consume_line()
         }
     }
 
@@ -189,12 +549,6 @@ impl<'src> MarkdownLexer<'src> {
         TextSize::try_from(self.position).expect("Input to be smaller than 4 GB")
     }
 
-    /// Bumps the current byte and creates a lexed token of the passed in kind
-    #[expect(dead_code)]
-    fn eat_byte(&mut self, tok: MarkdownSyntaxKind) -> MarkdownSyntaxKind {
-        self.advance(1);
-        tok
-    }
     /// Returns the byte at position `self.position + offset` or `None` if it is out of bounds.
     #[inline]
     fn byte_at(&self, offset: usize) -> Option<u8> {
@@ -361,7 +715,6 @@ impl<'src> MarkdownLexer<'src> {
     }
 
     /// Bumps the current byte and creates a lexed token of the passed in kind
-    #[expect(dead_code)]
     fn consume_byte(&mut self, tok: MarkdownSyntaxKind) -> MarkdownSyntaxKind {
         self.advance(1);
         tok
@@ -370,28 +723,54 @@ impl<'src> MarkdownLexer<'src> {
 
 impl<'src> ReLexer<'src> for MarkdownLexer<'src> {
     fn re_lex(&mut self, context: Self::ReLexContext) -> Self::Kind {
-        let old_position = self.position;
         self.position = u32::from(self.current_start) as usize;
 
         let re_lexed_kind = match self.current_byte() {
             Some(current) => match context {
-                MarkdownReLexContext::Regular => self.consume_token(current),
-                // MarkdownReLexContext::UnicodeRange => self.consume_unicode_range_token(current),
+                MarkdownReLexContext::Line => self.consume_line(),
+                MarkdownReLexContext::TableRow => match current {
+                    b'|' => self.consume_byte(T![|]),
+                    _ => self.consume_table_cell(),
+                },
+                MarkdownReLexContext::Span { end } => {
+                    debug_assert!(end > self.position && self.source.is_char_boundary(end));
+                    self.position = end;
+                    MD_TEXTUAL_LITERAL
+                }
             },
             None => EOF,
         };
 
-        if self.current() == re_lexed_kind {
-            // Didn't re-lex anything. Return existing token again
-            self.position = old_position;
-        } else {
-            self.current_kind = re_lexed_kind;
-        }
-
+        self.current_kind = re_lexed_kind;
         re_lexed_kind
     }
 }
 
+/// Returns `end` moved back before any trailing space or tab of `source[start..end]`
+fn trim_end_whitespace(source: &str, start: usize, end: usize) -> usize {
+    let trimmed = source.as_bytes()[start..end]
+        .iter()
+        .rposition(|byte| !matches!(byte, b' ' | b'\t'))
+        .map_or(0, |position| position + 1);
+    start + trimmed
+}
+
+/// Returns the column of the first non-whitespace character of the line starting at `line_start`,
+/// and its byte offset. Tabs advance to the next multiple of 4.
+fn line_indentation(source: &str, line_start: usize) -> (usize, usize) {
+    let mut column = 0;
+    let mut offset = line_start;
+    for byte in &source.as_bytes()[line_start..] {
+        match byte {
+            b' ' => column += 1,
+            b'\t' => column += 4 - column % 4,
+            _ => break,
+        }
+        offset += 1;
+    }
+    (column, offset)
+}
+
 impl<'src> LexerWithCheckpoint<'src> for MarkdownLexer<'src> {
     fn checkpoint(&self) -> LexerCheckpoint<Self::Kind> {
         LexerCheckpoint {
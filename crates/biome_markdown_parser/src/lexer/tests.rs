@@ -109,8 +109,8 @@ fn empty() {
 #[test]
 fn textual() {
     assert_lex! {
-        "+",
-       MD_TEXTUAL_LITERAL:1,
+        "+foo",
+        MD_TEXTUAL_LITERAL:4,
     }
 }
 
@@ -162,3 +162,85 @@ _ _ _ _  _ "#,
         MD_THEMATIC_BREAK_LITERAL:11,
     }
 }
+
+#[test]
+fn heading() {
+    assert_lex! {
+        "## heading",
+        HASH:1,
+        HASH:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:7,
+    }
+}
+
+#[test]
+fn not_heading() {
+    assert_lex! {
+        "#hashtag",
+        MD_TEXTUAL_LITERAL:8,
+    }
+}
+
+#[test]
+fn setext_underline_literal() {
+    assert_lex! {
+        "===",
+        MD_SETEXT_UNDERLINE_LITERAL:3,
+    }
+}
+
+#[test]
+fn code_fence_literal() {
+    assert_lex! {
+        "```js\n~~~~",
+        MD_CODE_FENCE_LITERAL:3,
+        MD_TEXTUAL_LITERAL:2,
+        NEWLINE:1,
+        MD_CODE_FENCE_LITERAL:4,
+    }
+}
+
+#[test]
+fn bullet_list_markers() {
+    assert_lex! {
+        "- a\n* b\n+ c",
+        MINUS:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        STAR:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        PLUS:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+    }
+}
+
+#[test]
+fn ordered_list_marker() {
+    assert_lex! {
+        "1. a\n10) b\n1.5",
+        MD_ORDERED_LIST_MARKER:2,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        MD_ORDERED_LIST_MARKER:3,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        MD_TEXTUAL_LITERAL:3,
+    }
+}
+
+#[test]
+fn quote_marker() {
+    assert_lex! {
+        "> quote",
+        R_ANGLE:1,
+        WHITESPACE:1,
+        MD_TEXTUAL_LITERAL:5,
+    }
+}
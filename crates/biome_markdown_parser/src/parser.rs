@@ -1,10 +1,11 @@
-use biome_markdown_syntax::MarkdownSyntaxKind;
+use biome_markdown_syntax::{MarkdownSyntaxKind, column_width};
 use biome_parser::ParserContext;
 use biome_parser::event::Event;
 use biome_parser::prelude::*;
 use biome_parser::token_source::Trivia;
 use biome_parser::{ParserContextCheckpoint, diagnostic::merge_diagnostics};
 
+use crate::lexer::MarkdownReLexContext;
 use crate::token_source::{MarkdownTokenSource, MarkdownTokenSourceCheckpoint};
 
 pub(crate) struct MarkdownParser<'source> {
@@ -27,8 +28,57 @@ impl<'source> MarkdownParser<'source> {
         }
     }
 
-    pub fn before_whitespace_count(&self) -> usize {
-        self.source.before_whitespace_count()
+    /// Re-lexes the current token in the given context
+    pub fn re_lex(&mut self, context: MarkdownReLexContext) -> MarkdownSyntaxKind {
+        self.source.re_lex(context)
+    }
+
+    /// Returns the column of the current token. Tabs advance to the next multiple of 4.
+    ///
+    /// See https://spec.commonmark.org/0.31.2/#tabs
+    pub fn column(&self) -> usize {
+        let source = self.source.text();
+        let start = usize::from(self.cur_range().start());
+        column_width(&source[line_start(source, start)..start])
+    }
+
+    /// Returns the indentation of the line containing the start of the current token
+    pub fn line_indentation(&self) -> usize {
+        let source = self.source.text();
+        let start = line_start(source, usize::from(self.cur_range().start()));
+        let indentation = source[start..]
+            .find(|c| c != ' ' && c != '\t')
+            .map_or(&source[start..], |end| &source[start..start + end]);
+        column_width(indentation)
+    }
+
+    /// Returns `true` if there's at least one blank line between the previous token and the current one
+    pub fn has_preceding_blank_line(&self) -> bool {
+        let source = self.source.text();
+        let start = usize::from(self.cur_range().start());
+        source[..start]
+            .bytes()
+            .rev()
+            .take_while(|byte| matches!(byte, b' ' | b'\t' | b'\n' | b'\r'))
+            .filter(|byte| *byte == b'\n')
+            .count()
+            > 1
+    }
+
+    /// Returns the text of the current line that follows the current token
+    pub fn rest_of_line(&self) -> &str {
+        let source = self.source.text();
+        let rest = &source[usize::from(self.cur_range().end())..];
+        rest.find(['\n', '\r']).map_or(rest, |end| &rest[..end])
+    }
+
+    /// Returns the text of the line following the current one
+    pub fn next_line(&self) -> Option<&str> {
+        let source = self.source.text();
+        let rest = &source[usize::from(self.cur_range().end())..];
+        let start = rest.find('\n')? + 1;
+        let rest = &rest[start..];
+        Some(rest.find(['\n', '\r']).map_or(rest, |end| &rest[..end]))
     }
 
     pub fn rewind(&mut self, checkpoint: MarkdownParserCheckpoint) {
@@ -79,3 +129,10 @@ pub struct MarkdownParserCheckpoint {
     pub(super) context: ParserContextCheckpoint,
     pub(super) source: MarkdownTokenSourceCheckpoint,
 }
+
+/// Returns the byte offset of the start of the line containing `offset`
+fn line_start(source: &str, offset: usize) -> usize {
+    source[..offset]
+        .rfind(['\n', '\r'])
+        .map_or(0, |position| position + 1)
+}
@@ -1,3 +1,11 @@
+pub mod code_block;
+pub mod header;
+pub mod html_block;
+pub mod inline;
+pub mod list;
+pub mod paragraph;
+pub mod quote;
+pub mod table;
 pub mod thematic_break_block;
 
 use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
@@ -5,70 +13,76 @@ use biome_parser::{
     Parser,
     prelude::ParsedSyntax::{self, *},
 };
+use code_block::{
+    at_fenced_code_block, at_indent_code_block, parse_fenced_code_block, parse_indent_code_block,
+};
+use header::{at_header, parse_header};
+use html_block::{at_html_block, parse_html_block};
+use list::{at_bullet_list, at_order_list, parse_bullet_list, parse_order_list};
+use paragraph::parse_paragraph;
+use quote::{at_quote, parse_quote};
+use table::{at_table, parse_table};
 use thematic_break_block::{at_thematic_break_block, parse_thematic_break_block};
 
 use crate::MarkdownParser;
+use crate::lexer::MarkdownLexContext;
 
 pub(crate) fn parse_document(p: &mut MarkdownParser) {
     let m = p.start();
-    let _ = parse_block_list(p);
+    let _ = parse_block_list(p, 0);
+    p.expect(T![EOF]);
     m.complete(p, MD_DOCUMENT);
 }
 
-pub(crate) fn parse_block_list(p: &mut MarkdownParser) -> ParsedSyntax {
+/// Parses the blocks of a container whose content starts at column `indent`.
+///
+/// The list ends at the first line indented less than `indent`. The first block of a list
+/// item starts on the same line as its marker, so it's always part of the list.
+pub(crate) fn parse_block_list(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
     let m = p.start();
 
-    while !p.at(T![EOF]) {
-        parse_any_block(p);
+    while !p.at(T![EOF]) && (!p.has_preceding_line_break() || p.column() >= indent) {
+        parse_any_block(p, indent);
     }
     Present(m.complete(p, MD_BLOCK_LIST))
 }
 
-pub(crate) fn parse_any_block(p: &mut MarkdownParser) {
-    if at_indent_code_block(p) {
-        parse_indent_code_block(p);
+pub(crate) fn parse_any_block(p: &mut MarkdownParser, indent: usize) {
+    if at_indent_code_block(p, indent) {
+        let _ = parse_indent_code_block(p, indent);
     } else if at_thematic_break_block(p) {
-        let break_block = try_parse(p, |p| {
-            let break_block = parse_thematic_break_block(p);
-            if break_block.is_absent() {
-                return Err(());
-            }
-            Ok(break_block)
-        });
-        if break_block.is_err() {
-            parse_paragraph(p);
-        }
+        let _ = parse_thematic_break_block(p);
+    } else if at_header(p) {
+        let _ = parse_header(p);
+    } else if at_fenced_code_block(p) {
+        let _ = parse_fenced_code_block(p, indent);
+    } else if at_quote(p) {
+        let _ = parse_quote(p);
+    } else if at_bullet_list(p) {
+        let _ = parse_bullet_list(p, indent);
+    } else if at_order_list(p) {
+        let _ = parse_order_list(p, indent);
+    } else if at_html_block(p) {
+        let _ = parse_html_block(p, indent);
+    } else if at_table(p) {
+        let _ = parse_table(p, indent);
+    } else {
+        let _ = parse_paragraph(p, indent);
     }
 }
 
-pub(crate) fn at_indent_code_block(p: &mut MarkdownParser) -> bool {
-    p.before_whitespace_count() > 4
-}
-
-pub(crate) fn parse_indent_code_block(_p: &mut MarkdownParser) {
-    todo!()
-}
-
-pub(crate) fn parse_paragraph(_p: &mut MarkdownParser) {
-    todo!()
-}
-
-/// Attempt to parse some input with the given parsing function. If parsing
-/// succeeds, `Ok` is returned with the result of the parse and the state is
-/// preserved. If parsing fails, this function rewinds the parser back to
-/// where it was before attempting the parse and the `Err` value is returned.
-#[must_use = "The result of try_parse contains information about whether the parse succeeded and should not be ignored"]
-pub(crate) fn try_parse<T, E>(
-    p: &mut MarkdownParser,
-    func: impl FnOnce(&mut MarkdownParser) -> Result<T, E>,
-) -> Result<T, E> {
-    let checkpoint = p.checkpoint();
-
-    let res = func(p);
-
-    if res.is_err() {
-        p.rewind(checkpoint);
+/// Parses the current token as a [MdTextual](biome_markdown_syntax::MdTextual), and lexes
+/// the next token using `context`.
+pub(crate) fn parse_textual(p: &mut MarkdownParser, context: MarkdownLexContext) -> ParsedSyntax {
+    if !p.at(MD_TEXTUAL_LITERAL) {
+        return Absent;
     }
+    let m = p.start();
+    p.bump_with_context(MD_TEXTUAL_LITERAL, context);
+    Present(m.complete(p, MD_TEXTUAL))
+}
 
-    res
+/// Returns `true` if the current token is on the same line as the previous one
+pub(crate) fn at_same_line(p: &mut MarkdownParser) -> bool {
+    !p.at(T![EOF]) && !p.has_preceding_line_break()
 }
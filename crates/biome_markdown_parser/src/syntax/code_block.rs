@@ -0,0 +1,77 @@
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+use crate::parser::MarkdownParser;
+use crate::syntax::{at_same_line, parse_textual};
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+
+/// An indented code block is indented by at least four columns more than its container.
+///
+/// See https://spec.commonmark.org/0.31.2/#indented-code-blocks
+pub(crate) fn at_indent_code_block(p: &mut MarkdownParser, indent: usize) -> bool {
+    !p.at(T![EOF]) && p.column() >= indent + 4
+}
+
+pub(crate) fn parse_indent_code_block(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    if !at_indent_code_block(p, indent) {
+        return Absent;
+    }
+    let m = p.start();
+    let lines = p.start();
+
+    // Blank lines don't interrupt the block as long as the following line is indented enough
+    while at_indent_code_block(p, indent) {
+        let line = p.start();
+        p.re_lex(MarkdownReLexContext::Line);
+        let _ = parse_textual(p, MarkdownLexContext::Regular);
+        line.complete(p, MD_INDENTED_CODE_LINE);
+    }
+
+    lines.complete(p, MD_INDENTED_CODE_LINE_LIST);
+    Present(m.complete(p, MD_INDENT_CODE_BLOCK))
+}
+
+pub(crate) fn at_fenced_code_block(p: &mut MarkdownParser) -> bool {
+    p.at(MD_CODE_FENCE_LITERAL)
+}
+
+/// Parses a code block delimited by fences of backticks or tildes.
+///
+/// See https://spec.commonmark.org/0.31.2/#fenced-code-blocks
+pub(crate) fn parse_fenced_code_block(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    if !at_fenced_code_block(p) {
+        return Absent;
+    }
+    let m = p.start();
+
+    let fence = p.cur_text();
+    let code_context = MarkdownLexContext::FencedCode {
+        fence: fence.as_bytes()[0],
+        length: u16::try_from(fence.len()).unwrap_or(u16::MAX),
+        indent: u16::try_from(indent).unwrap_or(u16::MAX),
+    };
+
+    if p.rest_of_line().trim().is_empty() {
+        p.bump_with_context(MD_CODE_FENCE_LITERAL, code_context);
+    } else {
+        p.bump_with_context(MD_CODE_FENCE_LITERAL, MarkdownLexContext::Inline);
+        let _ = parse_textual(p, code_context);
+    }
+
+    // Tokens that belong to the enclosing container have been lexed as regular tokens
+    let in_block = |p: &mut MarkdownParser| !p.at(T![EOF]) && p.line_indentation() >= indent;
+
+    let content = p.start();
+    if p.at(MD_TEXTUAL_LITERAL) && in_block(p) {
+        let _ = parse_textual(p, code_context);
+    }
+    content.complete(p, MD_INLINE_ITEM_LIST);
+
+    if p.at(MD_CODE_FENCE_LITERAL) && !at_same_line(p) && in_block(p) {
+        p.bump(MD_CODE_FENCE_LITERAL);
+    }
+
+    Present(m.complete(p, MD_FENCED_CODE_BLOCK))
+}
@@ -0,0 +1,53 @@
+use crate::lexer::MarkdownLexContext;
+use crate::parser::MarkdownParser;
+use crate::syntax::at_same_line;
+use crate::syntax::inline::parse_inline_content;
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+
+pub(crate) fn at_header(p: &mut MarkdownParser) -> bool {
+    p.at(T![#])
+}
+
+/// Parses an ATX heading, such as `## Title ##`.
+///
+/// See https://spec.commonmark.org/0.31.2/#atx-headings
+pub(crate) fn parse_header(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !at_header(p) {
+        return Absent;
+    }
+    let m = p.start();
+
+    let before = p.start();
+    while p.at(T![#]) {
+        let hash = p.start();
+        // The content after the opening sequence is lexed as heading content
+        if p.rest_of_line().starts_with('#') {
+            p.bump(T![#]);
+        } else {
+            p.bump_with_context(T![#], MarkdownLexContext::Heading);
+        }
+        hash.complete(p, MD_HASH);
+    }
+    before.complete(p, MD_HASH_LIST);
+
+    if p.at(MD_TEXTUAL_LITERAL) && at_same_line(p) {
+        let paragraph = p.start();
+        let content = p.cur_range();
+        parse_inline_content(p, &[content], MarkdownLexContext::Heading);
+        paragraph.complete(p, MD_PARAGRAPH);
+    }
+
+    let after = p.start();
+    while p.at(T![#]) && at_same_line(p) {
+        let hash = p.start();
+        p.bump_with_context(T![#], MarkdownLexContext::Heading);
+        hash.complete(p, MD_HASH);
+    }
+    after.complete(p, MD_HASH_LIST);
+
+    Present(m.complete(p, MD_HEADER))
+}
@@ -0,0 +1,216 @@
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+use crate::parser::MarkdownParser;
+use crate::syntax::parse_textual;
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+
+/// Elements whose content can contain blank lines
+const RAW_TAGS: &[&str] = &["pre", "script", "style", "textarea"];
+
+const BLOCK_TAGS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "base",
+    "basefont",
+    "blockquote",
+    "body",
+    "caption",
+    "center",
+    "col",
+    "colgroup",
+    "dd",
+    "details",
+    "dialog",
+    "dir",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "frame",
+    "frameset",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hr",
+    "html",
+    "iframe",
+    "legend",
+    "li",
+    "link",
+    "main",
+    "menu",
+    "menuitem",
+    "nav",
+    "noframes",
+    "ol",
+    "optgroup",
+    "option",
+    "p",
+    "param",
+    "search",
+    "section",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "title",
+    "tr",
+    "track",
+    "ul",
+];
+
+/// The kinds of HTML blocks, as defined by their start and end conditions.
+///
+/// See https://spec.commonmark.org/0.31.2/#html-blocks
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum HtmlBlockKind {
+    /// `<pre`, `<script`, `<style` or `<textarea`, ending with the matching closing tag
+    Raw(&'static str),
+    /// `<!--`, ending with `-->`
+    Comment,
+    /// `<?`, ending with `?>`
+    ProcessingInstruction,
+    /// `<!` followed by a letter, ending with `>`
+    Declaration,
+    /// `<![CDATA[`, ending with `]]>`
+    CData,
+    /// An opening or closing tag of a known block element, ending with a blank line
+    Block,
+    /// Any other complete opening or closing tag alone on its line, ending with a blank line.
+    /// It can't interrupt a paragraph.
+    Other,
+}
+
+impl HtmlBlockKind {
+    fn from_line(line: &str) -> Option<Self> {
+        let line = line.trim_start_matches([' ', '\t']);
+        let rest = line.strip_prefix('<')?;
+
+        if rest.starts_with("!--") {
+            return Some(Self::Comment);
+        }
+        if rest.starts_with('?') {
+            return Some(Self::ProcessingInstruction);
+        }
+        if rest.starts_with("![CDATA[") {
+            return Some(Self::CData);
+        }
+        if rest
+            .strip_prefix('!')
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_alphabetic()))
+        {
+            return Some(Self::Declaration);
+        }
+
+        let (is_closing, rest) = match rest.strip_prefix('/') {
+            Some(rest) => (true, rest),
+            None => (false, rest),
+        };
+        if !rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        let name_end = rest
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+            .unwrap_or(rest.len());
+        let name = &rest[..name_end];
+        let after_name = &rest[name_end..];
+
+        if !is_closing && (after_name.is_empty() || after_name.starts_with([' ', '\t', '>'])) {
+            if let Some(tag) = RAW_TAGS.iter().find(|tag| tag.eq_ignore_ascii_case(name)) {
+                return Some(Self::Raw(tag));
+            }
+        }
+        if BLOCK_TAGS.iter().any(|tag| tag.eq_ignore_ascii_case(name))
+            && (after_name.is_empty()
+                || after_name.starts_with([' ', '\t', '>'])
+                || after_name.starts_with("/>"))
+        {
+            return Some(Self::Block);
+        }
+        // The tag must be the only content of the line
+        let line = line.trim_end();
+        if line.find('>') == Some(line.len() - 1) {
+            return Some(Self::Other);
+        }
+        None
+    }
+
+    /// Returns `true` if `line` satisfies the end condition of the block
+    fn is_end(self, line: &str) -> bool {
+        match self {
+            Self::Raw(tag) => {
+                let closing_tag = format!("</{tag}>");
+                line.as_bytes()
+                    .windows(closing_tag.len())
+                    .any(|window| window.eq_ignore_ascii_case(closing_tag.as_bytes()))
+            }
+            Self::Comment => line.contains("-->"),
+            Self::ProcessingInstruction => line.contains("?>"),
+            Self::Declaration => line.contains('>'),
+            Self::CData => line.contains("]]>"),
+            Self::Block | Self::Other => false,
+        }
+    }
+
+    /// Returns `true` if the block ends before a blank line
+    fn ends_at_blank_line(self) -> bool {
+        matches!(self, Self::Block | Self::Other)
+    }
+}
+
+pub(crate) fn at_html_block(p: &mut MarkdownParser) -> bool {
+    p.at(MD_TEXTUAL_LITERAL) && HtmlBlockKind::from_line(p.cur_text()).is_some()
+}
+
+/// Returns `true` if the current line starts an HTML block that can interrupt a paragraph
+pub(crate) fn at_html_block_interrupt(p: &mut MarkdownParser) -> bool {
+    p.at(MD_TEXTUAL_LITERAL)
+        && HtmlBlockKind::from_line(p.cur_text()).is_some_and(|kind| kind != HtmlBlockKind::Other)
+}
+
+/// Parses an HTML block. Its lines are kept as text.
+///
+/// See https://spec.commonmark.org/0.31.2/#html-blocks
+pub(crate) fn parse_html_block(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    if !p.at(MD_TEXTUAL_LITERAL) {
+        return Absent;
+    }
+    let Some(kind) = HtmlBlockKind::from_line(p.cur_text()) else {
+        return Absent;
+    };
+
+    let m = p.start();
+    let list = p.start();
+
+    loop {
+        p.re_lex(MarkdownReLexContext::Line);
+        let is_end = kind.is_end(p.cur_text());
+        let _ = parse_textual(p, MarkdownLexContext::Regular);
+
+        if is_end || p.at(T![EOF]) || p.column() < indent {
+            break;
+        }
+        if kind.ends_at_blank_line() && p.has_preceding_blank_line() {
+            break;
+        }
+    }
+
+    list.complete(p, MD_INLINE_ITEM_LIST);
+    Present(m.complete(p, MD_HTML_BLOCK))
+}
@@ -0,0 +1,874 @@
+//! Inline content of paragraphs and headings: code spans, emphasis, strikethrough, links and images.
+//!
+//! Inline delimiters can match other delimiters far away in the content, so the structure
+//! of the content is computed first, following the algorithm described in
+//! https://spec.commonmark.org/0.31.2/#phase-2-inline-structure. The parser then emits the
+//! nodes of that structure, re-lexing every token with the range computed for it.
+
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+use crate::parser::MarkdownParser;
+use biome_markdown_syntax::{MarkdownSyntaxKind, T, kind::MarkdownSyntaxKind::*};
+use biome_parser::Parser;
+use biome_parser::token_source::TokenSource;
+use biome_rowan::{TextRange, TextSize};
+use std::cmp::Reverse;
+
+/// Parses the inline content made of `lines` as a [MdInlineItemList](biome_markdown_syntax::MdInlineItemList),
+/// and lexes the token following the content using `context`.
+///
+/// Each line is the range of its content, without its indentation and its trailing whitespace.
+pub(crate) fn parse_inline_content(
+    p: &mut MarkdownParser,
+    lines: &[TextRange],
+    context: MarkdownLexContext,
+) {
+    let elements = inline_elements(p.source().text(), lines);
+    let mut scanner = InlineScanner::new(&elements);
+    scanner.scan();
+
+    let mut nodes = scanner.nodes;
+    nodes.sort_unstable_by_key(|node| (node.start(), Reverse(node.end())));
+
+    InlineEmitter {
+        elements: &elements,
+        nodes: &nodes,
+        context,
+    }
+    .emit_list(p, 0, elements.len(), true);
+}
+
+/// A character of the inline content, or a line ending between two lines
+#[derive(Debug, Copy, Clone)]
+struct InlineElement {
+    range: TextRange,
+    /// The character, or `\n` for a line ending
+    char: char,
+    /// The kind of the line ending: [MD_SOFT_BREAK_LITERAL] or [MD_HARD_LINE_LITERAL]
+    line_ending: Option<MarkdownSyntaxKind>,
+}
+
+/// Splits the content of `lines` into elements.
+///
+/// A line ending is a hard line break when the line ends with two spaces or more, or
+/// with a backslash. The backslash is then part of the line break.
+///
+/// See https://spec.commonmark.org/0.31.2/#hard-line-breaks
+fn inline_elements(source: &str, lines: &[TextRange]) -> Vec<InlineElement> {
+    let mut elements = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        let start = usize::from(line.start());
+        let mut end = usize::from(line.end());
+        let mut line_ending = None;
+
+        if index + 1 < lines.len() {
+            let newline_start = source[end..]
+                .find(['\n', '\r'])
+                .map_or(source.len(), |offset| end + offset);
+            let newline_end = if source[newline_start..].starts_with("\r\n") {
+                newline_start + 2
+            } else {
+                (newline_start + 1).min(source.len())
+            };
+
+            let backslashes = source[start..end]
+                .bytes()
+                .rev()
+                .take_while(|byte| *byte == b'\\')
+                .count();
+            line_ending = if end == newline_start && backslashes % 2 == 1 {
+                end -= 1;
+                Some((MD_HARD_LINE_LITERAL, end, newline_end))
+            } else if source[end..newline_start]
+                .bytes()
+                .filter(|byte| *byte == b' ')
+                .count()
+                >= 2
+            {
+                Some((MD_HARD_LINE_LITERAL, newline_start, newline_end))
+            } else {
+                Some((MD_SOFT_BREAK_LITERAL, newline_start, newline_end))
+            };
+        }
+
+        for (offset, char) in source[start..end].char_indices() {
+            let char_start = start + offset;
+            elements.push(InlineElement {
+                range: text_range(char_start, char_start + char.len_utf8()),
+                char,
+                line_ending: None,
+            });
+        }
+
+        if let Some((kind, start, end)) = line_ending {
+            elements.push(InlineElement {
+                range: text_range(start, end),
+                char: '\n',
+                line_ending: Some(kind),
+            });
+        }
+    }
+
+    elements
+}
+
+fn text_range(start: usize, end: usize) -> TextRange {
+    TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32))
+}
+
+/// A node of the inline structure, whose slots are ranges of elements
+#[derive(Debug)]
+struct InlineNode {
+    kind: MarkdownSyntaxKind,
+    slots: Vec<InlineSlot>,
+}
+
+impl InlineNode {
+    fn start(&self) -> usize {
+        self.slots.first().map_or(0, InlineSlot::start)
+    }
+
+    fn end(&self) -> usize {
+        self.slots.last().map_or(0, InlineSlot::end)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum InlineSlot {
+    /// A token of the given kind
+    Token {
+        kind: MarkdownSyntaxKind,
+        start: usize,
+        end: usize,
+    },
+    /// An inline item list. Its content is plain text when `inline` is `false`.
+    List {
+        start: usize,
+        end: usize,
+        inline: bool,
+    },
+}
+
+impl InlineSlot {
+    fn start(&self) -> usize {
+        match self {
+            Self::Token { start, .. } | Self::List { start, .. } => *start,
+        }
+    }
+
+    fn end(&self) -> usize {
+        match self {
+            Self::Token { end, .. } | Self::List { end, .. } => *end,
+        }
+    }
+}
+
+/// An entry of the delimiter stack
+#[derive(Debug, Copy, Clone)]
+enum Delimiter {
+    /// A run of `*`, `_` or `~`. `start..end` are the elements of the run that haven't
+    /// been matched yet, and `length` is the length of the whole run.
+    Run {
+        char: char,
+        start: usize,
+        end: usize,
+        length: usize,
+        can_open: bool,
+        can_close: bool,
+    },
+    /// A `[` or a `![` starting at `start`
+    Bracket {
+        start: usize,
+        image: bool,
+        active: bool,
+    },
+}
+
+struct InlineScanner<'a> {
+    elements: &'a [InlineElement],
+    delimiters: Vec<Delimiter>,
+    nodes: Vec<InlineNode>,
+}
+
+impl<'a> InlineScanner<'a> {
+    fn new(elements: &'a [InlineElement]) -> Self {
+        Self {
+            elements,
+            delimiters: Vec::new(),
+            nodes: Vec::new(),
+        }
+    }
+
+    /// Returns the character at `index`, or a line ending past the end of the content
+    fn char_at(&self, index: usize) -> char {
+        self.elements
+            .get(index)
+            .map_or('\n', |element| element.char)
+    }
+
+    fn scan(&mut self) {
+        let mut index = 0;
+        while index < self.elements.len() {
+            index = match self.char_at(index) {
+                '\\' if self.char_at(index + 1).is_ascii_punctuation() => index + 2,
+                '`' => self.scan_code_span(index),
+                '<' => self.scan_autolink_or_html(index).unwrap_or(index + 1),
+                '*' | '_' | '~' => self.scan_delimiter_run(index),
+                '[' => {
+                    self.delimiters.push(Delimiter::Bracket {
+                        start: index,
+                        image: false,
+                        active: true,
+                    });
+                    index + 1
+                }
+                '!' if self.char_at(index + 1) == '[' => {
+                    self.delimiters.push(Delimiter::Bracket {
+                        start: index,
+                        image: true,
+                        active: true,
+                    });
+                    index + 2
+                }
+                ']' => self.scan_close_bracket(index),
+                _ => index + 1,
+            };
+        }
+
+        self.process_emphasis(0);
+    }
+
+    /// Returns the length of the run of `char` starting at `index`
+    fn run_length(&self, index: usize, char: char) -> usize {
+        self.elements[index..]
+            .iter()
+            .take_while(|element| element.char == char)
+            .count()
+    }
+
+    /// Scans a code span starting at `index`, and returns the index following it.
+    ///
+    /// See https://spec.commonmark.org/0.31.2/#code-spans
+    fn scan_code_span(&mut self, index: usize) -> usize {
+        let length = self.run_length(index, '`');
+        let mut position = index + length;
+        while position < self.elements.len() {
+            if self.char_at(position) != '`' {
+                position += 1;
+                continue;
+            }
+            let closing_length = self.run_length(position, '`');
+            if closing_length == length {
+                self.nodes.push(InlineNode {
+                    kind: MD_INLINE_CODE,
+                    slots: vec![
+                        InlineSlot::Token {
+                            kind: MD_CODE_SPAN_DELIMITER_LITERAL,
+                            start: index,
+                            end: index + length,
+                        },
+                        InlineSlot::List {
+                            start: index + length,
+                            end: position,
+                            inline: false,
+                        },
+                        InlineSlot::Token {
+                            kind: MD_CODE_SPAN_DELIMITER_LITERAL,
+                            start: position,
+                            end: position + length,
+                        },
+                    ],
+                });
+                return position + length;
+            }
+            position += closing_length;
+        }
+
+        // The backtick string is literal text when no closing string matches it
+        index + length
+    }
+
+    /// Scans an autolink or a raw HTML tag starting at `index`, and returns the index following it.
+    /// Their content is kept as text.
+    ///
+    /// See https://spec.commonmark.org/0.31.2/#autolinks
+    /// and https://spec.commonmark.org/0.31.2/#raw-html
+    fn scan_autolink_or_html(&self, index: usize) -> Option<usize> {
+        self.scan_autolink(index)
+            .or_else(|| self.scan_html_tag(index))
+    }
+
+    fn scan_autolink(&self, index: usize) -> Option<usize> {
+        let mut position = index + 1;
+        let scheme_length = self.elements[position.min(self.elements.len())..]
+            .iter()
+            .take_while(|element| {
+                element.char.is_ascii_alphanumeric() || matches!(element.char, '+' | '.' | '-')
+            })
+            .count();
+
+        if self.char_at(position).is_ascii_alphabetic()
+            && (2..=32).contains(&scheme_length)
+            && self.char_at(position + scheme_length) == ':'
+        {
+            position += scheme_length + 1;
+            loop {
+                match self.char_at(position) {
+                    '>' => return Some(position + 1),
+                    '<' => return None,
+                    char if char.is_whitespace() || char.is_control() => return None,
+                    _ => position += 1,
+                }
+            }
+        }
+
+        // Email autolink
+        position = index + 1;
+        let local_part_start = position;
+        while self.char_at(position).is_ascii_alphanumeric()
+            || ".!#$%&'*+/=?^_`{|}~-".contains(self.char_at(position))
+        {
+            position += 1;
+        }
+        if position == local_part_start || self.char_at(position) != '@' {
+            return None;
+        }
+        position += 1;
+        let domain_start = position;
+        while self.char_at(position).is_ascii_alphanumeric()
+            || matches!(self.char_at(position), '.' | '-')
+        {
+            position += 1;
+        }
+        (position > domain_start && self.char_at(position) == '>').then_some(position + 1)
+    }
+
+    fn scan_html_tag(&self, index: usize) -> Option<usize> {
+        let position = index + 1;
+        match self.char_at(position) {
+            '!' if self.starts_with(position + 1, "--") => {
+                self.find(position + 3, "-->").map(|end| end + 3)
+            }
+            '!' if self.starts_with(position + 1, "[CDATA[") => {
+                self.find(position + 8, "]]>").map(|end| end + 3)
+            }
+            '!' if self.char_at(position + 1).is_ascii_alphabetic() => {
+                self.find(position + 2, ">").map(|end| end + 1)
+            }
+            '?' => self.find(position + 1, "?>").map(|end| end + 2),
+            '/' => {
+                let mut position = self.skip_tag_name(position + 1)?;
+                position = self.skip_whitespace(position);
+                (self.char_at(position) == '>').then_some(position + 1)
+            }
+            _ => {
+                let mut position = self.skip_tag_name(position)?;
+                let mut quote = None;
+                while position < self.elements.len() {
+                    match (self.char_at(position), quote) {
+                        (char, Some(quote_char)) if char == quote_char => quote = None,
+                        (_, Some(_)) => {}
+                        ('"' | '\'', None) => quote = Some(self.char_at(position)),
+                        ('>', None) => return Some(position + 1),
+                        ('<', None) => return None,
+                        _ => {}
+                    }
+                    position += 1;
+                }
+                None
+            }
+        }
+    }
+
+    /// Skips the name of an HTML tag starting at `index`, which must be followed
+    /// by a whitespace, a `/` or a `>`
+    fn skip_tag_name(&self, index: usize) -> Option<usize> {
+        if !self.char_at(index).is_ascii_alphabetic() {
+            return None;
+        }
+        let mut position = index + 1;
+        while self.char_at(position).is_ascii_alphanumeric() || self.char_at(position) == '-' {
+            position += 1;
+        }
+        let next = self.char_at(position);
+        (next.is_whitespace() || matches!(next, '/' | '>')).then_some(position)
+    }
+
+    fn starts_with(&self, index: usize, text: &str) -> bool {
+        text.chars()
+            .enumerate()
+            .all(|(offset, char)| self.char_at(index + offset) == char)
+    }
+
+    /// Returns the index of the first occurrence of `text` at or after `index`
+    fn find(&self, index: usize, text: &str) -> Option<usize> {
+        (index..self.elements.len()).find(|position| self.starts_with(*position, text))
+    }
+
+    /// Skips spaces, tabs and line endings
+    fn skip_whitespace(&self, mut index: usize) -> usize {
+        while index < self.elements.len() && matches!(self.char_at(index), ' ' | '\t' | '\n') {
+            index += 1;
+        }
+        index
+    }
+
+    /// Pushes the run of `*`, `_` or `~` starting at `index` on the delimiter stack
+    ///
+    /// See https://spec.commonmark.org/0.31.2/#delimiter-run
+    fn scan_delimiter_run(&mut self, index: usize) -> usize {
+        let char = self.char_at(index);
+        let length = self.run_length(index, char);
+        let end = index + length;
+
+        let before = if index == 0 {
+            '\n'
+        } else {
+            self.char_at(index - 1)
+        };
+        let after = self.char_at(end);
+
+        let left_flanking = !after.is_whitespace()
+            && (!is_punctuation(after) || before.is_whitespace() || is_punctuation(before));
+        let right_flanking = !before.is_whitespace()
+            && (!is_punctuation(before) || after.is_whitespace() || is_punctuation(after));
+
+        let (can_open, can_close) = match char {
+            '_' => (
+                left_flanking && (!right_flanking || is_punctuation(before)),
+                right_flanking && (!left_flanking || is_punctuation(after)),
+            ),
+            // GFM strikethrough uses one or two tildes
+            '~' if length > 2 => (false, false),
+            _ => (left_flanking, right_flanking),
+        };
+
+        if can_open || can_close {
+            self.delimiters.push(Delimiter::Run {
+                char,
+                start: index,
+                end,
+                length,
+                can_open,
+                can_close,
+            });
+        }
+
+        end
+    }
+
+    /// Handles a `]`, creating a link or an image when the closest bracket is followed
+    /// by an inline link tail.
+    ///
+    /// See https://spec.commonmark.org/0.31.2/#look-for-link-or-image
+    fn scan_close_bracket(&mut self, index: usize) -> usize {
+        let Some(opener) = self
+            .delimiters
+            .iter()
+            .rposition(|delimiter| matches!(delimiter, Delimiter::Bracket { .. }))
+        else {
+            return index + 1;
+        };
+        let Delimiter::Bracket {
+            start,
+            image,
+            active,
+        } = self.delimiters[opener]
+        else {
+            unreachable!()
+        };
+
+        let tail = if active {
+            self.scan_link_tail(index + 1)
+        } else {
+            None
+        };
+        let Some(r_paren) = tail else {
+            self.delimiters.remove(opener);
+            return index + 1;
+        };
+
+        self.process_emphasis(opener + 1);
+        self.delimiters.truncate(opener);
+
+        // Links can't contain other links
+        if !image {
+            for delimiter in &mut self.delimiters {
+                if let Delimiter::Bracket {
+                    image: false,
+                    active,
+                    ..
+                } = delimiter
+                {
+                    *active = false;
+                }
+            }
+        }
+
+        let text_start = if image { start + 2 } else { start + 1 };
+        let mut slots = Vec::with_capacity(7);
+        if image {
+            slots.push(InlineSlot::Token {
+                kind: T![!],
+                start,
+                end: start + 1,
+            });
+        }
+        slots.extend([
+            InlineSlot::Token {
+                kind: T!['['],
+                start: text_start - 1,
+                end: text_start,
+            },
+            InlineSlot::List {
+                start: text_start,
+                end: index,
+                inline: true,
+            },
+            InlineSlot::Token {
+                kind: T![']'],
+                start: index,
+                end: index + 1,
+            },
+            InlineSlot::Token {
+                kind: T!['('],
+                start: index + 1,
+                end: index + 2,
+            },
+            InlineSlot::List {
+                start: index + 2,
+                end: r_paren,
+                inline: false,
+            },
+            InlineSlot::Token {
+                kind: T![')'],
+                start: r_paren,
+                end: r_paren + 1,
+            },
+        ]);
+        self.nodes.push(InlineNode {
+            kind: if image {
+                MD_INLINE_IMAGE
+            } else {
+                MD_INLINE_LINK
+            },
+            slots,
+        });
+
+        r_paren + 1
+    }
+
+    /// Scans the destination and the title of an inline link, starting at the `(` at `index`,
+    /// and returns the index of the closing `)`.
+    ///
+    /// See https://spec.commonmark.org/0.31.2/#inline-link
+    fn scan_link_tail(&self, index: usize) -> Option<usize> {
+        if self.char_at(index) != '(' || index >= self.elements.len() {
+            return None;
+        }
+        let mut position = self.skip_whitespace(index + 1);
+
+        // Destination
+        if self.char_at(position) == '<' {
+            position += 1;
+            loop {
+                match self.char_at(position) {
+                    '>' => break,
+                    '<' | '\n' => return None,
+                    '\\' if self.char_at(position + 1).is_ascii_punctuation() => position += 2,
+                    _ => position += 1,
+                }
+            }
+            position += 1;
+        } else {
+            let mut depth = 0usize;
+            while position < self.elements.len() {
+                match self.char_at(position) {
+                    '\\' if self.char_at(position + 1).is_ascii_punctuation() => {
+                        position += 2;
+                        continue;
+                    }
+                    '(' => depth += 1,
+                    ')' if depth == 0 => break,
+                    ')' => depth -= 1,
+                    char if char.is_whitespace() || char.is_control() => break,
+                    _ => {}
+                }
+                position += 1;
+            }
+            if depth != 0 {
+                return None;
+            }
+        }
+
+        // Title
+        let before_title = position;
+        position = self.skip_whitespace(position);
+        if position > before_title {
+            let closing = match self.char_at(position) {
+                '"' => Some('"'),
+                '\'' => Some('\''),
+                '(' => Some(')'),
+                _ => None,
+            };
+            if let Some(closing) = closing {
+                position += 1;
+                loop {
+                    if position >= self.elements.len() {
+                        return None;
+                    }
+                    match self.char_at(position) {
+                        '\\' => position += 1,
+                        char if char == closing => break,
+                        '(' if closing == ')' => return None,
+                        _ => {}
+                    }
+                    position += 1;
+                }
+                position = self.skip_whitespace(position + 1);
+            }
+        }
+
+        (self.char_at(position) == ')' && position < self.elements.len()).then_some(position)
+    }
+
+    /// Matches the delimiter runs above `bottom` in the delimiter stack, and removes them
+    /// from the stack.
+    ///
+    /// See https://spec.commonmark.org/0.31.2/#process-emphasis
+    fn process_emphasis(&mut self, bottom: usize) {
+        // The lowest index where an opener can be found, for each kind of closer
+        let mut openers_bottom = [bottom; 18];
+        let mut current = bottom;
+
+        while current < self.delimiters.len() {
+            let Delimiter::Run {
+                char,
+                start: closer_start,
+                end: closer_end,
+                length: closer_length,
+                can_open: closer_can_open,
+                can_close: true,
+            } = self.delimiters[current]
+            else {
+                current += 1;
+                continue;
+            };
+
+            let key = match char {
+                '*' => 0,
+                '_' => 6,
+                _ => 12,
+            } + usize::from(closer_can_open) * 3
+                + closer_length % 3;
+
+            let opener = (openers_bottom[key]..current).rev().find(|index| {
+                let Delimiter::Run {
+                    char: opener_char,
+                    start,
+                    end,
+                    length,
+                    can_open: true,
+                    can_close,
+                } = self.delimiters[*index]
+                else {
+                    return false;
+                };
+                if opener_char != char {
+                    return false;
+                }
+                if char == '~' {
+                    return end - start == closer_end - closer_start;
+                }
+                // The "rule of 3"
+                !((can_close || closer_can_open)
+                    && (length + closer_length) % 3 == 0
+                    && !(length % 3 == 0 && closer_length % 3 == 0))
+            });
+
+            let Some(opener) = opener else {
+                openers_bottom[key] = current;
+                if closer_can_open {
+                    current += 1;
+                } else {
+                    self.delimiters.remove(current);
+                }
+                continue;
+            };
+
+            let Delimiter::Run {
+                start: opener_start,
+                end: opener_end,
+                ..
+            } = self.delimiters[opener]
+            else {
+                unreachable!()
+            };
+
+            let count = if char == '~' {
+                closer_end - closer_start
+            } else if opener_end - opener_start >= 2 && closer_end - closer_start >= 2 {
+                2
+            } else {
+                1
+            };
+            let (kind, token_kind) = match (char, count) {
+                ('*', 1) => (MD_INLINE_ITALIC, T![*]),
+                ('*', _) => (MD_INLINE_EMPHASIS, T![**]),
+                ('_', 1) => (MD_INLINE_ITALIC, T!["_"]),
+                ('_', _) => (MD_INLINE_EMPHASIS, T!["__"]),
+                (_, 1) => (MD_INLINE_STRIKETHROUGH, T![~]),
+                (_, _) => (MD_INLINE_STRIKETHROUGH, T![~~]),
+            };
+            self.nodes.push(InlineNode {
+                kind,
+                slots: vec![
+                    InlineSlot::Token {
+                        kind: token_kind,
+                        start: opener_end - count,
+                        end: opener_end,
+                    },
+                    InlineSlot::List {
+                        start: opener_end,
+                        end: closer_start,
+                        inline: true,
+                    },
+                    InlineSlot::Token {
+                        kind: token_kind,
+                        start: closer_start,
+                        end: closer_start + count,
+                    },
+                ],
+            });
+
+            if let Delimiter::Run { end, .. } = &mut self.delimiters[opener] {
+                *end -= count;
+            }
+            if let Delimiter::Run { start, .. } = &mut self.delimiters[current] {
+                *start += count;
+            }
+
+            // The delimiters between the opener and the closer can't be matched anymore
+            self.delimiters.drain(opener + 1..current);
+            current = opener + 1;
+            for index in &mut openers_bottom {
+                *index = (*index).min(current);
+            }
+
+            if opener_end - count == opener_start {
+                self.delimiters.remove(opener);
+                current -= 1;
+                for index in &mut openers_bottom {
+                    *index = (*index).min(current);
+                }
+            }
+            if closer_start + count == closer_end {
+                self.delimiters.remove(current);
+            }
+        }
+
+        self.delimiters.truncate(bottom);
+    }
+}
+
+/// Returns `true` if `char` is a punctuation character for the flanking rules.
+///
+/// Non-ASCII characters that aren't alphanumeric or whitespace approximate the Unicode
+/// punctuation and symbol categories.
+///
+/// See https://spec.commonmark.org/0.31.2/#unicode-punctuation-character
+fn is_punctuation(char: char) -> bool {
+    char.is_ascii_punctuation()
+        || (!char.is_ascii() && !char.is_alphanumeric() && !char.is_whitespace())
+}
+
+/// Emits the inline structure as nodes and re-lexed tokens
+struct InlineEmitter<'a> {
+    elements: &'a [InlineElement],
+    /// The nodes, sorted by their start and by their decreasing end, so that parents come before their children
+    nodes: &'a [InlineNode],
+    /// The context used to lex the token following the content
+    context: MarkdownLexContext,
+}
+
+impl InlineEmitter<'_> {
+    /// Emits an inline item list with the elements `start..end`
+    fn emit_list(&self, p: &mut MarkdownParser, start: usize, end: usize, inline: bool) {
+        let m = p.start();
+        let mut position = start;
+
+        if inline {
+            let first = self.nodes.partition_point(|node| node.start() < start);
+            for node in &self.nodes[first..] {
+                if node.start() >= end {
+                    break;
+                }
+                // Nodes nested in the previous node are emitted by that node
+                if node.start() < position {
+                    continue;
+                }
+                self.emit_text(p, position, node.start());
+                self.emit_node(p, node);
+                position = node.end();
+            }
+        }
+
+        self.emit_text(p, position, end);
+        m.complete(p, MD_INLINE_ITEM_LIST);
+    }
+
+    fn emit_node(&self, p: &mut MarkdownParser, node: &InlineNode) {
+        let m = p.start();
+        for slot in &node.slots {
+            match *slot {
+                InlineSlot::Token { kind, start, end } => self.bump(p, kind, start, end),
+                InlineSlot::List { start, end, inline } => self.emit_list(p, start, end, inline),
+            }
+        }
+        m.complete(p, node.kind);
+    }
+
+    /// Emits the elements `start..end` as textual nodes and line breaks
+    fn emit_text(&self, p: &mut MarkdownParser, start: usize, end: usize) {
+        let mut position = start;
+        while position < end {
+            let m = p.start();
+            if let Some(kind) = self.elements[position].line_ending {
+                self.bump(p, kind, position, position + 1);
+                position += 1;
+                m.complete(
+                    p,
+                    if kind == MD_HARD_LINE_LITERAL {
+                        MD_HARD_LINE
+                    } else {
+                        MD_SOFT_BREAK
+                    },
+                );
+            } else {
+                let text_end = self.elements[position..end]
+                    .iter()
+                    .position(|element| element.line_ending.is_some())
+                    .map_or(end, |offset| position + offset);
+                self.bump(p, MD_TEXTUAL_LITERAL, position, text_end);
+                position = text_end;
+                m.complete(p, MD_TEXTUAL);
+            }
+        }
+    }
+
+    /// Re-lexes the current token so that it spans the elements `start..end`, and bumps it as `kind`
+    fn bump(&self, p: &mut MarkdownParser, kind: MarkdownSyntaxKind, start: usize, end: usize) {
+        debug_assert_eq!(p.cur_range().start(), self.elements[start].range.start());
+
+        let token_end = self.elements[end - 1].range.end();
+        p.re_lex(MarkdownReLexContext::Span {
+            end: usize::from(token_end),
+        });
+        let context = if end == self.elements.len() {
+            self.context
+        } else {
+            MarkdownLexContext::InlineContent
+        };
+        p.bump_remap_with_context(kind, context);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::parser::MarkdownParser;
+use crate::syntax::parse_block_list;
+use biome_markdown_syntax::{T, column_width, kind::MarkdownSyntaxKind::*};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+    token_set,
+};
+
+pub(crate) fn at_bullet_list(p: &mut MarkdownParser) -> bool {
+    p.at_ts(token_set![T![-], T![*], T![+]])
+}
+
+pub(crate) fn at_order_list(p: &mut MarkdownParser) -> bool {
+    p.at(MD_ORDERED_LIST_MARKER)
+}
+
+/// Parses a list of items starting with `-`, `*` or `+`.
+///
+/// See https://spec.commonmark.org/0.31.2/#lists
+pub(crate) fn parse_bullet_list(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    if !at_bullet_list(p) {
+        return Absent;
+    }
+    let m = p.start();
+    parse_list_items(p, indent);
+    Present(m.complete(p, MD_BULLET_LIST_ITEM))
+}
+
+/// Parses a list of items starting with a number followed by `.` or `)`.
+///
+/// See https://spec.commonmark.org/0.31.2/#lists
+pub(crate) fn parse_order_list(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    if !at_order_list(p) {
+        return Absent;
+    }
+    let m = p.start();
+    parse_list_items(p, indent);
+    Present(m.complete(p, MD_ORDER_LIST_ITEM))
+}
+
+/// Parses consecutive items sharing the same kind of marker. Changing the bullet
+/// character, or the delimiter of an ordered list, starts a new list.
+fn parse_list_items(p: &mut MarkdownParser, indent: usize) {
+    let list = p.start();
+    let marker = list_marker(p);
+
+    loop {
+        parse_bullet(p);
+
+        let column = p.column();
+        if list_marker(p) != marker || column < indent || column >= indent + 4 {
+            break;
+        }
+    }
+
+    list.complete(p, MD_BULLET_LIST);
+}
+
+/// Returns the character identifying the kind of the list item marker at the current token
+fn list_marker(p: &mut MarkdownParser) -> Option<char> {
+    if at_bullet_list(p) {
+        p.cur_text().chars().next()
+    } else if at_order_list(p) {
+        p.cur_text().chars().last()
+    } else {
+        None
+    }
+}
+
+/// Parses a list item. Its content is made of all the following blocks indented
+/// at least as much as the first character after the marker.
+///
+/// See https://spec.commonmark.org/0.31.2/#list-items
+fn parse_bullet(p: &mut MarkdownParser) {
+    let m = p.start();
+
+    let marker_end = p.column() + p.cur_text().len();
+    let rest = p.rest_of_line();
+    let spacing = rest
+        .find(|c| c != ' ' && c != '\t')
+        .map(|end| column_width(&rest[..end]));
+    let content_indent = match spacing {
+        // The item starts with a blank line or with an indented code block
+        None | Some(5..) => marker_end + 1,
+        Some(spacing) => marker_end + spacing,
+    };
+
+    p.bump_any();
+    let _ = parse_block_list(p, content_indent);
+
+    m.complete(p, MD_BULLET);
+}
@@ -0,0 +1,103 @@
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+use crate::parser::MarkdownParser;
+use crate::syntax::html_block::at_html_block_interrupt;
+use crate::syntax::inline::parse_inline_content;
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+use biome_rowan::TextRange;
+
+/// Parses a paragraph, or a setext heading when the paragraph is followed by an underline.
+///
+/// The lines of the paragraph are found first, then their content is parsed as inline content.
+///
+/// See https://spec.commonmark.org/0.31.2/#paragraphs
+/// and https://spec.commonmark.org/0.31.2/#setext-headings
+pub(crate) fn parse_paragraph(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    if p.at(T![EOF]) {
+        return Absent;
+    }
+    let lines = paragraph_lines(p, indent);
+
+    let m = p.start();
+    parse_inline_content(p, &lines, MarkdownLexContext::Regular);
+    let paragraph = m.complete(p, MD_PARAGRAPH);
+
+    if at_setext_underline(p, indent) {
+        let m = paragraph.precede(p);
+        p.bump_remap(MD_SETEXT_UNDERLINE_LITERAL);
+        return Present(m.complete(p, MD_SETEXT_HEADER));
+    }
+
+    Present(paragraph)
+}
+
+/// Returns the ranges of the lines of the paragraph starting at the current token,
+/// without their indentation and their trailing whitespace. The parser doesn't move.
+fn paragraph_lines(p: &mut MarkdownParser, indent: usize) -> Vec<TextRange> {
+    let checkpoint = p.checkpoint();
+    let mut lines = Vec::new();
+
+    loop {
+        p.re_lex(MarkdownReLexContext::Line);
+        lines.push(p.cur_range());
+        p.bump(MD_TEXTUAL_LITERAL);
+        if !at_paragraph_continuation(p, indent) {
+            break;
+        }
+    }
+
+    p.rewind(checkpoint);
+    lines
+}
+
+/// Returns `true` if the current token is the underline of a setext heading
+fn at_setext_underline(p: &mut MarkdownParser, indent: usize) -> bool {
+    if p.at(T![EOF]) || p.has_preceding_blank_line() {
+        return false;
+    }
+    let column = p.column();
+    if column < indent || column >= indent + 4 {
+        return false;
+    }
+    match p.cur() {
+        MD_SETEXT_UNDERLINE_LITERAL => true,
+        MD_THEMATIC_BREAK_LITERAL => p.cur_text().trim_end().bytes().all(|byte| byte == b'-'),
+        T![-] => p.rest_of_line().trim().is_empty(),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the current line continues the paragraph.
+///
+/// A paragraph ends at a blank line, or at the start of a block that can interrupt it.
+/// Lines indented less than the paragraph's container are
+/// [lazy continuation lines](https://spec.commonmark.org/0.31.2/#lazy-continuation-line),
+/// unless they start a block.
+fn at_paragraph_continuation(p: &mut MarkdownParser, indent: usize) -> bool {
+    if p.at(T![EOF]) || p.has_preceding_blank_line() || at_setext_underline(p, indent) {
+        return false;
+    }
+
+    let column = p.column();
+    if column >= indent + 4 {
+        return true;
+    }
+
+    let dedented = column < indent;
+    let interrupts = match p.cur() {
+        MD_THEMATIC_BREAK_LITERAL | MD_CODE_FENCE_LITERAL | T![#] | T![>] => true,
+        // An empty list item can't interrupt a paragraph
+        T![-] | T![*] | T![+] => dedented || !p.rest_of_line().trim().is_empty(),
+        // An ordered list can interrupt a paragraph only when it starts with 1
+        MD_ORDERED_LIST_MARKER => {
+            dedented || (matches!(p.cur_text(), "1." | "1)") && !p.rest_of_line().trim().is_empty())
+        }
+        MD_TEXTUAL_LITERAL => at_html_block_interrupt(p),
+        _ => false,
+    };
+
+    !interrupts
+}
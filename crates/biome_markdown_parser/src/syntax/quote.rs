@@ -0,0 +1,54 @@
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+use crate::parser::MarkdownParser;
+use crate::syntax::{at_same_line, parse_textual};
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+
+pub(crate) fn at_quote(p: &mut MarkdownParser) -> bool {
+    p.at(T![>])
+}
+
+/// Parses a block quote, line by line.
+///
+/// The content of each line is kept as text. Lines without a `>` marker are
+/// [lazy continuation lines](https://spec.commonmark.org/0.31.2/#lazy-continuation-line)
+/// of the paragraph of the previous line.
+///
+/// See https://spec.commonmark.org/0.31.2/#block-quotes
+pub(crate) fn parse_quote(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !at_quote(p) {
+        return Absent;
+    }
+    let m = p.start();
+    let lines = p.start();
+
+    loop {
+        let line = p.start();
+        let has_content = if p.at(T![>]) {
+            p.bump_with_context(T![>], MarkdownLexContext::Inline);
+            at_same_line(p)
+        } else {
+            p.re_lex(MarkdownReLexContext::Line);
+            true
+        };
+        if has_content {
+            let _ = parse_textual(p, MarkdownLexContext::Regular);
+        }
+        line.complete(p, MD_QUOTE_LINE);
+
+        if p.at(T![EOF]) || p.has_preceding_blank_line() {
+            break;
+        }
+
+        let at_lazy_continuation = has_content && p.at(MD_TEXTUAL_LITERAL);
+        if !p.at(T![>]) && !at_lazy_continuation {
+            break;
+        }
+    }
+
+    lines.complete(p, MD_QUOTE_LINE_LIST);
+    Present(m.complete(p, MD_QUOTE))
+}
@@ -0,0 +1,122 @@
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+use crate::parser::MarkdownParser;
+use crate::syntax::{at_same_line, parse_textual};
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+
+/// Returns `true` if the current line is the header row of a table, which is followed
+/// by a delimiter row with the same number of cells.
+pub(crate) fn at_table(p: &mut MarkdownParser) -> bool {
+    if !p.at(MD_TEXTUAL_LITERAL) {
+        return false;
+    }
+    let header = p.cur_text();
+    if !header.contains('|') {
+        return false;
+    }
+    let header_cells = split_row(header).len();
+    let Some(delimiter) = p.next_line() else {
+        return false;
+    };
+    if !delimiter.contains('|') {
+        return false;
+    }
+    let delimiter_cells = split_row(delimiter);
+    delimiter_cells.len() == header_cells && delimiter_cells.into_iter().all(is_delimiter_cell)
+}
+
+/// Parses a [GFM table](https://github.github.com/gfm/#tables-extension-).
+pub(crate) fn parse_table(p: &mut MarkdownParser, indent: usize) -> ParsedSyntax {
+    if !at_table(p) {
+        return Absent;
+    }
+    let m = p.start();
+
+    // Header and delimiter rows
+    parse_table_row(p);
+    parse_table_row(p);
+
+    let rows = p.start();
+    while at_table_row(p, indent) {
+        parse_table_row(p);
+    }
+    rows.complete(p, MD_TABLE_ROW_LIST);
+
+    Present(m.complete(p, MD_TABLE))
+}
+
+/// Returns `true` if the current line continues the table. The table ends
+/// at a blank line or at the start of another block.
+fn at_table_row(p: &mut MarkdownParser, indent: usize) -> bool {
+    if !p.at(MD_TEXTUAL_LITERAL) || p.has_preceding_blank_line() {
+        return false;
+    }
+    let column = p.column();
+    column >= indent && column < indent + 4
+}
+
+fn parse_table_row(p: &mut MarkdownParser) {
+    p.re_lex(MarkdownReLexContext::TableRow);
+    let row = p.start();
+
+    let mut in_row = true;
+    if p.at(T![|]) {
+        p.bump_with_context(T![|], MarkdownLexContext::TableRow);
+        in_row = at_same_line(p);
+    }
+
+    let cells = p.start();
+    while in_row {
+        let cell = p.start();
+        let _ = parse_textual(p, MarkdownLexContext::TableRow);
+        cell.complete(p, MD_TABLE_CELL);
+
+        if p.at(T![|]) && at_same_line(p) {
+            p.bump_with_context(T![|], MarkdownLexContext::TableRow);
+            in_row = at_same_line(p);
+        } else {
+            in_row = false;
+        }
+    }
+    cells.complete(p, MD_TABLE_CELL_LIST);
+
+    row.complete(p, MD_TABLE_ROW);
+}
+
+/// Splits a row into its cells, ignoring the leading and trailing pipes.
+/// Escaped pipes don't separate cells.
+fn split_row(row: &str) -> Vec<&str> {
+    let row = row.trim();
+    let row = row.strip_prefix('|').unwrap_or(row);
+    let row = match row.strip_suffix('|') {
+        Some(stripped) if !stripped.ends_with('\\') => stripped,
+        _ => row,
+    };
+
+    let mut cells = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (index, byte) in row.bytes().enumerate() {
+        match byte {
+            b'\\' => escaped = !escaped,
+            b'|' if !escaped => {
+                cells.push(&row[start..index]);
+                start = index + 1;
+            }
+            _ => escaped = false,
+        }
+    }
+    cells.push(&row[start..]);
+    cells
+}
+
+/// Returns `true` if `cell` is made of dashes, optionally surrounded by colons
+fn is_delimiter_cell(cell: &str) -> bool {
+    let cell = cell.trim();
+    let cell = cell.strip_prefix(':').unwrap_or(cell);
+    let cell = cell.strip_suffix(':').unwrap_or(cell);
+    !cell.is_empty() && cell.bytes().all(|byte| byte == b'-')
+}
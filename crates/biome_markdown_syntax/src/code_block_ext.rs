@@ -0,0 +1,114 @@
+use crate::{AnyMdInline, MarkdownSyntaxToken, MdFencedCodeBlock};
+use biome_rowan::AstNodeList;
+
+impl MdFencedCodeBlock {
+    /// Returns the first word of the info string, which usually names the language of the code
+    pub fn language(&self) -> Option<String> {
+        let token = self.info()?.value_token().ok()?;
+        token
+            .text_trimmed()
+            .split_whitespace()
+            .next()
+            .map(ToString::to_string)
+    }
+
+    /// Returns the token containing the lines of code, if the block isn't empty
+    pub fn code_token(&self) -> Option<MarkdownSyntaxToken> {
+        self.content().iter().find_map(|item| match item {
+            AnyMdInline::MdTextual(textual) => textual.value_token().ok(),
+            _ => None,
+        })
+    }
+
+    /// Returns the code of the block. The indentation of the opening fence is removed from each line.
+    pub fn code(&self) -> Option<String> {
+        let token = self.code_token()?;
+        let indentation = self.fence_indentation();
+        let mut code = token
+            .text_trimmed()
+            .lines()
+            .map(|line| strip_indentation(line, indentation))
+            .collect::<Vec<_>>()
+            .join("\n");
+        code.push('\n');
+        Some(code)
+    }
+
+    /// Returns the column of the opening fence
+    pub fn fence_indentation(&self) -> usize {
+        self.l_fence_token().map_or(0, |token| token_column(&token))
+    }
+}
+
+/// Returns the column at which `token` starts. Tabs advance to the next multiple of 4.
+///
+/// See https://spec.commonmark.org/0.31.2/#tabs
+pub fn token_column(token: &MarkdownSyntaxToken) -> usize {
+    // The text between the start of the line and the token, in reverse order
+    let mut segments = Vec::new();
+
+    let mut push = |text: &str| match text.rfind(['\n', '\r']) {
+        Some(line_break) => {
+            segments.push(text[line_break + 1..].to_string());
+            false
+        }
+        None => {
+            segments.push(text.to_string());
+            true
+        }
+    };
+
+    let mut current = token.clone();
+    let mut is_start = true;
+    'line: loop {
+        if !is_start {
+            for piece in current.trailing_trivia().pieces().rev() {
+                if !push(piece.text()) {
+                    break 'line;
+                }
+            }
+            if !push(current.text_trimmed()) {
+                break;
+            }
+        }
+        is_start = false;
+
+        for piece in current.leading_trivia().pieces().rev() {
+            if !push(piece.text()) {
+                break 'line;
+            }
+        }
+
+        match current.prev_token() {
+            Some(previous) => current = previous,
+            None => break,
+        }
+    }
+
+    let prefix: String = segments.into_iter().rev().collect();
+    column_width(&prefix)
+}
+
+/// Returns the width of `text`, where tabs advance to the next multiple of 4
+pub fn column_width(text: &str) -> usize {
+    text.chars().fold(0, |column, c| match c {
+        '\t' => column + 4 - column % 4,
+        _ => column + 1,
+    })
+}
+
+/// Removes up to `width` columns of leading whitespace from `line`
+pub fn strip_indentation(line: &str, width: usize) -> &str {
+    let mut column = 0;
+    for (index, c) in line.char_indices() {
+        if column >= width {
+            return &line[index..];
+        }
+        column = match c {
+            ' ' => column + 1,
+            '\t' => column + 4 - column % 4,
+            _ => return &line[index..],
+        };
+    }
+    ""
+}
@@ -27,11 +27,14 @@ pub enum MarkdownSyntaxKind {
     BACKTICK,
     TRIPLE_BACKTICK,
     TILDE,
+    DOUBLE_TILDE,
     WHITESPACE3,
     UNDERSCORE,
     DOUBLE_UNDERSCORE,
     HASH,
     COMMA,
+    PLUS,
+    PIPE,
     NULL_KW,
     MD_HARD_LINE_LITERAL,
     MD_SOFT_BREAK_LITERAL,
@@ -39,6 +42,10 @@ pub enum MarkdownSyntaxKind {
     MD_STRING_LITERAL,
     MD_INDENT_CHUNK_LITERAL,
     MD_THEMATIC_BREAK_LITERAL,
+    MD_SETEXT_UNDERLINE_LITERAL,
+    MD_CODE_FENCE_LITERAL,
+    MD_CODE_SPAN_DELIMITER_LITERAL,
+    MD_ORDERED_LIST_MARKER,
     MD_ERROR_LITERAL,
     ERROR_TOKEN,
     NEWLINE,
@@ -53,14 +60,14 @@ pub enum MarkdownSyntaxKind {
     MD_HEADER,
     MD_INDENT_CODE_BLOCK,
     MD_FENCED_CODE_BLOCK,
-    MD_CODE_NAME_LIST,
     MD_HTML_BLOCK,
     MD_LINK_BLOCK,
     MD_QUOTE,
+    MD_QUOTE_LINE_LIST,
+    MD_QUOTE_LINE,
     MD_ORDER_LIST_ITEM,
     MD_BULLET_LIST_ITEM,
     MD_BULLET_LIST,
-    MD_ORDER_LIST,
     MD_PARAGRAPH,
     MD_INLINE_ITEM_LIST,
     MD_INLINE_EMPHASIS,
@@ -69,18 +76,20 @@ pub enum MarkdownSyntaxKind {
     MD_BULLET,
     MD_INLINE_LINK,
     MD_INLINE_IMAGE,
-    MD_INLINE_IMAGE_ALT,
+    MD_INLINE_STRIKETHROUGH,
     MD_INDENTED_CODE_LINE,
-    MD_INLINE_IMAGE_LINK,
-    MD_INLINE_IMAGE_SOURCE,
     MD_INDENTED_CODE_LINE_LIST,
     MD_HARD_LINE,
     MD_SOFT_BREAK,
     MD_TEXTUAL,
     MD_SETEXT_HEADER,
     MD_STRING,
-    MD_INDENT,
     MD_THEMATIC_BREAK_BLOCK,
+    MD_TABLE,
+    MD_TABLE_ROW_LIST,
+    MD_TABLE_ROW,
+    MD_TABLE_CELL_LIST,
+    MD_TABLE_CELL,
     #[doc(hidden)]
     __LAST,
 }
@@ -104,11 +113,14 @@ impl MarkdownSyntaxKind {
                 | BACKTICK
                 | TRIPLE_BACKTICK
                 | TILDE
+                | DOUBLE_TILDE
                 | WHITESPACE3
                 | UNDERSCORE
                 | DOUBLE_UNDERSCORE
                 | HASH
                 | COMMA
+                | PLUS
+                | PIPE
         )
     }
     pub const fn is_literal(self) -> bool {
@@ -120,6 +132,10 @@ impl MarkdownSyntaxKind {
                 | MD_STRING_LITERAL
                 | MD_INDENT_CHUNK_LITERAL
                 | MD_THEMATIC_BREAK_LITERAL
+                | MD_SETEXT_UNDERLINE_LITERAL
+                | MD_CODE_FENCE_LITERAL
+                | MD_CODE_SPAN_DELIMITER_LITERAL
+                | MD_ORDERED_LIST_MARKER
                 | MD_ERROR_LITERAL
         )
     }
@@ -128,11 +144,12 @@ impl MarkdownSyntaxKind {
             self,
             MD_BLOCK_LIST
                 | MD_HASH_LIST
-                | MD_CODE_NAME_LIST
+                | MD_QUOTE_LINE_LIST
                 | MD_BULLET_LIST
-                | MD_ORDER_LIST
                 | MD_INLINE_ITEM_LIST
                 | MD_INDENTED_CODE_LINE_LIST
+                | MD_TABLE_ROW_LIST
+                | MD_TABLE_CELL_LIST
         )
     }
     pub fn from_keyword(ident: &str) -> Option<Self> {
@@ -159,11 +176,14 @@ impl MarkdownSyntaxKind {
             BACKTICK => "`",
             TRIPLE_BACKTICK => "```",
             TILDE => "~",
+            DOUBLE_TILDE => "~~",
             WHITESPACE3 => "   ",
             UNDERSCORE => "_",
             DOUBLE_UNDERSCORE => "__",
             HASH => "#",
             COMMA => ",",
+            PLUS => "+",
+            PIPE => "|",
             NULL_KW => "null",
             EOF => "EOF",
             _ => return None,
@@ -173,4 +193,4 @@ impl MarkdownSyntaxKind {
 }
 #[doc = r" Utility macro for creating a SyntaxKind through simple macro syntax"]
 #[macro_export]
-macro_rules ! T { [<] => { $ crate :: MarkdownSyntaxKind :: L_ANGLE } ; [>] => { $ crate :: MarkdownSyntaxKind :: R_ANGLE } ; ['('] => { $ crate :: MarkdownSyntaxKind :: L_PAREN } ; [')'] => { $ crate :: MarkdownSyntaxKind :: R_PAREN } ; ['['] => { $ crate :: MarkdownSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: MarkdownSyntaxKind :: R_BRACK } ; [/] => { $ crate :: MarkdownSyntaxKind :: SLASH } ; [=] => { $ crate :: MarkdownSyntaxKind :: EQ } ; [!] => { $ crate :: MarkdownSyntaxKind :: BANG } ; [-] => { $ crate :: MarkdownSyntaxKind :: MINUS } ; [*] => { $ crate :: MarkdownSyntaxKind :: STAR } ; [**] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_STAR } ; ['`'] => { $ crate :: MarkdownSyntaxKind :: BACKTICK } ; ["```"] => { $ crate :: MarkdownSyntaxKind :: TRIPLE_BACKTICK } ; [~] => { $ crate :: MarkdownSyntaxKind :: TILDE } ; ["   "] => { $ crate :: MarkdownSyntaxKind :: WHITESPACE3 } ; ["_"] => { $ crate :: MarkdownSyntaxKind :: UNDERSCORE } ; ["__"] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_UNDERSCORE } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; [,] => { $ crate :: MarkdownSyntaxKind :: COMMA } ; [null] => { $ crate :: MarkdownSyntaxKind :: NULL_KW } ; [ident] => { $ crate :: MarkdownSyntaxKind :: IDENT } ; [EOF] => { $ crate :: MarkdownSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: MarkdownSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; }
+macro_rules ! T { [<] => { $ crate :: MarkdownSyntaxKind :: L_ANGLE } ; [>] => { $ crate :: MarkdownSyntaxKind :: R_ANGLE } ; ['('] => { $ crate :: MarkdownSyntaxKind :: L_PAREN } ; [')'] => { $ crate :: MarkdownSyntaxKind :: R_PAREN } ; ['['] => { $ crate :: MarkdownSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: MarkdownSyntaxKind :: R_BRACK } ; [/] => { $ crate :: MarkdownSyntaxKind :: SLASH } ; [=] => { $ crate :: MarkdownSyntaxKind :: EQ } ; [!] => { $ crate :: MarkdownSyntaxKind :: BANG } ; [-] => { $ crate :: MarkdownSyntaxKind :: MINUS } ; [*] => { $ crate :: MarkdownSyntaxKind :: STAR } ; [**] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_STAR } ; ['`'] => { $ crate :: MarkdownSyntaxKind :: BACKTICK } ; ["```"] => { $ crate :: MarkdownSyntaxKind :: TRIPLE_BACKTICK } ; [~] => { $ crate :: MarkdownSyntaxKind :: TILDE } ; [~~] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_TILDE } ; ["   "] => { $ crate :: MarkdownSyntaxKind :: WHITESPACE3 } ; ["_"] => { $ crate :: MarkdownSyntaxKind :: UNDERSCORE } ; ["__"] => { $ crate :: MarkdownSyntaxKind :: DOUBLE_UNDERSCORE } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; [,] => { $ crate :: MarkdownSyntaxKind :: COMMA } ; [+] => { $ crate :: MarkdownSyntaxKind :: PLUS } ; [|] => { $ crate :: MarkdownSyntaxKind :: PIPE } ; [null] => { $ crate :: MarkdownSyntaxKind :: NULL_KW } ; [ident] => { $ crate :: MarkdownSyntaxKind :: IDENT } ; [EOF] => { $ crate :: MarkdownSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: MarkdownSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; }
@@ -48,10 +48,6 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdHtmlBlock::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INDENT => {
-                    let $pattern = unsafe { $crate::MdIndent::new_unchecked(node) };
-                    $body
-                }
                 $crate::MarkdownSyntaxKind::MD_INDENT_CODE_BLOCK => {
                     let $pattern = unsafe { $crate::MdIndentCodeBlock::new_unchecked(node) };
                     $body
@@ -72,18 +68,6 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdInlineImage::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_INLINE_IMAGE_ALT => {
-                    let $pattern = unsafe { $crate::MdInlineImageAlt::new_unchecked(node) };
-                    $body
-                }
-                $crate::MarkdownSyntaxKind::MD_INLINE_IMAGE_LINK => {
-                    let $pattern = unsafe { $crate::MdInlineImageLink::new_unchecked(node) };
-                    $body
-                }
-                $crate::MarkdownSyntaxKind::MD_INLINE_IMAGE_SOURCE => {
-                    let $pattern = unsafe { $crate::MdInlineImageSource::new_unchecked(node) };
-                    $body
-                }
                 $crate::MarkdownSyntaxKind::MD_INLINE_ITALIC => {
                     let $pattern = unsafe { $crate::MdInlineItalic::new_unchecked(node) };
                     $body
@@ -92,6 +76,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdInlineLink::new_unchecked(node) };
                     $body
                 }
+                $crate::MarkdownSyntaxKind::MD_INLINE_STRIKETHROUGH => {
+                    let $pattern = unsafe { $crate::MdInlineStrikethrough::new_unchecked(node) };
+                    $body
+                }
                 $crate::MarkdownSyntaxKind::MD_LINK_BLOCK => {
                     let $pattern = unsafe { $crate::MdLinkBlock::new_unchecked(node) };
                     $body
@@ -108,6 +96,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdQuote::new_unchecked(node) };
                     $body
                 }
+                $crate::MarkdownSyntaxKind::MD_QUOTE_LINE => {
+                    let $pattern = unsafe { $crate::MdQuoteLine::new_unchecked(node) };
+                    $body
+                }
                 $crate::MarkdownSyntaxKind::MD_SETEXT_HEADER => {
                     let $pattern = unsafe { $crate::MdSetextHeader::new_unchecked(node) };
                     $body
@@ -116,6 +108,18 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdSoftBreak::new_unchecked(node) };
                     $body
                 }
+                $crate::MarkdownSyntaxKind::MD_TABLE => {
+                    let $pattern = unsafe { $crate::MdTable::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_CELL => {
+                    let $pattern = unsafe { $crate::MdTableCell::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_ROW => {
+                    let $pattern = unsafe { $crate::MdTableRow::new_unchecked(node) };
+                    $body
+                }
                 $crate::MarkdownSyntaxKind::MD_TEXTUAL => {
                     let $pattern = unsafe { $crate::MdTextual::new_unchecked(node) };
                     $body
@@ -136,10 +140,6 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdBulletList::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_CODE_NAME_LIST => {
-                    let $pattern = unsafe { $crate::MdCodeNameList::new_unchecked(node) };
-                    $body
-                }
                 $crate::MarkdownSyntaxKind::MD_HASH_LIST => {
                     let $pattern = unsafe { $crate::MdHashList::new_unchecked(node) };
                     $body
@@ -152,8 +152,16 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::MdInlineItemList::new_unchecked(node) };
                     $body
                 }
-                $crate::MarkdownSyntaxKind::MD_ORDER_LIST => {
-                    let $pattern = unsafe { $crate::MdOrderList::new_unchecked(node) };
+                $crate::MarkdownSyntaxKind::MD_QUOTE_LINE_LIST => {
+                    let $pattern = unsafe { $crate::MdQuoteLineList::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_CELL_LIST => {
+                    let $pattern = unsafe { $crate::MdTableCellList::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MD_TABLE_ROW_LIST => {
+                    let $pattern = unsafe { $crate::MdTableRowList::new_unchecked(node) };
                     $body
                 }
                 _ => unreachable!(),
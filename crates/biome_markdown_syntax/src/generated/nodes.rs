@@ -37,18 +37,14 @@ impl MdBullet {
     pub fn as_fields(&self) -> MdBulletFields {
         MdBulletFields {
             bullet: self.bullet(),
-            space_token: self.space_token(),
             content: self.content(),
         }
     }
     pub fn bullet(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn space_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
-    }
-    pub fn content(&self) -> MdInlineItemList {
-        support::list(&self.syntax, 2usize)
+    pub fn content(&self) -> MdBlockList {
+        support::list(&self.syntax, 1usize)
     }
 }
 impl Serialize for MdBullet {
@@ -62,8 +58,7 @@ impl Serialize for MdBullet {
 #[derive(Serialize)]
 pub struct MdBulletFields {
     pub bullet: SyntaxResult<SyntaxToken>,
-    pub space_token: SyntaxResult<SyntaxToken>,
-    pub content: MdInlineItemList,
+    pub content: MdBlockList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdBulletListItem {
@@ -162,30 +157,22 @@ impl MdFencedCodeBlock {
     pub fn as_fields(&self) -> MdFencedCodeBlockFields {
         MdFencedCodeBlockFields {
             l_fence_token: self.l_fence_token(),
-            code_list: self.code_list(),
-            l_hard_line: self.l_hard_line(),
+            info: self.info(),
             content: self.content(),
-            r_hard_line: self.r_hard_line(),
             r_fence_token: self.r_fence_token(),
         }
     }
     pub fn l_fence_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn code_list(&self) -> MdCodeNameList {
-        support::list(&self.syntax, 1usize)
-    }
-    pub fn l_hard_line(&self) -> SyntaxResult<MdHardLine> {
-        support::required_node(&self.syntax, 2usize)
-    }
-    pub fn content(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 3usize)
+    pub fn info(&self) -> Option<MdTextual> {
+        support::node(&self.syntax, 1usize)
     }
-    pub fn r_hard_line(&self) -> SyntaxResult<MdHardLine> {
-        support::required_node(&self.syntax, 4usize)
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 2usize)
     }
-    pub fn r_fence_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 5usize)
+    pub fn r_fence_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 3usize)
     }
 }
 impl Serialize for MdFencedCodeBlock {
@@ -199,11 +186,9 @@ impl Serialize for MdFencedCodeBlock {
 #[derive(Serialize)]
 pub struct MdFencedCodeBlockFields {
     pub l_fence_token: SyntaxResult<SyntaxToken>,
-    pub code_list: MdCodeNameList,
-    pub l_hard_line: SyntaxResult<MdHardLine>,
-    pub content: SyntaxResult<MdTextual>,
-    pub r_hard_line: SyntaxResult<MdHardLine>,
-    pub r_fence_token: SyntaxResult<SyntaxToken>,
+    pub info: Option<MdTextual>,
+    pub content: MdInlineItemList,
+    pub r_fence_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdHardLine {
@@ -336,11 +321,11 @@ impl MdHtmlBlock {
     }
     pub fn as_fields(&self) -> MdHtmlBlockFields {
         MdHtmlBlockFields {
-            md_textual: self.md_textual(),
+            content: self.content(),
         }
     }
-    pub fn md_textual(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 0usize)
     }
 }
 impl Serialize for MdHtmlBlock {
@@ -353,42 +338,7 @@ impl Serialize for MdHtmlBlock {
 }
 #[derive(Serialize)]
 pub struct MdHtmlBlockFields {
-    pub md_textual: SyntaxResult<MdTextual>,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdIndent {
-    pub(crate) syntax: SyntaxNode,
-}
-impl MdIndent {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn as_fields(&self) -> MdIndentFields {
-        MdIndentFields {
-            value_token: self.value_token(),
-        }
-    }
-    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
-    }
-}
-impl Serialize for MdIndent {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[derive(Serialize)]
-pub struct MdIndentFields {
-    pub value_token: SyntaxResult<SyntaxToken>,
+    pub content: MdInlineItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdIndentCodeBlock {
@@ -441,15 +391,11 @@ impl MdIndentedCodeLine {
     }
     pub fn as_fields(&self) -> MdIndentedCodeLineFields {
         MdIndentedCodeLineFields {
-            indentation: self.indentation(),
             content: self.content(),
         }
     }
-    pub fn indentation(&self) -> SyntaxResult<MdIndent> {
-        support::required_node(&self.syntax, 0usize)
-    }
     pub fn content(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 1usize)
+        support::required_node(&self.syntax, 0usize)
     }
 }
 impl Serialize for MdIndentedCodeLine {
@@ -462,7 +408,6 @@ impl Serialize for MdIndentedCodeLine {
 }
 #[derive(Serialize)]
 pub struct MdIndentedCodeLineFields {
-    pub indentation: SyntaxResult<MdIndent>,
     pub content: SyntaxResult<MdTextual>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -571,31 +516,35 @@ impl MdInlineImage {
     }
     pub fn as_fields(&self) -> MdInlineImageFields {
         MdInlineImageFields {
-            l_brack_token: self.l_brack_token(),
             excl_token: self.excl_token(),
+            l_brack_token: self.l_brack_token(),
             alt: self.alt(),
-            source: self.source(),
             r_brack_token: self.r_brack_token(),
-            link: self.link(),
+            l_paren_token: self.l_paren_token(),
+            source: self.source(),
+            r_paren_token: self.r_paren_token(),
         }
     }
-    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn excl_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn excl_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 1usize)
     }
-    pub fn alt(&self) -> SyntaxResult<MdInlineImageAlt> {
-        support::required_node(&self.syntax, 2usize)
-    }
-    pub fn source(&self) -> SyntaxResult<MdInlineImageSource> {
-        support::required_node(&self.syntax, 3usize)
+    pub fn alt(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 2usize)
     }
     pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 4usize)
     }
-    pub fn link(&self) -> Option<MdInlineImageLink> {
-        support::node(&self.syntax, 5usize)
+    pub fn source(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 5usize)
+    }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 6usize)
     }
 }
 impl Serialize for MdInlineImage {
@@ -608,18 +557,19 @@ impl Serialize for MdInlineImage {
 }
 #[derive(Serialize)]
 pub struct MdInlineImageFields {
-    pub l_brack_token: SyntaxResult<SyntaxToken>,
     pub excl_token: SyntaxResult<SyntaxToken>,
-    pub alt: SyntaxResult<MdInlineImageAlt>,
-    pub source: SyntaxResult<MdInlineImageSource>,
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub alt: MdInlineItemList,
     pub r_brack_token: SyntaxResult<SyntaxToken>,
-    pub link: Option<MdInlineImageLink>,
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub source: MdInlineItemList,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineImageAlt {
+pub struct MdInlineItalic {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineImageAlt {
+impl MdInlineItalic {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -629,24 +579,24 @@ impl MdInlineImageAlt {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineImageAltFields {
-        MdInlineImageAltFields {
-            l_brack_token: self.l_brack_token(),
+    pub fn as_fields(&self) -> MdInlineItalicFields {
+        MdInlineItalicFields {
+            l_fence: self.l_fence(),
             content: self.content(),
-            r_brack_token: self.r_brack_token(),
+            r_fence: self.r_fence(),
         }
     }
-    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn l_fence(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
     pub fn content(&self) -> MdInlineItemList {
         support::list(&self.syntax, 1usize)
     }
-    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn r_fence(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 2usize)
     }
 }
-impl Serialize for MdInlineImageAlt {
+impl Serialize for MdInlineItalic {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -655,16 +605,16 @@ impl Serialize for MdInlineImageAlt {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineImageAltFields {
-    pub l_brack_token: SyntaxResult<SyntaxToken>,
+pub struct MdInlineItalicFields {
+    pub l_fence: SyntaxResult<SyntaxToken>,
     pub content: MdInlineItemList,
-    pub r_brack_token: SyntaxResult<SyntaxToken>,
+    pub r_fence: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineImageLink {
+pub struct MdInlineLink {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineImageLink {
+impl MdInlineLink {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -674,69 +624,36 @@ impl MdInlineImageLink {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineImageLinkFields {
-        MdInlineImageLinkFields {
+    pub fn as_fields(&self) -> MdInlineLinkFields {
+        MdInlineLinkFields {
+            l_brack_token: self.l_brack_token(),
+            text: self.text(),
+            r_brack_token: self.r_brack_token(),
             l_paren_token: self.l_paren_token(),
-            content: self.content(),
+            source: self.source(),
             r_paren_token: self.r_paren_token(),
         }
     }
-    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn content(&self) -> MdInlineItemList {
+    pub fn text(&self) -> MdInlineItemList {
         support::list(&self.syntax, 1usize)
     }
-    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 2usize)
     }
-}
-impl Serialize for MdInlineImageLink {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[derive(Serialize)]
-pub struct MdInlineImageLinkFields {
-    pub l_paren_token: SyntaxResult<SyntaxToken>,
-    pub content: MdInlineItemList,
-    pub r_paren_token: SyntaxResult<SyntaxToken>,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineImageSource {
-    pub(crate) syntax: SyntaxNode,
-}
-impl MdInlineImageSource {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn as_fields(&self) -> MdInlineImageSourceFields {
-        MdInlineImageSourceFields {
-            l_paren_token: self.l_paren_token(),
-            content: self.content(),
-            r_paren_token: self.r_paren_token(),
-        }
-    }
     pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
+        support::required_token(&self.syntax, 3usize)
     }
-    pub fn content(&self) -> MdInlineItemList {
-        support::list(&self.syntax, 1usize)
+    pub fn source(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 4usize)
     }
     pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
+        support::required_token(&self.syntax, 5usize)
     }
 }
-impl Serialize for MdInlineImageSource {
+impl Serialize for MdInlineLink {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -745,16 +662,19 @@ impl Serialize for MdInlineImageSource {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineImageSourceFields {
+pub struct MdInlineLinkFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub text: MdInlineItemList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
     pub l_paren_token: SyntaxResult<SyntaxToken>,
-    pub content: MdInlineItemList,
+    pub source: MdInlineItemList,
     pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineItalic {
+pub struct MdInlineStrikethrough {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineItalic {
+impl MdInlineStrikethrough {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -764,8 +684,8 @@ impl MdInlineItalic {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineItalicFields {
-        MdInlineItalicFields {
+    pub fn as_fields(&self) -> MdInlineStrikethroughFields {
+        MdInlineStrikethroughFields {
             l_fence: self.l_fence(),
             content: self.content(),
             r_fence: self.r_fence(),
@@ -781,7 +701,7 @@ impl MdInlineItalic {
         support::required_token(&self.syntax, 2usize)
     }
 }
-impl Serialize for MdInlineItalic {
+impl Serialize for MdInlineStrikethrough {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -790,72 +710,12 @@ impl Serialize for MdInlineItalic {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineItalicFields {
+pub struct MdInlineStrikethroughFields {
     pub l_fence: SyntaxResult<SyntaxToken>,
     pub content: MdInlineItemList,
     pub r_fence: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineLink {
-    pub(crate) syntax: SyntaxNode,
-}
-impl MdInlineLink {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn as_fields(&self) -> MdInlineLinkFields {
-        MdInlineLinkFields {
-            l_brack_token: self.l_brack_token(),
-            text: self.text(),
-            r_brack_token: self.r_brack_token(),
-            l_paren_token: self.l_paren_token(),
-            source: self.source(),
-            r_paren_token: self.r_paren_token(),
-        }
-    }
-    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
-    }
-    pub fn text(&self) -> MdInlineItemList {
-        support::list(&self.syntax, 1usize)
-    }
-    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
-    }
-    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
-    }
-    pub fn source(&self) -> MdInlineItemList {
-        support::list(&self.syntax, 4usize)
-    }
-    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 5usize)
-    }
-}
-impl Serialize for MdInlineLink {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[derive(Serialize)]
-pub struct MdInlineLinkFields {
-    pub l_brack_token: SyntaxResult<SyntaxToken>,
-    pub text: MdInlineItemList,
-    pub r_brack_token: SyntaxResult<SyntaxToken>,
-    pub l_paren_token: SyntaxResult<SyntaxToken>,
-    pub source: MdInlineItemList,
-    pub r_paren_token: SyntaxResult<SyntaxToken>,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdLinkBlock {
     pub(crate) syntax: SyntaxNode,
 }
@@ -950,17 +810,11 @@ impl MdParagraph {
         Self { syntax }
     }
     pub fn as_fields(&self) -> MdParagraphFields {
-        MdParagraphFields {
-            list: self.list(),
-            hard_line: self.hard_line(),
-        }
+        MdParagraphFields { list: self.list() }
     }
     pub fn list(&self) -> MdInlineItemList {
         support::list(&self.syntax, 0usize)
     }
-    pub fn hard_line(&self) -> SyntaxResult<MdHardLine> {
-        support::required_node(&self.syntax, 1usize)
-    }
 }
 impl Serialize for MdParagraph {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -973,7 +827,6 @@ impl Serialize for MdParagraph {
 #[derive(Serialize)]
 pub struct MdParagraphFields {
     pub list: MdInlineItemList,
-    pub hard_line: SyntaxResult<MdHardLine>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdQuote {
@@ -991,11 +844,11 @@ impl MdQuote {
     }
     pub fn as_fields(&self) -> MdQuoteFields {
         MdQuoteFields {
-            any_md_block: self.any_md_block(),
+            lines: self.lines(),
         }
     }
-    pub fn any_md_block(&self) -> SyntaxResult<AnyMdBlock> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn lines(&self) -> MdQuoteLineList {
+        support::list(&self.syntax, 0usize)
     }
 }
 impl Serialize for MdQuote {
@@ -1008,13 +861,13 @@ impl Serialize for MdQuote {
 }
 #[derive(Serialize)]
 pub struct MdQuoteFields {
-    pub any_md_block: SyntaxResult<AnyMdBlock>,
+    pub lines: MdQuoteLineList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdSetextHeader {
+pub struct MdQuoteLine {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdSetextHeader {
+impl MdQuoteLine {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -1024,16 +877,20 @@ impl MdSetextHeader {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdSetextHeaderFields {
-        MdSetextHeaderFields {
-            md_paragraph: self.md_paragraph(),
+    pub fn as_fields(&self) -> MdQuoteLineFields {
+        MdQuoteLineFields {
+            marker_token: self.marker_token(),
+            content: self.content(),
         }
     }
-    pub fn md_paragraph(&self) -> SyntaxResult<MdParagraph> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn marker_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> Option<MdTextual> {
+        support::node(&self.syntax, 1usize)
     }
 }
-impl Serialize for MdSetextHeader {
+impl Serialize for MdQuoteLine {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -1042,14 +899,15 @@ impl Serialize for MdSetextHeader {
     }
 }
 #[derive(Serialize)]
-pub struct MdSetextHeaderFields {
-    pub md_paragraph: SyntaxResult<MdParagraph>,
+pub struct MdQuoteLineFields {
+    pub marker_token: Option<SyntaxToken>,
+    pub content: Option<MdTextual>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdSoftBreak {
+pub struct MdSetextHeader {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdSoftBreak {
+impl MdSetextHeader {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -1059,16 +917,20 @@ impl MdSoftBreak {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdSoftBreakFields {
-        MdSoftBreakFields {
-            value_token: self.value_token(),
+    pub fn as_fields(&self) -> MdSetextHeaderFields {
+        MdSetextHeaderFields {
+            content: self.content(),
+            underline_token: self.underline_token(),
         }
     }
-    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
+    pub fn content(&self) -> SyntaxResult<MdParagraph> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn underline_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
     }
 }
-impl Serialize for MdSoftBreak {
+impl Serialize for MdSetextHeader {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -1077,10 +939,166 @@ impl Serialize for MdSoftBreak {
     }
 }
 #[derive(Serialize)]
-pub struct MdSoftBreakFields {
+pub struct MdSetextHeaderFields {
+    pub content: SyntaxResult<MdParagraph>,
+    pub underline_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdSoftBreak {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdSoftBreak {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdSoftBreakFields {
+        MdSoftBreakFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for MdSoftBreak {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdSoftBreakFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTable {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTable {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTableFields {
+        MdTableFields {
+            header: self.header(),
+            delimiter: self.delimiter(),
+            rows: self.rows(),
+        }
+    }
+    pub fn header(&self) -> SyntaxResult<MdTableRow> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn delimiter(&self) -> SyntaxResult<MdTableRow> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn rows(&self) -> MdTableRowList {
+        support::list(&self.syntax, 2usize)
+    }
+}
+impl Serialize for MdTable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableFields {
+    pub header: SyntaxResult<MdTableRow>,
+    pub delimiter: SyntaxResult<MdTableRow>,
+    pub rows: MdTableRowList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTableCell {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTableCell {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTableCellFields {
+        MdTableCellFields {
+            content: self.content(),
+        }
+    }
+    pub fn content(&self) -> Option<MdTextual> {
+        support::node(&self.syntax, 0usize)
+    }
+}
+impl Serialize for MdTableCell {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableCellFields {
+    pub content: Option<MdTextual>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTableRow {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTableRow {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTableRowFields {
+        MdTableRowFields {
+            l_pipe_token: self.l_pipe_token(),
+            cells: self.cells(),
+        }
+    }
+    pub fn l_pipe_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 0usize)
+    }
+    pub fn cells(&self) -> MdTableCellList {
+        support::list(&self.syntax, 1usize)
+    }
+}
+impl Serialize for MdTableRow {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableRowFields {
+    pub l_pipe_token: Option<SyntaxToken>,
+    pub cells: MdTableCellList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdTextual {
     pub(crate) syntax: SyntaxNode,
 }
@@ -1151,11 +1169,30 @@ pub struct MdThematicBreakBlockFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCodeBlock {
+pub enum AnyMdBlock {
+    AnyMdContainerBlock(AnyMdContainerBlock),
+    AnyMdLeafBlock(AnyMdLeafBlock),
+}
+impl AnyMdBlock {
+    pub fn as_any_md_container_block(&self) -> Option<&AnyMdContainerBlock> {
+        match &self {
+            Self::AnyMdContainerBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_any_md_leaf_block(&self) -> Option<&AnyMdLeafBlock> {
+        match &self {
+            Self::AnyMdLeafBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyMdCodeBlock {
     MdFencedCodeBlock(MdFencedCodeBlock),
     MdIndentCodeBlock(MdIndentCodeBlock),
 }
-impl AnyCodeBlock {
+impl AnyMdCodeBlock {
     pub fn as_md_fenced_code_block(&self) -> Option<&MdFencedCodeBlock> {
         match &self {
             Self::MdFencedCodeBlock(item) => Some(item),
@@ -1170,12 +1207,12 @@ impl AnyCodeBlock {
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyContainerBlock {
+pub enum AnyMdContainerBlock {
     MdBulletListItem(MdBulletListItem),
     MdOrderListItem(MdOrderListItem),
     MdQuote(MdQuote),
 }
-impl AnyContainerBlock {
+impl AnyMdContainerBlock {
     pub fn as_md_bullet_list_item(&self) -> Option<&MdBulletListItem> {
         match &self {
             Self::MdBulletListItem(item) => Some(item),
@@ -1196,142 +1233,137 @@ impl AnyContainerBlock {
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyLeafBlock {
-    AnyCodeBlock(AnyCodeBlock),
-    MdHeader(MdHeader),
+pub enum AnyMdInline {
+    MdHardLine(MdHardLine),
     MdHtmlBlock(MdHtmlBlock),
-    MdLinkBlock(MdLinkBlock),
-    MdParagraph(MdParagraph),
-    MdSetextHeader(MdSetextHeader),
-    MdThematicBreakBlock(MdThematicBreakBlock),
+    MdInlineCode(MdInlineCode),
+    MdInlineEmphasis(MdInlineEmphasis),
+    MdInlineImage(MdInlineImage),
+    MdInlineItalic(MdInlineItalic),
+    MdInlineLink(MdInlineLink),
+    MdInlineStrikethrough(MdInlineStrikethrough),
+    MdSoftBreak(MdSoftBreak),
+    MdTextual(MdTextual),
 }
-impl AnyLeafBlock {
-    pub fn as_any_code_block(&self) -> Option<&AnyCodeBlock> {
+impl AnyMdInline {
+    pub fn as_md_hard_line(&self) -> Option<&MdHardLine> {
         match &self {
-            Self::AnyCodeBlock(item) => Some(item),
+            Self::MdHardLine(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_header(&self) -> Option<&MdHeader> {
+    pub fn as_md_html_block(&self) -> Option<&MdHtmlBlock> {
         match &self {
-            Self::MdHeader(item) => Some(item),
+            Self::MdHtmlBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_html_block(&self) -> Option<&MdHtmlBlock> {
+    pub fn as_md_inline_code(&self) -> Option<&MdInlineCode> {
         match &self {
-            Self::MdHtmlBlock(item) => Some(item),
+            Self::MdInlineCode(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_link_block(&self) -> Option<&MdLinkBlock> {
+    pub fn as_md_inline_emphasis(&self) -> Option<&MdInlineEmphasis> {
         match &self {
-            Self::MdLinkBlock(item) => Some(item),
+            Self::MdInlineEmphasis(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_paragraph(&self) -> Option<&MdParagraph> {
+    pub fn as_md_inline_image(&self) -> Option<&MdInlineImage> {
         match &self {
-            Self::MdParagraph(item) => Some(item),
+            Self::MdInlineImage(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_setext_header(&self) -> Option<&MdSetextHeader> {
+    pub fn as_md_inline_italic(&self) -> Option<&MdInlineItalic> {
         match &self {
-            Self::MdSetextHeader(item) => Some(item),
+            Self::MdInlineItalic(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_thematic_break_block(&self) -> Option<&MdThematicBreakBlock> {
+    pub fn as_md_inline_link(&self) -> Option<&MdInlineLink> {
         match &self {
-            Self::MdThematicBreakBlock(item) => Some(item),
+            Self::MdInlineLink(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyMdBlock {
-    AnyContainerBlock(AnyContainerBlock),
-    AnyLeafBlock(AnyLeafBlock),
-}
-impl AnyMdBlock {
-    pub fn as_any_container_block(&self) -> Option<&AnyContainerBlock> {
+    pub fn as_md_inline_strikethrough(&self) -> Option<&MdInlineStrikethrough> {
         match &self {
-            Self::AnyContainerBlock(item) => Some(item),
+            Self::MdInlineStrikethrough(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_any_leaf_block(&self) -> Option<&AnyLeafBlock> {
+    pub fn as_md_soft_break(&self) -> Option<&MdSoftBreak> {
         match &self {
-            Self::AnyLeafBlock(item) => Some(item),
+            Self::MdSoftBreak(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyMdInline {
-    MdHardLine(MdHardLine),
-    MdHtmlBlock(MdHtmlBlock),
-    MdInlineCode(MdInlineCode),
-    MdInlineEmphasis(MdInlineEmphasis),
-    MdInlineImage(MdInlineImage),
-    MdInlineItalic(MdInlineItalic),
-    MdInlineLink(MdInlineLink),
-    MdSoftBreak(MdSoftBreak),
-    MdTextual(MdTextual),
-}
-impl AnyMdInline {
-    pub fn as_md_hard_line(&self) -> Option<&MdHardLine> {
+    pub fn as_md_textual(&self) -> Option<&MdTextual> {
         match &self {
-            Self::MdHardLine(item) => Some(item),
+            Self::MdTextual(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_html_block(&self) -> Option<&MdHtmlBlock> {
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyMdLeafBlock {
+    AnyMdCodeBlock(AnyMdCodeBlock),
+    MdHeader(MdHeader),
+    MdHtmlBlock(MdHtmlBlock),
+    MdLinkBlock(MdLinkBlock),
+    MdParagraph(MdParagraph),
+    MdSetextHeader(MdSetextHeader),
+    MdTable(MdTable),
+    MdThematicBreakBlock(MdThematicBreakBlock),
+}
+impl AnyMdLeafBlock {
+    pub fn as_any_md_code_block(&self) -> Option<&AnyMdCodeBlock> {
         match &self {
-            Self::MdHtmlBlock(item) => Some(item),
+            Self::AnyMdCodeBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_code(&self) -> Option<&MdInlineCode> {
+    pub fn as_md_header(&self) -> Option<&MdHeader> {
         match &self {
-            Self::MdInlineCode(item) => Some(item),
+            Self::MdHeader(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_emphasis(&self) -> Option<&MdInlineEmphasis> {
+    pub fn as_md_html_block(&self) -> Option<&MdHtmlBlock> {
         match &self {
-            Self::MdInlineEmphasis(item) => Some(item),
+            Self::MdHtmlBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_image(&self) -> Option<&MdInlineImage> {
+    pub fn as_md_link_block(&self) -> Option<&MdLinkBlock> {
         match &self {
-            Self::MdInlineImage(item) => Some(item),
+            Self::MdLinkBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_italic(&self) -> Option<&MdInlineItalic> {
+    pub fn as_md_paragraph(&self) -> Option<&MdParagraph> {
         match &self {
-            Self::MdInlineItalic(item) => Some(item),
+            Self::MdParagraph(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_inline_link(&self) -> Option<&MdInlineLink> {
+    pub fn as_md_setext_header(&self) -> Option<&MdSetextHeader> {
         match &self {
-            Self::MdInlineLink(item) => Some(item),
+            Self::MdSetextHeader(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_soft_break(&self) -> Option<&MdSoftBreak> {
+    pub fn as_md_table(&self) -> Option<&MdTable> {
         match &self {
-            Self::MdSoftBreak(item) => Some(item),
+            Self::MdTable(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_textual(&self) -> Option<&MdTextual> {
+    pub fn as_md_thematic_break_block(&self) -> Option<&MdThematicBreakBlock> {
         match &self {
-            Self::MdTextual(item) => Some(item),
+            Self::MdThematicBreakBlock(item) => Some(item),
             _ => None,
         }
     }
@@ -1365,10 +1397,6 @@ impl std::fmt::Debug for MdBullet {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdBullet")
                 .field("bullet", &support::DebugSyntaxResult(self.bullet()))
-                .field(
-                    "space_token",
-                    &support::DebugSyntaxResult(self.space_token()),
-                )
                 .field("content", &self.content())
                 .finish()
         } else {
@@ -1519,19 +1547,11 @@ impl std::fmt::Debug for MdFencedCodeBlock {
                     "l_fence_token",
                     &support::DebugSyntaxResult(self.l_fence_token()),
                 )
-                .field("code_list", &self.code_list())
-                .field(
-                    "l_hard_line",
-                    &support::DebugSyntaxResult(self.l_hard_line()),
-                )
-                .field("content", &support::DebugSyntaxResult(self.content()))
-                .field(
-                    "r_hard_line",
-                    &support::DebugSyntaxResult(self.r_hard_line()),
-                )
+                .field("info", &support::DebugOptionalElement(self.info()))
+                .field("content", &self.content())
                 .field(
                     "r_fence_token",
-                    &support::DebugSyntaxResult(self.r_fence_token()),
+                    &support::DebugOptionalElement(self.r_fence_token()),
                 )
                 .finish()
         } else {
@@ -1725,7 +1745,7 @@ impl std::fmt::Debug for MdHtmlBlock {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdHtmlBlock")
-                .field("md_textual", &support::DebugSyntaxResult(self.md_textual()))
+                .field("content", &self.content())
                 .finish()
         } else {
             f.debug_struct("MdHtmlBlock").finish()
@@ -1744,12 +1764,12 @@ impl From<MdHtmlBlock> for SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdIndent {
+impl AstNode for MdIndentCodeBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENT_CODE_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENT
+        kind == MD_INDENT_CODE_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1765,67 +1785,17 @@ impl AstNode for MdIndent {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdIndent {
+impl std::fmt::Debug for MdIndentCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdIndent")
-                .field(
-                    "value_token",
-                    &support::DebugSyntaxResult(self.value_token()),
-                )
+            f.debug_struct("MdIndentCodeBlock")
+                .field("lines", &self.lines())
                 .finish()
         } else {
-            f.debug_struct("MdIndent").finish()
-        };
-        DEPTH.set(current_depth);
-        result
-    }
-}
-impl From<MdIndent> for SyntaxNode {
-    fn from(n: MdIndent) -> Self {
-        n.syntax
-    }
-}
-impl From<MdIndent> for SyntaxElement {
-    fn from(n: MdIndent) -> Self {
-        n.syntax.into()
-    }
-}
-impl AstNode for MdIndentCodeBlock {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENT_CODE_BLOCK as u16));
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENT_CODE_BLOCK
-    }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
-    }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
-    }
-    fn into_syntax(self) -> SyntaxNode {
-        self.syntax
-    }
-}
-impl std::fmt::Debug for MdIndentCodeBlock {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
-        let current_depth = DEPTH.get();
-        let result = if current_depth < 16 {
-            DEPTH.set(current_depth + 1);
-            f.debug_struct("MdIndentCodeBlock")
-                .field("lines", &self.lines())
-                .finish()
-        } else {
-            f.debug_struct("MdIndentCodeBlock").finish()
+            f.debug_struct("MdIndentCodeBlock").finish()
         };
         DEPTH.set(current_depth);
         result
@@ -1869,10 +1839,6 @@ impl std::fmt::Debug for MdIndentedCodeLine {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdIndentedCodeLine")
-                .field(
-                    "indentation",
-                    &support::DebugSyntaxResult(self.indentation()),
-                )
                 .field("content", &support::DebugSyntaxResult(self.content()))
                 .finish()
         } else {
@@ -2024,18 +1990,25 @@ impl std::fmt::Debug for MdInlineImage {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdInlineImage")
+                .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
                 .field(
                     "l_brack_token",
                     &support::DebugSyntaxResult(self.l_brack_token()),
                 )
-                .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
-                .field("alt", &support::DebugSyntaxResult(self.alt()))
-                .field("source", &support::DebugSyntaxResult(self.source()))
+                .field("alt", &self.alt())
                 .field(
                     "r_brack_token",
                     &support::DebugSyntaxResult(self.r_brack_token()),
                 )
-                .field("link", &support::DebugOptionalElement(self.link()))
+                .field(
+                    "l_paren_token",
+                    &support::DebugSyntaxResult(self.l_paren_token()),
+                )
+                .field("source", &self.source())
+                .field(
+                    "r_paren_token",
+                    &support::DebugSyntaxResult(self.r_paren_token()),
+                )
                 .finish()
         } else {
             f.debug_struct("MdInlineImage").finish()
@@ -2054,12 +2027,12 @@ impl From<MdInlineImage> for SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineImageAlt {
+impl AstNode for MdInlineItalic {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE_ALT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITALIC as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE_ALT
+        kind == MD_INLINE_ITALIC
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2075,46 +2048,40 @@ impl AstNode for MdInlineImageAlt {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineImageAlt {
+impl std::fmt::Debug for MdInlineItalic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineImageAlt")
-                .field(
-                    "l_brack_token",
-                    &support::DebugSyntaxResult(self.l_brack_token()),
-                )
+            f.debug_struct("MdInlineItalic")
+                .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
                 .field("content", &self.content())
-                .field(
-                    "r_brack_token",
-                    &support::DebugSyntaxResult(self.r_brack_token()),
-                )
+                .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
                 .finish()
         } else {
-            f.debug_struct("MdInlineImageAlt").finish()
+            f.debug_struct("MdInlineItalic").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineImageAlt> for SyntaxNode {
-    fn from(n: MdInlineImageAlt) -> Self {
+impl From<MdInlineItalic> for SyntaxNode {
+    fn from(n: MdInlineItalic) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineImageAlt> for SyntaxElement {
-    fn from(n: MdInlineImageAlt) -> Self {
+impl From<MdInlineItalic> for SyntaxElement {
+    fn from(n: MdInlineItalic) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineImageLink {
+impl AstNode for MdInlineLink {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE_LINK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_LINK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE_LINK
+        kind == MD_INLINE_LINK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2130,101 +2097,55 @@ impl AstNode for MdInlineImageLink {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineImageLink {
+impl std::fmt::Debug for MdInlineLink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineImageLink")
+            f.debug_struct("MdInlineLink")
                 .field(
-                    "l_paren_token",
-                    &support::DebugSyntaxResult(self.l_paren_token()),
+                    "l_brack_token",
+                    &support::DebugSyntaxResult(self.l_brack_token()),
                 )
-                .field("content", &self.content())
+                .field("text", &self.text())
                 .field(
-                    "r_paren_token",
-                    &support::DebugSyntaxResult(self.r_paren_token()),
+                    "r_brack_token",
+                    &support::DebugSyntaxResult(self.r_brack_token()),
                 )
-                .finish()
-        } else {
-            f.debug_struct("MdInlineImageLink").finish()
-        };
-        DEPTH.set(current_depth);
-        result
-    }
-}
-impl From<MdInlineImageLink> for SyntaxNode {
-    fn from(n: MdInlineImageLink) -> Self {
-        n.syntax
-    }
-}
-impl From<MdInlineImageLink> for SyntaxElement {
-    fn from(n: MdInlineImageLink) -> Self {
-        n.syntax.into()
-    }
-}
-impl AstNode for MdInlineImageSource {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE_SOURCE as u16));
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE_SOURCE
-    }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
-    }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
-    }
-    fn into_syntax(self) -> SyntaxNode {
-        self.syntax
-    }
-}
-impl std::fmt::Debug for MdInlineImageSource {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
-        let current_depth = DEPTH.get();
-        let result = if current_depth < 16 {
-            DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineImageSource")
                 .field(
                     "l_paren_token",
                     &support::DebugSyntaxResult(self.l_paren_token()),
                 )
-                .field("content", &self.content())
+                .field("source", &self.source())
                 .field(
                     "r_paren_token",
                     &support::DebugSyntaxResult(self.r_paren_token()),
                 )
                 .finish()
         } else {
-            f.debug_struct("MdInlineImageSource").finish()
+            f.debug_struct("MdInlineLink").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineImageSource> for SyntaxNode {
-    fn from(n: MdInlineImageSource) -> Self {
+impl From<MdInlineLink> for SyntaxNode {
+    fn from(n: MdInlineLink) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineImageSource> for SyntaxElement {
-    fn from(n: MdInlineImageSource) -> Self {
+impl From<MdInlineLink> for SyntaxElement {
+    fn from(n: MdInlineLink) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineItalic {
+impl AstNode for MdInlineStrikethrough {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITALIC as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_STRIKETHROUGH as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_ITALIC
+        kind == MD_INLINE_STRIKETHROUGH
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2240,95 +2161,31 @@ impl AstNode for MdInlineItalic {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineItalic {
+impl std::fmt::Debug for MdInlineStrikethrough {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineItalic")
+            f.debug_struct("MdInlineStrikethrough")
                 .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
                 .field("content", &self.content())
                 .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
                 .finish()
         } else {
-            f.debug_struct("MdInlineItalic").finish()
-        };
-        DEPTH.set(current_depth);
-        result
-    }
-}
-impl From<MdInlineItalic> for SyntaxNode {
-    fn from(n: MdInlineItalic) -> Self {
-        n.syntax
-    }
-}
-impl From<MdInlineItalic> for SyntaxElement {
-    fn from(n: MdInlineItalic) -> Self {
-        n.syntax.into()
-    }
-}
-impl AstNode for MdInlineLink {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_LINK as u16));
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_LINK
-    }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
-    }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
-    }
-    fn into_syntax(self) -> SyntaxNode {
-        self.syntax
-    }
-}
-impl std::fmt::Debug for MdInlineLink {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
-        let current_depth = DEPTH.get();
-        let result = if current_depth < 16 {
-            DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineLink")
-                .field(
-                    "l_brack_token",
-                    &support::DebugSyntaxResult(self.l_brack_token()),
-                )
-                .field("text", &self.text())
-                .field(
-                    "r_brack_token",
-                    &support::DebugSyntaxResult(self.r_brack_token()),
-                )
-                .field(
-                    "l_paren_token",
-                    &support::DebugSyntaxResult(self.l_paren_token()),
-                )
-                .field("source", &self.source())
-                .field(
-                    "r_paren_token",
-                    &support::DebugSyntaxResult(self.r_paren_token()),
-                )
-                .finish()
-        } else {
-            f.debug_struct("MdInlineLink").finish()
+            f.debug_struct("MdInlineStrikethrough").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineLink> for SyntaxNode {
-    fn from(n: MdInlineLink) -> Self {
+impl From<MdInlineStrikethrough> for SyntaxNode {
+    fn from(n: MdInlineStrikethrough) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineLink> for SyntaxElement {
-    fn from(n: MdInlineLink) -> Self {
+impl From<MdInlineStrikethrough> for SyntaxElement {
+    fn from(n: MdInlineStrikethrough) -> Self {
         n.syntax.into()
     }
 }
@@ -2457,7 +2314,6 @@ impl std::fmt::Debug for MdParagraph {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdParagraph")
                 .field("list", &self.list())
-                .field("hard_line", &support::DebugSyntaxResult(self.hard_line()))
                 .finish()
         } else {
             f.debug_struct("MdParagraph").finish()
@@ -2504,10 +2360,7 @@ impl std::fmt::Debug for MdQuote {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdQuote")
-                .field(
-                    "any_md_block",
-                    &support::DebugSyntaxResult(self.any_md_block()),
-                )
+                .field("lines", &self.lines())
                 .finish()
         } else {
             f.debug_struct("MdQuote").finish()
@@ -2526,6 +2379,57 @@ impl From<MdQuote> for SyntaxElement {
         n.syntax.into()
     }
 }
+impl AstNode for MdQuoteLine {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_QUOTE_LINE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_QUOTE_LINE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdQuoteLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdQuoteLine")
+                .field(
+                    "marker_token",
+                    &support::DebugOptionalElement(self.marker_token()),
+                )
+                .field("content", &support::DebugOptionalElement(self.content()))
+                .finish()
+        } else {
+            f.debug_struct("MdQuoteLine").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdQuoteLine> for SyntaxNode {
+    fn from(n: MdQuoteLine) -> Self {
+        n.syntax
+    }
+}
+impl From<MdQuoteLine> for SyntaxElement {
+    fn from(n: MdQuoteLine) -> Self {
+        n.syntax.into()
+    }
+}
 impl AstNode for MdSetextHeader {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -2554,9 +2458,10 @@ impl std::fmt::Debug for MdSetextHeader {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("MdSetextHeader")
+                .field("content", &support::DebugSyntaxResult(self.content()))
                 .field(
-                    "md_paragraph",
-                    &support::DebugSyntaxResult(self.md_paragraph()),
+                    "underline_token",
+                    &support::DebugSyntaxResult(self.underline_token()),
                 )
                 .finish()
         } else {
@@ -2626,12 +2531,12 @@ impl From<MdSoftBreak> for SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for MdTextual {
+impl AstNode for MdTable {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TEXTUAL as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_TEXTUAL
+        kind == MD_TABLE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2647,14 +2552,161 @@ impl AstNode for MdTextual {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdTextual {
+impl std::fmt::Debug for MdTable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdTextual")
-                .field(
+            f.debug_struct("MdTable")
+                .field("header", &support::DebugSyntaxResult(self.header()))
+                .field("delimiter", &support::DebugSyntaxResult(self.delimiter()))
+                .field("rows", &self.rows())
+                .finish()
+        } else {
+            f.debug_struct("MdTable").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdTable> for SyntaxNode {
+    fn from(n: MdTable) -> Self {
+        n.syntax
+    }
+}
+impl From<MdTable> for SyntaxElement {
+    fn from(n: MdTable) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdTableCell {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_CELL as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_TABLE_CELL
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdTableCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdTableCell")
+                .field("content", &support::DebugOptionalElement(self.content()))
+                .finish()
+        } else {
+            f.debug_struct("MdTableCell").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdTableCell> for SyntaxNode {
+    fn from(n: MdTableCell) -> Self {
+        n.syntax
+    }
+}
+impl From<MdTableCell> for SyntaxElement {
+    fn from(n: MdTableCell) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdTableRow {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_ROW as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_TABLE_ROW
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdTableRow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdTableRow")
+                .field(
+                    "l_pipe_token",
+                    &support::DebugOptionalElement(self.l_pipe_token()),
+                )
+                .field("cells", &self.cells())
+                .finish()
+        } else {
+            f.debug_struct("MdTableRow").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdTableRow> for SyntaxNode {
+    fn from(n: MdTableRow) -> Self {
+        n.syntax
+    }
+}
+impl From<MdTableRow> for SyntaxElement {
+    fn from(n: MdTableRow) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdTextual {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TEXTUAL as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_TEXTUAL
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdTextual {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdTextual")
+                .field(
                     "value_token",
                     &support::DebugSyntaxResult(self.value_token()),
                 )
@@ -2726,17 +2778,75 @@ impl From<MdThematicBreakBlock> for SyntaxElement {
         n.syntax.into()
     }
 }
-impl From<MdFencedCodeBlock> for AnyCodeBlock {
+impl AstNode for AnyMdBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        AnyMdContainerBlock::KIND_SET.union(AnyMdLeafBlock::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        match kind {
+            k if AnyMdContainerBlock::can_cast(k) => true,
+            k if AnyMdLeafBlock::can_cast(k) => true,
+            _ => false,
+        }
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let syntax = match AnyMdContainerBlock::try_cast(syntax) {
+            Ok(any_md_container_block) => {
+                return Some(Self::AnyMdContainerBlock(any_md_container_block));
+            }
+            Err(syntax) => syntax,
+        };
+        if let Some(any_md_leaf_block) = AnyMdLeafBlock::cast(syntax) {
+            return Some(Self::AnyMdLeafBlock(any_md_leaf_block));
+        }
+        None
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::AnyMdContainerBlock(it) => it.syntax(),
+            Self::AnyMdLeafBlock(it) => it.syntax(),
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            Self::AnyMdContainerBlock(it) => it.into_syntax(),
+            Self::AnyMdLeafBlock(it) => it.into_syntax(),
+        }
+    }
+}
+impl std::fmt::Debug for AnyMdBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AnyMdContainerBlock(it) => std::fmt::Debug::fmt(it, f),
+            Self::AnyMdLeafBlock(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyMdBlock> for SyntaxNode {
+    fn from(n: AnyMdBlock) -> Self {
+        match n {
+            AnyMdBlock::AnyMdContainerBlock(it) => it.into(),
+            AnyMdBlock::AnyMdLeafBlock(it) => it.into(),
+        }
+    }
+}
+impl From<AnyMdBlock> for SyntaxElement {
+    fn from(n: AnyMdBlock) -> Self {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<MdFencedCodeBlock> for AnyMdCodeBlock {
     fn from(node: MdFencedCodeBlock) -> Self {
         Self::MdFencedCodeBlock(node)
     }
 }
-impl From<MdIndentCodeBlock> for AnyCodeBlock {
+impl From<MdIndentCodeBlock> for AnyMdCodeBlock {
     fn from(node: MdIndentCodeBlock) -> Self {
         Self::MdIndentCodeBlock(node)
     }
 }
-impl AstNode for AnyCodeBlock {
+impl AstNode for AnyMdCodeBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
         MdFencedCodeBlock::KIND_SET.union(MdIndentCodeBlock::KIND_SET);
@@ -2764,7 +2874,7 @@ impl AstNode for AnyCodeBlock {
         }
     }
 }
-impl std::fmt::Debug for AnyCodeBlock {
+impl std::fmt::Debug for AnyMdCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::MdFencedCodeBlock(it) => std::fmt::Debug::fmt(it, f),
@@ -2772,36 +2882,36 @@ impl std::fmt::Debug for AnyCodeBlock {
         }
     }
 }
-impl From<AnyCodeBlock> for SyntaxNode {
-    fn from(n: AnyCodeBlock) -> Self {
+impl From<AnyMdCodeBlock> for SyntaxNode {
+    fn from(n: AnyMdCodeBlock) -> Self {
         match n {
-            AnyCodeBlock::MdFencedCodeBlock(it) => it.into(),
-            AnyCodeBlock::MdIndentCodeBlock(it) => it.into(),
+            AnyMdCodeBlock::MdFencedCodeBlock(it) => it.into(),
+            AnyMdCodeBlock::MdIndentCodeBlock(it) => it.into(),
         }
     }
 }
-impl From<AnyCodeBlock> for SyntaxElement {
-    fn from(n: AnyCodeBlock) -> Self {
+impl From<AnyMdCodeBlock> for SyntaxElement {
+    fn from(n: AnyMdCodeBlock) -> Self {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl From<MdBulletListItem> for AnyContainerBlock {
+impl From<MdBulletListItem> for AnyMdContainerBlock {
     fn from(node: MdBulletListItem) -> Self {
         Self::MdBulletListItem(node)
     }
 }
-impl From<MdOrderListItem> for AnyContainerBlock {
+impl From<MdOrderListItem> for AnyMdContainerBlock {
     fn from(node: MdOrderListItem) -> Self {
         Self::MdOrderListItem(node)
     }
 }
-impl From<MdQuote> for AnyContainerBlock {
+impl From<MdQuote> for AnyMdContainerBlock {
     fn from(node: MdQuote) -> Self {
         Self::MdQuote(node)
     }
 }
-impl AstNode for AnyContainerBlock {
+impl AstNode for AnyMdContainerBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = MdBulletListItem::KIND_SET
         .union(MdOrderListItem::KIND_SET)
@@ -2833,7 +2943,7 @@ impl AstNode for AnyContainerBlock {
         }
     }
 }
-impl std::fmt::Debug for AnyContainerBlock {
+impl std::fmt::Debug for AnyMdContainerBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::MdBulletListItem(it) => std::fmt::Debug::fmt(it, f),
@@ -2842,198 +2952,17 @@ impl std::fmt::Debug for AnyContainerBlock {
         }
     }
 }
-impl From<AnyContainerBlock> for SyntaxNode {
-    fn from(n: AnyContainerBlock) -> Self {
-        match n {
-            AnyContainerBlock::MdBulletListItem(it) => it.into(),
-            AnyContainerBlock::MdOrderListItem(it) => it.into(),
-            AnyContainerBlock::MdQuote(it) => it.into(),
-        }
-    }
-}
-impl From<AnyContainerBlock> for SyntaxElement {
-    fn from(n: AnyContainerBlock) -> Self {
-        let node: SyntaxNode = n.into();
-        node.into()
-    }
-}
-impl From<MdHeader> for AnyLeafBlock {
-    fn from(node: MdHeader) -> Self {
-        Self::MdHeader(node)
-    }
-}
-impl From<MdHtmlBlock> for AnyLeafBlock {
-    fn from(node: MdHtmlBlock) -> Self {
-        Self::MdHtmlBlock(node)
-    }
-}
-impl From<MdLinkBlock> for AnyLeafBlock {
-    fn from(node: MdLinkBlock) -> Self {
-        Self::MdLinkBlock(node)
-    }
-}
-impl From<MdParagraph> for AnyLeafBlock {
-    fn from(node: MdParagraph) -> Self {
-        Self::MdParagraph(node)
-    }
-}
-impl From<MdSetextHeader> for AnyLeafBlock {
-    fn from(node: MdSetextHeader) -> Self {
-        Self::MdSetextHeader(node)
-    }
-}
-impl From<MdThematicBreakBlock> for AnyLeafBlock {
-    fn from(node: MdThematicBreakBlock) -> Self {
-        Self::MdThematicBreakBlock(node)
-    }
-}
-impl AstNode for AnyLeafBlock {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = AnyCodeBlock::KIND_SET
-        .union(MdHeader::KIND_SET)
-        .union(MdHtmlBlock::KIND_SET)
-        .union(MdLinkBlock::KIND_SET)
-        .union(MdParagraph::KIND_SET)
-        .union(MdSetextHeader::KIND_SET)
-        .union(MdThematicBreakBlock::KIND_SET);
-    fn can_cast(kind: SyntaxKind) -> bool {
-        match kind {
-            MD_HEADER
-            | MD_HTML_BLOCK
-            | MD_LINK_BLOCK
-            | MD_PARAGRAPH
-            | MD_SETEXT_HEADER
-            | MD_THEMATIC_BREAK_BLOCK => true,
-            k if AnyCodeBlock::can_cast(k) => true,
-            _ => false,
-        }
-    }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let res = match syntax.kind() {
-            MD_HEADER => Self::MdHeader(MdHeader { syntax }),
-            MD_HTML_BLOCK => Self::MdHtmlBlock(MdHtmlBlock { syntax }),
-            MD_LINK_BLOCK => Self::MdLinkBlock(MdLinkBlock { syntax }),
-            MD_PARAGRAPH => Self::MdParagraph(MdParagraph { syntax }),
-            MD_SETEXT_HEADER => Self::MdSetextHeader(MdSetextHeader { syntax }),
-            MD_THEMATIC_BREAK_BLOCK => Self::MdThematicBreakBlock(MdThematicBreakBlock { syntax }),
-            _ => {
-                if let Some(any_code_block) = AnyCodeBlock::cast(syntax) {
-                    return Some(Self::AnyCodeBlock(any_code_block));
-                }
-                return None;
-            }
-        };
-        Some(res)
-    }
-    fn syntax(&self) -> &SyntaxNode {
-        match self {
-            Self::MdHeader(it) => &it.syntax,
-            Self::MdHtmlBlock(it) => &it.syntax,
-            Self::MdLinkBlock(it) => &it.syntax,
-            Self::MdParagraph(it) => &it.syntax,
-            Self::MdSetextHeader(it) => &it.syntax,
-            Self::MdThematicBreakBlock(it) => &it.syntax,
-            Self::AnyCodeBlock(it) => it.syntax(),
-        }
-    }
-    fn into_syntax(self) -> SyntaxNode {
-        match self {
-            Self::MdHeader(it) => it.syntax,
-            Self::MdHtmlBlock(it) => it.syntax,
-            Self::MdLinkBlock(it) => it.syntax,
-            Self::MdParagraph(it) => it.syntax,
-            Self::MdSetextHeader(it) => it.syntax,
-            Self::MdThematicBreakBlock(it) => it.syntax,
-            Self::AnyCodeBlock(it) => it.into_syntax(),
-        }
-    }
-}
-impl std::fmt::Debug for AnyLeafBlock {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::AnyCodeBlock(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdHeader(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdHtmlBlock(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdLinkBlock(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdParagraph(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdSetextHeader(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdThematicBreakBlock(it) => std::fmt::Debug::fmt(it, f),
-        }
-    }
-}
-impl From<AnyLeafBlock> for SyntaxNode {
-    fn from(n: AnyLeafBlock) -> Self {
-        match n {
-            AnyLeafBlock::AnyCodeBlock(it) => it.into(),
-            AnyLeafBlock::MdHeader(it) => it.into(),
-            AnyLeafBlock::MdHtmlBlock(it) => it.into(),
-            AnyLeafBlock::MdLinkBlock(it) => it.into(),
-            AnyLeafBlock::MdParagraph(it) => it.into(),
-            AnyLeafBlock::MdSetextHeader(it) => it.into(),
-            AnyLeafBlock::MdThematicBreakBlock(it) => it.into(),
-        }
-    }
-}
-impl From<AnyLeafBlock> for SyntaxElement {
-    fn from(n: AnyLeafBlock) -> Self {
-        let node: SyntaxNode = n.into();
-        node.into()
-    }
-}
-impl AstNode for AnyMdBlock {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        AnyContainerBlock::KIND_SET.union(AnyLeafBlock::KIND_SET);
-    fn can_cast(kind: SyntaxKind) -> bool {
-        match kind {
-            k if AnyContainerBlock::can_cast(k) => true,
-            k if AnyLeafBlock::can_cast(k) => true,
-            _ => false,
-        }
-    }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let syntax = match AnyContainerBlock::try_cast(syntax) {
-            Ok(any_container_block) => {
-                return Some(Self::AnyContainerBlock(any_container_block));
-            }
-            Err(syntax) => syntax,
-        };
-        if let Some(any_leaf_block) = AnyLeafBlock::cast(syntax) {
-            return Some(Self::AnyLeafBlock(any_leaf_block));
-        }
-        None
-    }
-    fn syntax(&self) -> &SyntaxNode {
-        match self {
-            Self::AnyContainerBlock(it) => it.syntax(),
-            Self::AnyLeafBlock(it) => it.syntax(),
-        }
-    }
-    fn into_syntax(self) -> SyntaxNode {
-        match self {
-            Self::AnyContainerBlock(it) => it.into_syntax(),
-            Self::AnyLeafBlock(it) => it.into_syntax(),
-        }
-    }
-}
-impl std::fmt::Debug for AnyMdBlock {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::AnyContainerBlock(it) => std::fmt::Debug::fmt(it, f),
-            Self::AnyLeafBlock(it) => std::fmt::Debug::fmt(it, f),
-        }
-    }
-}
-impl From<AnyMdBlock> for SyntaxNode {
-    fn from(n: AnyMdBlock) -> Self {
+impl From<AnyMdContainerBlock> for SyntaxNode {
+    fn from(n: AnyMdContainerBlock) -> Self {
         match n {
-            AnyMdBlock::AnyContainerBlock(it) => it.into(),
-            AnyMdBlock::AnyLeafBlock(it) => it.into(),
+            AnyMdContainerBlock::MdBulletListItem(it) => it.into(),
+            AnyMdContainerBlock::MdOrderListItem(it) => it.into(),
+            AnyMdContainerBlock::MdQuote(it) => it.into(),
         }
     }
 }
-impl From<AnyMdBlock> for SyntaxElement {
-    fn from(n: AnyMdBlock) -> Self {
+impl From<AnyMdContainerBlock> for SyntaxElement {
+    fn from(n: AnyMdContainerBlock) -> Self {
         let node: SyntaxNode = n.into();
         node.into()
     }
@@ -3073,6 +3002,11 @@ impl From<MdInlineLink> for AnyMdInline {
         Self::MdInlineLink(node)
     }
 }
+impl From<MdInlineStrikethrough> for AnyMdInline {
+    fn from(node: MdInlineStrikethrough) -> Self {
+        Self::MdInlineStrikethrough(node)
+    }
+}
 impl From<MdSoftBreak> for AnyMdInline {
     fn from(node: MdSoftBreak) -> Self {
         Self::MdSoftBreak(node)
@@ -3092,6 +3026,7 @@ impl AstNode for AnyMdInline {
         .union(MdInlineImage::KIND_SET)
         .union(MdInlineItalic::KIND_SET)
         .union(MdInlineLink::KIND_SET)
+        .union(MdInlineStrikethrough::KIND_SET)
         .union(MdSoftBreak::KIND_SET)
         .union(MdTextual::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
@@ -3104,6 +3039,7 @@ impl AstNode for AnyMdInline {
                 | MD_INLINE_IMAGE
                 | MD_INLINE_ITALIC
                 | MD_INLINE_LINK
+                | MD_INLINE_STRIKETHROUGH
                 | MD_SOFT_BREAK
                 | MD_TEXTUAL
         )
@@ -3117,6 +3053,9 @@ impl AstNode for AnyMdInline {
             MD_INLINE_IMAGE => Self::MdInlineImage(MdInlineImage { syntax }),
             MD_INLINE_ITALIC => Self::MdInlineItalic(MdInlineItalic { syntax }),
             MD_INLINE_LINK => Self::MdInlineLink(MdInlineLink { syntax }),
+            MD_INLINE_STRIKETHROUGH => {
+                Self::MdInlineStrikethrough(MdInlineStrikethrough { syntax })
+            }
             MD_SOFT_BREAK => Self::MdSoftBreak(MdSoftBreak { syntax }),
             MD_TEXTUAL => Self::MdTextual(MdTextual { syntax }),
             _ => return None,
@@ -3125,88 +3064,227 @@ impl AstNode for AnyMdInline {
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            Self::MdHardLine(it) => &it.syntax,
+            Self::MdHardLine(it) => &it.syntax,
+            Self::MdHtmlBlock(it) => &it.syntax,
+            Self::MdInlineCode(it) => &it.syntax,
+            Self::MdInlineEmphasis(it) => &it.syntax,
+            Self::MdInlineImage(it) => &it.syntax,
+            Self::MdInlineItalic(it) => &it.syntax,
+            Self::MdInlineLink(it) => &it.syntax,
+            Self::MdInlineStrikethrough(it) => &it.syntax,
+            Self::MdSoftBreak(it) => &it.syntax,
+            Self::MdTextual(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            Self::MdHardLine(it) => it.syntax,
+            Self::MdHtmlBlock(it) => it.syntax,
+            Self::MdInlineCode(it) => it.syntax,
+            Self::MdInlineEmphasis(it) => it.syntax,
+            Self::MdInlineImage(it) => it.syntax,
+            Self::MdInlineItalic(it) => it.syntax,
+            Self::MdInlineLink(it) => it.syntax,
+            Self::MdInlineStrikethrough(it) => it.syntax,
+            Self::MdSoftBreak(it) => it.syntax,
+            Self::MdTextual(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyMdInline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MdHardLine(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdHtmlBlock(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineCode(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineEmphasis(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineImage(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineItalic(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineLink(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineStrikethrough(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdSoftBreak(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdTextual(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyMdInline> for SyntaxNode {
+    fn from(n: AnyMdInline) -> Self {
+        match n {
+            AnyMdInline::MdHardLine(it) => it.into(),
+            AnyMdInline::MdHtmlBlock(it) => it.into(),
+            AnyMdInline::MdInlineCode(it) => it.into(),
+            AnyMdInline::MdInlineEmphasis(it) => it.into(),
+            AnyMdInline::MdInlineImage(it) => it.into(),
+            AnyMdInline::MdInlineItalic(it) => it.into(),
+            AnyMdInline::MdInlineLink(it) => it.into(),
+            AnyMdInline::MdInlineStrikethrough(it) => it.into(),
+            AnyMdInline::MdSoftBreak(it) => it.into(),
+            AnyMdInline::MdTextual(it) => it.into(),
+        }
+    }
+}
+impl From<AnyMdInline> for SyntaxElement {
+    fn from(n: AnyMdInline) -> Self {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<MdHeader> for AnyMdLeafBlock {
+    fn from(node: MdHeader) -> Self {
+        Self::MdHeader(node)
+    }
+}
+impl From<MdHtmlBlock> for AnyMdLeafBlock {
+    fn from(node: MdHtmlBlock) -> Self {
+        Self::MdHtmlBlock(node)
+    }
+}
+impl From<MdLinkBlock> for AnyMdLeafBlock {
+    fn from(node: MdLinkBlock) -> Self {
+        Self::MdLinkBlock(node)
+    }
+}
+impl From<MdParagraph> for AnyMdLeafBlock {
+    fn from(node: MdParagraph) -> Self {
+        Self::MdParagraph(node)
+    }
+}
+impl From<MdSetextHeader> for AnyMdLeafBlock {
+    fn from(node: MdSetextHeader) -> Self {
+        Self::MdSetextHeader(node)
+    }
+}
+impl From<MdTable> for AnyMdLeafBlock {
+    fn from(node: MdTable) -> Self {
+        Self::MdTable(node)
+    }
+}
+impl From<MdThematicBreakBlock> for AnyMdLeafBlock {
+    fn from(node: MdThematicBreakBlock) -> Self {
+        Self::MdThematicBreakBlock(node)
+    }
+}
+impl AstNode for AnyMdLeafBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = AnyMdCodeBlock::KIND_SET
+        .union(MdHeader::KIND_SET)
+        .union(MdHtmlBlock::KIND_SET)
+        .union(MdLinkBlock::KIND_SET)
+        .union(MdParagraph::KIND_SET)
+        .union(MdSetextHeader::KIND_SET)
+        .union(MdTable::KIND_SET)
+        .union(MdThematicBreakBlock::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        match kind {
+            MD_HEADER
+            | MD_HTML_BLOCK
+            | MD_LINK_BLOCK
+            | MD_PARAGRAPH
+            | MD_SETEXT_HEADER
+            | MD_TABLE
+            | MD_THEMATIC_BREAK_BLOCK => true,
+            k if AnyMdCodeBlock::can_cast(k) => true,
+            _ => false,
+        }
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            MD_HEADER => Self::MdHeader(MdHeader { syntax }),
+            MD_HTML_BLOCK => Self::MdHtmlBlock(MdHtmlBlock { syntax }),
+            MD_LINK_BLOCK => Self::MdLinkBlock(MdLinkBlock { syntax }),
+            MD_PARAGRAPH => Self::MdParagraph(MdParagraph { syntax }),
+            MD_SETEXT_HEADER => Self::MdSetextHeader(MdSetextHeader { syntax }),
+            MD_TABLE => Self::MdTable(MdTable { syntax }),
+            MD_THEMATIC_BREAK_BLOCK => Self::MdThematicBreakBlock(MdThematicBreakBlock { syntax }),
+            _ => {
+                if let Some(any_md_code_block) = AnyMdCodeBlock::cast(syntax) {
+                    return Some(Self::AnyMdCodeBlock(any_md_code_block));
+                }
+                return None;
+            }
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::MdHeader(it) => &it.syntax,
             Self::MdHtmlBlock(it) => &it.syntax,
-            Self::MdInlineCode(it) => &it.syntax,
-            Self::MdInlineEmphasis(it) => &it.syntax,
-            Self::MdInlineImage(it) => &it.syntax,
-            Self::MdInlineItalic(it) => &it.syntax,
-            Self::MdInlineLink(it) => &it.syntax,
-            Self::MdSoftBreak(it) => &it.syntax,
-            Self::MdTextual(it) => &it.syntax,
+            Self::MdLinkBlock(it) => &it.syntax,
+            Self::MdParagraph(it) => &it.syntax,
+            Self::MdSetextHeader(it) => &it.syntax,
+            Self::MdTable(it) => &it.syntax,
+            Self::MdThematicBreakBlock(it) => &it.syntax,
+            Self::AnyMdCodeBlock(it) => it.syntax(),
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            Self::MdHardLine(it) => it.syntax,
+            Self::MdHeader(it) => it.syntax,
             Self::MdHtmlBlock(it) => it.syntax,
-            Self::MdInlineCode(it) => it.syntax,
-            Self::MdInlineEmphasis(it) => it.syntax,
-            Self::MdInlineImage(it) => it.syntax,
-            Self::MdInlineItalic(it) => it.syntax,
-            Self::MdInlineLink(it) => it.syntax,
-            Self::MdSoftBreak(it) => it.syntax,
-            Self::MdTextual(it) => it.syntax,
+            Self::MdLinkBlock(it) => it.syntax,
+            Self::MdParagraph(it) => it.syntax,
+            Self::MdSetextHeader(it) => it.syntax,
+            Self::MdTable(it) => it.syntax,
+            Self::MdThematicBreakBlock(it) => it.syntax,
+            Self::AnyMdCodeBlock(it) => it.into_syntax(),
         }
     }
 }
-impl std::fmt::Debug for AnyMdInline {
+impl std::fmt::Debug for AnyMdLeafBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::MdHardLine(it) => std::fmt::Debug::fmt(it, f),
+            Self::AnyMdCodeBlock(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdHeader(it) => std::fmt::Debug::fmt(it, f),
             Self::MdHtmlBlock(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdInlineCode(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdInlineEmphasis(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdInlineImage(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdInlineItalic(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdInlineLink(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdSoftBreak(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdTextual(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdLinkBlock(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdParagraph(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdSetextHeader(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdTable(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdThematicBreakBlock(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyMdInline> for SyntaxNode {
-    fn from(n: AnyMdInline) -> Self {
+impl From<AnyMdLeafBlock> for SyntaxNode {
+    fn from(n: AnyMdLeafBlock) -> Self {
         match n {
-            AnyMdInline::MdHardLine(it) => it.into(),
-            AnyMdInline::MdHtmlBlock(it) => it.into(),
-            AnyMdInline::MdInlineCode(it) => it.into(),
-            AnyMdInline::MdInlineEmphasis(it) => it.into(),
-            AnyMdInline::MdInlineImage(it) => it.into(),
-            AnyMdInline::MdInlineItalic(it) => it.into(),
-            AnyMdInline::MdInlineLink(it) => it.into(),
-            AnyMdInline::MdSoftBreak(it) => it.into(),
-            AnyMdInline::MdTextual(it) => it.into(),
+            AnyMdLeafBlock::AnyMdCodeBlock(it) => it.into(),
+            AnyMdLeafBlock::MdHeader(it) => it.into(),
+            AnyMdLeafBlock::MdHtmlBlock(it) => it.into(),
+            AnyMdLeafBlock::MdLinkBlock(it) => it.into(),
+            AnyMdLeafBlock::MdParagraph(it) => it.into(),
+            AnyMdLeafBlock::MdSetextHeader(it) => it.into(),
+            AnyMdLeafBlock::MdTable(it) => it.into(),
+            AnyMdLeafBlock::MdThematicBreakBlock(it) => it.into(),
         }
     }
 }
-impl From<AnyMdInline> for SyntaxElement {
-    fn from(n: AnyMdInline) -> Self {
+impl From<AnyMdLeafBlock> for SyntaxElement {
+    fn from(n: AnyMdLeafBlock) -> Self {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl std::fmt::Display for AnyCodeBlock {
+impl std::fmt::Display for AnyMdBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyContainerBlock {
+impl std::fmt::Display for AnyMdCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyLeafBlock {
+impl std::fmt::Display for AnyMdContainerBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyMdBlock {
+impl std::fmt::Display for AnyMdInline {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyMdInline {
+impl std::fmt::Display for AnyMdLeafBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -3251,11 +3329,6 @@ impl std::fmt::Display for MdHtmlBlock {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdIndent {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
-    }
-}
 impl std::fmt::Display for MdIndentCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -3281,57 +3354,67 @@ impl std::fmt::Display for MdInlineImage {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineImageAlt {
+impl std::fmt::Display for MdInlineItalic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineImageLink {
+impl std::fmt::Display for MdInlineLink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineImageSource {
+impl std::fmt::Display for MdInlineStrikethrough {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineItalic {
+impl std::fmt::Display for MdLinkBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineLink {
+impl std::fmt::Display for MdOrderListItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdLinkBlock {
+impl std::fmt::Display for MdParagraph {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdOrderListItem {
+impl std::fmt::Display for MdQuote {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdParagraph {
+impl std::fmt::Display for MdQuoteLine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdQuote {
+impl std::fmt::Display for MdSetextHeader {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdSetextHeader {
+impl std::fmt::Display for MdSoftBreak {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdSoftBreak {
+impl std::fmt::Display for MdTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdTableCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdTableRow {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -3568,10 +3651,10 @@ impl IntoIterator for MdBulletList {
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdCodeNameList {
+pub struct MdHashList {
     syntax_list: SyntaxList,
 }
-impl MdCodeNameList {
+impl MdHashList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3584,12 +3667,12 @@ impl MdCodeNameList {
         }
     }
 }
-impl AstNode for MdCodeNameList {
+impl AstNode for MdHashList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_CODE_NAME_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HASH_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_CODE_NAME_LIST
+        kind == MD_HASH_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3607,7 +3690,7 @@ impl AstNode for MdCodeNameList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdCodeNameList {
+impl Serialize for MdHashList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3619,9 +3702,9 @@ impl Serialize for MdCodeNameList {
         seq.end()
     }
 }
-impl AstSeparatedList for MdCodeNameList {
+impl AstNodeList for MdHashList {
     type Language = Language;
-    type Node = MdTextual;
+    type Node = MdHash;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3629,31 +3712,31 @@ impl AstSeparatedList for MdCodeNameList {
         self.syntax_list
     }
 }
-impl Debug for MdCodeNameList {
+impl Debug for MdHashList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdCodeNameList ")?;
-        f.debug_list().entries(self.elements()).finish()
+        f.write_str("MdHashList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for MdCodeNameList {
-    type Item = SyntaxResult<MdTextual>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, MdTextual>;
+impl IntoIterator for &MdHashList {
+    type Item = MdHash;
+    type IntoIter = AstNodeListIterator<Language, MdHash>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &MdCodeNameList {
-    type Item = SyntaxResult<MdTextual>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, MdTextual>;
+impl IntoIterator for MdHashList {
+    type Item = MdHash;
+    type IntoIter = AstNodeListIterator<Language, MdHash>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdHashList {
+pub struct MdIndentedCodeLineList {
     syntax_list: SyntaxList,
 }
-impl MdHashList {
+impl MdIndentedCodeLineList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3666,12 +3749,12 @@ impl MdHashList {
         }
     }
 }
-impl AstNode for MdHashList {
+impl AstNode for MdIndentedCodeLineList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HASH_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENTED_CODE_LINE_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_HASH_LIST
+        kind == MD_INDENTED_CODE_LINE_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3689,7 +3772,7 @@ impl AstNode for MdHashList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdHashList {
+impl Serialize for MdIndentedCodeLineList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3701,9 +3784,9 @@ impl Serialize for MdHashList {
         seq.end()
     }
 }
-impl AstNodeList for MdHashList {
+impl AstNodeList for MdIndentedCodeLineList {
     type Language = Language;
-    type Node = MdHash;
+    type Node = MdIndentedCodeLine;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3711,31 +3794,31 @@ impl AstNodeList for MdHashList {
         self.syntax_list
     }
 }
-impl Debug for MdHashList {
+impl Debug for MdIndentedCodeLineList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdHashList ")?;
+        f.write_str("MdIndentedCodeLineList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdHashList {
-    type Item = MdHash;
-    type IntoIter = AstNodeListIterator<Language, MdHash>;
+impl IntoIterator for &MdIndentedCodeLineList {
+    type Item = MdIndentedCodeLine;
+    type IntoIter = AstNodeListIterator<Language, MdIndentedCodeLine>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdHashList {
-    type Item = MdHash;
-    type IntoIter = AstNodeListIterator<Language, MdHash>;
+impl IntoIterator for MdIndentedCodeLineList {
+    type Item = MdIndentedCodeLine;
+    type IntoIter = AstNodeListIterator<Language, MdIndentedCodeLine>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdIndentedCodeLineList {
+pub struct MdInlineItemList {
     syntax_list: SyntaxList,
 }
-impl MdIndentedCodeLineList {
+impl MdInlineItemList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3748,12 +3831,12 @@ impl MdIndentedCodeLineList {
         }
     }
 }
-impl AstNode for MdIndentedCodeLineList {
+impl AstNode for MdInlineItemList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENTED_CODE_LINE_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITEM_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENTED_CODE_LINE_LIST
+        kind == MD_INLINE_ITEM_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3771,7 +3854,7 @@ impl AstNode for MdIndentedCodeLineList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdIndentedCodeLineList {
+impl Serialize for MdInlineItemList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3783,9 +3866,9 @@ impl Serialize for MdIndentedCodeLineList {
         seq.end()
     }
 }
-impl AstNodeList for MdIndentedCodeLineList {
+impl AstNodeList for MdInlineItemList {
     type Language = Language;
-    type Node = MdIndentedCodeLine;
+    type Node = AnyMdInline;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3793,31 +3876,31 @@ impl AstNodeList for MdIndentedCodeLineList {
         self.syntax_list
     }
 }
-impl Debug for MdIndentedCodeLineList {
+impl Debug for MdInlineItemList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdIndentedCodeLineList ")?;
+        f.write_str("MdInlineItemList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdIndentedCodeLineList {
-    type Item = MdIndentedCodeLine;
-    type IntoIter = AstNodeListIterator<Language, MdIndentedCodeLine>;
+impl IntoIterator for &MdInlineItemList {
+    type Item = AnyMdInline;
+    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdIndentedCodeLineList {
-    type Item = MdIndentedCodeLine;
-    type IntoIter = AstNodeListIterator<Language, MdIndentedCodeLine>;
+impl IntoIterator for MdInlineItemList {
+    type Item = AnyMdInline;
+    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdInlineItemList {
+pub struct MdQuoteLineList {
     syntax_list: SyntaxList,
 }
-impl MdInlineItemList {
+impl MdQuoteLineList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3830,12 +3913,12 @@ impl MdInlineItemList {
         }
     }
 }
-impl AstNode for MdInlineItemList {
+impl AstNode for MdQuoteLineList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITEM_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_QUOTE_LINE_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_ITEM_LIST
+        kind == MD_QUOTE_LINE_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3853,7 +3936,7 @@ impl AstNode for MdInlineItemList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdInlineItemList {
+impl Serialize for MdQuoteLineList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3865,9 +3948,9 @@ impl Serialize for MdInlineItemList {
         seq.end()
     }
 }
-impl AstNodeList for MdInlineItemList {
+impl AstNodeList for MdQuoteLineList {
     type Language = Language;
-    type Node = AnyMdInline;
+    type Node = MdQuoteLine;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3875,31 +3958,113 @@ impl AstNodeList for MdInlineItemList {
         self.syntax_list
     }
 }
-impl Debug for MdInlineItemList {
+impl Debug for MdQuoteLineList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdInlineItemList ")?;
+        f.write_str("MdQuoteLineList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdInlineItemList {
-    type Item = AnyMdInline;
-    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
+impl IntoIterator for &MdQuoteLineList {
+    type Item = MdQuoteLine;
+    type IntoIter = AstNodeListIterator<Language, MdQuoteLine>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdInlineItemList {
-    type Item = AnyMdInline;
-    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
+impl IntoIterator for MdQuoteLineList {
+    type Item = MdQuoteLine;
+    type IntoIter = AstNodeListIterator<Language, MdQuoteLine>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct MdTableCellList {
+    syntax_list: SyntaxList,
+}
+impl MdTableCellList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for MdTableCellList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_CELL_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_TABLE_CELL_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for MdTableCellList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstSeparatedList for MdTableCellList {
+    type Language = Language;
+    type Node = MdTableCell;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for MdTableCellList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MdTableCellList ")?;
+        f.debug_list().entries(self.elements()).finish()
+    }
+}
+impl IntoIterator for MdTableCellList {
+    type Item = SyntaxResult<MdTableCell>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, MdTableCell>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for &MdTableCellList {
+    type Item = SyntaxResult<MdTableCell>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, MdTableCell>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdOrderList {
+pub struct MdTableRowList {
     syntax_list: SyntaxList,
 }
-impl MdOrderList {
+impl MdTableRowList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3912,12 +4077,12 @@ impl MdOrderList {
         }
     }
 }
-impl AstNode for MdOrderList {
+impl AstNode for MdTableRowList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDER_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_ROW_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_ORDER_LIST
+        kind == MD_TABLE_ROW_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3935,7 +4100,7 @@ impl AstNode for MdOrderList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdOrderList {
+impl Serialize for MdTableRowList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3947,9 +4112,9 @@ impl Serialize for MdOrderList {
         seq.end()
     }
 }
-impl AstNodeList for MdOrderList {
+impl AstNodeList for MdTableRowList {
     type Language = Language;
-    type Node = AnyCodeBlock;
+    type Node = MdTableRow;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3957,22 +4122,22 @@ impl AstNodeList for MdOrderList {
         self.syntax_list
     }
 }
-impl Debug for MdOrderList {
+impl Debug for MdTableRowList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdOrderList ")?;
+        f.write_str("MdTableRowList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdOrderList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+impl IntoIterator for &MdTableRowList {
+    type Item = MdTableRow;
+    type IntoIter = AstNodeListIterator<Language, MdTableRow>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdOrderList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+impl IntoIterator for MdTableRowList {
+    type Item = MdTableRow;
+    type IntoIter = AstNodeListIterator<Language, MdTableRow>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
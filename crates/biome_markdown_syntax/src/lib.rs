@@ -1,11 +1,15 @@
 #![deny(clippy::use_self)]
 
+mod code_block_ext;
+mod file_source;
 #[macro_use]
 mod generated;
 mod syntax_node;
 
 pub use self::generated::*;
 use biome_rowan::{RawSyntaxKind, SyntaxKind, TriviaPieceKind};
+pub use code_block_ext::{column_width, strip_indentation, token_column};
+pub use file_source::MarkdownFileSource;
 pub use syntax_node::*;
 
 impl From<u16> for MarkdownSyntaxKind {
@@ -37,7 +41,7 @@ impl SyntaxKind for MarkdownSyntaxKind {
     }
 
     fn is_root(&self) -> bool {
-        todo!()
+        matches!(self, Self::MD_DOCUMENT)
     }
 
     fn is_list(&self) -> bool {
@@ -61,7 +65,7 @@ impl TryFrom<MarkdownSyntaxKind> for TriviaPieceKind {
             match value {
                 MarkdownSyntaxKind::NEWLINE => Ok(Self::Newline),
                 MarkdownSyntaxKind::WHITESPACE => Ok(Self::Whitespace),
-                MarkdownSyntaxKind::TAB => Ok(Self::Skipped),
+                MarkdownSyntaxKind::TAB => Ok(Self::Whitespace),
                 _ => unreachable!("Not Trivia"),
             }
         } else {
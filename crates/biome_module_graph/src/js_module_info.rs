@@ -18,7 +18,7 @@ use biome_rowan::{AstNode, Text, TextRange};
 use rust_lapper::Lapper;
 use rustc_hash::FxHashMap;
 
-use crate::ModuleGraph;
+use crate::{GlobalDeclarations, ModuleGraph};
 
 use scope::{JsScope, JsScopeData, TsBindingReference};
 
@@ -161,6 +161,10 @@ pub struct JsModuleInfoInner {
 
     /// Collection of all types in the module.
     pub(crate) types: Box<[TypeData]>,
+
+    /// Global declarations that are in scope for the module, such as those
+    /// from TypeScript's `lib.*.d.ts` files.
+    pub global_declarations: GlobalDeclarations,
 }
 
 #[derive(Debug, Default)]
@@ -1,7 +1,8 @@
 use std::sync::Arc;
 
 use biome_js_syntax::{
-    AnyJsDeclaration, JsImport, JsSyntaxNode, JsVariableKind, TextRange, TsTypeParameter,
+    AnyJsDeclaration, JsImport, JsSyntaxNode, JsVariableKind, TextRange, TsInferType, TsMappedType,
+    TsTypeParameter,
 };
 use biome_js_type_info::{BindingId, ScopeId, TypeReference};
 use biome_rowan::{AstNode, Text, TextSize};
@@ -180,7 +181,10 @@ impl JsDeclarationKind {
 
     pub fn from_node(node: &JsSyntaxNode) -> Self {
         for ancestor in node.ancestors() {
-            if TsTypeParameter::can_cast(ancestor.kind()) {
+            if TsTypeParameter::can_cast(ancestor.kind())
+                || TsInferType::can_cast(ancestor.kind())
+                || TsMappedType::can_cast(ancestor.kind())
+            {
                 return Self::Generic;
             }
 
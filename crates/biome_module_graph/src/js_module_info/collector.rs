@@ -8,8 +8,8 @@ use biome_js_semantic::{SemanticEvent, SemanticEventExtractor};
 use biome_js_syntax::{
     AnyJsCombinedSpecifier, AnyJsDeclaration, AnyJsExportDefaultDeclaration, AnyJsExpression,
     AnyJsImportClause, JsFormalParameter, JsIdentifierBinding, JsSyntaxKind, JsSyntaxNode,
-    JsSyntaxToken, JsVariableDeclaration, TsIdentifierBinding, TsTypeParameter,
-    TsTypeParameterName, inner_string_text,
+    JsSyntaxToken, JsVariableDeclaration, TsIdentifierBinding, TsInferType, TsMappedType,
+    TsTypeParameter, TsTypeParameterName, inner_string_text,
 };
 use biome_js_type_info::{
     BindingId, FunctionParameter, GLOBAL_RESOLVER, GLOBAL_UNKNOWN_ID, GenericTypeParameter, Module,
@@ -22,10 +22,13 @@ use biome_rowan::{AstNode, Text, TextRange, TextSize, TokenText};
 use rust_lapper::{Interval, Lapper};
 use rustc_hash::FxHashMap;
 
-use crate::js_module_info::{
-    binding::{JsBindingReference, JsBindingReferenceKind, JsDeclarationKind},
-    scope::TsBindingReference,
-    scope_id_for_range,
+use crate::{
+    GlobalDeclarations,
+    js_module_info::{
+        binding::{JsBindingReference, JsBindingReferenceKind, JsDeclarationKind},
+        scope::TsBindingReference,
+        scope_id_for_range,
+    },
 };
 
 use super::{
@@ -110,6 +113,9 @@ pub(super) struct JsModuleInfoCollector {
 
     /// Types collected in the module.
     types: TypeStore,
+
+    /// Global declarations that are in scope for the module.
+    pub(super) global_declarations: GlobalDeclarations,
 }
 
 /// Intermediary representation for an exported symbol.
@@ -475,6 +481,16 @@ impl JsModuleInfoCollector {
                     Some(generic) => self.reference_to_owned_data(TypeData::from(generic)),
                     None => TypeReference::Unknown,
                 };
+            } else if let Some(ty) = TsInferType::cast_ref(&ancestor) {
+                return match GenericTypeParameter::from_ts_infer_type(self, scope_id, &ty) {
+                    Some(generic) => self.reference_to_owned_data(TypeData::infer(generic)),
+                    None => TypeReference::Unknown,
+                };
+            } else if let Some(ty) = TsMappedType::cast_ref(&ancestor) {
+                return match GenericTypeParameter::from_ts_mapped_type(self, scope_id, &ty) {
+                    Some(generic) => self.reference_to_owned_data(TypeData::from(generic)),
+                    None => TypeReference::Unknown,
+                };
             }
         }
 
@@ -582,6 +598,57 @@ impl JsModuleInfoCollector {
         None
     }
 
+    /// Returns whether the global with the given `name` is declared by the
+    /// declarations from an installed TypeScript, which take precedence over
+    /// our hardcoded globals.
+    fn is_declared_by_typescript(&self, name: &str) -> bool {
+        self.global_declarations.is_from_typescript()
+            && self.global_declarations.modules().any(|module| {
+                module
+                    .find_binding_in_scope(name, ScopeId::GLOBAL)
+                    .is_some()
+            })
+    }
+
+    /// Finds a type parameter declared through `infer` or a mapped type with
+    /// the given `name` in any of the scopes nested inside the scope with the
+    /// given `scope_id`.
+    ///
+    /// Such type parameters live in scopes nested inside their type alias,
+    /// while references to them may be resolved from the scope of the alias
+    /// itself.
+    fn find_inferred_type_parameter_in_nested_scopes(
+        &self,
+        name: &str,
+        scope_id: ScopeId,
+    ) -> Option<BindingId> {
+        let mut scope_ids = self.scopes[scope_id.index()].children.clone();
+        while let Some(scope_id) = scope_ids.pop() {
+            let scope = &self.scopes[scope_id.index()];
+            let binding_id = scope
+                .bindings_by_name
+                .get(name)
+                .and_then(|binding_ref| binding_ref.namespace_ty_or_ty())
+                .filter(|binding_id| {
+                    let binding = &self.bindings[binding_id.index()];
+                    self.binding_node_by_start
+                        .get(&binding.range.start())
+                        .and_then(JsSyntaxNode::parent)
+                        .is_some_and(|parent| {
+                            TsInferType::can_cast(parent.kind())
+                                || TsMappedType::can_cast(parent.kind())
+                        })
+                });
+            if binding_id.is_some() {
+                return binding_id;
+            }
+
+            scope_ids.extend_from_slice(&scope.children);
+        }
+
+        None
+    }
+
     fn find_type_members_in_scope(&self, scope_id: ScopeId) -> Box<[TypeMember]> {
         self.bindings
             .iter()
@@ -650,7 +717,23 @@ impl TypeResolver for JsModuleInfoCollector {
     fn resolve_qualifier(&self, qualifier: &TypeReferenceQualifier) -> Option<ResolvedTypeId> {
         let identifier = qualifier.path.first()?;
         let Some(binding_ref) = self.find_binding_in_scope(identifier, qualifier.scope_id) else {
-            return GLOBAL_RESOLVER.resolve_qualifier(qualifier);
+            let global = if self.is_declared_by_typescript(identifier) {
+                // Resolved by the `ScopedResolver` from the declarations
+                None
+            } else {
+                GLOBAL_RESOLVER.resolve_qualifier(qualifier)
+            };
+            return global.or_else(|| {
+                if qualifier.path.len() > 1 {
+                    return None;
+                }
+
+                let binding_id = self.find_inferred_type_parameter_in_nested_scopes(
+                    identifier,
+                    qualifier.scope_id,
+                )?;
+                self.resolve_reference(&self.bindings[binding_id.index()].ty)
+            });
         };
 
         let binding_id = binding_ref.get_binding_id_for_qualifier(qualifier)?;
@@ -676,6 +759,10 @@ impl TypeResolver for JsModuleInfoCollector {
         self.resolve_reference(&ty)
     }
 
+    fn resolve_import_specifier(&self, specifier: &str) -> Option<ResolvedPath> {
+        self.static_import_paths.get(specifier).cloned()
+    }
+
     fn resolve_type_of(&self, identifier: &Text, scope_id: ScopeId) -> Option<ResolvedTypeId> {
         if let Some(binding_id) = self
             .find_binding_in_scope(identifier, scope_id)
@@ -692,6 +779,10 @@ impl TypeResolver for JsModuleInfoCollector {
             };
         }
 
+        if self.is_declared_by_typescript(identifier) {
+            return None;
+        }
+
         GLOBAL_RESOLVER.resolve_type_of(identifier, scope_id)
     }
 
@@ -775,10 +866,10 @@ impl JsModuleInfoBag {
                             let local_name = specifier.local_name()?;
                             let local_name = local_name.as_js_identifier_binding()?;
                             let local_name_token = local_name.name_token().ok()?;
-                            let symbol_name = specifier
-                                .imported_name()
-                                .unwrap_or_else(|| local_name_token.clone())
-                                .token_text_trimmed();
+                            let symbol_name = specifier.imported_name().map_or_else(
+                                || local_name_token.token_text_trimmed(),
+                                |name| inner_string_text(&name),
+                            );
                             self.static_imports.insert(
                                 local_name_token.token_text_trimmed().into(),
                                 JsImport {
@@ -833,10 +924,10 @@ impl JsModuleInfoBag {
                     let local_name = specifier.local_name()?;
                     let local_name = local_name.as_js_identifier_binding()?;
                     let local_name_token = local_name.name_token().ok()?;
-                    let symbol_name = specifier
-                        .imported_name()
-                        .unwrap_or_else(|| local_name_token.clone())
-                        .token_text_trimmed();
+                    let symbol_name = specifier.imported_name().map_or_else(
+                        || local_name_token.token_text_trimmed(),
+                        |name| inner_string_text(&name),
+                    );
                     self.static_imports.insert(
                         local_name_token.token_text_trimmed().into(),
                         JsImport {
@@ -996,6 +1087,7 @@ impl JsModuleInfo {
             scopes: collector.scopes.into(),
             scope_by_range,
             types: collector.types.into(),
+            global_declarations: collector.global_declarations,
         }))
     }
 }
@@ -6,9 +6,10 @@ use std::{
 
 use biome_js_syntax::AnyJsExpression;
 use biome_js_type_info::{
-    GLOBAL_RESOLVER, GLOBAL_UNKNOWN_ID, ImportSymbol, ModuleId, Resolvable, ResolvedTypeData,
-    ResolvedTypeId, ResolverId, ScopeId, Type, TypeData, TypeId, TypeImportQualifier,
-    TypeReference, TypeReferenceQualifier, TypeResolver, TypeResolverLevel, TypeStore,
+    GLOBAL_ARRAY_ID, GLOBAL_PROMISE_ID, GLOBAL_RESOLVER, GLOBAL_UNKNOWN_ID, ImportSymbol, ModuleId,
+    Resolvable, ResolvedTypeData, ResolvedTypeId, ResolverId, ScopeId, Type, TypeData, TypeGuard,
+    TypeId, TypeImportQualifier, TypeReference, TypeReferenceQualifier, TypeResolver,
+    TypeResolverLevel, TypeStore,
 };
 use biome_resolver::ResolvedPath;
 use biome_rowan::{AstNode, Text, TextRange};
@@ -54,6 +55,10 @@ pub struct ScopedResolver {
     /// Module IDs are used to index the [`Self::modules`] vector.
     pub modules_by_path: BTreeMap<ResolvedPath, ModuleId>,
 
+    /// IDs of the modules with global declarations, in the order in which
+    /// they should be searched.
+    global_module_ids: Vec<ModuleId>,
+
     /// Parsed expressions, mapped by their starting index to the ID of their
     /// type.
     expressions: FxHashMap<TextRange, TypeId>,
@@ -71,6 +76,7 @@ impl ScopedResolver {
             module_graph,
             modules: vec![module_info],
             modules_by_path: Default::default(),
+            global_module_ids: Default::default(),
             expressions: Default::default(),
             types: Default::default(),
             type_id_map: Default::default(),
@@ -86,6 +92,30 @@ impl ScopedResolver {
         Type::from_id(self.clone(), type_id)
     }
 
+    /// Returns the resolved type of the binding declared at the given `range`
+    /// within this module, if there is such a binding.
+    ///
+    /// Assumes that the resolver's inference has run.
+    pub fn resolved_type_for_binding(self: &Arc<Self>, range: TextRange) -> Option<Type> {
+        let module = &self.modules[0];
+        let binding = module
+            .bindings
+            .iter()
+            .find(|binding| binding.range == range)?;
+        let resolved_id = if binding.declaration_kind.is_import_declaration() {
+            let import = module.static_imports.get(&binding.name)?;
+            self.resolve_import(&TypeImportQualifier {
+                symbol: import.symbol.clone(),
+                resolved_path: import.resolved_path.clone(),
+                type_only: binding.declaration_kind.is_import_type_declaration(),
+            })
+        } else {
+            self.resolve_reference(&binding.ty)
+        }?;
+
+        Some(Type::from_id(self.clone(), resolved_id))
+    }
+
     fn find_module(&self, path: &ResolvedPath) -> Option<ModuleId> {
         self.modules_by_path.get(path).copied()
     }
@@ -105,10 +135,67 @@ impl ScopedResolver {
 
     pub fn run_inference(&mut self) {
         self.resolve_imports_in_modules();
+        self.register_global_declarations();
         self.resolve_all();
         self.flatten_all();
     }
 
+    /// Registers the modules with the global declarations that are in scope
+    /// for the first module.
+    ///
+    /// These are registered after the imported modules, so that they don't
+    /// affect the IDs of modules from the project.
+    fn register_global_declarations(&mut self) {
+        let global_declarations = self.modules[0].global_declarations.clone();
+        for module_info in global_declarations.modules() {
+            let module_id = ModuleId::new(self.modules.len());
+            self.modules.push(module_info.clone());
+            self.global_module_ids.push(module_id);
+        }
+    }
+
+    /// Resolves the given `qualifier` against the global declarations, such as
+    /// those from TypeScript's `lib.*.d.ts` files.
+    ///
+    /// Like the [`GLOBAL_RESOLVER`], this only resolves qualifiers without type
+    /// parameters, so that an instantiation gets created for them.
+    fn resolve_qualifier_in_global_declarations(
+        &self,
+        qualifier: &TypeReferenceQualifier,
+    ) -> Option<ResolvedTypeId> {
+        if qualifier.path.len() != 1 || qualifier.has_known_type_parameters() {
+            return None;
+        }
+
+        self.resolve_global_declaration(&qualifier.path[0], qualifier.type_only)
+    }
+
+    /// Returns whether the global declarations in scope for the first module
+    /// were loaded from an installed TypeScript.
+    fn has_typescript_declarations(&self) -> bool {
+        self.modules[0].global_declarations.is_from_typescript()
+    }
+
+    /// Resolves the type of the global declaration with the given `name`.
+    fn resolve_global_declaration(&self, name: &str, type_only: bool) -> Option<ResolvedTypeId> {
+        self.global_module_ids.iter().find_map(|module_id| {
+            let module = &self.modules[module_id.index()];
+            let binding_ref = module.find_binding_in_scope(name, ScopeId::GLOBAL)?;
+            let binding_id = if type_only {
+                binding_ref.namespace_ty_or_ty()?
+            } else {
+                binding_ref.value_ty_or_ty()
+            };
+
+            match &module.binding(binding_id).ty {
+                TypeReference::Resolved(resolved_id) => {
+                    Some(resolved_id.with_module_id(*module_id))
+                }
+                _ => None,
+            }
+        })
+    }
+
     /// Actively resolves imports in the modules we are of.
     fn resolve_imports_in_modules(&mut self) {
         let mut i = 0;
@@ -165,6 +252,38 @@ impl ScopedResolver {
             None => GLOBAL_UNKNOWN_ID,
         }
     }
+
+    /// Narrows the type of the given expression by applying the given
+    /// `guards`, which are known to apply at the location of the expression.
+    ///
+    /// Subsequent lookups of the expression's type will return the narrowed
+    /// type.
+    pub fn narrow_type_for_expression(&mut self, expr: &AnyJsExpression, guards: &[TypeGuard]) {
+        let Some(id) = self.expressions.get(&expr.range()).copied() else {
+            return;
+        };
+
+        let original_id = ResolvedTypeId::new(TypeResolverLevel::Scope, id);
+        let narrowed_id = guards.iter().fold(original_id, |id, guard| {
+            guard.narrow(id, self).unwrap_or(id)
+        });
+        if narrowed_id == original_id {
+            return;
+        }
+
+        let type_id = match narrowed_id.level() {
+            TypeResolverLevel::Scope => narrowed_id.id(),
+            _ => match self.get_by_resolved_id(narrowed_id) {
+                Some(resolved) => {
+                    let data = resolved.to_data();
+                    self.register_type(Cow::Owned(data))
+                }
+                None => return,
+            },
+        };
+
+        self.expressions.insert(expr.range(), type_id);
+    }
 }
 
 impl TypeResolver for ScopedResolver {
@@ -206,7 +325,7 @@ impl TypeResolver for ScopedResolver {
 
     fn resolve_reference(&self, ty: &TypeReference) -> Option<ResolvedTypeId> {
         match ty {
-            TypeReference::Qualifier(_qualifier) => None,
+            TypeReference::Qualifier(qualifier) => self.resolve_qualifier(qualifier),
             TypeReference::Resolved(resolved_id) => {
                 Some(if resolved_id.resolver_id() == MODULE_0_ID {
                     ResolvedTypeId::new(
@@ -273,16 +392,33 @@ impl TypeResolver for ScopedResolver {
         None
     }
 
-    fn resolve_qualifier(&self, _qualifier: &TypeReferenceQualifier) -> Option<ResolvedTypeId> {
+    fn resolve_qualifier(&self, qualifier: &TypeReferenceQualifier) -> Option<ResolvedTypeId> {
         // We rely on qualifiers to have been resolved during the construction
-        // of the module graph.
-        None
+        // of the module graph. Those that remain can only refer to globals.
+        // The declarations from an installed TypeScript take precedence over
+        // our hardcoded globals, which are the fallback for the bundled
+        // declarations that don't include `Array` or `Promise`.
+        if self.has_typescript_declarations() {
+            self.resolve_qualifier_in_global_declarations(qualifier)
+                .or_else(|| GLOBAL_RESOLVER.resolve_qualifier(qualifier))
+        } else {
+            GLOBAL_RESOLVER
+                .resolve_qualifier(qualifier)
+                .or_else(|| self.resolve_qualifier_in_global_declarations(qualifier))
+        }
     }
 
     fn resolve_type_of(&self, identifier: &Text, scope_id: ScopeId) -> Option<ResolvedTypeId> {
         let module = &self.modules[0];
         let Some(binding_ref) = module.find_binding_in_scope(identifier, scope_id) else {
-            return GLOBAL_RESOLVER.resolve_type_of(identifier, scope_id);
+            return if self.has_typescript_declarations() {
+                self.resolve_global_declaration(identifier, false)
+                    .or_else(|| GLOBAL_RESOLVER.resolve_type_of(identifier, scope_id))
+            } else {
+                GLOBAL_RESOLVER
+                    .resolve_type_of(identifier, scope_id)
+                    .or_else(|| self.resolve_global_declaration(identifier, false))
+            };
         };
 
         let binding = module.binding(binding_ref.value_ty_or_ty());
@@ -299,6 +435,24 @@ impl TypeResolver for ScopedResolver {
         }
     }
 
+    fn is_declaration_of_global(&self, id: ResolvedTypeId, global_id: ResolvedTypeId) -> bool {
+        let name = if global_id == GLOBAL_ARRAY_ID {
+            "Array"
+        } else if global_id == GLOBAL_PROMISE_ID {
+            "Promise"
+        } else {
+            return false;
+        };
+
+        // Interfaces can be declared by several files, so any of them matches
+        id.is_at_module_level()
+            && self.global_module_ids.contains(&id.module_id())
+            && matches!(
+                self.get_by_resolved_id(id).map(ResolvedTypeData::as_raw_data),
+                Some(TypeData::Interface(interface)) if interface.name.text() == name
+            )
+    }
+
     fn resolve_expression(&mut self, _scope_id: ScopeId, expr: &AnyJsExpression) -> Cow<TypeData> {
         let id = self.resolved_id_for_expression(expr);
         match self.get_by_resolved_id(id) {
@@ -3,7 +3,7 @@ use biome_js_syntax::{
     AnyJsExportClause, AnyJsExportDefaultDeclaration, AnyJsExpression, AnyJsImportLike,
     AnyJsObjectBindingPatternMember, AnyJsRoot, AnyTsIdentifierBinding, AnyTsModuleName,
     JsExportFromClause, JsExportNamedFromClause, JsExportNamedSpecifierList, JsIdentifierBinding,
-    JsVariableDeclaratorList, TsExportAssignmentClause, unescape_js_string,
+    JsVariableDeclaratorList, TsExportAssignmentClause, TsImportType, unescape_js_string,
 };
 use biome_js_type_info::{ImportSymbol, ScopeId, TypeData, TypeReference, TypeResolver};
 use biome_jsdoc_comment::JsdocComment;
@@ -12,7 +12,7 @@ use biome_rowan::{AstNode, TokenText, WalkEvent};
 use camino::Utf8Path;
 
 use crate::{
-    JsImport, JsModuleInfo, JsReexport, SUPPORTED_TYPE_EXTENSIONS,
+    GlobalDeclarations, JsImport, JsModuleInfo, JsReexport, SUPPORTED_TYPE_EXTENSIONS,
     js_module_info::collector::JsCollectedExport, module_graph::ModuleGraphFsProxy,
 };
 
@@ -22,6 +22,7 @@ pub(crate) struct JsModuleVisitor<'a> {
     root: AnyJsRoot,
     directory: &'a Utf8Path,
     fs_proxy: &'a ModuleGraphFsProxy<'a>,
+    global_declarations: GlobalDeclarations,
 }
 
 impl<'a> JsModuleVisitor<'a> {
@@ -30,11 +31,19 @@ impl<'a> JsModuleVisitor<'a> {
             root,
             directory,
             fs_proxy,
+            global_declarations: GlobalDeclarations::default(),
         }
     }
 
+    /// Sets the global declarations that are in scope for the module.
+    pub fn with_global_declarations(mut self, global_declarations: GlobalDeclarations) -> Self {
+        self.global_declarations = global_declarations;
+        self
+    }
+
     pub fn collect_info(self) -> JsModuleInfo {
         let mut collector = JsModuleInfoCollector::default();
+        collector.global_declarations = self.global_declarations.clone();
 
         let iter = self.root.syntax().preorder();
         for event in iter {
@@ -46,6 +55,8 @@ impl<'a> JsModuleVisitor<'a> {
                         self.visit_import(import, &mut collector);
                     } else if let Some(export) = biome_js_syntax::JsExport::cast_ref(&node) {
                         self.visit_export(export, &mut collector);
+                    } else if let Some(import_type) = TsImportType::cast_ref(&node) {
+                        self.visit_import_type(import_type, &mut collector);
                     }
                 }
                 WalkEvent::Leave(node) => {
@@ -76,6 +87,27 @@ impl<'a> JsModuleVisitor<'a> {
         }
     }
 
+    /// Import types, such as `import("./module").T`, are registered as static
+    /// imports so that the types they refer to can be resolved.
+    fn visit_import_type(
+        &self,
+        node: TsImportType,
+        collector: &mut JsModuleInfoCollector,
+    ) -> Option<()> {
+        let specifier = node
+            .arguments()
+            .ok()?
+            .argument()
+            .ok()?
+            .as_ts_string_literal_type()?
+            .inner_string_text()
+            .ok()?;
+        let resolved_path = self.resolved_path_from_specifier(specifier.text());
+        collector.register_static_import_path(specifier, resolved_path);
+
+        Some(())
+    }
+
     fn visit_export(
         &self,
         node: biome_js_syntax::JsExport,
@@ -10,4 +10,6 @@ pub use biome_resolver::ResolvedPath;
 pub use js_module_info::{
     JsExport, JsImport, JsModuleInfo, JsOwnExport, JsReexport, ScopedResolver,
 };
-pub use module_graph::{ModuleGraph, SUPPORTED_EXTENSIONS, SUPPORTED_TYPE_EXTENSIONS};
+pub use module_graph::{
+    GlobalDeclarations, ModuleGraph, SUPPORTED_EXTENSIONS, SUPPORTED_TYPE_EXTENSIONS, UsedExports,
+};
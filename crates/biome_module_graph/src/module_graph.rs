@@ -7,22 +7,26 @@
 //! The module graph is instantiated and updated inside the Workspace Server.
 
 mod fs_proxy;
+mod global_declarations;
 
 use std::collections::BTreeSet;
 
 use biome_fs::BiomePath;
-use biome_js_syntax::AnyJsRoot;
+use biome_js_syntax::{AnyJsRoot, TextRange};
 use biome_js_type_info::ImportSymbol;
 use biome_jsdoc_comment::JsdocComment;
 use biome_project_layout::ProjectLayout;
-use biome_resolver::{FsWithResolverProxy, PathInfo};
+use biome_resolver::{FsWithResolverProxy, PathInfo, ResolvedPath};
+use biome_rowan::Text;
 use camino::{Utf8Path, Utf8PathBuf};
 use papaya::{HashMap, HashMapRef, LocalGuard};
-use rustc_hash::FxBuildHasher;
+use rustc_hash::{FxBuildHasher, FxHashMap};
 
-use crate::{JsExport, JsModuleInfo, JsOwnExport, js_module_info::JsModuleVisitor};
+use crate::{JsExport, JsModuleInfo, JsOwnExport, JsReexport, js_module_info::JsModuleVisitor};
 
 pub(crate) use fs_proxy::ModuleGraphFsProxy;
+pub use global_declarations::GlobalDeclarations;
+use global_declarations::GlobalDeclarationsFile;
 
 pub const SUPPORTED_EXTENSIONS: &[&str] = &[
     "js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts", "json", "node",
@@ -51,6 +55,14 @@ pub struct ModuleGraph {
     /// Cache that tracks the presence of files, directories, and symlinks
     /// across the project.
     path_info: HashMap<Utf8PathBuf, Option<PathInfo>>,
+
+    /// Cached module info for files with global declarations, such as
+    /// TypeScript's `lib.*.d.ts` files, together with the libraries they
+    /// reference.
+    ///
+    /// These are kept separate from [Self::data], because they are not part of
+    /// the project.
+    global_declaration_files: HashMap<Utf8PathBuf, Option<GlobalDeclarationsFile>>,
 }
 
 impl ModuleGraph {
@@ -100,9 +112,29 @@ impl ModuleGraph {
         // Traverse all the added and updated paths and insert their resolved
         // imports.
         let imports = self.data.pin();
+        let mut global_declarations_by_package = FxHashMap::default();
         for (path, root) in added_or_updated_paths {
+            let package_path = project_layout
+                .find_node_manifest_for_path(path)
+                .map(|(package_path, _)| package_path);
+            let global_declarations = global_declarations_by_package
+                .entry(package_path.clone())
+                .or_insert_with(|| {
+                    let tsconfig = package_path.as_deref().and_then(|package_path| {
+                        project_layout.get_tsconfig_json_for_package(package_path)
+                    });
+                    self.global_declarations_for_package(
+                        &fs_proxy,
+                        fs,
+                        package_path.as_deref(),
+                        tsconfig.as_ref().map(|tsconfig| &tsconfig.compiler_options),
+                    )
+                })
+                .clone();
+
             let directory = path.parent().unwrap_or(path);
-            let visitor = JsModuleVisitor::new(root.clone(), directory, &fs_proxy);
+            let visitor = JsModuleVisitor::new(root.clone(), directory, &fs_proxy)
+                .with_global_declarations(global_declarations);
             imports.insert(path.to_path_buf(), visitor.collect_info());
         }
 
@@ -136,7 +168,7 @@ impl ModuleGraph {
         let mut seen_paths = BTreeSet::new();
 
         find_exported_symbol_with_seen_paths(&data, module, symbol_name, &mut seen_paths)
-            .map(|(_, export)| export.clone())
+            .map(|(_, _, export)| export.clone())
     }
 
     /// Finds an exported symbol by `symbol_name` as exported by `module`.
@@ -151,7 +183,7 @@ impl ModuleGraph {
         let mut seen_paths = BTreeSet::new();
 
         find_exported_symbol_with_seen_paths(&data, module, symbol_name, &mut seen_paths).and_then(
-            |(module, export)| match export {
+            |(_, module, export)| match export {
                 JsOwnExport::Binding(binding_id) => {
                     module.bindings[binding_id.index()].jsdoc.clone()
                 }
@@ -159,6 +191,291 @@ impl ModuleGraph {
             },
         )
     }
+
+    /// Finds the declaration of the symbol that the module at `path` exports
+    /// as `symbol_name`.
+    ///
+    /// Follows re-exports if necessary. Returns the path of the module that
+    /// declares the symbol, together with the range of its binding. Symbols
+    /// that are exported without a binding are not returned.
+    pub fn find_exported_symbol_declaration(
+        &self,
+        path: &Utf8Path,
+        symbol_name: &str,
+    ) -> Option<(Utf8PathBuf, TextRange)> {
+        let data = self.data.pin();
+        let module = data.get(path)?;
+        let mut seen_paths = BTreeSet::new();
+
+        find_exported_symbol_with_seen_paths(&data, module, symbol_name, &mut seen_paths).and_then(
+            |(declaring_path, module, export)| match export {
+                JsOwnExport::Binding(binding_id) => Some((
+                    declaring_path.unwrap_or(path).to_path_buf(),
+                    module.binding(*binding_id).range,
+                )),
+                JsOwnExport::Type(_) => None,
+            },
+        )
+    }
+
+    /// Returns the paths of the modules that statically import the module at
+    /// `path`.
+    ///
+    /// Modules that import one of the returned modules are included too, if
+    /// the latter re-exports symbols from `path`.
+    pub fn find_importers(&self, path: &Utf8Path) -> BTreeSet<Utf8PathBuf> {
+        let data = self.data.pin();
+        let mut importers = BTreeSet::new();
+        let mut queue = vec![path.to_path_buf()];
+        while let Some(imported_path) = queue.pop() {
+            let is_imported_path = |resolved_path: &ResolvedPath| {
+                resolved_path.as_deref() == Ok(imported_path.as_path())
+            };
+            for (importer_path, module) in data.iter() {
+                if importers.contains(importer_path)
+                    || !module.static_import_paths.values().any(is_imported_path)
+                {
+                    continue;
+                }
+
+                importers.insert(importer_path.clone());
+                let reexports = module.exports.values().any(|export| match export {
+                    JsExport::Reexport(reexport) | JsExport::ReexportType(reexport) => {
+                        is_imported_path(&reexport.import.resolved_path)
+                    }
+                    JsExport::Own(_) | JsExport::OwnType(_) => false,
+                }) || module
+                    .blanket_reexports
+                    .iter()
+                    .any(|reexport| is_imported_path(&reexport.import.resolved_path));
+                if reexports {
+                    queue.push(importer_path.clone());
+                }
+            }
+        }
+
+        importers
+    }
+
+    /// Returns the paths of the modules that import the module at `path`,
+    /// directly or transitively, by following the static imports, dynamic
+    /// imports, and re-exports of the modules.
+    ///
+    /// These are the modules that can affect whether the exports of `path`
+    /// are used, and whether it's reachable from an entry point.
+    pub fn find_dependents(&self, path: &Utf8Path) -> BTreeSet<Utf8PathBuf> {
+        let data = self.data.pin();
+        let mut importers_by_path: FxHashMap<&Utf8Path, Vec<&Utf8Path>> = FxHashMap::default();
+        for (importer_path, module) in data.iter() {
+            for resolved_path in module
+                .static_import_paths
+                .values()
+                .chain(module.dynamic_import_paths.values())
+            {
+                if let Some(resolved_path) = resolved_path.as_path() {
+                    importers_by_path
+                        .entry(resolved_path)
+                        .or_default()
+                        .push(importer_path);
+                }
+            }
+        }
+
+        let mut dependents = BTreeSet::new();
+        let mut queue = vec![path];
+        while let Some(imported_path) = queue.pop() {
+            for importer_path in importers_by_path.get(imported_path).into_iter().flatten() {
+                if *importer_path != path && dependents.insert(importer_path.to_path_buf()) {
+                    queue.push(importer_path);
+                }
+            }
+        }
+
+        dependents
+    }
+
+    /// Returns whether the module at `path` is reachable from one of the
+    /// entry points, by following the static imports, dynamic imports, and
+    /// re-exports of the modules.
+    ///
+    /// Every module for which `is_entry_point()` returns `true` is an entry
+    /// point.
+    pub fn is_reachable(
+        &self,
+        path: &Utf8Path,
+        is_entry_point: &dyn Fn(&Utf8Path) -> bool,
+    ) -> bool {
+        let data = self.data.pin();
+        let mut seen = BTreeSet::new();
+        let mut queue: Vec<Utf8PathBuf> = data
+            .keys()
+            .filter(|entry_point| is_entry_point(entry_point))
+            .cloned()
+            .collect();
+        while let Some(module_path) = queue.pop() {
+            if module_path == path {
+                return true;
+            }
+
+            if !seen.insert(module_path.clone()) {
+                continue;
+            }
+
+            if let Some(module) = data.get(&module_path) {
+                queue.extend(
+                    module
+                        .all_import_paths()
+                        .filter_map(|resolved_path| {
+                            resolved_path.as_path().map(Utf8Path::to_path_buf)
+                        })
+                        .filter(|resolved_path| !seen.contains(resolved_path)),
+                );
+            }
+        }
+
+        false
+    }
+
+    /// Returns the exports of the module at `path` that are used by other
+    /// modules.
+    ///
+    /// Re-exports only count as a use if the re-exported symbol is used in
+    /// turn. All the exports of modules for which `is_entry_point()` returns
+    /// `true` are considered used.
+    pub fn find_used_exports(
+        &self,
+        path: &Utf8Path,
+        is_entry_point: &dyn Fn(&Utf8Path) -> bool,
+    ) -> UsedExports {
+        let data = self.data.pin();
+        let mut stack = BTreeSet::new();
+
+        find_used_exports_with_stack(&data, path, is_entry_point, &mut stack)
+    }
+}
+
+/// Exports of a module that are used by other modules.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UsedExports {
+    /// All exports are used, for instance because the module is an entry
+    /// point, or because it's imported as a namespace.
+    All,
+
+    /// Only the exports with the given names are used.
+    Names(BTreeSet<Text>),
+}
+
+impl UsedExports {
+    pub fn contains(&self, name: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Names(names) => names.contains(name),
+        }
+    }
+}
+
+/// Finds the exports of the module at `path` that are used by other modules.
+///
+/// `stack` contains the paths of the modules whose used exports are being
+/// computed, so that cycles of re-exports don't recurse endlessly.
+fn find_used_exports_with_stack(
+    data: &HashMapRef<Utf8PathBuf, JsModuleInfo, FxBuildHasher, LocalGuard>,
+    path: &Utf8Path,
+    is_entry_point: &dyn Fn(&Utf8Path) -> bool,
+    stack: &mut BTreeSet<Utf8PathBuf>,
+) -> UsedExports {
+    if is_entry_point(path) {
+        return UsedExports::All;
+    }
+
+    if !stack.insert(path.to_path_buf()) {
+        return UsedExports::Names(BTreeSet::new());
+    }
+
+    let used_exports = find_used_exports_in_importers(data, path, is_entry_point, stack);
+    stack.remove(path);
+    used_exports
+}
+
+fn find_used_exports_in_importers(
+    data: &HashMapRef<Utf8PathBuf, JsModuleInfo, FxBuildHasher, LocalGuard>,
+    path: &Utf8Path,
+    is_entry_point: &dyn Fn(&Utf8Path) -> bool,
+    stack: &mut BTreeSet<Utf8PathBuf>,
+) -> UsedExports {
+    let is_path = |resolved_path: &ResolvedPath| resolved_path.as_deref() == Ok(path);
+    let mut names = BTreeSet::new();
+
+    for (importer_path, module) in data.iter() {
+        if importer_path == path {
+            continue;
+        }
+
+        // We can't tell which exports are used by dynamic imports.
+        if module.dynamic_import_paths.values().any(is_path) {
+            return UsedExports::All;
+        }
+
+        for import in module.static_imports.values() {
+            if is_path(&import.resolved_path) && !insert_imported_symbol(&mut names, &import.symbol)
+            {
+                return UsedExports::All;
+            }
+        }
+
+        let mut importer_used_exports = None;
+        for (name, export) in module.exports.iter() {
+            let (JsExport::Reexport(reexport) | JsExport::ReexportType(reexport)) = export else {
+                continue;
+            };
+            if !is_path(&reexport.import.resolved_path) {
+                continue;
+            }
+
+            let importer_used_exports = importer_used_exports.get_or_insert_with(|| {
+                find_used_exports_with_stack(data, importer_path, is_entry_point, stack)
+            });
+            if importer_used_exports.contains(name)
+                && !insert_imported_symbol(&mut names, &reexport.import.symbol)
+            {
+                return UsedExports::All;
+            }
+        }
+
+        if module
+            .blanket_reexports
+            .iter()
+            .any(|reexport| is_path(&reexport.import.resolved_path))
+        {
+            let importer_used_exports = importer_used_exports.get_or_insert_with(|| {
+                find_used_exports_with_stack(data, importer_path, is_entry_point, stack)
+            });
+            match importer_used_exports {
+                UsedExports::All => return UsedExports::All,
+                UsedExports::Names(importer_names) => names.extend(
+                    importer_names
+                        .iter()
+                        .filter(|name| !module.exports.contains_key(*name))
+                        .cloned(),
+                ),
+            }
+        }
+    }
+
+    UsedExports::Names(names)
+}
+
+/// Inserts the name of the imported `symbol` into `names`.
+///
+/// Returns `false` if the import uses all the symbols of the module instead.
+fn insert_imported_symbol(names: &mut BTreeSet<Text>, symbol: &ImportSymbol) -> bool {
+    match symbol {
+        ImportSymbol::All => return false,
+        ImportSymbol::Default => names.insert("default".into()),
+        ImportSymbol::Named(name) => names.insert(name.clone()),
+    };
+
+    true
 }
 
 fn find_exported_symbol_with_seen_paths<'a>(
@@ -166,31 +483,46 @@ fn find_exported_symbol_with_seen_paths<'a>(
     module: &'a JsModuleInfo,
     symbol_name: &str,
     seen_paths: &mut BTreeSet<&'a Utf8Path>,
-) -> Option<(&'a JsModuleInfo, &'a JsOwnExport)> {
+) -> Option<(Option<&'a Utf8Path>, &'a JsModuleInfo, &'a JsOwnExport)> {
     match module.exports.get(symbol_name) {
         Some(JsExport::Own(own_export) | JsExport::OwnType(own_export)) => {
-            Some((module, own_export))
+            Some((None, module, own_export))
         }
         Some(JsExport::Reexport(reexport) | JsExport::ReexportType(reexport)) => {
-            if reexport.import.symbol == ImportSymbol::All {
+            // The symbol may be re-exported under another name.
+            let imported_name = match &reexport.import.symbol {
                 // TODO: Follow namespace exports.
-                None
-            } else {
-                match reexport.import.resolved_path.as_deref() {
-                    Ok(path) if seen_paths.insert(path) => data.get(path).and_then(|module| {
-                        find_exported_symbol_with_seen_paths(data, module, symbol_name, seen_paths)
-                    }),
-                    _ => None,
-                }
-            }
+                ImportSymbol::All => return None,
+                ImportSymbol::Default => "default",
+                ImportSymbol::Named(name) => name.text(),
+            };
+            find_reexported_symbol_with_seen_paths(data, reexport, imported_name, seen_paths)
         }
         None => module.blanket_reexports.iter().find_map(|reexport| {
-            match reexport.import.resolved_path.as_deref() {
-                Ok(path) if seen_paths.insert(path) => data.get(path).and_then(|module| {
-                    find_exported_symbol_with_seen_paths(data, module, symbol_name, seen_paths)
-                }),
-                _ => None,
-            }
+            find_reexported_symbol_with_seen_paths(data, reexport, symbol_name, seen_paths)
         }),
     }
 }
+
+/// Follows the given `reexport` to find the symbol by `symbol_name`.
+///
+/// Returns the path of the module that exports the symbol as its own, unless
+/// it was already seen.
+fn find_reexported_symbol_with_seen_paths<'a>(
+    data: &'a HashMapRef<Utf8PathBuf, JsModuleInfo, FxBuildHasher, LocalGuard>,
+    reexport: &'a JsReexport,
+    symbol_name: &str,
+    seen_paths: &mut BTreeSet<&'a Utf8Path>,
+) -> Option<(Option<&'a Utf8Path>, &'a JsModuleInfo, &'a JsOwnExport)> {
+    match reexport.import.resolved_path.as_deref() {
+        Ok(path) if seen_paths.insert(path) => data
+            .get(path)
+            .and_then(|module| {
+                find_exported_symbol_with_seen_paths(data, module, symbol_name, seen_paths)
+            })
+            .map(|(declaring_path, module, export)| {
+                (declaring_path.or(Some(path)), module, export)
+            }),
+        _ => None,
+    }
+}
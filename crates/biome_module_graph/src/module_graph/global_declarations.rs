@@ -0,0 +1,274 @@
+//! Loading of global declarations, such as those from TypeScript's
+//! `lib.*.d.ts` files.
+//!
+//! Which files are loaded is determined by the `lib` and `target` options from
+//! the `tsconfig.json` that applies to a module. The files are looked up in the
+//! `typescript` package from `node_modules`. If TypeScript is not installed, we
+//! fall back to a bundled subset of the declarations.
+//!
+//! The declarations loaded from TypeScript take precedence over the globals
+//! from `biome_js_type_info::globals`. The bundled subset leaves out `Array`,
+//! `Promise` and a few utility types, so the hardcoded globals remain the
+//! fallback for them when TypeScript isn't installed.
+
+use std::{fmt::Debug, sync::Arc};
+
+use biome_js_parser::{JsParserOptions, parse};
+use biome_js_syntax::JsFileSource;
+use biome_package::CompilerOptions;
+use biome_resolver::{FsWithResolverProxy, PathInfo};
+use biome_string_case::StrLikeExtension;
+use camino::{Utf8Path, Utf8PathBuf};
+use rustc_hash::FxHashSet;
+
+use crate::{JsModuleInfo, js_module_info::JsModuleVisitor};
+
+use super::{ModuleGraph, ModuleGraphFsProxy};
+
+/// Bundled ECMAScript declarations for projects without TypeScript installed.
+const BUNDLED_ES_DECLARATIONS: &str = include_str!("global_declarations/lib.es.d.ts");
+
+/// Bundled DOM declarations for projects without TypeScript installed.
+const BUNDLED_DOM_DECLARATIONS: &str = include_str!("global_declarations/lib.dom.d.ts");
+
+/// Directory under which the bundled declarations are registered.
+///
+/// This isn't a real directory, but it is used to identify the bundled
+/// declarations in the module graph.
+const BUNDLED_DIRECTORY: &str = "<bundled>";
+
+/// Module info for a declaration file, together with the names of the
+/// libraries it references.
+pub(super) type GlobalDeclarationsFile = (JsModuleInfo, Arc<[String]>);
+
+/// Global declarations that are in scope for a module.
+///
+/// Every declaration file is represented by the [`JsModuleInfo`] that was
+/// collected for it. Lookups are expected to go through the files in order,
+/// where the first declaration that is found wins.
+#[derive(Clone, Default)]
+pub struct GlobalDeclarations(Arc<[(Utf8PathBuf, JsModuleInfo)]>);
+
+impl GlobalDeclarations {
+    /// Returns an iterator over the module info of all the declaration files.
+    pub fn modules(&self) -> impl Iterator<Item = &JsModuleInfo> {
+        self.0.iter().map(|(_, module_info)| module_info)
+    }
+
+    /// Returns an iterator over the paths of all the declaration files.
+    pub fn paths(&self) -> impl Iterator<Item = &Utf8Path> {
+        self.0.iter().map(|(path, _)| path.as_path())
+    }
+
+    /// Returns whether the declarations were loaded from an installed
+    /// TypeScript, rather than bundled.
+    pub fn is_from_typescript(&self) -> bool {
+        self.paths()
+            .next()
+            .is_some_and(|path| !path.starts_with(BUNDLED_DIRECTORY))
+    }
+}
+
+impl Debug for GlobalDeclarations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.paths()).finish()
+    }
+}
+
+impl ModuleGraph {
+    /// Returns the global declarations that apply to modules from the package
+    /// in the given `package_path`, configured by the given `compiler_options`.
+    ///
+    /// Declaration files are cached in the module graph, so they only get
+    /// parsed once.
+    pub(super) fn global_declarations_for_package(
+        &self,
+        fs_proxy: &ModuleGraphFsProxy,
+        fs: &dyn FsWithResolverProxy,
+        package_path: Option<&Utf8Path>,
+        compiler_options: Option<&CompilerOptions>,
+    ) -> GlobalDeclarations {
+        let lib_directory = package_path.and_then(|path| find_typescript_lib_directory(fs, path));
+        let Some(lib_directory) = lib_directory else {
+            return self.bundled_global_declarations(fs_proxy, includes_dom(compiler_options));
+        };
+
+        let mut declarations = Vec::new();
+        let mut seen_paths = FxHashSet::default();
+        let mut queue: Vec<Utf8PathBuf> = lib_file_names(compiler_options)
+            .into_iter()
+            .rev()
+            .map(|name| lib_directory.join(name))
+            .collect();
+        while let Some(path) = queue.pop() {
+            if !seen_paths.insert(path.clone()) {
+                continue;
+            }
+
+            let Some((module_info, referenced_libs)) =
+                self.global_declarations_file(fs_proxy, fs, &path)
+            else {
+                continue;
+            };
+
+            // References are visited before any remaining files, in the order
+            // in which they appear.
+            queue.extend(
+                referenced_libs
+                    .iter()
+                    .rev()
+                    .map(|lib| lib_directory.join(lib_file_name(lib))),
+            );
+            declarations.push((path, module_info));
+        }
+
+        GlobalDeclarations(declarations.into())
+    }
+
+    /// Returns the module info for the declaration file at the given `path`,
+    /// together with the names of the libraries it references.
+    fn global_declarations_file(
+        &self,
+        fs_proxy: &ModuleGraphFsProxy,
+        fs: &dyn FsWithResolverProxy,
+        path: &Utf8Path,
+    ) -> Option<GlobalDeclarationsFile> {
+        self.global_declaration_files
+            .pin()
+            .get_or_insert_with(path.to_path_buf(), || {
+                let content = fs.read_file_from_path(path).ok()?;
+                let directory = path.parent().unwrap_or(path);
+                Some(collect_declarations(fs_proxy, directory, &content))
+            })
+            .clone()
+    }
+
+    /// Returns the bundled global declarations, optionally including those for
+    /// the DOM.
+    fn bundled_global_declarations(
+        &self,
+        fs_proxy: &ModuleGraphFsProxy,
+        includes_dom: bool,
+    ) -> GlobalDeclarations {
+        let mut bundled_files = vec![("lib.es.d.ts", BUNDLED_ES_DECLARATIONS)];
+        if includes_dom {
+            bundled_files.push(("lib.dom.d.ts", BUNDLED_DOM_DECLARATIONS));
+        }
+
+        let declarations: Vec<_> = bundled_files
+            .into_iter()
+            .filter_map(|(name, content)| {
+                let path = Utf8Path::new(BUNDLED_DIRECTORY).join(name);
+                let (module_info, _) = self
+                    .global_declaration_files
+                    .pin()
+                    .get_or_insert_with(path.clone(), || {
+                        Some(collect_declarations(
+                            fs_proxy,
+                            Utf8Path::new(BUNDLED_DIRECTORY),
+                            content,
+                        ))
+                    })
+                    .clone()?;
+                Some((path, module_info))
+            })
+            .collect();
+
+        GlobalDeclarations(declarations.into())
+    }
+}
+
+/// Parses the given declaration file `content` and collects its module info,
+/// together with the names of the libraries it references through
+/// `/// <reference lib="..." />` directives.
+fn collect_declarations(
+    fs_proxy: &ModuleGraphFsProxy,
+    directory: &Utf8Path,
+    content: &str,
+) -> GlobalDeclarationsFile {
+    let parsed = parse(content, JsFileSource::d_ts(), JsParserOptions::default());
+    let module_info = JsModuleVisitor::new(parsed.tree(), directory, fs_proxy).collect_info();
+
+    let referenced_libs = content
+        .lines()
+        .map(str::trim_start)
+        .filter(|line| line.starts_with("///"))
+        .filter_map(|line| {
+            let (_, rest) = line.split_once("<reference lib=\"")?;
+            let (lib, _) = rest.split_once('"')?;
+            Some(lib.to_string())
+        })
+        .collect();
+
+    (module_info, referenced_libs)
+}
+
+/// Looks for the `lib` directory of the `typescript` package, starting at the
+/// given `package_path` and going up in the hierarchy.
+fn find_typescript_lib_directory(
+    fs: &dyn FsWithResolverProxy,
+    package_path: &Utf8Path,
+) -> Option<Utf8PathBuf> {
+    package_path.ancestors().find_map(|directory| {
+        let lib_directory = directory.join("node_modules/typescript/lib");
+        match fs.path_info(&lib_directory) {
+            Ok(PathInfo::Directory) => Some(lib_directory),
+            Ok(PathInfo::Symlink {
+                canonicalized_target,
+            }) => Some(canonicalized_target),
+            _ => None,
+        }
+    })
+}
+
+/// Returns the names of the declaration files to load for the given
+/// `compiler_options`.
+///
+/// If no `lib` is specified, this returns the default library for the
+/// configured `target`, just like TypeScript does. If no `target` is specified
+/// either, we default to the latest ECMAScript version.
+fn lib_file_names(compiler_options: Option<&CompilerOptions>) -> Vec<String> {
+    if let Some(lib) = compiler_options.and_then(|options| options.lib.as_ref()) {
+        return lib.iter().map(|lib| lib_file_name(lib)).collect();
+    }
+
+    let target = compiler_options
+        .and_then(|options| options.target.as_deref())
+        .map(|target| target.to_ascii_lowercase_cow());
+    let file_name = match target.as_deref() {
+        Some("es3" | "es5") => "lib.d.ts".to_string(),
+        Some("es6" | "es2015") => "lib.es6.d.ts".to_string(),
+        Some(target) => format!("lib.{target}.full.d.ts"),
+        None => "lib.esnext.full.d.ts".to_string(),
+    };
+
+    vec![file_name]
+}
+
+/// Returns the file name for the library with the given `name`, as it would be
+/// specified in the `lib` option or in a `/// <reference lib="..." />`
+/// directive.
+fn lib_file_name(name: &str) -> String {
+    let name = name.to_ascii_lowercase_cow();
+    let name = match name.as_ref() {
+        "es6" => "es2015",
+        "es7" => "es2016",
+        name => name,
+    };
+
+    format!("lib.{name}.d.ts")
+}
+
+/// Returns whether the DOM declarations should be included for the given
+/// `compiler_options`.
+///
+/// The default libraries for all targets include the DOM declarations, so
+/// they're only excluded if a `lib` is specified without them.
+fn includes_dom(compiler_options: Option<&CompilerOptions>) -> bool {
+    compiler_options
+        .and_then(|options| options.lib.as_ref())
+        .is_none_or(|lib| {
+            lib.iter()
+                .any(|lib| lib.to_ascii_lowercase_cow().starts_with("dom"))
+        })
+}
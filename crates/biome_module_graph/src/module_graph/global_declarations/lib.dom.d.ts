@@ -0,0 +1,260 @@
+/**
+ * Bundled subset of the DOM declarations from TypeScript's `lib.dom.d.ts`.
+ *
+ * These are used for projects that don't have TypeScript installed.
+ */
+
+interface EventListener {
+    (evt: Event): void;
+}
+
+interface AddEventListenerOptions {
+    capture?: boolean;
+    once?: boolean;
+    passive?: boolean;
+    signal?: AbortSignal;
+}
+
+interface Event {
+    readonly bubbles: boolean;
+    readonly cancelable: boolean;
+    readonly defaultPrevented: boolean;
+    readonly target: EventTarget | null;
+    readonly currentTarget: EventTarget | null;
+    readonly timeStamp: number;
+    readonly type: string;
+    preventDefault(): void;
+    stopImmediatePropagation(): void;
+    stopPropagation(): void;
+}
+
+interface EventConstructor {
+    new (type: string): Event;
+    readonly prototype: Event;
+}
+
+declare var Event: EventConstructor;
+
+interface EventTarget {
+    addEventListener(type: string, callback: EventListener | null, options?: AddEventListenerOptions | boolean): void;
+    dispatchEvent(event: Event): boolean;
+    removeEventListener(type: string, callback: EventListener | null, options?: boolean): void;
+}
+
+interface AbortSignal extends EventTarget {
+    readonly aborted: boolean;
+    readonly reason: any;
+    throwIfAborted(): void;
+}
+
+interface AbortController {
+    readonly signal: AbortSignal;
+    abort(reason?: any): void;
+}
+
+interface AbortControllerConstructor {
+    new (): AbortController;
+    readonly prototype: AbortController;
+}
+
+declare var AbortController: AbortControllerConstructor;
+
+interface Node extends EventTarget {
+    readonly childNodes: NodeList;
+    readonly firstChild: Node | null;
+    readonly lastChild: Node | null;
+    readonly nextSibling: Node | null;
+    readonly nodeName: string;
+    readonly nodeType: number;
+    readonly parentElement: Element | null;
+    readonly parentNode: Node | null;
+    readonly previousSibling: Node | null;
+    textContent: string | null;
+    appendChild(node: Node): Node;
+    contains(other: Node | null): boolean;
+    removeChild(child: Node): Node;
+}
+
+interface NodeList {
+    readonly length: number;
+    item(index: number): Node | null;
+}
+
+interface DOMTokenList {
+    readonly length: number;
+    add(...tokens: string[]): void;
+    contains(token: string): boolean;
+    remove(...tokens: string[]): void;
+    toggle(token: string, force?: boolean): boolean;
+}
+
+interface Element extends Node {
+    readonly classList: DOMTokenList;
+    className: string;
+    id: string;
+    innerHTML: string;
+    readonly tagName: string;
+    closest(selectors: string): Element | null;
+    getAttribute(qualifiedName: string): string | null;
+    hasAttribute(qualifiedName: string): boolean;
+    querySelector(selectors: string): Element | null;
+    remove(): void;
+    removeAttribute(qualifiedName: string): void;
+    setAttribute(qualifiedName: string, value: string): void;
+}
+
+interface HTMLElement extends Element {
+    hidden: boolean;
+    innerText: string;
+    title: string;
+    blur(): void;
+    click(): void;
+    focus(): void;
+}
+
+interface Document extends Node {
+    readonly body: HTMLElement;
+    readonly head: HTMLElement;
+    title: string;
+    createElement(tagName: string): HTMLElement;
+    createTextNode(data: string): Node;
+    getElementById(elementId: string): HTMLElement | null;
+    querySelector(selectors: string): Element | null;
+}
+
+declare var document: Document;
+
+interface Storage {
+    readonly length: number;
+    clear(): void;
+    getItem(key: string): string | null;
+    key(index: number): string | null;
+    removeItem(key: string): void;
+    setItem(key: string, value: string): void;
+}
+
+declare var localStorage: Storage;
+declare var sessionStorage: Storage;
+
+interface URLSearchParams {
+    append(name: string, value: string): void;
+    delete(name: string): void;
+    get(name: string): string | null;
+    has(name: string): boolean;
+    set(name: string, value: string): void;
+    toString(): string;
+}
+
+interface URLSearchParamsConstructor {
+    new (init?: string | Record<string, string>): URLSearchParams;
+    readonly prototype: URLSearchParams;
+}
+
+declare var URLSearchParams: URLSearchParamsConstructor;
+
+interface URL {
+    hash: string;
+    host: string;
+    hostname: string;
+    href: string;
+    readonly origin: string;
+    pathname: string;
+    port: string;
+    protocol: string;
+    search: string;
+    readonly searchParams: URLSearchParams;
+    toJSON(): string;
+    toString(): string;
+}
+
+interface URLConstructor {
+    new (url: string | URL, base?: string | URL): URL;
+    readonly prototype: URL;
+    canParse(url: string | URL, base?: string | URL): boolean;
+}
+
+declare var URL: URLConstructor;
+
+interface Headers {
+    append(name: string, value: string): void;
+    delete(name: string): void;
+    get(name: string): string | null;
+    has(name: string): boolean;
+    set(name: string, value: string): void;
+}
+
+interface HeadersConstructor {
+    new (init?: Record<string, string>): Headers;
+    readonly prototype: Headers;
+}
+
+declare var Headers: HeadersConstructor;
+
+interface RequestInit {
+    body?: string | null;
+    headers?: Headers | Record<string, string>;
+    method?: string;
+    signal?: AbortSignal | null;
+}
+
+interface Request {
+    readonly headers: Headers;
+    readonly method: string;
+    readonly signal: AbortSignal;
+    readonly url: string;
+}
+
+interface RequestConstructor {
+    new (input: Request | string | URL, init?: RequestInit): Request;
+    readonly prototype: Request;
+}
+
+declare var Request: RequestConstructor;
+
+interface Response {
+    readonly headers: Headers;
+    readonly ok: boolean;
+    readonly redirected: boolean;
+    readonly status: number;
+    readonly statusText: string;
+    readonly url: string;
+    arrayBuffer(): Promise<ArrayBuffer>;
+    json(): Promise<any>;
+    text(): Promise<string>;
+}
+
+interface ResponseConstructor {
+    new (body?: string | null): Response;
+    readonly prototype: Response;
+    error(): Response;
+    json(data: any): Response;
+    redirect(url: string | URL, status?: number): Response;
+}
+
+declare var Response: ResponseConstructor;
+
+declare function fetch(input: Request | string | URL, init?: RequestInit): Promise<Response>;
+
+interface Console {
+    assert(condition?: boolean, ...data: any[]): void;
+    debug(...data: any[]): void;
+    error(...data: any[]): void;
+    info(...data: any[]): void;
+    log(...data: any[]): void;
+    table(tabularData?: any, properties?: string[]): void;
+    time(label?: string): void;
+    timeEnd(label?: string): void;
+    trace(...data: any[]): void;
+    warn(...data: any[]): void;
+}
+
+declare var console: Console;
+
+declare function queueMicrotask(callback: () => void): void;
+declare function setTimeout(handler: (...args: any[]) => void, timeout?: number, ...args: any[]): number;
+declare function clearTimeout(id: number | undefined): void;
+declare function setInterval(handler: (...args: any[]) => void, timeout?: number, ...args: any[]): number;
+declare function clearInterval(id: number | undefined): void;
+declare function structuredClone<T = any>(value: T): T;
+declare function requestAnimationFrame(callback: (time: number) => void): number;
+declare function cancelAnimationFrame(handle: number): void;
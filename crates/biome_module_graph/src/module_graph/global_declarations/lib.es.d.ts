@@ -0,0 +1,269 @@
+/**
+ * Bundled subset of the ECMAScript declarations from TypeScript's `lib.*.d.ts`
+ * files.
+ *
+ * These are used for projects that don't have TypeScript installed. `Array`,
+ * `Promise` and a handful of utility types are intentionally missing, because
+ * Biome has built-in definitions for them.
+ */
+
+interface PropertyDescriptor {
+    configurable?: boolean;
+    enumerable?: boolean;
+    value?: any;
+    writable?: boolean;
+    get?(): any;
+    set?(v: any): void;
+}
+
+interface PromiseLike<T> {
+    then<TResult1 = T, TResult2 = never>(
+        onfulfilled?: ((value: T) => TResult1 | PromiseLike<TResult1>) | undefined | null,
+        onrejected?: ((reason: any) => TResult2 | PromiseLike<TResult2>) | undefined | null,
+    ): PromiseLike<TResult1 | TResult2>;
+}
+
+interface IteratorResult<T> {
+    done?: boolean;
+    value: T;
+}
+
+interface Iterator<T> {
+    next(): IteratorResult<T>;
+}
+
+interface IterableIterator<T> extends Iterator<T> {}
+
+interface Error {
+    name: string;
+    message: string;
+    stack?: string;
+    cause?: unknown;
+}
+
+interface ErrorConstructor {
+    new (message?: string): Error;
+    (message?: string): Error;
+    readonly prototype: Error;
+}
+
+declare var Error: ErrorConstructor;
+
+interface TypeError extends Error {}
+
+interface TypeErrorConstructor {
+    new (message?: string): TypeError;
+    (message?: string): TypeError;
+    readonly prototype: TypeError;
+}
+
+declare var TypeError: TypeErrorConstructor;
+
+interface RangeError extends Error {}
+
+interface RangeErrorConstructor {
+    new (message?: string): RangeError;
+    (message?: string): RangeError;
+    readonly prototype: RangeError;
+}
+
+declare var RangeError: RangeErrorConstructor;
+
+interface SyntaxError extends Error {}
+
+interface SyntaxErrorConstructor {
+    new (message?: string): SyntaxError;
+    (message?: string): SyntaxError;
+    readonly prototype: SyntaxError;
+}
+
+declare var SyntaxError: SyntaxErrorConstructor;
+
+interface Date {
+    getDate(): number;
+    getDay(): number;
+    getFullYear(): number;
+    getHours(): number;
+    getMilliseconds(): number;
+    getMinutes(): number;
+    getMonth(): number;
+    getSeconds(): number;
+    getTime(): number;
+    getTimezoneOffset(): number;
+    toDateString(): string;
+    toISOString(): string;
+    toJSON(key?: any): string;
+    toLocaleDateString(): string;
+    toLocaleString(): string;
+    toLocaleTimeString(): string;
+    toString(): string;
+    toTimeString(): string;
+    toUTCString(): string;
+    valueOf(): number;
+}
+
+interface DateConstructor {
+    new (): Date;
+    new (value: number | string | Date): Date;
+    (): string;
+    readonly prototype: Date;
+    now(): number;
+    parse(s: string): number;
+    UTC(year: number, monthIndex?: number, date?: number, hours?: number, minutes?: number, seconds?: number, ms?: number): number;
+}
+
+declare var Date: DateConstructor;
+
+interface RegExpMatchArray {
+    index?: number;
+    input?: string;
+    length: number;
+}
+
+interface RegExpExecArray {
+    index: number;
+    input: string;
+    length: number;
+}
+
+interface RegExp {
+    readonly flags: string;
+    readonly global: boolean;
+    readonly ignoreCase: boolean;
+    readonly multiline: boolean;
+    readonly source: string;
+    readonly sticky: boolean;
+    readonly unicode: boolean;
+    lastIndex: number;
+    exec(string: string): RegExpExecArray | null;
+    test(string: string): boolean;
+}
+
+interface RegExpConstructor {
+    new (pattern: RegExp | string, flags?: string): RegExp;
+    (pattern: RegExp | string, flags?: string): RegExp;
+    readonly prototype: RegExp;
+}
+
+declare var RegExp: RegExpConstructor;
+
+interface JSON {
+    parse(text: string, reviver?: (this: any, key: string, value: any) => any): any;
+    stringify(value: any, replacer?: (this: any, key: string, value: any) => any, space?: string | number): string;
+}
+
+declare var JSON: JSON;
+
+interface Math {
+    readonly E: number;
+    readonly PI: number;
+    abs(x: number): number;
+    ceil(x: number): number;
+    floor(x: number): number;
+    max(...values: number[]): number;
+    min(...values: number[]): number;
+    pow(x: number, y: number): number;
+    random(): number;
+    round(x: number): number;
+    sign(x: number): number;
+    sqrt(x: number): number;
+    trunc(x: number): number;
+}
+
+declare var Math: Math;
+
+interface Map<K, V> {
+    readonly size: number;
+    clear(): void;
+    delete(key: K): boolean;
+    entries(): IterableIterator<[K, V]>;
+    forEach(callbackfn: (value: V, key: K, map: Map<K, V>) => void, thisArg?: any): void;
+    get(key: K): V | undefined;
+    has(key: K): boolean;
+    keys(): IterableIterator<K>;
+    set(key: K, value: V): this;
+    values(): IterableIterator<V>;
+}
+
+interface MapConstructor {
+    new <K = any, V = any>(entries?: readonly (readonly [K, V])[] | null): Map<K, V>;
+    readonly prototype: Map<any, any>;
+}
+
+declare var Map: MapConstructor;
+
+interface ReadonlyMap<K, V> {
+    readonly size: number;
+    entries(): IterableIterator<[K, V]>;
+    forEach(callbackfn: (value: V, key: K, map: ReadonlyMap<K, V>) => void, thisArg?: any): void;
+    get(key: K): V | undefined;
+    has(key: K): boolean;
+    keys(): IterableIterator<K>;
+    values(): IterableIterator<V>;
+}
+
+interface WeakMap<K extends object, V> {
+    delete(key: K): boolean;
+    get(key: K): V | undefined;
+    has(key: K): boolean;
+    set(key: K, value: V): this;
+}
+
+interface WeakMapConstructor {
+    new <K extends object = object, V = any>(entries?: readonly (readonly [K, V])[] | null): WeakMap<K, V>;
+    readonly prototype: WeakMap<object, any>;
+}
+
+declare var WeakMap: WeakMapConstructor;
+
+interface Set<T> {
+    readonly size: number;
+    add(value: T): this;
+    clear(): void;
+    delete(value: T): boolean;
+    entries(): IterableIterator<[T, T]>;
+    forEach(callbackfn: (value: T, value2: T, set: Set<T>) => void, thisArg?: any): void;
+    has(value: T): boolean;
+    keys(): IterableIterator<T>;
+    values(): IterableIterator<T>;
+}
+
+interface SetConstructor {
+    new <T = any>(values?: readonly T[] | null): Set<T>;
+    readonly prototype: Set<any>;
+}
+
+declare var Set: SetConstructor;
+
+interface ReadonlySet<T> {
+    readonly size: number;
+    entries(): IterableIterator<[T, T]>;
+    forEach(callbackfn: (value: T, value2: T, set: ReadonlySet<T>) => void, thisArg?: any): void;
+    has(value: T): boolean;
+    keys(): IterableIterator<T>;
+    values(): IterableIterator<T>;
+}
+
+interface WeakSet<T extends object> {
+    add(value: T): this;
+    delete(value: T): boolean;
+    has(value: T): boolean;
+}
+
+interface WeakSetConstructor {
+    new <T extends object = object>(values?: readonly T[] | null): WeakSet<T>;
+    readonly prototype: WeakSet<object>;
+}
+
+declare var WeakSet: WeakSetConstructor;
+
+interface WeakRef<T extends object> {
+    deref(): T | undefined;
+}
+
+interface WeakRefConstructor {
+    new <T extends object>(target: T): WeakRef<T>;
+    readonly prototype: WeakRef<any>;
+}
+
+declare var WeakRef: WeakRefConstructor;
@@ -18,7 +18,7 @@ use biome_module_graph::{
 };
 use biome_package::{Dependencies, PackageJson};
 use biome_project_layout::ProjectLayout;
-use biome_rowan::Text;
+use biome_rowan::{Text, TextRange};
 use biome_test_utils::get_added_paths;
 use camino::{Utf8Path, Utf8PathBuf};
 
@@ -1308,3 +1308,366 @@ fn test_resolve_promise_from_imported_function_returning_reexported_promise_type
         "test_resolve_promise_from_imported_function_returning_reexported_promise_type",
     );
 }
+
+#[test]
+fn test_resolve_conditional_mapped_and_indexed_access_types() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        "/src/index.ts".into(),
+        r#"type Unwrap<T> = T extends Promise<infer U> ? U : T;
+
+interface Props {
+    name: string;
+    age: number;
+}
+
+function returnsPromise(): Promise<number> {
+    return Promise.resolve(1);
+}
+
+export type Name = Props["name"];
+export type Keys = keyof Props;
+export type OptionalProps = Partial<Props>;
+export type Getters = { [K in keyof Props]: () => Props[K] };
+export type Unwrapped = Unwrap<Promise<string>>;
+export type NotUnwrapped = Unwrap<number>;
+export type Returned = ReturnType<typeof returnsPromise>;
+export type AwaitedNumber = Awaited<ReturnType<typeof returnsPromise>>;
+"#,
+    );
+    fs.insert(
+        "/src/consumer.ts".into(),
+        r#"export type ImportedName = import("./index.ts").Name;
+"#,
+    );
+
+    let added_paths = [
+        BiomePath::new("/src/index.ts"),
+        BiomePath::new("/src/consumer.ts"),
+    ];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = Arc::new(ModuleGraph::default());
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths, &[]);
+
+    let index_module = module_graph
+        .module_info_for_path(Utf8Path::new("/src/index.ts"))
+        .expect("module must exist");
+    let mut resolver = ScopedResolver::from_global_scope(index_module, module_graph.clone());
+    resolver.run_inference();
+    let resolver = Arc::new(resolver);
+
+    let type_of = |name: &'static str| {
+        let resolved_id = resolver
+            .resolve_type_of(&Text::Static(name), ScopeId::GLOBAL)
+            .expect("type alias not found");
+        Type::from_id(resolver.clone(), resolved_id)
+    };
+    assert!(type_of("Name").is_string());
+    assert!(type_of("Unwrapped").is_string());
+    assert!(type_of("Returned").is_promise_instance());
+
+    let consumer_module = module_graph
+        .module_info_for_path(Utf8Path::new("/src/consumer.ts"))
+        .expect("module must exist");
+    let mut resolver = ScopedResolver::from_global_scope(consumer_module, module_graph.clone());
+    resolver.run_inference();
+    let resolver = Arc::new(resolver);
+
+    let resolved_id = resolver
+        .resolve_type_of(&Text::Static("ImportedName"), ScopeId::GLOBAL)
+        .expect("type alias not found");
+    let ty = Type::from_id(resolver.clone(), resolved_id);
+    let TypeData::InstanceOf(instance) = &*ty else {
+        panic!("import type must resolve to an instance");
+    };
+    assert!(ty.resolve(&instance.ty).is_some_and(|ty| ty.is_string()));
+
+    let snapshot = ModuleGraphSnapshot::new(module_graph.as_ref(), &fs);
+    snapshot.assert_snapshot("test_resolve_conditional_mapped_and_indexed_access_types");
+}
+
+#[test]
+fn test_resolve_bundled_global_declarations() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        "/src/index.ts".into(),
+        r#"export const map = new Map<string, number>();
+export const set = new Set([1, 2, 3]);
+export const response = fetch("https://biomejs.dev");
+export const body = document.body;
+"#,
+    );
+
+    let added_paths = [BiomePath::new("/src/index.ts")];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let project_layout = ProjectLayout::default();
+    project_layout.insert_node_manifest("/".into(), PackageJson::new("frontend"));
+
+    let module_graph = Arc::new(ModuleGraph::default());
+    module_graph.update_graph_for_js_paths(&fs, &project_layout, &added_paths, &[]);
+
+    let index_module = module_graph
+        .module_info_for_path(Utf8Path::new("/src/index.ts"))
+        .expect("module must exist");
+    assert_eq!(
+        index_module
+            .global_declarations
+            .paths()
+            .map(Utf8Path::as_str)
+            .collect::<Vec<_>>(),
+        ["<bundled>/lib.es.d.ts", "<bundled>/lib.dom.d.ts"]
+    );
+
+    let mut resolver = ScopedResolver::from_global_scope(index_module, module_graph.clone());
+    resolver.run_inference();
+    let resolver = Arc::new(resolver);
+
+    let type_of = |name: &'static str| {
+        let resolved_id = resolver
+            .resolve_type_of(&Text::Static(name), ScopeId::GLOBAL)
+            .expect("binding not found");
+        Type::from_id(resolver.clone(), resolved_id)
+    };
+    assert_eq!(instance_name(&type_of("map")).as_deref(), Some("Map"));
+    assert_eq!(instance_name(&type_of("set")).as_deref(), Some("Set"));
+    assert!(type_of("response").is_promise_instance());
+    assert_eq!(
+        instance_name(&type_of("body")).as_deref(),
+        Some("HTMLElement")
+    );
+}
+
+#[test]
+fn test_resolve_global_declarations_from_typescript() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        "/src/index.ts".into(),
+        r#"export const map = new Map<string, number>();
+"#,
+    );
+    fs.insert(
+        "/node_modules/typescript/lib/lib.es2020.d.ts".into(),
+        r#"/// <reference lib="es2015.collection" />
+/// <reference lib="es2015.iterable" />
+"#,
+    );
+    fs.insert(
+        "/node_modules/typescript/lib/lib.es2015.collection.d.ts".into(),
+        r#"interface Map<K, V> {
+    get(key: K): V | undefined;
+    set(key: K, value: V): this;
+}
+
+interface MapConstructor {
+    new <K, V>(): Map<K, V>;
+}
+
+declare var Map: MapConstructor;
+"#,
+    );
+    fs.insert(
+        "/node_modules/typescript/lib/lib.es2015.iterable.d.ts".into(),
+        r#"interface MapIterator<T> {
+    next(): T;
+}
+
+interface Map<K, V> {
+    entries(): MapIterator<[K, V]>;
+}
+"#,
+    );
+
+    let added_paths = [BiomePath::new("/src/index.ts")];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let project_layout = ProjectLayout::default();
+    project_layout.insert_node_manifest("/".into(), PackageJson::new("frontend"));
+
+    let tsconfig_json = parse_json(
+        r#"{ "compilerOptions": { "lib": ["ES2020"] } }"#,
+        JsonParserOptions::default(),
+    );
+    project_layout.insert_serialized_tsconfig("/".into(), tsconfig_json.into());
+
+    let module_graph = Arc::new(ModuleGraph::default());
+    module_graph.update_graph_for_js_paths(&fs, &project_layout, &added_paths, &[]);
+
+    let index_module = module_graph
+        .module_info_for_path(Utf8Path::new("/src/index.ts"))
+        .expect("module must exist");
+    assert_eq!(
+        index_module
+            .global_declarations
+            .paths()
+            .map(Utf8Path::as_str)
+            .collect::<Vec<_>>(),
+        [
+            "/node_modules/typescript/lib/lib.es2020.d.ts",
+            "/node_modules/typescript/lib/lib.es2015.collection.d.ts",
+            "/node_modules/typescript/lib/lib.es2015.iterable.d.ts",
+        ]
+    );
+
+    let mut resolver = ScopedResolver::from_global_scope(index_module, module_graph.clone());
+    resolver.run_inference();
+    let resolver = Arc::new(resolver);
+
+    let type_of = |name: &'static str| {
+        let resolved_id = resolver
+            .resolve_type_of(&Text::Static(name), ScopeId::GLOBAL)
+            .expect("binding not found");
+        Type::from_id(resolver.clone(), resolved_id)
+    };
+    assert_eq!(instance_name(&type_of("map")).as_deref(), Some("Map"));
+}
+
+#[test]
+fn test_global_declarations_from_typescript_take_precedence() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        "/src/index.ts".into(),
+        r#"declare const promiseOfNumber: Promise<number>;
+export const promise = promiseOfNumber.then();
+export const value = promiseOfNumber.fromTypeScript();
+"#,
+    );
+    fs.insert(
+        "/node_modules/typescript/lib/lib.esnext.full.d.ts".into(),
+        r#"interface Promise<T> {
+    then(): Promise<T>;
+    fromTypeScript(): string;
+}
+"#,
+    );
+
+    let added_paths = [BiomePath::new("/src/index.ts")];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let project_layout = ProjectLayout::default();
+    project_layout.insert_node_manifest("/".into(), PackageJson::new("frontend"));
+
+    let module_graph = Arc::new(ModuleGraph::default());
+    module_graph.update_graph_for_js_paths(&fs, &project_layout, &added_paths, &[]);
+
+    let index_module = module_graph
+        .module_info_for_path(Utf8Path::new("/src/index.ts"))
+        .expect("module must exist");
+
+    let mut resolver = ScopedResolver::from_global_scope(index_module, module_graph.clone());
+    resolver.run_inference();
+    let resolver = Arc::new(resolver);
+
+    let type_of = |name: &'static str| {
+        let resolved_id = resolver
+            .resolve_type_of(&Text::Static(name), ScopeId::GLOBAL)
+            .expect("binding not found");
+        Type::from_id(resolver.clone(), resolved_id)
+    };
+    let promise = type_of("promise");
+    assert_eq!(instance_name(&promise).as_deref(), Some("Promise"));
+    assert!(promise.is_promise_instance());
+    // Only the declaration from TypeScript has this member
+    assert!(matches!(&*type_of("value"), TypeData::String));
+}
+
+/// Returns the name of the interface or class the given `ty` is an instance
+/// of.
+fn instance_name(ty: &Type) -> Option<String> {
+    let TypeData::InstanceOf(instance) = &**ty else {
+        return None;
+    };
+    match &*ty.resolve(&instance.ty)? {
+        TypeData::Class(class) => class.name.as_ref().map(ToString::to_string),
+        TypeData::Interface(interface) => Some(interface.name.to_string()),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_find_exported_symbol_declaration_through_reexports() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert("/src/foo.ts".into(), "export function foo() {}\n");
+    fs.insert("/src/reexport.ts".into(), "export * from \"./foo.ts\";\n");
+    fs.insert(
+        "/src/renamed.ts".into(),
+        "export { foo as bar } from \"./foo.ts\";\n",
+    );
+    fs.insert(
+        "/src/index.ts".into(),
+        "import { foo } from \"./reexport.ts\";\n\nfoo();\n"
+            .as_bytes()
+            .to_vec(),
+    );
+    fs.insert("/src/unrelated.ts".into(), "import \"./index.ts\";\n");
+
+    let added_paths = [
+        BiomePath::new("/src/foo.ts"),
+        BiomePath::new("/src/reexport.ts"),
+        BiomePath::new("/src/renamed.ts"),
+        BiomePath::new("/src/index.ts"),
+        BiomePath::new("/src/unrelated.ts"),
+    ];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = ModuleGraph::default();
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths, &[]);
+
+    let (path, range) = module_graph
+        .find_exported_symbol_declaration(Utf8Path::new("/src/reexport.ts"), "foo")
+        .expect("declaration must be found");
+    assert_eq!(path, Utf8PathBuf::from("/src/foo.ts"));
+    assert_eq!(range, TextRange::new(16.into(), 19.into()));
+
+    let (path, range) = module_graph
+        .find_exported_symbol_declaration(Utf8Path::new("/src/renamed.ts"), "bar")
+        .expect("declaration must be found");
+    assert_eq!(path, Utf8PathBuf::from("/src/foo.ts"));
+    assert_eq!(range, TextRange::new(16.into(), 19.into()));
+
+    let importers = module_graph.find_importers(Utf8Path::new("/src/foo.ts"));
+    assert_eq!(
+        importers.into_iter().collect::<Vec<_>>(),
+        vec![
+            Utf8PathBuf::from("/src/index.ts"),
+            Utf8PathBuf::from("/src/reexport.ts"),
+            Utf8PathBuf::from("/src/renamed.ts")
+        ]
+    );
+}
+
+#[test]
+fn test_find_dependents() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert("/src/foo.ts".into(), "export function foo() {}\n");
+    fs.insert(
+        "/src/bar.ts".into(),
+        "import { foo } from \"./foo.ts\";\n\nexport const bar = foo;\n",
+    );
+    fs.insert("/src/main.ts".into(), "import { bar } from \"./bar.ts\";\n");
+    fs.insert("/src/lazy.ts".into(), "import(\"./main.ts\");\n");
+    fs.insert("/src/unrelated.ts".into(), "export const baz = 1;\n");
+
+    let added_paths = [
+        BiomePath::new("/src/foo.ts"),
+        BiomePath::new("/src/bar.ts"),
+        BiomePath::new("/src/main.ts"),
+        BiomePath::new("/src/lazy.ts"),
+        BiomePath::new("/src/unrelated.ts"),
+    ];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = ModuleGraph::default();
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths, &[]);
+
+    let dependents = module_graph.find_dependents(Utf8Path::new("/src/foo.ts"));
+    assert_eq!(
+        dependents.into_iter().collect::<Vec<_>>(),
+        vec![
+            Utf8PathBuf::from("/src/bar.ts"),
+            Utf8PathBuf::from("/src/lazy.ts"),
+            Utf8PathBuf::from("/src/main.ts")
+        ]
+    );
+}
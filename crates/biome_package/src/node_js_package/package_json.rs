@@ -113,6 +113,40 @@ impl PackageJson {
         }
         Some(value)
     }
+
+    /// Returns the paths of the entry points declared by the `main`, `bin`,
+    /// and `exports` fields, relative to the package.
+    ///
+    /// Conditions in the `exports` field aren't evaluated, so the paths for
+    /// all conditions are returned. The targets of subpath patterns are
+    /// returned as they are, with their `*`.
+    pub fn entry_points(&self) -> Vec<&str> {
+        fn collect_paths<'a>(value: &'a JsonValue, paths: &mut Vec<&'a str>) {
+            match value {
+                JsonValue::String(path) => paths.push(path.as_str()),
+                JsonValue::Object(object) => {
+                    for value in object.values() {
+                        collect_paths(value, paths);
+                    }
+                }
+                JsonValue::Array(array) => {
+                    for value in array.iter() {
+                        collect_paths(value, paths);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut paths = Vec::new();
+        for field in ["main", "bin", "exports"] {
+            if let Some(value) = self.raw_json.get(field) {
+                collect_paths(value, &mut paths);
+            }
+        }
+
+        paths
+    }
 }
 
 impl Manifest for PackageJson {
@@ -376,6 +410,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn package_json_entry_points() {
+        let deserialized = deserialize_from_json_str::<PackageJson>(
+            r#"{
+    "name": "@shared/utils",
+    "main": "./dist/index.cjs",
+    "bin": {
+        "utils": "./bin/utils.js"
+    },
+    "exports": {
+        ".": {
+            "import": "./dist/index.mjs",
+            "require": "./dist/index.cjs"
+        },
+        "./features/*": "./dist/features/*.js"
+    }
+}"#,
+            JsonParserOptions::default(),
+            "",
+        );
+        let (package_json, errors) = deserialized.consume();
+        assert!(errors.is_empty());
+
+        let package_json = package_json.expect("parsing must have succeeded");
+        assert_eq!(
+            package_json.entry_points(),
+            [
+                "./dist/index.cjs",
+                "./bin/utils.js",
+                "./dist/index.mjs",
+                "./dist/index.cjs",
+                "./dist/features/*.js"
+            ]
+        );
+    }
+
     #[test]
     fn should_not_panic_on_invalid_semver_range() {
         let result = parse_range("~0.x.0");
@@ -98,6 +98,17 @@ pub struct CompilerOptions {
     /// See: https://www.typescriptlang.org/tsconfig/#typeRoots
     #[deserializable(rename = "typeRoots")]
     pub type_roots: Option<Vec<String>>,
+
+    /// Names of the built-in declaration files to include.
+    ///
+    /// See: https://www.typescriptlang.org/tsconfig/#lib
+    pub lib: Option<Vec<String>>,
+
+    /// The ECMAScript version to target, which determines the built-in
+    /// declaration files to include if [`Self::lib`] is not specified.
+    ///
+    /// See: https://www.typescriptlang.org/tsconfig/#target
+    pub target: Option<String>,
 }
 
 pub type CompilerOptionsPathsMap = IndexMap<String, Vec<String>, BuildHasherDefault<FxHasher>>;
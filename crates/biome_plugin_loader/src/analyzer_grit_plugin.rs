@@ -1,17 +1,18 @@
-use biome_analyze::RuleDiagnostic;
+use biome_analyze::{FixKind, PluginAction, PluginSignal, PluginTargetLanguage, RuleDiagnostic};
 use biome_console::markup;
-use biome_diagnostics::{Severity, category};
+use biome_diagnostics::{Applicability, Severity, category};
 use biome_fs::FileSystem;
 use biome_grit_patterns::{
-    BuiltInFunction, CompilePatternOptions, GritBinding, GritExecContext, GritPattern, GritQuery,
-    GritQueryContext, GritQueryState, GritResolvedPattern, GritTargetFile,
-    compile_pattern_with_options,
+    BuiltInFunction, CompilePatternOptions, GritBinding, GritDiagnostic, GritExecContext,
+    GritPattern, GritQuery, GritQueryContext, GritQueryEffect, GritQueryState, GritResolvedPattern,
+    GritTargetFile, GritTargetLanguage, Rewrite, compile_pattern_with_options,
 };
 use biome_parser::AnyParse;
-use biome_rowan::TextRange;
+use biome_rowan::{TextRange, TextSize};
 use camino::{Utf8Path, Utf8PathBuf};
 use grit_pattern_matcher::{binding::Binding, pattern::ResolvedPattern};
 use grit_util::{AnalysisLogs, error::GritPatternError};
+use similar::{ChangeTag, TextDiff, utils::TextDiffRemapper};
 use std::{borrow::Cow, fmt::Debug, str::FromStr, sync::Arc};
 
 use crate::{AnalyzerPlugin, PluginDiagnostic};
@@ -29,7 +30,7 @@ impl AnalyzerGritPlugin {
             .with_extra_built_ins(vec![
                 BuiltInFunction::new(
                     "register_diagnostic",
-                    &["span", "message", "severity"],
+                    &["span", "message", "severity", "fix_kind"],
                     Box::new(register_diagnostic),
                 )
                 .as_predicate(),
@@ -42,13 +43,25 @@ impl AnalyzerGritPlugin {
 }
 
 impl AnalyzerPlugin for AnalyzerGritPlugin {
-    fn evaluate(&self, root: AnyParse, path: Arc<Utf8PathBuf>) -> Vec<RuleDiagnostic> {
+    fn evaluate(&self, root: AnyParse, path: Arc<Utf8PathBuf>) -> Vec<PluginSignal> {
         let name: &str = self.grit_query.name.as_deref().unwrap_or("anonymous");
 
-        let file = GritTargetFile { parse: root, path };
+        let file = GritTargetFile {
+            parse: root,
+            path: path.clone(),
+        };
         match self.grit_query.execute(file) {
             Ok(result) => {
-                let mut diagnostics: Vec<_> = result
+                let rewrite = result
+                    .effects
+                    .into_iter()
+                    .find_map(|effect| match effect {
+                        GritQueryEffect::Rewrite(rewrite) => Some(rewrite),
+                        _ => None,
+                    })
+                    .map(|rewrite| RewriteHunks::from_rewrite(&rewrite));
+
+                let mut signals: Vec<PluginSignal> = result
                     .logs
                     .iter()
                     .map(|log| {
@@ -58,42 +71,144 @@ impl AnalyzerPlugin for AnalyzerGritPlugin {
                         markup!(<Emphasis>{name}</Emphasis>" logged: "<Info>{log.message}</Info>),
                     )
                     .verbose()
+                    .into()
+                    })
+                    .chain(result.diagnostics.into_iter().map(
+                        |GritDiagnostic {
+                             diagnostic,
+                             fix_kind,
+                         }| {
+                            let action = rewrite.as_ref().and_then(|rewrite| {
+                                self.action_for(rewrite, &diagnostic, fix_kind)
+                            });
+                            PluginSignal { diagnostic, action }
+                        },
+                    ))
+                    .map(|mut signal| {
+                        signal.diagnostic = signal.diagnostic.subcategory(name.to_string());
+                        signal
                     })
-                    .chain(result.diagnostics)
-                    .map(|diagnostics| diagnostics.subcategory(name.to_string()))
                     .collect();
 
-                if diagnostics
+                if signals
                     .iter()
-                    .any(|diagnostic| diagnostic.span().is_none())
+                    .any(|signal| signal.diagnostic.span().is_none())
                 {
-                    diagnostics.push(RuleDiagnostic::new(
-                        category!("plugin"),
-                        None::<TextRange>,
-                        markup!(
-                            "Plugin "<Emphasis>{name}</Emphasis>" reported one or more diagnostics, "
-                            "but it didn't specify a valid "<Emphasis>"span"</Emphasis>". "
-                            "Diagnostics have been shown without context."
-                        ),
-                    ));
+                    signals.push(
+                        RuleDiagnostic::new(
+                            category!("plugin"),
+                            None::<TextRange>,
+                            markup!(
+                                "Plugin "<Emphasis>{name}</Emphasis>" reported one or more diagnostics, "
+                                "but it didn't specify a valid "<Emphasis>"span"</Emphasis>". "
+                                "Diagnostics have been shown without context."
+                            ),
+                        )
+                        .into(),
+                    );
                 }
 
-                diagnostics
+                signals
             }
-            Err(error) => vec![RuleDiagnostic::new(
-                category!("plugin"),
-                None::<TextRange>,
-                markup!(<Emphasis>{name}</Emphasis>" errored: "<Error>{error.to_string()}</Error>),
-            )],
+            Err(error) => vec![
+                RuleDiagnostic::new(
+                    category!("plugin"),
+                    None::<TextRange>,
+                    markup!(<Emphasis>{name}</Emphasis>" errored: "<Error>{error.to_string()}</Error>),
+                )
+                .into(),
+            ],
+        }
+    }
+
+    fn language(&self) -> PluginTargetLanguage {
+        match self.grit_query.language() {
+            GritTargetLanguage::CssTargetLanguage(_) => PluginTargetLanguage::Css,
+            GritTargetLanguage::GraphqlTargetLanguage(_) => PluginTargetLanguage::Graphql,
+            GritTargetLanguage::HtmlTargetLanguage(_) => PluginTargetLanguage::Html,
+            GritTargetLanguage::JsTargetLanguage(_) => PluginTargetLanguage::JavaScript,
+            GritTargetLanguage::JsonTargetLanguage(_) => PluginTargetLanguage::Json,
+        }
+    }
+}
+
+impl AnalyzerGritPlugin {
+    /// Creates the code action that applies the parts of the rewrite which
+    /// touch the span of the given diagnostic.
+    ///
+    /// Only these parts are applied, so that the fix of a diagnostic doesn't
+    /// rewrite code that was reported by other (possibly suppressed)
+    /// diagnostics.
+    fn action_for(
+        &self,
+        rewrite: &RewriteHunks,
+        diagnostic: &RuleDiagnostic,
+        fix_kind: FixKind,
+    ) -> Option<PluginAction> {
+        let name: &str = self.grit_query.name.as_deref().unwrap_or("anonymous");
+        let applicability = Applicability::try_from(fix_kind).ok()?;
+        let edits = rewrite.hunks_within(diagnostic.span()?);
+        if edits.is_empty() {
+            return None;
         }
+
+        Some(PluginAction {
+            applicability,
+            message: markup!("Apply the rewrite of "<Emphasis>{name}</Emphasis>".").to_owned(),
+            edits,
+        })
     }
+}
+
+/// The changes made by a rewrite, as a list of hunks that replace ranges of
+/// the original content.
+struct RewriteHunks {
+    hunks: Vec<(TextRange, String)>,
+}
 
-    fn supports_css(&self) -> bool {
-        self.grit_query.supports_css()
+impl RewriteHunks {
+    fn from_rewrite(rewrite: &Rewrite) -> Self {
+        let old = rewrite.original_content.as_str();
+        let new = rewrite.rewritten.content.as_str();
+        let diff = TextDiff::configure()
+            .newline_terminated(true)
+            .diff_unicode_words(old, new);
+        let remapper = TextDiffRemapper::from_text_diff(&diff, old, new);
+
+        let mut hunks: Vec<(TextRange, String)> = Vec::new();
+        let mut offset = TextSize::from(0);
+        let mut in_hunk = false;
+        for (tag, text) in diff.ops().iter().flat_map(|op| remapper.iter_slices(op)) {
+            if tag == ChangeTag::Equal {
+                offset += TextSize::of(text);
+                in_hunk = false;
+                continue;
+            }
+
+            if !in_hunk {
+                hunks.push((TextRange::empty(offset), String::new()));
+                in_hunk = true;
+            }
+
+            let (range, replacement) = hunks.last_mut().expect("a hunk was pushed");
+            if tag == ChangeTag::Delete {
+                offset += TextSize::of(text);
+                *range = TextRange::new(range.start(), offset);
+            } else {
+                replacement.push_str(text);
+            }
+        }
+
+        Self { hunks }
     }
 
-    fn supports_js(&self) -> bool {
-        self.grit_query.supports_js()
+    /// Returns the hunks that intersect the given span.
+    fn hunks_within(&self, span: TextRange) -> Vec<(TextRange, String)> {
+        self.hunks
+            .iter()
+            .filter(|(range, _)| span.intersect(*range).is_some())
+            .cloned()
+            .collect()
     }
 }
 
@@ -109,11 +224,11 @@ fn register_diagnostic<'a>(
 ) -> Result<GritResolvedPattern<'a>, GritPatternError> {
     let args = GritResolvedPattern::from_patterns(args, state, context, logs)?;
 
-    let (span_node, message, severity) = match args.as_slice() {
-        [Some(span), Some(message), severity] => (span, message, severity),
+    let (span_node, message, severity, fix_kind) = match args.as_slice() {
+        [Some(span), Some(message), severity, fix_kind] => (span, message, severity, fix_kind),
         _ => {
             return Err(GritPatternError::new(
-                "register_diagnostic() takes 2 required arguments: span and message, and an optional severity",
+                "register_diagnostic() takes 2 required arguments: span and message, and optional severity and fix_kind",
             ));
         }
     };
@@ -148,8 +263,18 @@ fn register_diagnostic<'a>(
         .and_then(|severity| Severity::from_str(severity.as_ref()).ok())
         .unwrap_or(Severity::Error);
 
+    let fix_kind = fix_kind
+        .as_ref()
+        .and_then(|fix_kind| fix_kind.text(&state.files, &context.lang).ok())
+        .map_or(FixKind::Unsafe, |fix_kind| match fix_kind.as_ref() {
+            "none" => FixKind::None,
+            "safe" => FixKind::Safe,
+            _ => FixKind::Unsafe,
+        });
+
     context.add_diagnostic(
         RuleDiagnostic::new(category!("plugin"), span, message).with_severity(severity),
+        fix_kind,
     );
 
     Ok(span_node.clone())
@@ -30,7 +30,7 @@ pub use mutation::{AstNodeExt, AstNodeListExt, AstSeparatedListExt};
 /// bitfield here being twice as large as it needs to cover all nodes as well
 /// as all token kinds
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct SyntaxKindSet<L: Language>([u128; 4], PhantomData<L>);
+pub struct SyntaxKindSet<L: Language>([u128; 8], PhantomData<L>);
 
 impl<L> SyntaxKindSet<L>
 where
@@ -49,7 +49,7 @@ where
     /// ```compile_fail
     /// # use biome_rowan::{SyntaxKindSet, RawSyntaxKind, raw_language::RawLanguage};
     /// const EXAMPLE: SyntaxKindSet<RawLanguage> =
-    ///     SyntaxKindSet::<RawLanguage>::from_raw(RawSyntaxKind(512));
+    ///     SyntaxKindSet::<RawLanguage>::from_raw(RawSyntaxKind(1024));
     /// # println!("{EXAMPLE:?}"); // The constant must be used to be evaluated
     /// ```
     pub const fn from_raw(kind: RawSyntaxKind) -> Self {
@@ -59,7 +59,7 @@ where
         let shift = kind % u128::BITS as u16;
         let mask = 1 << shift;
 
-        let mut bits = [0; 4];
+        let mut bits = [0; 8];
         bits[index] = mask;
 
         Self(bits, PhantomData)
@@ -67,15 +67,14 @@ where
 
     /// Returns the union of the two sets `self` and `other`
     pub const fn union(self, other: Self) -> Self {
-        Self(
-            [
-                self.0[0] | other.0[0],
-                self.0[1] | other.0[1],
-                self.0[2] | other.0[2],
-                self.0[3] | other.0[3],
-            ],
-            PhantomData,
-        )
+        let mut bits = [0; 8];
+        let mut index = 0;
+        while index < bits.len() {
+            bits[index] = self.0[index] | other.0[index];
+            index += 1;
+        }
+
+        Self(bits, PhantomData)
     }
 
     /// Returns true if `kind` is contained in this set
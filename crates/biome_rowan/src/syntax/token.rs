@@ -312,6 +312,50 @@ impl<L: Language> SyntaxToken<L> {
         }
     }
 
+    /// Return a new version of this token with its text replaced with `text`, keeping its trivia.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use biome_rowan::raw_language::{RawLanguage, RawLanguageKind};
+    /// use biome_rowan::{RawSyntaxToken, SyntaxToken, TriviaPiece};
+    ///
+    /// let token = SyntaxToken::<RawLanguage>::new_detached(
+    ///     RawLanguageKind::STRING_TOKEN,
+    ///     "\n\"a\" ",
+    ///     [TriviaPiece::newline(1)],
+    ///     [TriviaPiece::whitespace(1)]
+    /// );
+    /// let new_token = token.with_text_trimmed("\"abc\"");
+    ///
+    /// assert_eq!(
+    ///     format!("{:?}", new_token),
+    ///     "STRING_TOKEN@0..7 \"\\\"abc\\\"\" [Newline(\"\\n\")] [Whitespace(\" \")]"
+    /// );
+    /// ```
+    #[must_use = "syntax elements are immutable, the result of update methods must be propagated to have any effect"]
+    pub fn with_text_trimmed(&self, text: &str) -> Self {
+        let leading = self.green_token().leading_trivia().clone();
+        let trailing = self.green_token().trailing_trivia().clone();
+
+        let mut token_text = String::new();
+        let leading_len = usize::from(leading.text_len());
+        let trailing_len = usize::from(trailing.text_len());
+        token_text.push_str(&self.text()[..leading_len]);
+        token_text.push_str(text);
+        token_text.push_str(&self.text()[self.text().len() - trailing_len..]);
+
+        Self {
+            raw: cursor::SyntaxToken::new_detached(GreenToken::with_trivia(
+                self.kind().to_raw(),
+                &token_text,
+                leading,
+                trailing,
+            )),
+            _p: PhantomData,
+        }
+    }
+
     // Return a new version of this token with `trivia` prepended to its leading trivia.
     ///
     /// # Examples
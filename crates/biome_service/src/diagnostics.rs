@@ -59,6 +59,8 @@ pub enum WorkspaceError {
     Vcs(VcsDiagnostic),
     /// One or more errors occurred during plugin loading.
     PluginErrors(PluginErrors),
+    /// Suppression comments can't be added to the files of a language
+    SuppressionsNotSupported(SuppressionsNotSupported),
     /// Diagnostic raised when a file is protected.
     ProtectedFile(ProtectedFile),
     /// Error when searching for a pattern
@@ -108,6 +110,16 @@ impl WorkspaceError {
         })
     }
 
+    pub fn suppressions_not_supported(
+        language: impl Into<String>,
+        path: impl Into<String>,
+    ) -> Self {
+        Self::SuppressionsNotSupported(SuppressionsNotSupported {
+            language: language.into(),
+            path: path.into(),
+        })
+    }
+
     pub fn plugin_errors(diagnostics: Vec<PluginDiagnostic>) -> Self {
         Self::PluginErrors(PluginErrors { diagnostics })
     }
@@ -285,6 +297,20 @@ pub struct FileIgnored {
     path: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Diagnostic)]
+#[diagnostic(
+    category = "suppressions/unsupported",
+    message(
+        message("Biome can't add suppression comments to "{self.language}" files yet."),
+        description = "Biome can't add suppression comments to {language} files yet."
+    ),
+)]
+pub struct SuppressionsNotSupported {
+    language: String,
+    #[location(resource)]
+    path: String,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FileTooLarge {
     pub size: usize,
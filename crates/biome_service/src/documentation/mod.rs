@@ -6,6 +6,7 @@ use biome_console::fmt::{Display, Formatter};
 use biome_console::{Padding, markup};
 use biome_css_syntax::CssLanguage;
 use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_syntax::HtmlLanguage;
 use biome_js_syntax::JsLanguage;
 use biome_json_syntax::JsonLanguage;
 use biome_rowan::Language;
@@ -53,6 +54,9 @@ impl RulesVisitor {
         };
 
         biome_graphql_analyze::visit_registry(&mut visitor);
+        // HTML rules share their names with the JSX rules they port, which are visited later
+        // and whose documentation takes precedence
+        biome_html_analyze::visit_registry(&mut visitor);
         biome_css_analyze::visit_registry(&mut visitor);
         biome_json_analyze::visit_registry(&mut visitor);
         biome_js_analyze::visit_registry(&mut visitor);
@@ -121,6 +125,16 @@ impl RegistryVisitor<GraphqlLanguage> for RulesVisitor {
     }
 }
 
+impl RegistryVisitor<HtmlLanguage> for RulesVisitor {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.store_rule::<R, HtmlLanguage>();
+    }
+}
+
 impl biome_console::fmt::Display for ExplainRule {
     fn fmt(&self, fmt: &mut Formatter) -> std::io::Result<()> {
         let metadata = &self.metadata;
@@ -75,7 +75,10 @@ impl ExtensionHandler for AstroFileHandler {
                 linter: Some(javascript::linter_enabled),
             },
 
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                reparse: None,
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: None,
                 debug_control_flow: None,
@@ -87,7 +90,11 @@ impl ExtensionHandler for AstroFileHandler {
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
+                rename_declaration: None,
                 rename: None,
+                hover: None,
+                goto_definition: None,
+                find_references: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
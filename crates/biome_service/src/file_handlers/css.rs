@@ -329,7 +329,10 @@ pub(crate) struct CssFileHandler;
 impl ExtensionHandler for CssFileHandler {
     fn capabilities(&self) -> Capabilities {
         Capabilities {
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                reparse: None,
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: None,
@@ -341,7 +344,11 @@ impl ExtensionHandler for CssFileHandler {
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
+                rename_declaration: None,
                 rename: None,
+                hover: None,
+                goto_definition: None,
+                find_references: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
@@ -362,15 +369,15 @@ impl ExtensionHandler for CssFileHandler {
     }
 }
 
-fn formatter_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+pub(crate) fn formatter_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
     handle.formatter_enabled_for_file_path::<CssLanguage>(path)
 }
 
-fn linter_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+pub(crate) fn linter_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
     handle.linter_enabled_for_file_path::<CssLanguage>(path)
 }
 
-fn assist_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+pub(crate) fn assist_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
     handle.assist_enabled_for_file_path::<CssLanguage>(path)
 }
 
@@ -378,9 +385,9 @@ fn search_enabled(_path: &Utf8Path, _handle: &WorkspaceSettingsHandle) -> bool {
     true
 }
 
-fn parse(
+pub(crate) fn parse(
     biome_path: &BiomePath,
-    _file_source: DocumentFileSource,
+    file_source: DocumentFileSource,
     text: &str,
     handle: WorkspaceSettingsHandle,
     cache: &mut NodeCache,
@@ -396,6 +403,12 @@ fn parse(
             .unwrap_or_default()
             .into(),
         grit_metavariables: false,
+        scss: file_source
+            .to_css_file_source()
+            .is_some_and(|file_source| file_source.is_scss()),
+        less: file_source
+            .to_css_file_source()
+            .is_some_and(|file_source| file_source.is_less()),
     };
     if let Some(settings) = settings {
         options = settings
@@ -434,7 +447,7 @@ fn debug_formatter_ir(
 }
 
 #[tracing::instrument(level = "debug", skip(parse))]
-fn format(
+pub(crate) fn format(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
@@ -451,7 +464,7 @@ fn format(
     }
 }
 
-fn format_range(
+pub(crate) fn format_range(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
@@ -465,7 +478,7 @@ fn format_range(
     Ok(printed)
 }
 
-fn format_on_type(
+pub(crate) fn format_on_type(
     biome_path: &BiomePath,
     document_file_source: &DocumentFileSource,
     parse: AnyParse,
@@ -502,7 +515,7 @@ fn format_on_type(
     Ok(printed)
 }
 
-fn lint(params: LintParams) -> LintResults {
+pub(crate) fn lint(params: LintParams) -> LintResults {
     let _ =
         debug_span!("Linting CSS file", path =? params.path, language =? params.language).entered();
     let workspace_settings = &params.workspace;
@@ -558,6 +571,8 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         suppression_reason,
         plugins,
         categories,
+        json_schema: _,
+        graphql_schema: _,
     } = params;
     let _ = debug_span!("Code actions CSS", range =? range, path =? path).entered();
     let tree = parse.tree();
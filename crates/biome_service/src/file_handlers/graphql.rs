@@ -1,7 +1,7 @@
 use super::{
     AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource, EnabledForPath,
     ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult, ProcessLint,
-    SearchCapabilities, is_diagnostic_error,
+    SearchCapabilities, is_diagnostic_error, search,
 };
 use crate::WorkspaceError;
 use crate::file_handlers::DebugCapabilities;
@@ -9,8 +9,9 @@ use crate::file_handlers::{
     AnalyzerCapabilities, Capabilities, FormatterCapabilities, ParserCapabilities,
 };
 use crate::settings::{
-    FormatSettings, LanguageListSettings, LanguageSettings, OverrideSettings, ServiceLanguage,
-    Settings, WorkspaceSettingsHandle, check_feature_activity, check_override_feature_activity,
+    FormatSettings, Includes, LanguageListSettings, LanguageSettings, OverrideSettings,
+    ServiceLanguage, Settings, WorkspaceSettingsHandle, check_feature_activity,
+    check_override_feature_activity,
 };
 use crate::workspace::{
     CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
@@ -26,10 +27,10 @@ use biome_formatter::{
     QuoteStyle,
 };
 use biome_fs::BiomePath;
-use biome_graphql_analyze::analyze;
+use biome_graphql_analyze::{GraphqlAnalyzerServices, analyze};
 use biome_graphql_formatter::context::GraphqlFormatOptions;
 use biome_graphql_formatter::format_node;
-use biome_graphql_parser::parse_graphql_with_cache;
+use biome_graphql_parser::{GraphqlParserOptions, parse_graphql_with_cache};
 use biome_graphql_syntax::{GraphqlLanguage, GraphqlRoot, GraphqlSyntaxNode, TextRange, TextSize};
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, NodeCache, TokenAtOffset};
@@ -91,12 +92,18 @@ impl From<GraphqlAssistConfiguration> for GraphqlAssistSettings {
     }
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct GraphqlEnvironmentSettings {
+    /// The files that define the schema of the project
+    pub schema: Includes,
+}
+
 impl ServiceLanguage for GraphqlLanguage {
     type FormatterSettings = GraphqlFormatterSettings;
     type LinterSettings = GraphqlLinterSettings;
     type FormatOptions = GraphqlFormatOptions;
     type ParserSettings = ();
-    type EnvironmentSettings = ();
+    type EnvironmentSettings = GraphqlEnvironmentSettings;
     type AssistSettings = GraphqlAssistSettings;
 
     fn lookup_settings(language: &LanguageListSettings) -> &LanguageSettings<Self> {
@@ -268,7 +275,10 @@ impl ExtensionHandler for GraphqlFileHandler {
                 linter: Some(linter_enabled),
                 search: Some(search_enabled),
             },
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                reparse: None,
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: None,
@@ -280,7 +290,11 @@ impl ExtensionHandler for GraphqlFileHandler {
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
+                rename_declaration: None,
                 rename: None,
+                hover: None,
+                goto_definition: None,
+                find_references: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
@@ -288,7 +302,9 @@ impl ExtensionHandler for GraphqlFileHandler {
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities {
+                search: Some(search),
+            },
         }
     }
 }
@@ -297,7 +313,7 @@ fn formatter_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool
     handle.formatter_enabled_for_file_path::<GraphqlLanguage>(path)
 }
 
-fn linter_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+pub(crate) fn linter_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
     handle.linter_enabled_for_file_path::<GraphqlLanguage>(path)
 }
 
@@ -309,14 +325,14 @@ fn search_enabled(_path: &Utf8Path, _handle: &WorkspaceSettingsHandle) -> bool {
     true
 }
 
-fn parse(
+pub(crate) fn parse(
     _biome_path: &BiomePath,
     file_source: DocumentFileSource,
     text: &str,
     _settings: WorkspaceSettingsHandle,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let parse = parse_graphql_with_cache(text, cache);
+    let parse = parse_graphql_with_cache(text, cache, GraphqlParserOptions::default());
 
     ParseResult {
         any_parse: parse.into(),
@@ -417,7 +433,7 @@ fn format_on_type(
     Ok(printed)
 }
 
-fn lint(params: LintParams) -> LintResults {
+pub(crate) fn lint(params: LintParams) -> LintResults {
     let _ = debug_span!("Linting GraphQL file", path =? params.path, language =? params.language)
         .entered();
     let workspace_settings = &params.workspace;
@@ -446,9 +462,14 @@ fn lint(params: LintParams) -> LintResults {
 
     let mut process_lint = ProcessLint::new(&params);
 
-    let (_, analyze_diagnostics) = analyze(&tree, filter, &analyzer_options, |signal| {
-        process_lint.process_signal(signal)
-    });
+    let (_, analyze_diagnostics) = analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        &params.plugins,
+        GraphqlAnalyzerServices::default().with_schema(params.graphql_schema.clone()),
+        |signal| process_lint.process_signal(signal),
+    );
 
     process_lint.into_result(params.parse.into_diagnostics(), analyze_diagnostics)
 }
@@ -467,8 +488,10 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         skip,
         suppression_reason,
         enabled_rules: rules,
-        plugins: _,
+        plugins,
         categories,
+        json_schema: _,
+        graphql_schema,
     } = params;
     let _ = debug_span!("Code actions GraphQL", range =? range, path =? path).entered();
     let tree = parse.tree();
@@ -504,19 +527,27 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
 
     info!("GraphQL runs the analyzer");
 
-    analyze(&tree, filter, &analyzer_options, |signal| {
-        actions.extend(signal.actions().into_code_action_iter().map(|item| {
-            CodeAction {
-                category: item.category.clone(),
-                rule_name: item
-                    .rule_name
-                    .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
-                suggestion: item.suggestion,
-            }
-        }));
+    let services = GraphqlAnalyzerServices::default().with_schema(graphql_schema);
+    analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        &plugins,
+        services,
+        |signal| {
+            actions.extend(signal.actions().into_code_action_iter().map(|item| {
+                CodeAction {
+                    category: item.category.clone(),
+                    rule_name: item
+                        .rule_name
+                        .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                    suggestion: item.suggestion,
+                }
+            }));
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        },
+    );
 
     PullActionsResult { actions }
 }
@@ -561,48 +592,55 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     let mut errors: u16 = 0;
 
     loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, |signal| {
-            let current_diagnostic = signal.diagnostic();
-
-            if let Some(diagnostic) = current_diagnostic.as_ref() {
-                if is_diagnostic_error(diagnostic, rules.as_deref()) {
-                    errors += 1;
+        let (action, _) = analyze(
+            &tree,
+            filter,
+            &analyzer_options,
+            &params.plugins,
+            GraphqlAnalyzerServices::default().with_schema(params.graphql_schema.clone()),
+            |signal| {
+                let current_diagnostic = signal.diagnostic();
+
+                if let Some(diagnostic) = current_diagnostic.as_ref() {
+                    if is_diagnostic_error(diagnostic, rules.as_deref()) {
+                        errors += 1;
+                    }
                 }
-            }
 
-            for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
-                    continue;
-                }
+                for action in signal.actions() {
+                    // suppression actions should not be part of the fixes (safe or suggested)
+                    if action.is_suppression() {
+                        continue;
+                    }
 
-                match params.fix_file_mode {
-                    FixFileMode::SafeFixes => {
-                        if action.applicability == Applicability::MaybeIncorrect {
-                            skipped_suggested_fixes += 1;
+                    match params.fix_file_mode {
+                        FixFileMode::SafeFixes => {
+                            if action.applicability == Applicability::MaybeIncorrect {
+                                skipped_suggested_fixes += 1;
+                            }
+                            if action.applicability == Applicability::Always {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                        if action.applicability == Applicability::Always {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::SafeAndUnsafeFixes => {
+                            if matches!(
+                                action.applicability,
+                                Applicability::Always | Applicability::MaybeIncorrect
+                            ) {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                    }
-                    FixFileMode::SafeAndUnsafeFixes => {
-                        if matches!(
-                            action.applicability,
-                            Applicability::Always | Applicability::MaybeIncorrect
-                        ) {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::ApplySuppressions => {
+                            // TODO: implement once a GraphQL suppression action is available
                         }
                     }
-                    FixFileMode::ApplySuppressions => {
-                        // TODO: implement once a GraphQL suppression action is available
-                    }
                 }
-            }
 
-            ControlFlow::Continue(())
-        });
+                ControlFlow::Continue(())
+            },
+        );
 
         match action {
             Some(action) => {
@@ -242,7 +242,10 @@ impl ExtensionHandler for GritFileHandler {
                 assist: Some(assist_enabled),
                 search: Some(search_enabled),
             },
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                reparse: None,
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: None,
@@ -254,7 +257,11 @@ impl ExtensionHandler for GritFileHandler {
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
                 code_actions: None,
+                rename_declaration: None,
                 rename: None,
+                hover: None,
+                goto_definition: None,
+                find_references: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
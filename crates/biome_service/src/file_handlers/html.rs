@@ -1,34 +1,39 @@
 use super::{
-    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, EnabledForPath,
-    ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities, SearchCapabilities,
+    AnalyzerCapabilities, AnalyzerVisitorBuilder, Capabilities, CodeActionsParams,
+    DebugCapabilities, DocumentFileSource, EnabledForPath, ExtensionHandler, FixAllParams,
+    FormatterCapabilities, LintParams, LintResults, ParseResult, ParserCapabilities, ProcessLint,
+    SearchCapabilities, is_diagnostic_error, search,
 };
 use crate::settings::{check_feature_activity, check_override_feature_activity};
-use crate::workspace::FixFileResult;
+use crate::workspace::{CodeAction, FixAction, FixFileMode, FixFileResult, PullActionsResult};
 use crate::{
     WorkspaceError,
     settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
     workspace::GetSyntaxTreeResult,
 };
-use biome_analyze::AnalyzerOptions;
-use biome_configuration::html::{HtmlFormatterConfiguration, HtmlFormatterEnabled};
-use biome_diagnostics::{Diagnostic, Severity};
+use biome_analyze::{AnalysisFilter, AnalyzerOptions, ControlFlow, Never, RuleError};
+use biome_configuration::html::{
+    HtmlFormatterConfiguration, HtmlFormatterEnabled, HtmlLinterConfiguration, HtmlLinterEnabled,
+};
+use biome_diagnostics::Applicability;
 use biome_formatter::{
     AttributePosition, BracketSameLine, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed,
 };
 use biome_fs::BiomePath;
+use biome_html_analyze::analyze;
 use biome_html_formatter::context::SelfCloseVoidElements;
 use biome_html_formatter::{
     HtmlFormatOptions,
     context::{IndentScriptAndStyle, WhitespaceSensitivity},
     format_node,
 };
-use biome_html_parser::parse_html_with_cache;
+use biome_html_parser::{HtmlParserOptions, parse_html_with_cache};
 use biome_html_syntax::{HtmlLanguage, HtmlRoot, HtmlSyntaxNode};
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, NodeCache};
 use camino::Utf8Path;
-use tracing::debug_span;
+use std::borrow::Cow;
+use tracing::{debug_span, info, trace_span};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -62,9 +67,23 @@ impl From<HtmlFormatterConfiguration> for HtmlFormatterSettings {
     }
 }
 
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HtmlLinterSettings {
+    pub enabled: Option<HtmlLinterEnabled>,
+}
+
+impl From<HtmlLinterConfiguration> for HtmlLinterSettings {
+    fn from(configuration: HtmlLinterConfiguration) -> Self {
+        Self {
+            enabled: configuration.enabled,
+        }
+    }
+}
+
 impl ServiceLanguage for HtmlLanguage {
     type FormatterSettings = HtmlFormatterSettings;
-    type LinterSettings = ();
+    type LinterSettings = HtmlLinterSettings;
     type FormatOptions = HtmlFormatOptions;
     type ParserSettings = ();
     type EnvironmentSettings = ();
@@ -180,8 +199,33 @@ impl ServiceLanguage for HtmlLanguage {
         false
     }
 
-    fn linter_enabled_for_file_path(_settings: Option<&Settings>, _path: &Utf8Path) -> bool {
-        false
+    fn linter_enabled_for_file_path(settings: Option<&Settings>, path: &Utf8Path) -> bool {
+        settings
+            .and_then(|settings| {
+                let overrides_activity =
+                    settings
+                        .override_settings
+                        .patterns
+                        .iter()
+                        .rev()
+                        .find_map(|pattern| {
+                            check_override_feature_activity(
+                                pattern.languages.html.linter.enabled,
+                                pattern.linter.enabled,
+                            )
+                            .filter(|_| {
+                                // Then check whether the path satisfies
+                                pattern.is_file_included(path)
+                            })
+                        });
+
+                overrides_activity.or(check_feature_activity(
+                    settings.languages.html.linter.enabled,
+                    settings.linter.enabled,
+                ))
+            })
+            .unwrap_or_default()
+            .into()
     }
 
     fn resolve_environment(_settings: Option<&Settings>) -> Option<&Self::EnvironmentSettings> {
@@ -201,7 +245,10 @@ impl ExtensionHandler for HtmlFileHandler {
                 assist: Some(assist_enabled),
                 search: Some(search_enabled),
             },
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                reparse: None,
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: None,
@@ -212,8 +259,12 @@ impl ExtensionHandler for HtmlFileHandler {
             },
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
-                code_actions: None,
+                code_actions: Some(code_actions),
+                rename_declaration: None,
                 rename: None,
+                hover: None,
+                goto_definition: None,
+                find_references: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
@@ -221,7 +272,9 @@ impl ExtensionHandler for HtmlFileHandler {
                 format_range: None,
                 format_on_type: None,
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities {
+                search: Some(search),
+            },
         }
     }
 }
@@ -249,7 +302,7 @@ fn parse(
     _handle: WorkspaceSettingsHandle,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let parse = parse_html_with_cache(text, cache);
+    let parse = parse_html_with_cache(text, cache, HtmlParserOptions::default());
 
     ParseResult {
         any_parse: parse.into(),
@@ -299,46 +352,251 @@ fn format(
     }
 }
 
-#[tracing::instrument(level = "debug", skip(params))]
 fn lint(params: LintParams) -> LintResults {
     let _ = debug_span!("Linting HTML file", path =? params.path, language =? params.language)
         .entered();
-    let diagnostics = params.parse.into_diagnostics();
-
-    let diagnostic_count = diagnostics.len() as u32;
-    let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
-    let errors = diagnostics
-        .iter()
-        .filter(|diag| diag.severity() <= Severity::Error)
-        .count();
-
-    LintResults {
-        diagnostics,
-        errors,
-        skipped_diagnostics,
-    }
+    let workspace_settings = &params.workspace;
+    let analyzer_options = workspace_settings.analyzer_options::<HtmlLanguage>(
+        params.path,
+        &params.language,
+        params.suppression_reason.as_deref(),
+    );
+    let tree = params.parse.tree();
+
+    let (enabled_rules, disabled_rules, analyzer_options) =
+        AnalyzerVisitorBuilder::new(params.workspace.settings(), analyzer_options)
+            .with_only(&params.only)
+            .with_skip(&params.skip)
+            .with_path(params.path.as_path())
+            .with_enabled_rules(&params.enabled_rules)
+            .with_project_layout(params.project_layout.clone())
+            .finish();
+
+    let filter = AnalysisFilter {
+        categories: params.categories,
+        enabled_rules: Some(enabled_rules.as_slice()),
+        disabled_rules: &disabled_rules,
+        range: None,
+    };
+
+    let mut process_lint = ProcessLint::new(&params);
+
+    let (_, analyze_diagnostics) = analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        &params.plugins,
+        |signal| process_lint.process_signal(signal),
+    );
+
+    process_lint.into_result(params.parse.into_diagnostics(), analyze_diagnostics)
 }
 
 #[tracing::instrument(level = "debug", skip(params))]
+pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
+    let CodeActionsParams {
+        parse,
+        range,
+        workspace,
+        path,
+        module_graph: _,
+        project_layout,
+        language,
+        only,
+        skip,
+        suppression_reason,
+        enabled_rules: rules,
+        plugins,
+        categories,
+        json_schema: _,
+        graphql_schema: _,
+    } = params;
+    let _ = debug_span!("Code actions HTML", range =? range, path =? path).entered();
+    let tree: HtmlRoot = parse.tree();
+    let _ = trace_span!("Parsed file", tree =? tree).entered();
+
+    let analyzer_options =
+        workspace.analyzer_options::<HtmlLanguage>(path, &language, suppression_reason.as_deref());
+    let mut actions = Vec::new();
+    let (enabled_rules, disabled_rules, analyzer_options) =
+        AnalyzerVisitorBuilder::new(params.workspace.settings(), analyzer_options)
+            .with_only(&only)
+            .with_skip(&skip)
+            .with_path(path.as_path())
+            .with_enabled_rules(&rules)
+            .with_project_layout(project_layout)
+            .finish();
+
+    let filter = AnalysisFilter {
+        categories,
+        enabled_rules: Some(enabled_rules.as_slice()),
+        disabled_rules: &disabled_rules,
+        range,
+    };
+
+    info!("HTML runs the analyzer");
+
+    analyze(&tree, filter, &analyzer_options, &plugins, |signal| {
+        actions.extend(signal.actions().into_code_action_iter().map(|item| {
+            CodeAction {
+                category: item.category.clone(),
+                rule_name: item
+                    .rule_name
+                    .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                suggestion: item.suggestion,
+            }
+        }));
+
+        ControlFlow::<Never>::Continue(())
+    });
+
+    PullActionsResult { actions }
+}
+
+/// If applies all the safe fixes to the given syntax tree.
+///
+/// Suppression comments can't be applied yet: HTML comments are nodes of the tree, while
+/// the analyzer reads suppression comments from the trivia of the tokens.
 pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
-    // We don't have analyzer rules yet
-    let tree: HtmlRoot = params.parse.tree();
-    let code = if params.should_format {
-        format_node(
-            params
-                .workspace
-                .format_options::<HtmlLanguage>(params.biome_path, &params.document_file_source),
-            tree.syntax(),
-        )?
-        .print()?
-        .into_code()
-    } else {
-        tree.syntax().to_string()
+    if params.fix_file_mode == FixFileMode::ApplySuppressions {
+        return Err(WorkspaceError::suppressions_not_supported(
+            "HTML",
+            params.biome_path.to_string(),
+        ));
+    }
+
+    let mut tree: HtmlRoot = params.parse.tree();
+    let Some(settings) = params.workspace.settings() else {
+        return Ok(FixFileResult {
+            actions: Vec::new(),
+            errors: 0,
+            skipped_suggested_fixes: 0,
+            code: tree.syntax().to_string(),
+        });
     };
-    Ok(FixFileResult {
-        code,
-        skipped_suggested_fixes: 0,
-        actions: vec![],
-        errors: 0,
-    })
+
+    // Compute final rules (taking `overrides` into account)
+    let rules = settings.as_linter_rules(params.biome_path.as_path());
+    let analyzer_options = params.workspace.analyzer_options::<HtmlLanguage>(
+        params.biome_path,
+        &params.document_file_source,
+        params.suppression_reason.as_deref(),
+    );
+    let (enabled_rules, disabled_rules, analyzer_options) =
+        AnalyzerVisitorBuilder::new(params.workspace.settings(), analyzer_options)
+            .with_only(&params.only)
+            .with_skip(&params.skip)
+            .with_path(params.biome_path.as_path())
+            .with_enabled_rules(&params.enabled_rules)
+            .with_project_layout(params.project_layout)
+            .finish();
+
+    let filter = AnalysisFilter {
+        categories: params.rule_categories,
+        enabled_rules: Some(enabled_rules.as_slice()),
+        disabled_rules: &disabled_rules,
+        range: None,
+    };
+
+    let mut actions = Vec::new();
+    let mut skipped_suggested_fixes = 0;
+    let mut errors: u16 = 0;
+
+    loop {
+        let (action, _) = analyze(
+            &tree,
+            filter,
+            &analyzer_options,
+            &params.plugins,
+            |signal| {
+                let current_diagnostic = signal.diagnostic();
+
+                if let Some(diagnostic) = current_diagnostic.as_ref() {
+                    if is_diagnostic_error(diagnostic, rules.as_deref()) {
+                        errors += 1;
+                    }
+                }
+
+                for action in signal.actions() {
+                    // suppression actions should not be part of the fixes (safe or suggested)
+                    if action.is_suppression() {
+                        continue;
+                    }
+
+                    match params.fix_file_mode {
+                        FixFileMode::SafeFixes => {
+                            if action.applicability == Applicability::MaybeIncorrect {
+                                skipped_suggested_fixes += 1;
+                            }
+                            if action.applicability == Applicability::Always {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
+                        }
+                        FixFileMode::SafeAndUnsafeFixes => {
+                            if matches!(
+                                action.applicability,
+                                Applicability::Always | Applicability::MaybeIncorrect
+                            ) {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
+                        }
+                        // Rejected before running the analyzer
+                        FixFileMode::ApplySuppressions => {}
+                    }
+                }
+
+                ControlFlow::Continue(())
+            },
+        );
+
+        match action {
+            Some(action) => {
+                if let (root, Some((range, _))) =
+                    action.mutation.commit_with_text_range_and_edit(true)
+                {
+                    tree = match HtmlRoot::cast(root) {
+                        Some(tree) => tree,
+                        None => {
+                            return Err(WorkspaceError::RuleError(
+                                RuleError::ReplacedRootWithNonRootError {
+                                    rule_name: action.rule_name.map(|(group, rule)| {
+                                        (Cow::Borrowed(group), Cow::Borrowed(rule))
+                                    }),
+                                },
+                            ));
+                        }
+                    };
+                    actions.push(FixAction {
+                        rule_name: action
+                            .rule_name
+                            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
+                        range,
+                    });
+                }
+            }
+            None => {
+                let code = if params.should_format {
+                    format_node(
+                        params.workspace.format_options::<HtmlLanguage>(
+                            params.biome_path,
+                            &params.document_file_source,
+                        ),
+                        tree.syntax(),
+                    )?
+                    .print()?
+                    .into_code()
+                } else {
+                    tree.syntax().to_string()
+                };
+                return Ok(FixFileResult {
+                    code,
+                    skipped_suggested_fixes,
+                    actions,
+                    errors: errors.into(),
+                });
+            }
+        }
+    }
 }
@@ -1,7 +1,8 @@
 use super::{
     AnalyzerCapabilities, AnalyzerVisitorBuilder, CodeActionsParams, DebugCapabilities,
-    EnabledForPath, ExtensionHandler, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities, ProcessLint, SearchCapabilities, search,
+    EnabledForPath, ExtensionHandler, FormatterCapabilities, Lint, LintParams, LintResults, Parse,
+    ParseResult, ParserCapabilities, ProcessLint, RenameDeclaration, SearchCapabilities, css,
+    graphql, search,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::diagnostics::extension_error;
@@ -17,44 +18,54 @@ use crate::{
         WorkspaceSettingsHandle,
     },
     workspace::{
-        CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
-        RenameResult,
+        CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, HoverResult,
+        PullActionsResult, SymbolLocation,
     },
 };
 use biome_analyze::options::PreferredQuote;
 use biome_analyze::{
     AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never, QueryMatch,
-    RuleCategoriesBuilder, RuleError, RuleFilter,
+    RuleCategoriesBuilder, RuleCategory, RuleError, RuleFilter,
 };
 use biome_configuration::javascript::{
     JsAssistConfiguration, JsAssistEnabled, JsFormatterConfiguration, JsFormatterEnabled,
     JsGritMetavariable, JsLinterConfiguration, JsLinterEnabled, JsParserConfiguration,
     JsxEverywhere, JsxRuntime, UnsafeParameterDecoratorsEnabled,
 };
-use biome_diagnostics::Applicability;
+use biome_css_syntax::CssFileSource;
+use biome_diagnostics::{Applicability, Diagnostic, Severity};
 use biome_formatter::{
     AttributePosition, BracketSameLine, BracketSpacing, Expand, FormatError, IndentStyle,
     IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
 };
 use biome_fs::BiomePath;
+use biome_graphql_syntax::GraphqlFileSource;
 use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
 use biome_js_analyze::{
     ControlFlowGraph, JsAnalyzerServices, analyze, analyze_with_inspect_matcher,
 };
 use biome_js_formatter::context::trailing_commas::TrailingCommas;
-use biome_js_formatter::context::{ArrowParentheses, JsFormatOptions, QuoteProperties, Semicolons};
+use biome_js_formatter::context::{
+    ArrowParentheses, EmbeddedLanguageFormatting, JsFormatOptions, QuoteProperties, Semicolons,
+};
 use biome_js_formatter::format_node;
 use biome_js_parser::JsParserOptions;
-use biome_js_semantic::{SemanticModelOptions, semantic_model};
+use biome_js_semantic::{Binding, SemanticModel, SemanticModelOptions, semantic_model};
+use biome_js_syntax::binding_ext::AnyJsIdentifierBinding;
+use biome_js_syntax::embedded_template::EmbeddedLanguage;
 use biome_js_syntax::{
-    AnyJsRoot, JsClassDeclaration, JsClassExpression, JsFileSource, JsFunctionDeclaration,
-    JsLanguage, JsSyntaxNode, JsVariableDeclarator, LanguageVariant, TextRange, TextSize,
-    TokenAtOffset,
+    AnyJsExpression, AnyJsIdentifierUsage, AnyJsImportLike, AnyJsRoot, JsClassDeclaration,
+    JsClassExpression, JsExportNamedFromClause, JsExportNamedFromSpecifier, JsFileSource,
+    JsFunctionDeclaration, JsLanguage, JsLiteralExportName, JsModuleSource, JsName,
+    JsNamedImportSpecifier, JsShorthandNamedImportSpecifier, JsSyntaxKind, JsSyntaxNode,
+    JsSyntaxToken, JsTemplateExpression, JsVariableDeclarator, LanguageVariant, TextRange,
+    TextSize, TokenAtOffset, inner_string_text,
 };
 use biome_js_type_info::{GlobalsResolver, ScopeId, TypeData, TypeResolver};
-use biome_module_graph::ModuleGraph;
+use biome_module_graph::{ImportSymbol, JsModuleInfo, ModuleGraph, ScopedResolver};
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, BatchMutationExt, Direction, NodeCache, WalkEvent};
+use biome_rowan::{AstNode, BatchMutation, BatchMutationExt, NodeCache, WalkEvent};
+use biome_text_edit::TextEdit;
 use camino::Utf8Path;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
@@ -80,6 +91,7 @@ pub struct JsFormatterSettings {
     pub enabled: Option<JsFormatterEnabled>,
     pub attribute_position: Option<AttributePosition>,
     pub expand: Option<Expand>,
+    pub embedded_language_formatting: Option<EmbeddedLanguageFormatting>,
 }
 
 impl From<JsFormatterConfiguration> for JsFormatterSettings {
@@ -100,6 +112,7 @@ impl From<JsFormatterConfiguration> for JsFormatterSettings {
             indent_style: value.indent_style,
             line_ending: value.line_ending,
             expand: value.expand,
+            embedded_language_formatting: value.embedded_language_formatting,
         }
     }
 }
@@ -254,6 +267,11 @@ impl ServiceLanguage for JsLanguage {
                 .and_then(|l| l.expand)
                 .or(global.and_then(|g| g.expand))
                 .unwrap_or_default(),
+        )
+        .with_embedded_language_formatting(
+            language
+                .and_then(|l| l.embedded_language_formatting)
+                .unwrap_or_default(),
         );
 
         if let Some(overrides) = overrides {
@@ -480,7 +498,10 @@ impl ExtensionHandler for JsFileHandler {
                 assist: Some(assist_enabled),
                 search: Some(search_enabled),
             },
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                reparse: Some(reparse),
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: Some(debug_control_flow),
@@ -493,7 +514,11 @@ impl ExtensionHandler for JsFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 fix_all: Some(fix_all),
+                rename_declaration: Some(rename_declaration),
                 rename: Some(rename),
+                hover: Some(hover),
+                goto_definition: Some(goto_definition),
+                find_references: Some(find_references),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
@@ -530,6 +555,20 @@ fn parse(
     handle: WorkspaceSettingsHandle,
     cache: &mut NodeCache,
 ) -> ParseResult {
+    let (file_source, options) = parser_options(biome_path, file_source, &handle);
+    let parse = biome_js_parser::parse_js_with_cache(text, file_source, options, cache);
+    ParseResult {
+        any_parse: parse.into(),
+        language: None,
+    }
+}
+
+/// Returns the source type and the options to parse a file with.
+fn parser_options(
+    biome_path: &BiomePath,
+    file_source: DocumentFileSource,
+    handle: &WorkspaceSettingsHandle,
+) -> (JsFileSource, JsParserOptions) {
     let settings = handle.settings();
     let mut options = JsParserOptions {
         grit_metavariables: false,
@@ -562,11 +601,27 @@ fn parse(
     if jsx_everywhere && !file_source.is_typescript() {
         file_source = file_source.with_variant(LanguageVariant::Jsx);
     }
-    let parse = biome_js_parser::parse_js_with_cache(text, file_source, options, cache);
-    ParseResult {
-        any_parse: parse.into(),
-        language: None,
+    (file_source, options)
+}
+
+fn reparse(
+    biome_path: &BiomePath,
+    file_source: DocumentFileSource,
+    parse: &AnyParse,
+    range: TextRange,
+    text: &str,
+    handle: &WorkspaceSettingsHandle,
+) -> Option<AnyParse> {
+    if !parse.diagnostics().is_empty() {
+        return None;
     }
+
+    let root = parse.syntax::<JsLanguage>();
+    let root = biome_js_parser::reparse_token(&root, range, text).or_else(|| {
+        let (file_source, options) = parser_options(biome_path, file_source, handle);
+        biome_js_parser::reparse_statement(&root, range, text, file_source, options)
+    })?;
+    Some(root.as_send()?.into())
 }
 
 fn debug_syntax_tree(_rome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeResult {
@@ -740,6 +795,34 @@ fn debug_semantic_model(_path: &BiomePath, parse: AnyParse) -> Result<String, Wo
 }
 
 pub(crate) fn lint(params: LintParams) -> LintResults {
+    lint_embedded(params, EmbeddedNames::default())
+}
+
+/// The names a JavaScript root shares with the document it's embedded in, such as
+/// the `<script>` block of a Vue component and the rest of the component.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct EmbeddedNames {
+    /// Top-level bindings with these names are used by the rest of the document
+    pub(crate) references: Vec<String>,
+    /// These names are declared by the rest of the document
+    pub(crate) bindings: Vec<String>,
+}
+
+impl EmbeddedNames {
+    /// Adds the bindings declared by the rest of the document to the globals of `options`
+    fn push_bindings_as_globals(&self, options: &mut AnalyzerOptions) {
+        options.push_globals(
+            self.bindings
+                .iter()
+                .map(|name| name.as_str().into())
+                .collect(),
+        );
+    }
+}
+
+/// Lints a JavaScript root that is embedded in another document.
+/// See [EmbeddedNames].
+pub(crate) fn lint_embedded(params: LintParams, embedded: EmbeddedNames) -> LintResults {
     let _ =
         debug_span!("Linting JavaScript file", path =? params.path, language =? params.language)
             .entered();
@@ -760,7 +843,7 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
         &params.language,
         params.suppression_reason.as_deref(),
     );
-    let (enabled_rules, disabled_rules, analyzer_options) =
+    let (enabled_rules, disabled_rules, mut analyzer_options) =
         AnalyzerVisitorBuilder::new(params.workspace.settings(), analyzer_options)
             .with_only(&params.only)
             .with_skip(&params.skip)
@@ -768,6 +851,7 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
             .with_enabled_rules(&params.enabled_rules)
             .with_project_layout(params.project_layout.clone())
             .finish();
+    embedded.push_bindings_as_globals(&mut analyzer_options);
 
     let filter = AnalysisFilter {
         categories: params.categories,
@@ -777,8 +861,12 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
     };
 
     let mut process_lint = ProcessLint::new(&params);
-    let services =
-        JsAnalyzerServices::from((params.module_graph, params.project_layout, file_source));
+    let services = JsAnalyzerServices::from((
+        params.module_graph.clone(),
+        params.project_layout.clone(),
+        file_source,
+    ))
+    .with_embedded_references(embedded.references);
     let (_, analyze_diagnostics) = analyze(
         &tree,
         filter,
@@ -788,11 +876,111 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
         |signal| process_lint.process_signal(signal),
     );
 
-    process_lint.into_result(params.parse.into_diagnostics(), analyze_diagnostics)
+    let mut results =
+        process_lint.into_result(params.parse.clone().into_diagnostics(), analyze_diagnostics);
+    lint_embedded_templates(&params, &tree, &mut results);
+    results
+}
+
+/// Lints the content of the templates tagged with a well-known tag of an embedded
+/// language, such as `` css`...` `` and `` gql`...` ``, with the analyzer of that language.
+///
+/// The templates are only linted when `javascript.formatter.embeddedLanguageFormatting`
+/// is enabled. The diagnostics are reported at their position in the JavaScript file,
+/// and the diagnostics that can't be mapped back to it are skipped.
+fn lint_embedded_templates(params: &LintParams, tree: &AnyJsRoot, results: &mut LintResults) {
+    let options = params
+        .workspace
+        .format_options::<JsLanguage>(params.path, &params.language);
+    if options.embedded_language_formatting().is_off()
+        || !params.categories.contains(RuleCategory::Lint)
+    {
+        return;
+    }
+
+    for template in tree
+        .syntax()
+        .descendants()
+        .filter_map(JsTemplateExpression::cast)
+    {
+        let Some(embedded) = template.as_embedded_template() else {
+            continue;
+        };
+        let (language, linter_enabled, parse, lint): (DocumentFileSource, _, Parse, Lint) =
+            match embedded.language() {
+                EmbeddedLanguage::Css => (
+                    CssFileSource::css().into(),
+                    css::linter_enabled(params.path, params.workspace),
+                    css::parse,
+                    css::lint,
+                ),
+                EmbeddedLanguage::GraphQL => (
+                    GraphqlFileSource::graphql().into(),
+                    graphql::linter_enabled(params.path, params.workspace),
+                    graphql::parse,
+                    graphql::lint,
+                ),
+            };
+        if !linter_enabled {
+            continue;
+        }
+        let ParseResult { any_parse, .. } = parse(
+            params.path,
+            language,
+            embedded.text(),
+            params.workspace.clone(),
+            &mut NodeCache::default(),
+        );
+        // Templates with syntax errors are left to the formatter, which prints them as is
+        if any_parse.has_errors() {
+            continue;
+        }
+
+        let embedded_results = lint(LintParams {
+            parse: any_parse,
+            workspace: params.workspace,
+            language,
+            path: params.path,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            categories: RuleCategoriesBuilder::default().with_lint().build(),
+            module_graph: params.module_graph.clone(),
+            project_layout: params.project_layout.clone(),
+            suppression_reason: params.suppression_reason.clone(),
+            enabled_rules: params.enabled_rules.clone(),
+            plugins: params.plugins.clone(),
+            // The code actions apply to the embedded document
+            pull_code_actions: false,
+            json_schema: None,
+            graphql_schema: None,
+        });
+
+        results.skipped_diagnostics += embedded_results.skipped_diagnostics;
+        for diagnostic in embedded_results.diagnostics {
+            let Some(diagnostic) = diagnostic.map_spans(|span| embedded.to_source_range(span))
+            else {
+                results.skipped_diagnostics += 1;
+                continue;
+            };
+            if diagnostic.severity() >= Severity::Error {
+                results.errors += 1;
+            }
+            results.diagnostics.push(diagnostic);
+        }
+    }
 }
 
-#[tracing::instrument(level = "debug", skip(params))]
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
+    code_actions_embedded(params, EmbeddedNames::default())
+}
+
+/// Pulls the code actions of a JavaScript root that is embedded in another document.
+/// See [EmbeddedNames].
+#[tracing::instrument(level = "debug", skip(params))]
+pub(crate) fn code_actions_embedded(
+    params: CodeActionsParams,
+    embedded: EmbeddedNames,
+) -> PullActionsResult {
     let CodeActionsParams {
         parse,
         range,
@@ -807,6 +995,8 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         enabled_rules: rules,
         plugins,
         categories,
+        json_schema: _,
+        graphql_schema: _,
     } = params;
     let _ = debug_span!("Code actions JavaScript", range =? range, path =? path).entered();
     let tree = parse.tree();
@@ -814,7 +1004,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
     let analyzer_options =
         workspace.analyzer_options::<JsLanguage>(path, &language, suppression_reason.as_deref());
     let mut actions = Vec::new();
-    let (enabled_rules, disabled_rules, analyzer_options) =
+    let (enabled_rules, disabled_rules, mut analyzer_options) =
         AnalyzerVisitorBuilder::new(params.workspace.settings(), analyzer_options)
             .with_only(&only)
             .with_skip(&skip)
@@ -822,6 +1012,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
             .with_enabled_rules(&rules)
             .with_project_layout(project_layout.clone())
             .finish();
+    embedded.push_bindings_as_globals(&mut analyzer_options);
     let filter = AnalysisFilter {
         categories,
         enabled_rules: Some(enabled_rules.as_slice()),
@@ -836,7 +1027,8 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         };
     };
 
-    let services = JsAnalyzerServices::from((module_graph, project_layout, source_type));
+    let services = JsAnalyzerServices::from((module_graph, project_layout, source_type))
+        .with_embedded_references(embedded.references);
 
     debug!("Javascript runs the analyzer");
     analyze(
@@ -866,6 +1058,15 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
 
 /// If applies all the safe fixes to the given syntax tree.
 pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    fix_all_embedded(params, EmbeddedNames::default())
+}
+
+/// Applies the fixes to a JavaScript root that is embedded in another document.
+/// See [EmbeddedNames].
+pub(crate) fn fix_all_embedded(
+    params: FixAllParams,
+    embedded: EmbeddedNames,
+) -> Result<FixFileResult, WorkspaceError> {
     let mut tree: AnyJsRoot = params.parse.tree();
     let Some(settings) = params.workspace.settings() else {
         return Ok(FixFileResult {
@@ -883,7 +1084,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         &params.document_file_source,
         params.suppression_reason.as_deref(),
     );
-    let (enabled_rules, disabled_rules, analyzer_options) =
+    let (enabled_rules, disabled_rules, mut analyzer_options) =
         AnalyzerVisitorBuilder::new(params.workspace.settings(), analyzer_options)
             .with_only(&params.only)
             .with_skip(&params.skip)
@@ -891,6 +1092,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             .with_enabled_rules(&params.enabled_rules)
             .with_project_layout(params.project_layout.clone())
             .finish();
+    embedded.push_bindings_as_globals(&mut analyzer_options);
 
     let filter = AnalysisFilter {
         categories: params.rule_categories,
@@ -916,7 +1118,8 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             params.module_graph.clone(),
             params.project_layout.clone(),
             file_source,
-        ));
+        ))
+        .with_embedded_references(embedded.references.iter().cloned());
 
         let (action, _) = analyze(
             &tree,
@@ -1093,44 +1296,357 @@ pub(crate) fn format_on_type(
     Ok(printed)
 }
 
-fn rename(
-    _rome_path: &BiomePath,
+/// Returns the declaration renamed by renaming the identifier at `symbol_at`.
+fn rename_declaration(
+    path: &BiomePath,
     parse: AnyParse,
+    module_graph: &ModuleGraph,
     symbol_at: TextSize,
-    new_name: String,
-) -> Result<RenameResult, WorkspaceError> {
+) -> Option<RenameDeclaration> {
+    let node = identifier_at(&parse, symbol_at)?;
     let root = parse.tree();
     let model = semantic_model(&root, SemanticModelOptions::default());
+    let binding = binding_of_identifier(&model, &node)?;
+    let name = binding.tree().name_token().ok()?.text_trimmed().to_string();
+
+    // Renaming an aliased, default or namespace import only renames the local
+    // binding, while renaming a shorthand named import also renames the
+    // exported symbol.
+    if binding.is_imported() && !is_shorthand_import(&binding) {
+        return Some(RenameDeclaration {
+            name,
+            location: SymbolLocation {
+                path: path.clone(),
+                range: binding.syntax().text_trimmed_range(),
+            },
+        });
+    }
 
-    if let Some(node) = parse
-        .syntax()
-        .descendants_tokens(Direction::Next)
-        .find(|token| token.text_range().contains(symbol_at))
-        .and_then(|token| token.parent())
-    {
-        let original_name = node.text_trimmed();
-        let range = node.text_range_with_trivia();
-        match node.try_into() {
-            Ok(node) => {
-                let mut batch = root.begin();
-                let result = batch.rename_any_renamable_node(&model, &node, &new_name);
-                if !result {
-                    Err(WorkspaceError::RenameError(RenameError::CannotBeRenamed {
-                        original_name: original_name.to_string(),
-                        original_range: range,
-                        new_name,
-                    }))
-                } else {
-                    let (range, indels) = batch.to_text_range_and_edit().unwrap_or_default();
-                    Ok(RenameResult { range, indels })
+    let module_info = module_graph.module_info_for_path(path);
+    let location = declaration_location(path, module_info.as_ref(), module_graph, &binding);
+    // The exported symbol couldn't be found in the imported module
+    if location.range.is_empty() {
+        return None;
+    }
+
+    Some(RenameDeclaration { name, location })
+}
+
+/// Renames the `declaration` and its references inside the file.
+///
+/// Besides the declaration itself, this updates the modules that import or
+/// re-export the declaration by its name.
+fn rename(
+    path: &BiomePath,
+    parse: AnyParse,
+    module_graph: &ModuleGraph,
+    declaration: &RenameDeclaration,
+    new_name: &str,
+) -> Result<Option<(TextRange, TextEdit)>, WorkspaceError> {
+    let root = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let module_info = module_graph.module_info_for_path(path);
+    let cannot_be_renamed = |original_range: TextRange| {
+        WorkspaceError::RenameError(RenameError::CannotBeRenamed {
+            original_name: declaration.name.clone(),
+            original_range,
+            new_name: new_name.to_string(),
+        })
+    };
+    let refers_to_declaration = |binding: &Binding| {
+        declaration_location(path, module_info.as_ref(), module_graph, binding)
+            == declaration.location
+    };
+
+    let mut batch = root.clone().begin();
+    for binding in model.all_bindings() {
+        let range = binding.syntax().text_trimmed_range();
+        let is_declaration =
+            *path == declaration.location.path && range == declaration.location.range;
+        // A shorthand import of a re-export alias refers to the declaration
+        // through another name, so it keeps its name.
+        let is_renamed_import = is_shorthand_import(&binding)
+            && binding.syntax().text_trimmed() == declaration.name.as_str()
+            && refers_to_declaration(&binding);
+        if is_declaration || is_renamed_import {
+            let binding = binding.tree();
+            if binding.syntax().text_trimmed() != declaration.name.as_str()
+                || !batch.rename_node_declaration(&model, &binding, new_name)
+            {
+                return Err(cannot_be_renamed(range));
+            }
+        } else if let Some(specifier) = binding
+            .syntax()
+            .parent()
+            .and_then(JsNamedImportSpecifier::cast)
+        {
+            // `import { foo as bar }` keeps its local binding
+            if refers_to_declaration(&binding) {
+                if let Ok(name) = specifier.name() {
+                    rename_export_name(&mut batch, &name, declaration, new_name);
                 }
             }
-            Err(err) => Err(WorkspaceError::RenameError(err)),
         }
+    }
+
+    // `export { foo } from "./foo"` re-exports the symbol without binding it
+    for specifier in root
+        .syntax()
+        .descendants()
+        .filter_map(JsExportNamedFromSpecifier::cast)
+    {
+        let Ok(name) = specifier.source_name() else {
+            continue;
+        };
+        let Some(source) = specifier
+            .syntax()
+            .ancestors()
+            .find_map(JsExportNamedFromClause::cast)
+            .and_then(|clause| clause.source().ok())
+            .and_then(|source| source.as_js_module_source().cloned())
+        else {
+            continue;
+        };
+        let Some(resolved_path) = module_info.as_ref().and_then(|module_info| {
+            module_info
+                .get_import_path_by_js_node(&AnyJsImportLike::JsModuleSource(source))?
+                .as_path()
+                .map(Utf8Path::to_path_buf)
+        }) else {
+            continue;
+        };
+
+        let reexported = module_graph
+            .find_exported_symbol_declaration(&resolved_path, &declaration.name)
+            .is_some_and(|(path, range)| {
+                path == declaration.location.path.as_path() && range == declaration.location.range
+            });
+        if reexported {
+            rename_export_name(&mut batch, &name, declaration, new_name);
+        }
+    }
+
+    Ok(batch.to_text_range_and_edit())
+}
+
+/// Renames an imported or re-exported name, unless it refers to the
+/// declaration through another name.
+fn rename_export_name(
+    batch: &mut BatchMutation<JsLanguage>,
+    name: &JsLiteralExportName,
+    declaration: &RenameDeclaration,
+    new_name: &str,
+) {
+    let Ok(token) = name.value() else {
+        return;
+    };
+    if inner_string_text(&token) != declaration.name.as_str() {
+        return;
+    }
+    // `export { "foo" }` keeps the name as a string literal, with its quotes
+    let text = if token.kind() == JsSyntaxKind::JS_STRING_LITERAL {
+        let quote = &token.text_trimmed()[..1];
+        format!("{quote}{new_name}{quote}")
     } else {
-        Err(WorkspaceError::RenameError(
-            RenameError::CannotFindDeclaration(new_name),
-        ))
+        new_name.to_string()
+    };
+    batch.replace_token(
+        token.clone(),
+        JsSyntaxToken::new_detached(token.kind(), &text, [], []),
+    );
+}
+
+fn is_shorthand_import(binding: &Binding) -> bool {
+    binding
+        .syntax()
+        .parent()
+        .is_some_and(|parent| JsShorthandNamedImportSpecifier::can_cast(parent.kind()))
+}
+
+fn hover(
+    path: &BiomePath,
+    parse: AnyParse,
+    module_graph: Arc<ModuleGraph>,
+    symbol_at: TextSize,
+) -> HoverResult {
+    let Some(node) = identifier_at(&parse, symbol_at) else {
+        return HoverResult::default();
+    };
+    let Some(module_info) = module_graph.module_info_for_path(path) else {
+        return HoverResult::default();
+    };
+
+    let mut resolver = ScopedResolver::from_global_scope(module_info, module_graph);
+    resolver.run_inference();
+    let resolver = Arc::new(resolver);
+
+    let ty = if AnyJsIdentifierBinding::can_cast(node.kind()) {
+        resolver.resolved_type_for_binding(node.text_trimmed_range())
+    } else if let Some(expression) =
+        node.parent()
+            .and_then(AnyJsExpression::cast)
+            .filter(|expression| {
+                expression.as_js_identifier_expression().is_some()
+                    || expression.as_js_static_member_expression().is_some()
+            })
+    {
+        Some(resolver.resolved_type_for_expression(&expression))
+    } else {
+        let root = parse.tree();
+        let model = semantic_model(&root, SemanticModelOptions::default());
+        binding_of_identifier(&model, &node).and_then(|binding| {
+            resolver.resolved_type_for_binding(binding.syntax().text_trimmed_range())
+        })
+    };
+
+    HoverResult {
+        range: Some(node.text_trimmed_range()),
+        contents: ty
+            .filter(|ty| ty.is_inferred())
+            .map(|ty| format!("```ts\n{ty}\n```")),
+    }
+}
+
+fn goto_definition(
+    path: &BiomePath,
+    parse: AnyParse,
+    module_graph: &ModuleGraph,
+    symbol_at: TextSize,
+) -> Option<SymbolLocation> {
+    let module_info = module_graph.module_info_for_path(path);
+    let node = identifier_at(&parse, symbol_at)?;
+    if let Some(source) = JsModuleSource::cast_ref(&node) {
+        let resolved_path = module_info?
+            .get_import_path_by_js_node(&AnyJsImportLike::JsModuleSource(source))?
+            .as_path()?
+            .to_path_buf();
+        return Some(SymbolLocation {
+            path: BiomePath::new(resolved_path),
+            range: TextRange::default(),
+        });
+    }
+
+    let root = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let binding = binding_of_identifier(&model, &node)?;
+
+    Some(declaration_location(
+        path,
+        module_info.as_ref(),
+        module_graph,
+        &binding,
+    ))
+}
+
+fn find_references(
+    path: &BiomePath,
+    parse: AnyParse,
+    module_graph: &ModuleGraph,
+    definition: &SymbolLocation,
+    include_declaration: bool,
+) -> Vec<TextRange> {
+    let root = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let module_info = module_graph.module_info_for_path(path);
+
+    let mut references = Vec::new();
+    for binding in model.all_bindings() {
+        if declaration_location(path, module_info.as_ref(), module_graph, &binding) != *definition {
+            continue;
+        }
+
+        // Imports are references to the declaration of another module
+        if include_declaration || binding.is_imported() {
+            references.push(binding.syntax().text_trimmed_range());
+        }
+        references.extend(
+            binding
+                .all_references()
+                .map(|reference| reference.syntax().text_trimmed_range()),
+        );
+    }
+
+    references.sort_by_key(|range| range.start());
+    references
+}
+
+/// Returns the identifier at the given `offset`, which is either a binding, a
+/// reference, a member name or an import source.
+fn identifier_at(parse: &AnyParse, offset: TextSize) -> Option<JsSyntaxNode> {
+    parse
+        .syntax::<JsLanguage>()
+        .token_at_offset(offset)
+        .filter(|token| token.text_trimmed_range().contains_inclusive(offset))
+        .filter_map(|token| token.parent())
+        .find(|node| {
+            AnyJsIdentifierBinding::can_cast(node.kind())
+                || AnyJsIdentifierUsage::can_cast(node.kind())
+                || JsName::can_cast(node.kind())
+                || JsModuleSource::can_cast(node.kind())
+        })
+}
+
+/// Returns the binding declared or referenced by the identifier `node`
+fn binding_of_identifier(model: &SemanticModel, node: &JsSyntaxNode) -> Option<Binding> {
+    if AnyJsIdentifierBinding::can_cast(node.kind()) {
+        let range = node.text_trimmed_range();
+        return model
+            .all_bindings()
+            .find(|binding| binding.syntax().text_trimmed_range() == range);
+    }
+
+    match AnyJsIdentifierUsage::cast_ref(node)? {
+        AnyJsIdentifierUsage::JsReferenceIdentifier(reference) => model.binding(&reference),
+        AnyJsIdentifierUsage::JsIdentifierAssignment(assignment) => model.binding(&assignment),
+        AnyJsIdentifierUsage::JsxReferenceIdentifier(reference) => model.binding(&reference),
+    }
+}
+
+/// Returns the location where `binding` is declared.
+///
+/// Imported bindings are followed through the module graph, up to the module
+/// that declares the imported symbol. If the symbol can't be found, the
+/// location is the start of the imported module.
+fn declaration_location(
+    path: &BiomePath,
+    module_info: Option<&JsModuleInfo>,
+    module_graph: &ModuleGraph,
+    binding: &Binding,
+) -> SymbolLocation {
+    let local_location = SymbolLocation {
+        path: path.clone(),
+        range: binding.syntax().text_trimmed_range(),
+    };
+    if !binding.is_imported() {
+        return local_location;
+    }
+
+    let Some(import) = module_info.and_then(|module_info| {
+        let name = binding.tree().name_token().ok()?;
+        module_info.static_imports.get(name.text_trimmed()).cloned()
+    }) else {
+        return local_location;
+    };
+    let Some(resolved_path) = import.resolved_path.as_path() else {
+        return local_location;
+    };
+
+    let symbol_name = match &import.symbol {
+        ImportSymbol::Default => Some("default"),
+        ImportSymbol::Named(name) => Some(name.text()),
+        ImportSymbol::All => None,
+    };
+    match symbol_name.and_then(|symbol_name| {
+        module_graph.find_exported_symbol_declaration(resolved_path, symbol_name)
+    }) {
+        Some((path, range)) => SymbolLocation {
+            path: BiomePath::new(path),
+            range,
+        },
+        None => SymbolLocation {
+            path: BiomePath::new(resolved_path),
+            range: TextRange::default(),
+        },
     }
 }
 
@@ -1,6 +1,6 @@
 use super::{
     AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource, EnabledForPath,
-    ExtensionHandler, ParseResult, ProcessLint, SearchCapabilities, is_diagnostic_error,
+    ExtensionHandler, ParseResult, ProcessLint, SearchCapabilities, is_diagnostic_error, search,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::DebugCapabilities;
@@ -9,8 +9,9 @@ use crate::file_handlers::{
     LintResults, ParserCapabilities,
 };
 use crate::settings::{
-    FormatSettings, LanguageListSettings, LanguageSettings, OverrideSettings, ServiceLanguage,
-    Settings, WorkspaceSettingsHandle, check_feature_activity, check_override_feature_activity,
+    FormatSettings, Includes, LanguageListSettings, LanguageSettings, OverrideSettings,
+    ServiceLanguage, Settings, WorkspaceSettingsHandle, check_feature_activity,
+    check_override_feature_activity,
 };
 use crate::workspace::{
     CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
@@ -32,7 +33,7 @@ use biome_formatter::{
     BracketSpacing, Expand, FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed,
 };
 use biome_fs::{BiomePath, ConfigName};
-use biome_json_analyze::analyze;
+use biome_json_analyze::{JsonAnalyzerServices, analyze};
 use biome_json_formatter::context::{JsonFormatOptions, TrailingCommas};
 use biome_json_formatter::format_node;
 use biome_json_parser::JsonParserOptions;
@@ -40,7 +41,7 @@ use biome_json_syntax::{JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxNode};
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
-use camino::Utf8Path;
+use camino::{Utf8Path, Utf8PathBuf};
 use std::borrow::Cow;
 use tracing::{debug_span, error, instrument};
 
@@ -116,13 +117,27 @@ impl From<JsonAssistConfiguration> for JsonAssistSettings {
     }
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct JsonEnvironmentSettings {
+    /// JSON Schemas associated with the files they apply to
+    pub schemas: Vec<JsonSchemaSettings>,
+}
+
+#[derive(Clone, Debug)]
+pub struct JsonSchemaSettings {
+    /// The files the schema applies to
+    pub includes: Includes,
+    /// The path to the schema, resolved against the configuration file
+    pub schema: Utf8PathBuf,
+}
+
 impl ServiceLanguage for JsonLanguage {
     type FormatterSettings = JsonFormatterSettings;
     type LinterSettings = JsonLinterSettings;
     type FormatOptions = JsonFormatOptions;
     type ParserSettings = JsonParserSettings;
     type AssistSettings = JsonAssistSettings;
-    type EnvironmentSettings = ();
+    type EnvironmentSettings = JsonEnvironmentSettings;
 
     fn lookup_settings(language: &LanguageListSettings) -> &LanguageSettings<Self> {
         &language.json
@@ -321,7 +336,10 @@ impl ExtensionHandler for JsonFileHandler {
                 assist: Some(assist_enabled),
                 linter: Some(linter_enabled),
             },
-            parser: ParserCapabilities { parse: Some(parse) },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                reparse: None,
+            },
             debug: DebugCapabilities {
                 debug_syntax_tree: Some(debug_syntax_tree),
                 debug_control_flow: None,
@@ -333,7 +351,11 @@ impl ExtensionHandler for JsonFileHandler {
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
+                rename_declaration: None,
                 rename: None,
+                hover: None,
+                goto_definition: None,
+                find_references: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
@@ -341,7 +363,9 @@ impl ExtensionHandler for JsonFileHandler {
                 format_range: Some(format_range),
                 format_on_type: Some(format_on_type),
             },
-            search: SearchCapabilities { search: None },
+            search: SearchCapabilities {
+                search: Some(search),
+            },
         }
     }
 }
@@ -387,6 +411,7 @@ fn parse(
                 || optional_json_file_source.is_some_and(|x| x.allow_trailing_commas()),
                 |value| value.value(),
             ),
+            ..Default::default()
         };
         if let Some(overrides) = overrides {
             overrides.to_override_json_parser_options(biome_path, options)
@@ -536,10 +561,14 @@ fn lint(params: LintParams) -> LintResults {
 
     let mut process_lint = ProcessLint::new(&params);
 
-    let (_, analyze_diagnostics) =
-        analyze(&root, filter, &analyzer_options, file_source, |signal| {
-            process_lint.process_signal(signal)
-        });
+    let (_, analyze_diagnostics) = analyze(
+        &root,
+        filter,
+        &analyzer_options,
+        &params.plugins,
+        JsonAnalyzerServices::from(file_source).with_schema(params.json_schema.clone()),
+        |signal| process_lint.process_signal(signal),
+    );
 
     let mut diagnostics = params.parse.into_diagnostics();
     // if we're parsing the `biome.json` file, we deserialize it, so we can emit diagnostics for
@@ -573,8 +602,10 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         only,
         enabled_rules: rules,
         suppression_reason,
-        plugins: _,
+        plugins,
         categories,
+        json_schema,
+        graphql_schema: _,
     } = params;
 
     let _ = debug_span!("Code actions JSON",  range =? range, path =? path).entered();
@@ -606,19 +637,26 @@ fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         return PullActionsResult { actions: vec![] };
     };
 
-    analyze(&tree, filter, &analyzer_options, file_source, |signal| {
-        actions.extend(signal.actions().into_code_action_iter().map(|item| {
-            CodeAction {
-                category: item.category.clone(),
-                rule_name: item
-                    .rule_name
-                    .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
-                suggestion: item.suggestion,
-            }
-        }));
+    analyze(
+        &tree,
+        filter,
+        &analyzer_options,
+        &plugins,
+        JsonAnalyzerServices::from(file_source).with_schema(json_schema),
+        |signal| {
+            actions.extend(signal.actions().into_code_action_iter().map(|item| {
+                CodeAction {
+                    category: item.category.clone(),
+                    rule_name: item
+                        .rule_name
+                        .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                    suggestion: item.suggestion,
+                }
+            }));
 
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        },
+    );
 
     PullActionsResult { actions }
 }
@@ -671,48 +709,55 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     let mut errors: u16 = 0;
 
     loop {
-        let (action, _) = analyze(&tree, filter, &analyzer_options, file_source, |signal| {
-            let current_diagnostic = signal.diagnostic();
-
-            if let Some(diagnostic) = current_diagnostic.as_ref() {
-                if is_diagnostic_error(diagnostic, rules.as_deref()) {
-                    errors += 1;
+        let (action, _) = analyze(
+            &tree,
+            filter,
+            &analyzer_options,
+            &params.plugins,
+            JsonAnalyzerServices::from(file_source).with_schema(params.json_schema.clone()),
+            |signal| {
+                let current_diagnostic = signal.diagnostic();
+
+                if let Some(diagnostic) = current_diagnostic.as_ref() {
+                    if is_diagnostic_error(diagnostic, rules.as_deref()) {
+                        errors += 1;
+                    }
                 }
-            }
 
-            for action in signal.actions() {
-                // suppression actions should not be part of the fixes (safe or suggested)
-                if action.is_suppression() {
-                    continue;
-                }
+                for action in signal.actions() {
+                    // suppression actions should not be part of the fixes (safe or suggested)
+                    if action.is_suppression() {
+                        continue;
+                    }
 
-                match params.fix_file_mode {
-                    FixFileMode::SafeFixes => {
-                        if action.applicability == Applicability::MaybeIncorrect {
-                            skipped_suggested_fixes += 1;
+                    match params.fix_file_mode {
+                        FixFileMode::SafeFixes => {
+                            if action.applicability == Applicability::MaybeIncorrect {
+                                skipped_suggested_fixes += 1;
+                            }
+                            if action.applicability == Applicability::Always {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                        if action.applicability == Applicability::Always {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::SafeAndUnsafeFixes => {
+                            if matches!(
+                                action.applicability,
+                                Applicability::Always | Applicability::MaybeIncorrect
+                            ) {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
                         }
-                    }
-                    FixFileMode::SafeAndUnsafeFixes => {
-                        if matches!(
-                            action.applicability,
-                            Applicability::Always | Applicability::MaybeIncorrect
-                        ) {
-                            errors = errors.saturating_sub(1);
-                            return ControlFlow::Break(action);
+                        FixFileMode::ApplySuppressions => {
+                            // TODO: implement once a JSON suppression action is available
                         }
                     }
-                    FixFileMode::ApplySuppressions => {
-                        // TODO: implement once a JSON suppression action is available
-                    }
                 }
-            }
 
-            ControlFlow::Continue(())
-        });
+                ControlFlow::Continue(())
+            },
+        );
 
         match action {
             Some(action) => {
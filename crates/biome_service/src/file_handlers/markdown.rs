@@ -0,0 +1,392 @@
+use super::{
+    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, EnabledForPath,
+    ExtensionHandler, Features, FixAllParams, FormatterCapabilities, LintParams, LintResults,
+    ParseResult, ParserCapabilities, SearchCapabilities,
+};
+use crate::settings::{check_feature_activity, check_override_feature_activity};
+use crate::workspace::FixFileResult;
+use crate::{
+    WorkspaceError,
+    settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
+    workspace::GetSyntaxTreeResult,
+};
+use biome_analyze::AnalyzerOptions;
+use biome_configuration::markdown::{MarkdownFormatterConfiguration, MarkdownFormatterEnabled};
+use biome_diagnostics::{Diagnostic, Severity};
+use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
+use biome_fs::BiomePath;
+use biome_js_syntax::EmbeddingKind;
+use biome_markdown_formatter::context::{FormattedCodeBlocks, MarkdownFormatOptions};
+use biome_markdown_formatter::format_node_with_code_blocks;
+use biome_markdown_parser::parse_markdown_with_cache;
+use biome_markdown_syntax::{MarkdownLanguage, MarkdownSyntaxNode, MdDocument, MdFencedCodeBlock};
+use biome_parser::AnyParse;
+use biome_rowan::{AstNode, NodeCache};
+use camino::Utf8Path;
+use tracing::debug_span;
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MarkdownFormatterSettings {
+    pub enabled: Option<MarkdownFormatterEnabled>,
+    pub line_ending: Option<LineEnding>,
+    pub line_width: Option<LineWidth>,
+    pub indent_width: Option<IndentWidth>,
+    pub indent_style: Option<IndentStyle>,
+}
+
+impl From<MarkdownFormatterConfiguration> for MarkdownFormatterSettings {
+    fn from(config: MarkdownFormatterConfiguration) -> Self {
+        Self {
+            enabled: config.enabled,
+            line_ending: config.line_ending,
+            line_width: config.line_width,
+            indent_width: config.indent_width,
+            indent_style: config.indent_style,
+        }
+    }
+}
+
+impl ServiceLanguage for MarkdownLanguage {
+    type FormatterSettings = MarkdownFormatterSettings;
+    type LinterSettings = ();
+    type FormatOptions = MarkdownFormatOptions;
+    type ParserSettings = ();
+    type EnvironmentSettings = ();
+    type AssistSettings = ();
+
+    fn lookup_settings(
+        languages: &crate::settings::LanguageListSettings,
+    ) -> &crate::settings::LanguageSettings<Self> {
+        &languages.markdown
+    }
+
+    fn resolve_format_options(
+        global: Option<&crate::settings::FormatSettings>,
+        overrides: Option<&crate::settings::OverrideSettings>,
+        language: Option<&Self::FormatterSettings>,
+        path: &BiomePath,
+        file_source: &DocumentFileSource,
+    ) -> Self::FormatOptions {
+        let indent_style = language
+            .and_then(|l| l.indent_style)
+            .or(global.and_then(|g| g.indent_style))
+            .unwrap_or_default();
+        let line_width = language
+            .and_then(|l| l.line_width)
+            .or(global.and_then(|g| g.line_width))
+            .unwrap_or_default();
+        let indent_width = language
+            .and_then(|l| l.indent_width)
+            .or(global.and_then(|g| g.indent_width))
+            .unwrap_or_default();
+        let line_ending = language
+            .and_then(|l| l.line_ending)
+            .or(global.and_then(|g| g.line_ending))
+            .unwrap_or_default();
+
+        let options =
+            MarkdownFormatOptions::new(file_source.to_markdown_file_source().unwrap_or_default())
+                .with_indent_style(indent_style)
+                .with_indent_width(indent_width)
+                .with_line_width(line_width)
+                .with_line_ending(line_ending);
+        if let Some(overrides) = overrides {
+            overrides.to_override_markdown_format_options(path, options)
+        } else {
+            options
+        }
+    }
+
+    fn resolve_analyzer_options(
+        _global: Option<&Settings>,
+        _language: Option<&Self::LinterSettings>,
+        _environment: Option<&Self::EnvironmentSettings>,
+        path: &BiomePath,
+        _file_source: &DocumentFileSource,
+        suppression_reason: Option<&str>,
+    ) -> AnalyzerOptions {
+        AnalyzerOptions::default()
+            .with_file_path(path.as_path())
+            .with_suppression_reason(suppression_reason)
+    }
+
+    fn formatter_enabled_for_file_path(settings: Option<&Settings>, path: &Utf8Path) -> bool {
+        settings
+            .and_then(|settings| {
+                let overrides_activity =
+                    settings
+                        .override_settings
+                        .patterns
+                        .iter()
+                        .rev()
+                        .find_map(|pattern| {
+                            check_override_feature_activity(
+                                pattern.languages.markdown.formatter.enabled,
+                                pattern.formatter.enabled,
+                            )
+                            .filter(|_| {
+                                // Then check whether the path satisfies
+                                pattern.is_file_included(path)
+                            })
+                        });
+
+                overrides_activity.or(check_feature_activity(
+                    settings.languages.markdown.formatter.enabled,
+                    settings.formatter.enabled,
+                ))
+            })
+            .unwrap_or_default()
+            .into()
+    }
+
+    fn assist_enabled_for_file_path(_settings: Option<&Settings>, _path: &Utf8Path) -> bool {
+        false
+    }
+
+    fn linter_enabled_for_file_path(_settings: Option<&Settings>, _path: &Utf8Path) -> bool {
+        false
+    }
+
+    fn resolve_environment(_settings: Option<&Settings>) -> Option<&Self::EnvironmentSettings> {
+        None
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct MarkdownFileHandler;
+
+impl ExtensionHandler for MarkdownFileHandler {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            enabled_for_path: EnabledForPath {
+                formatter: Some(formatter_enabled),
+                linter: Some(linter_enabled),
+                assist: Some(assist_enabled),
+                search: Some(search_enabled),
+            },
+            parser: ParserCapabilities {
+                parse: Some(parse),
+                reparse: None,
+            },
+            debug: DebugCapabilities {
+                debug_syntax_tree: Some(debug_syntax_tree),
+                debug_control_flow: None,
+                debug_formatter_ir: Some(debug_formatter_ir),
+                debug_type_info: None,
+                debug_registered_types: None,
+                debug_semantic_model: None,
+            },
+            analyzer: AnalyzerCapabilities {
+                lint: Some(lint),
+                code_actions: None,
+                rename_declaration: None,
+                rename: None,
+                hover: None,
+                goto_definition: None,
+                find_references: None,
+                fix_all: Some(fix_all),
+            },
+            formatter: FormatterCapabilities {
+                format: Some(format),
+                format_range: None,
+                format_on_type: None,
+            },
+            search: SearchCapabilities { search: None },
+        }
+    }
+}
+
+fn formatter_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+    handle.formatter_enabled_for_file_path::<MarkdownLanguage>(path)
+}
+
+fn linter_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+    handle.linter_enabled_for_file_path::<MarkdownLanguage>(path)
+}
+
+fn assist_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+    handle.assist_enabled_for_file_path::<MarkdownLanguage>(path)
+}
+
+fn search_enabled(_path: &Utf8Path, _handle: &WorkspaceSettingsHandle) -> bool {
+    true
+}
+
+fn parse(
+    _biome_path: &BiomePath,
+    file_source: DocumentFileSource,
+    text: &str,
+    _handle: WorkspaceSettingsHandle,
+    cache: &mut NodeCache,
+) -> ParseResult {
+    let parse = parse_markdown_with_cache(text, cache);
+
+    ParseResult {
+        any_parse: parse.into(),
+        language: Some(file_source),
+    }
+}
+
+fn debug_syntax_tree(_biome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeResult {
+    let syntax: MarkdownSyntaxNode = parse.syntax();
+    let tree: MdDocument = parse.tree();
+    GetSyntaxTreeResult {
+        cst: format!("{syntax:#?}"),
+        ast: format!("{tree:#?}"),
+    }
+}
+
+fn debug_formatter_ir(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<String, WorkspaceError> {
+    let options = settings.format_options::<MarkdownLanguage>(path, document_file_source);
+
+    let tree = parse.syntax();
+    let code_blocks = format_code_blocks(path, &tree, &settings);
+    let formatted = format_node_with_code_blocks(options, &tree, code_blocks)?;
+
+    let root_element = formatted.into_document();
+    Ok(root_element.to_string())
+}
+
+#[tracing::instrument(level = "debug", skip(parse, settings))]
+fn format(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<MarkdownLanguage>(biome_path, document_file_source);
+
+    let tree = parse.syntax();
+    let code_blocks = format_code_blocks(biome_path, &tree, &settings);
+    let formatted = format_node_with_code_blocks(options, &tree, code_blocks)?;
+
+    match formatted.print() {
+        Ok(printed) => Ok(printed),
+        Err(error) => Err(WorkspaceError::FormatError(error.into())),
+    }
+}
+
+/// Formats the code of the fenced code blocks written in a language that Biome can format.
+///
+/// Code blocks are left untouched when their language isn't supported, when the
+/// formatter is disabled for their language, or when their code contains syntax errors.
+fn format_code_blocks(
+    biome_path: &BiomePath,
+    tree: &MarkdownSyntaxNode,
+    settings: &WorkspaceSettingsHandle,
+) -> FormattedCodeBlocks {
+    let features = Features::new();
+    let mut code_blocks = FormattedCodeBlocks::default();
+
+    for code_block in tree.descendants().filter_map(MdFencedCodeBlock::cast) {
+        let (Some(mut language), Some(code_token), Some(code)) = (
+            code_block.language(),
+            code_block.code_token(),
+            code_block.code(),
+        ) else {
+            continue;
+        };
+
+        language.make_ascii_lowercase();
+        let file_source = DocumentFileSource::from_extension(&language)
+            .or(DocumentFileSource::from_language_id(&language));
+        if !can_format_code_block(&file_source) {
+            continue;
+        }
+
+        let capabilities = features.get_capabilities(file_source);
+        let (Some(parse), Some(format), Some(formatter_enabled)) = (
+            capabilities.parser.parse,
+            capabilities.formatter.format,
+            capabilities.enabled_for_path.formatter,
+        ) else {
+            continue;
+        };
+        if !formatter_enabled(biome_path, settings) {
+            continue;
+        }
+
+        let parsed = parse(
+            biome_path,
+            file_source,
+            &code,
+            settings.settings().cloned().into(),
+            &mut NodeCache::default(),
+        );
+        if parsed.any_parse.has_errors() {
+            continue;
+        }
+
+        let settings = settings.settings().cloned().into();
+        if let Ok(printed) = format(biome_path, &file_source, parsed.any_parse, settings) {
+            code_blocks.insert(code_token.text_trimmed_range(), printed.into_code());
+        }
+    }
+
+    code_blocks
+}
+
+/// Whether the code of a fenced code block written in this language can be formatted
+fn can_format_code_block(file_source: &DocumentFileSource) -> bool {
+    match file_source {
+        DocumentFileSource::Js(source) => matches!(source.as_embedding_kind(), EmbeddingKind::None),
+        DocumentFileSource::Json(_)
+        | DocumentFileSource::Css(_)
+        | DocumentFileSource::Graphql(_) => true,
+        DocumentFileSource::Html(_)
+        | DocumentFileSource::Grit(_)
+        | DocumentFileSource::Markdown(_)
+        | DocumentFileSource::Yaml(_)
+        | DocumentFileSource::Ignore
+        | DocumentFileSource::Unknown => false,
+    }
+}
+
+#[tracing::instrument(level = "debug", skip(params))]
+fn lint(params: LintParams) -> LintResults {
+    let _ = debug_span!("Linting Markdown file", path =? params.path, language =? params.language)
+        .entered();
+    let diagnostics = params.parse.into_diagnostics();
+
+    let diagnostic_count = diagnostics.len() as u32;
+    let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
+    let errors = diagnostics
+        .iter()
+        .filter(|diag| diag.severity() <= Severity::Error)
+        .count();
+
+    LintResults {
+        diagnostics,
+        errors,
+        skipped_diagnostics,
+    }
+}
+
+#[tracing::instrument(level = "debug", skip(params))]
+pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    // We don't have analyzer rules yet
+    let tree: MdDocument = params.parse.tree();
+    let code = if params.should_format {
+        let options = params
+            .workspace
+            .format_options::<MarkdownLanguage>(params.biome_path, &params.document_file_source);
+        let code_blocks = format_code_blocks(params.biome_path, tree.syntax(), &params.workspace);
+        format_node_with_code_blocks(options, tree.syntax(), code_blocks)?
+            .print()?
+            .into_code()
+    } else {
+        tree.syntax().to_string()
+    };
+    Ok(FixFileResult {
+        code,
+        skipped_suggested_fixes: 0,
+        actions: vec![],
+        errors: 0,
+    })
+}
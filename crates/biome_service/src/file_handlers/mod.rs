@@ -7,10 +7,11 @@ use crate::diagnostics::{QueryDiagnostic, SearchError};
 pub use crate::file_handlers::astro::{ASTRO_FENCE, AstroFileHandler};
 use crate::file_handlers::graphql::GraphqlFileHandler;
 pub use crate::file_handlers::svelte::{SVELTE_FENCE, SvelteFileHandler};
-pub use crate::file_handlers::vue::{VUE_FENCE, VueFileHandler};
+pub use crate::file_handlers::vue::VueFileHandler;
 use crate::settings::{Settings, WorkspaceSettingsHandle};
 use crate::workspace::{
-    FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult,
+    FixFileMode, FixFileResult, GetSyntaxTreeResult, HoverResult, PullActionsResult, SearchResults,
+    SymbolLocation,
 };
 use biome_analyze::{
     AnalyzerDiagnostic, AnalyzerOptions, AnalyzerPluginVec, AnalyzerSignal, ControlFlow,
@@ -27,22 +28,28 @@ use biome_diagnostics::{Diagnostic, DiagnosticExt, Severity, category};
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
 use biome_graphql_analyze::METADATA as graphql_metadata;
+use biome_graphql_semantic::GraphqlSchema;
 use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
 use biome_grit_patterns::{GritQuery, GritQueryEffect, GritTargetFile};
 use biome_grit_syntax::file_source::GritFileSource;
-use biome_html_syntax::HtmlFileSource;
+use biome_html_analyze::METADATA as html_metadata;
+use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
 use biome_js_analyze::METADATA as js_metadata;
 use biome_js_parser::{JsParserOptions, parse};
 use biome_js_syntax::{
     EmbeddingKind, JsFileSource, JsLanguage, Language, LanguageVariant, TextRange, TextSize,
 };
 use biome_json_analyze::METADATA as json_metadata;
+use biome_json_schema::JsonSchema;
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
+use biome_markdown_syntax::MarkdownFileSource;
 use biome_module_graph::ModuleGraph;
 use biome_parser::AnyParse;
 use biome_project_layout::ProjectLayout;
 use biome_rowan::{FileSourceError, NodeCache};
 use biome_string_case::StrLikeExtension;
+use biome_text_edit::TextEdit;
+use biome_yaml_syntax::YamlFileSource;
 
 use crate::file_handlers::ignore::IgnoreFileHandler;
 use biome_configuration::vcs::{GIT_IGNORE_FILE_NAME, IGNORE_FILE_NAME};
@@ -51,10 +58,12 @@ use camino::Utf8Path;
 use grit::GritFileHandler;
 use html::HtmlFileHandler;
 pub use javascript::JsFormatterSettings;
+use markdown::MarkdownFileHandler;
 use rustc_hash::FxHashSet;
 use std::borrow::Cow;
 use std::sync::Arc;
 use tracing::instrument;
+use yaml::YamlFileHandler;
 
 mod astro;
 pub(crate) mod css;
@@ -64,9 +73,11 @@ pub(crate) mod html;
 mod ignore;
 pub(crate) mod javascript;
 pub(crate) mod json;
+pub(crate) mod markdown;
 mod svelte;
 mod unknown;
 mod vue;
+pub(crate) mod yaml;
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
@@ -79,6 +90,8 @@ pub enum DocumentFileSource {
     Graphql(GraphqlFileSource),
     Html(HtmlFileSource),
     Grit(GritFileSource),
+    Markdown(MarkdownFileSource),
+    Yaml(YamlFileSource),
     // Ignore files
     Ignore,
     #[default]
@@ -121,6 +134,18 @@ impl From<GritFileSource> for DocumentFileSource {
     }
 }
 
+impl From<MarkdownFileSource> for DocumentFileSource {
+    fn from(value: MarkdownFileSource) -> Self {
+        Self::Markdown(value)
+    }
+}
+
+impl From<YamlFileSource> for DocumentFileSource {
+    fn from(value: YamlFileSource) -> Self {
+        Self::Yaml(value)
+    }
+}
+
 impl From<&Utf8Path> for DocumentFileSource {
     fn from(path: &Utf8Path) -> Self {
         Self::from_path(path)
@@ -141,6 +166,9 @@ impl DocumentFileSource {
         if let Ok(file_source) = GraphqlFileSource::try_from_well_known(path) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = YamlFileSource::try_from_well_known(path) {
+            return Ok(file_source.into());
+        }
 
         Err(FileSourceError::UnknownFileName)
     }
@@ -169,6 +197,12 @@ impl DocumentFileSource {
         if let Ok(file_source) = GritFileSource::try_from_extension(extension) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = MarkdownFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = YamlFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
         Err(FileSourceError::UnknownExtension)
     }
 
@@ -197,6 +231,12 @@ impl DocumentFileSource {
         if let Ok(file_source) = GritFileSource::try_from_language_id(language_id) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = MarkdownFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = YamlFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
         Err(FileSourceError::UnknownLanguageId)
     }
 
@@ -332,12 +372,32 @@ impl DocumentFileSource {
         }
     }
 
+    pub fn to_markdown_file_source(&self) -> Option<MarkdownFileSource> {
+        match self {
+            Self::Markdown(markdown) => Some(*markdown),
+            _ => None,
+        }
+    }
+
+    pub fn to_yaml_file_source(&self) -> Option<YamlFileSource> {
+        match self {
+            Self::Yaml(yaml) => Some(*yaml),
+            _ => None,
+        }
+    }
+
     /// The file can be parsed
     pub fn can_parse(path: &Utf8Path) -> bool {
         let file_source = Self::from(path);
         match file_source {
             Self::Js(_) => true,
-            Self::Css(_) | Self::Graphql(_) | Self::Json(_) | Self::Html(_) | Self::Grit(_) => true,
+            Self::Css(_)
+            | Self::Graphql(_)
+            | Self::Json(_)
+            | Self::Html(_)
+            | Self::Grit(_)
+            | Self::Markdown(_)
+            | Self::Yaml(_) => true,
             Self::Ignore => false,
             Self::Unknown => false,
         }
@@ -352,7 +412,9 @@ impl DocumentFileSource {
             | Self::Graphql(_)
             | Self::Json(_)
             | Self::Html(_)
-            | Self::Grit(_) => true,
+            | Self::Grit(_)
+            | Self::Markdown(_)
+            | Self::Yaml(_) => true,
             Self::Ignore => true,
             Self::Unknown => false,
         }
@@ -387,6 +449,8 @@ impl biome_console::fmt::Display for DocumentFileSource {
             Self::Graphql(_) => fmt.write_markup(markup! { "GraphQL" }),
             Self::Html(_) => fmt.write_markup(markup! { "HTML" }),
             Self::Grit(_) => fmt.write_markup(markup! { "Grit" }),
+            Self::Markdown(_) => fmt.write_markup(markup! { "Markdown" }),
+            Self::Yaml(_) => fmt.write_markup(markup! { "YAML" }),
             Self::Ignore => fmt.write_markup(markup! { "Ignore" }),
             Self::Unknown => fmt.write_markup(markup! { "Unknown" }),
         }
@@ -409,6 +473,10 @@ pub struct FixAllParams<'a> {
     pub(crate) suppression_reason: Option<String>,
     pub(crate) enabled_rules: Vec<RuleSelector>,
     pub(crate) plugins: AnalyzerPluginVec,
+    /// The JSON Schema of the file, if it's a JSON file and one is configured
+    pub(crate) json_schema: Option<Arc<JsonSchema>>,
+    /// The GraphQL schema of the project, if it's a GraphQL file and one is configured
+    pub(crate) graphql_schema: Option<Arc<GraphqlSchema>>,
 }
 
 #[derive(Default)]
@@ -436,10 +504,22 @@ type Parse = fn(
     &mut NodeCache,
 ) -> ParseResult;
 
+type Reparse = fn(
+    &BiomePath,
+    DocumentFileSource,
+    &AnyParse,
+    TextRange,
+    &str,
+    &WorkspaceSettingsHandle,
+) -> Option<AnyParse>;
+
 #[derive(Default)]
 pub struct ParserCapabilities {
     /// Parse a file
     pub(crate) parse: Option<Parse>,
+    /// Update the syntax tree of a file after an edit, without parsing the
+    /// whole file again
+    pub(crate) reparse: Option<Reparse>,
 }
 
 type DebugSyntaxTree = fn(&BiomePath, AnyParse) -> GetSyntaxTreeResult;
@@ -486,6 +566,10 @@ pub(crate) struct LintParams<'a> {
     pub(crate) enabled_rules: Vec<RuleSelector>,
     pub(crate) plugins: AnalyzerPluginVec,
     pub(crate) pull_code_actions: bool,
+    /// The JSON Schema of the file, if it's a JSON file and one is configured
+    pub(crate) json_schema: Option<Arc<JsonSchema>>,
+    /// The GraphQL schema of the project, if it's a GraphQL file and one is configured
+    pub(crate) graphql_schema: Option<Arc<GraphqlSchema>>,
 }
 
 pub(crate) struct LintResults {
@@ -615,12 +699,36 @@ pub(crate) struct CodeActionsParams<'a> {
     pub(crate) enabled_rules: Vec<RuleSelector>,
     pub(crate) plugins: AnalyzerPluginVec,
     pub(crate) categories: RuleCategories,
+    /// The JSON Schema of the file, if it's a JSON file and one is configured
+    pub(crate) json_schema: Option<Arc<JsonSchema>>,
+    /// The GraphQL schema of the project, if it's a GraphQL file and one is configured
+    pub(crate) graphql_schema: Option<Arc<GraphqlSchema>>,
 }
 
 type Lint = fn(LintParams) -> LintResults;
 type CodeActions = fn(CodeActionsParams) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, WorkspaceError>;
-type Rename = fn(&BiomePath, AnyParse, TextSize, String) -> Result<RenameResult, WorkspaceError>;
+type FindRenameDeclaration =
+    fn(&BiomePath, AnyParse, &ModuleGraph, TextSize) -> Option<RenameDeclaration>;
+type Rename = fn(
+    &BiomePath,
+    AnyParse,
+    &ModuleGraph,
+    &RenameDeclaration,
+    &str,
+) -> Result<Option<(TextRange, TextEdit)>, WorkspaceError>;
+type Hover = fn(&BiomePath, AnyParse, Arc<ModuleGraph>, TextSize) -> HoverResult;
+type GotoDefinition = fn(&BiomePath, AnyParse, &ModuleGraph, TextSize) -> Option<SymbolLocation>;
+type FindReferences =
+    fn(&BiomePath, AnyParse, &ModuleGraph, &SymbolLocation, bool) -> Vec<TextRange>;
+
+/// The declaration targeted by a rename operation
+pub(crate) struct RenameDeclaration {
+    /// Name of the declared symbol before the rename
+    pub(crate) name: String,
+    /// Location of the declaration
+    pub(crate) location: SymbolLocation,
+}
 
 #[derive(Default)]
 pub struct AnalyzerCapabilities {
@@ -630,8 +738,16 @@ pub struct AnalyzerCapabilities {
     pub(crate) code_actions: Option<CodeActions>,
     /// Applies fixes to a file
     pub(crate) fix_all: Option<FixAll>,
-    /// It renames a binding inside a file
+    /// It finds the declaration renamed by renaming the symbol at a given position
+    pub(crate) rename_declaration: Option<FindRenameDeclaration>,
+    /// It renames a declaration and its references inside a file
     pub(crate) rename: Option<Rename>,
+    /// It describes the symbol at a given position
+    pub(crate) hover: Option<Hover>,
+    /// It finds the declaration of the symbol at a given position
+    pub(crate) goto_definition: Option<GotoDefinition>,
+    /// It finds the references of a declaration inside a file
+    pub(crate) find_references: Option<FindReferences>,
 }
 
 type Format = fn(
@@ -673,7 +789,7 @@ type Search = fn(
     AnyParse,
     &GritQuery,
     WorkspaceSettingsHandle,
-) -> Result<Vec<TextRange>, WorkspaceError>;
+) -> Result<SearchResults, WorkspaceError>;
 
 #[derive(Default)]
 pub(crate) struct SearchCapabilities {
@@ -709,6 +825,8 @@ pub(crate) struct Features {
     graphql: GraphqlFileHandler,
     html: HtmlFileHandler,
     grit: GritFileHandler,
+    markdown: MarkdownFileHandler,
+    yaml: YamlFileHandler,
     ignore: IgnoreFileHandler,
 }
 
@@ -724,6 +842,8 @@ impl Features {
             graphql: GraphqlFileHandler {},
             html: HtmlFileHandler {},
             grit: GritFileHandler {},
+            markdown: MarkdownFileHandler {},
+            yaml: YamlFileHandler {},
             ignore: IgnoreFileHandler {},
             unknown: UnknownFileHandler::default(),
         }
@@ -743,6 +863,8 @@ impl Features {
             DocumentFileSource::Graphql(_) => self.graphql.capabilities(),
             DocumentFileSource::Html(_) => self.html.capabilities(),
             DocumentFileSource::Grit(_) => self.grit.capabilities(),
+            DocumentFileSource::Markdown(_) => self.markdown.capabilities(),
+            DocumentFileSource::Yaml(_) => self.yaml.capabilities(),
             DocumentFileSource::Ignore => self.ignore.capabilities(),
             DocumentFileSource::Unknown => self.unknown.capabilities(),
         }
@@ -832,24 +954,34 @@ pub(crate) fn search(
     parse: AnyParse,
     query: &GritQuery,
     _settings: WorkspaceSettingsHandle,
-) -> Result<Vec<TextRange>, WorkspaceError> {
+) -> Result<SearchResults, WorkspaceError> {
     let result = query
         .execute(GritTargetFile::new(path.as_path(), parse))
         .map_err(|err| {
             WorkspaceError::SearchError(SearchError::QueryError(QueryDiagnostic(err.to_string())))
         })?;
 
-    let matches = result
-        .effects
-        .into_iter()
-        .flat_map(|result| match result {
-            GritQueryEffect::Match(m) => m.ranges,
-            _ => Vec::new(),
-        })
-        .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into()))
-        .collect();
+    let mut matches = Vec::new();
+    let mut rewritten = None;
+    for effect in result.effects {
+        match effect {
+            GritQueryEffect::Match(m) => matches.extend(m.ranges),
+            GritQueryEffect::Rewrite(rewrite) => {
+                matches.extend(rewrite.original.ranges);
+                rewritten = Some(rewrite.rewritten.content);
+            }
+            GritQueryEffect::CreateFile(_) => {}
+        }
+    }
 
-    Ok(matches)
+    Ok(SearchResults {
+        path: path.clone(),
+        matches: matches
+            .into_iter()
+            .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into()))
+            .collect(),
+        rewritten,
+    })
 }
 
 #[test]
@@ -966,6 +1098,25 @@ impl RegistryVisitor<GraphqlLanguage> for SyntaxVisitor<'_> {
     }
 }
 
+impl RegistryVisitor<HtmlLanguage> for SyntaxVisitor<'_> {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Syntax {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.enabled_rules.push(RuleFilter::Rule(
+            <R::Group as RuleGroup>::NAME,
+            R::METADATA.name,
+        ))
+    }
+}
+
 /// Type meant to register all the lint rules for each language supported by Biome
 ///
 #[derive(Debug)]
@@ -1271,6 +1422,30 @@ impl RegistryVisitor<GraphqlLanguage> for LintVisitor<'_, '_> {
     }
 }
 
+impl RegistryVisitor<HtmlLanguage> for LintVisitor<'_, '_> {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Lint {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_group<G: RuleGroup<Language = HtmlLanguage>>(&mut self) {
+        G::record_rules(self)
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>(
+            html_metadata
+                .find_rule(R::Group::NAME, R::METADATA.name)
+                .map(RuleFilter::from),
+        )
+    }
+}
+
 struct AssistsVisitor<'a, 'b> {
     settings: Option<&'b Settings>,
     enabled_rules: Vec<RuleFilter<'a>>,
@@ -1405,6 +1580,22 @@ impl RegistryVisitor<GraphqlLanguage> for AssistsVisitor<'_, '_> {
     }
 }
 
+impl RegistryVisitor<HtmlLanguage> for AssistsVisitor<'_, '_> {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Action {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>();
+    }
+}
+
 pub(crate) struct AnalyzerVisitorBuilder<'a> {
     settings: Option<&'a Settings>,
     only: Option<&'a [RuleSelector]>,
@@ -1477,6 +1668,7 @@ impl<'b> AnalyzerVisitorBuilder<'b> {
         biome_css_analyze::visit_registry(&mut syntax);
         biome_json_analyze::visit_registry(&mut syntax);
         biome_graphql_analyze::visit_registry(&mut syntax);
+        biome_html_analyze::visit_registry(&mut syntax);
         enabled_rules.extend(syntax.enabled_rules);
 
         let package_json = self
@@ -1497,6 +1689,7 @@ impl<'b> AnalyzerVisitorBuilder<'b> {
         biome_css_analyze::visit_registry(&mut lint);
         biome_json_analyze::visit_registry(&mut lint);
         biome_graphql_analyze::visit_registry(&mut lint);
+        biome_html_analyze::visit_registry(&mut lint);
         let (linter_enabled_rules, linter_disabled_rules) = lint.finish();
         enabled_rules.extend(linter_enabled_rules);
         disabled_rules.extend(linter_disabled_rules);
@@ -1507,6 +1700,7 @@ impl<'b> AnalyzerVisitorBuilder<'b> {
         biome_css_analyze::visit_registry(&mut assist);
         biome_json_analyze::visit_registry(&mut assist);
         biome_graphql_analyze::visit_registry(&mut assist);
+        biome_html_analyze::visit_registry(&mut assist);
         let (assists_enabled_rules, assists_disabled_rules) = assist.finish();
         enabled_rules.extend(assists_enabled_rules);
         disabled_rules.extend(assists_disabled_rules);
@@ -1,19 +1,32 @@
 use crate::WorkspaceError;
+use crate::file_handlers::javascript::EmbeddedNames;
 use crate::file_handlers::{
     AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities, EnabledForPath,
     ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities, javascript,
+    ParserCapabilities, css, javascript,
 };
 use crate::settings::WorkspaceSettingsHandle;
-use crate::workspace::{DocumentFileSource, FixFileResult, PullActionsResult};
-use biome_formatter::Printed;
+use crate::workspace::{
+    CodeAction, DocumentFileSource, FixAction, FixFileResult, PullActionsResult,
+};
+use biome_analyze::{RuleCategories, RuleCategory};
+use biome_css_syntax::CssFileSource;
+use biome_diagnostics::{CodeSuggestion, Diagnostic, Severity};
+use biome_formatter::{FormatError, Printed};
 use biome_fs::BiomePath;
-use biome_js_parser::{JsParserOptions, parse_js_with_cache};
-use biome_js_syntax::{EmbeddingKind, JsFileSource, TextRange, TextSize};
+use biome_html_parser::{HtmlParserOptions, parse_html, parse_html_with_cache};
+use biome_html_syntax::element_ext::AnyHtmlTagElement;
+use biome_html_syntax::{HtmlContent, HtmlElement, HtmlRoot};
+use biome_js_parser::{JsParserOptions, parse, parse_js_with_cache};
+use biome_js_semantic::{SemanticModelOptions, semantic_model};
+use biome_js_syntax::{
+    AnyJsRoot, EmbeddingKind, JsFileSource, JsReferenceIdentifier, TextRange, TextSize,
+};
 use biome_parser::AnyParse;
-use biome_rowan::NodeCache;
-use regex::{Match, Regex};
-use std::sync::LazyLock;
+use biome_rowan::{AstNode, AstNodeList, NodeCache};
+use biome_string_case::Case;
+use biome_text_edit::TextEdit;
+use camino::Utf8Path;
 use tracing::debug;
 
 use super::{SearchCapabilities, parse_lang_from_script_opening_tag};
@@ -21,61 +34,341 @@ use super::{SearchCapabilities, parse_lang_from_script_opening_tag};
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct VueFileHandler;
 
-// https://regex101.com/r/E4n4hh/6
-pub static VUE_FENCE: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r#"(?ixs)(?<opening><script(?:\s.*?)?>)\r?\n(?<script>(?U:.*))</script>"#).unwrap()
-});
+/// A `<script>` or `<style>` block of a Vue single-file component
+#[derive(Debug)]
+struct VueBlock {
+    /// The range of the content of the block, in the coordinates of the component
+    range: TextRange,
+    /// The language of the content of the block
+    file_source: DocumentFileSource,
+    /// Whether the block is a `<script setup>` block
+    setup: bool,
+}
 
-impl VueFileHandler {
-    /// It extracts the JavaScript/TypeScript code contained in the script block of a Vue file
-    ///
-    /// If there's no script block, an empty string is returned.
-    pub fn input(text: &str) -> &str {
-        match Self::matches_script(text) {
-            Some(script) => &text[script.start()..script.end()],
-            _ => "",
+impl VueBlock {
+    fn is_script(&self) -> bool {
+        matches!(self.file_source, DocumentFileSource::Js(_))
+    }
+
+    /// Parses the content of the block. The resulting tree starts at offset zero.
+    fn parse(
+        &self,
+        path: &BiomePath,
+        source: &str,
+        settings: &WorkspaceSettingsHandle,
+    ) -> AnyParse {
+        let text = &source[self.range];
+        let mut cache = NodeCache::default();
+        match self.file_source {
+            DocumentFileSource::Js(file_source) => {
+                parse_js_with_cache(text, file_source, JsParserOptions::default(), &mut cache)
+                    .into()
+            }
+            _ => css::parse(path, self.file_source, text, settings.clone(), &mut cache).any_parse,
         }
     }
 
-    /// It takes the original content of a Vue file, and new output of an Vue file. The output is only the content contained inside the
-    /// Vue `<script>` tag. The function replaces `output` inside that `<script>`.
-    pub fn output(input: &str, output: &str) -> String {
-        if let Some(script) = Self::matches_script(input) {
-            format!(
-                "{}{}{}",
-                &input[..script.start()],
-                output,
-                &input[script.end()..]
-            )
+    fn formatter_enabled(&self, path: &Utf8Path, settings: &WorkspaceSettingsHandle) -> bool {
+        if self.is_script() {
+            javascript::formatter_enabled(path, settings)
         } else {
-            input.to_string()
+            css::formatter_enabled(path, settings)
         }
     }
 
-    /// Returns the start byte offset of the Vue `<script>` tag
-    pub fn start(input: &str) -> Option<u32> {
-        Self::matches_script(input).map(|m| m.start() as u32)
+    /// Removes the categories that are disabled for the language of the block
+    fn categories(
+        &self,
+        path: &Utf8Path,
+        settings: &WorkspaceSettingsHandle,
+        mut categories: RuleCategories,
+    ) -> RuleCategories {
+        let (linter_enabled, assist_enabled) = if self.is_script() {
+            (
+                javascript::linter_enabled(path, settings),
+                javascript::assist_enabled(path, settings),
+            )
+        } else {
+            (
+                css::linter_enabled(path, settings),
+                css::assist_enabled(path, settings),
+            )
+        };
+        if !linter_enabled {
+            categories.remove(RuleCategory::Lint);
+        }
+        if !assist_enabled {
+            categories.remove(RuleCategory::Action);
+        }
+        categories
     }
+}
 
-    fn matches_script(input: &str) -> Option<Match> {
-        VUE_FENCE
-            .captures(input)
-            .and_then(|captures| captures.name("script"))
+impl VueFileHandler {
+    /// It extracts the JavaScript/TypeScript code contained in the first script block of a Vue file
+    ///
+    /// If there's no script block, an empty string is returned.
+    pub fn input(text: &str) -> &str {
+        let root: HtmlRoot = parse_html(text).tree();
+        Self::blocks(&root)
+            .into_iter()
+            .find(VueBlock::is_script)
+            .map_or("", |block| &text[block.range])
     }
 
+    /// Returns the file source of the first script block of a Vue file
     pub fn file_source(text: &str) -> JsFileSource {
-        VUE_FENCE
-            .captures(text)
-            .and_then(|captures| {
-                let (language, variant) =
-                    parse_lang_from_script_opening_tag(captures.name("opening")?.as_str());
-                Some(
+        Self::script_file_source(&parse_html(text).tree())
+    }
+
+    /// Returns the `<script>` and `<style>` blocks of the component, in source order.
+    ///
+    /// Only the style blocks written in CSS, SCSS or Less are returned.
+    fn blocks(root: &HtmlRoot) -> Vec<VueBlock> {
+        root.html()
+            .iter()
+            .filter_map(|element| {
+                let element = element.as_html_element()?;
+                let opening = element.opening_element().ok()?;
+                let tag = AnyHtmlTagElement::from(opening.clone());
+                let file_source = if tag.has_name("script") {
+                    let (language, variant) = parse_lang_from_script_opening_tag(
+                        opening.syntax().text_trimmed().to_string().as_str(),
+                    );
                     JsFileSource::from(language)
                         .with_variant(variant)
-                        .with_embedding_kind(EmbeddingKind::Vue),
+                        .with_embedding_kind(EmbeddingKind::Vue)
+                        .into()
+                } else if tag.has_name("style") {
+                    let lang = tag
+                        .find_attribute_by_name("lang")
+                        .and_then(|lang| lang.value())
+                        .map(|lang| lang.text().to_string());
+                    match lang.as_deref() {
+                        None | Some("css") => CssFileSource::css().into(),
+                        Some("scss") => CssFileSource::scss().into(),
+                        Some("less") => CssFileSource::less().into(),
+                        Some(_) => return None,
+                    }
+                } else {
+                    return None;
+                };
+
+                Some(VueBlock {
+                    range: Self::content_range(element)?,
+                    file_source,
+                    setup: tag.find_attribute_by_name("setup").is_some(),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the range between the opening and the closing tag of `element`,
+    /// without the line break that follows the opening tag.
+    fn content_range(element: &HtmlElement) -> Option<TextRange> {
+        let start = element
+            .opening_element()
+            .ok()?
+            .syntax()
+            .text_range_with_trivia()
+            .end();
+        let end = element
+            .closing_element()
+            .ok()?
+            .syntax()
+            .text_range_with_trivia()
+            .start();
+        let content = element.children().syntax().text_with_trivia().to_string();
+        let line_break = if content.starts_with("\r\n") {
+            2
+        } else if content.starts_with('\n') {
+            1
+        } else {
+            0
+        };
+
+        Some(TextRange::new(start + TextSize::from(line_break), end))
+    }
+
+    /// Returns the file source of the first `<script>` block of the component.
+    fn script_file_source(root: &HtmlRoot) -> JsFileSource {
+        Self::blocks(root)
+            .into_iter()
+            .find_map(|block| block.file_source.to_js_file_source())
+            .unwrap_or(JsFileSource::js_module().with_embedding_kind(EmbeddingKind::Vue))
+    }
+
+    /// Parses the first `<script>` block of the component.
+    pub fn script_root(root: &HtmlRoot) -> Option<AnyJsRoot> {
+        let source = root.syntax().to_string();
+        Self::blocks(root).into_iter().find_map(|block| {
+            let file_source = block.file_source.to_js_file_source()?;
+            Some(
+                parse(
+                    &source[block.range],
+                    file_source,
+                    JsParserOptions::default(),
                 )
+                .tree(),
+            )
+        })
+    }
+
+    /// Returns the names of the variables and components used by the `<template>`
+    /// block of the component.
+    ///
+    /// The expressions of the directives, such as `:prop`, `@event` or `v-if`, and of
+    /// the `{{ }}` interpolations are parsed as TypeScript to extract their references.
+    fn template_references(root: &HtmlRoot) -> Vec<String> {
+        let Some(template) = root.html().iter().find_map(|element| {
+            let element = element.as_html_element()?.clone();
+            AnyHtmlTagElement::from(element.opening_element().ok()?)
+                .has_name("template")
+                .then_some(element)
+        }) else {
+            return Vec::new();
+        };
+
+        let mut references = Vec::new();
+        for node in template.syntax().descendants() {
+            if let Some(tag) = AnyHtmlTagElement::cast_ref(&node) {
+                if let Some(name) = Self::component_name(&tag) {
+                    references.push(name);
+                }
+                for attribute in tag.attributes() {
+                    let Some(attribute) = attribute.as_html_attribute() else {
+                        continue;
+                    };
+                    let is_directive = attribute
+                        .name()
+                        .and_then(|name| name.value_token())
+                        .is_ok_and(|name| {
+                            let name = name.text_trimmed();
+                            name.starts_with([':', '@', '#']) || name.starts_with("v-")
+                        });
+                    if let (true, Some(value)) = (is_directive, attribute.value()) {
+                        Self::push_expression_references(value.text(), &mut references);
+                    }
+                }
+            } else if let Some(content) = HtmlContent::cast_ref(&node) {
+                let Ok(token) = content.value_token() else {
+                    continue;
+                };
+                let mut text = token.text_trimmed();
+                while let Some(start) = text.find("{{") {
+                    let Some(end) = text[start..].find("}}") else {
+                        break;
+                    };
+                    let expression = &text[start + 2..start + end];
+                    Self::push_expression_references(expression, &mut references);
+                    text = &text[start + end + 2..];
+                }
+            }
+        }
+
+        references
+    }
+
+    /// Returns the names that each block shares with the rest of the component.
+    ///
+    /// The template can reference the bindings of every script block, and a `<script setup>`
+    /// block can reference the bindings of the plain `<script>` block.
+    fn embedded_names(root: &HtmlRoot, blocks: &[(VueBlock, AnyParse)]) -> Vec<EmbeddedNames> {
+        let template_references = Self::template_references(root);
+        let scripts: Vec<_> = blocks
+            .iter()
+            .map(|(block, parse)| {
+                block.is_script().then(|| {
+                    let model = semantic_model(&parse.tree(), SemanticModelOptions::default());
+                    let bindings: Vec<_> = model
+                        .global_scope()
+                        .bindings()
+                        .map(|binding| binding.syntax().text_trimmed().to_string())
+                        .collect();
+                    let references: Vec<_> = model
+                        .all_unresolved_references()
+                        .map(|reference| reference.syntax().text_trimmed().to_string())
+                        .collect();
+                    (bindings, references)
+                })
             })
-            .map_or(JsFileSource::js_module(), |fs| fs)
+            .collect();
+
+        blocks
+            .iter()
+            .map(|(block, _)| {
+                let mut names = EmbeddedNames {
+                    references: template_references.clone(),
+                    bindings: Vec::new(),
+                };
+                for ((other, _), script) in blocks.iter().zip(&scripts) {
+                    let Some((bindings, references)) = script else {
+                        continue;
+                    };
+                    if block.is_script() && !block.setup && other.setup {
+                        names.references.extend(references.iter().cloned());
+                    }
+                    if block.setup && !other.setup {
+                        names.bindings.extend(bindings.iter().cloned());
+                    }
+                }
+                names
+            })
+            .collect()
+    }
+
+    /// Parses the blocks of the component
+    fn parse_blocks(
+        root: &HtmlRoot,
+        path: &BiomePath,
+        source: &str,
+        settings: &WorkspaceSettingsHandle,
+    ) -> Vec<(VueBlock, AnyParse)> {
+        Self::blocks(root)
+            .into_iter()
+            .map(|block| {
+                let parse = block.parse(path, source, settings);
+                (block, parse)
+            })
+            .collect()
+    }
+
+    /// Returns the name of the script binding that `tag` could refer to, if `tag`
+    /// is a component. `<my-component>` can refer to an import named `MyComponent`.
+    fn component_name(tag: &AnyHtmlTagElement) -> Option<String> {
+        let name = tag.name_value_token().ok()?;
+        let name = name.text_trimmed();
+        if name.contains('-') {
+            Some(Case::Pascal.convert(name))
+        } else if name.starts_with(|c: char| c.is_ascii_uppercase()) {
+            Some(name.to_string())
+        } else {
+            None
+        }
+    }
+
+    fn push_expression_references(expression: &str, references: &mut Vec<String>) {
+        let file_source = JsFileSource::ts();
+        // Most expressions are parsed inside parentheses, so that an object
+        // literal isn't parsed as a block. Handlers can contain statements, though.
+        let mut parse = parse(
+            &format!("({expression})"),
+            file_source,
+            JsParserOptions::default(),
+        );
+        if parse.has_errors() {
+            parse = biome_js_parser::parse(expression, file_source, JsParserOptions::default());
+        }
+
+        references.extend(
+            parse
+                .syntax()
+                .descendants()
+                .filter_map(JsReferenceIdentifier::cast)
+                .filter_map(|reference| {
+                    Some(reference.value_token().ok()?.text_trimmed().to_string())
+                }),
+        );
     }
 }
 
@@ -83,12 +376,15 @@ impl ExtensionHandler for VueFileHandler {
     fn capabilities(&self) -> Capabilities {
         Capabilities {
             enabled_for_path: EnabledForPath {
-                formatter: Some(javascript::formatter_enabled),
+                formatter: Some(formatter_enabled),
                 search: Some(javascript::search_enabled),
-                assist: Some(javascript::assist_enabled),
-                linter: Some(javascript::linter_enabled),
+                assist: Some(assist_enabled),
+                linter: Some(linter_enabled),
+            },
+            parser: ParserCapabilities {
+                parse: Some(parse_vue),
+                reparse: None,
             },
-            parser: ParserCapabilities { parse: Some(parse) },
             debug: DebugCapabilities {
                 debug_syntax_tree: None,
                 debug_control_flow: None,
@@ -100,7 +396,11 @@ impl ExtensionHandler for VueFileHandler {
             analyzer: AnalyzerCapabilities {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
+                rename_declaration: None,
                 rename: None,
+                hover: None,
+                goto_definition: None,
+                find_references: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
@@ -114,20 +414,32 @@ impl ExtensionHandler for VueFileHandler {
     }
 }
 
-fn parse(
+fn formatter_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+    javascript::formatter_enabled(path, handle) || css::formatter_enabled(path, handle)
+}
+
+fn linter_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+    javascript::linter_enabled(path, handle) || css::linter_enabled(path, handle)
+}
+
+fn assist_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+    javascript::assist_enabled(path, handle) || css::assist_enabled(path, handle)
+}
+
+fn parse_vue(
     _rome_path: &BiomePath,
     _file_source: DocumentFileSource,
     text: &str,
     _settings: WorkspaceSettingsHandle,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let script = VueFileHandler::input(text);
-    let file_source = VueFileHandler::file_source(text);
+    let parse = parse_html_with_cache(text, cache, HtmlParserOptions::default());
+    let root: HtmlRoot = parse.tree();
+    let file_source = VueFileHandler::script_file_source(&root);
 
     debug!("Parsing file with language {:?}", file_source);
 
-    let parse = parse_js_with_cache(script, file_source, JsParserOptions::default(), cache);
-
+    // The blocks are parsed by the language they're written in when they're processed
     ParseResult {
         any_parse: parse.into(),
         language: Some(file_source.into()),
@@ -137,41 +449,305 @@ fn parse(
 #[tracing::instrument(level = "debug", skip(parse, settings))]
 fn format(
     biome_path: &BiomePath,
-    document_file_source: &DocumentFileSource,
+    _document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format(biome_path, document_file_source, parse, settings)
+    let root: HtmlRoot = parse.tree();
+    let source = root.syntax().to_string();
+    let mut code = String::with_capacity(source.len());
+    let mut last_end = 0;
+    for block in VueFileHandler::blocks(&root) {
+        if !block.formatter_enabled(biome_path, &settings) {
+            continue;
+        }
+        let parse = block.parse(biome_path, &source, &settings);
+        if block.is_script()
+            && parse.has_errors()
+            && !settings.format_with_errors_enabled_for_this_file_path(biome_path)
+        {
+            return Err(WorkspaceError::format_with_errors_disabled());
+        }
+        let printed = if block.is_script() {
+            javascript::format(biome_path, &block.file_source, parse, settings.clone())?
+        } else {
+            css::format(biome_path, &block.file_source, parse, settings.clone())?
+        };
+
+        code.push_str(&source[last_end..usize::from(block.range.start())]);
+        code.push_str(printed.as_code());
+        last_end = usize::from(block.range.end());
+    }
+    code.push_str(&source[last_end..]);
+
+    Ok(Printed::new(code, None, Vec::new(), Vec::new()))
+}
+
+/// Returns the block that contains `range`, with `range` shifted into it.
+fn find_block(root: &HtmlRoot, range: TextRange) -> Result<(VueBlock, TextRange), WorkspaceError> {
+    VueFileHandler::blocks(root)
+        .into_iter()
+        .find(|block| block.range.contains_range(range))
+        .map(|block| {
+            let range = range - block.range.start();
+            (block, range)
+        })
+        .ok_or_else(|| {
+            WorkspaceError::FormatError(FormatError::RangeError {
+                input: range,
+                tree: root.syntax().text_range_with_trivia(),
+            })
+        })
+}
+
+/// Shifts the range of a block's formatting result to the coordinates of the component.
+fn shift_printed(printed: Printed, offset: TextSize) -> Printed {
+    let range = printed.range().map(|range| range + offset);
+    Printed::new(printed.into_code(), range, Vec::new(), Vec::new())
 }
 
 pub(crate) fn format_range(
     biome_path: &BiomePath,
-    document_file_source: &DocumentFileSource,
+    _document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
     range: TextRange,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_range(biome_path, document_file_source, parse, settings, range)
+    let root: HtmlRoot = parse.tree();
+    let source = root.syntax().to_string();
+    let (block, range) = find_block(&root, range)?;
+    let parse = block.parse(biome_path, &source, &settings);
+    let printed = if block.is_script() {
+        javascript::format_range(biome_path, &block.file_source, parse, settings, range)?
+    } else {
+        css::format_range(biome_path, &block.file_source, parse, settings, range)?
+    };
+
+    Ok(shift_printed(printed, block.range.start()))
 }
 
 pub(crate) fn format_on_type(
     biome_path: &BiomePath,
-    document_file_source: &DocumentFileSource,
+    _document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
     offset: TextSize,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_on_type(biome_path, document_file_source, parse, settings, offset)
+    let root: HtmlRoot = parse.tree();
+    let source = root.syntax().to_string();
+    let (block, range) = find_block(&root, TextRange::empty(offset))?;
+    let parse = block.parse(biome_path, &source, &settings);
+    let printed = if block.is_script() {
+        javascript::format_on_type(
+            biome_path,
+            &block.file_source,
+            parse,
+            settings,
+            range.start(),
+        )?
+    } else {
+        css::format_on_type(
+            biome_path,
+            &block.file_source,
+            parse,
+            settings,
+            range.start(),
+        )?
+    };
+
+    Ok(shift_printed(printed, block.range.start()))
 }
 
 pub(crate) fn lint(params: LintParams) -> LintResults {
-    javascript::lint(params)
+    let root: HtmlRoot = params.parse.tree();
+    let source = root.syntax().to_string();
+    let blocks = VueFileHandler::parse_blocks(&root, params.path, &source, params.workspace);
+    let embedded_names = VueFileHandler::embedded_names(&root, &blocks);
+
+    // The diagnostics of the component's structure
+    let diagnostics = params.parse.clone().into_diagnostics();
+    let mut results = LintResults {
+        errors: diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity() >= Severity::Error)
+            .count(),
+        diagnostics,
+        skipped_diagnostics: 0,
+    };
+    for ((block, parse), embedded) in blocks.into_iter().zip(embedded_names) {
+        let block_params = LintParams {
+            parse,
+            workspace: params.workspace,
+            language: block.file_source,
+            path: params.path,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            categories: block.categories(params.path, params.workspace, params.categories),
+            module_graph: params.module_graph.clone(),
+            project_layout: params.project_layout.clone(),
+            suppression_reason: params.suppression_reason.clone(),
+            enabled_rules: params.enabled_rules.clone(),
+            plugins: params.plugins.clone(),
+            pull_code_actions: params.pull_code_actions,
+            json_schema: None,
+            graphql_schema: None,
+        };
+        let block_results = if block.is_script() {
+            javascript::lint_embedded(block_params, embedded)
+        } else {
+            css::lint(block_params)
+        };
+
+        results.diagnostics.extend(
+            block_results
+                .diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_offset(block.range.start())),
+        );
+        results.errors += block_results.errors;
+        results.skipped_diagnostics += block_results.skipped_diagnostics;
+    }
+
+    results
 }
 
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
-    javascript::code_actions(params)
+    let root: HtmlRoot = params.parse.tree();
+    let source = root.syntax().to_string();
+    let blocks = VueFileHandler::parse_blocks(&root, params.path, &source, params.workspace);
+    let embedded_names = VueFileHandler::embedded_names(&root, &blocks);
+
+    let mut actions = Vec::new();
+    for ((block, parse), embedded) in blocks.into_iter().zip(embedded_names) {
+        let range = match params.range {
+            Some(range) if block.range.intersect(range).is_none() => continue,
+            Some(range) => Some(
+                block
+                    .range
+                    .intersect(range)
+                    .map_or(range, |range| range - block.range.start()),
+            ),
+            None => None,
+        };
+        let block_params = CodeActionsParams {
+            parse,
+            range,
+            workspace: params.workspace,
+            path: params.path,
+            module_graph: params.module_graph.clone(),
+            project_layout: params.project_layout.clone(),
+            language: block.file_source,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            suppression_reason: params.suppression_reason.clone(),
+            enabled_rules: params.enabled_rules.clone(),
+            plugins: params.plugins.clone(),
+            categories: block.categories(params.path, params.workspace, params.categories),
+            json_schema: None,
+            graphql_schema: None,
+        };
+        let block_actions = if block.is_script() {
+            javascript::code_actions_embedded(block_params, embedded)
+        } else {
+            css::code_actions(block_params)
+        };
+
+        actions.extend(
+            block_actions
+                .actions
+                .into_iter()
+                .map(|action| shift_code_action(action, &source, block.range)),
+        );
+    }
+
+    PullActionsResult { actions }
+}
+
+/// Shifts a code action pulled from a block to the coordinates of the component.
+fn shift_code_action(action: CodeAction, source: &str, block_range: TextRange) -> CodeAction {
+    let CodeSuggestion {
+        span,
+        applicability,
+        msg,
+        suggestion,
+        labels,
+    } = action.suggestion;
+    let offset = block_range.start();
+    let block_text = &source[block_range];
+
+    let mut builder = TextEdit::builder();
+    builder.equal(&source[..usize::from(offset)]);
+    builder.with_unicode_words_diff(block_text, &suggestion.new_string(block_text));
+    builder.equal(&source[usize::from(block_range.end())..]);
+
+    CodeAction {
+        category: action.category,
+        rule_name: action.rule_name,
+        suggestion: CodeSuggestion {
+            span: span + offset,
+            applicability,
+            msg,
+            suggestion: builder.finish(),
+            labels: labels.into_iter().map(|label| label + offset).collect(),
+        },
+    }
 }
 
 fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
-    javascript::fix_all(params)
+    let root: HtmlRoot = params.parse.tree();
+    let source = root.syntax().to_string();
+    let blocks = VueFileHandler::parse_blocks(&root, params.biome_path, &source, &params.workspace);
+    let embedded_names = VueFileHandler::embedded_names(&root, &blocks);
+
+    let mut result = FixFileResult {
+        code: String::with_capacity(source.len()),
+        actions: Vec::new(),
+        errors: 0,
+        skipped_suggested_fixes: 0,
+    };
+    let mut last_end = 0;
+    for ((block, parse), embedded) in blocks.into_iter().zip(embedded_names) {
+        let path = params.biome_path;
+        let block_params = FixAllParams {
+            parse,
+            fix_file_mode: params.fix_file_mode,
+            workspace: params.workspace.clone(),
+            should_format: params.should_format && block.formatter_enabled(path, &params.workspace),
+            biome_path: path,
+            module_graph: params.module_graph.clone(),
+            project_layout: params.project_layout.clone(),
+            document_file_source: block.file_source,
+            only: params.only.clone(),
+            skip: params.skip.clone(),
+            rule_categories: block.categories(path, &params.workspace, params.rule_categories),
+            suppression_reason: params.suppression_reason.clone(),
+            enabled_rules: params.enabled_rules.clone(),
+            plugins: params.plugins.clone(),
+            json_schema: None,
+            graphql_schema: None,
+        };
+        let fixed = if block.is_script() {
+            javascript::fix_all_embedded(block_params, embedded)?
+        } else {
+            css::fix_all(block_params)?
+        };
+
+        result
+            .code
+            .push_str(&source[last_end..usize::from(block.range.start())]);
+        result.code.push_str(&fixed.code);
+        last_end = usize::from(block.range.end());
+
+        result
+            .actions
+            .extend(fixed.actions.into_iter().map(|action| FixAction {
+                rule_name: action.rule_name,
+                range: action.range + block.range.start(),
+            }));
+        result.errors += fixed.errors;
+        result.skipped_suggested_fixes += fixed.skipped_suggested_fixes;
+    }
+    result.code.push_str(&source[last_end..]);
+
+    Ok(result)
 }
@@ -1,4 +1,6 @@
 use crate::WorkspaceError;
+use crate::file_handlers::graphql::GraphqlEnvironmentSettings;
+use crate::file_handlers::json::{JsonEnvironmentSettings, JsonSchemaSettings};
 use crate::workspace::DocumentFileSource;
 use biome_analyze::{AnalyzerOptions, AnalyzerRules};
 use biome_configuration::analyzer::assist::{Actions, AssistConfiguration, AssistEnabled};
@@ -8,9 +10,12 @@ use biome_configuration::diagnostics::InvalidIgnorePattern;
 use biome_configuration::formatter::{FormatWithErrorsEnabled, FormatterEnabled};
 use biome_configuration::html::HtmlConfiguration;
 use biome_configuration::javascript::JsxRuntime;
+use biome_configuration::json::JsonSchemas;
+use biome_configuration::markdown::MarkdownConfiguration;
 use biome_configuration::max_size::MaxSize;
 use biome_configuration::plugins::Plugins;
 use biome_configuration::vcs::{VcsClientKind, VcsConfiguration, VcsEnabled, VcsUseIgnoreFile};
+use biome_configuration::yaml::YamlConfiguration;
 use biome_configuration::{
     BiomeDiagnostic, Configuration, CssConfiguration, FilesConfiguration,
     FilesIgnoreUnknownEnabled, FormatterConfiguration, GraphqlConfiguration, GritConfiguration,
@@ -26,7 +31,7 @@ use biome_formatter::{
     AttributePosition, BracketSameLine, BracketSpacing, Expand, IndentStyle, IndentWidth,
     LineEnding, LineWidth,
 };
-use biome_fs::BiomePath;
+use biome_fs::{BiomePath, normalize_path};
 use biome_graphql_formatter::context::GraphqlFormatOptions;
 use biome_graphql_syntax::GraphqlLanguage;
 use biome_grit_formatter::context::GritFormatOptions;
@@ -39,6 +44,10 @@ use biome_js_syntax::JsLanguage;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
+use biome_markdown_formatter::context::MarkdownFormatOptions;
+use biome_markdown_syntax::MarkdownLanguage;
+use biome_yaml_formatter::context::YamlFormatOptions;
+use biome_yaml_syntax::YamlLanguage;
 use camino::{Utf8Path, Utf8PathBuf};
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use std::borrow::Cow;
@@ -144,21 +153,40 @@ impl Settings {
             self.languages.javascript = javascript.into()
         }
         // json settings
-        if let Some(json) = configuration.json {
-            self.languages.json = json.into()
+        if let Some(mut json) = configuration.json {
+            let schemas = json.schemas.take();
+            self.languages.json = json.into();
+            if let Some(schemas) = schemas {
+                self.languages.json.environment =
+                    to_json_environment_settings(working_directory.clone(), schemas);
+            }
         }
         // css settings
         if let Some(css) = configuration.css {
             self.languages.css = css.into()
         }
         // graphql settings
-        if let Some(graphql) = configuration.graphql {
-            self.languages.graphql = graphql.into()
+        if let Some(mut graphql) = configuration.graphql {
+            let schema = graphql.schema.take();
+            self.languages.graphql = graphql.into();
+            if let Some(schema) = schema {
+                self.languages.graphql.environment = GraphqlEnvironmentSettings {
+                    schema: Includes::new(working_directory.clone(), Some(schema)),
+                };
+            }
         }
         // html settings
         if let Some(html) = configuration.html {
             self.languages.html = html.into()
         }
+        // markdown settings
+        if let Some(markdown) = configuration.markdown {
+            self.languages.markdown = markdown.into()
+        }
+        // yaml settings
+        if let Some(yaml) = configuration.yaml {
+            self.languages.yaml = yaml.into()
+        }
 
         // plugin settings
         if let Some(plugins) = configuration.plugins {
@@ -271,6 +299,29 @@ impl Settings {
         result
     }
 
+    /// Returns the path of the JSON Schema configured for the given JSON
+    /// file, if any.
+    ///
+    /// The first schema whose `includes` match the path is returned.
+    pub fn get_json_schema_for_path(&self, path: &Utf8Path) -> Option<&Utf8Path> {
+        self.languages
+            .json
+            .environment
+            .schemas
+            .iter()
+            .find(|schema| {
+                !schema.includes.is_unset() && schema.includes.matches_with_exceptions(path)
+            })
+            .map(|schema| schema.schema.as_path())
+    }
+
+    /// Returns whether the given GraphQL file defines the schema of the
+    /// project, according to `graphql.schema`.
+    pub fn is_graphql_schema_file(&self, path: &Utf8Path) -> bool {
+        let schema = &self.languages.graphql.environment.schema;
+        !schema.is_unset() && schema.matches_with_exceptions(path)
+    }
+
     /// Return all plugins configured in setting
     pub fn as_all_plugins(&self) -> Cow<Plugins> {
         let mut result = Cow::Borrowed(&self.plugins);
@@ -458,6 +509,8 @@ pub struct LanguageListSettings {
     pub graphql: LanguageSettings<GraphqlLanguage>,
     pub html: LanguageSettings<HtmlLanguage>,
     pub grit: LanguageSettings<GritLanguage>,
+    pub markdown: LanguageSettings<MarkdownLanguage>,
+    pub yaml: LanguageSettings<YamlLanguage>,
 }
 
 impl From<JsConfiguration> for LanguageSettings<JsLanguage> {
@@ -585,11 +638,11 @@ impl From<HtmlConfiguration> for LanguageSettings<HtmlLanguage> {
             language_setting.formatter = formatter.into();
         }
 
+        if let Some(linter) = html.linter {
+            language_setting.linter = linter.into();
+        }
+
         // NOTE: uncomment once ready
-        // if let Some(linter) = html.linter {
-        //     language_setting.linter = linter.into();
-        // }
-        //
         // if let Some(assist) = html.assist {
         //     language_setting.assist = assist.into();
         // }
@@ -598,6 +651,28 @@ impl From<HtmlConfiguration> for LanguageSettings<HtmlLanguage> {
     }
 }
 
+impl From<MarkdownConfiguration> for LanguageSettings<MarkdownLanguage> {
+    fn from(markdown: MarkdownConfiguration) -> Self {
+        let mut language_setting: Self = Self::default();
+        if let Some(formatter) = markdown.formatter {
+            language_setting.formatter = formatter.into();
+        }
+
+        language_setting
+    }
+}
+
+impl From<YamlConfiguration> for LanguageSettings<YamlLanguage> {
+    fn from(yaml: YamlConfiguration) -> Self {
+        let mut language_setting: Self = Self::default();
+        if let Some(formatter) = yaml.formatter {
+            language_setting.formatter = formatter.into();
+        }
+
+        language_setting
+    }
+}
+
 pub trait ServiceLanguage: biome_rowan::Language {
     /// Formatter settings type for this language
     type FormatterSettings: Default;
@@ -916,6 +991,30 @@ impl OverrideIncludes {
     }
 }
 
+fn to_json_environment_settings(
+    working_directory: Option<Utf8PathBuf>,
+    schemas: JsonSchemas,
+) -> JsonEnvironmentSettings {
+    JsonEnvironmentSettings {
+        schemas: schemas
+            .0
+            .into_iter()
+            .filter_map(|association| {
+                let schema = association.schema?;
+                let schema = match &working_directory {
+                    Some(working_directory) => normalize_path(&working_directory.join(schema)),
+                    None => Utf8PathBuf::from(schema),
+                };
+
+                Some(JsonSchemaSettings {
+                    includes: Includes::new(working_directory.clone(), association.includes),
+                    schema,
+                })
+            })
+            .collect(),
+    }
+}
+
 fn to_file_settings(
     working_directory: Option<Utf8PathBuf>,
     config: FilesConfiguration,
@@ -947,7 +1046,7 @@ fn to_vcs_settings(config: VcsConfiguration) -> Result<VcsSettings, WorkspaceErr
 }
 /// Handle object holding a pin of the workspace settings until the deferred
 /// language-specific options resolution is called.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct WorkspaceSettingsHandle {
     settings: Option<Settings>,
 }
@@ -1153,6 +1252,32 @@ impl OverrideSettings {
         options
     }
 
+    pub fn to_override_markdown_format_options(
+        &self,
+        path: &Utf8Path,
+        mut options: MarkdownFormatOptions,
+    ) -> MarkdownFormatOptions {
+        for pattern in self.patterns.iter() {
+            if pattern.is_file_included(path) {
+                pattern.apply_overrides_to_markdown_format_options(&mut options);
+            }
+        }
+        options
+    }
+
+    pub fn to_override_yaml_format_options(
+        &self,
+        path: &Utf8Path,
+        mut options: YamlFormatOptions,
+    ) -> YamlFormatOptions {
+        for pattern in self.patterns.iter() {
+            if pattern.is_file_included(path) {
+                pattern.apply_overrides_to_yaml_format_options(&mut options);
+            }
+        }
+        options
+    }
+
     pub fn to_override_js_parser_options(
         &self,
         path: &Utf8Path,
@@ -1285,6 +1410,11 @@ impl OverrideSettings {
                         biome_graphql_analyze::METADATA.deref(),
                         &mut analyzer_rules,
                     );
+                    push_to_analyzer_rules(
+                        rules,
+                        biome_html_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
                 }
 
                 if let Some(actions) = pattern.assist.actions.as_ref() {
@@ -1308,6 +1438,11 @@ impl OverrideSettings {
                         biome_graphql_analyze::METADATA.deref(),
                         &mut analyzer_rules,
                     );
+                    push_to_analyzer_assist(
+                        actions,
+                        biome_html_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
                 }
             }
         }
@@ -1386,6 +1521,9 @@ impl OverrideSettingPattern {
         {
             options.set_attribute_position(attribute_position);
         }
+        if let Some(embedded_language_formatting) = js_formatter.embedded_language_formatting {
+            options.set_embedded_language_formatting(embedded_language_formatting);
+        }
     }
 
     fn apply_overrides_to_json_format_options(&self, options: &mut JsonFormatOptions) {
@@ -1529,6 +1667,42 @@ impl OverrideSettingPattern {
         // #endregion
     }
 
+    fn apply_overrides_to_markdown_format_options(&self, options: &mut MarkdownFormatOptions) {
+        let markdown_formatter = &self.languages.markdown.formatter;
+        let formatter = &self.formatter;
+
+        if let Some(indent_style) = markdown_formatter.indent_style.or(formatter.indent_style) {
+            options.set_indent_style(indent_style);
+        }
+        if let Some(indent_width) = markdown_formatter.indent_width.or(formatter.indent_width) {
+            options.set_indent_width(indent_width)
+        }
+        if let Some(line_ending) = markdown_formatter.line_ending.or(formatter.line_ending) {
+            options.set_line_ending(line_ending);
+        }
+        if let Some(line_width) = markdown_formatter.line_width.or(formatter.line_width) {
+            options.set_line_width(line_width);
+        }
+    }
+
+    fn apply_overrides_to_yaml_format_options(&self, options: &mut YamlFormatOptions) {
+        let yaml_formatter = &self.languages.yaml.formatter;
+        let formatter = &self.formatter;
+
+        if let Some(indent_width) = yaml_formatter.indent_width.or(formatter.indent_width) {
+            options.set_indent_width(indent_width)
+        }
+        if let Some(line_ending) = yaml_formatter.line_ending.or(formatter.line_ending) {
+            options.set_line_ending(line_ending);
+        }
+        if let Some(line_width) = yaml_formatter.line_width.or(formatter.line_width) {
+            options.set_line_width(line_width);
+        }
+        if let Some(quote_style) = yaml_formatter.quote_style {
+            options.set_quote_style(quote_style);
+        }
+    }
+
     fn apply_overrides_to_js_parser_options(&self, options: &mut JsParserOptions) {
         let js_parser = &self.languages.javascript.parser;
 
@@ -1622,6 +1796,8 @@ pub fn to_override_settings(
         let graphql = pattern.graphql.take().unwrap_or_default();
         let grit = pattern.grit.take().unwrap_or_default();
         let html = pattern.html.take().unwrap_or_default();
+        let markdown = pattern.markdown.take().unwrap_or_default();
+        let yaml = pattern.yaml.take().unwrap_or_default();
 
         languages.javascript =
             to_javascript_language_settings(javascript, &current_settings.languages.javascript);
@@ -1632,6 +1808,9 @@ pub fn to_override_settings(
             to_graphql_language_settings(graphql, &current_settings.languages.graphql);
         languages.grit = to_grit_language_settings(grit, &current_settings.languages.grit);
         languages.html = to_html_language_settings(html, &current_settings.languages.html);
+        languages.markdown =
+            to_markdown_language_settings(markdown, &current_settings.languages.markdown);
+        languages.yaml = to_yaml_language_settings(yaml, &current_settings.languages.yaml);
 
         let pattern_setting = OverrideSettingPattern {
             includes: OverrideIncludes::new(working_directory.clone(), pattern.includes),
@@ -1770,6 +1949,33 @@ fn to_html_language_settings(
 
     language_setting.formatter = formatter.into();
 
+    let linter = conf.linter.take().unwrap_or_default();
+    language_setting.linter.enabled = linter.enabled;
+
+    language_setting
+}
+
+fn to_markdown_language_settings(
+    mut conf: MarkdownConfiguration,
+    _parent_settings: &LanguageSettings<MarkdownLanguage>,
+) -> LanguageSettings<MarkdownLanguage> {
+    let mut language_setting: LanguageSettings<MarkdownLanguage> = LanguageSettings::default();
+    let formatter = conf.formatter.take().unwrap_or_default();
+
+    language_setting.formatter = formatter.into();
+
+    language_setting
+}
+
+fn to_yaml_language_settings(
+    mut conf: YamlConfiguration,
+    _parent_settings: &LanguageSettings<YamlLanguage>,
+) -> LanguageSettings<YamlLanguage> {
+    let mut language_setting: LanguageSettings<YamlLanguage> = LanguageSettings::default();
+    let formatter = conf.formatter.take().unwrap_or_default();
+
+    language_setting.formatter = formatter.into();
+
     language_setting
 }
 
@@ -709,6 +709,31 @@ pub struct GetFileContentParams {
     pub path: BiomePath,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetModuleDependenciesParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetModuleDependenciesResult {
+    /// The paths of the modules that are imported by the file, directly or
+    /// transitively, sorted alphabetically.
+    ///
+    /// For JSON files, these are the documents of the JSON Schema the file is
+    /// validated against. For GraphQL files, these are the files that define
+    /// the schema of the project.
+    pub dependencies: Vec<BiomePath>,
+
+    /// The paths of the modules that import the file, directly or
+    /// transitively, sorted alphabetically.
+    pub dependents: Vec<BiomePath>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
@@ -739,6 +764,23 @@ pub struct ChangeFileParams {
     pub path: BiomePath,
     pub content: String,
     pub version: i32,
+    /// The edits that turned the previous content of the file into
+    /// `content`, in the order they were applied.
+    ///
+    /// When provided, the workspace tries to update the syntax tree of the
+    /// file in place instead of parsing `content` from scratch.
+    #[serde(default)]
+    pub edits: Vec<ChangeFileEdit>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeFileEdit {
+    /// The range of the replaced text, relative to the content of the file
+    /// after the previous edits.
+    pub range: TextRange,
+    pub text: String,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -912,6 +954,86 @@ pub struct RenameResult {
     pub range: TextRange,
     /// List of text edit operations to apply on the source code
     pub indels: TextEdit,
+    /// Text edit operations to apply on the other modules affected by this
+    /// rename operation, such as the modules importing a renamed export
+    pub module_edits: Vec<ModuleTextEdit>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleTextEdit {
+    /// Path of the modified module
+    pub path: BiomePath,
+    /// Range of source code modified in the module
+    pub range: TextRange,
+    /// List of text edit operations to apply on the source code of the module
+    pub indels: TextEdit,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct HoverParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct HoverResult {
+    /// Range of the symbol that was hovered, if any
+    pub range: Option<TextRange>,
+    /// Markdown content describing the hovered symbol, such as its inferred type
+    pub contents: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GotoDefinitionParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GotoDefinitionResult {
+    /// Locations where the symbol is declared
+    pub definitions: Vec<SymbolLocation>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FindReferencesParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+    /// Whether the declaration of the symbol should be included in the result
+    pub include_declaration: bool,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FindReferencesResult {
+    /// Locations where the symbol is referenced, across the project
+    pub references: Vec<SymbolLocation>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolLocation {
+    /// Path of the file that contains the symbol
+    pub path: BiomePath,
+    /// Range of the symbol inside the file
+    pub range: TextRange,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -1012,6 +1134,10 @@ pub struct SearchPatternParams {
 pub struct SearchResults {
     pub path: BiomePath,
     pub matches: Vec<TextRange>,
+    /// Content of the file after applying the rewrites of the pattern.
+    ///
+    /// It's `None` when the pattern doesn't rewrite anything in the file.
+    pub rewritten: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -1275,6 +1401,15 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Returns the content of a given file.
     fn get_file_content(&self, params: GetFileContentParams) -> Result<String, WorkspaceError>;
 
+    /// Returns the modules that a given file depends on, according to the
+    /// module graph.
+    ///
+    /// Files that aren't part of the module graph don't have dependencies.
+    fn get_module_dependencies(
+        &self,
+        params: GetModuleDependenciesParams,
+    ) -> Result<GetModuleDependenciesResult, WorkspaceError>;
+
     /// Returns the size of a given file, as well as the allowed maximum file
     /// size for that file.
     fn check_file_size(
@@ -1310,8 +1445,29 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     fn fix_file(&self, params: FixFileParams) -> Result<FixFileResult, WorkspaceError>;
 
     /// Returns the content of the file after renaming a symbol.
+    ///
+    /// Renaming an exported symbol also renames it in the modules that
+    /// import it, which are returned as [RenameResult::module_edits].
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError>;
 
+    /// Returns information about the symbol at the given position, such as
+    /// its inferred type.
+    fn hover(&self, params: HoverParams) -> Result<HoverResult, WorkspaceError>;
+
+    /// Returns the locations where the symbol at the given position is
+    /// declared, following imports across modules.
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError>;
+
+    /// Returns the locations where the symbol at the given position is
+    /// referenced within the project.
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError>;
+
     /// Closes a file that is opened in the workspace.
     ///
     /// This only unloads the document from the workspace if the file is NOT
@@ -1444,6 +1600,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             project_key: self.project_key,
             path: self.path.clone(),
             version,
+            edits: Vec::new(),
             content,
         })
     }
@@ -9,7 +9,7 @@ use biome_configuration::{
 };
 use biome_diagnostics::Diagnostic;
 use biome_fs::{BiomePath, MemoryFileSystem};
-use biome_js_syntax::{JsFileSource, TextSize};
+use biome_js_syntax::{JsFileSource, TextRange, TextSize};
 use camino::Utf8PathBuf;
 use insta::{assert_debug_snapshot, assert_snapshot};
 
@@ -19,9 +19,10 @@ use crate::{Workspace, WorkspaceError};
 
 use super::{
     CloseFileParams, CloseProjectParams, FileContent, FileFeaturesResult, FileGuard,
-    GetFileContentParams, GetSyntaxTreeParams, OpenFileParams, OpenProjectParams,
-    OpenProjectResult, PullDiagnosticsParams, ScanKind, ScanProjectFolderParams,
-    UpdateSettingsParams, server,
+    FindReferencesParams, FixFileMode, FixFileParams, GetFileContentParams,
+    GetModuleDependenciesParams, GetSyntaxTreeParams, GotoDefinitionParams, HoverParams,
+    OpenFileParams, OpenProjectParams, OpenProjectResult, PullDiagnosticsParams, RenameParams,
+    ScanKind, ScanProjectFolderParams, SymbolLocation, UpdateSettingsParams, server,
 };
 
 fn create_server() -> (Box<dyn Workspace>, ProjectKey) {
@@ -442,6 +443,63 @@ fn too_large_files_are_tracked_but_not_parsed() {
     );
 }
 
+#[test]
+fn module_dependencies_are_resolved_transitively() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8PathBuf::from("/project/a.ts"),
+        b"import { b } from './b';\nexport const a = b;\n",
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/b.ts"),
+        b"import { c } from './c';\nimport { a } from './a';\nexport const b = c;\n",
+    );
+    fs.insert(Utf8PathBuf::from("/project/c.ts"), b"export const c = 1;\n");
+    fs.insert(Utf8PathBuf::from("/project/d.ts"), b"export const d = 1;\n");
+
+    let workspace = server(Box::new(fs), None);
+    let OpenProjectResult { project_key, .. } = workspace
+        .open_project(OpenProjectParams {
+            path: Utf8PathBuf::from("/project").into(),
+            open_uninitialized: true,
+            only_rules: None,
+            skip_rules: None,
+        })
+        .unwrap();
+
+    workspace
+        .scan_project_folder(ScanProjectFolderParams {
+            project_key,
+            path: None,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+        })
+        .unwrap();
+
+    let result = workspace
+        .get_module_dependencies(GetModuleDependenciesParams {
+            project_key,
+            path: BiomePath::new("/project/a.ts"),
+        })
+        .unwrap();
+    assert_eq!(
+        result.dependencies,
+        vec![
+            BiomePath::new("/project/b.ts"),
+            BiomePath::new("/project/c.ts")
+        ]
+    );
+
+    let result = workspace
+        .get_module_dependencies(GetModuleDependenciesParams {
+            project_key,
+            path: BiomePath::new("/project/d.ts"),
+        })
+        .unwrap();
+    assert!(result.dependencies.is_empty());
+}
+
 #[test]
 fn plugins_are_loaded_and_used_during_analysis() {
     const PLUGIN_CONTENT: &[u8] = br#"
@@ -641,6 +699,78 @@ fn plugins_may_use_invalid_span() {
     assert_eq!(result.errors, 0);
 }
 
+#[test]
+fn plugins_rewrites_are_applied_as_fixes() {
+    const PLUGIN_CONTENT: &[u8] = br#"
+`console.log($message)` as $call where {
+    register_diagnostic(
+        span = $call,
+        message = "Use the logger instead of `console.log()`",
+        fix_kind = "safe"
+    ),
+    $call => `logger.info($message)`
+}
+"#;
+
+    const FILE_CONTENT: &[u8] = b"console.log('first');\nconsole.log('second');\n";
+
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(Utf8PathBuf::from("/project/plugin.grit"), PLUGIN_CONTENT);
+    fs.insert(Utf8PathBuf::from("/project/a.ts"), FILE_CONTENT);
+
+    let workspace = server(Box::new(fs), None);
+    let OpenProjectResult { project_key, .. } = workspace
+        .open_project(OpenProjectParams {
+            path: Utf8PathBuf::from("/project").into(),
+            open_uninitialized: true,
+            only_rules: None,
+            skip_rules: None,
+        })
+        .unwrap();
+
+    workspace
+        .update_settings(UpdateSettingsParams {
+            project_key,
+            configuration: Configuration {
+                plugins: Some(Plugins(vec![PluginConfiguration::Path(
+                    "./plugin.grit".to_string(),
+                )])),
+                ..Default::default()
+            },
+            workspace_directory: Some(BiomePath::new("/project")),
+        })
+        .unwrap();
+
+    workspace
+        .scan_project_folder(ScanProjectFolderParams {
+            project_key,
+            path: None,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+        })
+        .unwrap();
+
+    let result = workspace
+        .fix_file(FixFileParams {
+            project_key,
+            path: BiomePath::new("/project/a.ts"),
+            fix_file_mode: FixFileMode::SafeFixes,
+            should_format: false,
+            only: Vec::new(),
+            skip: Vec::new(),
+            enabled_rules: Vec::new(),
+            rule_categories: RuleCategories::default(),
+            suppression_reason: None,
+        })
+        .unwrap();
+    assert_eq!(
+        result.code,
+        "logger.info('first');\nlogger.info('second');\n"
+    );
+    assert_eq!(result.actions.len(), 2);
+}
+
 #[test]
 fn correctly_apply_plugins_in_override() {
     let files: &[(&str, &[u8])] = &[
@@ -877,3 +1007,276 @@ class Person {
     assert!(result.is_ok());
     assert_snapshot!(result.unwrap());
 }
+
+#[test]
+fn navigation_follows_imports_across_modules() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        Utf8PathBuf::from("/project/foo.ts"),
+        b"export function foo(): number {\n    return 1;\n}\n",
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/reexport.ts"),
+        b"export * from './foo';\n",
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/index.ts"),
+        b"import { foo } from './foo';\n\nconst result = foo();\n",
+    );
+    fs.insert(
+        Utf8PathBuf::from("/project/other.ts"),
+        b"import { foo } from './reexport';\n\nfoo();\n",
+    );
+
+    let workspace = server(Box::new(fs), None);
+    let OpenProjectResult { project_key, .. } = workspace
+        .open_project(OpenProjectParams {
+            path: Utf8PathBuf::from("/project").into(),
+            open_uninitialized: true,
+            only_rules: None,
+            skip_rules: None,
+        })
+        .unwrap();
+
+    workspace
+        .scan_project_folder(ScanProjectFolderParams {
+            project_key,
+            path: None,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+        })
+        .unwrap();
+
+    let result = workspace
+        .hover(HoverParams {
+            project_key,
+            path: BiomePath::new("/project/index.ts"),
+            symbol_at: TextSize::from(36),
+        })
+        .unwrap();
+    assert_eq!(
+        result.range,
+        Some(TextRange::new(TextSize::from(36), TextSize::from(42)))
+    );
+    assert_eq!(result.contents.as_deref(), Some("```ts\nnumber\n```"));
+
+    // `foo` in `foo();`, imported through a re-export
+    let result = workspace
+        .goto_definition(GotoDefinitionParams {
+            project_key,
+            path: BiomePath::new("/project/other.ts"),
+            symbol_at: TextSize::from(35),
+        })
+        .unwrap();
+    let foo_declaration = SymbolLocation {
+        path: BiomePath::new("/project/foo.ts"),
+        range: TextRange::new(TextSize::from(16), TextSize::from(19)),
+    };
+    assert_eq!(result.definitions, vec![foo_declaration.clone()]);
+
+    let result = workspace
+        .find_references(FindReferencesParams {
+            project_key,
+            path: BiomePath::new("/project/index.ts"),
+            symbol_at: TextSize::from(45),
+            include_declaration: true,
+        })
+        .unwrap();
+    assert_eq!(
+        result.references,
+        vec![
+            foo_declaration,
+            SymbolLocation {
+                path: BiomePath::new("/project/index.ts"),
+                range: TextRange::new(TextSize::from(9), TextSize::from(12)),
+            },
+            SymbolLocation {
+                path: BiomePath::new("/project/index.ts"),
+                range: TextRange::new(TextSize::from(45), TextSize::from(48)),
+            },
+            SymbolLocation {
+                path: BiomePath::new("/project/other.ts"),
+                range: TextRange::new(TextSize::from(9), TextSize::from(12)),
+            },
+            SymbolLocation {
+                path: BiomePath::new("/project/other.ts"),
+                range: TextRange::new(TextSize::from(35), TextSize::from(38)),
+            },
+        ]
+    );
+}
+
+#[test]
+fn rename_updates_importing_modules() {
+    const FILES: [(&str, &str); 5] = [
+        ("/project/foo.ts", "export function foo() {}\nfoo();\n"),
+        ("/project/reexport.ts", "export { foo } from './foo';\n"),
+        (
+            "/project/index.ts",
+            "import { foo } from './foo';\n\nfoo();\n",
+        ),
+        (
+            "/project/aliased.ts",
+            "import { foo as bar } from './reexport';\n\nbar();\n",
+        ),
+        ("/project/unrelated.ts", "const foo = 1;\n"),
+    ];
+
+    let mut fs = MemoryFileSystem::default();
+    for (path, content) in FILES {
+        fs.insert(Utf8PathBuf::from(path), content.as_bytes());
+    }
+
+    let workspace = server(Box::new(fs), None);
+    let OpenProjectResult { project_key, .. } = workspace
+        .open_project(OpenProjectParams {
+            path: Utf8PathBuf::from("/project").into(),
+            open_uninitialized: true,
+            only_rules: None,
+            skip_rules: None,
+        })
+        .unwrap();
+
+    workspace
+        .scan_project_folder(ScanProjectFolderParams {
+            project_key,
+            path: None,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+        })
+        .unwrap();
+
+    // `foo` in `foo();`
+    let result = workspace
+        .rename(RenameParams {
+            project_key,
+            path: BiomePath::new("/project/index.ts"),
+            symbol_at: TextSize::from(31),
+            new_name: "baz".to_string(),
+        })
+        .unwrap();
+
+    let content = |path: &str| FILES.iter().find(|(p, _)| *p == path).unwrap().1;
+    assert_eq!(
+        result.indels.new_string(content("/project/index.ts")),
+        "import { baz } from './foo';\n\nbaz();\n"
+    );
+
+    let mut module_edits: Vec<_> = result
+        .module_edits
+        .iter()
+        .map(|edit| {
+            (
+                edit.path.as_str(),
+                edit.indels.new_string(content(edit.path.as_str())),
+            )
+        })
+        .collect();
+    module_edits.sort();
+    assert_eq!(
+        module_edits,
+        vec![
+            (
+                "/project/aliased.ts",
+                "import { baz as bar } from './reexport';\n\nbar();\n".to_string()
+            ),
+            (
+                "/project/foo.ts",
+                "export function baz() {}\nbaz();\n".to_string()
+            ),
+            (
+                "/project/reexport.ts",
+                "export { baz } from './foo';\n".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn rename_follows_aliased_reexports() {
+    const FILES: [(&str, &str); 5] = [
+        ("/project/foo.ts", "export function foo() {}\n"),
+        (
+            "/project/renamed.ts",
+            "export { foo as bar } from './foo';\n",
+        ),
+        ("/project/chain.ts", "export { bar } from './renamed';\n"),
+        (
+            "/project/index.ts",
+            "import { bar } from './chain';\n\nbar();\n",
+        ),
+        (
+            "/project/string.ts",
+            "import { \"foo\" as qux } from './foo';\nexport { 'foo' as \"quux\" } from './foo';\n\nqux();\n",
+        ),
+    ];
+
+    let mut fs = MemoryFileSystem::default();
+    for (path, content) in FILES {
+        fs.insert(Utf8PathBuf::from(path), content.as_bytes());
+    }
+
+    let workspace = server(Box::new(fs), None);
+    let OpenProjectResult { project_key, .. } = workspace
+        .open_project(OpenProjectParams {
+            path: Utf8PathBuf::from("/project").into(),
+            open_uninitialized: true,
+            only_rules: None,
+            skip_rules: None,
+        })
+        .unwrap();
+
+    workspace
+        .scan_project_folder(ScanProjectFolderParams {
+            project_key,
+            path: None,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+        })
+        .unwrap();
+
+    // `foo` in `export function foo() {}`
+    let result = workspace
+        .rename(RenameParams {
+            project_key,
+            path: BiomePath::new("/project/foo.ts"),
+            symbol_at: TextSize::from(17),
+            new_name: "baz".to_string(),
+        })
+        .unwrap();
+
+    let content = |path: &str| FILES.iter().find(|(p, _)| *p == path).unwrap().1;
+    assert_eq!(
+        result.indels.new_string(content("/project/foo.ts")),
+        "export function baz() {}\n"
+    );
+
+    let mut module_edits: Vec<_> = result
+        .module_edits
+        .iter()
+        .map(|edit| {
+            (
+                edit.path.as_str(),
+                edit.indels.new_string(content(edit.path.as_str())),
+            )
+        })
+        .collect();
+    module_edits.sort();
+    assert_eq!(
+        module_edits,
+        vec![
+            (
+                "/project/renamed.ts",
+                "export { baz as bar } from './foo';\n".to_string()
+            ),
+            (
+                "/project/string.ts",
+                "import { \"baz\" as qux } from './foo';\nexport { 'baz' as \"quux\" } from './foo';\n\nqux();\n"
+                    .to_string()
+            ),
+        ]
+    );
+}
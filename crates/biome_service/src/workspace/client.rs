@@ -9,8 +9,10 @@ use super::{
 };
 use crate::workspace::{
     CheckFileSizeParams, CheckFileSizeResult, CloseProjectParams, FileFeaturesResult,
-    GetFileContentParams, GetRegisteredTypesParams, GetTypeInfoParams, IsPathIgnoredParams,
-    OpenProjectParams, OpenProjectResult, RageParams, RageResult, ServerInfo,
+    FindReferencesParams, FindReferencesResult, GetFileContentParams, GetModuleDependenciesParams,
+    GetModuleDependenciesResult, GetRegisteredTypesParams, GetTypeInfoParams, GotoDefinitionParams,
+    GotoDefinitionResult, HoverParams, HoverResult, IsPathIgnoredParams, OpenProjectParams,
+    OpenProjectResult, RageParams, RageResult, ServerInfo,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
 use biome_formatter::Printed;
@@ -185,6 +187,13 @@ where
         self.request("biome/get_file_content", params)
     }
 
+    fn get_module_dependencies(
+        &self,
+        params: GetModuleDependenciesParams,
+    ) -> Result<GetModuleDependenciesResult, WorkspaceError> {
+        self.request("biome/get_module_dependencies", params)
+    }
+
     fn check_file_size(
         &self,
         params: CheckFileSizeParams,
@@ -227,6 +236,24 @@ where
         self.request("biome/rename", params)
     }
 
+    fn hover(&self, params: HoverParams) -> Result<HoverResult, WorkspaceError> {
+        self.request("biome/hover", params)
+    }
+
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError> {
+        self.request("biome/goto_definition", params)
+    }
+
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError> {
+        self.request("biome/find_references", params)
+    }
+
     fn close_file(&self, params: CloseFileParams) -> Result<(), WorkspaceError> {
         self.request("biome/close_file", params)
     }
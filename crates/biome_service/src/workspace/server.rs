@@ -1,6 +1,6 @@
 use super::document::Document;
 use super::{
-    ChangeFileParams, CheckFileSizeParams, CheckFileSizeResult, CloseFileParams,
+    ChangeFileEdit, ChangeFileParams, CheckFileSizeParams, CheckFileSizeResult, CloseFileParams,
     CloseProjectParams, FeatureName, FileContent, FileExitsParams, FixFileParams, FixFileResult,
     FormatFileParams, FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams,
     GetFormatterIRParams, GetSemanticModelParams, GetSyntaxTreeParams, GetSyntaxTreeResult,
@@ -14,14 +14,16 @@ use crate::configuration::{LoadedConfiguration, ProjectScanComputer, read_config
 use crate::diagnostics::FileTooLarge;
 use crate::file_handlers::{
     Capabilities, CodeActionsParams, DocumentFileSource, Features, FixAllParams, LintParams,
-    ParseResult,
+    ParseResult, VueFileHandler,
 };
 use crate::projects::Projects;
-use crate::settings::WorkspaceSettingsHandle;
+use crate::settings::{Settings, WorkspaceSettingsHandle};
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, GetRegisteredTypesParams, GetTypeInfoParams,
-    IsPathIgnoredParams, OpenProjectResult, RageEntry, RageParams, RageResult, ScanKind,
-    ServerInfo,
+    FileFeaturesResult, FindReferencesParams, FindReferencesResult, GetFileContentParams,
+    GetModuleDependenciesParams, GetModuleDependenciesResult, GetRegisteredTypesParams,
+    GetTypeInfoParams, GotoDefinitionParams, GotoDefinitionResult, HoverParams, HoverResult,
+    IsPathIgnoredParams, ModuleTextEdit, OpenProjectResult, RageEntry, RageParams, RageResult,
+    ScanKind, ServerInfo, SymbolLocation,
 };
 use crate::workspace_watcher::{OpenFileReason, WatcherSignalKind};
 use crate::{WatcherInstruction, Workspace, WorkspaceError};
@@ -38,22 +40,29 @@ use biome_diagnostics::{
     Diagnostic, DiagnosticExt, Severity, serde::Diagnostic as SerdeDiagnostic,
 };
 use biome_formatter::Printed;
-use biome_fs::{BiomePath, ConfigName};
+use biome_fs::{BiomePath, ConfigName, normalize_path};
+use biome_graphql_semantic::{GraphqlSchema, GraphqlSchemaBuilder};
+use biome_graphql_syntax::GraphqlRoot;
 use biome_grit_patterns::{CompilePatternOptions, GritQuery, compile_pattern_with_options};
+use biome_html_syntax::{HtmlLanguage, HtmlRoot};
+use biome_js_analyze::utils::rename::RenameError;
 use biome_js_syntax::{AnyJsRoot, ModuleKind};
 use biome_json_parser::JsonParserOptions;
-use biome_json_syntax::JsonFileSource;
+use biome_json_schema::{JsonSchema, find_schema_reference, is_remote_reference};
+use biome_json_syntax::{JsonFileSource, JsonRoot};
 use biome_module_graph::ModuleGraph;
 use biome_package::PackageType;
 use biome_parser::AnyParse;
 use biome_plugin_loader::{BiomePlugin, PluginCache, PluginDiagnostic};
 use biome_project_layout::ProjectLayout;
 use biome_resolver::FsWithResolverProxy;
-use biome_rowan::{AstNode, NodeCache, SendNode};
+use biome_rowan::{AstNode, NodeCache, SendNode, TextRange};
+use biome_text_edit::TextEdit;
 use camino::{Utf8Path, Utf8PathBuf};
 use crossbeam::channel::Sender;
 use papaya::{Compute, HashMap, HashSet, Operation};
 use rustc_hash::{FxBuildHasher, FxHashMap};
+use std::collections::BTreeSet;
 use std::panic::RefUnwindSafe;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
@@ -78,6 +87,17 @@ pub struct WorkspaceServer {
     /// Keeps all loaded plugins in memory, per project.
     plugin_caches: Arc<HashMap<Utf8PathBuf, PluginCache>>,
 
+    /// Keeps the JSON Schemas used for validating JSON files in memory,
+    /// indexed by the path of their root document.
+    ///
+    /// Schemas that failed to load are stored as `None`, so that we don't
+    /// attempt to load them again until one of their files changes.
+    json_schemas: HashMap<Utf8PathBuf, Option<Arc<JsonSchema>>, FxBuildHasher>,
+
+    /// Keeps the GraphQL schema of each project in memory, assembled from the
+    /// files that match `graphql.schema`.
+    graphql_schemas: HashMap<ProjectKey, Arc<GraphqlSchema>, FxBuildHasher>,
+
     /// Stores the document (text content + version number) associated with a URL
     pub(super) documents: HashMap<Utf8PathBuf, Document, FxBuildHasher>,
 
@@ -144,6 +164,8 @@ impl WorkspaceServer {
             project_layout: Default::default(),
             module_graph: Default::default(),
             plugin_caches: Default::default(),
+            json_schemas: Default::default(),
+            graphql_schemas: Default::default(),
             documents: Default::default(),
             file_sources: AppendOnlyVec::default(),
             patterns: Default::default(),
@@ -537,6 +559,54 @@ impl WorkspaceServer {
         Ok(parsed)
     }
 
+    /// Applies `edits` to the syntax tree of `document`, without parsing the
+    /// whole file again.
+    ///
+    /// Returns `None` if the file needs to be parsed from scratch, either
+    /// because its language doesn't support incremental reparsing, or because
+    /// one of the edits can't be applied in place.
+    fn reparse(
+        &self,
+        project_key: ProjectKey,
+        path: &Utf8Path,
+        file_source_index: usize,
+        document: &Document,
+        edits: &[ChangeFileEdit],
+        content: &str,
+    ) -> Option<AnyParse> {
+        if edits.is_empty() {
+            return None;
+        }
+
+        let file_source = self.get_source(file_source_index)?;
+        let reparse = self.features.get_capabilities(file_source).parser.reparse?;
+        let settings: WorkspaceSettingsHandle = self
+            .projects
+            .get_settings_based_on_path(project_key, path)?
+            .into();
+        let biome_path = BiomePath::new(path);
+
+        let mut any_parse = document.syntax.as_ref()?.as_ref().ok()?.clone();
+        let mut text = document.content.clone();
+        for edit in edits {
+            let range = std::ops::Range::<usize>::from(edit.range);
+            text.get(range.clone())?;
+            text.replace_range(range, &edit.text);
+            any_parse = reparse(
+                &biome_path,
+                file_source,
+                &any_parse,
+                edit.range,
+                &edit.text,
+                &settings,
+            )?;
+        }
+
+        // The edits must describe the change of content exactly, otherwise
+        // the tree would get out of sync with the file.
+        (text == content).then_some(any_parse)
+    }
+
     /// Checks whether a file is ignored in the top-level config's
     /// `files.includes` or in the feature's `includes`.
     fn is_ignored(&self, project_key: ProjectKey, path: &Utf8Path, features: FeatureName) -> bool {
@@ -601,6 +671,210 @@ impl WorkspaceServer {
         }
     }
 
+    /// Returns the JSON Schema the JSON file at `path` should be validated
+    /// against, if any.
+    ///
+    /// A local schema referenced by the `$schema` property of the file takes
+    /// precedence over the schemas associated with the file through the
+    /// `json.schemas` configuration.
+    fn get_json_schema(
+        &self,
+        path: &BiomePath,
+        parse: &AnyParse,
+        language: &DocumentFileSource,
+        settings: &Settings,
+    ) -> Option<Arc<JsonSchema>> {
+        let schema_path = Self::get_json_schema_path(path, parse, language, settings)?;
+        self.load_json_schema(schema_path)
+    }
+
+    /// Returns the path of the root document of the JSON Schema the JSON file
+    /// at `path` should be validated against, if any.
+    fn get_json_schema_path(
+        path: &BiomePath,
+        parse: &AnyParse,
+        language: &DocumentFileSource,
+        settings: &Settings,
+    ) -> Option<Utf8PathBuf> {
+        // Biome's own configuration files are validated when deserialized.
+        if language.to_json_file_source().is_none() || path.is_config() {
+            return None;
+        }
+
+        let root: JsonRoot = parse.tree();
+        match find_schema_reference(&root) {
+            Some(reference) if !is_remote_reference(reference.as_str()) => {
+                Some(normalize_path(&path.parent()?.join(reference.as_str())))
+            }
+            _ => Some(settings.get_json_schema_for_path(path)?.to_path_buf()),
+        }
+    }
+
+    /// Loads the JSON Schema whose root document is at `schema_path`, unless
+    /// it's already loaded.
+    fn load_json_schema(&self, schema_path: Utf8PathBuf) -> Option<Arc<JsonSchema>> {
+        let json_schemas = self.json_schemas.pin();
+        if let Some(schema) = json_schemas.get(&schema_path) {
+            return schema.clone();
+        }
+
+        let schema = match JsonSchema::load(self.fs.as_ref(), &schema_path) {
+            Ok(schema) => Some(Arc::new(schema)),
+            Err(error) => {
+                warn!("Could not load the JSON Schema {schema_path}: {error:?}");
+                None
+            }
+        };
+        json_schemas.insert(schema_path, schema.clone());
+
+        schema
+    }
+
+    /// Returns the paths of the documents of the JSON Schema the JSON file at
+    /// `path` is validated against.
+    ///
+    /// The path of the root document is returned even if the schema can't be
+    /// loaded, so that creating it can be noticed. The file is parsed if it
+    /// isn't open in the workspace.
+    fn get_json_schema_dependencies(
+        &self,
+        project_key: ProjectKey,
+        path: &BiomePath,
+    ) -> Vec<Utf8PathBuf> {
+        let language = self.get_file_source(path);
+        if language.to_json_file_source().is_none() {
+            return Vec::new();
+        }
+        let Some(settings) = self.projects.get_settings_based_on_path(project_key, path) else {
+            return Vec::new();
+        };
+
+        let parse = match self.get_parse(path) {
+            Ok(parse) => parse,
+            Err(_) => {
+                let Ok(content) = self.fs.read_file_from_path(path) else {
+                    return Vec::new();
+                };
+                let file_source_index = self.insert_source(language);
+                match self.parse(
+                    project_key,
+                    path,
+                    &content,
+                    file_source_index,
+                    &mut NodeCache::default(),
+                ) {
+                    Ok(parsed) => parsed.any_parse,
+                    Err(_) => return Vec::new(),
+                }
+            }
+        };
+
+        let Some(schema_path) = Self::get_json_schema_path(path, &parse, &language, &settings)
+        else {
+            return Vec::new();
+        };
+        let mut paths = vec![schema_path.clone()];
+        if let Some(schema) = self.load_json_schema(schema_path) {
+            paths.extend(schema.document_paths().map(Utf8Path::to_path_buf));
+        }
+
+        paths
+    }
+
+    /// Returns the GraphQL schema the GraphQL file at `path` should be
+    /// validated against, if the project configures one.
+    ///
+    /// The schema is assembled from the files of the project that match the
+    /// `graphql.schema` globs of its root configuration, in the order of their
+    /// paths. The locations of its definitions are relative to the project.
+    fn get_graphql_schema(
+        &self,
+        project_key: ProjectKey,
+        language: &DocumentFileSource,
+    ) -> Option<Arc<GraphqlSchema>> {
+        language.to_graphql_file_source()?;
+
+        let graphql_schemas = self.graphql_schemas.pin();
+        if let Some(schema) = graphql_schemas.get(&project_key) {
+            return Some(schema.clone());
+        }
+
+        let settings = self.projects.get_root_settings(project_key)?;
+        let project_path = self.projects.get_project_path(project_key)?;
+        let documents = self.documents.pin();
+        let mut schema_files: Vec<_> = documents
+            .iter()
+            .filter(|(path, document)| {
+                self.is_graphql_schema_document(&project_path, &settings, path, document)
+            })
+            .filter_map(|(path, document)| {
+                let parse = document.syntax.as_ref()?.as_ref().ok()?;
+                let path = path.strip_prefix(&project_path).unwrap_or(path);
+                Some((path, parse.tree::<GraphqlRoot>()))
+            })
+            .collect();
+        if schema_files.is_empty() {
+            return None;
+        }
+        schema_files.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut builder = GraphqlSchemaBuilder::default();
+        for (path, root) in &schema_files {
+            builder.add_document(path, root);
+        }
+        let schema = Arc::new(builder.build());
+        graphql_schemas.insert(project_key, schema.clone());
+
+        Some(schema)
+    }
+
+    /// Returns the paths of the files that define the GraphQL schema of the
+    /// project, if the GraphQL file at `path` is validated against it.
+    fn get_graphql_schema_dependencies(
+        &self,
+        project_key: ProjectKey,
+        path: &Utf8Path,
+    ) -> Vec<Utf8PathBuf> {
+        if self
+            .get_file_source(path)
+            .to_graphql_file_source()
+            .is_none()
+        {
+            return Vec::new();
+        }
+        let (Some(settings), Some(project_path)) = (
+            self.projects.get_root_settings(project_key),
+            self.projects.get_project_path(project_key),
+        ) else {
+            return Vec::new();
+        };
+
+        self.documents
+            .pin()
+            .iter()
+            .filter(|(schema_path, document)| {
+                self.is_graphql_schema_document(&project_path, &settings, schema_path, document)
+            })
+            .map(|(schema_path, _)| schema_path.clone())
+            .collect()
+    }
+
+    /// Returns whether the open `document` at `path` is one of the files that
+    /// define the GraphQL schema of the project at `project_path`.
+    fn is_graphql_schema_document(
+        &self,
+        project_path: &Utf8Path,
+        settings: &Settings,
+        path: &Utf8Path,
+        document: &Document,
+    ) -> bool {
+        path.starts_with(project_path)
+            && settings.is_graphql_schema_file(path)
+            && self
+                .get_source(document.file_source_index)
+                .is_some_and(|source| source.to_graphql_file_source().is_some())
+    }
+
     /// It updates the nested settings of the project assigned to the `project_key`.
     ///
     /// If a configuration file contains errors, it's not processed and the project isn't updated.
@@ -798,8 +1072,15 @@ impl WorkspaceServer {
     ) {
         let (added_or_changed_paths, removed_paths) = match signal_kind {
             WatcherSignalKind::AddedOrChanged(_) => {
-                let Some(root) = root.and_then(SendNode::into_node).and_then(AnyJsRoot::cast)
-                else {
+                let Some(root) = root.and_then(|root| {
+                    if path.extension() == Some("vue") {
+                        // Vue components are parsed as HTML, their script is parsed again
+                        let root = root.into_node::<HtmlLanguage>().and_then(HtmlRoot::cast)?;
+                        VueFileHandler::script_root(&root)
+                    } else {
+                        root.into_node().and_then(AnyJsRoot::cast)
+                    }
+                }) else {
                     return;
                 };
 
@@ -816,6 +1097,29 @@ impl WorkspaceServer {
         );
     }
 
+    /// Returns a module graph that contains the module at `path`.
+    ///
+    /// Modules that weren't indexed by the scanner, for instance because the
+    /// project doesn't need the module graph, are added to a temporary graph of
+    /// their own, so that their types can still be inferred.
+    fn module_graph_with_path(&self, path: &BiomePath, parse: &AnyParse) -> Arc<ModuleGraph> {
+        if self.module_graph.module_info_for_path(path).is_some() {
+            return self.module_graph.clone();
+        }
+
+        let module_graph = ModuleGraph::default();
+        if let Some(root) = parse.root().into_node().and_then(AnyJsRoot::cast) {
+            module_graph.update_graph_for_js_paths(
+                self.fs.as_ref(),
+                &self.project_layout,
+                &[(path, root)],
+                &[],
+            );
+        }
+
+        Arc::new(module_graph)
+    }
+
     /// Updates the state of any services relevant to the given `path`.
     pub(super) fn update_service_data(
         &self,
@@ -830,6 +1134,24 @@ impl WorkspaceServer {
 
         self.update_module_graph(signal_kind, &path, root);
 
+        self.json_schemas.pin().retain(|schema_path, schema| {
+            schema_path != path.as_path()
+                && schema
+                    .as_ref()
+                    .is_none_or(|schema| !schema.depends_on(path.as_path()))
+        });
+
+        if DocumentFileSource::from_path(&path)
+            .to_graphql_file_source()
+            .is_some()
+        {
+            self.graphql_schemas.pin().retain(|project_key, _| {
+                self.projects
+                    .get_root_settings(*project_key)
+                    .is_none_or(|settings| !settings.is_graphql_schema_file(&path))
+            });
+        }
+
         match signal_kind {
             WatcherSignalKind::AddedOrChanged(OpenFileReason::InitialScan) => {
                 // We'll send a single signal at the end of the scan.
@@ -991,6 +1313,7 @@ impl Workspace for WorkspaceServer {
         } else {
             self.projects
                 .set_root_settings(params.project_key, settings);
+            self.graphql_schemas.pin().remove(&params.project_key);
         }
 
         Ok(UpdateSettingsResult {
@@ -1201,6 +1524,43 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(WorkspaceError::not_found)
     }
 
+    fn get_module_dependencies(
+        &self,
+        params: GetModuleDependenciesParams,
+    ) -> Result<GetModuleDependenciesResult, WorkspaceError> {
+        let mut dependencies = BTreeSet::new();
+        let mut queue = vec![params.path.to_path_buf()];
+        while let Some(path) = queue.pop() {
+            let Some(module_info) = self.module_graph.module_info_for_path(&path) else {
+                continue;
+            };
+
+            for resolved_path in module_info.all_import_paths() {
+                let Some(resolved_path) = resolved_path.as_path() else {
+                    continue;
+                };
+                if resolved_path != params.path.as_path()
+                    && dependencies.insert(resolved_path.to_path_buf())
+                {
+                    queue.push(resolved_path.to_path_buf());
+                }
+            }
+        }
+
+        // The documents of the JSON Schema and the files of the GraphQL schema
+        // can change the diagnostics of a file just like modules do for the
+        // diagnostics of a JavaScript file.
+        dependencies.extend(self.get_json_schema_dependencies(params.project_key, &params.path));
+        dependencies.extend(self.get_graphql_schema_dependencies(params.project_key, &params.path));
+
+        let dependents = self.module_graph.find_dependents(params.path.as_path());
+
+        Ok(GetModuleDependenciesResult {
+            dependencies: dependencies.into_iter().map(BiomePath::from).collect(),
+            dependents: dependents.into_iter().map(BiomePath::from).collect(),
+        })
+    }
+
     fn check_file_size(
         &self,
         params: CheckFileSizeParams,
@@ -1224,56 +1584,61 @@ impl Workspace for WorkspaceServer {
             path,
             content,
             version,
+            edits,
         }: ChangeFileParams,
     ) -> Result<(), WorkspaceError> {
         let documents = self.documents.pin();
-        let (index, opened_by_scanner, existing_version) = documents
+        let document = documents
             .get(path.as_path())
-            .map(|document| {
-                (
-                    document.file_source_index,
-                    document.opened_by_scanner,
-                    document.version,
-                )
-            })
             .ok_or_else(WorkspaceError::not_found)?;
+        let index = document.file_source_index;
+        let opened_by_scanner = document.opened_by_scanner;
 
-        if existing_version.is_some_and(|existing_version| existing_version >= version) {
+        if document
+            .version
+            .is_some_and(|existing_version| existing_version >= version)
+        {
             warn!(%version, %path, "outdated_file_change");
             return Ok(()); // Safely ignore older versions.
         }
 
-        // We remove the node cache for the document, if it exists.
-        // This is done so that we need to hold the lock as short as possible
-        // (it's released directly after the statement). The potential downside
-        // is that if two calls to `change_file()` happen concurrently, then the
-        // second would have a cache miss, and not update the cache either.
-        // This seems an unlikely scenario however, and the impact is small
-        // anyway, so this seems a worthwhile tradeoff.
-        let node_cache = self.node_cache.lock().unwrap().remove(path.as_path());
-
-        let persist_node_cache = node_cache.is_some();
-        let mut node_cache = node_cache.unwrap_or_default();
+        let any_parse = match self.reparse(project_key, &path, index, document, &edits, &content) {
+            Some(any_parse) => any_parse,
+            None => {
+                // We remove the node cache for the document, if it exists.
+                // This is done so that we need to hold the lock as short as possible
+                // (it's released directly after the statement). The potential downside
+                // is that if two calls to `change_file()` happen concurrently, then the
+                // second would have a cache miss, and not update the cache either.
+                // This seems an unlikely scenario however, and the impact is small
+                // anyway, so this seems a worthwhile tradeoff.
+                let node_cache = self.node_cache.lock().unwrap().remove(path.as_path());
+
+                let persist_node_cache = node_cache.is_some();
+                let mut node_cache = node_cache.unwrap_or_default();
+
+                let parsed = self.parse(project_key, &path, &content, index, &mut node_cache)?;
+
+                if persist_node_cache {
+                    self.node_cache
+                        .lock()
+                        .unwrap()
+                        .insert(path.to_path_buf(), node_cache);
+                }
 
-        let parsed = self.parse(project_key, &path, &content, index, &mut node_cache)?;
-        let root = parsed.any_parse.root();
+                parsed.any_parse
+            }
+        };
+        let root = any_parse.root();
 
         let document = Document {
             content,
             version: Some(version),
             file_source_index: index,
-            syntax: Some(Ok(parsed.any_parse)),
+            syntax: Some(Ok(any_parse)),
             opened_by_scanner,
         };
 
-        if persist_node_cache {
-            self.node_cache
-                .lock()
-                .unwrap()
-                .insert(path.to_path_buf(), node_cache);
-        }
-
-        let opened_by_scanner = document.opened_by_scanner;
         documents
             .insert(path.clone().into(), document)
             .ok_or_else(WorkspaceError::not_found)?;
@@ -1330,6 +1695,14 @@ impl Workspace for WorkspaceServer {
                         &settings.get_plugins_for_path(&path),
                     )
                     .map_err(WorkspaceError::plugin_errors)?;
+                let (json_schema, graphql_schema) = if categories.contains(RuleCategory::Lint) {
+                    (
+                        self.get_json_schema(&path, &parse, &language, &settings),
+                        self.get_graphql_schema(project_key, &language),
+                    )
+                } else {
+                    (None, None)
+                };
                 let results = lint(LintParams {
                     parse,
                     workspace: &settings.into(),
@@ -1348,6 +1721,8 @@ impl Workspace for WorkspaceServer {
                     } else {
                         Vec::new()
                     },
+                    json_schema,
+                    graphql_schema,
                 });
 
                 (
@@ -1418,6 +1793,8 @@ impl Workspace for WorkspaceServer {
             .projects
             .get_settings_based_on_path(project_key, &path)
             .ok_or_else(WorkspaceError::no_project)?;
+        let json_schema = self.get_json_schema(&path, &parse, &language, &settings);
+        let graphql_schema = self.get_graphql_schema(project_key, &language);
         Ok(code_actions(CodeActionsParams {
             parse,
             range,
@@ -1432,6 +1809,8 @@ impl Workspace for WorkspaceServer {
             enabled_rules,
             plugins: Vec::new(),
             categories,
+            json_schema,
+            graphql_schema,
         }))
     }
 
@@ -1565,6 +1944,14 @@ impl Workspace for WorkspaceServer {
             )
             .map_err(WorkspaceError::plugin_errors)?;
         let language = self.get_file_source(&path);
+        let (json_schema, graphql_schema) = if rule_categories.contains(RuleCategory::Lint) {
+            (
+                self.get_json_schema(&path, &parse, &language, &settings),
+                self.get_graphql_schema(project_key, &language),
+            )
+        } else {
+            (None, None)
+        };
         fix_all(FixAllParams {
             parse,
             fix_file_mode,
@@ -1584,22 +1971,170 @@ impl Workspace for WorkspaceServer {
             } else {
                 Vec::new()
             },
+            json_schema,
+            graphql_schema,
         })
     }
 
+    /// Renames the symbol at the given position.
+    ///
+    /// Like [Self::find_references], the modules that import the renamed
+    /// declaration, directly or through re-exports, are renamed as well.
     fn rename(&self, params: super::RenameParams) -> Result<RenameResult, WorkspaceError> {
         let capabilities = self.get_file_capabilities(&params.path);
-        let rename = capabilities
+        let rename_declaration = capabilities
             .analyzer
-            .rename
+            .rename_declaration
             .ok_or_else(self.build_capability_error(&params.path))?;
 
         let parse = self.get_parse(&params.path)?;
-        let result = rename(&params.path, parse, params.symbol_at, params.new_name)?;
+        let module_graph = self.module_graph_with_path(&params.path, &parse);
+        let declaration = rename_declaration(&params.path, parse, &module_graph, params.symbol_at)
+            .ok_or_else(|| {
+                WorkspaceError::RenameError(RenameError::CannotFindDeclaration(
+                    params.new_name.clone(),
+                ))
+            })?;
+
+        let mut paths = self.module_graph.find_importers(&declaration.location.path);
+        paths.insert(declaration.location.path.to_path_buf());
+        paths.insert(params.path.to_path_buf());
+
+        let mut result = RenameResult {
+            range: TextRange::default(),
+            indels: TextEdit::default(),
+            module_edits: Vec::new(),
+        };
+        for path in paths {
+            let path = BiomePath::new(path);
+            let is_declaration_path = path == declaration.location.path;
+            let Some(rename) = self.get_file_capabilities(&path).analyzer.rename else {
+                continue;
+            };
+            // The module of the declaration must be renamed, while the other
+            // modules of the graph that aren't open in the workspace are skipped.
+            let parse = match self.get_parse(&path) {
+                Ok(parse) => parse,
+                Err(error) if is_declaration_path => return Err(error),
+                Err(_) => continue,
+            };
+
+            let module_graph = self.module_graph_with_path(&path, &parse);
+            let Some((range, indels)) =
+                rename(&path, parse, &module_graph, &declaration, &params.new_name)?
+            else {
+                if is_declaration_path {
+                    return Err(WorkspaceError::RenameError(RenameError::CannotBeRenamed {
+                        original_name: declaration.name,
+                        original_range: declaration.location.range,
+                        new_name: params.new_name,
+                    }));
+                }
+                continue;
+            };
+
+            if path == params.path {
+                result.range = range;
+                result.indels = indels;
+            } else {
+                result.module_edits.push(ModuleTextEdit {
+                    path,
+                    range,
+                    indels,
+                });
+            }
+        }
 
         Ok(result)
     }
 
+    fn hover(&self, params: HoverParams) -> Result<HoverResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let hover = capabilities
+            .analyzer
+            .hover
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let module_graph = self.module_graph_with_path(&params.path, &parse);
+        Ok(hover(&params.path, parse, module_graph, params.symbol_at))
+    }
+
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let goto_definition = capabilities
+            .analyzer
+            .goto_definition
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let module_graph = self.module_graph_with_path(&params.path, &parse);
+        let definitions = goto_definition(&params.path, parse, &module_graph, params.symbol_at)
+            .into_iter()
+            .collect();
+
+        Ok(GotoDefinitionResult { definitions })
+    }
+
+    /// Finds the references of the symbol at the given position.
+    ///
+    /// Besides the file of the declaration, only the modules that import it,
+    /// directly or through re-exports, can reference the symbol.
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let goto_definition = capabilities
+            .analyzer
+            .goto_definition
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let module_graph = self.module_graph_with_path(&params.path, &parse);
+        let Some(definition) =
+            goto_definition(&params.path, parse, &module_graph, params.symbol_at)
+        else {
+            return Ok(FindReferencesResult::default());
+        };
+
+        let mut paths = self.module_graph.find_importers(&definition.path);
+        paths.insert(definition.path.to_path_buf());
+        paths.insert(params.path.to_path_buf());
+
+        let mut references = Vec::new();
+        for path in paths {
+            let path = BiomePath::new(path);
+            let Some(find_references) = self.get_file_capabilities(&path).analyzer.find_references
+            else {
+                continue;
+            };
+            // Modules of the graph that aren't open in the workspace, such as
+            // dependencies, are skipped.
+            let Ok(parse) = self.get_parse(&path) else {
+                continue;
+            };
+
+            let module_graph = self.module_graph_with_path(&path, &parse);
+            let ranges = find_references(
+                &path,
+                parse,
+                &module_graph,
+                &definition,
+                params.include_declaration,
+            );
+            references.extend(ranges.into_iter().map(|range| SymbolLocation {
+                path: path.clone(),
+                range,
+            }));
+        }
+
+        Ok(FindReferencesResult { references })
+    }
+
     /// Closes a file that is opened in the workspace.
     ///
     /// This only unloads the document from the workspace if the file is NOT
@@ -1688,9 +2223,7 @@ impl Workspace for WorkspaceServer {
         let parse = self.get_parse(&path)?;
 
         let document_file_source = self.get_file_source(&path);
-        let matches = search(&path, &document_file_source, parse, query, settings.into())?;
-
-        Ok(SearchResults { path, matches })
+        search(&path, &document_file_source, parse, query, settings.into())
     }
 
     fn drop_pattern(&self, params: super::DropPatternParams) -> Result<(), WorkspaceError> {
@@ -1,6 +1,7 @@
 use super::*;
 use crate::workspace::ScanKind;
 use biome_fs::MemoryFileSystem;
+use biome_js_syntax::{JsLanguage, JsVariableStatement};
 use crossbeam::channel::bounded;
 use tokio::sync::watch;
 
@@ -58,3 +59,112 @@ fn commonjs_file_rejects_import_statement() {
         Err(error) => panic!("File not available: {error}"),
     }
 }
+
+#[test]
+fn change_file_reparses_edited_token() {
+    let (watcher_tx, _) = bounded(0);
+    let (service_data_tx, _) = watch::channel(ServiceDataNotification::Updated);
+    let workspace = WorkspaceServer::new(
+        Box::new(MemoryFileSystem::default()),
+        watcher_tx,
+        service_data_tx,
+        None,
+    );
+    let project_key = workspace
+        .open_project(OpenProjectParams {
+            path: BiomePath::new("/"),
+            open_uninitialized: true,
+            skip_rules: None,
+            only_rules: None,
+        })
+        .unwrap()
+        .project_key;
+
+    workspace
+        .open_file(OpenFileParams {
+            project_key,
+            path: BiomePath::new("/project/a.js"),
+            content: FileContent::FromClient {
+                content: "let foo = 1;\nfoo();\n".to_string(),
+                version: 0,
+            },
+            document_file_source: None,
+            persist_node_cache: false,
+        })
+        .unwrap();
+
+    let statement_key = |parse: &AnyParse| {
+        let root = parse.tree::<AnyJsRoot>();
+        root.syntax()
+            .descendants()
+            .find_map(JsVariableStatement::cast)
+            .map(|statement| statement.syntax().key())
+    };
+    let parse = workspace.get_parse("/project/a.js".into()).unwrap();
+    let key = statement_key(&parse);
+
+    workspace
+        .change_file(ChangeFileParams {
+            project_key,
+            path: BiomePath::new("/project/a.js"),
+            content: "let foo = 1;\nbar();\n".to_string(),
+            version: 1,
+            edits: vec![ChangeFileEdit {
+                range: TextRange::new(13.into(), 16.into()),
+                text: "bar".to_string(),
+            }],
+        })
+        .unwrap();
+
+    let parse = workspace.get_parse("/project/a.js".into()).unwrap();
+    assert_eq!(
+        parse.syntax::<JsLanguage>().to_string(),
+        "let foo = 1;\nbar();\n"
+    );
+    assert!(parse.diagnostics().is_empty());
+    // The statement before the edit wasn't parsed again.
+    assert!(statement_key(&parse) == key);
+
+    // Edits of several tokens reparse the statement that contains them.
+    workspace
+        .change_file(ChangeFileParams {
+            project_key,
+            path: BiomePath::new("/project/a.js"),
+            content: "let foo = 1;\nbar(foo.baz, 2);\n".to_string(),
+            version: 2,
+            edits: vec![ChangeFileEdit {
+                range: TextRange::new(17.into(), 17.into()),
+                text: "foo.baz, 2".to_string(),
+            }],
+        })
+        .unwrap();
+
+    let parse = workspace.get_parse("/project/a.js".into()).unwrap();
+    assert_eq!(
+        parse.syntax::<JsLanguage>().to_string(),
+        "let foo = 1;\nbar(foo.baz, 2);\n"
+    );
+    assert!(parse.diagnostics().is_empty());
+    assert!(statement_key(&parse) == key);
+
+    // Other edits fall back to a full reparse.
+    workspace
+        .change_file(ChangeFileParams {
+            project_key,
+            path: BiomePath::new("/project/a.js"),
+            content: "let foo = 1;\nbar(foo.baz, 2);\nbaz();\n".to_string(),
+            version: 3,
+            edits: vec![ChangeFileEdit {
+                range: TextRange::new(30.into(), 30.into()),
+                text: "baz();\n".to_string(),
+            }],
+        })
+        .unwrap();
+
+    let parse = workspace.get_parse("/project/a.js".into()).unwrap();
+    assert_eq!(
+        parse.syntax::<JsLanguage>().to_string(),
+        "let foo = 1;\nbar(foo.baz, 2);\nbaz();\n"
+    );
+    assert!(parse.diagnostics().is_empty());
+}
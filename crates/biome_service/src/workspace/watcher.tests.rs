@@ -188,6 +188,7 @@ fn close_modified_file_from_client_before_watcher() {
             path: BiomePath::new("/project/a.js"),
             content: FILE_CONTENT_MODIFIED.to_string(),
             version: 2,
+            edits: Vec::new(),
         })
         .expect("can change file");
 
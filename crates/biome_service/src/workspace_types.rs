@@ -587,7 +587,7 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 25] {
+pub fn methods() -> [WorkspaceMethod; 29] {
     [
         workspace_method!(file_features),
         workspace_method!(update_settings),
@@ -599,6 +599,7 @@ pub fn methods() -> [WorkspaceMethod; 25] {
         workspace_method!(file_exists),
         workspace_method!(check_file_size),
         workspace_method!(get_file_content),
+        workspace_method!(get_module_dependencies),
         workspace_method!(get_control_flow_graph),
         workspace_method!(get_formatter_ir),
         workspace_method!(get_type_info),
@@ -611,6 +612,9 @@ pub fn methods() -> [WorkspaceMethod; 25] {
         workspace_method!(format_on_type),
         workspace_method!(fix_file),
         workspace_method!(rename),
+        workspace_method!(hover),
+        workspace_method!(goto_definition),
+        workspace_method!(find_references),
         workspace_method!(parse_pattern),
         workspace_method!(search_pattern),
         workspace_method!(drop_pattern),
@@ -5,10 +5,11 @@ use wasm_bindgen::prelude::*;
 
 use biome_fs::MemoryFileSystem;
 use biome_service::workspace::{
-    self, ChangeFileParams, CloseFileParams, FixFileParams, FormatFileParams, FormatOnTypeParams,
-    FormatRangeParams, GetControlFlowGraphParams, GetFileContentParams, GetFormatterIRParams,
-    GetRegisteredTypesParams, GetSemanticModelParams, GetSyntaxTreeParams, GetTypeInfoParams,
-    OpenProjectParams, PullActionsParams, PullDiagnosticsParams, RenameParams,
+    self, ChangeFileParams, CloseFileParams, FindReferencesParams, FixFileParams, FormatFileParams,
+    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFileContentParams,
+    GetFormatterIRParams, GetModuleDependenciesParams, GetRegisteredTypesParams,
+    GetSemanticModelParams, GetSyntaxTreeParams, GetTypeInfoParams, GotoDefinitionParams,
+    HoverParams, OpenProjectParams, PullActionsParams, PullDiagnosticsParams, RenameParams,
     UpdateSettingsParams,
 };
 use biome_service::workspace::{OpenFileParams, SupportsFeatureParams};
@@ -90,6 +91,22 @@ impl Workspace {
         self.inner.get_file_content(params).map_err(into_error)
     }
 
+    #[wasm_bindgen(js_name = getModuleDependencies)]
+    pub fn get_module_dependencies(
+        &self,
+        params: IGetModuleDependenciesParams,
+    ) -> Result<IGetModuleDependenciesResult, Error> {
+        let params: GetModuleDependenciesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self
+            .inner
+            .get_module_dependencies(params)
+            .map_err(into_error)?;
+        to_value(&result)
+            .map(IGetModuleDependenciesResult::from)
+            .map_err(into_error)
+    }
+
     #[wasm_bindgen(js_name = getSyntaxTree)]
     pub fn get_syntax_tree(
         &self,
@@ -222,6 +239,41 @@ impl Workspace {
             .map(IRenameResult::from)
             .map_err(into_error)
     }
+
+    pub fn hover(&self, params: IHoverParams) -> Result<IHoverResult, Error> {
+        let params: HoverParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.hover(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IHoverResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = gotoDefinition)]
+    pub fn goto_definition(
+        &self,
+        params: IGotoDefinitionParams,
+    ) -> Result<IGotoDefinitionResult, Error> {
+        let params: GotoDefinitionParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.goto_definition(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IGotoDefinitionResult::from)
+            .map_err(into_error)
+    }
+
+    #[wasm_bindgen(js_name = findReferences)]
+    pub fn find_references(
+        &self,
+        params: IFindReferencesParams,
+    ) -> Result<IFindReferencesResult, Error> {
+        let params: FindReferencesParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.find_references(params).map_err(into_error)?;
+        to_value(&result)
+            .map(IFindReferencesResult::from)
+            .map_err(into_error)
+    }
 }
 
 impl Default for Workspace {
@@ -0,0 +1,91 @@
+use crate::prelude::*;
+use biome_diagnostics::category;
+use biome_formatter::comments::{
+    CommentKind, CommentPlacement, CommentStyle, Comments, DecoratedComment, SourceComment,
+};
+use biome_formatter::formatter::Formatter;
+use biome_formatter::{FormatResult, FormatRule, write};
+use biome_rowan::{AstNode, SyntaxTriviaPieceComments};
+use biome_suppression::parse_suppression_comment;
+use biome_yaml_syntax::{YamlDocument, YamlLanguage, YamlSyntaxKind};
+
+pub type YamlComments = Comments<YamlLanguage>;
+
+#[derive(Default)]
+pub struct FormatYamlLeadingComment;
+
+impl FormatRule<SourceComment<YamlLanguage>> for FormatYamlLeadingComment {
+    type Context = YamlFormatContext;
+
+    fn fmt(
+        &self,
+        comment: &SourceComment<YamlLanguage>,
+        f: &mut Formatter<Self::Context>,
+    ) -> FormatResult<()> {
+        write!(f, [comment.piece().as_piece()])
+    }
+}
+
+/// YAML only has line comments, which start with `#`
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct YamlCommentStyle;
+
+impl CommentStyle for YamlCommentStyle {
+    type Language = YamlLanguage;
+
+    fn is_suppression(text: &str) -> bool {
+        parse_suppression_comment(text)
+            .filter_map(Result::ok)
+            .flat_map(|suppression| suppression.categories)
+            .any(|(key, ..)| key == category!("format"))
+    }
+
+    fn get_comment_kind(_comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind {
+        CommentKind::Line
+    }
+
+    fn place_comment(
+        &self,
+        comment: DecoratedComment<Self::Language>,
+    ) -> CommentPlacement<Self::Language> {
+        handle_indicator_comment(comment)
+    }
+}
+
+/// Makes the comments that follow an indicator on the same line, such as the `:` of a mapping
+/// entry or the `---` of a document, dangling comments of the node that contains the indicator.
+///
+/// They are kept on the line of the indicator, instead of becoming leading comments of the
+/// node that follows it on the next line.
+fn handle_indicator_comment(
+    comment: DecoratedComment<YamlLanguage>,
+) -> CommentPlacement<YamlLanguage> {
+    if !comment.text_position().is_end_of_line() {
+        return CommentPlacement::Default(comment);
+    }
+
+    let enclosing_node = comment.enclosing_node();
+    let is_after_indicator = match enclosing_node.kind() {
+        YamlSyntaxKind::YAML_BLOCK_MAP_IMPLICIT_VALUE
+        | YamlSyntaxKind::YAML_BLOCK_MAP_EXPLICIT_KEY
+        | YamlSyntaxKind::YAML_BLOCK_MAP_EXPLICIT_VALUE
+        | YamlSyntaxKind::YAML_BLOCK_SEQUENCE_ENTRY => comment.preceding_node().is_none(),
+        YamlSyntaxKind::YAML_DOCUMENT => {
+            let comment_range = comment.piece().text_range();
+            YamlDocument::cast_ref(enclosing_node).is_some_and(|document| {
+                document.dashdashdash_token().is_some_and(|dashes| {
+                    dashes.text_trimmed_range().end() <= comment_range.start()
+                }) && document
+                    .node()
+                    .is_none_or(|node| comment_range.end() <= node.range().start())
+            })
+        }
+        _ => false,
+    };
+
+    if is_after_indicator {
+        CommentPlacement::dangling(enclosing_node.clone(), comment)
+    } else {
+        CommentPlacement::Default(comment)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::prelude::*;
+use biome_formatter::{FormatOwnedWithRule, FormatRefWithRule, FormatResult, FormatToken};
+use biome_yaml_syntax::{YamlSyntaxNode, YamlSyntaxToken, map_syntax_node};
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FormatYamlSyntaxNode;
+
+impl FormatRule<YamlSyntaxNode> for FormatYamlSyntaxNode {
+    type Context = YamlFormatContext;
+
+    fn fmt(&self, node: &YamlSyntaxNode, f: &mut YamlFormatter) -> FormatResult<()> {
+        map_syntax_node!(node.clone(), node => node.format().fmt(f))
+    }
+}
+
+impl AsFormat<YamlFormatContext> for YamlSyntaxNode {
+    type Format<'a> = FormatRefWithRule<'a, Self, FormatYamlSyntaxNode>;
+
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(self, FormatYamlSyntaxNode)
+    }
+}
+
+impl IntoFormat<YamlFormatContext> for YamlSyntaxNode {
+    type Format = FormatOwnedWithRule<Self, FormatYamlSyntaxNode>;
+
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, FormatYamlSyntaxNode)
+    }
+}
+
+/// Format implementation specific to Yaml tokens.
+pub(crate) type FormatYamlSyntaxToken = FormatToken<YamlFormatContext>;
+
+impl AsFormat<YamlFormatContext> for YamlSyntaxToken {
+    type Format<'a> = FormatRefWithRule<'a, Self, FormatYamlSyntaxToken>;
+
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(self, FormatYamlSyntaxToken::default())
+    }
+}
+
+impl IntoFormat<YamlFormatContext> for YamlSyntaxToken {
+    type Format = FormatOwnedWithRule<Self, FormatYamlSyntaxToken>;
+
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, FormatYamlSyntaxToken::default())
+    }
+}
@@ -0,0 +1,362 @@
+use crate::prelude::*;
+use biome_formatter::{QuoteStyle, format_args, write};
+use biome_rowan::TextSize;
+use biome_yaml_syntax::{
+    YamlBlockCollection, YamlFlowInBlockNode, YamlSyntaxKind, YamlSyntaxNode, YamlSyntaxToken,
+};
+
+/// Writes a line break followed by `blank_lines` empty lines.
+///
+/// The empty lines are written as text because the printer collapses consecutive line breaks.
+/// Unlike line breaks, they don't get indented, so they don't contain trailing whitespace.
+pub(crate) fn write_line_break(
+    f: &mut YamlFormatter,
+    blank_lines: usize,
+    position: TextSize,
+) -> FormatResult<()> {
+    if blank_lines > 0 {
+        write!(f, [dynamic_text(&"\n".repeat(blank_lines + 1), position)])?;
+    }
+    write!(f, [hard_line_break()])
+}
+
+/// Joins the entries of a block collection or the documents of a stream, one per line.
+///
+/// At most one empty line is kept between two entries, unless the previous entry ends with a
+/// block scalar that keeps its trailing line breaks: these empty lines are part of its content.
+pub(crate) fn write_block_entries<I, F>(f: &mut YamlFormatter, entries: I) -> FormatResult<()>
+where
+    I: IntoIterator<Item = (YamlSyntaxNode, F)>,
+    F: Format<YamlFormatContext>,
+{
+    let mut previous: Option<YamlSyntaxNode> = None;
+    for (node, format_node) in entries {
+        if let Some(previous) = previous {
+            let lines_before = get_lines_before(&node);
+            let position = node.text_trimmed_range().start();
+            if previous
+                .last_token()
+                .is_some_and(|token| is_kept_block_scalar(&token))
+            {
+                write_line_break(f, lines_before.saturating_sub(1), position)?;
+            } else if lines_before > 1 {
+                write!(f, [empty_line()])?;
+            } else {
+                write!(f, [hard_line_break()])?;
+            }
+        }
+        write!(f, [format_node])?;
+        previous = Some(node);
+    }
+    Ok(())
+}
+
+/// Returns `true` if `token` is a block scalar with the "keep" chomping indicator, whose
+/// trailing empty lines are part of its content
+fn is_kept_block_scalar(token: &YamlSyntaxToken) -> bool {
+    matches!(
+        token.kind(),
+        YamlSyntaxKind::LITERAL_BLOCK_LITERAL | YamlSyntaxKind::FOLDED_BLOCK_LITERAL
+    ) && block_scalar_header(token.text_trimmed()).contains('+')
+}
+
+/// Returns the header of a block scalar, without its comment
+pub(crate) fn block_scalar_header(text: &str) -> &str {
+    let header = text.lines().next().unwrap_or_default();
+    header
+        .split_once('#')
+        .map_or(header, |(header, _)| header)
+        .trim_end()
+}
+
+/// How the node following an indicator is laid out when it doesn't fit on the line of the
+/// indicator
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum BlockValueLayout {
+    /// The value of an implicit mapping entry, indented on the next line:
+    /// ```yaml
+    /// key:
+    ///   nested: value
+    /// ```
+    Indent,
+    /// The value following a `-`, `?` or `:` indicator, aligned with the content of the
+    /// indicator line:
+    /// ```yaml
+    /// - nested: value
+    ///   other: value
+    /// ```
+    Align,
+}
+
+/// Formats the block node that follows an indicator, including the whitespace between them.
+///
+/// The dangling comments of `parent` are the comments that follow the indicator on the same line.
+pub(crate) struct FormatBlockValue<'a> {
+    parent: &'a YamlSyntaxNode,
+    value: Option<YamlSyntaxNode>,
+    layout: BlockValueLayout,
+}
+
+impl<'a> FormatBlockValue<'a> {
+    pub(crate) fn new(
+        parent: &'a YamlSyntaxNode,
+        value: Option<YamlSyntaxNode>,
+        layout: BlockValueLayout,
+    ) -> Self {
+        Self {
+            parent,
+            value,
+            layout,
+        }
+    }
+}
+
+impl Format<YamlFormatContext> for FormatBlockValue<'_> {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        let has_dangling_comments = f.comments().has_dangling_comments(self.parent);
+        let Some(value) = &self.value else {
+            if has_dangling_comments {
+                write!(f, [space(), format_dangling_comments(self.parent)])?;
+            }
+            return Ok(());
+        };
+
+        let nested = format_with(|f| match self.layout {
+            BlockValueLayout::Indent => write!(f, [indent(&value.format())]),
+            BlockValueLayout::Align => write!(f, [align(2, &value.format())]),
+        });
+        let nested_on_next_line = format_with(|f| match self.layout {
+            BlockValueLayout::Indent => {
+                write!(
+                    f,
+                    [indent(&format_args![hard_line_break(), value.format()])]
+                )
+            }
+            BlockValueLayout::Align => {
+                write!(
+                    f,
+                    [align(2, &format_args![hard_line_break(), value.format()])]
+                )
+            }
+        });
+
+        if has_dangling_comments {
+            return write!(
+                f,
+                [
+                    space(),
+                    format_dangling_comments(self.parent),
+                    nested_on_next_line
+                ]
+            );
+        }
+
+        if f.comments().has_leading_comments(value) {
+            return write!(f, [nested_on_next_line]);
+        }
+
+        match value.kind() {
+            YamlSyntaxKind::YAML_BLOCK_COLLECTION => {
+                let has_properties = YamlBlockCollection::cast_ref(value)
+                    .is_some_and(|collection| collection.properties().is_some());
+                if has_properties || self.layout == BlockValueLayout::Align {
+                    write!(f, [space(), nested])
+                } else {
+                    write!(f, [nested_on_next_line])
+                }
+            }
+            YamlSyntaxKind::YAML_COMPACT_MAPPING | YamlSyntaxKind::YAML_COMPACT_SEQUENCE => {
+                write!(f, [space(), nested])
+            }
+            _ if self.layout == BlockValueLayout::Indent && is_flow_collection(value) => {
+                // A flow collection that doesn't fit on the line of the key moves to the next
+                // line, where it can be indented more than the key
+                write!(
+                    f,
+                    [group(&indent(&format_args![
+                        soft_line_break_or_space(),
+                        value.format()
+                    ]))]
+                )
+            }
+            _ => write!(f, [space(), nested]),
+        }
+    }
+}
+
+/// Returns `true` if `node` is a flow mapping or a flow sequence in a block context
+fn is_flow_collection(node: &YamlSyntaxNode) -> bool {
+    YamlFlowInBlockNode::cast_ref(node)
+        .and_then(|node| node.flow().ok())
+        .and_then(|flow| flow.as_yaml_flow_json_node().cloned())
+        .and_then(|flow| flow.content())
+        .is_some_and(|content| {
+            content.as_yaml_flow_mapping().is_some() || content.as_yaml_flow_sequence().is_some()
+        })
+}
+
+/// Writes a flow scalar that spans multiple lines, one line of the source per line.
+///
+/// Line breaks in flow scalars are folded, so the indentation of the lines and the whitespace
+/// around line breaks aren't part of the value. Empty lines are kept as they are, because each
+/// of them is a line feed in the value.
+pub(crate) fn write_multiline_flow_scalar(
+    f: &mut YamlFormatter,
+    token: &YamlSyntaxToken,
+) -> FormatResult<()> {
+    let is_double_quoted = token.kind() == YamlSyntaxKind::DOUBLE_QUOTED_LITERAL;
+    let is_plain = token.kind() == YamlSyntaxKind::PLAIN_LITERAL;
+    let position = token.text_trimmed_range().start();
+    let text = token.text_trimmed();
+
+    let format_lines = format_once(|f| {
+        let mut blank_lines = 0;
+        for (index, line) in text.lines().enumerate() {
+            // The whitespace after the opening quote is part of the value
+            let line = if index > 0 || is_plain {
+                line.trim_start()
+            } else {
+                line
+            };
+            let line = trim_flow_line_end(line, is_double_quoted);
+            if index > 0 {
+                if line.is_empty() {
+                    blank_lines += 1;
+                    continue;
+                }
+                write_line_break(f, blank_lines, position)?;
+                blank_lines = 0;
+            }
+            write!(f, [dynamic_text(line, position)])?;
+        }
+        Ok(())
+    });
+
+    write!(f, [format_replaced(token, &format_lines)])
+}
+
+/// Removes the trailing whitespace of a line of a flow scalar, unless it's escaped
+fn trim_flow_line_end(line: &str, is_double_quoted: bool) -> &str {
+    let trimmed = line.trim_end();
+    if is_double_quoted && trimmed.len() < line.len() {
+        let backslashes = trimmed
+            .bytes()
+            .rev()
+            .take_while(|byte| *byte == b'\\')
+            .count();
+        if backslashes % 2 == 1 {
+            // Keep the escaped whitespace character
+            let escaped_len = line[trimmed.len()..]
+                .chars()
+                .next()
+                .map_or(0, char::len_utf8);
+            return &line[..trimmed.len() + escaped_len];
+        }
+    }
+    trimmed
+}
+
+/// Writes a literal or a folded block scalar.
+///
+/// The content of the scalar is re-indented relative to the current indentation level,
+/// which is always deeper than the indentation of the node that contains the scalar.
+pub(crate) fn write_block_scalar(
+    f: &mut YamlFormatter,
+    token: &YamlSyntaxToken,
+) -> FormatResult<()> {
+    let position = token.text_trimmed_range().start();
+    let text = token.text_trimmed();
+    let mut lines = text.lines();
+    let header_line = lines.next().unwrap_or_default();
+    let header = block_scalar_header(header_line);
+
+    if header.bytes().any(|byte| byte.is_ascii_digit()) {
+        // The indentation indicator is relative to the indentation of the parent node,
+        // which can't be changed without updating the indicator
+        return write!(f, [format_replaced(token, &dynamic_text(text, position))]);
+    }
+
+    let content_lines: Vec<_> = lines.collect();
+    let indent = content_lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start_matches(' ').len())
+        .min()
+        .unwrap_or_default();
+
+    let format_content = format_once(|f| {
+        write!(f, [dynamic_text(header, position)])?;
+        if let Some(comment) = header_line[header.len()..].trim().strip_prefix('#') {
+            write!(
+                f,
+                [space(), dynamic_text(&std::format!("#{comment}"), position)]
+            )?;
+        }
+
+        let mut blank_lines = 0;
+        for line in content_lines {
+            if line.len() <= indent && line.trim().is_empty() {
+                blank_lines += 1;
+                continue;
+            }
+            write_line_break(f, blank_lines, position)?;
+            write!(f, [dynamic_text(&line[indent..], position)])?;
+            blank_lines = 0;
+        }
+        Ok(())
+    });
+
+    write!(f, [format_replaced(token, &format_content)])
+}
+
+/// Writes a single or a double-quoted scalar, using the preferred quotes when the value can be
+/// written with them without adding escapes
+pub(crate) fn write_quoted_scalar(
+    f: &mut YamlFormatter,
+    token: &YamlSyntaxToken,
+) -> FormatResult<()> {
+    let text = token.text_trimmed();
+    if text.contains('\n') {
+        return write_multiline_flow_scalar(f, token);
+    }
+
+    match normalize_quotes(text, f.options().quote_style()) {
+        Some(normalized) => write!(
+            f,
+            [format_replaced(
+                token,
+                &dynamic_text(&normalized, token.text_trimmed_range().start())
+            )]
+        ),
+        None => write!(f, [token.format()]),
+    }
+}
+
+/// Returns `text` quoted with `preferred_quote`, or [None] if it's already quoted with it or
+/// if it can't be converted without adding escapes.
+///
+/// Double-quoted scalars support escape sequences, while single-quoted scalars only
+/// support `''` to represent a single quote.
+fn normalize_quotes(text: &str, preferred_quote: QuoteStyle) -> Option<String> {
+    let quote = QuoteStyle::from_byte(*text.as_bytes().first()?)?;
+    if quote == preferred_quote || text.len() < 2 {
+        return None;
+    }
+    let content = &text[1..text.len() - 1];
+
+    match preferred_quote {
+        QuoteStyle::Single => {
+            if content.contains(['\\', '\'']) {
+                return None;
+            }
+            Some(std::format!("'{content}'"))
+        }
+        QuoteStyle::Double => {
+            let content = content.replace("''", "'");
+            if content.contains(['\\', '"']) {
+                return None;
+            }
+            Some(std::format!("\"{content}\""))
+        }
+    }
+}
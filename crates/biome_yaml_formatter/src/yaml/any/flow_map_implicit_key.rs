@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_yaml_syntax::AnyYamlFlowMapImplicitKey;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyYamlFlowMapImplicitKey;
+impl FormatRule<AnyYamlFlowMapImplicitKey> for FormatAnyYamlFlowMapImplicitKey {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &AnyYamlFlowMapImplicitKey, f: &mut YamlFormatter) -> FormatResult<()> {
+        match node {
+            AnyYamlFlowMapImplicitKey::YamlAliasNode(node) => node.format().fmt(f),
+            AnyYamlFlowMapImplicitKey::YamlFlowJsonNode(node) => node.format().fmt(f),
+            AnyYamlFlowMapImplicitKey::YamlFlowYamlNode(node) => node.format().fmt(f),
+        }
+    }
+}
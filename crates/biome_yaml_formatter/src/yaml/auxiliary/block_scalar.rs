@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlBlockScalar, YamlBlockScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockScalar;
+impl FormatNodeRule<YamlBlockScalar> for FormatYamlBlockScalar {
+    fn fmt_fields(&self, node: &YamlBlockScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlBlockScalarFields {
+            properties,
+            content,
+        } = node.as_fields();
+        if let Some(properties) = properties {
+            write!(f, [properties.format(), space()])?;
+        }
+        write!(f, [content.format()])
+    }
+}
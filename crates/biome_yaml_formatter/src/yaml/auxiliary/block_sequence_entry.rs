@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use crate::utils::{BlockValueLayout, FormatBlockValue};
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlBlockSequenceEntry, YamlBlockSequenceEntryFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockSequenceEntry;
+impl FormatNodeRule<YamlBlockSequenceEntry> for FormatYamlBlockSequenceEntry {
+    fn fmt_fields(&self, node: &YamlBlockSequenceEntry, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlBlockSequenceEntryFields { minus_token, value } = node.as_fields();
+        write!(
+            f,
+            [
+                minus_token.format(),
+                FormatBlockValue::new(
+                    node.syntax(),
+                    value.map(|value| value.into_syntax()),
+                    BlockValueLayout::Align
+                )
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &YamlBlockSequenceEntry,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
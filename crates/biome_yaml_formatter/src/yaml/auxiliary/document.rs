@@ -0,0 +1,79 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{AnyYamlBlockNode, YamlDocument, YamlDocumentFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlDocument;
+impl FormatNodeRule<YamlDocument> for FormatYamlDocument {
+    fn fmt_fields(&self, node: &YamlDocument, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlDocumentFields {
+            bom_token,
+            directives,
+            dashdashdash_token,
+            node: block_node,
+            dotdotdot_token,
+        } = node.as_fields();
+
+        write!(f, [bom_token.format(), directives.format()])?;
+        if !directives.is_empty() {
+            write!(f, [hard_line_break()])?;
+        }
+
+        // The comments that follow `---` on the same line
+        let has_dangling_comments = f.comments().has_dangling_comments(node.syntax());
+        if let Some(dashdashdash_token) = &dashdashdash_token {
+            write!(f, [dashdashdash_token.format()])?;
+            if has_dangling_comments {
+                write!(f, [space(), format_dangling_comments(node.syntax())])?;
+            }
+        }
+
+        if let Some(block_node) = &block_node {
+            if dashdashdash_token.is_some() {
+                if has_dangling_comments || starts_on_new_line(block_node, f) {
+                    write!(f, [hard_line_break()])?;
+                } else {
+                    write!(f, [space()])?;
+                }
+            }
+            if is_block_scalar(block_node) {
+                // Block scalars at the top level can have unindented content, but it's more
+                // readable and better supported when it's indented
+                write!(f, [indent(&block_node.format())])?;
+            } else {
+                write!(f, [block_node.format()])?;
+            }
+        }
+
+        if let Some(dotdotdot_token) = dotdotdot_token {
+            if dashdashdash_token.is_some() || block_node.is_some() || !directives.is_empty() {
+                write!(f, [hard_line_break()])?;
+            }
+            write!(f, [dotdotdot_token.format()])?;
+        }
+        Ok(())
+    }
+
+    fn fmt_dangling_comments(&self, _: &YamlDocument, _: &mut YamlFormatter) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
+
+/// Returns `true` if the top-level node of a document must be written on the line after `---`
+fn starts_on_new_line(block_node: &AnyYamlBlockNode, f: &YamlFormatter) -> bool {
+    let is_collection_without_properties = block_node
+        .as_any_yaml_block_in_block_node()
+        .and_then(|node| node.as_yaml_block_collection())
+        .is_some_and(|collection| collection.properties().is_none());
+
+    is_collection_without_properties
+        || f.comments().has_leading_comments(block_node.syntax())
+        || get_lines_before(block_node.syntax()) > 0
+}
+
+fn is_block_scalar(block_node: &AnyYamlBlockNode) -> bool {
+    block_node
+        .as_any_yaml_block_in_block_node()
+        .is_some_and(|node| node.as_yaml_block_scalar().is_some())
+}
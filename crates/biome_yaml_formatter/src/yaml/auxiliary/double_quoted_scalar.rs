@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use crate::utils::write_quoted_scalar;
+use biome_yaml_syntax::{YamlDoubleQuotedScalar, YamlDoubleQuotedScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlDoubleQuotedScalar;
+impl FormatNodeRule<YamlDoubleQuotedScalar> for FormatYamlDoubleQuotedScalar {
+    fn fmt_fields(&self, node: &YamlDoubleQuotedScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlDoubleQuotedScalarFields { value_token } = node.as_fields();
+        write_quoted_scalar(f, &value_token?)
+    }
+}
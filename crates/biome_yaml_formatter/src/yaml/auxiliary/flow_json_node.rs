@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlFlowJsonNode, YamlFlowJsonNodeFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFlowJsonNode;
+impl FormatNodeRule<YamlFlowJsonNode> for FormatYamlFlowJsonNode {
+    fn fmt_fields(&self, node: &YamlFlowJsonNode, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlFlowJsonNodeFields {
+            properties,
+            content,
+        } = node.as_fields();
+        match (properties, content) {
+            (Some(properties), Some(content)) => {
+                write!(f, [properties.format(), space(), content.format()])
+            }
+            (properties, content) => write!(f, [properties.format(), content.format()]),
+        }
+    }
+}
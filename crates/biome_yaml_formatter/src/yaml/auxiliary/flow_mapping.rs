@@ -0,0 +1,41 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_rowan::AstSeparatedList;
+use biome_yaml_syntax::{YamlFlowMapping, YamlFlowMappingFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFlowMapping;
+impl FormatNodeRule<YamlFlowMapping> for FormatYamlFlowMapping {
+    fn fmt_fields(&self, node: &YamlFlowMapping, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlFlowMappingFields {
+            l_curly_token,
+            entries,
+            r_curly_token,
+        } = node.as_fields();
+
+        let should_expand = f.comments().has_dangling_comments(node.syntax());
+
+        write!(
+            f,
+            [
+                l_curly_token.format(),
+                group(&soft_block_indent_with_maybe_space(
+                    &format_args![entries.format(), format_dangling_comments(node.syntax())],
+                    !entries.is_empty()
+                ))
+                .should_expand(should_expand),
+                line_suffix_boundary(),
+                r_curly_token.format()
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &YamlFlowMapping,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlFlowYamlNode, YamlFlowYamlNodeFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFlowYamlNode;
+impl FormatNodeRule<YamlFlowYamlNode> for FormatYamlFlowYamlNode {
+    fn fmt_fields(&self, node: &YamlFlowYamlNode, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlFlowYamlNodeFields {
+            properties,
+            content,
+        } = node.as_fields();
+        match (properties, content) {
+            (Some(properties), Some(content)) => {
+                write!(f, [properties.format(), space(), content.format()])
+            }
+            (properties, content) => write!(f, [properties.format(), content.format()]),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use crate::utils::write_block_scalar;
+use biome_yaml_syntax::{YamlFoldedScalar, YamlFoldedScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFoldedScalar;
+impl FormatNodeRule<YamlFoldedScalar> for FormatYamlFoldedScalar {
+    fn fmt_fields(&self, node: &YamlFoldedScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlFoldedScalarFields { value_token } = node.as_fields();
+        write_block_scalar(f, &value_token?)
+    }
+}
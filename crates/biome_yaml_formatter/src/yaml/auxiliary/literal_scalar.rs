@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use crate::utils::write_block_scalar;
+use biome_yaml_syntax::{YamlLiteralScalar, YamlLiteralScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlLiteralScalar;
+impl FormatNodeRule<YamlLiteralScalar> for FormatYamlLiteralScalar {
+    fn fmt_fields(&self, node: &YamlLiteralScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlLiteralScalarFields { value_token } = node.as_fields();
+        write_block_scalar(f, &value_token?)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use crate::utils::write_multiline_flow_scalar;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlPlainScalar, YamlPlainScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlPlainScalar;
+impl FormatNodeRule<YamlPlainScalar> for FormatYamlPlainScalar {
+    fn fmt_fields(&self, node: &YamlPlainScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlPlainScalarFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+        if value_token.text_trimmed().contains('\n') {
+            write_multiline_flow_scalar(f, &value_token)
+        } else {
+            write!(f, [value_token.format()])
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlRoot, YamlRootFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlRoot;
+impl FormatNodeRule<YamlRoot> for FormatYamlRoot {
+    fn fmt_fields(&self, node: &YamlRoot, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlRootFields {
+            documents,
+            eof_token,
+        } = node.as_fields();
+
+        write!(f, [documents.format()])?;
+        if !documents.is_empty() {
+            write!(f, [hard_line_break()])?;
+        }
+
+        // A file that only contains comments doesn't have any document
+        if f.comments().has_dangling_comments(node.syntax()) {
+            write!(
+                f,
+                [format_dangling_comments(node.syntax()), hard_line_break()]
+            )?;
+        }
+
+        write!(f, [format_removed(&eof_token?)])
+    }
+
+    fn fmt_dangling_comments(&self, _: &YamlRoot, _: &mut YamlFormatter) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use crate::utils::write_quoted_scalar;
+use biome_yaml_syntax::{YamlSingleQuotedScalar, YamlSingleQuotedScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlSingleQuotedScalar;
+impl FormatNodeRule<YamlSingleQuotedScalar> for FormatYamlSingleQuotedScalar {
+    fn fmt_fields(&self, node: &YamlSingleQuotedScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlSingleQuotedScalarFields { value_token } = node.as_fields();
+        write_quoted_scalar(f, &value_token?)
+    }
+}
@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use crate::utils::write_block_entries;
+use biome_yaml_syntax::YamlBlockMapEntryList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockMapEntryList;
+impl FormatRule<YamlBlockMapEntryList> for FormatYamlBlockMapEntryList {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &YamlBlockMapEntryList, f: &mut YamlFormatter) -> FormatResult<()> {
+        write_block_entries(
+            f,
+            node.iter()
+                .map(|entry| (entry.syntax().clone(), entry.into_format())),
+        )
+    }
+}
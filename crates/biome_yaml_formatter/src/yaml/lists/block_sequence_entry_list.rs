@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use crate::utils::write_block_entries;
+use biome_yaml_syntax::YamlBlockSequenceEntryList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockSequenceEntryList;
+impl FormatRule<YamlBlockSequenceEntryList> for FormatYamlBlockSequenceEntryList {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &YamlBlockSequenceEntryList, f: &mut YamlFormatter) -> FormatResult<()> {
+        write_block_entries(
+            f,
+            node.iter()
+                .map(|entry| (entry.syntax().clone(), entry.into_format())),
+        )
+    }
+}
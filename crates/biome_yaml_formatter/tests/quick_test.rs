@@ -0,0 +1,40 @@
+use biome_formatter::LineWidth;
+use biome_formatter_test::check_reformat::CheckReformat;
+use biome_yaml_formatter::context::YamlFormatOptions;
+use biome_yaml_formatter::{YamlFormatLanguage, format_node};
+use biome_yaml_parser::parse_yaml;
+use biome_yaml_syntax::YamlFileSource;
+
+mod language {
+    include!("language.rs");
+}
+
+#[ignore]
+#[test]
+// use this test check if your snippet prints as you wish, without using a snapshot
+fn quick_test() {
+    let src = r#"key:   value
+list:
+    -   a
+    -   b
+"#;
+    let tree = parse_yaml(src);
+    dbg!(tree.tree());
+    let options = YamlFormatOptions::new(YamlFileSource::yaml())
+        .with_line_width(LineWidth::try_from(80).unwrap());
+
+    let doc = format_node(options.clone(), &tree.syntax()).unwrap();
+    let result = doc.print().unwrap();
+
+    println!("{}", doc.into_document());
+    eprintln!("{}", result.as_code());
+
+    CheckReformat::new(
+        &tree.syntax(),
+        result.as_code(),
+        "testing",
+        &language::YamlTestFormatLanguage,
+        YamlFormatLanguage::new(options),
+    )
+    .check_reformat();
+}
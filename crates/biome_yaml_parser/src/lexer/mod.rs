@@ -1,5 +1,3 @@
-#![allow(dead_code)]
-
 use biome_parser::{
     diagnostic::ParseDiagnostic,
     lexer::{LexContext, Lexer, LexerCheckpoint, LexerWithCheckpoint, ReLexer, TokenFlags},
@@ -30,6 +28,9 @@ pub(crate) struct YamlLexer<'src> {
     /// Flags for the current token
     current_flags: TokenFlags,
 
+    /// `true` if a line break was lexed since the last non-trivia token
+    after_newline: bool,
+
     /// diagnostics emitted during the parsing phase
     diagnostics: Vec<ParseDiagnostic>,
 }
@@ -45,6 +46,7 @@ impl<'src> YamlLexer<'src> {
             current_kind: TOMBSTONE,
             current_start: TextSize::from(0),
             current_flags: TokenFlags::empty(),
+            after_newline: false,
             diagnostics: vec![],
         }
     }
@@ -54,16 +56,39 @@ impl<'src> YamlLexer<'src> {
         let start = self.text_position();
 
         let kind = match current {
-            b':' => self.consume_byte(T![:]),
+            b' ' | b'\t' | b'\n' | b'\r' => self.consume_newline_or_whitespaces(),
+            b'#' => self.consume_comment(),
+            0xef if self.position == 0 && self.source.starts_with('\u{feff}') => {
+                self.unicode_bom_length = 3;
+                self.consume_byte_sequence(UNICODE_BOM, 3)
+            }
+            b'-' if self.is_at_document_marker(b'-') => self.consume_byte_sequence(T![---], 3),
+            b'.' if self.is_at_document_marker(b'.') => self.consume_byte_sequence(T![...], 3),
+            b'%' if self.is_at_column_zero() => self.consume_directive(),
+            b':' if self.is_at_json_like_value() => self.consume_byte(T![:]),
+            b'?' | b':' | b'-' if !self.is_first_plain_char(current, context) => {
+                self.consume_byte(match current {
+                    b'?' => T![?],
+                    b':' => T![:],
+                    _ => T![-],
+                })
+            }
             b',' => self.consume_byte(T![,]),
             b'[' => self.consume_byte(T!['[']),
             b']' => self.consume_byte(T![']']),
-            b'?' => self.consume_byte(T![?]),
-            b'-' => self.consume_byte(T![-]),
-            b'#' => self.consume_comment(),
+            b'{' => self.consume_byte(T!['{']),
+            b'}' => self.consume_byte(T!['}']),
+            b'&' => self.consume_node_property(ANCHOR_PROPERTY_LITERAL),
+            b'!' => self.consume_node_property(TAG_PROPERTY_LITERAL),
+            b'*' => self.consume_node_property(ALIAS_LITERAL),
+            b'|' if !matches!(context, YamlLexContext::FlowIn) => {
+                self.consume_block_scalar(LITERAL_BLOCK_LITERAL)
+            }
+            b'>' if !matches!(context, YamlLexContext::FlowIn) => {
+                self.consume_block_scalar(FOLDED_BLOCK_LITERAL)
+            }
             b'\'' => self.consume_single_quoted_literal(),
             b'"' => self.consume_double_quoted_literal(),
-            b' ' | b'\n' => self.consume_newline_or_whitespaces(),
             _ if self.is_first_plain_char(current, context) => self.consume_plain_literal(context),
             _ => self.consume_unexpected_token(),
         };
@@ -79,33 +104,149 @@ impl<'src> YamlLexer<'src> {
         tok
     }
 
+    /// Bumps `length` bytes and creates a lexed token of the passed in kind.
+    #[inline]
+    fn consume_byte_sequence(&mut self, tok: YamlSyntaxKind, length: usize) -> YamlSyntaxKind {
+        self.advance(length);
+        tok
+    }
+
     fn consume_comment(&mut self) -> YamlSyntaxKind {
         self.assert_byte(b'#');
+        self.advance_to_line_end();
+        COMMENT
+    }
+
+    // https://yaml.org/spec/1.2.2/#rule-l-directive
+    fn consume_directive(&mut self) -> YamlSyntaxKind {
+        self.assert_byte(b'%');
+        let mut end = self.position;
         while let Some(c) = self.current_byte() {
-            if c == b'\n' {
+            if is_break(c) || (c == b'#' && self.is_preceded_by_space()) {
                 break;
             }
             self.advance(1);
+            if !is_space(c) {
+                end = self.position;
+            }
         }
-        COMMENT
+        self.position = end;
+        DIRECTIVE_LITERAL
+    }
+
+    // https://yaml.org/spec/1.2.2/#691-node-tags
+    // https://yaml.org/spec/1.2.2/#692-node-anchors
+    // https://yaml.org/spec/1.2.2/#71-alias-nodes
+    fn consume_node_property(&mut self, kind: YamlSyntaxKind) -> YamlSyntaxKind {
+        self.advance(1);
+        while let Some(c) = self.current_byte() {
+            if !is_non_space_char(c) || (kind != TAG_PROPERTY_LITERAL && is_flow_indicator(c)) {
+                break;
+            }
+            self.advance_char_unchecked();
+        }
+        kind
+    }
+
+    /// Consumes a literal or folded block scalar, including its header and all its content lines.
+    ///
+    /// The content of a block scalar ends with the first non-empty line that is indented less than
+    /// the first non-empty content line. Trailing empty lines aren't part of the token.
+    // https://yaml.org/spec/1.2.2/#81-block-scalar-styles
+    fn consume_block_scalar(&mut self, kind: YamlSyntaxKind) -> YamlSyntaxKind {
+        let parent_indent = self.current_line_indent();
+        self.advance(1);
+
+        let mut content_indent = None;
+        while let Some(c) = self.current_byte() {
+            match c {
+                b'1'..=b'9' => {
+                    content_indent = Some(parent_indent + usize::from(c - b'0'));
+                    self.advance(1);
+                }
+                b'+' | b'-' => self.advance(1),
+                _ => break,
+            }
+        }
+
+        // The rest of the header line can only contain a comment
+        self.advance_to_line_end();
+        let mut end = self.position;
+
+        while self.current_byte().is_some_and(is_break) {
+            self.consume_newline();
+            let line_start = self.position;
+            while self.current_byte() == Some(b' ') {
+                self.advance(1);
+            }
+            let indent = self.position - line_start;
+            let is_blank = self
+                .current_byte()
+                .is_none_or(|c| is_break(c) || (is_space(c) && self.is_blank_line_end()));
+
+            if !is_blank {
+                let content_indent = *content_indent.get_or_insert(indent);
+                if indent < content_indent || indent <= parent_indent {
+                    break;
+                }
+            }
+
+            self.advance_to_line_end();
+            if !is_blank {
+                end = self.position;
+            }
+        }
+
+        self.position = end;
+        kind
     }
 
     // https://yaml.org/spec/1.2.2/#rule-ns-plain
-    // TODO: parse multiline plain scalar at current indentation level
     fn consume_plain_literal(&mut self, context: YamlLexContext) -> YamlSyntaxKind {
         self.assert_current_char_boundary();
         debug_assert!(
             self.current_byte()
                 .is_some_and(|c| self.is_first_plain_char(c, context))
         );
+
+        loop {
+            let reached_line_end = self.consume_plain_line(context);
+            let end = self.position;
+
+            let continuation_indent = match context {
+                YamlLexContext::Regular => None,
+                YamlLexContext::FlowOut { indent } => Some(indent),
+                YamlLexContext::FlowIn => Some(0),
+            };
+
+            match continuation_indent {
+                Some(indent)
+                    if reached_line_end && self.skip_to_plain_continuation(indent, context) => {}
+                _ => {
+                    self.position = end;
+                    break;
+                }
+            }
+        }
+
+        PLAIN_LITERAL
+    }
+
+    /// Consumes the plain characters of the current line, without trailing white space.
+    ///
+    /// Returns `true` if the scalar may continue on the next line.
+    fn consume_plain_line(&mut self, context: YamlLexContext) -> bool {
         self.advance_char_unchecked();
-        while let Some(c) = self.current_byte() {
+        let mut end = self.position;
+        let reached_line_end = loop {
+            let Some(c) = self.current_byte() else {
+                break true;
+            };
             // https://yaml.org/spec/1.2.2/#rule-ns-plain-char
             if is_plain_safe(c, context) && c != b':' && c != b'#' {
                 self.advance_char_unchecked();
-            } else if is_non_space_char(c) && self.peek_byte().is_some_and(|c| c == b'#') {
-                self.advance_char_unchecked();
-                self.advance(1); // '#'
+            } else if c == b'#' && !self.is_preceded_by_space() {
+                self.advance(1);
             } else if c == b':' && self.peek_byte().is_some_and(|c| is_plain_safe(c, context)) {
                 self.advance(1); // ':'
                 self.advance_char_unchecked();
@@ -116,11 +257,51 @@ impl<'src> YamlLexer<'src> {
             // Is a valid mapping
             else if is_space(c) {
                 self.advance(1);
+                continue;
+            } else {
+                break is_break(c);
+            }
+            end = self.position;
+        };
+        self.position = end;
+        reached_line_end
+    }
+
+    /// Moves to the first character of the next non-empty line if that line continues the
+    /// current plain scalar, that is, if it's indented by at least `indent` columns and doesn't
+    /// start with a comment or a document marker.
+    fn skip_to_plain_continuation(&mut self, indent: usize, context: YamlLexContext) -> bool {
+        let checkpoint = self.position;
+        while let Some(c) = self.current_byte() {
+            if is_break(c) {
+                self.consume_newline();
+                let line_start = self.position;
+                while self.current_byte().is_some_and(is_space) {
+                    self.advance(1);
+                }
+                let Some(first) = self.current_byte() else {
+                    break;
+                };
+                if is_break(first) {
+                    continue;
+                }
+                if self.position - line_start >= indent
+                    && first != b'#'
+                    && !self.is_at_document_marker(b'-')
+                    && !self.is_at_document_marker(b'.')
+                    && self.is_first_plain_char(first, context)
+                {
+                    return true;
+                }
+                break;
+            } else if is_space(c) {
+                self.advance(1);
             } else {
                 break;
             }
         }
-        PLAIN_LITERAL
+        self.position = checkpoint;
+        false
     }
 
     // https://yaml.org/spec/1.2.2/#rule-ns-plain-first
@@ -133,23 +314,23 @@ impl<'src> YamlLexer<'src> {
     // https://yaml.org/spec/1.2.2/#731-double-quoted-style
     fn consume_double_quoted_literal(&mut self) -> YamlSyntaxKind {
         self.assert_byte(b'"');
+        let start = self.text_position();
         self.advance(1);
 
         loop {
             match self.current_byte() {
                 Some(b'\\') => {
-                    if matches!(self.peek_byte(), Some(b'"')) {
-                        self.advance(2)
-                    } else {
-                        self.advance(1)
+                    self.advance(1);
+                    if self.current_byte().is_some() {
+                        self.advance_char_unchecked();
                     }
                 }
                 Some(b'"') => {
                     self.advance(1);
                     break DOUBLE_QUOTED_LITERAL;
                 }
-                Some(_) => self.advance(1),
-                None => break ERROR_TOKEN,
+                Some(_) => self.advance_char_unchecked(),
+                None => break self.unterminated_string(start),
             }
         }
     }
@@ -157,6 +338,7 @@ impl<'src> YamlLexer<'src> {
     // https://yaml.org/spec/1.2.2/#732-single-quoted-style
     fn consume_single_quoted_literal(&mut self) -> YamlSyntaxKind {
         self.assert_byte(b'\'');
+        let start = self.text_position();
         self.advance(1);
 
         loop {
@@ -169,12 +351,18 @@ impl<'src> YamlLexer<'src> {
                         break SINGLE_QUOTED_LITERAL;
                     }
                 }
-                Some(_) => self.advance(1),
-                None => break ERROR_TOKEN,
+                Some(_) => self.advance_char_unchecked(),
+                None => break self.unterminated_string(start),
             }
         }
     }
 
+    fn unterminated_string(&mut self, start: TextSize) -> YamlSyntaxKind {
+        let err = ParseDiagnostic::new("Missing closing quote", start..self.text_position());
+        self.diagnostics.push(err);
+        ERROR_TOKEN
+    }
+
     fn consume_unexpected_token(&mut self) -> YamlSyntaxKind {
         self.assert_current_char_boundary();
 
@@ -188,6 +376,67 @@ impl<'src> YamlLexer<'src> {
 
         ERROR_TOKEN
     }
+
+    /// Advances to the next line break, or to the end of the source
+    fn advance_to_line_end(&mut self) {
+        while let Some(c) = self.current_byte() {
+            if is_break(c) {
+                break;
+            }
+            self.advance_char_unchecked();
+        }
+    }
+
+    /// Returns `true` if only white space remains until the end of the current line
+    fn is_blank_line_end(&self) -> bool {
+        self.source.as_bytes()[self.position..]
+            .iter()
+            .take_while(|c| !is_break(**c))
+            .all(|c| is_space(*c))
+    }
+
+    /// Returns the number of spaces at the start of the current line
+    fn current_line_indent(&self) -> usize {
+        let bytes = self.source.as_bytes();
+        let line_start = bytes[..self.position]
+            .iter()
+            .rposition(|c| is_break(*c))
+            .map_or(self.unicode_bom_length, |index| index + 1);
+        bytes[line_start..self.position]
+            .iter()
+            .take_while(|c| **c == b' ')
+            .count()
+    }
+
+    fn is_at_column_zero(&self) -> bool {
+        self.position == self.unicode_bom_length
+            || self.source.as_bytes()[self.position - 1] == b'\n'
+    }
+
+    fn is_preceded_by_space(&self) -> bool {
+        self.position == 0 || !is_non_space_char(self.source.as_bytes()[self.position - 1])
+    }
+
+    /// Returns `true` if the lexer is at a `---` or `...` marker at the start of a line
+    // https://yaml.org/spec/1.2.2/#912-document-markers
+    fn is_at_document_marker(&self, marker: u8) -> bool {
+        let bytes = &self.source.as_bytes()[self.position..];
+        self.is_at_column_zero()
+            && bytes.len() >= 3
+            && bytes[..3].iter().all(|c| *c == marker)
+            && bytes.get(3).is_none_or(|c| !is_non_space_char(*c))
+    }
+
+    /// A `:` directly following a JSON-like node is a value indicator, for example:
+    /// {"a":1}
+    // https://yaml.org/spec/1.2.2/#rule-c-ns-flow-map-adjacent-value
+    fn is_at_json_like_value(&self) -> bool {
+        self.position > 0
+            && matches!(
+                self.source.as_bytes()[self.position - 1],
+                b'"' | b'\'' | b']' | b'}'
+            )
+    }
 }
 
 impl<'src> Lexer<'src> for YamlLexer<'src> {
@@ -228,7 +477,17 @@ impl<'src> Lexer<'src> for YamlLexer<'src> {
             Some(current) => self.consume_token(current, context),
             None => EOF,
         };
+
+        self.current_flags
+            .set(TokenFlags::PRECEDING_LINE_BREAK, self.after_newline);
         self.current_kind = kind;
+
+        if kind == NEWLINE {
+            self.after_newline = true;
+        } else if !kind.is_trivia() && kind != COMMENT {
+            self.after_newline = false;
+        }
+
         kind
     }
 
@@ -246,9 +505,9 @@ impl<'src> Lexer<'src> for YamlLexer<'src> {
             current_start,
             current_flags,
             current_kind,
+            after_line_break,
             unicode_bom_length,
             diagnostics_pos,
-            ..
         } = checkpoint;
 
         let new_pos = u32::from(position) as usize;
@@ -257,6 +516,7 @@ impl<'src> Lexer<'src> for YamlLexer<'src> {
         self.current_kind = current_kind;
         self.current_start = current_start;
         self.current_flags = current_flags;
+        self.after_newline = after_line_break;
         self.unicode_bom_length = unicode_bom_length;
         self.diagnostics.truncate(diagnostics_pos as usize);
     }
@@ -316,7 +576,7 @@ impl<'src> LexerWithCheckpoint<'src> for YamlLexer<'src> {
             current_start: self.current_start,
             current_flags: self.current_flags,
             current_kind: self.current_kind,
-            after_line_break: self.has_preceding_line_break(),
+            after_line_break: self.after_newline,
             unicode_bom_length: self.unicode_bom_length,
             diagnostics_pos: self.diagnostics.len() as u32,
         }
@@ -327,29 +587,21 @@ impl<'src> LexerWithCheckpoint<'src> for YamlLexer<'src> {
 /// https://yaml.org/spec/1.2.2/#42-production-parameters
 #[derive(Default, Clone, Copy)]
 pub enum YamlLexContext {
-    /// Before getting into the document body, for example:
-    /// %YAML 1.2
-    /// ---
-    /// <document body>
-    /// ...
-    #[default]
-    Regular,
-    /// Inside block key context, for example:
+    /// Block context, where plain scalars can't span multiple lines. This is the context used
+    /// for implicit keys and for the tokens outside of the document body, for example:
     /// abc: xyz
     /// ^^^
-    BlockKey,
-    /// Inside block value, but outside flow context, for example:
-    /// abc: [1, 2, 3]
-    ///      ^^^^^^^^^
-    FlowOut,
+    #[default]
+    Regular,
+    /// Inside block value, but outside flow context. Plain scalars continue on the following
+    /// lines that are indented by at least `indent` columns, for example:
+    /// abc: first line
+    ///   second line
+    FlowOut { indent: usize },
     /// Inside flow context, for example:
     /// abc: [1, 2, [4, 5]]
     ///       ^  ^  ^^^^^^
     FlowIn,
-    /// Inside flow key context
-    /// abc: {{10: [100]}: 50}
-    ///       ^^^^^^^^^^
-    FlowKey,
 }
 
 impl LexContext for YamlLexContext {
@@ -362,13 +614,8 @@ impl LexContext for YamlLexContext {
 fn is_plain_safe(c: u8, context: YamlLexContext) -> bool {
     use YamlLexContext::*;
     match context {
-        FlowOut | BlockKey => is_non_space_char(c),
-        FlowIn | FlowKey => is_non_space_char(c) && !is_flow_indicator(c),
-        // Can happen when a YAML document starts with a plain token. This token will then be
-        // used by the parser to determine whether the parser is already inside the document
-        // body. This token is not used inside the CST and instead is just used to signal the
-        // start of document body
-        Regular => is_non_space_char(c) && !is_indicator(c),
+        Regular | FlowOut { .. } => is_non_space_char(c),
+        FlowIn => is_non_space_char(c) && !is_flow_indicator(c),
     }
 }
 
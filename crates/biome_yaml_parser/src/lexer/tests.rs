@@ -128,7 +128,7 @@ fn lex_comment() {
 #[test]
 fn lex_simple_mapping_key() {
     assert_lex!(
-        YamlLexContext::BlockKey,
+        YamlLexContext::Regular,
         "abc:",
         PLAIN_LITERAL:3,
         COLON:1,
@@ -138,7 +138,7 @@ fn lex_simple_mapping_key() {
 #[test]
 fn lex_plain_with_special_char() {
     assert_lex!(
-        YamlLexContext::BlockKey,
+        YamlLexContext::Regular,
         "ab,c:d e-[f:#gh: ",
         PLAIN_LITERAL:15,
         COLON:1,
@@ -149,7 +149,7 @@ fn lex_plain_with_special_char() {
 #[test]
 fn lex_unambigous_mapping_and_comment() {
     assert_lex!(
-        YamlLexContext::BlockKey,
+        YamlLexContext::Regular,
         "abc: #abc",
         PLAIN_LITERAL:3,
         COLON:1,
@@ -161,10 +161,138 @@ fn lex_unambigous_mapping_and_comment() {
 #[test]
 fn lex_incorrect_flow_key() {
     assert_lex!(
-        YamlLexContext::FlowKey,
+        YamlLexContext::FlowIn,
         "a bc[xyz",
         PLAIN_LITERAL:4,
         L_BRACK:1,
         PLAIN_LITERAL:3,
     );
 }
+
+#[test]
+fn lex_plain_without_trailing_whitespace() {
+    assert_lex!(
+        YamlLexContext::Regular,
+        "a b  \nc",
+        PLAIN_LITERAL:3,
+        WHITESPACE:2,
+        NEWLINE:1,
+        PLAIN_LITERAL:1,
+    );
+}
+
+#[test]
+fn lex_multiline_plain() {
+    assert_lex!(
+        YamlLexContext::FlowOut { indent: 1 },
+        "a b\n\n  c d\ne",
+        PLAIN_LITERAL:10,
+        NEWLINE:1,
+        PLAIN_LITERAL:1,
+    );
+}
+
+#[test]
+fn lex_multiline_plain_stops_at_comment() {
+    assert_lex!(
+        YamlLexContext::FlowOut { indent: 0 },
+        "a # b\nc",
+        PLAIN_LITERAL:1,
+        WHITESPACE:1,
+        COMMENT:3,
+        NEWLINE:1,
+        PLAIN_LITERAL:1,
+    );
+}
+
+#[test]
+fn lex_sequence_entry() {
+    assert_lex!(
+        YamlLexContext::Regular,
+        "- -a",
+        DASH:1,
+        WHITESPACE:1,
+        PLAIN_LITERAL:2,
+    );
+}
+
+#[test]
+fn lex_flow_collections() {
+    assert_lex!(
+        YamlLexContext::FlowIn,
+        "{a: [b,c]}",
+        L_CURLY:1,
+        PLAIN_LITERAL:1,
+        COLON:1,
+        WHITESPACE:1,
+        L_BRACK:1,
+        PLAIN_LITERAL:1,
+        COMMA:1,
+        PLAIN_LITERAL:1,
+        R_BRACK:1,
+        R_CURLY:1,
+    );
+}
+
+#[test]
+fn lex_json_like_value() {
+    assert_lex!(
+        YamlLexContext::FlowIn,
+        "\"a\":1",
+        DOUBLE_QUOTED_LITERAL:3,
+        COLON:1,
+        PLAIN_LITERAL:1,
+    );
+}
+
+#[test]
+fn lex_node_properties() {
+    assert_lex!(
+        YamlLexContext::Regular,
+        "!!map &anchor *alias",
+        TAG_PROPERTY_LITERAL:5,
+        WHITESPACE:1,
+        ANCHOR_PROPERTY_LITERAL:7,
+        WHITESPACE:1,
+        ALIAS_LITERAL:6,
+    );
+}
+
+#[test]
+fn lex_document_markers() {
+    assert_lex!(
+        YamlLexContext::Regular,
+        "%YAML 1.2\n---\na ---\n...",
+        DIRECTIVE_LITERAL:9,
+        NEWLINE:1,
+        DIRECTIVE_END:3,
+        NEWLINE:1,
+        PLAIN_LITERAL:5,
+        NEWLINE:1,
+        DOC_END:3,
+    );
+}
+
+#[test]
+fn lex_literal_block_scalar() {
+    assert_lex!(
+        YamlLexContext::Regular,
+        "|\n  a\n\n   b\n\nc",
+        LITERAL_BLOCK_LITERAL:11,
+        NEWLINE:1,
+        NEWLINE:1,
+        PLAIN_LITERAL:1,
+    );
+}
+
+#[test]
+fn lex_folded_block_scalar_with_header() {
+    assert_lex!(
+        YamlLexContext::Regular,
+        ">-  # comment\n  a\n b",
+        FOLDED_BLOCK_LITERAL:17,
+        NEWLINE:1,
+        WHITESPACE:1,
+        PLAIN_LITERAL:1,
+    );
+}
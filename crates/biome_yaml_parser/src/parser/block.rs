@@ -1,7 +1,5 @@
 use biome_parser::{
-    CompletedMarker, Marker, Parser,
-    parse_lists::ParseNodeList,
-    parse_recovery::ParseRecovery,
+    CompletedMarker, Parser,
     prelude::ParsedSyntax::{self, *},
 };
 use biome_yaml_syntax::{
@@ -13,229 +11,343 @@ use crate::lexer::YamlLexContext;
 
 use super::{
     YamlParser,
-    flow::{is_at_flow_yaml_node, parse_flow_yaml_node},
-    parse_error::expected_block_mapping,
+    document::is_at_document_end,
+    flow::{is_at_properties, parse_flow_content, parse_properties},
+    parse_error::{expected_block_mapping_entry, expected_line_break, unexpected_indentation},
 };
 
-pub(crate) fn parse_any_block_node(p: &mut YamlParser) -> ParsedSyntax {
-    if maybe_at_implicit_mapping_key(p) {
-        Present(parse_implicit_entry_or_flow_node(p))
-    } else if is_at_explicit_mapping_key(p) {
-        Present(parse_block_collection(p))
-    } else if is_at_block_scalar(p) {
-        Present(parse_block_scalar(p))
-    } else {
-        Absent
-    }
+/// Describes where a block node may start, relative to the indicator that precedes it.
+///
+/// YAML doesn't have explicit delimiters for block collections: their extent is determined by
+/// the column of their entries.
+#[derive(Clone, Copy)]
+pub(crate) struct BlockContext {
+    /// The minimum column of a node that starts on a new line
+    indent: usize,
+    /// The minimum column of a block sequence that starts on a new line. It's the column of the
+    /// parent key for mapping values, because the entries of a block sequence can be at the same
+    /// column as the key of the mapping entry containing it:
+    /// ```yaml
+    /// key:
+    /// - a
+    /// - b
+    /// ```
+    sequence_indent: usize,
+    /// Whether a block collection can start on the same line as the parent indicator, for example:
+    /// ```yaml
+    /// - a: b
+    ///   c: d
+    /// ```
+    compact: bool,
 }
 
-fn parse_block_scalar(p: &mut YamlParser) -> CompletedMarker {
-    let m = p.start();
-    match p.cur() {
-        LITERAL_BLOCK_LITERAL => {
-            parse_literal_scalar(p);
+impl BlockContext {
+    /// The context of the top level node of a document
+    pub(crate) fn document() -> Self {
+        Self {
+            indent: 0,
+            sequence_indent: 0,
+            compact: false,
+        }
+    }
+
+    /// The context of the value of an implicit mapping entry at `column`
+    fn mapping_value(column: usize) -> Self {
+        Self {
+            indent: column + 1,
+            sequence_indent: column,
+            compact: false,
         }
-        FOLDED_BLOCK_LITERAL => {
-            parse_folded_scalar(p);
+    }
+
+    /// The context of a node following a `-`, `?` or `:` indicator at `column`
+    fn indented(column: usize) -> Self {
+        Self {
+            indent: column + 1,
+            sequence_indent: column + 1,
+            compact: true,
         }
-        _ => {}
     }
-    m.complete(p, YAML_BLOCK_SCALAR)
+
+    /// Returns `true` if the current token, which starts a new line, belongs to the node
+    fn is_in_scope(&self, p: &YamlParser) -> bool {
+        let column = p.cur_column();
+        column >= self.indent || (p.at(T![-]) && column >= self.sequence_indent)
+    }
 }
 
-fn parse_literal_scalar(p: &mut YamlParser) -> CompletedMarker {
+pub(crate) fn parse_any_block_node(p: &mut YamlParser, context: BlockContext) -> ParsedSyntax {
+    if is_at_document_end(p) {
+        return Absent;
+    }
+
+    let on_new_line = p.is_at_line_start();
+    if on_new_line && !context.is_in_scope(p) {
+        return Absent;
+    }
+
+    if (on_new_line || context.compact) && is_at_block_collection_start(p) {
+        return if on_new_line {
+            Present(parse_block_collection(p))
+        } else {
+            Present(parse_compact_collection(p))
+        };
+    }
+
     let m = p.start();
-    p.bump(LITERAL_BLOCK_LITERAL);
-    m.complete(p, YAML_LITERAL_SCALAR)
+    let has_properties = parse_properties(p).is_present();
+
+    if has_properties && !is_at_document_end(p) && p.is_at_line_start() {
+        if !context.is_in_scope(p) {
+            // A node with properties but without content
+            let node = m.complete(p, YAML_FLOW_YAML_NODE);
+            return Present(node.precede(p).complete(p, YAML_FLOW_IN_BLOCK_NODE));
+        }
+
+        if is_at_block_collection_start(p) {
+            parse_block_collection_content(p);
+            return Present(m.complete(p, YAML_BLOCK_COLLECTION));
+        }
+    }
+
+    if p.at(LITERAL_BLOCK_LITERAL) || p.at(FOLDED_BLOCK_LITERAL) {
+        parse_block_scalar_content(p);
+        return Present(m.complete(p, YAML_BLOCK_SCALAR));
+    }
+
+    if !has_properties && p.at(ALIAS_LITERAL) {
+        m.abandon(p);
+        let alias = p.start();
+        p.bump(ALIAS_LITERAL);
+        let alias = alias.complete(p, YAML_ALIAS_NODE);
+        return Present(alias.precede(p).complete(p, YAML_FLOW_IN_BLOCK_NODE));
+    }
+
+    let kind = parse_flow_content(
+        p,
+        YamlLexContext::FlowOut {
+            indent: context.indent,
+        },
+    );
+    let node = match kind {
+        Some(kind) => m.complete(p, kind),
+        None if has_properties => m.complete(p, YAML_FLOW_YAML_NODE),
+        None => {
+            m.abandon(p);
+            return Absent;
+        }
+    };
+    Present(node.precede(p).complete(p, YAML_FLOW_IN_BLOCK_NODE))
 }
 
-fn parse_folded_scalar(p: &mut YamlParser) -> CompletedMarker {
+fn parse_block_scalar_content(p: &mut YamlParser) -> CompletedMarker {
     let m = p.start();
-    p.bump(FOLDED_BLOCK_LITERAL);
-    m.complete(p, YAML_FOLDED_SCALAR)
+    if p.at(LITERAL_BLOCK_LITERAL) {
+        p.bump(LITERAL_BLOCK_LITERAL);
+        m.complete(p, YAML_LITERAL_SCALAR)
+    } else {
+        p.bump(FOLDED_BLOCK_LITERAL);
+        m.complete(p, YAML_FOLDED_SCALAR)
+    }
 }
 
+/// Parses a block sequence or a block mapping starting at the current token, which is the first
+/// token of its line
 fn parse_block_collection(p: &mut YamlParser) -> CompletedMarker {
     let m = p.start();
-    parse_block_mapping(p);
+    parse_block_collection_content(p);
     m.complete(p, YAML_BLOCK_COLLECTION)
 }
 
-/// Since the block key context's only difference with flow context is that it disallows multiline
-/// flow node, we can just parse a flow node and emit a diagnostic when we encounter a key that
-/// violate the restriction.lexer
-fn parse_implicit_entry_or_flow_node(p: &mut YamlParser) -> CompletedMarker {
-    let implicit_key_marker = parse_block_map_implicit_key(p);
-    // It's indeed an implicit key
-    if p.at(T![:]) {
-        let implicit_entry_marker = implicit_key_marker.precede(p);
-        parse_block_map_implicit_value(p);
-        let implicit_entry_marker =
-            implicit_entry_marker.complete(p, YAML_BLOCK_MAP_IMPLICIT_ENTRY);
-        parse_block_collection_starting_with_implicit_entry(p, implicit_entry_marker)
-    }
-    // It's just a flow node
-    else {
-        let block_node_marker = implicit_key_marker.precede(p);
-        p.eat(NEWLINE);
-        block_node_marker.complete(p, YAML_FLOW_IN_BLOCK_NODE)
+fn parse_block_collection_content(p: &mut YamlParser) -> CompletedMarker {
+    let column = p.cur_column();
+    let m = p.start();
+    if p.at(T![-]) {
+        parse_block_sequence_entries(p, column);
+        m.complete(p, YAML_BLOCK_SEQUENCE)
+    } else {
+        parse_block_map_entries(p, column);
+        m.complete(p, YAML_BLOCK_MAPPING)
     }
 }
 
-fn parse_block_collection_starting_with_implicit_entry(
-    p: &mut YamlParser,
-    implicit_entry_marker: CompletedMarker,
-) -> CompletedMarker {
-    let block_mapping_marker =
-        parse_block_mapping_starting_with_implicit_entry(p, implicit_entry_marker);
-    let block_collection_marker = block_mapping_marker.precede(p);
-    block_collection_marker.complete(p, YAML_BLOCK_COLLECTION)
-}
-
-fn parse_block_mapping(p: &mut YamlParser) -> CompletedMarker {
+/// Parses a block sequence or a block mapping that starts on the same line as its parent
+/// indicator, for example:
+/// ```yaml
+/// - - a
+///   - b
+/// - c: d
+///   e: f
+/// ```
+fn parse_compact_collection(p: &mut YamlParser) -> CompletedMarker {
+    let column = p.cur_column();
     let m = p.start();
-    BlockMapEntryList::default().parse_list(p);
-    m.complete(p, YAML_BLOCK_MAPPING)
+    if p.at(T![-]) {
+        parse_block_sequence_entries(p, column);
+        m.complete(p, YAML_COMPACT_SEQUENCE)
+    } else {
+        parse_block_map_entries(p, column);
+        m.complete(p, YAML_COMPACT_MAPPING)
+    }
 }
 
-fn parse_block_mapping_starting_with_implicit_entry(
-    p: &mut YamlParser,
-    implicit_entry_marker: CompletedMarker,
-) -> CompletedMarker {
-    let block_map_entry_list_marker = implicit_entry_marker.precede(p);
-    let block_map_entry_list_marker =
-        BlockMapEntryList::with(block_map_entry_list_marker).parse_list(p);
-    let block_map_marker = block_map_entry_list_marker.precede(p);
-    block_map_marker.complete(p, YAML_BLOCK_MAPPING)
-}
+fn parse_block_sequence_entries(p: &mut YamlParser, column: usize) -> CompletedMarker {
+    let list = p.start();
+    loop {
+        let entry = p.start();
+        p.bump(T![-]);
+        parse_any_block_node(p, BlockContext::indented(column)).ok();
+        entry.complete(p, YAML_BLOCK_SEQUENCE_ENTRY);
 
-#[derive(Default)]
-pub(crate) struct BlockMapEntryList {
-    start_marker: Option<Marker>,
-}
+        recover_unexpected_lines(p, column, YAML_BOGUS);
 
-impl BlockMapEntryList {
-    fn with(start_marker: Marker) -> Self {
-        Self {
-            start_marker: Some(start_marker),
+        // The entries of a mapping can be at the same column as the sequence when the sequence
+        // is the value of that mapping
+        if !is_at_next_entry(p, column) || !p.at(T![-]) {
+            break;
         }
     }
+    list.complete(p, YAML_BLOCK_SEQUENCE_ENTRY_LIST)
 }
 
-impl ParseNodeList for BlockMapEntryList {
-    type Kind = YamlSyntaxKind;
-    type Parser<'source> = YamlParser<'source>;
+fn parse_block_map_entries(p: &mut YamlParser, column: usize) -> CompletedMarker {
+    let list = p.start();
+    loop {
+        if is_at_block_map_entry_start(p) {
+            parse_block_map_entry(p, column);
+        } else {
+            let range = p.cur_range();
+            p.error(expected_block_mapping_entry(p, range));
+            recover_to_next_line(p, column, YAML_BOGUS_BLOCK_MAP_ENTRY);
+        }
 
-    const LIST_KIND: Self::Kind = YAML_BLOCK_MAP_ENTRY_LIST;
+        recover_unexpected_lines(p, column, YAML_BOGUS_BLOCK_MAP_ENTRY);
 
-    fn start_list(&mut self, p: &mut Self::Parser<'_>) -> Marker {
-        self.start_marker.take().unwrap_or_else(|| p.start())
+        if !is_at_next_entry(p, column) {
+            break;
+        }
     }
+    list.complete(p, YAML_BLOCK_MAP_ENTRY_LIST)
+}
 
-    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
-        parse_block_map_entry(p)
-    }
+/// Returns `true` if the current token starts a new line at `column`, where the next entry of a
+/// collection at `column` would be
+fn is_at_next_entry(p: &YamlParser, column: usize) -> bool {
+    !is_at_document_end(p) && p.is_at_line_start() && p.cur_column() == column
+}
 
-    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
-        p.at(DEDENT)
-        // If this is the top level block mapping
-        || p.at(DOC_END)
+/// Recovers from the tokens that follow an entry of the collection at `column` but don't belong
+/// to it: tokens on the same line as the end of the entry, or more indented lines.
+fn recover_unexpected_lines(p: &mut YamlParser, column: usize, kind: YamlSyntaxKind) {
+    if is_at_document_end(p) {
+        return;
     }
 
-    fn recover(
-        &mut self,
-        p: &mut Self::Parser<'_>,
-        parsed_element: ParsedSyntax,
-    ) -> biome_parser::parse_recovery::RecoveryResult {
-        parsed_element.or_recover(p, &BlockMapEntryListParseRecovery, expected_block_mapping)
+    if !p.is_at_line_start() {
+        let range = p.cur_range();
+        p.error(expected_line_break(p, range));
+        recover_to_next_line(p, column, kind);
+    } else if p.cur_column() > column {
+        let range = p.cur_range();
+        p.error(unexpected_indentation(p, range));
+        recover_to_next_line(p, column, kind);
     }
 }
 
-struct BlockMapEntryListParseRecovery;
-
-impl ParseRecovery for BlockMapEntryListParseRecovery {
-    type Kind = YamlSyntaxKind;
-    type Parser<'source> = YamlParser<'source>;
-    const RECOVERED_KIND: Self::Kind = YAML_BOGUS_BLOCK_MAP_ENTRY;
-
-    fn is_at_recovered(&self, p: &mut Self::Parser<'_>) -> bool {
-        p.at(DEDENT)
+/// Consumes all tokens until the next line that starts at or before `column`
+fn recover_to_next_line(p: &mut YamlParser, column: usize, kind: YamlSyntaxKind) {
+    let m = p.start();
+    p.bump_any();
+    while !(is_at_document_end(p) || (p.is_at_line_start() && p.cur_column() <= column)) {
+        p.bump_any();
     }
+    m.complete(p, kind);
 }
 
-fn parse_block_map_entry(p: &mut YamlParser) -> ParsedSyntax {
-    if is_at_explicit_mapping_key(p) {
-        parse_block_map_explicit_entry(p)
+fn parse_block_map_entry(p: &mut YamlParser, column: usize) -> CompletedMarker {
+    if p.at(T![?]) {
+        parse_block_map_explicit_entry(p, column)
     } else {
-        parse_block_map_implicit_entry(p)
+        parse_block_map_implicit_entry(p, column)
     }
 }
 
-fn parse_block_map_explicit_entry(p: &mut YamlParser) -> ParsedSyntax {
-    if !is_at_explicit_mapping_key(p) {
-        return Absent;
-    }
+fn parse_block_map_explicit_entry(p: &mut YamlParser, column: usize) -> CompletedMarker {
     let m = p.start();
-    parse_block_map_explicit_key(p);
-    // Value can be omitted in an explicit entry
-    parse_block_map_explicit_value(p).ok();
-    Present(m.complete(p, YAML_BLOCK_MAP_EXPLICIT_ENTRY))
-}
 
-fn parse_block_map_explicit_key(p: &mut YamlParser) -> CompletedMarker {
-    let m = p.start();
+    let key = p.start();
     p.bump(T![?]);
-    parse_block_indented(p).ok();
-    m.complete(p, YAML_BLOCK_MAP_EXPLICIT_KEY)
-}
+    parse_any_block_node(p, BlockContext::indented(column)).ok();
+    key.complete(p, YAML_BLOCK_MAP_EXPLICIT_KEY);
 
-fn parse_block_map_explicit_value(p: &mut YamlParser) -> ParsedSyntax {
-    if !p.at(T![:]) {
-        return Absent;
+    // Value can be omitted in an explicit entry
+    if p.at(T![:]) && p.is_at_line_start() && p.cur_column() == column {
+        let value = p.start();
+        p.bump(T![:]);
+        parse_any_block_node(p, BlockContext::indented(column)).ok();
+        value.complete(p, YAML_BLOCK_MAP_EXPLICIT_VALUE);
     }
-    let m = p.start();
-    p.bump(T![:]);
-    parse_block_indented(p).ok();
-    Present(m.complete(p, YAML_BLOCK_MAP_EXPLICIT_VALUE))
-}
 
-fn parse_block_indented(p: &mut YamlParser) -> ParsedSyntax {
-    parse_any_block_node(p)
+    m.complete(p, YAML_BLOCK_MAP_EXPLICIT_ENTRY)
 }
 
-fn parse_block_map_implicit_entry(p: &mut YamlParser) -> ParsedSyntax {
-    if !is_at_flow_yaml_node(p) {
-        return Absent;
-    }
+fn parse_block_map_implicit_entry(p: &mut YamlParser, column: usize) -> CompletedMarker {
     let m = p.start();
-    parse_block_map_implicit_key(p);
-    parse_block_map_implicit_value(p);
-    Present(m.complete(p, YAML_BLOCK_MAP_IMPLICIT_ENTRY))
+    // The key can be omitted, for example `: value`
+    if !p.at(T![:]) {
+        parse_block_map_implicit_key(p);
+    }
+
+    let value = p.start();
+    p.expect(T![:]);
+    // Value can be completely empty according to the spec
+    parse_any_block_node(p, BlockContext::mapping_value(column)).ok();
+    value.complete(p, YAML_BLOCK_MAP_IMPLICIT_VALUE);
+
+    m.complete(p, YAML_BLOCK_MAP_IMPLICIT_ENTRY)
 }
 
+/// Implicit keys are restricted to a single line, so their plain scalars are lexed in the
+/// regular context
 fn parse_block_map_implicit_key(p: &mut YamlParser) -> CompletedMarker {
-    parse_flow_yaml_node(p, YamlLexContext::BlockKey)
-}
+    if p.at(ALIAS_LITERAL) {
+        let m = p.start();
+        p.bump(ALIAS_LITERAL);
+        return m.complete(p, YAML_ALIAS_NODE);
+    }
 
-fn parse_block_map_implicit_value(p: &mut YamlParser) -> CompletedMarker {
     let m = p.start();
-    p.bump(COLON);
-    // Value can be completely empty according to the spec
-    let value = parse_any_block_node(p);
-    if value.is_absent() {
-        p.eat(NEWLINE);
-    }
-    m.complete(p, YAML_BLOCK_MAP_IMPLICIT_VALUE)
+    parse_properties(p).ok();
+    let kind = parse_flow_content(p, YamlLexContext::Regular).unwrap_or(YAML_FLOW_YAML_NODE);
+    m.complete(p, kind)
 }
 
-fn is_at_explicit_mapping_key(p: &mut YamlParser) -> bool {
-    p.at(T![?])
+fn is_at_block_collection_start(p: &mut YamlParser) -> bool {
+    p.at(T![-]) || is_at_block_map_entry_start(p)
 }
 
-fn maybe_at_implicit_mapping_key(p: &YamlParser) -> bool {
-    is_at_flow_yaml_node(p)
+fn is_at_block_map_entry_start(p: &mut YamlParser) -> bool {
+    p.at(T![?]) || p.at(T![:]) || is_at_implicit_key(p)
 }
 
-fn is_at_block_scalar(p: &YamlParser) -> bool {
-    p.at(LITERAL_BLOCK_LITERAL) || p.at(FOLDED_BLOCK_LITERAL)
+/// Returns `true` if the parser is at an implicit key, that is, a scalar or an alias followed by
+/// a `:` on the same line
+fn is_at_implicit_key(p: &mut YamlParser) -> bool {
+    let mut n = 0;
+    // Skip the node properties of the key
+    while n < 2 && is_at_properties(p.nth(n)) {
+        n += 1;
+        if p.has_nth_preceding_line_break(n) {
+            return false;
+        }
+    }
+
+    if !matches!(
+        p.nth(n),
+        PLAIN_LITERAL | DOUBLE_QUOTED_LITERAL | SINGLE_QUOTED_LITERAL | ALIAS_LITERAL
+    ) {
+        return false;
+    }
+    n += 1;
+    p.nth_at(n, T![:]) && !p.has_nth_preceding_line_break(n)
 }
@@ -10,7 +10,11 @@ use biome_yaml_syntax::{
     YamlSyntaxKind::{self, *},
 };
 
-use super::{YamlParser, block::parse_any_block_node, parse_error::expected_directive};
+use super::{
+    YamlParser,
+    block::{BlockContext, parse_any_block_node},
+    parse_error::{expected_block_node, expected_directive},
+};
 
 #[derive(Default)]
 pub(crate) struct DocumentList;
@@ -41,14 +45,34 @@ impl ParseNodeList for DocumentList {
 fn parse_document(p: &mut YamlParser) -> CompletedMarker {
     let m = p.start();
     p.eat(UNICODE_BOM);
-    p.eat(NEWLINE);
     DirectiveList.parse_list(p);
     p.eat(T![---]);
-    parse_any_block_node(p).ok();
+
+    let node = parse_any_block_node(p, BlockContext::document());
+    if !is_at_document_end(p) {
+        // Everything until the end of the document is part of the node
+        let bogus = match node {
+            Present(node) => node.precede(p),
+            Absent => p.start(),
+        };
+        let range = p.cur_range();
+        p.error(expected_block_node(p, range));
+        while !is_at_document_end(p) {
+            p.bump_any();
+        }
+        bogus.complete(p, YAML_BOGUS_BLOCK_NODE);
+    }
+
     p.eat(T![...]);
     m.complete(p, YAML_DOCUMENT)
 }
 
+/// Returns `true` if the parser is at the end of the current document: the end of the file, a
+/// document marker or a directive of the next document
+pub(crate) fn is_at_document_end(p: &YamlParser) -> bool {
+    p.at(EOF) || p.at(T![---]) || p.at(T![...]) || p.at(DIRECTIVE_LITERAL)
+}
+
 #[derive(Default)]
 pub(crate) struct DirectiveList;
 
@@ -1,30 +1,256 @@
-use biome_parser::{CompletedMarker, Parser};
-use biome_yaml_syntax::YamlSyntaxKind::*;
+use biome_parser::{
+    CompletedMarker, Parser,
+    parse_lists::ParseSeparatedList,
+    parse_recovery::{ParseRecoveryTokenSet, RecoveryResult},
+    prelude::ParsedSyntax::{self, *},
+    token_set,
+};
+use biome_yaml_syntax::{
+    T,
+    YamlSyntaxKind::{self, *},
+};
 
 use crate::lexer::YamlLexContext;
 
-use super::YamlParser;
+use super::{YamlParser, parse_error::expected_flow_node};
+
+/// Returns `true` if `kind` starts the properties of a node
+pub(crate) fn is_at_properties(kind: YamlSyntaxKind) -> bool {
+    matches!(kind, ANCHOR_PROPERTY_LITERAL | TAG_PROPERTY_LITERAL)
+}
+
+/// Parses the anchor and the tag of a node, in any order
+// https://yaml.org/spec/1.2.2/#rule-c-ns-properties
+pub(crate) fn parse_properties(p: &mut YamlParser) -> ParsedSyntax {
+    parse_properties_with_context(p, YamlLexContext::Regular)
+}
+
+fn parse_properties_with_context(p: &mut YamlParser, context: YamlLexContext) -> ParsedSyntax {
+    let m = p.start();
+    let kind = if p.at(ANCHOR_PROPERTY_LITERAL) {
+        parse_property(p, ANCHOR_PROPERTY_LITERAL, YAML_ANCHOR_PROPERTY, context);
+        if p.at(TAG_PROPERTY_LITERAL) {
+            parse_property(p, TAG_PROPERTY_LITERAL, YAML_TAG_PROPERTY, context);
+        }
+        YAML_PROPERTIES_ANCHOR_FIRST
+    } else if p.at(TAG_PROPERTY_LITERAL) {
+        parse_property(p, TAG_PROPERTY_LITERAL, YAML_TAG_PROPERTY, context);
+        if p.at(ANCHOR_PROPERTY_LITERAL) {
+            parse_property(p, ANCHOR_PROPERTY_LITERAL, YAML_ANCHOR_PROPERTY, context);
+        }
+        YAML_PROPERTIES_TAG_FIRST
+    } else {
+        m.abandon(p);
+        return Absent;
+    };
+    Present(m.complete(p, kind))
+}
+
+fn parse_property(
+    p: &mut YamlParser,
+    token: YamlSyntaxKind,
+    kind: YamlSyntaxKind,
+    context: YamlLexContext,
+) -> CompletedMarker {
+    let m = p.start();
+    p.bump_with_context(token, context);
+    m.complete(p, kind)
+}
+
+/// Parses the content of a flow node: a scalar or a flow collection.
+///
+/// Returns the kind of the node that should wrap the content, or [None] if there's no content.
+/// `context` is used to lex plain scalars.
+pub(crate) fn parse_flow_content(
+    p: &mut YamlParser,
+    context: YamlLexContext,
+) -> Option<YamlSyntaxKind> {
+    // Tokens following a node in block context are lexed in the regular context
+    let next_context = match context {
+        YamlLexContext::FlowIn => YamlLexContext::FlowIn,
+        _ => YamlLexContext::Regular,
+    };
+
+    match p.cur() {
+        T!['['] => {
+            parse_flow_sequence(p, next_context);
+            Some(YAML_FLOW_JSON_NODE)
+        }
+        T!['{'] => {
+            parse_flow_mapping(p, next_context);
+            Some(YAML_FLOW_JSON_NODE)
+        }
+        DOUBLE_QUOTED_LITERAL => {
+            let m = p.start();
+            p.bump_with_context(DOUBLE_QUOTED_LITERAL, next_context);
+            m.complete(p, YAML_DOUBLE_QUOTED_SCALAR);
+            Some(YAML_FLOW_JSON_NODE)
+        }
+        SINGLE_QUOTED_LITERAL => {
+            let m = p.start();
+            p.bump_with_context(SINGLE_QUOTED_LITERAL, next_context);
+            m.complete(p, YAML_SINGLE_QUOTED_SCALAR);
+            Some(YAML_FLOW_JSON_NODE)
+        }
+        PLAIN_LITERAL => {
+            // The current plain token was lexed during the last `bump` or `bump_with_context`
+            // call, which might have used a different context than the current one
+            p.re_lex(context);
+            let m = p.start();
+            p.bump_with_context(PLAIN_LITERAL, next_context);
+            m.complete(p, YAML_PLAIN_SCALAR);
+            Some(YAML_FLOW_YAML_NODE)
+        }
+        _ => None,
+    }
+}
+
+/// Parses a node inside a flow collection
+fn parse_flow_node(p: &mut YamlParser) -> ParsedSyntax {
+    if p.at(ALIAS_LITERAL) {
+        let m = p.start();
+        p.bump_with_context(ALIAS_LITERAL, YamlLexContext::FlowIn);
+        return Present(m.complete(p, YAML_ALIAS_NODE));
+    }
 
-// TODO: parse node properties
-pub(crate) fn parse_flow_yaml_node(p: &mut YamlParser, context: YamlLexContext) -> CompletedMarker {
     let m = p.start();
-    parse_plain_scalar(p, context);
-    m.complete(p, YAML_FLOW_YAML_NODE)
+    let has_properties = parse_properties_with_context(p, YamlLexContext::FlowIn).is_present();
+    match parse_flow_content(p, YamlLexContext::FlowIn) {
+        Some(kind) => Present(m.complete(p, kind)),
+        None if has_properties => Present(m.complete(p, YAML_FLOW_YAML_NODE)),
+        None => {
+            m.abandon(p);
+            Absent
+        }
+    }
 }
 
-fn parse_plain_scalar(p: &mut YamlParser, context: YamlLexContext) -> CompletedMarker {
-    // The current plain token was lexed during the last `bump` or `bump_with_context` call, which
-    // might have used a different context than the current one
-    p.re_lex(context);
+// https://yaml.org/spec/1.2.2/#741-flow-sequences
+fn parse_flow_sequence(p: &mut YamlParser, next_context: YamlLexContext) -> CompletedMarker {
     let m = p.start();
-    p.bump(PLAIN_LITERAL);
-    m.complete(p, YAML_PLAIN_SCALAR)
+    p.bump_with_context(T!['['], YamlLexContext::FlowIn);
+    FlowEntryList {
+        kind: YAML_FLOW_SEQUENCE_ENTRY_LIST,
+        end: T![']'],
+    }
+    .parse_list(p);
+    p.expect_with_context(T![']'], next_context);
+    m.complete(p, YAML_FLOW_SEQUENCE)
 }
 
-pub(crate) fn is_at_flow_yaml_node(p: &YamlParser) -> bool {
-    is_at_plain_scalar(p)
+// https://yaml.org/spec/1.2.2/#742-flow-mappings
+fn parse_flow_mapping(p: &mut YamlParser, next_context: YamlLexContext) -> CompletedMarker {
+    let m = p.start();
+    p.bump_with_context(T!['{'], YamlLexContext::FlowIn);
+    FlowEntryList {
+        kind: YAML_FLOW_MAP_ENTRY_LIST,
+        end: T!['}'],
+    }
+    .parse_list(p);
+    p.expect_with_context(T!['}'], next_context);
+    m.complete(p, YAML_FLOW_MAPPING)
+}
+
+struct FlowEntryList {
+    kind: YamlSyntaxKind,
+    end: YamlSyntaxKind,
+}
+
+impl ParseSeparatedList for FlowEntryList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+
+    const LIST_KIND: Self::Kind = YAML_FLOW_SEQUENCE_ENTRY_LIST;
+
+    fn start_list(&mut self, p: &mut Self::Parser<'_>) -> biome_parser::Marker {
+        p.start()
+    }
+
+    fn finish_list(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        m: biome_parser::Marker,
+    ) -> CompletedMarker {
+        m.complete(p, self.kind)
+    }
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if self.kind == YAML_FLOW_MAP_ENTRY_LIST {
+            parse_flow_map_entry(p)
+        } else {
+            parse_flow_sequence_entry(p)
+        }
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(self.end)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(YAML_BOGUS, token_set![T![,], T![']'], T!['}']]),
+            expected_flow_node,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn expect_separator(&mut self, p: &mut Self::Parser<'_>) -> bool {
+        p.expect_with_context(T![,], YamlLexContext::FlowIn)
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+/// Parses an entry of a flow sequence, which is either a node or a single pair mapping
+// https://yaml.org/spec/1.2.2/#rule-ns-flow-seq-entry
+fn parse_flow_sequence_entry(p: &mut YamlParser) -> ParsedSyntax {
+    if p.at(T![?]) || p.at(T![:]) {
+        return parse_flow_map_entry(p);
+    }
+    let node = parse_flow_node(p);
+    match node {
+        Present(key) if p.at(T![:]) => {
+            let m = key.precede(p);
+            Present(parse_flow_map_implicit_value(p, m))
+        }
+        node => node,
+    }
+}
+
+// https://yaml.org/spec/1.2.2/#rule-ns-flow-map-entry
+fn parse_flow_map_entry(p: &mut YamlParser) -> ParsedSyntax {
+    if p.at(T![?]) {
+        let m = p.start();
+        p.bump_with_context(T![?], YamlLexContext::FlowIn);
+        parse_flow_map_implicit_entry(p).ok();
+        return Present(m.complete(p, YAML_FLOW_MAP_EXPLICIT_ENTRY));
+    }
+    parse_flow_map_implicit_entry(p)
+}
+
+fn parse_flow_map_implicit_entry(p: &mut YamlParser) -> ParsedSyntax {
+    let m = p.start();
+    // The key can be omitted, for example `{: value}`
+    if parse_flow_node(p).is_absent() && !p.at(T![:]) {
+        m.abandon(p);
+        return Absent;
+    }
+    Present(parse_flow_map_implicit_value(p, m))
 }
 
-fn is_at_plain_scalar(p: &YamlParser) -> bool {
-    p.at(PLAIN_LITERAL)
+fn parse_flow_map_implicit_value(p: &mut YamlParser, m: biome_parser::Marker) -> CompletedMarker {
+    p.expect_with_context(T![:], YamlLexContext::FlowIn);
+    // The value can be omitted, for example `{a: , b: c}`
+    parse_flow_node(p).ok();
+    m.complete(p, YAML_FLOW_MAP_IMPLICIT_ENTRY)
 }
@@ -37,6 +37,21 @@ impl<'source> YamlParser<'source> {
         self.source_mut().re_lex(context)
     }
 
+    /// Returns the column of the current token, that is, its byte offset from the start of its line
+    pub fn cur_column(&self) -> usize {
+        let start = usize::from(self.cur_range().start());
+        start - line_start(self.source.text(), start)
+    }
+
+    /// Returns `true` if the current token is the first token of its line
+    pub fn is_at_line_start(&self) -> bool {
+        let text = self.source.text();
+        let start = usize::from(self.cur_range().start());
+        text[line_start(text, start)..start]
+            .bytes()
+            .all(|byte| byte == b' ' || byte == b'\t')
+    }
+
     pub fn finish(
         self,
     ) -> (
@@ -106,6 +121,17 @@ pub(crate) fn parse_root(p: &mut YamlParser) -> CompletedMarker {
     m.complete(p, YAML_ROOT)
 }
 
+/// Returns the byte offset of the start of the line containing `position`, after the Unicode BOM
+fn line_start(text: &str, position: usize) -> usize {
+    text[..position]
+        .rfind(['\n', '\r'])
+        .map_or_else(
+            || text.len() - text.trim_start_matches('\u{feff}').len(),
+            |index| index + 1,
+        )
+        .min(position)
+}
+
 pub struct YamlParserCheckpoint {
     pub(super) context: ParserContextCheckpoint,
     pub(super) source: YamlTokenSourceCheckpoint,
@@ -1,11 +1,33 @@
 use crate::parser::YamlParser;
-use biome_parser::diagnostic::{ParseDiagnostic, expected_node};
+use biome_parser::diagnostic::{ParseDiagnostic, expected_any, expected_node};
 use biome_rowan::TextRange;
 
-pub(crate) fn expected_block_mapping(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
-    expected_node("mapping", range, p)
+pub(crate) fn expected_block_mapping_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("mapping entry", range, p)
 }
 
 pub(crate) fn expected_directive(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
     expected_node("directive", range, p)
 }
+
+pub(crate) fn expected_flow_node(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(
+        &["scalar", "alias", "flow sequence", "flow mapping"],
+        range,
+        p,
+    )
+}
+
+pub(crate) fn expected_block_node(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["block sequence", "block mapping", "scalar"], range, p)
+}
+
+pub(crate) fn expected_line_break(_p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    ParseDiagnostic::new("Expected a line break before this token.", range)
+        .with_hint("The previous node ends here, only comments can follow it on the same line.")
+}
+
+pub(crate) fn unexpected_indentation(_p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    ParseDiagnostic::new("Unexpected indentation.", range)
+        .with_hint("This line is indented more than the entries of its parent collection.")
+}
@@ -56,6 +56,10 @@ impl<'source> YamlTokenSource<'source> {
     }
 
     pub fn re_lex(&mut self, mode: YamlLexContext) -> YamlSyntaxKind {
+        // Re-lexing a plain scalar can change its extent without changing its kind, which would
+        // leave the lookahead tokens out of sync. Discard them before re-lexing.
+        let checkpoint = self.lexer.checkpoint();
+        self.lexer.rewind(checkpoint);
         self.lexer.re_lex(mode)
     }
 
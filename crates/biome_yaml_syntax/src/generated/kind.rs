@@ -36,8 +36,6 @@ pub enum YamlSyntaxKind {
     ERROR_TOKEN,
     NEWLINE,
     WHITESPACE,
-    INDENT,
-    DEDENT,
     COMMENT,
     YAML_ROOT,
     YAML_DOCUMENT_LIST,
@@ -307,7 +307,6 @@ impl YamlBlockMapImplicitValue {
         YamlBlockMapImplicitValueFields {
             colon_token: self.colon_token(),
             value: self.value(),
-            newline_token: self.newline_token(),
         }
     }
     pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
@@ -316,9 +315,6 @@ impl YamlBlockMapImplicitValue {
     pub fn value(&self) -> Option<AnyYamlBlockNode> {
         support::node(&self.syntax, 1usize)
     }
-    pub fn newline_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, 2usize)
-    }
 }
 impl Serialize for YamlBlockMapImplicitValue {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -332,7 +328,6 @@ impl Serialize for YamlBlockMapImplicitValue {
 pub struct YamlBlockMapImplicitValueFields {
     pub colon_token: SyntaxResult<SyntaxToken>,
     pub value: Option<AnyYamlBlockNode>,
-    pub newline_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlBlockMapping {
@@ -350,19 +345,11 @@ impl YamlBlockMapping {
     }
     pub fn as_fields(&self) -> YamlBlockMappingFields {
         YamlBlockMappingFields {
-            indent_token: self.indent_token(),
             entries: self.entries(),
-            dedent_token: self.dedent_token(),
         }
     }
-    pub fn indent_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, 0usize)
-    }
     pub fn entries(&self) -> YamlBlockMapEntryList {
-        support::list(&self.syntax, 1usize)
-    }
-    pub fn dedent_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, 2usize)
+        support::list(&self.syntax, 0usize)
     }
 }
 impl Serialize for YamlBlockMapping {
@@ -375,9 +362,7 @@ impl Serialize for YamlBlockMapping {
 }
 #[derive(Serialize)]
 pub struct YamlBlockMappingFields {
-    pub indent_token: Option<SyntaxToken>,
     pub entries: YamlBlockMapEntryList,
-    pub dedent_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlBlockScalar {
@@ -435,19 +420,11 @@ impl YamlBlockSequence {
     }
     pub fn as_fields(&self) -> YamlBlockSequenceFields {
         YamlBlockSequenceFields {
-            indent_token: self.indent_token(),
             entries: self.entries(),
-            dedent_token: self.dedent_token(),
         }
     }
-    pub fn indent_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, 0usize)
-    }
     pub fn entries(&self) -> YamlBlockSequenceEntryList {
-        support::list(&self.syntax, 1usize)
-    }
-    pub fn dedent_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, 2usize)
+        support::list(&self.syntax, 0usize)
     }
 }
 impl Serialize for YamlBlockSequence {
@@ -460,9 +437,7 @@ impl Serialize for YamlBlockSequence {
 }
 #[derive(Serialize)]
 pub struct YamlBlockSequenceFields {
-    pub indent_token: Option<SyntaxToken>,
     pub entries: YamlBlockSequenceEntryList,
-    pub dedent_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlBlockSequenceEntry {
@@ -523,7 +498,7 @@ impl YamlCompactMapping {
             entries: self.entries(),
         }
     }
-    pub fn entries(&self) -> YamlBlockSequenceEntryList {
+    pub fn entries(&self) -> YamlBlockMapEntryList {
         support::list(&self.syntax, 0usize)
     }
 }
@@ -537,7 +512,7 @@ impl Serialize for YamlCompactMapping {
 }
 #[derive(Serialize)]
 pub struct YamlCompactMappingFields {
-    pub entries: YamlBlockSequenceEntryList,
+    pub entries: YamlBlockMapEntryList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlCompactSequence {
@@ -714,17 +689,11 @@ impl YamlFlowInBlockNode {
         Self { syntax }
     }
     pub fn as_fields(&self) -> YamlFlowInBlockNodeFields {
-        YamlFlowInBlockNodeFields {
-            flow: self.flow(),
-            newline_token: self.newline_token(),
-        }
+        YamlFlowInBlockNodeFields { flow: self.flow() }
     }
     pub fn flow(&self) -> SyntaxResult<AnyYamlFlowNode> {
         support::required_node(&self.syntax, 0usize)
     }
-    pub fn newline_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, 1usize)
-    }
 }
 impl Serialize for YamlFlowInBlockNode {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -737,7 +706,6 @@ impl Serialize for YamlFlowInBlockNode {
 #[derive(Serialize)]
 pub struct YamlFlowInBlockNodeFields {
     pub flow: SyntaxResult<AnyYamlFlowNode>,
-    pub newline_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlFlowJsonNode {
@@ -846,8 +814,8 @@ impl YamlFlowMapImplicitEntry {
     pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 1usize)
     }
-    pub fn value(&self) -> SyntaxResult<AnyYamlFlowNode> {
-        support::required_node(&self.syntax, 2usize)
+    pub fn value(&self) -> Option<AnyYamlFlowNode> {
+        support::node(&self.syntax, 2usize)
     }
 }
 impl Serialize for YamlFlowMapImplicitEntry {
@@ -862,7 +830,7 @@ impl Serialize for YamlFlowMapImplicitEntry {
 pub struct YamlFlowMapImplicitEntryFields {
     pub key: Option<AnyYamlFlowMapImplicitKey>,
     pub colon_token: SyntaxResult<SyntaxToken>,
-    pub value: SyntaxResult<AnyYamlFlowNode>,
+    pub value: Option<AnyYamlFlowNode>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct YamlFlowMapping {
@@ -1381,10 +1349,17 @@ impl AnyYamlBlockMapEntry {
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum AnyYamlBlockMapImplicitKey {
+    YamlAliasNode(YamlAliasNode),
     YamlFlowJsonNode(YamlFlowJsonNode),
     YamlFlowYamlNode(YamlFlowYamlNode),
 }
 impl AnyYamlBlockMapImplicitKey {
+    pub fn as_yaml_alias_node(&self) -> Option<&YamlAliasNode> {
+        match &self {
+            Self::YamlAliasNode(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_yaml_flow_json_node(&self) -> Option<&YamlFlowJsonNode> {
         match &self {
             Self::YamlFlowJsonNode(item) => Some(item),
@@ -1464,10 +1439,17 @@ impl AnyYamlFlowMapEntry {
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum AnyYamlFlowMapImplicitKey {
+    YamlAliasNode(YamlAliasNode),
     YamlFlowJsonNode(YamlFlowJsonNode),
     YamlFlowYamlNode(YamlFlowYamlNode),
 }
 impl AnyYamlFlowMapImplicitKey {
+    pub fn as_yaml_alias_node(&self) -> Option<&YamlAliasNode> {
+        match &self {
+            Self::YamlAliasNode(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_yaml_flow_json_node(&self) -> Option<&YamlFlowJsonNode> {
         match &self {
             Self::YamlFlowJsonNode(item) => Some(item),
@@ -1960,10 +1942,6 @@ impl std::fmt::Debug for YamlBlockMapImplicitValue {
                     &support::DebugSyntaxResult(self.colon_token()),
                 )
                 .field("value", &support::DebugOptionalElement(self.value()))
-                .field(
-                    "newline_token",
-                    &support::DebugOptionalElement(self.newline_token()),
-                )
                 .finish()
         } else {
             f.debug_struct("YamlBlockMapImplicitValue").finish()
@@ -2010,15 +1988,7 @@ impl std::fmt::Debug for YamlBlockMapping {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("YamlBlockMapping")
-                .field(
-                    "indent_token",
-                    &support::DebugOptionalElement(self.indent_token()),
-                )
                 .field("entries", &self.entries())
-                .field(
-                    "dedent_token",
-                    &support::DebugOptionalElement(self.dedent_token()),
-                )
                 .finish()
         } else {
             f.debug_struct("YamlBlockMapping").finish()
@@ -2116,15 +2086,7 @@ impl std::fmt::Debug for YamlBlockSequence {
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
             f.debug_struct("YamlBlockSequence")
-                .field(
-                    "indent_token",
-                    &support::DebugOptionalElement(self.indent_token()),
-                )
                 .field("entries", &self.entries())
-                .field(
-                    "dedent_token",
-                    &support::DebugOptionalElement(self.dedent_token()),
-                )
                 .finish()
         } else {
             f.debug_struct("YamlBlockSequence").finish()
@@ -2477,10 +2439,6 @@ impl std::fmt::Debug for YamlFlowInBlockNode {
             DEPTH.set(current_depth + 1);
             f.debug_struct("YamlFlowInBlockNode")
                 .field("flow", &support::DebugSyntaxResult(self.flow()))
-                .field(
-                    "newline_token",
-                    &support::DebugOptionalElement(self.newline_token()),
-                )
                 .finish()
         } else {
             f.debug_struct("YamlFlowInBlockNode").finish()
@@ -2634,7 +2592,7 @@ impl std::fmt::Debug for YamlFlowMapImplicitEntry {
                     "colon_token",
                     &support::DebugSyntaxResult(self.colon_token()),
                 )
-                .field("value", &support::DebugSyntaxResult(self.value()))
+                .field("value", &support::DebugOptionalElement(self.value()))
                 .finish()
         } else {
             f.debug_struct("YamlFlowMapImplicitEntry").finish()
@@ -3484,6 +3442,11 @@ impl From<AnyYamlBlockMapEntry> for SyntaxElement {
         node.into()
     }
 }
+impl From<YamlAliasNode> for AnyYamlBlockMapImplicitKey {
+    fn from(node: YamlAliasNode) -> Self {
+        Self::YamlAliasNode(node)
+    }
+}
 impl From<YamlFlowJsonNode> for AnyYamlBlockMapImplicitKey {
     fn from(node: YamlFlowJsonNode) -> Self {
         Self::YamlFlowJsonNode(node)
@@ -3496,13 +3459,18 @@ impl From<YamlFlowYamlNode> for AnyYamlBlockMapImplicitKey {
 }
 impl AstNode for AnyYamlBlockMapImplicitKey {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        YamlFlowJsonNode::KIND_SET.union(YamlFlowYamlNode::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = YamlAliasNode::KIND_SET
+        .union(YamlFlowJsonNode::KIND_SET)
+        .union(YamlFlowYamlNode::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, YAML_FLOW_JSON_NODE | YAML_FLOW_YAML_NODE)
+        matches!(
+            kind,
+            YAML_ALIAS_NODE | YAML_FLOW_JSON_NODE | YAML_FLOW_YAML_NODE
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
+            YAML_ALIAS_NODE => Self::YamlAliasNode(YamlAliasNode { syntax }),
             YAML_FLOW_JSON_NODE => Self::YamlFlowJsonNode(YamlFlowJsonNode { syntax }),
             YAML_FLOW_YAML_NODE => Self::YamlFlowYamlNode(YamlFlowYamlNode { syntax }),
             _ => return None,
@@ -3511,12 +3479,14 @@ impl AstNode for AnyYamlBlockMapImplicitKey {
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
+            Self::YamlAliasNode(it) => &it.syntax,
             Self::YamlFlowJsonNode(it) => &it.syntax,
             Self::YamlFlowYamlNode(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
+            Self::YamlAliasNode(it) => it.syntax,
             Self::YamlFlowJsonNode(it) => it.syntax,
             Self::YamlFlowYamlNode(it) => it.syntax,
         }
@@ -3525,6 +3495,7 @@ impl AstNode for AnyYamlBlockMapImplicitKey {
 impl std::fmt::Debug for AnyYamlBlockMapImplicitKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::YamlAliasNode(it) => std::fmt::Debug::fmt(it, f),
             Self::YamlFlowJsonNode(it) => std::fmt::Debug::fmt(it, f),
             Self::YamlFlowYamlNode(it) => std::fmt::Debug::fmt(it, f),
         }
@@ -3533,6 +3504,7 @@ impl std::fmt::Debug for AnyYamlBlockMapImplicitKey {
 impl From<AnyYamlBlockMapImplicitKey> for SyntaxNode {
     fn from(n: AnyYamlBlockMapImplicitKey) -> Self {
         match n {
+            AnyYamlBlockMapImplicitKey::YamlAliasNode(it) => it.into(),
             AnyYamlBlockMapImplicitKey::YamlFlowJsonNode(it) => it.into(),
             AnyYamlBlockMapImplicitKey::YamlFlowYamlNode(it) => it.into(),
         }
@@ -3745,6 +3717,11 @@ impl From<AnyYamlFlowMapEntry> for SyntaxElement {
         node.into()
     }
 }
+impl From<YamlAliasNode> for AnyYamlFlowMapImplicitKey {
+    fn from(node: YamlAliasNode) -> Self {
+        Self::YamlAliasNode(node)
+    }
+}
 impl From<YamlFlowJsonNode> for AnyYamlFlowMapImplicitKey {
     fn from(node: YamlFlowJsonNode) -> Self {
         Self::YamlFlowJsonNode(node)
@@ -3757,13 +3734,18 @@ impl From<YamlFlowYamlNode> for AnyYamlFlowMapImplicitKey {
 }
 impl AstNode for AnyYamlFlowMapImplicitKey {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        YamlFlowJsonNode::KIND_SET.union(YamlFlowYamlNode::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = YamlAliasNode::KIND_SET
+        .union(YamlFlowJsonNode::KIND_SET)
+        .union(YamlFlowYamlNode::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, YAML_FLOW_JSON_NODE | YAML_FLOW_YAML_NODE)
+        matches!(
+            kind,
+            YAML_ALIAS_NODE | YAML_FLOW_JSON_NODE | YAML_FLOW_YAML_NODE
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
+            YAML_ALIAS_NODE => Self::YamlAliasNode(YamlAliasNode { syntax }),
             YAML_FLOW_JSON_NODE => Self::YamlFlowJsonNode(YamlFlowJsonNode { syntax }),
             YAML_FLOW_YAML_NODE => Self::YamlFlowYamlNode(YamlFlowYamlNode { syntax }),
             _ => return None,
@@ -3772,12 +3754,14 @@ impl AstNode for AnyYamlFlowMapImplicitKey {
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
+            Self::YamlAliasNode(it) => &it.syntax,
             Self::YamlFlowJsonNode(it) => &it.syntax,
             Self::YamlFlowYamlNode(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
+            Self::YamlAliasNode(it) => it.syntax,
             Self::YamlFlowJsonNode(it) => it.syntax,
             Self::YamlFlowYamlNode(it) => it.syntax,
         }
@@ -3786,6 +3770,7 @@ impl AstNode for AnyYamlFlowMapImplicitKey {
 impl std::fmt::Debug for AnyYamlFlowMapImplicitKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::YamlAliasNode(it) => std::fmt::Debug::fmt(it, f),
             Self::YamlFlowJsonNode(it) => std::fmt::Debug::fmt(it, f),
             Self::YamlFlowYamlNode(it) => std::fmt::Debug::fmt(it, f),
         }
@@ -3794,6 +3779,7 @@ impl std::fmt::Debug for AnyYamlFlowMapImplicitKey {
 impl From<AnyYamlFlowMapImplicitKey> for SyntaxNode {
     fn from(n: AnyYamlFlowMapImplicitKey) -> Self {
         match n {
+            AnyYamlFlowMapImplicitKey::YamlAliasNode(it) => it.into(),
             AnyYamlFlowMapImplicitKey::YamlFlowJsonNode(it) => it.into(),
             AnyYamlFlowMapImplicitKey::YamlFlowYamlNode(it) => it.into(),
         }
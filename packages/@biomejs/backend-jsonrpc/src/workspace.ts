@@ -75,6 +75,10 @@ export interface Configuration {
 	 * The configuration for the linter
 	 */
 	linter?: LinterConfiguration;
+	/**
+	 * Specific configuration for the Markdown language
+	 */
+	markdown?: MarkdownConfiguration;
 	/**
 	 * A list of granular patterns that should be applied only to a sub set of files
 	 */
@@ -91,6 +95,10 @@ export interface Configuration {
 	 * The configuration of the VCS integration
 	 */
 	vcs?: VcsConfiguration;
+	/**
+	 * Specific configuration for the YAML language
+	 */
+	yaml?: YamlConfiguration;
 }
 export type Schema = string;
 export interface AssistConfiguration {
@@ -235,6 +243,10 @@ export interface GraphqlConfiguration {
 	 */
 	formatter?: GraphqlFormatterConfiguration;
 	linter?: GraphqlLinterConfiguration;
+	/**
+	 * A list of glob patterns that match the files that define the schema of the project. The schema is used to validate the operations and the fragments of the other GraphQL files.
+	 */
+	schema?: NormalizedGlob[];
 }
 /**
  * Options applied to GritQL files
@@ -261,6 +273,10 @@ export interface HtmlConfiguration {
 	 * HTML formatter options
 	 */
 	formatter?: HtmlFormatterConfiguration;
+	/**
+	 * HTML linter options
+	 */
+	linter?: HtmlLinterConfiguration;
 	/**
 	 * HTML parsing options
 	 */
@@ -317,6 +333,10 @@ export interface JsonConfiguration {
 	 * Parsing options
 	 */
 	parser?: JsonParserConfiguration;
+	/**
+	 * JSON Schemas used to validate JSON files, associated with the files they apply to
+	 */
+	schemas?: JsonSchemas;
 }
 export interface LinterConfiguration {
 	/**
@@ -336,6 +356,15 @@ export interface LinterConfiguration {
 	 */
 	rules?: Rules;
 }
+/**
+ * Options applied to Markdown files
+ */
+export interface MarkdownConfiguration {
+	/**
+	 * Markdown formatter options
+	 */
+	formatter?: MarkdownFormatterConfiguration;
+}
 export type Overrides = OverridePattern[];
 export type Plugins = PluginConfiguration[];
 export type Bool = boolean;
@@ -366,6 +395,15 @@ If Biome can't find the configuration, it will attempt to use the current workin
 	 */
 	useIgnoreFile?: Bool;
 }
+/**
+ * Options applied to YAML files
+ */
+export interface YamlConfiguration {
+	/**
+	 * YAML formatter options
+	 */
+	formatter?: YamlFormatterConfiguration;
+}
 export interface Actions {
 	/**
 	 * It enables the assist actions recommended by Biome. `true` by default.
@@ -584,6 +622,15 @@ export interface HtmlFormatterConfiguration {
 	 */
 	whitespaceSensitivity?: WhitespaceSensitivity;
 }
+/**
+ * Options that change how the HTML linter behaves.
+ */
+export interface HtmlLinterConfiguration {
+	/**
+	 * Control the linter for HTML (and its super languages) files.
+	 */
+	enabled?: Bool;
+}
 /**
  * Options that changes how the HTML parser behaves
  */
@@ -617,6 +664,10 @@ export interface JsFormatterConfiguration {
 	 * Whether to insert spaces around brackets in object literals. Defaults to true.
 	 */
 	bracketSpacing?: BracketSpacing;
+	/**
+	 * Whether to format the content of well-known tagged templates with the formatter of the embedded language. When set to `auto`, the CSS inside `css`, `keyframes`, `createGlobalStyle` and `styled` templates, and the GraphQL inside `gql` and `graphql` templates, are formatted. Interpolations are kept as they are. Defaults to "off".
+	 */
+	embeddedLanguageFormatting?: EmbeddedLanguageFormatting;
 	/**
 	 * Control the formatter for JavaScript (and its super languages) files.
 	 */
@@ -761,6 +812,7 @@ export interface JsonParserConfiguration {
 	 */
 	allowTrailingCommas?: Bool;
 }
+export type JsonSchemas = JsonSchemaAssociation[];
 export type RuleDomains = { [K in RuleDomain]?: RuleDomainValue };
 export interface Rules {
 	a11y?: SeverityOrGroup_for_A11y;
@@ -776,6 +828,31 @@ export interface Rules {
 	style?: SeverityOrGroup_for_Style;
 	suspicious?: SeverityOrGroup_for_Suspicious;
 }
+/**
+ * Options that changes how the Markdown formatter behaves
+ */
+export interface MarkdownFormatterConfiguration {
+	/**
+	 * Control the formatter for Markdown files.
+	 */
+	enabled?: Bool;
+	/**
+	 * The indent style applied to Markdown files.
+	 */
+	indentStyle?: IndentStyle;
+	/**
+	 * The size of the indentation applied to Markdown files. Default to 2.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to Markdown files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to Markdown files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+}
 export interface OverridePattern {
 	/**
 	 * Specific configuration for the Json language
@@ -821,13 +898,48 @@ export interface OverridePattern {
 	 * Specific configuration for the Json language
 	 */
 	linter?: OverrideLinterConfiguration;
+	/**
+	 * Specific configuration for the Markdown language
+	 */
+	markdown?: MarkdownConfiguration;
 	/**
 	 * Specific configuration for additional plugins
 	 */
 	plugins?: Plugins;
+	/**
+	 * Specific configuration for the YAML language
+	 */
+	yaml?: YamlConfiguration;
 }
 export type PluginConfiguration = string;
 export type VcsClientKind = "git";
+/**
+	* Options that changes how the YAML formatter behaves.
+
+YAML doesn't allow tabs in indentation, so YAML files are always indented with spaces. 
+	 */
+export interface YamlFormatterConfiguration {
+	/**
+	 * Control the formatter for YAML files.
+	 */
+	enabled?: Bool;
+	/**
+	 * The size of the indentation applied to YAML files. Default to 2.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to YAML files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to YAML files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+	/**
+	 * The type of quotes used in YAML files. Defaults to double.
+	 */
+	quoteStyle?: QuoteStyle;
+}
 /**
  * A list of rules that belong to this group
  */
@@ -880,6 +992,7 @@ Note that this is only necessary for inline elements. Block elements do not have
 	 */
 export type WhitespaceSensitivity = "css" | "strict" | "ignore";
 export type ArrowParentheses = "always" | "asNeeded";
+export type EmbeddedLanguageFormatting = "auto" | "off";
 export type QuoteProperties = "asNeeded" | "preserve";
 export type Semicolons = "always" | "asNeeded";
 /**
@@ -887,6 +1000,19 @@ export type Semicolons = "always" | "asNeeded";
  */
 export type TrailingCommas = "all" | "es5" | "none";
 export type TrailingCommas2 = "none" | "all";
+/**
+ * Associates a JSON Schema with the JSON files it applies to
+ */
+export interface JsonSchemaAssociation {
+	/**
+	 * A list of glob patterns. The schema is used to validate the JSON files that match these patterns, unless they declare their own `$schema`.
+	 */
+	includes?: NormalizedGlob[];
+	/**
+	 * The path to the JSON Schema, relative to the configuration file
+	 */
+	schema?: string;
+}
 /**
  * Rule domains
  */
@@ -1582,6 +1708,18 @@ export interface Nursery {
 	 * Disallow the use of the !important style.
 	 */
 	noImportantStyles?: RuleFixConfiguration_for_Null;
+	/**
+	 * Disallow arguments that don't match their definition in the schema.
+	 */
+	noInvalidArguments?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow fragments whose type condition can never match.
+	 */
+	noInvalidFragmentTypes?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow values that don't conform to the JSON Schema of the document.
+	 */
+	noJsonSchemaViolations?: RuleConfiguration_for_Null;
 	/**
 	 * Reports usage of "magic numbers" — numbers used directly instead of being assigned to named constants.
 	 */
@@ -1622,14 +1760,38 @@ export interface Nursery {
 	 * Disallow let or var variables that are read but never assigned.
 	 */
 	noUnassignedVariables?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow using variables that aren't defined by the operation.
+	 */
+	noUndefinedVariables?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unknown at-rules.
 	 */
 	noUnknownAtRule?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow using directives that aren't defined by the schema, or in locations where they aren't allowed.
+	 */
+	noUnknownDirectives?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow selecting fields that aren't defined by the schema.
+	 */
+	noUnknownFields?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow referencing types that aren't defined by the schema.
+	 */
+	noUnknownTypes?: RuleConfiguration_for_Null;
 	/**
 	 * Warn when importing non-existing exports.
 	 */
 	noUnresolvedImports?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow exports that no module imports, and modules that no entry point reaches.
+	 */
+	noUnusedExports?: RuleConfiguration_for_NoUnusedExportsOptions;
+	/**
+	 * Disallow variable definitions that aren't used by the operation.
+	 */
+	noUnusedVariableDefinitions?: RuleFixConfiguration_for_Null;
 	/**
 	 * Prevent duplicate polyfills from Polyfill.io.
 	 */
@@ -2473,6 +2635,9 @@ export type RuleConfiguration_for_NoRestrictedElementsOptions =
 export type RuleConfiguration_for_NoSecretsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_NoSecretsOptions;
+export type RuleConfiguration_for_NoUnusedExportsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoUnusedExportsOptions;
 export type RuleFixConfiguration_for_UseConsistentObjectDefinitionOptions =
 	| RulePlainConfiguration
 	| RuleWithFixOptions_for_UseConsistentObjectDefinitionOptions;
@@ -2741,6 +2906,16 @@ export interface RuleWithOptions_for_NoSecretsOptions {
 	 */
 	options: NoSecretsOptions;
 }
+export interface RuleWithOptions_for_NoUnusedExportsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoUnusedExportsOptions;
+}
 export interface RuleWithFixOptions_for_UseConsistentObjectDefinitionOptions {
 	/**
 	 * The kind of the code actions emitted by the rule
@@ -3117,6 +3292,15 @@ export interface NoSecretsOptions {
 	 */
 	entropyThreshold?: number;
 }
+/**
+ * Options for the rule `noUnusedExports`.
+ */
+export interface NoUnusedExportsOptions {
+	/**
+	 * Globs of the files that are entry points, in addition to the ones declared by the `package.json`.
+	 */
+	entryPoints?: Glob[];
+}
 export interface UseConsistentObjectDefinitionOptions {
 	/**
 	 * The preferred syntax to enforce.
@@ -3629,9 +3813,12 @@ export type Category =
 	| "lint/nursery/noImportCycles"
 	| "lint/nursery/noImportantInKeyframe"
 	| "lint/nursery/noImportantStyles"
+	| "lint/nursery/noInvalidArguments"
 	| "lint/nursery/noInvalidDirectionInLinearGradient"
+	| "lint/nursery/noInvalidFragmentTypes"
 	| "lint/nursery/noInvalidGridAreas"
 	| "lint/nursery/noInvalidPositionAtImportRule"
+	| "lint/nursery/noJsonSchemaViolations"
 	| "lint/nursery/noMagicNumbers"
 	| "lint/nursery/noMissingGenericFamilyKeyword"
 	| "lint/nursery/noNestedComponentDefinitions"
@@ -3645,16 +3832,22 @@ export type Category =
 	| "lint/nursery/noShorthandPropertyOverrides"
 	| "lint/nursery/noTsIgnore"
 	| "lint/nursery/noUnassignedVariables"
+	| "lint/nursery/noUndefinedVariables"
 	| "lint/nursery/noUndeclaredDependencies"
 	| "lint/nursery/noUnknownAtRule"
+	| "lint/nursery/noUnknownDirectives"
+	| "lint/nursery/noUnknownFields"
 	| "lint/nursery/noUnknownFunction"
 	| "lint/nursery/noUnknownMediaFeatureName"
 	| "lint/nursery/noUnknownProperty"
 	| "lint/nursery/noUnknownSelectorPseudoElement"
+	| "lint/nursery/noUnknownTypes"
 	| "lint/nursery/noUnknownUnit"
 	| "lint/nursery/noUnmatchableAnbSelector"
 	| "lint/nursery/noUnresolvedImports"
+	| "lint/nursery/noUnusedExports"
 	| "lint/nursery/noUnusedFunctionParameters"
+	| "lint/nursery/noUnusedVariableDefinitions"
 	| "lint/nursery/noUnwantedPolyfillio"
 	| "lint/nursery/noUselessBackrefInRegex"
 	| "lint/nursery/noUselessEscapeInString"
@@ -3869,6 +4062,8 @@ export type Category =
 	| "reporter/parse"
 	| "reporter/format"
 	| "reporter/violations"
+	| "reporter/unreachableModules"
+	| "reporter/unusedExports"
 	| "parse"
 	| "lint"
 	| "lint/a11y"
@@ -3886,6 +4081,7 @@ export type Category =
 	| "suppressions/unknownAction"
 	| "suppressions/unused"
 	| "suppressions/incorrect"
+	| "suppressions/unsupported"
 	| "args/fileNotFound"
 	| "flags/invalid"
 	| "semanticTests";
@@ -4033,7 +4229,9 @@ export type DocumentFileSource =
 	| { Css: CssFileSource }
 	| { Graphql: GraphqlFileSource }
 	| { Html: HtmlFileSource }
-	| { Grit: GritFileSource };
+	| { Grit: GritFileSource }
+	| { Markdown: MarkdownFileSource }
+	| { Yaml: YamlFileSource };
 export interface JsFileSource {
 	/**
 	 * Used to mark if the source is being used for an Astro, Svelte or Vue file
@@ -4061,6 +4259,10 @@ export interface HtmlFileSource {
 export interface GritFileSource {
 	variant: GritVariant;
 }
+export interface MarkdownFileSource {
+	variant: MarkdownVariant;
+}
+export interface YamlFileSource {}
 export type EmbeddingKind = "Astro" | "Vue" | "Svelte" | "None";
 export type Language =
 	| "javaScript"
@@ -4083,21 +4285,38 @@ export type JsonFileVariant = "standard" | "jsonc";
 /**
 	* The style of CSS contained in the file.
 
-Currently, Biome only supports plain CSS, and aims to be compatible with the latest Recommendation level standards. 
+Biome aims to be compatible with the latest Recommendation level standards for plain CSS, and additionally supports the SCSS syntax of Sass and Less. 
 	 */
-export type CssVariant = "standard";
+export type CssVariant = "standard" | "scss" | "less";
 /**
  * The style of GraphQL contained in the file.
  */
 export type GraphqlVariant = "standard";
 export type HtmlVariant = "Standard" | "Astro";
 export type GritVariant = "Standard";
+/**
+ * The flavour of Markdown contained in the file.
+ */
+export type MarkdownVariant = "gfm";
 export interface ChangeFileParams {
 	content: string;
+	/**
+	* The edits that turned the previous content of the file into `content`, in the order they were applied.
+
+When provided, the workspace tries to update the syntax tree of the file in place instead of parsing `content` from scratch. 
+	 */
+	edits?: ChangeFileEdit[];
 	path: BiomePath;
 	projectKey: ProjectKey;
 	version: number;
 }
+export interface ChangeFileEdit {
+	/**
+	 * The range of the replaced text, relative to the content of the file after the previous edits.
+	 */
+	range: TextRange;
+	text: string;
+}
 export interface CloseFileParams {
 	path: BiomePath;
 	projectKey: ProjectKey;
@@ -4125,6 +4344,16 @@ export interface GetFileContentParams {
 	path: BiomePath;
 	projectKey: ProjectKey;
 }
+export interface GetModuleDependenciesParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface GetModuleDependenciesResult {
+	/**
+	 * The paths of the modules that are imported by the file, directly or transitively, sorted alphabetically.
+	 */
+	dependencies: BiomePath[];
+}
 export interface GetControlFlowGraphParams {
 	cursor: TextSize;
 	path: BiomePath;
@@ -4326,16 +4555,90 @@ export interface RenameResult {
 	 * List of text edit operations to apply on the source code
 	 */
 	indels: TextEdit;
+	/**
+	 * Text edit operations to apply on the other modules affected by this rename operation, such as the modules importing a renamed export
+	 */
+	moduleEdits: ModuleTextEdit[];
 	/**
 	 * Range of source code modified by this rename operation
 	 */
 	range: TextRange;
 }
+export interface ModuleTextEdit {
+	/**
+	 * List of text edit operations to apply on the source code of the module
+	 */
+	indels: TextEdit;
+	/**
+	 * Path of the modified module
+	 */
+	path: BiomePath;
+	/**
+	 * Range of source code modified in the module
+	 */
+	range: TextRange;
+}
+export interface HoverParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+	symbolAt: TextSize;
+}
+export interface HoverResult {
+	/**
+	 * Markdown content describing the hovered symbol, such as its inferred type
+	 */
+	contents?: string;
+	/**
+	 * Range of the symbol that was hovered, if any
+	 */
+	range?: TextRange;
+}
+export interface GotoDefinitionParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+	symbolAt: TextSize;
+}
+export interface GotoDefinitionResult {
+	/**
+	 * Locations where the symbol is declared
+	 */
+	definitions: SymbolLocation[];
+}
+export interface SymbolLocation {
+	/**
+	 * Path of the file that contains the symbol
+	 */
+	path: BiomePath;
+	/**
+	 * Range of the symbol inside the file
+	 */
+	range: TextRange;
+}
+export interface FindReferencesParams {
+	/**
+	 * Whether the declaration of the symbol should be included in the result
+	 */
+	includeDeclaration: boolean;
+	path: BiomePath;
+	projectKey: ProjectKey;
+	symbolAt: TextSize;
+}
+export interface FindReferencesResult {
+	/**
+	 * Locations where the symbol is referenced, across the project
+	 */
+	references: SymbolLocation[];
+}
 export interface ParsePatternParams {
 	defaultLanguage: GritTargetLanguage;
 	pattern: string;
 }
-export type GritTargetLanguage = "CSS" | "JavaScript";
+export type GritTargetLanguage =
+	| "CSS"
+	| "GraphQL"
+	| "HTML"
+	| "JavaScript"
+	| "JSON";
 export interface ParsePatternResult {
 	patternId: PatternId;
 }
@@ -4348,6 +4651,12 @@ export interface SearchPatternParams {
 export interface SearchResults {
 	matches: TextRange[];
 	path: BiomePath;
+	/**
+	* Content of the file after applying the rewrites of the pattern.
+
+It's `None` when the pattern doesn't rewrite anything in the file. 
+	 */
+	rewritten?: string;
 }
 export interface DropPatternParams {
 	pattern: PatternId;
@@ -4363,6 +4672,9 @@ export interface Workspace {
 	fileExists(params: FileExitsParams): Promise<boolean>;
 	checkFileSize(params: CheckFileSizeParams): Promise<CheckFileSizeResult>;
 	getFileContent(params: GetFileContentParams): Promise<string>;
+	getModuleDependencies(
+		params: GetModuleDependenciesParams,
+	): Promise<GetModuleDependenciesResult>;
 	getControlFlowGraph(params: GetControlFlowGraphParams): Promise<string>;
 	getFormatterIr(params: GetFormatterIRParams): Promise<string>;
 	getTypeInfo(params: GetTypeInfoParams): Promise<string>;
@@ -4377,6 +4689,9 @@ export interface Workspace {
 	formatOnType(params: FormatOnTypeParams): Promise<Printed>;
 	fixFile(params: FixFileParams): Promise<FixFileResult>;
 	rename(params: RenameParams): Promise<RenameResult>;
+	hover(params: HoverParams): Promise<HoverResult>;
+	gotoDefinition(params: GotoDefinitionParams): Promise<GotoDefinitionResult>;
+	findReferences(params: FindReferencesParams): Promise<FindReferencesResult>;
 	parsePattern(params: ParsePatternParams): Promise<ParsePatternResult>;
 	searchPattern(params: SearchPatternParams): Promise<SearchResults>;
 	dropPattern(params: DropPatternParams): Promise<void>;
@@ -4414,6 +4729,9 @@ export function createWorkspace(transport: Transport): Workspace {
 		getFileContent(params) {
 			return transport.request("biome/get_file_content", params);
 		},
+		getModuleDependencies(params) {
+			return transport.request("biome/get_module_dependencies", params);
+		},
 		getControlFlowGraph(params) {
 			return transport.request("biome/get_control_flow_graph", params);
 		},
@@ -4450,6 +4768,15 @@ export function createWorkspace(transport: Transport): Workspace {
 		rename(params) {
 			return transport.request("biome/rename", params);
 		},
+		hover(params) {
+			return transport.request("biome/hover", params);
+		},
+		gotoDefinition(params) {
+			return transport.request("biome/goto_definition", params);
+		},
+		findReferences(params) {
+			return transport.request("biome/find_references", params);
+		},
 		parsePattern(params) {
 			return transport.request("biome/parse_pattern", params);
 		},
@@ -40,6 +40,9 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         ("%=", "PERCENTEQ"),
         ("@", "AT"),
         ("$=", "DOLLAR_EQ"),
+        ("$", "DOLLAR"),
+        ("==", "EQ2"),
+        ("...", "DOT3"),
         ("~=", "TILDE_EQ"),
         ("-->", "CDC"),
         ("<!--", "CDO"),
@@ -116,6 +119,27 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "ridge",
         "inset",
         "outset",
+        // SCSS keywords
+        "use",
+        "forward",
+        "with",
+        "show",
+        "hide",
+        "mixin",
+        "include",
+        "function",
+        "return",
+        "content",
+        "extend",
+        "optional",
+        "if",
+        "else",
+        "each",
+        "for",
+        "through",
+        "while",
+        // Less keywords
+        "when",
         // HERE: Add new regular keywords _above_ here. Be sure to also add them
         // to `consume_identifier` in `biome_css_parser/src/lexer/mod.rs` as well.
         // CSS-wide keywords
@@ -514,5 +538,79 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_BOGUS_SUPPORTS_CONDITION",
         // Grit metavariable
         "CSS_METAVARIABLE",
+        // SCSS
+        "SCSS_DECLARATION",
+        "SCSS_VARIABLE_MODIFIER_LIST",
+        "SCSS_VARIABLE_MODIFIER",
+        "SCSS_IDENTIFIER",
+        "SCSS_QUALIFIED_NAME",
+        "SCSS_INTERPOLATION",
+        "SCSS_INTERPOLATED_IDENTIFIER",
+        "SCSS_INTERPOLATED_IDENTIFIER_PART_LIST",
+        "SCSS_PARENTHESIZED_EXPRESSION",
+        "SCSS_MAP_EXPRESSION",
+        "SCSS_MAP_EXPRESSION_PAIR_LIST",
+        "SCSS_MAP_EXPRESSION_PAIR",
+        "SCSS_OPERATOR",
+        "SCSS_PLACEHOLDER_SELECTOR",
+        "SCSS_USE_AT_RULE",
+        "SCSS_USE_AS_CLAUSE",
+        "SCSS_USE_ALL_NAMESPACE",
+        "SCSS_FORWARD_AT_RULE",
+        "SCSS_FORWARD_AS_CLAUSE",
+        "SCSS_FORWARD_VISIBILITY_CLAUSE",
+        "SCSS_FORWARD_MEMBER_LIST",
+        "SCSS_MODULE_CONFIGURATION",
+        "SCSS_KEYWORD_ARGUMENT_LIST",
+        "SCSS_KEYWORD_ARGUMENT",
+        "SCSS_MIXIN_AT_RULE",
+        "SCSS_FUNCTION_AT_RULE",
+        "SCSS_PARAMETERS",
+        "SCSS_PARAMETER_LIST",
+        "SCSS_PARAMETER",
+        "SCSS_PARAMETER_DEFAULT_VALUE",
+        "SCSS_RETURN_AT_RULE",
+        "SCSS_INCLUDE_AT_RULE",
+        "SCSS_NAMESPACED_NAME",
+        "SCSS_ARGUMENTS",
+        "SCSS_ARGUMENT_LIST",
+        "SCSS_ARGUMENT",
+        "SCSS_CONTENT_AT_RULE",
+        "SCSS_EXTEND_AT_RULE",
+        "SCSS_EXTEND_OPTIONAL_MODIFIER",
+        "SCSS_IF_AT_RULE",
+        "SCSS_ELSE_CLAUSE",
+        "SCSS_EACH_AT_RULE",
+        "SCSS_EACH_BINDING_LIST",
+        "SCSS_FOR_AT_RULE",
+        "SCSS_WHILE_AT_RULE",
+        // Less
+        "LESS_VARIABLE_DECLARATION",
+        "LESS_IDENTIFIER",
+        "LESS_ESCAPED_VALUE",
+        "LESS_PARENTHESIZED_EXPRESSION",
+        "LESS_OPERATOR",
+        "LESS_DETACHED_RULESET_DECLARATION",
+        "LESS_DETACHED_RULESET_CALL",
+        "LESS_MIXIN_DECLARATION",
+        "LESS_MIXIN_PARAMETERS",
+        "LESS_MIXIN_PARAMETER_LIST",
+        "LESS_MIXIN_PARAMETER",
+        "LESS_MIXIN_PARAMETER_DEFAULT_VALUE",
+        "LESS_MIXIN_REST_PARAMETER",
+        "LESS_MIXIN_PATTERN_PARAMETER",
+        "LESS_MIXIN_GUARD",
+        "LESS_GUARD_CONDITION",
+        "LESS_GUARD_LOGICAL_CONDITION",
+        "LESS_MIXIN_CALL",
+        "LESS_MIXIN_PATH_SEGMENT_LIST",
+        "LESS_MIXIN_PATH_SEGMENT",
+        "LESS_MIXIN_ARGUMENTS",
+        "LESS_MIXIN_ARGUMENT_LIST",
+        "LESS_MIXIN_NAMED_ARGUMENT",
+        "LESS_MIXIN_ARGUMENT",
+        "LESS_IMPORT_AT_RULE",
+        "LESS_IMPORT_OPTIONS",
+        "LESS_IMPORT_OPTION_LIST",
     ],
 };
@@ -561,9 +561,13 @@ enum NodeDialect {
     Jsx,
     Json,
     Css,
+    Scss,
+    Less,
     Grit,
     Graphql,
     Html,
+    Md,
+    Yaml,
 }
 
 impl NodeDialect {
@@ -574,9 +578,13 @@ impl NodeDialect {
             Self::Jsx,
             Self::Json,
             Self::Css,
+            Self::Scss,
+            Self::Less,
             Self::Grit,
             Self::Graphql,
             Self::Html,
+            Self::Md,
+            Self::Yaml,
         ]
     }
 
@@ -591,9 +599,13 @@ impl NodeDialect {
             Self::Jsx => "jsx",
             Self::Json => "json",
             Self::Css => "css",
+            Self::Scss => "scss",
+            Self::Less => "less",
             Self::Grit => "grit",
             Self::Graphql => "graphql",
             Self::Html => "html",
+            Self::Md => "markdown",
+            Self::Yaml => "yaml",
         }
     }
 
@@ -604,9 +616,13 @@ impl NodeDialect {
             "Ts" => Self::Ts,
             "Json" => Self::Json,
             "Css" => Self::Css,
+            "Scss" => Self::Scss,
+            "Less" => Self::Less,
             "Grit" => Self::Grit,
             "Graphql" => Self::Graphql,
             "Html" => Self::Html,
+            "Md" => Self::Md,
+            "Yaml" => Self::Yaml,
             _ => {
                 eprintln!("missing prefix {name}");
                 Self::Js
@@ -829,7 +845,6 @@ fn get_node_concept(
                 _ => NodeConcept::Auxiliary,
             },
 
-            // TODO: implement formatter
             LanguageKind::Yaml => NodeConcept::Auxiliary,
         }
     }
@@ -896,7 +911,7 @@ impl LanguageKind {
             Self::Grit => "GritFormatter",
             Self::Html => "HtmlFormatter",
             Self::Yaml => "YamlFormatter",
-            Self::Markdown => "DemoFormatter",
+            Self::Markdown => "MarkdownFormatter",
         };
 
         Ident::new(name, Span::call_site())
@@ -911,7 +926,7 @@ impl LanguageKind {
             Self::Grit => "GritFormatContext",
             Self::Html => "HtmlFormatContext",
             Self::Yaml => "YamlFormatContext",
-            Self::Markdown => "DemoFormatterContext",
+            Self::Markdown => "MarkdownFormatContext",
         };
 
         Ident::new(name, Span::call_site())
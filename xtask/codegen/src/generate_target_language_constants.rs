@@ -31,22 +31,34 @@ pub fn generate_target_language_constants(
                 .collect::<Vec<_>>()
         })
         .collect();
+    // Glob imports are only needed when there are slots to refer to.
+    let glob = if disregarded_slots.is_empty() {
+        ""
+    } else {
+        ", *"
+    };
     let disregarded_slots = disregarded_slots.join("\n    ");
 
     let syntax_kind = match language_kind {
         LanguageKind::Css => "CssSyntaxKind",
+        LanguageKind::Graphql => "GraphqlSyntaxKind",
+        LanguageKind::Html => "HtmlSyntaxKind",
         LanguageKind::Js => "JsSyntaxKind",
+        LanguageKind::Json => "JsonSyntaxKind",
         _ => unimplemented!(),
     };
     let syntax_kind_module = match language_kind {
         LanguageKind::Css => "biome_css_syntax",
+        LanguageKind::Graphql => "biome_graphql_syntax",
+        LanguageKind::Html => "biome_html_syntax",
         LanguageKind::Js => "biome_js_syntax",
+        LanguageKind::Json => "biome_json_syntax",
         _ => unimplemented!(),
     };
 
     let result = format!(
-        "use crate::grit_target_language::DisregardedSlotCondition::{{self, *}};
-use {syntax_kind_module}::{syntax_kind}::{{self, *}};
+        "use crate::grit_target_language::DisregardedSlotCondition::{{self{glob}}};
+use {syntax_kind_module}::{syntax_kind}::{{self{glob}}};
 
 pub(crate) const DISREGARDED_SNIPPET_SLOTS: &[({syntax_kind}, u32, DisregardedSlotCondition)] = &[
     {disregarded_slots}
@@ -21,6 +21,7 @@ pub const HTML_KINDS_SRC: KindsSrc = KindsSrc {
         "WHITESPACE",
         "IDENT",
         "HTML_IDENT",
+        "GRIT_METAVARIABLE",
     ],
     nodes: &[
         "HTML_ROOT",
@@ -41,6 +42,7 @@ pub const HTML_KINDS_SRC: KindsSrc = KindsSrc {
         "HTML_COMMENT",
         "HTML_CDATA_SECTION",
         // Bogus nodes
+        "HTML_METAVARIABLE",
         "HTML_BOGUS",
         "HTML_BOGUS_ELEMENT",
         "HTML_BOGUS_ATTRIBUTE",
@@ -659,6 +659,7 @@ impl Field {
                     (">>=", _) => "left_shift_assign",
                     ("<<=", _) => "right_shift_assign",
                     (">>>=", _) => "unsigned_right_shift_assign",
+                    ("~", LanguageKind::Css) => "tilde",
                     ("~", _) => "bitwise_not",
                     ("&=", _) => "bitwise_and_assign",
                     ("|=", LanguageKind::Js) => "bitwise_or_assign",
@@ -689,7 +690,7 @@ impl Field {
                     ("||", _) => "logical_or",
                     ("&&", _) => "logical_and",
                     ("$=", _) => "suffix",
-                    ("$", LanguageKind::Graphql) => "dollar",
+                    ("$", LanguageKind::Graphql | LanguageKind::Css) => "dollar",
                     ("~=", _) => "whitespace_like",
                     (",", _) => "comma",
                     ("---", LanguageKind::Yaml) => "dashdashdash",
@@ -12,12 +12,14 @@ use crate::yaml_kinds_src::YAML_KINDS_SRC;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
 
-pub const LANGUAGE_PREFIXES: [&str; 10] = [
+pub const LANGUAGE_PREFIXES: [&str; 12] = [
     "js_",
     "ts_",
     "jsx_",
     "tsx_",
     "css_",
+    "scss_",
+    "less_",
     "json_",
     "grit_",
     "html_",
@@ -162,6 +164,9 @@ impl LanguageKind {
     }
 
     pub fn supports_grit(&self) -> bool {
-        matches!(self, Self::Css | Self::Js)
+        matches!(
+            self,
+            Self::Css | Self::Graphql | Self::Html | Self::Js | Self::Json
+        )
     }
 }
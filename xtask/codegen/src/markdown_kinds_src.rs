@@ -17,11 +17,14 @@ pub const MARKDOWN_KINDS_SRC: KindsSrc = KindsSrc {
         ("`", "BACKTICK"),
         ("```", "TRIPLE_BACKTICK"),
         ("~", "TILDE"),
+        ("~~", "DOUBLE_TILDE"),
         ("   ", "WHITESPACE3"),
         ("_", "UNDERSCORE"),
         ("__", "DOUBLE_UNDERSCORE"),
         ("#", "HASH"),
         (",", "COMMA"),
+        ("+", "PLUS"),
+        ("|", "PIPE"),
     ],
     keywords: &["null"],
     literals: &[
@@ -31,6 +34,10 @@ pub const MARKDOWN_KINDS_SRC: KindsSrc = KindsSrc {
         "MD_STRING_LITERAL",
         "MD_INDENT_CHUNK_LITERAL",
         "MD_THEMATIC_BREAK_LITERAL",
+        "MD_SETEXT_UNDERLINE_LITERAL",
+        "MD_CODE_FENCE_LITERAL",
+        "MD_CODE_SPAN_DELIMITER_LITERAL",
+        "MD_ORDERED_LIST_MARKER",
         "MD_ERROR_LITERAL",
     ],
     tokens: &["ERROR_TOKEN", "NEWLINE", "WHITESPACE", "TAB"],
@@ -46,14 +53,14 @@ pub const MARKDOWN_KINDS_SRC: KindsSrc = KindsSrc {
         "MD_HEADER",
         "MD_INDENT_CODE_BLOCK",
         "MD_FENCED_CODE_BLOCK",
-        "MD_CODE_NAME_LIST",
         "MD_HTML_BLOCK",
         "MD_LINK_BLOCK",
         "MD_QUOTE",
+        "MD_QUOTE_LINE_LIST",
+        "MD_QUOTE_LINE",
         "MD_ORDER_LIST_ITEM",
         "MD_BULLET_LIST_ITEM",
         "MD_BULLET_LIST",
-        "MD_ORDER_LIST",
         "MD_PARAGRAPH",
         "MD_INLINE_ITEM_LIST",
         "MD_INLINE_EMPHASIS",
@@ -62,17 +69,19 @@ pub const MARKDOWN_KINDS_SRC: KindsSrc = KindsSrc {
         "MD_BULLET",
         "MD_INLINE_LINK",
         "MD_INLINE_IMAGE",
-        "MD_INLINE_IMAGE_ALT",
+        "MD_INLINE_STRIKETHROUGH",
         "MD_INDENTED_CODE_LINE",
-        "MD_INLINE_IMAGE_LINK",
-        "MD_INLINE_IMAGE_SOURCE",
         "MD_INDENTED_CODE_LINE_LIST",
         "MD_HARD_LINE",
         "MD_SOFT_BREAK",
         "MD_TEXTUAL",
         "MD_SETEXT_HEADER",
         "MD_STRING",
-        "MD_INDENT",
         "MD_THEMATIC_BREAK_BLOCK",
+        "MD_TABLE",
+        "MD_TABLE_ROW_LIST",
+        "MD_TABLE_ROW",
+        "MD_TABLE_CELL_LIST",
+        "MD_TABLE_CELL",
     ],
 };
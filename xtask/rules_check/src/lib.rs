@@ -13,7 +13,10 @@ use biome_css_syntax::CssLanguage;
 use biome_deserialize::json::deserialize_from_json_ast;
 use biome_diagnostics::{DiagnosticExt, PrintDiagnostic, Severity};
 use biome_fs::BiomePath;
+use biome_graphql_analyze::GraphqlAnalyzerServices;
+use biome_graphql_semantic::GraphqlSchemaBuilder;
 use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_syntax::HtmlLanguage;
 use biome_js_analyze::JsAnalyzerServices;
 use biome_js_parser::JsParserOptions;
 use biome_js_syntax::{EmbeddingKind, JsFileSource, JsLanguage, TextSize};
@@ -24,12 +27,13 @@ use biome_rowan::AstNode;
 use biome_service::projects::{ProjectKey, Projects};
 use biome_service::settings::ServiceLanguage;
 use biome_service::workspace::DocumentFileSource;
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag, TagEnd};
 use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter, Write};
 use std::slice;
 use std::str::FromStr;
+use std::sync::Arc;
 
 #[derive(Debug)]
 struct Errors(String);
@@ -130,11 +134,22 @@ pub fn check_rules() -> anyhow::Result<()> {
         }
     }
 
+    impl RegistryVisitor<HtmlLanguage> for LintRulesVisitor {
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule<Options: Default, Query: Queryable<Language = HtmlLanguage, Output: Clone>>
+                + 'static,
+        {
+            self.push_rule::<R, <R::Query as Queryable>::Language>()
+        }
+    }
+
     let mut visitor = LintRulesVisitor::default();
     biome_js_analyze::visit_registry(&mut visitor);
     biome_json_analyze::visit_registry(&mut visitor);
     biome_css_analyze::visit_registry(&mut visitor);
     biome_graphql_analyze::visit_registry(&mut visitor);
+    biome_html_analyze::visit_registry(&mut visitor);
 
     let LintRulesVisitor { groups, errors } = visitor;
     if !errors.is_empty() {
@@ -471,7 +486,60 @@ fn assert_lint(
                     test,
                 );
 
-                biome_json_analyze::analyze(&root, filter, &options, file_source, |signal| {
+                biome_json_analyze::analyze(
+                    &root,
+                    filter,
+                    &options,
+                    &[],
+                    file_source.into(),
+                    |signal| {
+                        if let Some(mut diag) = signal.diagnostic() {
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diag = diag.add_code_suggestion(action.into());
+                                }
+                            }
+
+                            let error = diag.with_file_path(&file_path).with_file_source_code(code);
+                            let res = diagnostics.write_diagnostic(error);
+
+                            // Abort the analysis on error
+                            if let Err(err) = res {
+                                eprintln!("Error: {err}");
+                                return ControlFlow::Break(err);
+                            }
+                        }
+
+                        ControlFlow::Continue(())
+                    },
+                );
+            }
+        }
+        DocumentFileSource::Css(..) => {
+            let parse = biome_css_parser::parse_css(code, CssParserOptions::default());
+
+            if parse.has_errors() {
+                for diag in parse.into_diagnostics() {
+                    let error = diag.with_file_path(&file_path).with_file_source_code(code);
+                    diagnostics.write_diagnostic(error)?;
+                }
+            } else {
+                let root = parse.tree();
+
+                let rule_filter = RuleFilter::Rule(group, rule);
+                let filter = AnalysisFilter {
+                    enabled_rules: Some(slice::from_ref(&rule_filter)),
+                    ..AnalysisFilter::default()
+                };
+
+                let options = create_analyzer_options::<JsonLanguage>(
+                    &workspace_settings,
+                    project_key,
+                    &file_path,
+                    test,
+                );
+
+                biome_css_analyze::analyze(&root, filter, &options, &[], |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         for action in signal.actions() {
                             if !action.is_suppression() {
@@ -493,8 +561,8 @@ fn assert_lint(
                 });
             }
         }
-        DocumentFileSource::Css(..) => {
-            let parse = biome_css_parser::parse_css(code, CssParserOptions::default());
+        DocumentFileSource::Graphql(..) => {
+            let parse = biome_graphql_parser::parse_graphql(code);
 
             if parse.has_errors() {
                 for diag in parse.into_diagnostics() {
@@ -517,7 +585,14 @@ fn assert_lint(
                     test,
                 );
 
-                biome_css_analyze::analyze(&root, filter, &options, &[], |signal| {
+                // Code blocks are validated against the schema they define, so
+                // that examples can show both the schema and the operations.
+                let mut schema = GraphqlSchemaBuilder::default();
+                schema.add_document(Utf8Path::new(&file_path), &root);
+                let services =
+                    GraphqlAnalyzerServices::default().with_schema(Some(Arc::new(schema.build())));
+
+                biome_graphql_analyze::analyze(&root, filter, &options, &[], services, |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         for action in signal.actions() {
                             if !action.is_suppression() {
@@ -539,8 +614,8 @@ fn assert_lint(
                 });
             }
         }
-        DocumentFileSource::Graphql(..) => {
-            let parse = biome_graphql_parser::parse_graphql(code);
+        DocumentFileSource::Html(..) => {
+            let parse = biome_html_parser::parse_html(code);
 
             if parse.has_errors() {
                 for diag in parse.into_diagnostics() {
@@ -556,14 +631,14 @@ fn assert_lint(
                     ..AnalysisFilter::default()
                 };
 
-                let options = create_analyzer_options::<JsonLanguage>(
+                let options = create_analyzer_options::<HtmlLanguage>(
                     &workspace_settings,
                     project_key,
                     &file_path,
                     test,
                 );
 
-                biome_graphql_analyze::analyze(&root, filter, &options, |signal| {
+                biome_html_analyze::analyze(&root, filter, &options, &[], |signal| {
                     if let Some(mut diag) = signal.diagnostic() {
                         for action in signal.actions() {
                             if !action.is_suppression() {
@@ -585,8 +660,9 @@ fn assert_lint(
                 });
             }
         }
-        DocumentFileSource::Html(..) => todo!("HTML analysis is not yet supported"),
         DocumentFileSource::Grit(..) => todo!("Grit analysis is not yet supported"),
+        DocumentFileSource::Markdown(..) => todo!("Markdown analysis is not yet supported"),
+        DocumentFileSource::Yaml(..) => todo!("YAML analysis is not yet supported"),
 
         // Unknown code blocks should be ignored by tests
         DocumentFileSource::Unknown | DocumentFileSource::Ignore => {}